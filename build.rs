@@ -1,7 +1,22 @@
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// The build script only uses part of the reader
+#[allow(dead_code)]
+#[path = "src/model/obj.rs"]
+mod obj;
+
 fn main() {
+    bake_meshes();
+    bake_textures();
     linker_be_nice();
     // make sure linkall.x is the last linker script (otherwise might cause problems with flip-link)
-    println!("cargo:rustc-link-arg=-Tlinkall.x");
+    println!("cargo:rustc-link-arg-bins=-Tlinkall.x");
 }
 
 fn linker_be_nice() {
@@ -34,7 +49,319 @@ fn linker_be_nice() {
     }
 
     println!(
-        "cargo:rustc-link-arg=-Wl,--error-handling-script={}",
+        "cargo:rustc-link-arg-bins=-Wl,--error-handling-script={}",
         std::env::current_exe().unwrap().display()
     );
 }
+
+/// Converts every OBJ file in `assets/` into a `BakedMesh` static,
+/// so models don't need to be parsed on the device at boot.
+fn bake_meshes() {
+    println!("cargo:rerun-if-changed=assets");
+
+    let mut out = String::new();
+    for path in asset_paths("obj") {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let source = fs::read_to_string(&path).unwrap();
+        let mesh = parse_obj(&source).unwrap_or_else(|e| panic!("{}:{}", path.display(), e));
+        let name = path.file_stem().unwrap().to_str().unwrap().to_uppercase();
+        write_mesh(&mut out, &name, &path, &mesh);
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("baked_meshes.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+/// Lists the files in `assets/` with the given extension, sorted
+/// so the generated code doesn't change between builds.
+fn asset_paths(extension: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir("assets")
+        .map(|dir| dir.filter_map(|entry| Some(entry.ok()?.path())).collect())
+        .unwrap_or_default();
+    paths.retain(|path| path.extension().and_then(|e| e.to_str()) == Some(extension));
+    paths.sort();
+    paths
+}
+
+/// Converts every PNG file in `assets/` into `BakedTexture` statics
+/// in RGB565 and RGB888, flipped so row 0 is the bottom of the image,
+/// with a full mip chain.
+fn bake_textures() {
+    let mut out = String::new();
+    for path in asset_paths("png") {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let mut decoder = png::Decoder::new(fs::File::open(&path).unwrap());
+        // Palettes, low bit depths and 16-bit channels all become 8-bit
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder
+            .read_info()
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader
+            .next_frame(&mut buffer)
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        let channels = info.color_type.samples();
+        let (width, height) = (info.width as usize, info.height as usize);
+
+        let mut rgb = Vec::with_capacity(width * height);
+        for row in buffer[..info.buffer_size()].chunks(info.line_size).rev() {
+            for texel in row.chunks(channels) {
+                // Greyscale, with or without alpha, has one colour channel
+                rgb.push(match channels {
+                    1 | 2 => [texel[0]; 3],
+                    _ => [texel[0], texel[1], texel[2]],
+                });
+            }
+        }
+
+        let levels = mip_chain(rgb, width, height);
+
+        let name = path.file_stem().unwrap().to_str().unwrap().to_uppercase();
+        let texture = BakedTexture {
+            name: name.clone(),
+            path: &path,
+            width,
+            height,
+            level_offsets: levels
+                .iter()
+                .scan(0, |offset, level| {
+                    let start = *offset;
+                    *offset += level.len();
+                    Some(start)
+                })
+                .collect(),
+        };
+        let rgb565 = levels.iter().flatten().flat_map(|&[r, g, b]| {
+            let packed = (r as u16 >> 3) << 11 | (g as u16 >> 2) << 5 | b as u16 >> 3;
+            packed.to_le_bytes()
+        });
+        write_texture(&mut out, &texture, "Rgb565", rgb565);
+        let rgb888 = levels.iter().flatten().flatten().copied();
+        let texture = BakedTexture {
+            name: format!("{}_RGB888", name),
+            ..texture
+        };
+        write_texture(&mut out, &texture, "Rgb888", rgb888);
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("baked_textures.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+/// Box filters an image down to 1x1, halving each side per level
+/// and rounding down, as `graphics::texture::mipmap` does.
+fn mip_chain(rgb: Vec<[u8; 3]>, width: usize, height: usize) -> Vec<Vec<[u8; 3]>> {
+    let mut levels = vec![rgb];
+    let (mut w, mut h) = (width, height);
+    while w > 1 || h > 1 {
+        let above = levels.last().unwrap();
+        let (next_w, next_h) = ((w / 2).max(1), (h / 2).max(1));
+        let mut level = Vec::with_capacity(next_w * next_h);
+        for y in 0..next_h {
+            for x in 0..next_w {
+                let (x0, y0) = ((x * 2).min(w - 1), (y * 2).min(h - 1));
+                let (x1, y1) = ((x0 + 1).min(w - 1), (y0 + 1).min(h - 1));
+                let block = [(x0, y0), (x1, y0), (x0, y1), (x1, y1)].map(|(x, y)| above[y * w + x]);
+                level.push(std::array::from_fn(|c| {
+                    ((block.iter().map(|t| t[c] as u16).sum::<u16>() + 2) / 4) as u8
+                }));
+            }
+        }
+        levels.push(level);
+        (w, h) = (next_w, next_h);
+    }
+    levels
+}
+
+/// The fields of a generated `BakedTexture` other than its data.
+struct BakedTexture<'a> {
+    name: String,
+    path: &'a Path,
+    width: usize,
+    height: usize,
+    level_offsets: Vec<usize>,
+}
+
+fn write_texture(
+    out: &mut String,
+    texture: &BakedTexture,
+    format: &str,
+    data: impl Iterator<Item = u8>,
+) {
+    writeln!(out, "/// Baked from `{}`.", texture.path.display()).unwrap();
+    writeln!(
+        out,
+        "pub static {}: BakedTexture = BakedTexture {{",
+        texture.name
+    )
+    .unwrap();
+    writeln!(out, "    width: {},", texture.width).unwrap();
+    writeln!(out, "    height: {},", texture.height).unwrap();
+    writeln!(out, "    format: RawFormat::{},", format).unwrap();
+    writeln!(out, "    level_offsets: &{:?},", texture.level_offsets).unwrap();
+    write!(out, "    data: &[").unwrap();
+    for byte in data {
+        write!(out, "{},", byte).unwrap();
+    }
+    writeln!(out, "],").unwrap();
+    writeln!(out, "}};").unwrap();
+}
+
+/// An OBJ file flattened into unique vertices and triangles.
+#[derive(Default)]
+struct ObjMesh {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    tex_coords: Vec<[f32; 2]>,
+    indices: Vec<[u16; 3]>,
+}
+
+/// Flattens an OBJ file into a mesh with the reader the model
+/// loader uses, so baked and parsed models always agree.
+fn parse_obj(source: &str) -> Result<ObjMesh, String> {
+    let mut sink = MeshSink::default();
+    obj::read_obj(source, &mut sink).map_err(|e| {
+        let message = match e.kind {
+            obj::ObjErrorKind::CapacityExceeded {
+                capacity: obj::Capacity::Vertices,
+                ..
+            } => "vertex index past 65535".to_string(),
+            kind => format!("{:?}", kind),
+        };
+        format!("{}:{}: {}", e.line, e.column, message)
+    })?;
+    Ok(sink.mesh)
+}
+
+/// Collects an OBJ file's elements for `parse_obj`.
+#[derive(Default)]
+struct MeshSink {
+    points: Vec<[f32; 3]>,
+    tex_coords: Vec<[f32; 2]>,
+    normals: Vec<[f32; 3]>,
+    vertex_indices: HashMap<obj::VertexKey, u16>,
+    mesh: ObjMesh,
+}
+
+impl obj::ObjSink for MeshSink {
+    fn point(&mut self, point: [f32; 3]) -> Result<(), obj::Capacity> {
+        self.points.push(point);
+        Ok(())
+    }
+
+    fn tex_coord(&mut self, tex_coord: [f32; 2]) -> Result<(), obj::Capacity> {
+        self.tex_coords.push(tex_coord);
+        Ok(())
+    }
+
+    fn normal(&mut self, normal: [f32; 3]) -> Result<(), obj::Capacity> {
+        self.normals.push(normal);
+        Ok(())
+    }
+
+    fn point_at(&self, index: usize) -> [f32; 3] {
+        self.points[index]
+    }
+
+    fn vertex(
+        &mut self,
+        key: obj::VertexKey,
+        polygon_normal: [f32; 3],
+    ) -> Result<u16, obj::Capacity> {
+        if let Some(i) = self.vertex_indices.get(&key) {
+            return Ok(*i);
+        }
+        // Faces hold 16-bit indices
+        let i = u16::try_from(self.mesh.positions.len()).map_err(|_| obj::Capacity::Vertices)?;
+        let normal = match key.normal {
+            obj::NormalKey::Given(n) => self.normals[n],
+            obj::NormalKey::Polygon(_) => normalise(polygon_normal),
+        };
+        self.mesh.positions.push(self.points[key.point]);
+        self.mesh
+            .tex_coords
+            .push(key.tex_coord.map_or([0.0, 0.0], |t| self.tex_coords[t]));
+        self.mesh.normals.push(normal);
+        self.vertex_indices.insert(key, i);
+        Ok(i)
+    }
+
+    fn triangle(&mut self, verts: [u16; 3]) -> Result<(), obj::Capacity> {
+        self.mesh.indices.push(verts);
+        Ok(())
+    }
+}
+
+fn normalise(v: [f32; 3]) -> [f32; 3] {
+    let len = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if len == 0.0 {
+        return v;
+    }
+    v.map(|c| c / len)
+}
+
+/// Returns the offset and step size that map the range of
+/// `values` onto `steps` quantisation steps starting at `start`.
+fn quantise_range(values: impl Iterator<Item = f32>, start: f32, steps: f32) -> (f32, f32) {
+    let (min, max) = values.fold((f32::MAX, f32::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+    if min > max {
+        return (0.0, 1.0);
+    }
+    let scale = if max > min { (max - min) / steps } else { 1.0 };
+    (min - start * scale, scale)
+}
+
+/// Writes a mesh as a `BakedMesh` static.
+fn write_mesh(out: &mut String, name: &str, path: &Path, mesh: &ObjMesh) {
+    let mut pos_offset = [0.0; 3];
+    let mut pos_scale = [0.0; 3];
+    for axis in 0..3 {
+        (pos_offset[axis], pos_scale[axis]) =
+            quantise_range(mesh.positions.iter().map(|p| p[axis]), -32767.0, 65534.0);
+    }
+    let mut uv_offset = [0.0; 2];
+    let mut uv_scale = [0.0; 2];
+    for axis in 0..2 {
+        (uv_offset[axis], uv_scale[axis]) =
+            quantise_range(mesh.tex_coords.iter().map(|t| t[axis]), 0.0, 65535.0);
+    }
+
+    writeln!(out, "/// Baked from `{}`.", path.display()).unwrap();
+    writeln!(out, "pub static {}: BakedMesh = BakedMesh {{", name).unwrap();
+    writeln!(out, "    pos_offset: {:?},", pos_offset).unwrap();
+    writeln!(out, "    pos_scale: {:?},", pos_scale).unwrap();
+    writeln!(out, "    uv_offset: {:?},", uv_offset).unwrap();
+    writeln!(out, "    uv_scale: {:?},", uv_scale).unwrap();
+
+    write!(out, "    positions: &[").unwrap();
+    for p in &mesh.positions {
+        let q: [i16; 3] =
+            std::array::from_fn(|a| ((p[a] - pos_offset[a]) / pos_scale[a]).round() as i16);
+        write!(out, "{:?},", q).unwrap();
+    }
+    writeln!(out, "],").unwrap();
+
+    write!(out, "    normals: &[").unwrap();
+    for n in &mesh.normals {
+        let q: [i8; 3] = normalise(*n).map(|c| (c * 127.0).round() as i8);
+        write!(out, "{:?},", q).unwrap();
+    }
+    writeln!(out, "],").unwrap();
+
+    write!(out, "    tex_coords: &[").unwrap();
+    for t in &mesh.tex_coords {
+        let q: [u16; 2] =
+            std::array::from_fn(|a| ((t[a] - uv_offset[a]) / uv_scale[a]).round() as u16);
+        write!(out, "{:?},", q).unwrap();
+    }
+    writeln!(out, "],").unwrap();
+
+    write!(out, "    indices: &[").unwrap();
+    for i in &mesh.indices {
+        write!(out, "{:?},", i).unwrap();
+    }
+    writeln!(out, "],").unwrap();
+    writeln!(out, "}};").unwrap();
+}
@@ -3,7 +3,10 @@ use esp_hal::{
     gpio::{AnyPin, GpioPin}, i2c::master::{AnyI2c, I2c}, time::Duration, timer::{AnyTimer, OneShotTimer, PeriodicTimer}, Blocking, Config
 };
 
-use crate::{display::tmds::TMDS, types::vector::Vec3};
+use crate::{
+    display::{sink::PixelSink, tmds::TMDS},
+    types::vector::Vec3,
+};
 
 /// Represents a single set of three TMDS pairs.
 /// DVI Single Link has only one, but Dual Link
@@ -58,66 +61,6 @@ impl<'a> DviInterface<'a> {
         }
     }
 
-    pub fn render_pixel(&mut self, pixel: Vec3<u8>) {
-        let red_tmds = self.red_link.encode_tmds(pixel.x);
-        let green_tmds = self.green_link.encode_tmds(pixel.y);
-        let blue_tmds = self.blue_link.encode_tmds(pixel.z);
-        self.clock.toggle();
-        for i in 0..10 {
-            self.red_link.set_bit((red_tmds >> i) & 1 == 1);
-            self.green_link.set_bit((green_tmds >> i) & 1 == 1);
-            self.blue_link.set_bit((blue_tmds >> i) & 1 == 1);
-            if i == 5 {
-                self.clock.toggle();
-            }
-        }
-    }
-
-    pub fn end_row(&mut self) {
-        // FRONT PORCH
-        for _ in 0..16 {
-            self.clock.toggle();
-        }
-
-        // SYNC PERIOD
-        for _ in 0..40 {
-            self.clock.toggle();
-            for i in 0..10 {
-                self.red_link.set_bit((HSYNC_PULSE >> i) & 1 == 1);
-                self.green_link.set_bit((HSYNC_PULSE >> i) & 1 == 1);
-                self.blue_link.set_bit((HSYNC_PULSE >> i) & 1 == 1);
-                if i == 5 {
-                    self.clock.toggle();
-                }
-            }
-        }
-
-        // BACK PORCH
-        for _ in 0..56 {
-            self.clock.toggle();
-        }
-    }
-
-    pub fn end_frame(&mut self) {
-        // FRONT PORCH
-        for _ in 0..3 {
-            self.end_line(false);
-            self.line_clock.wait();
-        }
-
-        // SYNC PERIOD
-        for _ in 0..4 {
-            self.end_line(true);
-            self.line_clock.wait();
-        }
-
-        // BACK PORCH
-        for _ in 0..9 {
-            self.end_line(false);
-            self.line_clock.wait();
-        }
-    }
-
     /// Fake line for vertical blanking
     fn end_line(&mut self, vsync: bool) {
         // Data period
@@ -215,3 +158,65 @@ impl<'a> DviInterface<'a> {
         }
     }
 }
+
+impl<'a> PixelSink for DviInterface<'a> {
+    fn render_pixel(&mut self, pixel: Vec3<u8>) {
+        let red_tmds = self.red_link.encode_tmds(pixel.x);
+        let green_tmds = self.green_link.encode_tmds(pixel.y);
+        let blue_tmds = self.blue_link.encode_tmds(pixel.z);
+        self.clock.toggle();
+        for i in 0..10 {
+            self.red_link.set_bit((red_tmds >> i) & 1 == 1);
+            self.green_link.set_bit((green_tmds >> i) & 1 == 1);
+            self.blue_link.set_bit((blue_tmds >> i) & 1 == 1);
+            if i == 5 {
+                self.clock.toggle();
+            }
+        }
+    }
+
+    fn end_row(&mut self) {
+        // FRONT PORCH
+        for _ in 0..16 {
+            self.clock.toggle();
+        }
+
+        // SYNC PERIOD
+        for _ in 0..40 {
+            self.clock.toggle();
+            for i in 0..10 {
+                self.red_link.set_bit((HSYNC_PULSE >> i) & 1 == 1);
+                self.green_link.set_bit((HSYNC_PULSE >> i) & 1 == 1);
+                self.blue_link.set_bit((HSYNC_PULSE >> i) & 1 == 1);
+                if i == 5 {
+                    self.clock.toggle();
+                }
+            }
+        }
+
+        // BACK PORCH
+        for _ in 0..56 {
+            self.clock.toggle();
+        }
+    }
+
+    fn end_frame(&mut self) {
+        // FRONT PORCH
+        for _ in 0..3 {
+            self.end_line(false);
+            self.line_clock.wait();
+        }
+
+        // SYNC PERIOD
+        for _ in 0..4 {
+            self.end_line(true);
+            self.line_clock.wait();
+        }
+
+        // BACK PORCH
+        for _ in 0..9 {
+            self.end_line(false);
+            self.line_clock.wait();
+        }
+    }
+}
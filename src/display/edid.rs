@@ -29,7 +29,6 @@ pub fn read_edid(buf: EdidBuffer) {
 
     if correct_edid[1] == 0 {
         error!("EDID signature not found");
-        return;
     } else {
         info!("Valid signature found: {:?}", correct_edid[0..8]);
         info!(
@@ -87,7 +86,7 @@ fn read_mfn(mf: u16) -> String<3> {
 
 /// Reads past the header for EDID v1.3
 fn read_103(buf: &EdidBuffer) {
-    read_display_params_103(&buf);
+    read_display_params_103(buf);
 
     if buf[0x23] & 0b100000 == 0b100000 {
         info!("640x480p60 supported!");
@@ -98,7 +97,7 @@ fn read_103(buf: &EdidBuffer) {
 
 /// Reads past the header for EDID v1.4
 fn read_104(buf: &EdidBuffer) {
-    read_display_params_104(&buf);
+    read_display_params_104(buf);
 }
 
 fn read_display_params_103(buf: &EdidBuffer) {
@@ -1,3 +1,5 @@
+#[cfg(feature = "esp32s3")]
 pub mod dvi;
 pub mod edid;
+pub mod sink;
 pub mod tmds;
@@ -0,0 +1,138 @@
+use heapless::Vec;
+
+use crate::types::vector::Vec3;
+
+/// A destination for pixels streamed out in scanline
+/// order: left to right, then top to bottom.
+pub trait PixelSink {
+    /// Outputs the next pixel of the current row.
+    fn render_pixel(&mut self, pixel: Vec3<u8>);
+
+    /// Finishes the current row and moves to the start of the next.
+    fn end_row(&mut self);
+
+    /// Finishes the current frame and moves back to the top-left pixel.
+    fn end_frame(&mut self);
+}
+
+/// Records streamed pixels into memory.
+/// Pixels outside of the `W`x`H` area are dropped.
+pub struct PixelRecorder<const W: usize, const H: usize>
+where
+    [(); W * H]:,
+{
+    buffer: Vec<Vec3<u8>, { W * H }>,
+    x: usize,
+    y: usize,
+    /// Number of frames that have been ended.
+    pub frames: usize,
+}
+
+impl<const W: usize, const H: usize> PixelRecorder<W, H>
+where
+    [(); W * H]:,
+{
+    /// Creates a new recorder with every pixel set to black.
+    pub fn new() -> PixelRecorder<W, H> {
+        let mut buffer = Vec::new();
+        buffer
+            .resize(W * H, Vec3 { x: 0, y: 0, z: 0 })
+            .expect("buffer capacity should be W * H");
+        PixelRecorder {
+            buffer,
+            x: 0,
+            y: 0,
+            frames: 0,
+        }
+    }
+
+    /// Gets the last pixel written at the given coordinates.
+    pub fn get(&self, x: usize, y: usize) -> Vec3<u8> {
+        *self
+            .buffer
+            .get(y * W + x)
+            .expect("coordinates specified should be within bounds of the recorder")
+    }
+}
+
+impl<const W: usize, const H: usize> Default for PixelRecorder<W, H>
+where
+    [(); W * H]:,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize> PixelSink for PixelRecorder<W, H>
+where
+    [(); W * H]:,
+{
+    fn render_pixel(&mut self, pixel: Vec3<u8>) {
+        if self.x < W && self.y < H {
+            self.buffer[self.y * W + self.x] = pixel;
+        }
+        self.x += 1;
+    }
+
+    fn end_row(&mut self) {
+        self.x = 0;
+        self.y += 1;
+    }
+
+    fn end_frame(&mut self) {
+        self.x = 0;
+        self.y = 0;
+        self.frames += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grey(value: u8) -> Vec3<u8> {
+        Vec3 {
+            x: value,
+            y: value,
+            z: value,
+        }
+    }
+
+    #[test]
+    fn records_pixels_in_scanline_order() {
+        let mut recorder = PixelRecorder::<3, 2>::new();
+        for y in 0..2 {
+            for x in 0..3 {
+                recorder.render_pixel(grey(y * 3 + x));
+            }
+            recorder.end_row();
+        }
+        recorder.end_frame();
+
+        for y in 0..2 {
+            for x in 0..3 {
+                assert_eq!(recorder.get(x, y).x as usize, y * 3 + x);
+            }
+        }
+        assert_eq!(recorder.frames, 1);
+    }
+
+    #[test]
+    fn drops_pixels_outside_and_restarts_each_frame() {
+        let mut recorder = PixelRecorder::<2, 1>::new();
+        for value in 1..=3 {
+            recorder.render_pixel(grey(value));
+        }
+        // A row past the bottom is dropped too
+        recorder.end_row();
+        recorder.render_pixel(grey(9));
+        recorder.end_frame();
+        assert_eq!([recorder.get(0, 0).x, recorder.get(1, 0).x], [1, 2]);
+
+        recorder.render_pixel(grey(7));
+        recorder.end_frame();
+        assert_eq!([recorder.get(0, 0).x, recorder.get(1, 0).x], [7, 2]);
+        assert_eq!(recorder.frames, 2);
+    }
+}
@@ -1,4 +1,4 @@
-use defmt::info;
+#[cfg(feature = "esp32s3")]
 use esp_hal::{
     gpio::{Level, Output, OutputConfig, OutputPin},
     peripheral::Peripheral,
@@ -6,12 +6,14 @@ use esp_hal::{
 
 /// Manages a single TMDS (transition-minimised
 /// differential signaling) pair.
+#[cfg(feature = "esp32s3")]
 pub struct TMDS<'a> {
     pin_nor: Output<'a>,
     pin_inv: Output<'a>,
-    prev_disparity: i32,
+    encoder: TmdsEncoder,
 }
 
+#[cfg(feature = "esp32s3")]
 impl<'a> TMDS<'a> {
     pub fn new(
         pin_nor: impl Peripheral<P = impl OutputPin> + 'a,
@@ -20,7 +22,7 @@ impl<'a> TMDS<'a> {
         TMDS {
             pin_nor: Output::new(pin_nor, Level::Low, OutputConfig::default()),
             pin_inv: Output::new(pin_inv, Level::Low, OutputConfig::default()),
-            prev_disparity: 0,
+            encoder: TmdsEncoder::new(),
         }
     }
 
@@ -34,6 +36,27 @@ impl<'a> TMDS<'a> {
         self.pin_inv.toggle();
     }
 
+    pub const fn encode_control_signal(c0: bool, c1: bool) -> u16 {
+        TmdsEncoder::encode_control_signal(c0, c1)
+    }
+
+    pub fn encode_tmds(&mut self, data: u8) -> u16 {
+        self.encoder.encode_tmds(data)
+    }
+}
+
+/// Hardware-independent TMDS symbol encoder.
+/// Tracks the running disparity of a single pair.
+#[derive(Default)]
+pub struct TmdsEncoder {
+    prev_disparity: i32,
+}
+
+impl TmdsEncoder {
+    pub const fn new() -> Self {
+        TmdsEncoder { prev_disparity: 0 }
+    }
+
     pub const fn encode_control_signal(c0: bool, c1: bool) -> u16 {
         match (c1, c0) {
             (false, false) => 0b0010101011,
@@ -45,28 +68,27 @@ impl<'a> TMDS<'a> {
 
     pub fn encode_tmds(&mut self, data: u8) -> u16 {
         let mut encoded: u8 = 0;
-        let using_xor: bool;
         let bit_flip_occurred: bool;
         let mut num_ones = count_bits(data);
 
         encoded |= data & 1;
 
         // we choose either xor or xnor based on which one will give the fewest transitions
-        if num_ones > 4 || (num_ones == 4 && (data & 0b10000000) >> 7 == 0) {
+        let using_xor = if num_ones > 4 || (num_ones == 4 && (data & 0b10000000) >> 7 == 0) {
             // use xnor
             // out[1] = out[0] xnor in[1]
             for i in 1..8 {
                 encoded |= !(get_bit(encoded, i - 1) ^ get_bit(data, i));
             }
-            using_xor = false;
+            false
         } else {
             // use xor
             // out[1] = out[0] xor in[1]
             for i in 1..8 {
                 encoded |= get_bit(encoded, i - 1) ^ get_bit(data, i);
             }
-            using_xor = true;
-        }
+            true
+        };
 
         num_ones = count_bits(encoded);
 
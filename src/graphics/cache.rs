@@ -0,0 +1,115 @@
+use heapless::Vec;
+
+use crate::{
+    graphics::shader::{ShadedVertex, Varyings, VertexShader},
+    model::mesh::Mesh,
+};
+
+/// Holds the output of the vertex shader for every vertex of a
+/// mesh, so shared vertices are transformed once per draw rather
+/// than once per face. Kept apart from the mesh, which is never
+/// modified, and reused between frames to avoid reallocating.
+pub struct VertexCache<V: Varyings, const N: usize> {
+    verts: Vec<ShadedVertex<V>, N>,
+}
+
+impl<V: Varyings, const N: usize> VertexCache<V, N> {
+    /// Creates an empty cache with room for `N` vertices.
+    pub const fn new() -> VertexCache<V, N> {
+        VertexCache { verts: Vec::new() }
+    }
+
+    /// Runs the vertex shader over every vertex of `mesh`,
+    /// replacing the previous contents.
+    pub fn fill<M, U, S>(&mut self, mesh: &M, uniforms: &U, shader: &S)
+    where
+        M: Mesh + ?Sized,
+        S: VertexShader<U, Varyings = V>,
+    {
+        defmt::assert!(
+            mesh.vertex_count() <= N,
+            "mesh should fit in the vertex cache"
+        );
+        self.verts.clear();
+        for i in 0..mesh.vertex_count() {
+            let _ = self.verts.push(shader.vertex(uniforms, &mesh.vertex(i)));
+        }
+    }
+
+    /// Returns the shaded vertex at `index`.
+    pub fn get(&self, index: usize) -> &ShadedVertex<V> {
+        self.verts
+            .get(index)
+            .expect("face should have a valid vertex index")
+    }
+
+    pub fn as_slice(&self) -> &[ShadedVertex<V>] {
+        &self.verts
+    }
+}
+
+impl<V: Varyings, const N: usize> Default for VertexCache<V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::model::{from_obj, Vertex};
+    use core::cell::Cell;
+
+    /// Passes each vertex's x through, counting how often it runs.
+    struct Counting {
+        runs: Cell<usize>,
+    }
+
+    impl VertexShader<()> for Counting {
+        type Varyings = f32;
+
+        fn vertex(&self, _uniforms: &(), vertex: &Vertex) -> ShadedVertex<f32> {
+            self.runs.set(self.runs.get() + 1);
+            ShadedVertex {
+                pos: vertex.pos,
+                varyings: vertex.pos.x,
+            }
+        }
+    }
+
+    /// A square split into two triangles sharing a diagonal.
+    const SQUARE: &str = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3 4";
+
+    #[test]
+    fn shared_vertices_are_shaded_once_per_fill() {
+        let square = from_obj::<4, 2>(SQUARE).unwrap();
+        let shader = Counting { runs: Cell::new(0) };
+        let mut cache = VertexCache::<f32, 4>::new();
+
+        // Six corners, but only four vertices
+        cache.fill(&square, &(), &shader);
+        assert_eq!(shader.runs.get(), 4);
+        for face in &square.faces {
+            for i in face.verts {
+                let vertex = cache.get(i as usize);
+                assert_eq!(vertex.varyings, square.verts[i as usize].pos.x);
+            }
+        }
+
+        // Refilling replaces the previous mesh's vertices
+        let triangle = from_obj::<3, 1>("v 5 0 0\nv 6 0 0\nv 5 1 0\nf 1 2 3").unwrap();
+        cache.fill(&triangle, &(), &shader);
+        assert_eq!(shader.runs.get(), 7);
+        let xs: [f32; 3] = core::array::from_fn(|i| cache.as_slice()[i].varyings);
+        assert_eq!(cache.as_slice().len(), 3);
+        assert_eq!(xs, [5.0, 6.0, 5.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn meshes_larger_than_the_cache_are_rejected() {
+        let square = from_obj::<4, 2>(SQUARE).unwrap();
+        let shader = Counting { runs: Cell::new(0) };
+        VertexCache::<f32, 3>::new().fill(&square, &(), &shader);
+    }
+}
@@ -1,30 +1,30 @@
 use core::f32::consts::PI;
 use defmt::info;
-use heapless::Vec;
 
 use crate::{
-    display::dvi::DviInterface,
-    graphics::texture::Texture,
-    math::powi,
-    model::model::{self, Model, Vertex},
+    display::sink::PixelSink,
+    graphics::{
+        cache::VertexCache,
+        clip::clip_triangle,
+        cull::{CullMode, FrontFace},
+        depth::{DepthFunc, DEPTH_CLEAR},
+        light::Light,
+        raster::{bary_steps, coverage, edge, Interpolation, RasterMode, TriangleRaster},
+        scene::Scene,
+        shader::{
+            Derivatives, FragmentShader, ShadedVertex, StandardUniforms, Varyings, VertexShader,
+        },
+        texture::{sampler::Sampler, Image},
+        viewport::FrameBuffer,
+    },
+    model::{material::Material, mesh::Mesh, model::Face},
     types::{
         angle::tan,
         matrix::Mat4,
-        vector::{Vec3, Vec4, VEC3_Z, VEC3_ZERO},
+        vector::{Vec2, Vec3, VEC3_ZERO},
     },
 };
 
-const LIGHT_POS: Vec3<f32> = Vec3 {
-    x: 1.5,
-    y: 0.0,
-    z: -1.5,
-};
-const LIGHT_COL: Vec3<f32> = Vec3 {
-    x: 1.0,
-    y: 1.0,
-    z: 1.0,
-};
-
 /// A virtual camera for rendering
 pub struct Camera<const W: usize, const H: usize>
 where
@@ -38,14 +38,22 @@ where
     pub near: f32,
     pub far: f32,
     pub fov_y: f32,
-    texture: Texture<16, 16>, //pub fbo: FrameBuffer<Vec3<u8>, W, H>,
+    /// NDC depth of the nearest fragment drawn at each pixel.
+    pub depth: FrameBuffer<f32, W, H>,
+    pub depth_func: DepthFunc,
+    pub raster_mode: RasterMode,
+    pub cull_mode: CullMode,
+    pub front_face: FrontFace,
+    /// How vertex attributes are interpolated across faces.
+    pub interpolation: Interpolation,
+    pub fbo: FrameBuffer<Vec3<u8>, W, H>,
 }
 
 impl<const W: usize, const H: usize> Camera<W, H>
 where
     [(); W * H]:,
 {
-    pub fn render_test(&mut self, output: &mut DviInterface) {
+    pub fn render_test(&mut self, output: &mut impl PixelSink) {
         for y in 0..H {
             for x in 0..W {
                 output.render_pixel(Vec3 {
@@ -59,144 +67,368 @@ where
         output.end_frame();
     }
 
-    pub fn render(
-        &mut self,
-        object: &Model,
+    /// Resets every pixel of the depth buffer to the far plane.
+    pub fn clear_depth(&mut self) {
+        self.depth.clear(DEPTH_CLEAR);
+    }
+
+    /// Creates uniforms for the built-in shaders, lit by `lights`,
+    /// for a model placed with `model_transform`.
+    pub fn uniforms<'a>(
+        &self,
         model_transform: Mat4<f32>,
-        output: &mut DviInterface,
-    ) {
-        let mut local_model: Model = object.clone();
-        let mut pd_verts: Vec<Vec3<f32>, { model::NUM_VERTS }> = Vec::new(); // Transformed verts after perspective division
+        lights: &'a [Light],
+        texture: &'a dyn Image,
+    ) -> StandardUniforms<'a> {
+        StandardUniforms {
+            model: model_transform,
+            view_proj: self.proj * self.view,
+            camera_pos: self.pos,
+            lights,
+            texture,
+            material_textures: &[],
+            sampler: Sampler::default(),
+        }
+    }
 
+    /// Renders a mesh with the given shader and streams the frame to `output`.
+    /// `cache` receives the shaded vertices, and must hold every vertex of the mesh.
+    pub fn render<M, U, S, const N: usize>(
+        &mut self,
+        object: &M,
+        uniforms: &U,
+        shader: &S,
+        cache: &mut VertexCache<S::Varyings, N>,
+        output: &mut impl PixelSink,
+    ) where
+        M: Mesh + ?Sized,
+        S: VertexShader<U> + FragmentShader<U, <S as VertexShader<U>>::Varyings>,
+    {
+        self.begin_frame();
+        self.draw_mesh(object, None, uniforms, shader, cache);
+        self.end_frame(output);
+    }
+
+    /// Renders every node of `scene` that has a mesh and streams the
+    /// frame to `output`. World matrices are taken from the last
+    /// [`Scene::update`], and nodes with a texture draw it in place of
+    /// the one in `uniforms`. `cache` is reused for each node, and
+    /// must hold every vertex of the largest mesh.
+    pub fn render_scene<'a, S, const N: usize>(
+        &mut self,
+        scene: &Scene<'a>,
+        uniforms: &StandardUniforms<'a>,
+        shader: &S,
+        cache: &mut VertexCache<S::Varyings, N>,
+        output: &mut impl PixelSink,
+    ) where
+        S: VertexShader<StandardUniforms<'a>>
+            + FragmentShader<
+                StandardUniforms<'a>,
+                <S as VertexShader<StandardUniforms<'a>>>::Varyings,
+            >,
+    {
+        self.begin_frame();
+        for node in scene.nodes() {
+            if let Some(mesh) = node.mesh {
+                let uniforms = StandardUniforms {
+                    texture: node.texture.unwrap_or(uniforms.texture),
+                    ..*uniforms
+                };
+                self.draw(mesh, node.world(), node.material, &uniforms, shader, cache);
+            }
+        }
+        self.end_frame(output);
+    }
+
+    /// Clears the colour and depth buffers, ready for a new frame
+    /// to be built up with [`Camera::draw`].
+    pub fn begin_frame(&mut self) {
+        self.clear_depth();
+        self.fbo.clear(VEC3_ZERO.to_8bit_colour());
+    }
+
+    /// Draws a mesh placed with `transform` into the current frame,
+    /// depth tested against everything drawn since [`Camera::begin_frame`].
+    /// `material`, if given, replaces the materials of every face.
+    ///
+    /// `uniforms` holds the rest of the per-frame state; its model
+    /// matrix is ignored. To bind a texture to just this mesh, pass
+    /// a copy with a different `texture`. `cache` must hold every
+    /// vertex of the mesh.
+    pub fn draw<'a, M, S, const N: usize>(
+        &mut self,
+        object: &M,
+        transform: Mat4<f32>,
+        material: Option<&Material>,
+        uniforms: &StandardUniforms<'a>,
+        shader: &S,
+        cache: &mut VertexCache<S::Varyings, N>,
+    ) where
+        M: Mesh + ?Sized,
+        S: VertexShader<StandardUniforms<'a>>
+            + FragmentShader<
+                StandardUniforms<'a>,
+                <S as VertexShader<StandardUniforms<'a>>>::Varyings,
+            >,
+    {
+        let uniforms = StandardUniforms {
+            model: transform,
+            ..*uniforms
+        };
+        self.draw_mesh(object, material, &uniforms, shader, cache);
+    }
+
+    /// Streams the finished frame to `output`.
+    pub fn end_frame(&self, output: &mut impl PixelSink) {
+        self.fbo.scan_out(output);
+    }
+
+    /// Draws a mesh into the colour and depth buffers
+    /// without clearing them first.
+    fn draw_mesh<M, U, S, const N: usize>(
+        &mut self,
+        object: &M,
+        material: Option<&Material>,
+        uniforms: &U,
+        shader: &S,
+        cache: &mut VertexCache<S::Varyings, N>,
+    ) where
+        M: Mesh + ?Sized,
+        S: VertexShader<U> + FragmentShader<U, <S as VertexShader<U>>::Varyings>,
+    {
         // Vertex shader
-        local_model.verts.iter_mut().for_each(|vertex| -> () {
-            info!("Initial: {:?}", vertex.pos);
-            vertex.pos = model_transform * vertex.pos;
-            info!("Model: {:?}", vertex.pos);
-            vertex.pos = self.view * vertex.pos;
-            info!("View: {:?}", vertex.pos);
-            vertex.pos = self.proj * vertex.pos;
-            info!("Projection: {:?}", vertex.pos);
-            info!("Perspective division: {:?}", vertex.pos.perspective_division());
-            pd_verts
-                .push(vertex.pos.perspective_division())
-                .expect("vertex vectors should be the same length");
-        });
+        cache.fill(object, uniforms, shader);
 
         // Rasterisation and rendering
+        match self.raster_mode {
+            RasterMode::RayCast => self.rasterise_rays(object, material, cache, uniforms, shader),
+            RasterMode::EdgeFunction => {
+                self.rasterise_triangles(object, material, cache.as_slice(), uniforms, shader)
+            }
+        }
+    }
+
+    /// Fills the colour and depth buffers by casting a ray
+    /// through the centre of each pixel against every face.
+    fn rasterise_rays<M, U, S, const N: usize>(
+        &mut self,
+        object: &M,
+        material: Option<&Material>,
+        cache: &VertexCache<S::Varyings, N>,
+        uniforms: &U,
+        shader: &S,
+    ) where
+        M: Mesh + ?Sized,
+        S: VertexShader<U> + FragmentShader<U, <S as VertexShader<U>>::Varyings>,
+    {
+        // Rays are cast through NDC, dividing each face's vertices as
+        // it's tested so no per-vertex buffer is needed on the stack.
+        // Faces with a vertex behind the camera are skipped rather
+        // than clipped.
         for y in 0..H {
             for x in 0..W {
-                let x_ndc = (x as f32 / W as f32 - 0.5) * 2.0;
-                let y_ndc = (y as f32 / H as f32 - 0.5) * 2.0;
+                // The nearest face so far and the ray's weights on it
+                let mut hit: Option<(Face, [f32; 3])> = None;
+                let mut depth = self.depth.get(x, y);
 
-                let ray_origin = Vec3 {
-                    x: x_ndc,
-                    y: y_ndc,
-                    z: -1.0,
-                };
-                let ray_direction = VEC3_Z;
+                for face in (0..object.face_count()).map(|i| object.face(i)) {
+                    let [vert_a, vert_b, vert_c] = face.indices().map(|i| cache.get(i));
+                    if vert_a.pos.w <= 0.0 || vert_b.pos.w <= 0.0 || vert_c.pos.w <= 0.0 {
+                        continue;
+                    }
+
+                    // The ray runs along +z in NDC, so it hits the face
+                    // where the face covers the pixel centre on screen.
+                    // Testing that with the edge-function fill rule
+                    // keeps both paths in step on shared edges.
+                    let [a, b, c] = [vert_a, vert_b, vert_c].map(|v| v.pos.perspective_division());
+                    let [sa, sb, sc] = [a, b, c].map(Self::to_screen);
+                    let Some(bary) = coverage(sa, sb, sc, x, y) else {
+                        continue;
+                    };
 
-                let mut vertex: Option<Vertex> = None;
-                let fragment_colour: Vec3<u8>;
+                    // Face culling
+                    let area = edge(a.to_vec2(), b.to_vec2(), c.to_vec2());
+                    if self.cull_mode.culls(self.front_face, area) {
+                        continue;
+                    }
 
-                // Vertex attribute interpolation
-                for face in &local_model.faces {
-                    let intersection_loc =
-                        face.ray_intersects_face(&local_model, ray_origin, ray_direction);
-
-                    if intersection_loc.is_some()
-                        //&& face.ray_front_face(&local_model, ray_direction)
-                    {
-                        let p = intersection_loc.expect("there should be a value");
-                        let vert_a = local_model
-                            .verts
-                            .get(face.verts[0])
-                            .expect("there should be a vertex specified by the face");
-                        let vert_b = local_model
-                            .verts
-                            .get(face.verts[1])
-                            .expect("there should be a vertex specified by the face");
-                        let vert_c = local_model
-                            .verts
-                            .get(face.verts[2])
-                            .expect("there should be a vertex specified by the face");
-
-                        let a = vert_a.pos.to_vec3();
-                        let b = vert_b.pos.to_vec3();
-                        let c = vert_c.pos.to_vec3();
-
-                        let pbc_area = 0.5 * Vec3::len(&Vec3::cross(b - p, c - p));
-                        let pac_area = 0.5 * Vec3::len(&Vec3::cross(a - p, c - p));
-                        let pab_area = 0.5 * Vec3::len(&Vec3::cross(a - p, b - p));
-                        let tot_area = pbc_area + pac_area + pab_area;
-
-                        let w_a = pbc_area / tot_area;
-                        let w_b = pac_area / tot_area;
-                        let w_c = pab_area / tot_area;
-
-                        vertex = Some(Vertex {
-                            pos: Vec4::of(p, 1.0),
-                            tex_coords: vert_a.tex_coords * w_a
-                                + vert_b.tex_coords * w_b
-                                + vert_c.tex_coords * w_c,
-                            normal: vert_a.normal * w_a + vert_b.normal * w_b + vert_c.normal * w_c,
-                        })
+                    // Depth test
+                    let z = a.z * bary[0] + b.z * bary[1] + c.z * bary[2];
+                    if !self.depth_func.test(z, depth) {
+                        continue;
                     }
+                    depth = z;
+                    hit = Some((face, bary));
                 }
 
-                // Vertex shader
-                if vertex.is_some() {
-                    let vertex = vertex.expect("vertex should exist");
-                    // Blinn Phong shading
+                self.depth.set(x, y, depth);
 
-                    // Config
-                    let ambient_colour = Vec3::<f32> {
-                        x: 1.0,
-                        y: 1.0,
-                        z: 1.0,
-                    };
-                    let ambient_factor: f32 = 0.1;
-                    let specular_colour = Vec3::<f32> {
-                        x: 0.1,
-                        y: 0.1,
-                        z: 0.1,
-                    };
-                    let specular_factor: f32 = 0.25;
-                    let diffuse_factor: f32 = 0.65;
-                    let shininess: u8 = 32;
-
-                    // general calc
-                    let light_dir = *(vertex.pos.to_vec3() - LIGHT_POS).nor();
-
-                    // Ambient
-                    let ambient = ambient_colour * ambient_factor;
-
-                    // Diffuse
-                    let diffuse_colour: Vec3<f32> = self
-                        .texture
-                        .sample(vertex.tex_coords)
-                        .expect("texture should have pixel for coordinates")
-                        .to_float_colour();
-                    let lambertian = f32::max(Vec3::dot(light_dir, vertex.normal.to_vec3()), 0.0);
-                    let diffuse = diffuse_colour * LIGHT_COL * lambertian * diffuse_factor;
-
-                    // Specular
-                    let half_dir = *(light_dir + -*(vertex.pos.to_vec3()).nor()).nor();
-                    let view_angle = f32::max(Vec3::dot(half_dir, vertex.normal.to_vec3()), 0.0);
-                    let specular = specular_colour * powi(view_angle, shininess) * specular_factor;
-
-                    fragment_colour = (ambient + specular + diffuse).to_8bit_colour();
-                    // fragment_colour = VEC3_Z.to_8bit_colour();
-                } else {
-                    fragment_colour = VEC3_ZERO.to_8bit_colour();
-                }
+                let Some((face, bary)) = hit else {
+                    continue;
+                };
 
-                output.render_pixel(fragment_colour);
+                // Vertex attribute interpolation
+                let [vert_a, vert_b, vert_c] = face.indices().map(|i| cache.get(i));
+                let tri = [&vert_a.varyings, &vert_b.varyings, &vert_c.varyings];
+                let clip_w = [vert_a.pos.w, vert_b.pos.w, vert_c.pos.w];
+                let varyings = S::Varyings::interpolate(
+                    tri[0],
+                    tri[1],
+                    tri[2],
+                    self.interpolation.weights(bary, clip_w),
+                );
+
+                let [a, b, c] =
+                    [vert_a, vert_b, vert_c].map(|v| Self::to_screen(v.pos.perspective_division()));
+                let (bary_dx, bary_dy) = bary_steps(a, b, c);
+                let derivatives = self.derivatives(tri, bary, bary_dx, bary_dy, clip_w);
+
+                // Fragment shader
+                let material = material.or_else(|| object.material(&face));
+                let fragment_colour = shader
+                    .fragment(uniforms, material, &varyings, &derivatives)
+                    .to_8bit_colour();
+                self.fbo.set(x, y, fragment_colour);
             }
+        }
+    }
 
-            output.end_row();
+    /// Fills the colour and depth buffers by clipping each face
+    /// to the view frustum, then walking the screen-space bounding
+    /// box of the resulting triangles with edge functions.
+    fn rasterise_triangles<M, U, S>(
+        &mut self,
+        object: &M,
+        material: Option<&Material>,
+        shaded_verts: &[ShadedVertex<S::Varyings>],
+        uniforms: &U,
+        shader: &S,
+    ) where
+        M: Mesh + ?Sized,
+        S: VertexShader<U> + FragmentShader<U, <S as VertexShader<U>>::Varyings>,
+    {
+        for face in (0..object.face_count()).map(|i| object.face(i)) {
+            let tri = face.indices().map(|i| {
+                *shaded_verts
+                    .get(i)
+                    .expect("there should be a vertex specified by the face")
+            });
+
+            // Clipping produces a convex polygon, drawn as a fan
+            let polygon = clip_triangle(tri);
+            if polygon.len() < 3 {
+                continue;
+            }
+
+            // Face culling, using the signed area of the clipped
+            // polygon as the original may cross behind the camera
+            let ndc = |v: &ShadedVertex<S::Varyings>| v.pos.perspective_division().to_vec2();
+            let area = (2..polygon.len())
+                .map(|i| edge(ndc(&polygon[0]), ndc(&polygon[i - 1]), ndc(&polygon[i])))
+                .sum::<f32>();
+            if self.cull_mode.culls(self.front_face, area) {
+                continue;
+            }
+
+            let material = material.or_else(|| object.material(&face));
+            for i in 2..polygon.len() {
+                self.rasterise_triangle(
+                    [polygon[0], polygon[i - 1], polygon[i]],
+                    material,
+                    uniforms,
+                    shader,
+                );
+            }
+        }
+    }
+
+    /// Rasterises a single triangle with clip-space positions
+    /// that lies entirely within the view frustum.
+    fn rasterise_triangle<U, S>(
+        &mut self,
+        [vert_a, vert_b, vert_c]: [ShadedVertex<S::Varyings>; 3],
+        material: Option<&Material>,
+        uniforms: &U,
+        shader: &S,
+    ) where
+        S: VertexShader<U> + FragmentShader<U, <S as VertexShader<U>>::Varyings>,
+    {
+        let a = vert_a.pos.perspective_division();
+        let b = vert_b.pos.perspective_division();
+        let c = vert_c.pos.perspective_division();
+        let clip_w = [vert_a.pos.w, vert_b.pos.w, vert_c.pos.w];
+
+        let [sa, sb, sc] = [a, b, c].map(Self::to_screen);
+        for fragment in TriangleRaster::new(sa, sb, sc, W, H) {
+            // NDC depth is already linear in screen space
+            let [s_a, s_b, s_c] = fragment.bary;
+            let depth = a.z * s_a + b.z * s_b + c.z * s_c;
+
+            // Depth test
+            if !self
+                .depth_func
+                .test(depth, self.depth.get(fragment.x, fragment.y))
+            {
+                continue;
+            }
+            self.depth.set(fragment.x, fragment.y, depth);
+
+            // Vertex attribute interpolation
+            let tri = [&vert_a.varyings, &vert_b.varyings, &vert_c.varyings];
+            let varyings = S::Varyings::interpolate(
+                tri[0],
+                tri[1],
+                tri[2],
+                self.interpolation.weights(fragment.bary, clip_w),
+            );
+            let derivatives = self.derivatives(
+                tri,
+                fragment.bary,
+                fragment.bary_dx,
+                fragment.bary_dy,
+                clip_w,
+            );
+
+            // Fragment shader
+            let fragment_colour = shader
+                .fragment(uniforms, material, &varyings, &derivatives)
+                .to_8bit_colour();
+            self.fbo.set(fragment.x, fragment.y, fragment_colour);
+        }
+    }
+
+    /// Converts NDC to pixel coordinates. Rows run top
+    /// to bottom, so NDC +y is flipped.
+    fn to_screen(v: Vec3<f32>) -> Vec2<f32> {
+        Vec2 {
+            x: (v.x / 2.0 + 0.5) * W as f32,
+            y: (0.5 - v.y / 2.0) * H as f32,
+        }
+    }
+
+    /// Finds how the varyings of a triangle change one pixel right and
+    /// one pixel down from the screen-space barycentric weights `bary`.
+    fn derivatives<V: Varyings>(
+        &self,
+        [a, b, c]: [&V; 3],
+        bary: [f32; 3],
+        bary_dx: [f32; 3],
+        bary_dy: [f32; 3],
+        clip_w: [f32; 3],
+    ) -> Derivatives<V> {
+        let here = self.interpolation.weights(bary, clip_w);
+        let change = |step: [f32; 3]| {
+            let there = self
+                .interpolation
+                .weights(core::array::from_fn(|i| bary[i] + step[i]), clip_w);
+            V::interpolate(a, b, c, core::array::from_fn(|i| there[i] - here[i]))
+        };
+        Derivatives {
+            dx: change(bary_dx),
+            dy: change(bary_dy),
         }
-        output.end_frame();
     }
 
     /// Creates a new perspective camera. `fov_h` is measured in degrees
@@ -219,17 +451,22 @@ where
             fov_y: fov_h,
             near,
             far,
-            // fbo: FrameBuffer::<Vec3<u8>, W, H>::new(),
+            fbo: FrameBuffer::<Vec3<u8>, W, H>::new(VEC3_ZERO.to_8bit_colour()),
+            depth: FrameBuffer::<f32, W, H>::new(DEPTH_CLEAR),
+            depth_func: DepthFunc::Less,
+            raster_mode: RasterMode::EdgeFunction,
+            cull_mode: CullMode::Back,
+            front_face: FrontFace::Ccw,
+            interpolation: Interpolation::Perspective,
             proj,
             view,
-            texture: Texture::<16, 16>::gen_checkerboard(),
         }
     }
 
     /// Creates a projection matrix
     pub fn projection(near: f32, far: f32, fov_h: f32, aspect: f32) -> Mat4<f32> {
         let l_fd = 1.0 / tan((fov_h * (PI / 180.0)) / 2.0);
-        let l_a1 = (-(near-far))/(near-far);
+        let l_a1 = (far + near) / (near - far);
         let l_a2 = (2.0 * far * near) / (near - far);
         Mat4 {
             v_00: l_fd / aspect,
@@ -246,7 +483,8 @@ where
             v_23: l_a2,
             v_30: 0.0,
             v_31: 0.0,
-            v_32: 1.0,
+            // The camera looks down -z, so w is the distance in front of it
+            v_32: -1.0,
             v_33: 0.0,
         }
     }
@@ -257,15 +495,218 @@ where
         let right = *Vec3::cross(up, forward).nor();
         let new_up = Vec3::cross(forward, right);
         Mat4::<f32> {
-            v_00: right.x, v_01: new_up.x, v_02: forward.x, v_03: 0.0,
-            v_10: right.y, v_11: new_up.y, v_12: forward.y, v_13: 0.0,
-            v_20: right.z, v_21: new_up.z, v_22: forward.z, v_23: 0.0,
-            v_30: 0.0    , v_31: 0.0     , v_32: 0.0      , v_33: 1.0
+            v_00: right.x,
+            v_01: right.y,
+            v_02: right.z,
+            v_03: 0.0,
+            v_10: new_up.x,
+            v_11: new_up.y,
+            v_12: new_up.z,
+            v_13: 0.0,
+            v_20: forward.x,
+            v_21: forward.y,
+            v_22: forward.z,
+            v_23: 0.0,
+            v_30: 0.0,
+            v_31: 0.0,
+            v_32: 0.0,
+            v_33: 1.0,
         } * Mat4::<f32> {
-            v_00: 1.0, v_01: 0.0, v_02: 0.0, v_03: 0.0,
-            v_10: 0.0, v_11: 1.0, v_12: 0.0, v_13: 0.0,
-            v_20: 0.0, v_21: 0.0, v_22: 1.0, v_23: 0.0,
-            v_30: -eye.x, v_31: -eye.y, v_32: -eye.z, v_33: 1.0,
+            v_00: 1.0,
+            v_01: 0.0,
+            v_02: 0.0,
+            v_03: -eye.x,
+            v_10: 0.0,
+            v_11: 1.0,
+            v_12: 0.0,
+            v_13: -eye.y,
+            v_20: 0.0,
+            v_21: 0.0,
+            v_22: 1.0,
+            v_23: -eye.z,
+            v_30: 0.0,
+            v_31: 0.0,
+            v_32: 0.0,
+            v_33: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        display::sink::PixelRecorder,
+        graphics::{
+            shader::unlit::UnlitTexture,
+            texture::{procedural::WHITE, Texture},
+        },
+        model::model::from_obj,
+        types::vector::{VEC3_Y, VEC3_Z},
+    };
+
+    /// A triangle from world y 1 to 2, facing +z.
+    const TRIANGLE: &str = "
+v -1 1 0
+v 1 1 0
+v 0 2 0
+vt 0 0
+vn 0 0 1
+f 1/1/1 2/1/1 3/1/1
+";
+
+    /// Renders `model` in white, seen from `eye`.
+    fn render_white(model: &impl Mesh, eye: Vec3<f32>, mode: RasterMode) -> PixelRecorder<80, 60> {
+        let mut cam = Camera::<80, 60>::perspective(90.0, eye, -eye, VEC3_Y, 0.1, 50.0);
+        cam.raster_mode = mode;
+        let texture = Texture::<1, 1>::from_fn(|_, _| WHITE);
+        let uniforms = cam.uniforms(Mat4::idt(), &[], &texture);
+        let mut output = PixelRecorder::<80, 60>::new();
+        cam.render(
+            model,
+            &uniforms,
+            &UnlitTexture,
+            &mut VertexCache::<_, 24>::new(),
+            &mut output,
+        );
+        output
+    }
+
+    fn lit(output: &PixelRecorder<80, 60>, x: usize, y: usize) -> bool {
+        output.get(x, y).x > 0
+    }
+
+    #[test]
+    fn positive_y_is_drawn_at_the_top() {
+        let model = from_obj::<3, 1>(TRIANGLE).unwrap();
+        for mode in [RasterMode::RayCast, RasterMode::EdgeFunction] {
+            let output = render_white(&model, VEC3_Z * 5.0, mode);
+            let lit_row = |y| (0..80).any(|x| lit(&output, x, y));
+            let first = (0..60)
+                .find(|&y| lit_row(y))
+                .expect("the triangle should be drawn");
+            let last = (0..60).rev().find(|&y| lit_row(y)).unwrap();
+            // World y 1 and 2 project to NDC y 0.2 and 0.4, rows 24 and 18
+            assert!(
+                first >= 18 && last <= 24,
+                "{:?}: rows {}..={}",
+                mode,
+                first,
+                last
+            );
+        }
+    }
+
+    #[test]
+    fn raster_modes_cover_the_same_pixels() {
+        let model = from_obj::<24, 12>(crate::model::CUBE_OBJ).unwrap();
+        let eye = Vec3 {
+            x: 3.0,
+            y: 2.0,
+            z: 4.0,
+        };
+        let rays = render_white(&model, eye, RasterMode::RayCast);
+        let edges = render_white(&model, eye, RasterMode::EdgeFunction);
+        for y in 0..60 {
+            for x in 0..80 {
+                assert_eq!(lit(&rays, x, y), lit(&edges, x, y), "pixel ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn draws_in_one_frame_are_depth_tested_against_each_other() {
+        let model = from_obj::<3, 1>(TRIANGLE).unwrap();
+        let mut cam = Camera::<80, 60>::perspective(90.0, VEC3_Z * 5.0, -VEC3_Z, VEC3_Y, 0.1, 50.0);
+        let red = Texture::<1, 1>::from_fn(|_, _| Vec3 { x: 255, y: 0, z: 0 });
+        let green = Texture::<1, 1>::from_fn(|_, _| Vec3 { x: 0, y: 255, z: 0 });
+        let near = Mat4::translate(VEC3_Z);
+        let mut cache = VertexCache::<_, 24>::new();
+
+        // Pixel (40, 21) is covered by the triangle at z 0 and at z 1
+        for near_first in [false, true] {
+            cam.begin_frame();
+            let order = if near_first {
+                [(near, &red), (Mat4::idt(), &green)]
+            } else {
+                [(Mat4::idt(), &green), (near, &red)]
+            };
+            for (transform, texture) in order {
+                let uniforms = cam.uniforms(Mat4::idt(), &[], texture);
+                cam.draw(
+                    &model,
+                    transform,
+                    None,
+                    &uniforms,
+                    &UnlitTexture,
+                    &mut cache,
+                );
+            }
+            let pixel = cam.fbo.get(40, 21);
+            assert_eq!(
+                (pixel.x, pixel.y),
+                (255, 0),
+                "near drawn first: {}",
+                near_first
+            );
+        }
+
+        // Nothing drawn last frame is left to cover the next
+        cam.begin_frame();
+        for y in 0..60 {
+            for x in 0..80 {
+                let pixel = cam.fbo.get(x, y);
+                assert_eq!((pixel.x, pixel.y, pixel.z), (0, 0, 0));
+                assert_eq!(cam.depth.get(x, y), DEPTH_CLEAR);
+            }
+        }
+        let uniforms = cam.uniforms(Mat4::idt(), &[], &green);
+        cam.draw(
+            &model,
+            Mat4::idt(),
+            None,
+            &uniforms,
+            &UnlitTexture,
+            &mut cache,
+        );
+        assert_eq!(cam.fbo.get(40, 21).y, 255);
+    }
+
+    #[test]
+    fn culling_uses_the_winding_seen_on_screen() {
+        // The triangle is counter-clockwise from the front
+        let model = from_obj::<3, 1>(TRIANGLE).unwrap();
+        let texture = Texture::<1, 1>::from_fn(|_, _| WHITE);
+        for raster_mode in [RasterMode::RayCast, RasterMode::EdgeFunction] {
+            for (eye, cull_mode, front_face, drawn) in [
+                (VEC3_Z, CullMode::Back, FrontFace::Ccw, true),
+                (-VEC3_Z, CullMode::Back, FrontFace::Ccw, false),
+                (VEC3_Z, CullMode::Back, FrontFace::Cw, false),
+                (VEC3_Z, CullMode::Front, FrontFace::Ccw, false),
+                (-VEC3_Z, CullMode::Front, FrontFace::Ccw, true),
+                (-VEC3_Z, CullMode::None, FrontFace::Cw, true),
+            ] {
+                let eye = eye * 5.0;
+                let mut cam = Camera::<80, 60>::perspective(90.0, eye, -eye, VEC3_Y, 0.1, 50.0);
+                cam.raster_mode = raster_mode;
+                cam.cull_mode = cull_mode;
+                cam.front_face = front_face;
+                let uniforms = cam.uniforms(Mat4::idt(), &[], &texture);
+                let mut output = PixelRecorder::<80, 60>::new();
+                cam.render(
+                    &model,
+                    &uniforms,
+                    &UnlitTexture,
+                    &mut VertexCache::<_, 24>::new(),
+                    &mut output,
+                );
+                let any_lit = (0..60).any(|y| (0..80).any(|x| lit(&output, x, y)));
+                assert_eq!(
+                    any_lit, drawn,
+                    "{:?} from z {} with {:?} culling, {:?} front faces",
+                    raster_mode, eye.z, cull_mode, front_face
+                );
+            }
         }
     }
 }
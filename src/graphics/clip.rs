@@ -0,0 +1,155 @@
+use defmt::Format;
+use heapless::Vec;
+
+use crate::{
+    graphics::shader::{ShadedVertex, Varyings},
+    types::vector::Vec4,
+};
+
+/// Most vertices a triangle can have after clipping.
+/// Each of the six planes adds at most one vertex.
+pub const MAX_CLIPPED_VERTS: usize = 3 + 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// A plane of the view frustum in clip space.
+pub enum ClipPlane {
+    Left,
+    Right,
+    Bottom,
+    Top,
+    Near,
+    Far,
+}
+
+impl ClipPlane {
+    pub const ALL: [ClipPlane; 6] = [
+        ClipPlane::Left,
+        ClipPlane::Right,
+        ClipPlane::Bottom,
+        ClipPlane::Top,
+        ClipPlane::Near,
+        ClipPlane::Far,
+    ];
+
+    /// Signed distance of a clip-space position from the plane.
+    /// Non-negative values are inside the frustum.
+    pub fn distance(&self, pos: Vec4<f32>) -> f32 {
+        match self {
+            ClipPlane::Left => pos.w + pos.x,
+            ClipPlane::Right => pos.w - pos.x,
+            ClipPlane::Bottom => pos.w + pos.y,
+            ClipPlane::Top => pos.w - pos.y,
+            ClipPlane::Near => pos.w + pos.z,
+            ClipPlane::Far => pos.w - pos.z,
+        }
+    }
+}
+
+/// Clips a triangle with clip-space positions against the view
+/// frustum using Sutherland-Hodgman. Returns a convex polygon,
+/// which is empty if the triangle is entirely outside. Vertices
+/// created on the planes have their varyings interpolated.
+pub fn clip_triangle<V: Varyings>(
+    tri: [ShadedVertex<V>; 3],
+) -> Vec<ShadedVertex<V>, MAX_CLIPPED_VERTS> {
+    let mut polygon: Vec<ShadedVertex<V>, MAX_CLIPPED_VERTS> = Vec::new();
+    let mut clipped: Vec<ShadedVertex<V>, MAX_CLIPPED_VERTS> = Vec::new();
+
+    // Trivially accept triangles inside every plane
+    let inside = ClipPlane::ALL
+        .iter()
+        .all(|plane| tri.iter().all(|vertex| plane.distance(vertex.pos) >= 0.0));
+    let _ = polygon.extend_from_slice(&tri);
+    if inside {
+        return polygon;
+    }
+
+    for plane in ClipPlane::ALL {
+        clipped.clear();
+
+        for (i, current) in polygon.iter().enumerate() {
+            let previous = &polygon[(i + polygon.len() - 1) % polygon.len()];
+            let d_previous = plane.distance(previous.pos);
+            let d_current = plane.distance(current.pos);
+
+            if d_current >= 0.0 {
+                if d_previous < 0.0 {
+                    let t = d_previous / (d_previous - d_current);
+                    let _ = clipped.push(previous.lerp(current, t));
+                }
+                let _ = clipped.push(*current);
+            } else if d_previous >= 0.0 {
+                let t = d_previous / (d_previous - d_current);
+                let _ = clipped.push(previous.lerp(current, t));
+            }
+        }
+
+        core::mem::swap(&mut polygon, &mut clipped);
+        if polygon.is_empty() {
+            break;
+        }
+    }
+
+    polygon
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A vertex at a clip-space position, carrying its z as a varying.
+    fn vertex(x: f32, y: f32, z: f32) -> ShadedVertex<f32> {
+        ShadedVertex {
+            pos: Vec4 { x, y, z, w: 1.0 },
+            varyings: z,
+        }
+    }
+
+    #[test]
+    fn triangles_inside_are_unchanged() {
+        let tri = [
+            vertex(-0.5, -0.5, 0.0),
+            vertex(0.5, -0.5, 0.0),
+            vertex(0.0, 0.5, 0.0),
+        ];
+        let polygon = clip_triangle(tri);
+        assert_eq!(polygon.len(), 3);
+        for (clipped, original) in polygon.iter().zip(&tri) {
+            assert_eq!(
+                (clipped.pos.x, clipped.pos.y),
+                (original.pos.x, original.pos.y)
+            );
+        }
+    }
+
+    #[test]
+    fn triangles_outside_one_plane_are_dropped() {
+        let tri = [
+            vertex(2.0, -0.5, 0.0),
+            vertex(3.0, -0.5, 0.0),
+            vertex(2.5, 0.5, 0.0),
+        ];
+        assert!(clip_triangle(tri).is_empty());
+    }
+
+    #[test]
+    fn crossing_the_near_plane_cuts_off_a_corner() {
+        // One vertex behind the near plane at z = -1
+        let tri = [
+            vertex(-0.5, 0.0, 0.0),
+            vertex(0.5, 0.0, 0.0),
+            vertex(0.0, 0.0, -3.0),
+        ];
+        let polygon = clip_triangle(tri);
+        assert_eq!(polygon.len(), 4);
+        for vertex in &polygon {
+            for plane in ClipPlane::ALL {
+                assert!(plane.distance(vertex.pos) >= -1e-6, "{:?}", plane);
+            }
+            // Varyings are interpolated along with the position
+            assert!((vertex.varyings - vertex.pos.z).abs() < 1e-6);
+        }
+        let on_near = |v: &&ShadedVertex<f32>| (v.pos.z + 1.0).abs() < 1e-6;
+        assert_eq!(polygon.iter().filter(on_near).count(), 2);
+    }
+}
@@ -0,0 +1,74 @@
+use defmt::Format;
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// Which faces are discarded before rasterisation.
+pub enum CullMode {
+    /// Discards faces pointing away from the camera.
+    Back,
+    /// Discards faces pointing towards the camera.
+    Front,
+    /// Draws every face.
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// The screen-space winding order of front faces.
+pub enum FrontFace {
+    /// Counter-clockwise, as exported by most modelling tools.
+    Ccw,
+    /// Clockwise.
+    Cw,
+}
+
+impl CullMode {
+    /// Returns true if a face should be discarded. `area` is the signed
+    /// area of the face in NDC, positive when wound counter-clockwise.
+    pub fn culls(&self, front_face: FrontFace, area: f32) -> bool {
+        let front = match front_face {
+            FrontFace::Ccw => area > 0.0,
+            FrontFace::Cw => area < 0.0,
+        };
+        match self {
+            CullMode::Back => !front,
+            CullMode::Front => front,
+            CullMode::None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_mode_culls_by_winding() {
+        use CullMode::{Back, Front, None};
+        use FrontFace::{Ccw, Cw};
+
+        // Whether counter-clockwise and clockwise faces are culled
+        for (mode, front_face, culled) in [
+            (None, Ccw, [false, false]),
+            (None, Cw, [false, false]),
+            (Back, Ccw, [false, true]),
+            (Back, Cw, [true, false]),
+            (Front, Ccw, [true, false]),
+            (Front, Cw, [false, true]),
+        ] {
+            assert_eq!(
+                [mode.culls(front_face, 1.0), mode.culls(front_face, -1.0)],
+                culled,
+                "{:?} culling with {:?} front faces",
+                mode,
+                front_face
+            );
+        }
+    }
+
+    #[test]
+    fn edge_on_faces_are_back_faces() {
+        for front_face in [FrontFace::Ccw, FrontFace::Cw] {
+            assert!(CullMode::Back.culls(front_face, 0.0));
+            assert!(!CullMode::Front.culls(front_face, 0.0));
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use defmt::Format;
+
+/// Depth value that a cleared depth buffer holds (the far plane in NDC).
+pub const DEPTH_CLEAR: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// Comparison used to decide if a fragment is
+/// closer than what is already in the depth buffer.
+pub enum DepthFunc {
+    /// Passes if the new depth is strictly less than the stored depth.
+    Less,
+    /// Passes if the new depth is less than or equal to the stored depth.
+    LessEqual,
+    /// Always passes, so the last fragment drawn is kept.
+    Always,
+}
+
+impl DepthFunc {
+    /// Returns true if a fragment at depth `new` should
+    /// replace one already stored at depth `stored`.
+    pub fn test(&self, new: f32, stored: f32) -> bool {
+        match self {
+            DepthFunc::Less => new < stored,
+            DepthFunc::LessEqual => new <= stored,
+            DepthFunc::Always => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearer_fragments_pass() {
+        for func in [DepthFunc::Less, DepthFunc::LessEqual, DepthFunc::Always] {
+            assert!(func.test(0.2, 0.5), "{:?}", func);
+            assert!(func.test(0.9, DEPTH_CLEAR), "{:?}", func);
+        }
+        assert!(!DepthFunc::Less.test(0.5, 0.2));
+        assert!(!DepthFunc::LessEqual.test(0.5, 0.2));
+        assert!(DepthFunc::Always.test(0.5, 0.2));
+    }
+
+    #[test]
+    fn equal_depths_only_pass_less_equal() {
+        assert!(!DepthFunc::Less.test(0.5, 0.5));
+        assert!(DepthFunc::LessEqual.test(0.5, 0.5));
+        // Nothing drawn at the far plane passes Less
+        assert!(!DepthFunc::Less.test(DEPTH_CLEAR, DEPTH_CLEAR));
+    }
+}
@@ -0,0 +1,250 @@
+use defmt::Format;
+
+use crate::types::{angle::Angle, vector::Vec3};
+
+/// The most lights that can be passed to a single render.
+pub const MAX_LIGHTS: usize = 8;
+
+/// The lights in a scene.
+pub type Lights = heapless::Vec<Light, MAX_LIGHTS>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// How the brightness of a light falls off with distance `d`,
+/// scaled by `1 / (constant + linear * d + quadratic * d^2)`.
+pub struct Attenuation {
+    pub constant: f32,
+    pub linear: f32,
+    pub quadratic: f32,
+}
+
+/// No falloff with distance.
+pub const ATTENUATION_NONE: Attenuation = Attenuation {
+    constant: 1.0,
+    linear: 0.0,
+    quadratic: 0.0,
+};
+
+impl Attenuation {
+    /// Returns the fraction of light remaining at `distance`.
+    pub fn factor(&self, distance: f32) -> f32 {
+        let denom = self.constant + self.linear * distance + self.quadratic * distance * distance;
+        if denom <= 0.0 {
+            return 1.0;
+        }
+        1.0 / denom
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// A light source in world space.
+pub enum Light {
+    /// Shines equally in every direction from a point.
+    Point {
+        pos: Vec3<f32>,
+        colour: Vec3<f32>,
+        attenuation: Attenuation,
+    },
+    /// Shines in one direction from infinitely far away,
+    /// like the sun. `dir` is the direction the light travels.
+    Directional { dir: Vec3<f32>, colour: Vec3<f32> },
+    /// Shines in a cone from a point. Full brightness within the
+    /// inner cone, fading to nothing at the edge of the outer cone.
+    /// The cones are stored as the cosines of their half-angles.
+    Spot {
+        pos: Vec3<f32>,
+        dir: Vec3<f32>,
+        colour: Vec3<f32>,
+        attenuation: Attenuation,
+        inner_cos: f32,
+        outer_cos: f32,
+    },
+}
+
+impl Light {
+    /// Creates a point light.
+    pub fn point(pos: Vec3<f32>, colour: Vec3<f32>, attenuation: Attenuation) -> Light {
+        Light::Point {
+            pos,
+            colour,
+            attenuation,
+        }
+    }
+
+    /// Creates a directional light travelling along `dir`.
+    pub fn directional(mut dir: Vec3<f32>, colour: Vec3<f32>) -> Light {
+        dir.nor();
+        Light::Directional { dir, colour }
+    }
+
+    /// Creates a spot light at `pos` pointing along `dir`.
+    /// `inner` and `outer` are the half-angles of the cones.
+    pub fn spot(
+        pos: Vec3<f32>,
+        mut dir: Vec3<f32>,
+        colour: Vec3<f32>,
+        attenuation: Attenuation,
+        inner: Angle,
+        outer: Angle,
+    ) -> Light {
+        dir.nor();
+        Light::Spot {
+            pos,
+            dir,
+            colour,
+            attenuation,
+            inner_cos: inner.cos(),
+            outer_cos: outer.cos(),
+        }
+    }
+
+    /// Returns the colour of the light.
+    pub fn colour(&self) -> Vec3<f32> {
+        match self {
+            Light::Point { colour, .. }
+            | Light::Directional { colour, .. }
+            | Light::Spot { colour, .. } => *colour,
+        }
+    }
+
+    /// Moves a point or spot light. Directional lights are unaffected.
+    pub fn set_pos(&mut self, new_pos: Vec3<f32>) {
+        match self {
+            Light::Point { pos, .. } | Light::Spot { pos, .. } => *pos = new_pos,
+            Light::Directional { .. } => {}
+        }
+    }
+
+    /// Returns the unit direction from a world-space surface point
+    /// towards the light, and the light arriving at that point.
+    pub fn incident(&self, surface: Vec3<f32>) -> (Vec3<f32>, Vec3<f32>) {
+        match *self {
+            Light::Point {
+                pos,
+                colour,
+                attenuation,
+            } => {
+                let mut to_light = pos - surface;
+                let distance = to_light.len();
+                to_light.nor();
+                (to_light, colour * attenuation.factor(distance))
+            }
+            Light::Directional { dir, colour } => (-dir, colour),
+            Light::Spot {
+                pos,
+                dir,
+                colour,
+                attenuation,
+                inner_cos,
+                outer_cos,
+            } => {
+                let mut to_light = pos - surface;
+                let distance = to_light.len();
+                to_light.nor();
+
+                // Smoothly fade between the cones
+                let cos_angle = Vec3::dot(-to_light, dir);
+                let cone = if inner_cos <= outer_cos {
+                    if cos_angle >= outer_cos {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                } else {
+                    ((cos_angle - outer_cos) / (inner_cos - outer_cos)).clamp(0.0, 1.0)
+                };
+
+                (to_light, colour * attenuation.factor(distance) * cone)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: Vec3<f32> = Vec3 {
+        x: 1.0,
+        y: 1.0,
+        z: 1.0,
+    };
+
+    fn vec3(x: f32, y: f32, z: f32) -> Vec3<f32> {
+        Vec3 { x, y, z }
+    }
+
+    fn assert_vec(actual: Vec3<f32>, expected: [f32; 3]) {
+        let actual = [actual.x, actual.y, actual.z];
+        for (value, expected_value) in actual.iter().zip(expected) {
+            assert!(
+                (value - expected_value).abs() < 1e-4,
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn point_lights_fade_with_distance() {
+        let attenuation = Attenuation {
+            constant: 1.0,
+            linear: 0.5,
+            quadratic: 0.25,
+        };
+        let light = Light::point(vec3(0.0, 0.0, 2.0), WHITE, attenuation);
+
+        // 1 / (1 + 0.5 * 2 + 0.25 * 2^2)
+        let (dir, colour) = light.incident(vec3(0.0, 0.0, 0.0));
+        assert_vec(dir, [0.0, 0.0, 1.0]);
+        assert_vec(colour, [1.0 / 3.0; 3]);
+
+        let (_, colour) = light.incident(vec3(0.0, 0.0, 1.0));
+        assert_vec(colour, [1.0 / 1.75; 3]);
+
+        // A denominator of zero would divide by zero, so is ignored
+        let none = Attenuation {
+            constant: 0.0,
+            linear: 0.0,
+            quadratic: 0.0,
+        };
+        assert_eq!(none.factor(2.0), 1.0);
+    }
+
+    #[test]
+    fn directional_lights_are_the_same_everywhere() {
+        let light = Light::directional(vec3(0.0, -2.0, 0.0), WHITE * 0.5);
+        for surface in [vec3(0.0, 0.0, 0.0), vec3(10.0, -50.0, 3.0)] {
+            let (dir, colour) = light.incident(surface);
+            assert_vec(dir, [0.0, 1.0, 0.0]);
+            assert_vec(colour, [0.5; 3]);
+        }
+    }
+
+    #[test]
+    fn spot_lights_fade_between_their_cones() {
+        let light = Light::spot(
+            vec3(0.0, 1.0, 0.0),
+            vec3(0.0, -1.0, 0.0),
+            WHITE,
+            ATTENUATION_NONE,
+            Angle::from_deg(30.0),
+            Angle::from_deg(60.0),
+        );
+        // Points on the floor, this far from the axis at a height of 1
+        let brightness = |offset: f32| light.incident(vec3(offset, 0.0, 0.0)).1.x;
+
+        // Full inside the inner cone, none outside the outer cone
+        assert!((brightness(0.0) - 1.0).abs() < 1e-4);
+        assert!((brightness(0.5) - 1.0).abs() < 1e-4);
+        assert_eq!(brightness(2.0), 0.0);
+        // At 45 degrees, between cos 30 and cos 60
+        let expected = (0.5f32.sqrt() - 0.5) / (0.75f32.sqrt() - 0.5);
+        assert!(
+            (brightness(1.0) - expected).abs() < 1e-3,
+            "{} != {}",
+            brightness(1.0),
+            expected
+        );
+    }
+}
@@ -1,3 +1,11 @@
+pub mod cache;
 pub mod camera;
+pub mod clip;
+pub mod cull;
+pub mod depth;
+pub mod light;
+pub mod raster;
+pub mod scene;
+pub mod shader;
 pub mod texture;
 pub mod viewport;
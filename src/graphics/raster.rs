@@ -0,0 +1,375 @@
+use defmt::Format;
+
+use crate::{math::floor, types::vector::Vec2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// Selects how a camera turns faces into fragments.
+pub enum RasterMode {
+    /// Casts a ray through the centre of every pixel against every face.
+    /// Slow, but kept as a reference implementation.
+    RayCast,
+    /// Walks the screen-space bounding box of each
+    /// triangle, testing pixels with edge functions.
+    EdgeFunction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// Selects how vertex attributes are interpolated across a triangle.
+pub enum Interpolation {
+    /// Interpolates linearly in screen space. Cheaper, but
+    /// textures swim on surfaces at an angle to the camera.
+    Affine,
+    /// Interpolates `attribute / w` and `1 / w`, which is
+    /// linear in screen space, then divides back out.
+    Perspective,
+}
+
+impl Interpolation {
+    /// Adjusts screen-space barycentric weights so attributes
+    /// interpolated with them follow this mode. `w` holds the
+    /// clip-space `w` of each vertex.
+    pub fn weights(&self, bary: [f32; 3], w: [f32; 3]) -> [f32; 3] {
+        match self {
+            Interpolation::Affine => bary,
+            Interpolation::Perspective => {
+                let over_w = [bary[0] / w[0], bary[1] / w[1], bary[2] / w[2]];
+                let inv_w = over_w[0] + over_w[1] + over_w[2];
+                if inv_w == 0.0 {
+                    return bary;
+                }
+                over_w.map(|b| b / inv_w)
+            }
+        }
+    }
+}
+
+/// Returns twice the signed area of the triangle `a`, `b`, `p`.
+/// Positive when `p` is to the left of the edge `a` -> `b`
+/// (with y pointing up).
+pub fn edge(a: Vec2<f32>, b: Vec2<f32>, p: Vec2<f32>) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// Returns how the barycentric weights of the triangle `a`, `b`, `c`
+/// change per unit step in x and in y. Zero for degenerate triangles.
+pub fn bary_steps(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> ([f32; 3], [f32; 3]) {
+    let area = edge(a, b, c);
+    if area == 0.0 {
+        return ([0.0; 3], [0.0; 3]);
+    }
+    (
+        [b.y - c.y, c.y - a.y, a.y - b.y].map(|d| d / area),
+        [c.x - b.x, a.x - c.x, b.x - a.x].map(|d| d / area),
+    )
+}
+
+/// Bits of subpixel precision that screen-space vertices are snapped
+/// to. Edge functions on the snapped grid are exact integers, so
+/// triangles sharing an edge agree on which side of it a pixel lies.
+const SUBPIXEL_BITS: u32 = 8;
+
+/// Snaps a point in pixel coordinates onto the subpixel grid.
+fn snap(v: Vec2<f32>) -> [i64; 2] {
+    let scale = (1 << SUBPIXEL_BITS) as f32;
+    [
+        floor(v.x * scale + 0.5) as i64,
+        floor(v.y * scale + 0.5) as i64,
+    ]
+}
+
+/// The edge functions of a triangle in subpixel units, oriented
+/// so covered pixels are non-negative. Edge `i` is opposite vertex `i`.
+#[derive(Clone)]
+struct Edges {
+    /// Values at the centre of pixel (0, 0).
+    origin: [i64; 3],
+    /// Change one pixel to the right.
+    step_x: [i64; 3],
+    /// Change one pixel down.
+    step_y: [i64; 3],
+    /// Added before testing coverage. Pixel centres exactly on an
+    /// edge belong to the triangle for which it is a top or left
+    /// edge, so shared edges are only drawn once.
+    bias: [i64; 3],
+    /// Twice the area of the triangle. Zero if it's degenerate.
+    area: i64,
+}
+
+impl Edges {
+    fn new(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Edges {
+        let [a, b, c] = [a, b, c].map(snap);
+        let edge = |p: [i64; 2], q: [i64; 2], r: [i64; 2]| {
+            (q[0] - p[0]) * (r[1] - p[1]) - (q[1] - p[1]) * (r[0] - p[0])
+        };
+        // Flip the edge functions of triangles wound the other way
+        let area = edge(a, b, c);
+        let sign = area.signum();
+
+        let one = 1 << SUBPIXEL_BITS;
+        let centre = [one / 2, one / 2];
+        let edges = [(b, c), (c, a), (a, b)];
+        let step_x = edges.map(|(p, q)| (p[1] - q[1]) * one * sign);
+        let step_y = edges.map(|(p, q)| (q[0] - p[0]) * one * sign);
+        Edges {
+            origin: edges.map(|(p, q)| edge(p, q, centre) * sign),
+            step_x,
+            step_y,
+            // The inside of a left edge is to its right, and the
+            // inside of a top edge is below it
+            bias: core::array::from_fn(|i| {
+                let top_left = step_x[i] > 0 || (step_x[i] == 0 && step_y[i] > 0);
+                if top_left {
+                    0
+                } else {
+                    -1
+                }
+            }),
+            area: area * sign,
+        }
+    }
+
+    /// Returns the edge functions at the centre of a pixel.
+    fn at(&self, x: usize, y: usize) -> [i64; 3] {
+        core::array::from_fn(|i| {
+            self.origin[i] + self.step_x[i] * x as i64 + self.step_y[i] * y as i64
+        })
+    }
+
+    fn covers(&self, weights: [i64; 3]) -> bool {
+        self.area != 0 && (0..3).all(|i| weights[i] + self.bias[i] >= 0)
+    }
+
+    /// Converts edge functions or their steps to barycentric weights.
+    fn bary(&self, weights: [i64; 3]) -> [f32; 3] {
+        weights.map(|w| w as f32 / self.area as f32)
+    }
+}
+
+/// Returns the barycentric weights of the screen-space triangle `a`,
+/// `b`, `c` at the centre of pixel (`x`, `y`), if the triangle covers
+/// it. Coverage follows the same rule as `TriangleRaster`.
+pub fn coverage(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>, x: usize, y: usize) -> Option<[f32; 3]> {
+    let edges = Edges::new(a, b, c);
+    let weights = edges.at(x, y);
+    edges.covers(weights).then(|| edges.bary(weights))
+}
+
+#[derive(Debug, Clone, Copy, Format)]
+/// A pixel covered by a triangle.
+pub struct Fragment {
+    pub x: usize,
+    pub y: usize,
+    /// Barycentric weights of the triangle's vertices
+    /// at the pixel centre. These sum to 1.
+    pub bary: [f32; 3],
+    /// Change in `bary` one pixel to the right.
+    pub bary_dx: [f32; 3],
+    /// Change in `bary` one pixel down.
+    pub bary_dy: [f32; 3],
+}
+
+/// Iterates over the pixels covered by a screen-space triangle,
+/// with y increasing down the rows. A pixel is covered if its centre
+/// is inside the triangle, or on a top or left edge. The edge functions
+/// are stepped incrementally across each row rather than recomputed
+/// per pixel.
+#[derive(Clone)]
+pub struct TriangleRaster {
+    x: usize,
+    y: usize,
+    min_x: usize,
+    max_x: usize,
+    max_y: usize,
+    edges: Edges,
+    /// Edge function values at the current pixel.
+    weights: [i64; 3],
+    /// Edge function values at the start of the current row.
+    row_weights: [i64; 3],
+}
+
+impl TriangleRaster {
+    /// Sets up a triangle with vertices in pixel coordinates,
+    /// clipped to a `width`x`height` target. Either winding
+    /// order is accepted; degenerate triangles cover no pixels.
+    pub fn new(
+        a: Vec2<f32>,
+        b: Vec2<f32>,
+        c: Vec2<f32>,
+        width: usize,
+        height: usize,
+    ) -> TriangleRaster {
+        let edges = Edges::new(a, b, c);
+
+        let min_x = f32::max(f32::min(f32::min(a.x, b.x), c.x), 0.0) as usize;
+        let min_y = f32::max(f32::min(f32::min(a.y, b.y), c.y), 0.0) as usize;
+        let max_x = f32::max(f32::max(a.x, b.x), c.x) as isize;
+        let max_y = f32::max(f32::max(a.y, b.y), c.y) as isize;
+        let max_x = isize::min(max_x, width as isize - 1);
+        let max_y = isize::min(max_y, height as isize - 1);
+
+        let empty = edges.area == 0 || max_x < min_x as isize || max_y < min_y as isize;
+        let row_weights = edges.at(min_x, min_y);
+
+        TriangleRaster {
+            x: min_x,
+            // Start past the last row so nothing is produced
+            y: if empty { usize::MAX } else { min_y },
+            min_x,
+            max_x: isize::max(max_x, 0) as usize,
+            max_y: isize::max(max_y, 0) as usize,
+            edges,
+            weights: row_weights,
+            row_weights,
+        }
+    }
+}
+
+impl Iterator for TriangleRaster {
+    type Item = Fragment;
+
+    fn next(&mut self) -> Option<Fragment> {
+        loop {
+            if self.y > self.max_y {
+                return None;
+            }
+
+            if self.x > self.max_x {
+                self.x = self.min_x;
+                self.y += 1;
+                for i in 0..3 {
+                    self.row_weights[i] += self.edges.step_y[i];
+                }
+                self.weights = self.row_weights;
+                continue;
+            }
+
+            let x = self.x;
+            let weights = self.weights;
+            self.x += 1;
+            for i in 0..3 {
+                self.weights[i] += self.edges.step_x[i];
+            }
+
+            if self.edges.covers(weights) {
+                return Some(Fragment {
+                    x,
+                    y: self.y,
+                    bary: self.edges.bary(weights),
+                    bary_dx: self.edges.bary(self.edges.step_x),
+                    bary_dy: self.edges.bary(self.edges.step_y),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: f32, y: f32) -> Vec2<f32> {
+        Vec2 { x, y }
+    }
+
+    /// Counts how many times each pixel of a 20x20 target is drawn.
+    fn overdraw(triangles: &[[Vec2<f32>; 3]]) -> [[u8; 20]; 20] {
+        let mut counts = [[0; 20]; 20];
+        for &[a, b, c] in triangles {
+            for fragment in TriangleRaster::new(a, b, c, 20, 20) {
+                counts[fragment.y][fragment.x] += 1;
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn covers_pixel_centres_inside() {
+        // (2, 0) and (1, 1) have centres on the bottom-right edge
+        let pixels: heapless::Vec<(usize, usize), 16> =
+            TriangleRaster::new(v(0.0, 0.0), v(3.0, 0.0), v(0.0, 3.0), 4, 4)
+                .map(|f| (f.x, f.y))
+                .collect();
+        assert_eq!(pixels, [(0, 0), (1, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn shared_edges_are_drawn_once() {
+        // A quad with its edges and diagonal through pixel centres
+        let [a, b, c, d] = [v(2.5, 2.5), v(18.5, 2.5), v(18.5, 18.5), v(2.5, 18.5)];
+        for quad in [[[a, b, c], [a, c, d]], [[a, c, b], [a, d, c]]] {
+            let counts = overdraw(&quad);
+            assert!(counts.iter().flatten().all(|&n| n <= 1));
+            // The top and left edges are drawn, the bottom and right aren't
+            assert_eq!(counts.iter().flatten().filter(|&&n| n == 1).count(), 256);
+            assert_eq!(counts[2][2], 1);
+            assert_eq!(counts[18][10], 0);
+            assert_eq!(counts[10][18], 0);
+        }
+    }
+
+    #[test]
+    fn fan_around_a_shared_vertex_has_no_gaps_or_overlaps() {
+        let centre = v(10.0, 10.0);
+        let rim = [
+            v(3.0, 4.5),
+            v(16.2, 2.0),
+            v(17.0, 15.5),
+            v(9.5, 18.0),
+            v(2.0, 12.0),
+        ];
+        let triangles: [[Vec2<f32>; 3]; 5] =
+            core::array::from_fn(|i| [centre, rim[i], rim[(i + 1) % 5]]);
+        let counts = overdraw(&triangles);
+        assert!(counts.iter().flatten().all(|&n| n <= 1));
+        // Every pixel around the shared vertex is drawn
+        for (x, y) in [(9, 9), (10, 9), (9, 10), (10, 10)] {
+            assert_eq!(counts[y][x], 1);
+        }
+    }
+
+    #[test]
+    fn coverage_matches_the_raster() {
+        let [a, b, c] = [v(1.3, 0.7), v(13.9, 5.5), v(4.2, 12.0)];
+        let mut raster = TriangleRaster::new(a, b, c, 16, 16);
+        for y in 0..16 {
+            for x in 0..16 {
+                let expected = coverage(a, b, c, x, y).is_some();
+                let drawn = raster.clone().any(|f| f.x == x && f.y == y);
+                assert_eq!(drawn, expected, "pixel ({}, {})", x, y);
+            }
+        }
+        let fragment = raster.next().unwrap();
+        let bary = coverage(a, b, c, fragment.x, fragment.y).unwrap();
+        assert_eq!(fragment.bary, bary);
+        assert!((bary.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn degenerate_triangles_cover_nothing() {
+        assert_eq!(
+            TriangleRaster::new(v(0.0, 0.0), v(5.0, 5.0), v(10.0, 10.0), 20, 20).count(),
+            0
+        );
+        assert!(coverage(v(1.0, 1.0), v(1.0, 1.0), v(1.0, 1.0), 1, 1).is_none());
+    }
+
+    #[test]
+    fn perspective_weights_favour_the_nearer_vertex() {
+        // Halfway across the screen between a vertex at w 1 and one
+        // at w 3 is a quarter of the way along the edge in 3D
+        let bary = [0.5, 0.5, 0.0];
+        let w = [1.0, 3.0, 1.0];
+        assert_eq!(Interpolation::Affine.weights(bary, w), bary);
+        let weights = Interpolation::Perspective.weights(bary, w);
+        for (weight, expected) in weights.iter().zip([0.75, 0.25, 0.0]) {
+            assert!((weight - expected).abs() < 1e-6, "{:?}", weights);
+        }
+
+        // With equal w the two modes agree
+        let bary = [0.2, 0.3, 0.5];
+        let weights = Interpolation::Perspective.weights(bary, [2.0; 3]);
+        for (weight, expected) in weights.iter().zip(bary) {
+            assert!((weight - expected).abs() < 1e-6, "{:?}", weights);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use defmt::Format;
+use heapless::Vec;
+
+use crate::{
+    graphics::texture::Image,
+    model::{material::Material, mesh::Mesh},
+    types::{angle::Angle, matrix::Mat4, quat::Quaternion, vector::Vec3},
+};
+
+pub const MAX_NODES: usize = 16;
+
+/// Identifies a node by its position in the scene.
+pub type NodeId = usize;
+
+#[derive(Clone, Copy, Format)]
+/// The placement of a node relative to its parent.
+pub struct Transform {
+    pub translation: Vec3<f32>,
+    pub rotation: Quaternion,
+    pub scale: f32,
+}
+
+impl Transform {
+    /// A transform that leaves its node where its parent is.
+    pub fn idt() -> Transform {
+        Transform {
+            translation: Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            rotation: Quaternion::idt(),
+            scale: 1.0,
+        }
+    }
+
+    /// Scales, then rotates, then translates.
+    pub fn matrix(&self) -> Mat4<f32> {
+        Mat4::transform(self.translation, self.rotation, self.scale)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::idt()
+    }
+}
+
+#[derive(Clone, Copy, Format)]
+/// Turns a transform about a fixed axis at a constant rate.
+pub struct Spin {
+    pub axis: Vec3<f32>,
+    /// Angle turned per second.
+    pub rate: Angle,
+}
+
+impl Spin {
+    /// Turns `transform` by however far the spin covers in `seconds`.
+    pub fn advance(&self, transform: &mut Transform, seconds: f32) {
+        let step = Quaternion::of(self.axis, Angle::from_rad(self.rate.rad * seconds));
+        // Renormalise so rounding errors don't build up over many frames
+        transform.rotation = *(step * transform.rotation).nor();
+    }
+}
+
+/// An object in a scene, with an optional mesh to draw.
+/// Nodes without a mesh can group others to move them together.
+pub struct Node<'a> {
+    pub transform: Transform,
+    pub mesh: Option<&'a dyn Mesh>,
+    /// Replaces the materials of the mesh when set.
+    pub material: Option<&'a Material>,
+    /// Replaces the default texture when set.
+    pub texture: Option<&'a dyn Image>,
+    parent: Option<NodeId>,
+    world: Mat4<f32>,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(transform: Transform, mesh: Option<&'a dyn Mesh>) -> Node<'a> {
+        Node {
+            transform,
+            mesh,
+            material: None,
+            texture: None,
+            parent: None,
+            world: Mat4::idt(),
+        }
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// Returns the model-to-world matrix as of the last
+    /// call to [`Scene::update`].
+    pub fn world(&self) -> Mat4<f32> {
+        self.world
+    }
+}
+
+/// A hierarchy of nodes, each placed relative to its parent.
+///
+/// A parent is always added before its children, so world
+/// matrices can be found in a single pass in insertion order.
+pub struct Scene<'a> {
+    nodes: Vec<Node<'a>, MAX_NODES>,
+}
+
+impl<'a> Scene<'a> {
+    pub const fn new() -> Scene<'a> {
+        Scene { nodes: Vec::new() }
+    }
+
+    /// Adds a node at the top of the hierarchy.
+    /// Returns `None` if the scene is full.
+    pub fn add(&mut self, node: Node<'a>) -> Option<NodeId> {
+        let id = self.nodes.len();
+        self.nodes.push(node).ok()?;
+        Some(id)
+    }
+
+    /// Adds a node placed relative to `parent`.
+    /// Returns `None` if the scene is full.
+    pub fn add_child(&mut self, parent: NodeId, mut node: Node<'a>) -> Option<NodeId> {
+        defmt::assert!(parent < self.nodes.len(), "parent should be in the scene");
+        node.parent = Some(parent);
+        self.add(node)
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node<'a> {
+        &self.nodes[id]
+    }
+
+    /// Returns a node to move it. Call [`Scene::update`]
+    /// afterwards to apply the change to it and its children.
+    pub fn node_mut(&mut self, id: NodeId) -> &mut Node<'a> {
+        &mut self.nodes[id]
+    }
+
+    pub fn nodes(&self) -> &[Node<'a>] {
+        &self.nodes
+    }
+
+    /// Finds the world matrix of every node. Done once per frame,
+    /// after nodes have moved and before any are drawn.
+    pub fn update(&mut self) {
+        for i in 0..self.nodes.len() {
+            let local = self.nodes[i].transform.matrix();
+            self.nodes[i].world = match self.nodes[i].parent {
+                Some(parent) => self.nodes[parent].world * local,
+                None => local,
+            };
+        }
+    }
+}
+
+impl Default for Scene<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::vector::VEC3_Z;
+
+    /// Asserts that `m` matches `expected`, given row by row.
+    fn assert_mat(m: Mat4<f32>, expected: [[f32; 4]; 4]) {
+        let rows = [
+            [m.v_00, m.v_01, m.v_02, m.v_03],
+            [m.v_10, m.v_11, m.v_12, m.v_13],
+            [m.v_20, m.v_21, m.v_22, m.v_23],
+            [m.v_30, m.v_31, m.v_32, m.v_33],
+        ];
+        for (row, expected_row) in rows.iter().zip(expected) {
+            for (value, expected) in row.iter().zip(expected_row) {
+                assert!(
+                    (value - expected).abs() < 1e-5,
+                    "{:?} != {:?}",
+                    rows,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn children_are_placed_relative_to_their_parent() {
+        let mut scene = Scene::new();
+        let parent = scene
+            .add(Node::new(
+                Transform {
+                    translation: Vec3 {
+                        x: 1.0,
+                        y: 0.0,
+                        z: 0.0,
+                    },
+                    rotation: Quaternion::of(VEC3_Z, Angle::from_deg(90.0)),
+                    scale: 1.0,
+                },
+                None,
+            ))
+            .unwrap();
+        let child = scene
+            .add_child(
+                parent,
+                Node::new(
+                    Transform {
+                        translation: Vec3 {
+                            x: 0.0,
+                            y: 2.0,
+                            z: 0.0,
+                        },
+                        scale: 0.5,
+                        ..Transform::idt()
+                    },
+                    None,
+                ),
+            )
+            .unwrap();
+        scene.update();
+
+        // The child's offset is turned by the parent,
+        // then the parent's own offset is added
+        assert_mat(
+            scene.node(child).world(),
+            [
+                [0.0, -0.5, 0.0, -1.0],
+                [0.5, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.5, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        );
+
+        // Moving the parent moves the child on the next update
+        scene.node_mut(parent).transform.translation.x = 3.0;
+        scene.update();
+        assert!((scene.node(child).world().v_03 - 1.0).abs() < 1e-5);
+    }
+}
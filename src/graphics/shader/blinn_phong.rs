@@ -0,0 +1,118 @@
+use crate::{
+    graphics::shader::{
+        Derivatives, FragmentShader, Phong, ShadedVertex, StandardUniforms, Varyings, VertexShader,
+    },
+    model::{material::Material, model::Vertex},
+    types::vector::{Vec2, Vec3},
+};
+
+/// Textured Blinn-Phong shading, lit per fragment.
+/// Face materials override the colours and shininess.
+#[derive(Clone, Copy, Default)]
+pub struct BlinnPhong {
+    pub phong: Phong,
+}
+
+#[derive(Clone, Copy)]
+/// World-space surface attributes.
+pub struct SurfaceVaryings {
+    pub pos: Vec3<f32>,
+    pub normal: Vec3<f32>,
+    pub tex_coords: Vec2<f32>,
+}
+
+impl Varyings for SurfaceVaryings {
+    fn interpolate(a: &Self, b: &Self, c: &Self, weights: [f32; 3]) -> Self {
+        SurfaceVaryings {
+            pos: Varyings::interpolate(&a.pos, &b.pos, &c.pos, weights),
+            normal: Varyings::interpolate(&a.normal, &b.normal, &c.normal, weights),
+            tex_coords: Varyings::interpolate(&a.tex_coords, &b.tex_coords, &c.tex_coords, weights),
+        }
+    }
+}
+
+impl<'a> VertexShader<StandardUniforms<'a>> for BlinnPhong {
+    type Varyings = SurfaceVaryings;
+
+    fn vertex(
+        &self,
+        uniforms: &StandardUniforms<'a>,
+        vertex: &Vertex,
+    ) -> ShadedVertex<SurfaceVaryings> {
+        let (pos, normal) = uniforms.to_world(vertex);
+        ShadedVertex {
+            pos: uniforms.to_clip(vertex.pos),
+            varyings: SurfaceVaryings {
+                pos,
+                normal,
+                tex_coords: vertex.tex_coords,
+            },
+        }
+    }
+}
+
+impl<'a> FragmentShader<StandardUniforms<'a>, SurfaceVaryings> for BlinnPhong {
+    fn fragment(
+        &self,
+        uniforms: &StandardUniforms<'a>,
+        material: Option<&Material>,
+        varyings: &SurfaceVaryings,
+        derivatives: &Derivatives<SurfaceVaryings>,
+    ) -> Vec3<f32> {
+        // Interpolated normals are no longer unit length
+        let normal = *varyings.normal.clone().nor();
+        let (diffuse, specular) =
+            self.phong
+                .with_material(material)
+                .light(uniforms, varyings.pos, normal);
+        let tex_derivatives = derivatives.map(|d| d.tex_coords);
+        uniforms.surface(material, varyings.tex_coords, tex_derivatives) * diffuse + specular
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graphics::{
+            shader::tests::{
+                assert_colour, uniforms, vertex, AMBIENT_SPECULAR, DIFFUSE, HEAD_ON, RED,
+            },
+            texture::Texture,
+        },
+        types::vector::{VEC3_Z, VEC3_ZERO},
+    };
+
+    #[test]
+    fn lights_the_texture_at_each_fragment() {
+        let texture = Texture::<1, 1>::from_fn(|_, _| RED);
+        let uniforms = uniforms(&HEAD_ON, &texture);
+        let shader = BlinnPhong::default();
+        // Interpolated normals are renormalised
+        let varyings = shader
+            .vertex(&uniforms, &vertex(VEC3_ZERO, VEC3_Z * 2.0))
+            .varyings;
+        let varyings = SurfaceVaryings {
+            normal: varyings.normal * 0.5,
+            ..varyings
+        };
+        let still = Derivatives {
+            dx: varyings,
+            dy: varyings,
+        }
+        .map(|v| SurfaceVaryings {
+            tex_coords: Vec2 { x: 0.0, y: 0.0 },
+            ..v
+        });
+
+        let colour = shader.fragment(&uniforms, None, &varyings, &still);
+        assert_colour(
+            colour,
+            [
+                DIFFUSE + AMBIENT_SPECULAR,
+                AMBIENT_SPECULAR,
+                AMBIENT_SPECULAR,
+            ],
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    graphics::shader::{
+        Derivatives, FragmentShader, Phong, ShadedVertex, StandardUniforms, Varyings, VertexShader,
+    },
+    model::{material::Material, model::Vertex},
+    types::vector::Vec3,
+};
+
+/// Varyings that are not interpolated: every fragment
+/// takes the value of the face's first vertex.
+#[derive(Clone, Copy)]
+pub struct Flat<V: Copy>(pub V);
+
+impl<V: Copy> Varyings for Flat<V> {
+    fn interpolate(a: &Self, _b: &Self, _c: &Self, _weights: [f32; 3]) -> Self {
+        *a
+    }
+}
+
+/// Untextured shading with one lit colour per face. Faces are
+/// lit at their first vertex with the normal of their plane, so
+/// vertex normals are ignored, and whichever side is seen is lit.
+/// Face materials override the colour, `Ka`, `Ks` and `Ns`.
+#[derive(Clone, Copy)]
+pub struct FlatShader {
+    pub colour: Vec3<f32>,
+    pub phong: Phong,
+}
+
+impl Default for FlatShader {
+    fn default() -> Self {
+        FlatShader {
+            colour: Vec3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+            phong: Phong::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+/// World-space positions for flat shading.
+pub struct FlatVaryings {
+    /// Interpolated, so it only changes along the face's plane.
+    pub pos: Vec3<f32>,
+    /// Where the face is lit.
+    pub provoking: Flat<Vec3<f32>>,
+}
+
+impl Varyings for FlatVaryings {
+    fn interpolate(a: &Self, b: &Self, c: &Self, weights: [f32; 3]) -> Self {
+        FlatVaryings {
+            pos: Varyings::interpolate(&a.pos, &b.pos, &c.pos, weights),
+            provoking: Varyings::interpolate(&a.provoking, &b.provoking, &c.provoking, weights),
+        }
+    }
+}
+
+impl<'a> VertexShader<StandardUniforms<'a>> for FlatShader {
+    type Varyings = FlatVaryings;
+
+    fn vertex(
+        &self,
+        uniforms: &StandardUniforms<'a>,
+        vertex: &Vertex,
+    ) -> ShadedVertex<FlatVaryings> {
+        let (pos, _) = uniforms.to_world(vertex);
+        ShadedVertex {
+            pos: uniforms.to_clip(vertex.pos),
+            varyings: FlatVaryings {
+                pos,
+                provoking: Flat(pos),
+            },
+        }
+    }
+}
+
+impl<'a> FragmentShader<StandardUniforms<'a>, FlatVaryings> for FlatShader {
+    fn fragment(
+        &self,
+        uniforms: &StandardUniforms<'a>,
+        material: Option<&Material>,
+        varyings: &FlatVaryings,
+        derivatives: &Derivatives<FlatVaryings>,
+    ) -> Vec3<f32> {
+        // Both steps lie in the face's plane. Screen y runs
+        // down, so this order gives the normal facing the camera.
+        let normal = Vec3::cross(derivatives.dy.pos, derivatives.dx.pos);
+        // The steps are short, so the product is tiny. Scale it
+        // up first, as `sqrt` is only accurate for values near 1.
+        let largest = normal.x.abs().max(normal.y.abs()).max(normal.z.abs());
+        let normal = *(normal * (1.0 / largest)).nor();
+        let Flat(pos) = varyings.provoking;
+        let (diffuse, specular) = self
+            .phong
+            .with_material(material)
+            .light(uniforms, pos, normal);
+        material.map_or(self.colour, |m| m.diffuse) * diffuse + specular
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graphics::{
+            shader::tests::{assert_colour, uniforms, vertex, AMBIENT_SPECULAR, DIFFUSE, HEAD_ON},
+            texture::Texture,
+        },
+        types::vector::{VEC3_X, VEC3_Y, VEC3_ZERO},
+    };
+
+    #[test]
+    fn faces_are_lit_with_their_plane_normal() {
+        let texture = Texture::<1, 1>::from_fn(|_, _| Vec3 { x: 0, y: 0, z: 0 });
+        let uniforms = uniforms(&HEAD_ON, &texture);
+        let shader = FlatShader::default();
+
+        // A face towards the camera, with every vertex
+        // normal wrongly pointing along +x
+        let [a, b, c] = [VEC3_ZERO, VEC3_X, VEC3_Y]
+            .map(|pos| shader.vertex(&uniforms, &vertex(pos, VEC3_X)).varyings);
+        let weights = [0.2, 0.3, 0.5];
+        let varyings = FlatVaryings::interpolate(&a, &b, &c, weights);
+        // Stepping right moves towards b, and down away from c
+        let derivatives = Derivatives {
+            dx: FlatVaryings::interpolate(&a, &b, &c, [-0.1, 0.1, 0.0]),
+            dy: FlatVaryings::interpolate(&a, &b, &c, [0.1, 0.0, -0.1]),
+        };
+
+        let colour = shader.fragment(&uniforms, None, &varyings, &derivatives);
+        assert_colour(colour, [DIFFUSE + AMBIENT_SPECULAR; 3]);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    graphics::shader::{
+        Derivatives, FragmentShader, Phong, ShadedVertex, StandardUniforms, Varyings, VertexShader,
+    },
+    model::{material::Material, model::Vertex},
+    types::vector::{Vec2, Vec3},
+};
+
+/// Textured Blinn-Phong shading, lit per vertex and
+/// interpolated. Cheaper than `BlinnPhong`, but
+/// highlights smaller than a face are lost. Lighting is
+/// done before the face's material is known, so materials
+/// only set the surface colour, not `Ka`, `Ks` or `Ns`.
+#[derive(Clone, Copy, Default)]
+pub struct Gouraud {
+    pub phong: Phong,
+}
+
+#[derive(Clone, Copy)]
+/// Lighting computed at each vertex.
+pub struct GouraudVaryings {
+    pub diffuse: Vec3<f32>,
+    pub specular: Vec3<f32>,
+    pub tex_coords: Vec2<f32>,
+}
+
+impl Varyings for GouraudVaryings {
+    fn interpolate(a: &Self, b: &Self, c: &Self, weights: [f32; 3]) -> Self {
+        GouraudVaryings {
+            diffuse: Varyings::interpolate(&a.diffuse, &b.diffuse, &c.diffuse, weights),
+            specular: Varyings::interpolate(&a.specular, &b.specular, &c.specular, weights),
+            tex_coords: Varyings::interpolate(&a.tex_coords, &b.tex_coords, &c.tex_coords, weights),
+        }
+    }
+}
+
+impl<'a> VertexShader<StandardUniforms<'a>> for Gouraud {
+    type Varyings = GouraudVaryings;
+
+    fn vertex(
+        &self,
+        uniforms: &StandardUniforms<'a>,
+        vertex: &Vertex,
+    ) -> ShadedVertex<GouraudVaryings> {
+        let (pos, normal) = uniforms.to_world(vertex);
+        let (diffuse, specular) = self.phong.light(uniforms, pos, normal);
+        ShadedVertex {
+            pos: uniforms.to_clip(vertex.pos),
+            varyings: GouraudVaryings {
+                diffuse,
+                specular,
+                tex_coords: vertex.tex_coords,
+            },
+        }
+    }
+}
+
+impl<'a> FragmentShader<StandardUniforms<'a>, GouraudVaryings> for Gouraud {
+    fn fragment(
+        &self,
+        uniforms: &StandardUniforms<'a>,
+        material: Option<&Material>,
+        varyings: &GouraudVaryings,
+        derivatives: &Derivatives<GouraudVaryings>,
+    ) -> Vec3<f32> {
+        let tex_derivatives = derivatives.map(|d| d.tex_coords);
+        uniforms.surface(material, varyings.tex_coords, tex_derivatives) * varyings.diffuse
+            + varyings.specular
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graphics::{
+            shader::tests::{
+                assert_colour, uniforms, vertex, AMBIENT_SPECULAR, DIFFUSE, HEAD_ON, RED,
+            },
+            texture::Texture,
+        },
+        types::vector::{VEC3_X, VEC3_Z, VEC3_ZERO},
+    };
+
+    #[test]
+    fn interpolates_the_light_at_each_vertex() {
+        let texture = Texture::<1, 1>::from_fn(|_, _| RED);
+        let uniforms = uniforms(&HEAD_ON, &texture);
+        let shader = Gouraud::default();
+
+        // Facing the light, then edge on to it
+        let lit = shader.vertex(&uniforms, &vertex(VEC3_ZERO, VEC3_Z));
+        let edge_on = shader.vertex(&uniforms, &vertex(VEC3_ZERO, VEC3_X));
+        assert_colour(lit.varyings.diffuse, [DIFFUSE; 3]);
+        assert_colour(edge_on.varyings.diffuse, [0.0; 3]);
+
+        let varyings = Varyings::interpolate(
+            &lit.varyings,
+            &edge_on.varyings,
+            &edge_on.varyings,
+            [0.5, 0.5, 0.0],
+        );
+        let still = Derivatives {
+            dx: varyings,
+            dy: varyings,
+        }
+        .map(|v| GouraudVaryings {
+            tex_coords: Vec2 { x: 0.0, y: 0.0 },
+            ..v
+        });
+        let ambient = 0.1;
+        let specular = (AMBIENT_SPECULAR - ambient) / 2.0;
+        let colour = shader.fragment(&uniforms, None, &varyings, &still);
+        assert_colour(
+            colour,
+            [
+                DIFFUSE / 2.0 + ambient + specular,
+                ambient + specular,
+                ambient + specular,
+            ],
+        );
+    }
+}
@@ -0,0 +1,361 @@
+pub mod blinn_phong;
+pub mod flat;
+pub mod gouraud;
+pub mod normals;
+pub mod unlit;
+
+use crate::{
+    graphics::{
+        light::Light,
+        texture::{sampler::Sampler, Image, TextureBinding},
+    },
+    math::powi,
+    model::{material::Material, model::Vertex},
+    types::{
+        matrix::Mat4,
+        vector::{Vec2, Vec3, Vec4, VEC3_ZERO},
+    },
+};
+
+/// Values output by a vertex shader and interpolated
+/// across each face for the fragment shader.
+pub trait Varyings: Copy {
+    /// Blends the varyings of three vertices with barycentric
+    /// weights, which sum to 1.
+    fn interpolate(a: &Self, b: &Self, c: &Self, weights: [f32; 3]) -> Self;
+}
+
+impl Varyings for f32 {
+    fn interpolate(a: &Self, b: &Self, c: &Self, [w_a, w_b, w_c]: [f32; 3]) -> Self {
+        a * w_a + b * w_b + c * w_c
+    }
+}
+
+impl Varyings for Vec2<f32> {
+    fn interpolate(a: &Self, b: &Self, c: &Self, [w_a, w_b, w_c]: [f32; 3]) -> Self {
+        *a * w_a + *b * w_b + *c * w_c
+    }
+}
+
+impl Varyings for Vec3<f32> {
+    fn interpolate(a: &Self, b: &Self, c: &Self, [w_a, w_b, w_c]: [f32; 3]) -> Self {
+        *a * w_a + *b * w_b + *c * w_c
+    }
+}
+
+impl Varyings for Vec4<f32> {
+    fn interpolate(a: &Self, b: &Self, c: &Self, [w_a, w_b, w_c]: [f32; 3]) -> Self {
+        *a * w_a + *b * w_b + *c * w_c
+    }
+}
+
+impl Varyings for Vertex {
+    fn interpolate(a: &Self, b: &Self, c: &Self, weights: [f32; 3]) -> Self {
+        Vertex {
+            pos: Varyings::interpolate(&a.pos, &b.pos, &c.pos, weights),
+            tex_coords: Varyings::interpolate(&a.tex_coords, &b.tex_coords, &c.tex_coords, weights),
+            normal: Varyings::interpolate(&a.normal, &b.normal, &c.normal, weights),
+        }
+    }
+}
+
+/// A vertex after the vertex shader has run.
+#[derive(Clone, Copy)]
+pub struct ShadedVertex<V: Varyings> {
+    /// Clip-space position.
+    pub pos: Vec4<f32>,
+    pub varyings: V,
+}
+
+impl<V: Varyings> ShadedVertex<V> {
+    /// Linearly interpolates the position and varyings between
+    /// this vertex (`t` = 0) and `other` (`t` = 1).
+    pub fn lerp(&self, other: &ShadedVertex<V>, t: f32) -> ShadedVertex<V> {
+        ShadedVertex {
+            pos: self.pos * (1.0 - t) + other.pos * t,
+            varyings: V::interpolate(
+                &self.varyings,
+                &other.varyings,
+                &other.varyings,
+                [1.0 - t, t, 0.0],
+            ),
+        }
+    }
+}
+
+/// Runs once per model vertex.
+pub trait VertexShader<U> {
+    type Varyings: Varyings;
+
+    /// Transforms a model-space vertex to clip space
+    /// and computes the varyings for its faces.
+    fn vertex(&self, uniforms: &U, vertex: &Vertex) -> ShadedVertex<Self::Varyings>;
+}
+
+#[derive(Clone, Copy)]
+/// How varyings change between neighbouring pixels, used to pick a
+/// mip level. Found by interpolating with the change in weights, so
+/// only meaningful for varyings that are interpolated linearly.
+pub struct Derivatives<V> {
+    /// Change one pixel to the right.
+    pub dx: V,
+    /// Change one pixel down.
+    pub dy: V,
+}
+
+impl<V> Derivatives<V> {
+    /// Picks out the derivatives of one varying.
+    pub fn map<T>(self, f: impl Fn(V) -> T) -> Derivatives<T> {
+        Derivatives {
+            dx: f(self.dx),
+            dy: f(self.dy),
+        }
+    }
+}
+
+/// Runs once per fragment that passes the depth test.
+pub trait FragmentShader<U, V: Varyings> {
+    /// Returns the colour of a fragment, with components
+    /// from 0 to 1, from its varyings and the material
+    /// of the face it belongs to.
+    fn fragment(
+        &self,
+        uniforms: &U,
+        material: Option<&Material>,
+        varyings: &V,
+        derivatives: &Derivatives<V>,
+    ) -> Vec3<f32>;
+}
+
+#[derive(Clone, Copy)]
+/// Uniforms shared by the built-in shaders.
+pub struct StandardUniforms<'a> {
+    /// Model space to world space.
+    pub model: Mat4<f32>,
+    /// World space to clip space.
+    pub view_proj: Mat4<f32>,
+    /// World-space camera position, for specular highlights.
+    pub camera_pos: Vec3<f32>,
+    /// Lights summed by the lit shaders.
+    pub lights: &'a [Light],
+    /// Drawn on faces without a material, and on materials
+    /// whose diffuse map isn't bound.
+    pub texture: &'a dyn Image,
+    /// Textures for materials with a diffuse map.
+    pub material_textures: &'a [TextureBinding<'a>],
+    /// Wrapping and filtering for every texture.
+    pub sampler: Sampler,
+}
+
+impl<'a> StandardUniforms<'a> {
+    /// Transforms a model-space vertex into world space,
+    /// returning its position and normal.
+    pub fn to_world(&self, vertex: &Vertex) -> (Vec3<f32>, Vec3<f32>) {
+        let pos = (self.model * vertex.pos).to_vec3();
+        let normal = *(self.model * vertex.normal).to_vec3().nor();
+        (pos, normal)
+    }
+
+    /// Transforms a model-space position into clip space.
+    pub fn to_clip(&self, pos: Vec4<f32>) -> Vec4<f32> {
+        self.view_proj * (self.model * pos)
+    }
+
+    /// Samples the texture as a float colour, at a mip
+    /// level chosen from the texture coordinate derivatives.
+    pub fn sample(&self, tex_coords: Vec2<f32>, derivatives: Derivatives<Vec2<f32>>) -> Vec3<f32> {
+        self.sample_image(self.texture, tex_coords, derivatives)
+    }
+
+    fn sample_image(
+        &self,
+        image: &dyn Image,
+        tex_coords: Vec2<f32>,
+        derivatives: Derivatives<Vec2<f32>>,
+    ) -> Vec3<f32> {
+        self.sampler
+            .sample_grad(image, tex_coords, derivatives.dx, derivatives.dy)
+    }
+
+    /// Returns the texture bound to a material's diffuse map,
+    /// falling back to the default texture.
+    pub fn material_texture(&self, material: &Material) -> &'a dyn Image {
+        material
+            .diffuse_map
+            .as_ref()
+            .and_then(|map| self.material_textures.iter().find(|b| b.name == map))
+            .map_or(self.texture, |binding| binding.texture)
+    }
+
+    /// Returns the diffuse colour of a surface. Faces without a
+    /// material use the texture, and materials without a diffuse
+    /// map use their diffuse colour.
+    pub fn surface(
+        &self,
+        material: Option<&Material>,
+        tex_coords: Vec2<f32>,
+        derivatives: Derivatives<Vec2<f32>>,
+    ) -> Vec3<f32> {
+        match material {
+            None => self.sample(tex_coords, derivatives),
+            Some(m) if m.diffuse_map.is_some() => {
+                self.sample_image(self.material_texture(m), tex_coords, derivatives) * m.diffuse
+            }
+            Some(m) => m.diffuse,
+        }
+    }
+}
+
+/// Shading parameters for the Blinn-Phong lighting model.
+#[derive(Clone, Copy)]
+pub struct Phong {
+    pub ambient_colour: Vec3<f32>,
+    pub ambient_factor: f32,
+    pub diffuse_factor: f32,
+    pub specular_colour: Vec3<f32>,
+    pub specular_factor: f32,
+    pub shininess: u8,
+}
+
+impl Default for Phong {
+    fn default() -> Self {
+        Phong {
+            ambient_colour: Vec3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+            ambient_factor: 0.1,
+            diffuse_factor: 0.65,
+            specular_colour: Vec3 {
+                x: 0.1,
+                y: 0.1,
+                z: 0.1,
+            },
+            specular_factor: 0.25,
+            shininess: 32,
+        }
+    }
+}
+
+impl Phong {
+    /// Replaces the ambient and specular colours and the
+    /// shininess with those authored in a material.
+    pub fn with_material(mut self, material: Option<&Material>) -> Phong {
+        if let Some(m) = material {
+            self.ambient_colour = m.ambient;
+            self.specular_colour = m.specular;
+            self.shininess = m.shininess.clamp(0.0, u8::MAX as f32) as u8;
+        }
+        self
+    }
+
+    /// Computes the light reaching the camera from a world-space
+    /// surface point, summed over every light in the scene.
+    /// Returns the diffuse light, to be multiplied by the
+    /// surface colour, and the ambient plus specular light.
+    pub fn light(
+        &self,
+        uniforms: &StandardUniforms,
+        pos: Vec3<f32>,
+        normal: Vec3<f32>,
+    ) -> (Vec3<f32>, Vec3<f32>) {
+        let view_dir = *(uniforms.camera_pos - pos).nor();
+
+        // Ambient
+        let ambient = self.ambient_colour * self.ambient_factor;
+
+        let mut diffuse = VEC3_ZERO;
+        let mut specular = VEC3_ZERO;
+        for light in uniforms.lights {
+            let (light_dir, light_col) = light.incident(pos);
+
+            // Diffuse
+            let lambertian = Vec3::dot(light_dir, normal);
+            if lambertian <= 0.0 {
+                // Lit from behind, so no highlight either
+                continue;
+            }
+            diffuse += light_col * lambertian * self.diffuse_factor;
+
+            // Specular
+            let half_dir = *(light_dir + view_dir).nor();
+            let view_angle = f32::max(Vec3::dot(half_dir, normal), 0.0);
+            specular += self.specular_colour
+                * light_col
+                * powi(view_angle, self.shininess)
+                * self.specular_factor;
+        }
+
+        (diffuse, ambient + specular)
+    }
+}
+
+/// Fixtures shared by the tests of each shader.
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A white light shining along -z, head on
+    /// to surfaces facing the camera.
+    pub(crate) const HEAD_ON: [Light; 1] = [Light::Directional {
+        dir: Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: -1.0,
+        },
+        colour: Vec3 {
+            x: 1.0,
+            y: 1.0,
+            z: 1.0,
+        },
+    }];
+
+    /// With the default `Phong`, a point at the origin facing +z
+    /// and lit by `HEAD_ON` gets this diffuse light...
+    pub(crate) const DIFFUSE: f32 = 0.65;
+    /// ...and this ambient plus specular light, 0.1 + 0.1 * 0.25.
+    pub(crate) const AMBIENT_SPECULAR: f32 = 0.125;
+
+    pub(crate) const RED: Vec3<u8> = Vec3 { x: 255, y: 0, z: 0 };
+
+    /// Uniforms without any transform, seen from (0, 0, 5).
+    pub(crate) fn uniforms<'a>(
+        lights: &'a [Light],
+        texture: &'a dyn Image,
+    ) -> StandardUniforms<'a> {
+        StandardUniforms {
+            model: Mat4::idt(),
+            view_proj: Mat4::idt(),
+            camera_pos: Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 5.0,
+            },
+            lights,
+            texture,
+            material_textures: &[],
+            sampler: Sampler::default(),
+        }
+    }
+
+    pub(crate) fn vertex(pos: Vec3<f32>, normal: Vec3<f32>) -> Vertex {
+        Vertex {
+            pos: Vec4::of(pos, 1.0),
+            tex_coords: Vec2 { x: 0.5, y: 0.5 },
+            normal: Vec4::of(normal, 0.0),
+        }
+    }
+
+    pub(crate) fn assert_colour(actual: Vec3<f32>, expected: [f32; 3]) {
+        let actual = [actual.x, actual.y, actual.z];
+        for (value, expected_value) in actual.iter().zip(expected) {
+            assert!(
+                (value - expected_value).abs() < 1e-5,
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    graphics::shader::{Derivatives, FragmentShader, ShadedVertex, StandardUniforms, VertexShader},
+    model::{material::Material, model::Vertex},
+    types::vector::Vec3,
+};
+
+/// Colours each fragment by its world-space normal,
+/// mapping each axis from -1..1 to 0..1.
+#[derive(Clone, Copy, Default)]
+pub struct NormalShader;
+
+impl<'a> VertexShader<StandardUniforms<'a>> for NormalShader {
+    type Varyings = Vec3<f32>;
+
+    fn vertex(&self, uniforms: &StandardUniforms<'a>, vertex: &Vertex) -> ShadedVertex<Vec3<f32>> {
+        let (_, normal) = uniforms.to_world(vertex);
+        ShadedVertex {
+            pos: uniforms.to_clip(vertex.pos),
+            varyings: normal,
+        }
+    }
+}
+
+impl<'a> FragmentShader<StandardUniforms<'a>, Vec3<f32>> for NormalShader {
+    fn fragment(
+        &self,
+        _uniforms: &StandardUniforms<'a>,
+        _material: Option<&Material>,
+        normal: &Vec3<f32>,
+        _derivatives: &Derivatives<Vec3<f32>>,
+    ) -> Vec3<f32> {
+        let normal = *normal.clone().nor();
+        Vec3 {
+            x: normal.x * 0.5 + 0.5,
+            y: normal.y * 0.5 + 0.5,
+            z: normal.z * 0.5 + 0.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graphics::{
+            shader::tests::{assert_colour, uniforms, vertex},
+            texture::Texture,
+        },
+        types::vector::VEC3_ZERO,
+    };
+
+    #[test]
+    fn maps_each_axis_to_a_channel() {
+        let texture = Texture::<1, 1>::from_fn(|_, _| Vec3 { x: 0, y: 0, z: 0 });
+        let uniforms = uniforms(&[], &texture);
+        let normal = Vec3 {
+            x: 0.0,
+            y: 3.0,
+            z: -4.0,
+        };
+        let varyings = NormalShader
+            .vertex(&uniforms, &vertex(VEC3_ZERO, normal))
+            .varyings;
+        let still = Derivatives {
+            dx: VEC3_ZERO,
+            dy: VEC3_ZERO,
+        };
+
+        // Normalised to (0, 0.6, -0.8) first
+        let colour = NormalShader.fragment(&uniforms, None, &varyings, &still);
+        assert_colour(colour, [0.5, 0.8, 0.1]);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    graphics::shader::{Derivatives, FragmentShader, ShadedVertex, StandardUniforms, VertexShader},
+    model::{material::Material, model::Vertex},
+    types::vector::{Vec2, Vec3},
+};
+
+/// Draws the texture, or the face material's
+/// diffuse colour, without any lighting.
+#[derive(Clone, Copy, Default)]
+pub struct UnlitTexture;
+
+impl<'a> VertexShader<StandardUniforms<'a>> for UnlitTexture {
+    type Varyings = Vec2<f32>;
+
+    fn vertex(&self, uniforms: &StandardUniforms<'a>, vertex: &Vertex) -> ShadedVertex<Vec2<f32>> {
+        ShadedVertex {
+            pos: uniforms.to_clip(vertex.pos),
+            varyings: vertex.tex_coords,
+        }
+    }
+}
+
+impl<'a> FragmentShader<StandardUniforms<'a>, Vec2<f32>> for UnlitTexture {
+    fn fragment(
+        &self,
+        uniforms: &StandardUniforms<'a>,
+        material: Option<&Material>,
+        tex_coords: &Vec2<f32>,
+        derivatives: &Derivatives<Vec2<f32>>,
+    ) -> Vec3<f32> {
+        uniforms.surface(material, *tex_coords, *derivatives)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graphics::{
+            shader::tests::{assert_colour, uniforms, vertex, HEAD_ON, RED},
+            texture::Texture,
+        },
+        types::vector::{VEC3_Z, VEC3_ZERO},
+    };
+
+    #[test]
+    fn draws_the_texture_or_material_unlit() {
+        let texture = Texture::<1, 1>::from_fn(|_, _| RED);
+        let uniforms = uniforms(&HEAD_ON, &texture);
+        let tex_coords = UnlitTexture
+            .vertex(&uniforms, &vertex(VEC3_ZERO, VEC3_Z))
+            .varyings;
+        let still = Derivatives {
+            dx: Vec2 { x: 0.0, y: 0.0 },
+            dy: Vec2 { x: 0.0, y: 0.0 },
+        };
+
+        let colour = UnlitTexture.fragment(&uniforms, None, &tex_coords, &still);
+        assert_colour(colour, [1.0, 0.0, 0.0]);
+
+        let material = Material {
+            diffuse: Vec3 {
+                x: 0.0,
+                y: 0.5,
+                z: 1.0,
+            },
+            ..Material::new("blue")
+        };
+        let colour = UnlitTexture.fragment(&uniforms, Some(&material), &tex_coords, &still);
+        assert_colour(colour, [0.0, 0.5, 1.0]);
+    }
+}
@@ -0,0 +1,89 @@
+use defmt::Format;
+
+use crate::{
+    graphics::texture::{
+        format::{PixelFormat, Rgb565, Rgb888},
+        Image,
+    },
+    types::vector::Vec3,
+};
+
+#[derive(Clone, Copy, PartialEq, Format)]
+/// How the texels of a raw image are packed.
+pub enum RawFormat {
+    /// 16-bit little-endian, 5 bits of red, 6 of green and 5 of blue.
+    Rgb565,
+    /// One byte each of red, green and blue.
+    Rgb888,
+}
+
+impl RawFormat {
+    /// Bytes taken up by each texel.
+    pub const fn texel_size(self) -> usize {
+        match self {
+            RawFormat::Rgb565 => 2,
+            RawFormat::Rgb888 => 3,
+        }
+    }
+
+    /// Unpacks the texel starting at `bytes[0]`.
+    pub fn decode(self, bytes: &[u8]) -> Vec3<u8> {
+        match self {
+            RawFormat::Rgb565 => {
+                Rgb565::decode(u16::from_le_bytes([bytes[0], bytes[1]]) as u32, &())
+            }
+            RawFormat::Rgb888 => {
+                Rgb888::decode(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]), &())
+            }
+        }
+    }
+}
+
+/// A texture converted from a PNG file by `build.rs`. Like
+/// `BakedMesh`, the texels stay in flash and are unpacked
+/// as they're read. Every mip level is baked, one after another.
+#[derive(Clone, Copy)]
+pub struct BakedTexture {
+    pub width: usize,
+    pub height: usize,
+    pub format: RawFormat,
+    /// The texel each mip level starts at, one entry per level.
+    pub level_offsets: &'static [usize],
+    /// Rows from the bottom of each level up.
+    pub data: &'static [u8],
+}
+
+impl Image for BakedTexture {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn texel(&self, x: usize, y: usize) -> Vec3<u8> {
+        self.level_texel(0, x, y)
+    }
+
+    fn levels(&self) -> usize {
+        self.level_offsets.len()
+    }
+
+    fn level_texel(&self, level: usize, x: usize, y: usize) -> Vec3<u8> {
+        let (width, _) = self.level_size(level);
+        let index = self.level_offsets[level] + y * width + x;
+        self.format
+            .decode(&self.data[index * self.format.texel_size()..])
+    }
+}
+
+/// Textures baked from the PNG files in `assets/`, named after
+/// each file in upper case. Every file is baked as both RGB565
+/// and, with an `_RGB888` suffix, RGB888; the linker drops
+/// whichever isn't used.
+pub mod textures {
+    use super::{BakedTexture, RawFormat};
+
+    include!(concat!(env!("OUT_DIR"), "/baked_textures.rs"));
+}
@@ -0,0 +1,268 @@
+use crate::types::vector::Vec3;
+
+/// Returns the bytes needed to store a `width`x`height`
+/// texture with `bits` per texel.
+pub const fn texture_bytes(width: usize, height: usize, bits: usize) -> usize {
+    (width * height * bits).div_ceil(8)
+}
+
+/// Stores the low `P::BITS` of `bits` as the texel at `index`.
+pub(crate) fn write_texel<P: PixelFormat>(buffer: &mut [u8], index: usize, bits: u32) {
+    let start = index * P::BITS;
+    if P::BITS < 8 {
+        let shift = start % 8;
+        let mask = ((1u32 << P::BITS) - 1) as u8;
+        let byte = &mut buffer[start / 8];
+        *byte = (*byte & !(mask << shift)) | ((bits as u8 & mask) << shift);
+    } else {
+        let bytes = bits.to_le_bytes();
+        buffer[start / 8..(start + P::BITS) / 8].copy_from_slice(&bytes[..P::BITS / 8]);
+    }
+}
+
+/// Reads the packed bits of the texel at `index`.
+pub(crate) fn read_texel<P: PixelFormat>(buffer: &[u8], index: usize) -> u32 {
+    let start = index * P::BITS;
+    if P::BITS < 8 {
+        (buffer[start / 8] >> (start % 8)) as u32 & ((1 << P::BITS) - 1)
+    } else {
+        let mut bytes = [0; 4];
+        bytes[..P::BITS / 8].copy_from_slice(&buffer[start / 8..(start + P::BITS) / 8]);
+        u32::from_le_bytes(bytes)
+    }
+}
+
+/// How a texture packs each texel into memory. Texels are stored
+/// one after another; formats under 8 bits share bytes starting
+/// from the low bits, and wider ones are little-endian.
+pub trait PixelFormat {
+    /// Bits taken by each texel: 4, 8, 16 or 24.
+    const BITS: usize;
+
+    /// Colours that indexed formats choose from.
+    /// `()` for formats that store colours directly.
+    type Palette: Copy;
+
+    /// Packs a colour into the low `BITS` bits. Indexed
+    /// formats store the closest colour in the palette.
+    fn encode(colour: Vec3<u8>, palette: &Self::Palette) -> u32;
+
+    /// Unpacks a colour from the low `BITS` bits.
+    fn decode(bits: u32, palette: &Self::Palette) -> Vec3<u8>;
+}
+
+/// 8 bits each of red, green and blue.
+pub struct Rgb888;
+
+impl PixelFormat for Rgb888 {
+    const BITS: usize = 24;
+    type Palette = ();
+
+    fn encode(colour: Vec3<u8>, _palette: &()) -> u32 {
+        colour.x as u32 | (colour.y as u32) << 8 | (colour.z as u32) << 16
+    }
+
+    fn decode(bits: u32, _palette: &()) -> Vec3<u8> {
+        Vec3 {
+            x: bits as u8,
+            y: (bits >> 8) as u8,
+            z: (bits >> 16) as u8,
+        }
+    }
+}
+
+/// 5 bits of red, 6 of green and 5 of blue, with red in the high bits.
+pub struct Rgb565;
+
+impl PixelFormat for Rgb565 {
+    const BITS: usize = 16;
+    type Palette = ();
+
+    fn encode(colour: Vec3<u8>, _palette: &()) -> u32 {
+        (colour.x as u32 >> 3) << 11 | (colour.y as u32 >> 2) << 5 | colour.z as u32 >> 3
+    }
+
+    fn decode(bits: u32, _palette: &()) -> Vec3<u8> {
+        Vec3 {
+            x: expand((bits >> 11) as u8 & 0x1f, 5),
+            y: expand((bits >> 5) as u8 & 0x3f, 6),
+            z: expand(bits as u8 & 0x1f, 5),
+        }
+    }
+}
+
+/// 3 bits of red, 3 of green and 2 of blue, with red in the high bits.
+pub struct Rgb332;
+
+impl PixelFormat for Rgb332 {
+    const BITS: usize = 8;
+    type Palette = ();
+
+    fn encode(colour: Vec3<u8>, _palette: &()) -> u32 {
+        (colour.x as u32 >> 5) << 5 | (colour.y as u32 >> 5) << 2 | colour.z as u32 >> 6
+    }
+
+    fn decode(bits: u32, _palette: &()) -> Vec3<u8> {
+        Vec3 {
+            x: expand((bits >> 5) as u8 & 0x7, 3),
+            y: expand((bits >> 2) as u8 & 0x7, 3),
+            z: expand(bits as u8 & 0x3, 2),
+        }
+    }
+}
+
+/// 8 bits of luminance.
+pub struct Grey8;
+
+impl PixelFormat for Grey8 {
+    const BITS: usize = 8;
+    type Palette = ();
+
+    fn encode(colour: Vec3<u8>, _palette: &()) -> u32 {
+        // Rec. 601 luma weights, scaled to sum to 256
+        (colour.x as u32 * 77 + colour.y as u32 * 150 + colour.z as u32 * 29) >> 8
+    }
+
+    fn decode(bits: u32, _palette: &()) -> Vec3<u8> {
+        let grey = bits as u8;
+        Vec3 {
+            x: grey,
+            y: grey,
+            z: grey,
+        }
+    }
+}
+
+/// An index into a palette of 256 colours.
+pub struct Indexed8;
+
+impl PixelFormat for Indexed8 {
+    const BITS: usize = 8;
+    type Palette = [Vec3<u8>; 256];
+
+    fn encode(colour: Vec3<u8>, palette: &Self::Palette) -> u32 {
+        closest(colour, palette)
+    }
+
+    fn decode(bits: u32, palette: &Self::Palette) -> Vec3<u8> {
+        palette[bits as usize & 0xff]
+    }
+}
+
+/// An index into a palette of 16 colours.
+pub struct Indexed4;
+
+impl PixelFormat for Indexed4 {
+    const BITS: usize = 4;
+    type Palette = [Vec3<u8>; 16];
+
+    fn encode(colour: Vec3<u8>, palette: &Self::Palette) -> u32 {
+        closest(colour, palette)
+    }
+
+    fn decode(bits: u32, palette: &Self::Palette) -> Vec3<u8> {
+        palette[bits as usize & 0xf]
+    }
+}
+
+/// Widens a channel of `bits` bits to 8, copying the high bits
+/// into the low ones so full intensity stays at 255.
+fn expand(value: u8, bits: u32) -> u8 {
+    let mut out = value << (8 - bits);
+    let mut filled = bits;
+    while filled < 8 {
+        out |= out >> filled;
+        filled *= 2;
+    }
+    out
+}
+
+/// Returns the index of the palette colour nearest to `colour`.
+fn closest(colour: Vec3<u8>, palette: &[Vec3<u8>]) -> u32 {
+    let distance = |entry: &Vec3<u8>| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(entry.x, colour.x) + d(entry.y, colour.y) + d(entry.z, colour.z)
+    };
+    palette
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, entry)| distance(entry))
+        .map_or(0, |(i, _)| i as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(x: u8, y: u8, z: u8) -> Vec3<u8> {
+        Vec3 { x, y, z }
+    }
+
+    type RoundTrip = fn(Vec3<u8>) -> (u8, u8, u8);
+
+    /// Encodes and decodes a colour, as storing it in a texture does.
+    fn round_trip<P: PixelFormat>(colour: Vec3<u8>, palette: &P::Palette) -> (u8, u8, u8) {
+        let bits = P::encode(colour, palette);
+        assert!(P::BITS == 32 || bits >> P::BITS == 0, "bits past BITS");
+        let decoded = P::decode(bits, palette);
+        (decoded.x, decoded.y, decoded.z)
+    }
+
+    #[test]
+    fn direct_formats_keep_black_white_and_their_precision() {
+        let colour = rgb(0x12, 0x9a, 0xfe);
+        assert_eq!(round_trip::<Rgb888>(colour, &()), (0x12, 0x9a, 0xfe));
+        // Kept bits are copied into the dropped ones
+        assert_eq!(round_trip::<Rgb565>(colour, &()), (0x10, 0x9a, 0xff));
+        assert_eq!(round_trip::<Rgb332>(colour, &()), (0x00, 0x92, 0xff));
+
+        let formats: [(&str, RoundTrip); 4] = [
+            ("Rgb888", |c| round_trip::<Rgb888>(c, &())),
+            ("Rgb565", |c| round_trip::<Rgb565>(c, &())),
+            ("Rgb332", |c| round_trip::<Rgb332>(c, &())),
+            ("Grey8", |c| round_trip::<Grey8>(c, &())),
+        ];
+        for (name, trip) in formats {
+            assert_eq!(trip(rgb(0, 0, 0)), (0, 0, 0), "{}", name);
+            assert_eq!(trip(rgb(255, 255, 255)), (255, 255, 255), "{}", name);
+        }
+    }
+
+    #[test]
+    fn grey_uses_luma_weights() {
+        let (grey, _, _) = round_trip::<Grey8>(rgb(0, 255, 0), &());
+        assert_eq!(grey, 149);
+        assert_eq!(round_trip::<Grey8>(rgb(90, 90, 90), &()), (90, 90, 90));
+    }
+
+    #[test]
+    fn indexed_formats_store_the_closest_colour() {
+        let mut palette = [rgb(0, 0, 0); 16];
+        palette[3] = rgb(200, 0, 0);
+        palette[9] = rgb(0, 0, 200);
+        assert_eq!(Indexed4::encode(rgb(180, 20, 10), &palette), 3);
+        assert_eq!(
+            round_trip::<Indexed4>(rgb(10, 0, 250), &palette),
+            (0, 0, 200)
+        );
+        assert_eq!(round_trip::<Indexed4>(rgb(20, 20, 20), &palette), (0, 0, 0));
+    }
+
+    #[test]
+    fn packed_texels_leave_their_neighbours_alone() {
+        let mut buffer = [0u8; texture_bytes(5, 1, 4)];
+        for i in 0..5 {
+            write_texel::<Indexed4>(&mut buffer, i, i as u32 + 10);
+        }
+        write_texel::<Indexed4>(&mut buffer, 2, 1);
+        let texels = [0, 1, 2, 3, 4].map(|i| read_texel::<Indexed4>(&buffer, i));
+        assert_eq!(texels, [10, 11, 1, 13, 14]);
+        // Low nibble first
+        assert_eq!(buffer[0], 0xba);
+
+        let mut buffer = [0u8; texture_bytes(2, 1, 24)];
+        write_texel::<Rgb888>(&mut buffer, 1, 0x00ab_cdef);
+        assert_eq!(buffer, [0, 0, 0, 0xef, 0xcd, 0xab]);
+        assert_eq!(read_texel::<Rgb888>(&buffer, 1), 0x00ab_cdef);
+    }
+}
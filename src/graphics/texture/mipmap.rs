@@ -0,0 +1,230 @@
+use defmt::debug;
+use heapless::Vec;
+
+use crate::{
+    graphics::texture::{
+        format::{read_texel, texture_bytes, write_texel, PixelFormat, Rgb888},
+        Image, TextureError,
+    },
+    types::vector::Vec3,
+};
+
+/// The most levels a mip chain can have, one per bit of its size.
+pub const MAX_LEVELS: usize = usize::BITS as usize;
+
+/// Returns the size of a mip level. Each level halves the one
+/// before, rounding down, but never goes below 1.
+pub const fn level_size(width: usize, height: usize, level: usize) -> (usize, usize) {
+    let (width, height) = (width >> level, height >> level);
+    (
+        if width == 0 { 1 } else { width },
+        if height == 0 { 1 } else { height },
+    )
+}
+
+/// Returns the number of levels in a chain down to 1x1.
+pub const fn level_count(width: usize, height: usize) -> usize {
+    let mut levels = 1;
+    while (width >> levels) > 0 || (height >> levels) > 0 {
+        levels += 1;
+    }
+    levels
+}
+
+/// Returns how many texels come before each level in a chain
+/// stored one level after another. Entries from `level_count`
+/// on hold the size of the whole chain.
+pub const fn level_offsets(width: usize, height: usize) -> [usize; MAX_LEVELS + 1] {
+    let levels = level_count(width, height);
+    let mut offsets = [0; MAX_LEVELS + 1];
+    let mut i = 0;
+    while i < MAX_LEVELS {
+        let (w, h) = level_size(width, height, i);
+        offsets[i + 1] = offsets[i] + if i < levels { w * h } else { 0 };
+        i += 1;
+    }
+    offsets
+}
+
+/// Returns the number of texels in a full chain.
+pub const fn mip_capacity(width: usize, height: usize) -> usize {
+    level_offsets(width, height)[level_count(width, height)]
+}
+
+/// Averages the 2x2 block of texels in the level above that
+/// covers a texel of the next level. Blocks at the edge of
+/// an odd-sized level repeat the last row or column.
+pub fn box_filter(
+    texel: impl Fn(usize, usize) -> Vec3<u8>,
+    (width, height): (usize, usize),
+    x: usize,
+    y: usize,
+) -> Vec3<u8> {
+    let (x0, y0) = ((x * 2).min(width - 1), (y * 2).min(height - 1));
+    let (x1, y1) = ((x0 + 1).min(width - 1), (y0 + 1).min(height - 1));
+    let block = [texel(x0, y0), texel(x1, y0), texel(x0, y1), texel(x1, y1)];
+    let average =
+        |c: fn(&Vec3<u8>) -> u8| ((block.iter().map(|t| c(t) as u16).sum::<u16>() + 2) / 4) as u8;
+    Vec3 {
+        x: average(|t| t.x),
+        y: average(|t| t.y),
+        z: average(|t| t.z),
+    }
+}
+
+/// A texture stored with every mip level, generated when loaded,
+/// so minified surfaces can be sampled without aliasing. Takes a
+/// third more memory than a single-level texture. Texels are
+/// packed in the pixel format `P`, as in `Texture`.
+pub struct Mipmapped<const W: usize, const H: usize, P: PixelFormat = Rgb888>
+where
+    [(); texture_bytes(mip_capacity(W, H), 1, P::BITS)]:,
+{
+    buffer: Vec<u8, { texture_bytes(mip_capacity(W, H), 1, P::BITS) }>,
+    palette: P::Palette,
+}
+
+impl<const W: usize, const H: usize, P: PixelFormat> Mipmapped<W, H, P>
+where
+    [(); texture_bytes(mip_capacity(W, H), 1, P::BITS)]:,
+{
+    /// Where each level starts, worked out once per texture size.
+    const OFFSETS: [usize; MAX_LEVELS + 1] = level_offsets(W, H);
+
+    /// Copies an image of the same size as level 0, converting it to
+    /// the closest colours in `palette`, and box filters each level
+    /// from the one above it. Levels are filtered after packing, so
+    /// formats with few colours round each level again.
+    pub fn from_image_with_palette(
+        image: &dyn Image,
+        palette: P::Palette,
+    ) -> Result<Mipmapped<W, H, P>, TextureError> {
+        if image.width() != W || image.height() != H {
+            return Err(TextureError::SizeMismatch {
+                width: image.width(),
+                height: image.height(),
+            });
+        }
+        debug!("Generating {} mip levels", level_count(W, H));
+
+        let mut tex: Mipmapped<W, H, P> = Mipmapped {
+            buffer: Vec::new(),
+            palette,
+        };
+        let _ = tex
+            .buffer
+            .resize(texture_bytes(mip_capacity(W, H), 1, P::BITS), 0);
+        for y in 0..H {
+            for x in 0..W {
+                tex.set(0, x, y, image.texel(x, y));
+            }
+        }
+        for level in 1..level_count(W, H) {
+            let above = level_size(W, H, level - 1);
+            let (width, height) = level_size(W, H, level);
+            for y in 0..height {
+                for x in 0..width {
+                    let texel = box_filter(|x, y| tex.level_texel(level - 1, x, y), above, x, y);
+                    tex.set(level, x, y, texel);
+                }
+            }
+        }
+        Ok(tex)
+    }
+
+    pub fn palette(&self) -> &P::Palette {
+        &self.palette
+    }
+
+    /// Packs a colour into a texel of a mip level.
+    fn set(&mut self, level: usize, x: usize, y: usize, colour: Vec3<u8>) {
+        let bits = P::encode(colour, &self.palette);
+        write_texel::<P>(&mut self.buffer, Self::index(level, x, y), bits);
+    }
+
+    /// Returns the position of a texel in the chain.
+    fn index(level: usize, x: usize, y: usize) -> usize {
+        let (width, _) = level_size(W, H, level);
+        Self::OFFSETS[level] + y * width + x
+    }
+}
+
+impl<const W: usize, const H: usize, P: PixelFormat<Palette = ()>> Mipmapped<W, H, P>
+where
+    [(); texture_bytes(mip_capacity(W, H), 1, P::BITS)]:,
+{
+    /// Copies an image of the same size as level 0 and
+    /// box filters each level from the one above it.
+    pub fn from_image(image: &dyn Image) -> Result<Mipmapped<W, H, P>, TextureError> {
+        Self::from_image_with_palette(image, ())
+    }
+}
+
+impl<const W: usize, const H: usize, P: PixelFormat> Image for Mipmapped<W, H, P>
+where
+    [(); texture_bytes(mip_capacity(W, H), 1, P::BITS)]:,
+{
+    fn width(&self) -> usize {
+        W
+    }
+
+    fn height(&self) -> usize {
+        H
+    }
+
+    fn texel(&self, x: usize, y: usize) -> Vec3<u8> {
+        self.level_texel(0, x, y)
+    }
+
+    fn levels(&self) -> usize {
+        level_count(W, H)
+    }
+
+    fn level_texel(&self, level: usize, x: usize, y: usize) -> Vec3<u8> {
+        let bits = read_texel::<P>(&self.buffer, Self::index(level, x, y));
+        P::decode(bits, &self.palette)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::texture::{
+        format::Rgb565,
+        procedural::{checkerboard, BLACK, WHITE},
+        Texture,
+    };
+
+    #[test]
+    fn offsets_follow_each_level() {
+        // Levels of 8x2, 4x1, 2x1 and 1x1
+        let offsets = level_offsets(8, 2);
+        assert_eq!(offsets[..5], [0, 16, 20, 22, 23]);
+        assert_eq!(offsets[MAX_LEVELS], 23);
+        assert_eq!(mip_capacity(8, 2), 23);
+        assert_eq!(mip_capacity(16, 16), 341);
+    }
+
+    #[test]
+    fn packed_levels_average_the_level_above() {
+        let image = Texture::<8, 2>::from_fn(checkerboard(1, BLACK, WHITE));
+        let mip = Mipmapped::<8, 2, Rgb565>::from_image(&image).unwrap();
+        assert_eq!(mip.levels(), 4);
+        assert_eq!([mip.texel(0, 0).x, mip.texel(1, 0).x], [0, 255]);
+        for level in 1..mip.levels() {
+            let (width, height) = mip.level_size(level);
+            for y in 0..height {
+                for x in 0..width {
+                    // Half black and half white, rounded to 5 and 6 bits
+                    let texel = mip.level_texel(level, x, y);
+                    assert!(
+                        (123..=132).contains(&texel.x),
+                        "level {}: {}",
+                        level,
+                        texel.x
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use defmt::{info, Format};
+use heapless::Vec;
+
+use crate::{
+    graphics::texture::format::{read_texel, texture_bytes, write_texel, PixelFormat, Rgb888},
+    types::vector::{Vec2, Vec3},
+};
+
+pub mod baked;
+pub mod format;
+pub mod mipmap;
+pub mod ppm;
+pub mod procedural;
+pub mod sampler;
+pub mod tga;
+
+#[derive(Debug, Clone, Copy, PartialEq, Format)]
+/// An error from loading an image into a texture.
+pub enum TextureError {
+    /// The data doesn't start with a header of the expected format.
+    BadHeader,
+    /// A valid image in a variant the loader can't read,
+    /// such as colour-mapped TGA or 16-bit PPM.
+    Unsupported,
+    /// The image isn't the same size as the texture.
+    SizeMismatch { width: usize, height: usize },
+    /// The data ended before every pixel was read.
+    Truncated,
+}
+
+/// Read access to the texels of a texture, whatever its size and storage.
+///
+/// Row 0 is the bottom of the image, matching texture coordinates
+/// where v increases upwards.
+pub trait Image {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    /// Returns the texel at a column and row within the image.
+    fn texel(&self, x: usize, y: usize) -> Vec3<u8>;
+
+    /// Number of mip levels, counting the full image as level 0.
+    fn levels(&self) -> usize {
+        1
+    }
+
+    /// Returns a texel from a mip level. Images without
+    /// mipmaps only have level 0.
+    fn level_texel(&self, _level: usize, x: usize, y: usize) -> Vec3<u8> {
+        self.texel(x, y)
+    }
+
+    /// Returns the width and height of a mip level.
+    fn level_size(&self, level: usize) -> (usize, usize) {
+        mipmap::level_size(self.width(), self.height(), level)
+    }
+
+    /// Returns the texel containing `tex_coords`, or `None`
+    /// if they fall outside [0, 1). Use a `Sampler` to wrap
+    /// or filter.
+    fn sample(&self, tex_coords: Vec2<f32>) -> Option<Vec3<u8>> {
+        if tex_coords.x < 0.0 || tex_coords.y < 0.0 {
+            return None;
+        }
+        let x = (tex_coords.x * self.width() as f32) as usize;
+        let y = (tex_coords.y * self.height() as f32) as usize;
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+        Some(self.texel(x, y))
+    }
+}
+
+#[derive(Clone, Copy)]
+/// Binds a texture to the materials whose diffuse map names it.
+pub struct TextureBinding<'a> {
+    /// The `map_Kd` file name, as written in the MTL file.
+    pub name: &'a str,
+    pub texture: &'a dyn Image,
+}
+
+/// A texture held in RAM, with texels packed in the pixel
+/// format `P`. Smaller formats trade colour depth for room
+/// to fit larger textures.
+pub struct Texture<const W: usize, const H: usize, P: PixelFormat = Rgb888>
+where
+    [(); texture_bytes(W, H, P::BITS)]:,
+{
+    buffer: Vec<u8, { texture_bytes(W, H, P::BITS) }>,
+    palette: P::Palette,
+}
+
+impl<const W: usize, const H: usize, P: PixelFormat> Texture<W, H, P>
+where
+    [(); texture_bytes(W, H, P::BITS)]:,
+{
+    /// Creates a texture by calling `f` with the column and row of
+    /// every texel. Indexed formats store the closest palette colour.
+    pub fn from_fn_with_palette(
+        palette: P::Palette,
+        mut f: impl FnMut(usize, usize) -> Vec3<u8>,
+    ) -> Texture<W, H, P> {
+        let mut tex: Texture<W, H, P> = Texture {
+            buffer: Vec::new(),
+            palette,
+        };
+        let _ = tex.buffer.resize(texture_bytes(W, H, P::BITS), 0);
+        for y in 0..H {
+            for x in 0..W {
+                tex.set(x, y, f(x, y));
+            }
+        }
+        tex
+    }
+
+    /// Copies an image of the same size, converting it to the
+    /// closest colours in `palette`.
+    pub fn from_image_with_palette(
+        image: &dyn Image,
+        palette: P::Palette,
+    ) -> Result<Texture<W, H, P>, TextureError> {
+        Self::check_size(image.width(), image.height())?;
+        Ok(Texture::from_fn_with_palette(palette, |x, y| {
+            image.texel(x, y)
+        }))
+    }
+
+    /// Checks that an image being loaded is the size of the texture.
+    fn check_size(width: usize, height: usize) -> Result<(), TextureError> {
+        if width == W && height == H {
+            Ok(())
+        } else {
+            Err(TextureError::SizeMismatch { width, height })
+        }
+    }
+
+    pub fn palette(&self) -> &P::Palette {
+        &self.palette
+    }
+
+    /// Packs a colour into the texel at a column and row.
+    pub fn set(&mut self, x: usize, y: usize, colour: Vec3<u8>) {
+        let bits = P::encode(colour, &self.palette);
+        write_texel::<P>(&mut self.buffer, y * W + x, bits);
+    }
+
+    pub fn sample_pixels(&self, x: usize, y: usize) -> Option<Vec3<u8>> {
+        if x >= W || y >= H {
+            return None;
+        }
+        Some(self.texel(x, y))
+    }
+
+    pub fn sample_f(&self, x: f32, y: f32) -> Option<Vec3<u8>> {
+        Image::sample(self, Vec2 { x, y })
+    }
+
+    pub fn sample(&self, tex_coords: Vec2<f32>) -> Option<Vec3<u8>> {
+        Image::sample(self, tex_coords)
+    }
+}
+
+impl<const W: usize, const H: usize, P: PixelFormat<Palette = ()>> Texture<W, H, P>
+where
+    [(); texture_bytes(W, H, P::BITS)]:,
+{
+    /// Creates a texture by calling `f` with the column
+    /// and row of every texel.
+    pub fn from_fn(f: impl FnMut(usize, usize) -> Vec3<u8>) -> Texture<W, H, P> {
+        Texture::from_fn_with_palette((), f)
+    }
+
+    /// Copies an image of the same size into RAM, such as a
+    /// `BakedTexture` that is sampled often.
+    pub fn from_image(image: &dyn Image) -> Result<Texture<W, H, P>, TextureError> {
+        Self::from_image_with_palette(image, ())
+    }
+
+    /// Generates a black and white checkerboard of 8x8 cells.
+    pub fn gen_checkerboard() -> Texture<W, H, P> {
+        Self::gen_checkerboard_with(8, procedural::BLACK, procedural::WHITE)
+    }
+
+    /// Generates a checkerboard of `cell`x`cell` squares
+    /// in two colours, starting with `a` in the bottom left.
+    /// A `cell` of 0 is taken as 1.
+    pub fn gen_checkerboard_with(cell: usize, a: Vec3<u8>, b: Vec3<u8>) -> Texture<W, H, P> {
+        info!("Generating checkerboard texture {:?}x{:?}", W, H);
+        Texture::from_fn(procedural::checkerboard(cell, a, b))
+    }
+
+    /// Generates a pattern showing texture coordinates,
+    /// for checking how surfaces are mapped.
+    pub fn gen_uv_debug() -> Texture<W, H, P> {
+        info!("Generating UV debug texture {:?}x{:?}", W, H);
+        Texture::from_fn(procedural::uv_debug((W, H)))
+    }
+}
+
+impl<const W: usize, const H: usize, P: PixelFormat> Image for Texture<W, H, P>
+where
+    [(); texture_bytes(W, H, P::BITS)]:,
+{
+    fn width(&self) -> usize {
+        W
+    }
+
+    fn height(&self) -> usize {
+        H
+    }
+
+    fn texel(&self, x: usize, y: usize) -> Vec3<u8> {
+        P::decode(read_texel::<P>(&self.buffer, y * W + x), &self.palette)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a texel's column and row in its colour.
+    fn position(x: usize, y: usize) -> Vec3<u8> {
+        Vec3 {
+            x: x as u8,
+            y: y as u8,
+            z: 0,
+        }
+    }
+
+    #[test]
+    fn sample_pixels_uses_rows_of_width_texels() {
+        // Rows were strided by the height, which only shows
+        // when the texture isn't square
+        let tex = Texture::<4, 2>::from_fn(position);
+        for y in 0..2 {
+            for x in 0..4 {
+                let texel = tex.sample_pixels(x, y).unwrap();
+                assert_eq!((texel.x, texel.y), (x as u8, y as u8));
+            }
+        }
+    }
+
+    #[test]
+    fn sample_pixels_rejects_texels_outside() {
+        let tex = Texture::<4, 2>::from_fn(position);
+        assert!(tex.sample_pixels(4, 0).is_none());
+        assert!(tex.sample_pixels(0, 2).is_none());
+        assert!(tex.sample_pixels(3, 1).is_some());
+    }
+}
@@ -0,0 +1,137 @@
+use defmt::debug;
+
+use crate::{
+    graphics::texture::{
+        format::{texture_bytes, PixelFormat},
+        Texture, TextureError,
+    },
+    types::vector::Vec3,
+};
+
+impl<const W: usize, const H: usize, P: PixelFormat<Palette = ()>> Texture<W, H, P>
+where
+    [(); texture_bytes(W, H, P::BITS)]:,
+{
+    /// Loads a binary (`P6`) PPM image, which must be the size of the
+    /// texture. Channels of up to 8 bits are supported.
+    pub fn from_ppm(data: &[u8]) -> Result<Texture<W, H, P>, TextureError> {
+        let mut header = Header { data, pos: 0 };
+        if header.token() != Some(b"P6") {
+            return Err(TextureError::BadHeader);
+        }
+        let width = header.number()?;
+        let height = header.number()?;
+        let max = header.number()?;
+        if max == 0 || max > 255 {
+            return Err(TextureError::Unsupported);
+        }
+        Self::check_size(width, height)?;
+        debug!("Loading {}x{} PPM", width, height);
+
+        // A single whitespace byte separates the header from the pixels
+        let pixels = data
+            .get(header.pos + 1..)
+            .filter(|pixels| pixels.len() >= W * H * 3)
+            .ok_or(TextureError::Truncated)?;
+        let scale = |c: u8| (c.min(max as u8) as usize * 255 / max) as u8;
+
+        // PPM rows run top to bottom
+        Ok(Texture::from_fn(|x, y| {
+            let i = ((H - 1 - y) * W + x) * 3;
+            Vec3 {
+                x: scale(pixels[i]),
+                y: scale(pixels[i + 1]),
+                z: scale(pixels[i + 2]),
+            }
+        }))
+    }
+}
+
+/// Reads whitespace-separated header fields, skipping comments.
+struct Header<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Header<'a> {
+    fn token(&mut self) -> Option<&'a [u8]> {
+        loop {
+            match self.data.get(self.pos)? {
+                b'#' => {
+                    while self.data.get(self.pos).is_some_and(|&c| c != b'\n') {
+                        self.pos += 1;
+                    }
+                }
+                c if c.is_ascii_whitespace() => self.pos += 1,
+                _ => break,
+            }
+        }
+        let start = self.pos;
+        while self
+            .data
+            .get(self.pos)
+            .is_some_and(|c| !c.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+        Some(&self.data[start..self.pos])
+    }
+
+    fn number(&mut self) -> Result<usize, TextureError> {
+        let token = self.token().ok_or(TextureError::Truncated)?;
+        core::str::from_utf8(token)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or(TextureError::BadHeader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::texture::Image;
+
+    fn rgb(tex: &Texture<2, 2>, x: usize, y: usize) -> (u8, u8, u8) {
+        let texel = tex.texel(x, y);
+        (texel.x, texel.y, texel.z)
+    }
+
+    #[test]
+    fn comments_are_skipped_and_rows_run_top_down() {
+        let ppm = b"P6 # made by hand\n2 # wide\n2\n# tall\n255\n\
+            \x01\x02\x03\x04\x05\x06\
+            \x07\x08\x09\x0a\x0b\x0c";
+        let tex = Texture::<2, 2>::from_ppm(ppm).unwrap();
+        assert_eq!(rgb(&tex, 0, 1), (1, 2, 3));
+        assert_eq!(rgb(&tex, 1, 1), (4, 5, 6));
+        assert_eq!(rgb(&tex, 0, 0), (7, 8, 9));
+        assert_eq!(rgb(&tex, 1, 0), (10, 11, 12));
+    }
+
+    #[test]
+    fn channels_are_scaled_to_8_bits() {
+        // Values above the maximum are clamped to it
+        let ppm = b"P6 2 2 15\n\x00\x05\x0f\x0f\x0f\x0f\x0f\x0f\x0f\xff\x0a\x01";
+        let tex = Texture::<2, 2>::from_ppm(ppm).unwrap();
+        assert_eq!(rgb(&tex, 0, 1), (0, 85, 255));
+        assert_eq!(rgb(&tex, 1, 0), (255, 170, 17));
+    }
+
+    #[test]
+    fn bad_files_are_rejected() {
+        // Returns the error, as Texture isn't Debug for unwrap_err
+        let load = |data: &[u8]| Texture::<2, 2>::from_ppm(data).err().unwrap();
+        assert_eq!(load(b"P3 2 2 255\n"), TextureError::BadHeader);
+        assert_eq!(load(b"P6 2 x 255\n"), TextureError::BadHeader);
+        assert_eq!(load(b"P6 2 2"), TextureError::Truncated);
+        assert_eq!(load(b"P6 2 2 255\n\x00\x00\x00"), TextureError::Truncated);
+        assert_eq!(
+            load(b"P6 3 2 255\n"),
+            TextureError::SizeMismatch {
+                width: 3,
+                height: 2
+            }
+        );
+        assert_eq!(load(b"P6 2 2 65535\n"), TextureError::Unsupported);
+    }
+}
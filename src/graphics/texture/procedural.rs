@@ -0,0 +1,254 @@
+use core::f32::consts::FRAC_1_SQRT_2;
+
+use crate::{
+    math::{floor, sqrt},
+    types::vector::Vec3,
+};
+
+pub const BLACK: Vec3<u8> = Vec3 { x: 0, y: 0, z: 0 };
+pub const WHITE: Vec3<u8> = Vec3 {
+    x: 255,
+    y: 255,
+    z: 255,
+};
+
+/// Alternates between square cells of `a` and `b`, `cell` texels
+/// across, starting with `a` in the bottom left. A `cell` of 0
+/// is taken as 1.
+pub fn checkerboard(cell: usize, a: Vec3<u8>, b: Vec3<u8>) -> impl Fn(usize, usize) -> Vec3<u8> {
+    let cell = cell.max(1);
+    move |x, y| {
+        if (x / cell + y / cell) % 2 == 1 {
+            b
+        } else {
+            a
+        }
+    }
+}
+
+/// Blends from `from` on the left column to `to` on the right.
+pub fn horizontal_gradient(
+    width: usize,
+    from: Vec3<u8>,
+    to: Vec3<u8>,
+) -> impl Fn(usize, usize) -> Vec3<u8> {
+    move |x, _| mix(from, to, fraction(x, width))
+}
+
+/// Blends from `from` on the bottom row to `to` on the top.
+pub fn vertical_gradient(
+    height: usize,
+    from: Vec3<u8>,
+    to: Vec3<u8>,
+) -> impl Fn(usize, usize) -> Vec3<u8> {
+    move |_, y| mix(from, to, fraction(y, height))
+}
+
+/// Blends from `inner` at the centre to `outer` at the
+/// middle of each edge and beyond, into the corners.
+pub fn radial_gradient(
+    (width, height): (usize, usize),
+    inner: Vec3<u8>,
+    outer: Vec3<u8>,
+) -> impl Fn(usize, usize) -> Vec3<u8> {
+    move |x, y| {
+        let u = (x as f32 + 0.5) / width as f32 * 2.0 - 1.0;
+        let v = (y as f32 + 0.5) / height as f32 * 2.0 - 1.0;
+        mix(inner, outer, f32::min(sqrt(u * u + v * v), 1.0))
+    }
+}
+
+/// Draws lines `thickness` texels wide every `spacing` texels
+/// over `background`. Lines run along the bottom and left edges
+/// of each cell, so the pattern tiles when the texture size is
+/// a multiple of `spacing`. A `spacing` of 0 is taken as 1.
+pub fn grid(
+    spacing: usize,
+    thickness: usize,
+    line: Vec3<u8>,
+    background: Vec3<u8>,
+) -> impl Fn(usize, usize) -> Vec3<u8> {
+    let spacing = spacing.max(1);
+    move |x, y| {
+        if x % spacing < thickness || y % spacing < thickness {
+            line
+        } else {
+            background
+        }
+    }
+}
+
+/// Smoothly blends random values between `a` and `b`, chosen on a
+/// lattice of `cells` by `cells` squares stretched over the texture.
+/// The lattice wraps, so the noise tiles seamlessly. A `cells` of 0
+/// is taken as 1.
+pub fn value_noise(
+    size: (usize, usize),
+    cells: usize,
+    seed: u32,
+    a: Vec3<u8>,
+    b: Vec3<u8>,
+) -> impl Fn(usize, usize) -> Vec3<u8> {
+    move |x, y| {
+        let value = lattice_noise(size, cells, x, y, |cx, cy, _, _| {
+            hash(cx, cy, seed) as f32 / u32::MAX as f32
+        });
+        mix(a, b, value)
+    }
+}
+
+/// Gradient (Perlin) noise blending between `a` and `b`. Smoother
+/// and less blocky than `value_noise`, with the same tiling lattice.
+/// A `cells` of 0 is taken as 1.
+pub fn perlin_noise(
+    size: (usize, usize),
+    cells: usize,
+    seed: u32,
+    a: Vec3<u8>,
+    b: Vec3<u8>,
+) -> impl Fn(usize, usize) -> Vec3<u8> {
+    move |x, y| {
+        let value = lattice_noise(size, cells, x, y, |cx, cy, dx, dy| {
+            // One of eight directions, skipping trig
+            let (gx, gy) = match hash(cx, cy, seed) >> 29 {
+                0 => (1.0, 0.0),
+                1 => (-1.0, 0.0),
+                2 => (0.0, 1.0),
+                3 => (0.0, -1.0),
+                4 => (FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+                5 => (-FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+                6 => (FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+                _ => (-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+            };
+            // 2D Perlin noise stays within +-sqrt(1/2), so scale it onto 0 to 1
+            (gx * dx + gy * dy) * FRAC_1_SQRT_2 + 0.5
+        });
+        mix(a, b, value.clamp(0.0, 1.0))
+    }
+}
+
+/// Shows texture coordinates as colours: red increases with u and
+/// green with v, so a surface shows how it's mapped. Blue marks an
+/// 8 by 8 checkerboard to show scale and tiling.
+pub fn uv_debug((width, height): (usize, usize)) -> impl Fn(usize, usize) -> Vec3<u8> {
+    move |x, y| {
+        let cell = (x * 8 / width + y * 8 / height) % 2;
+        Vec3 {
+            x: (fraction(x, width) * 255.0) as u8,
+            y: (fraction(y, height) * 255.0) as u8,
+            z: if cell == 0 { 64 } else { 192 },
+        }
+    }
+}
+
+/// Linearly blends between two colours, `t` from 0 to 1.
+fn mix(a: Vec3<u8>, b: Vec3<u8>, t: f32) -> Vec3<u8> {
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t + 0.5) as u8;
+    Vec3 {
+        x: channel(a.x, b.x),
+        y: channel(a.y, b.y),
+        z: channel(a.z, b.z),
+    }
+}
+
+/// Maps an index from 0 to `size - 1` onto 0 to 1.
+fn fraction(index: usize, size: usize) -> f32 {
+    if size > 1 {
+        index as f32 / (size - 1) as f32
+    } else {
+        0.0
+    }
+}
+
+/// Evaluates `corner` at the four lattice points around a texel
+/// centre, along with the texel's offset from each point in cells,
+/// and blends the results with smoothstep weights.
+fn lattice_noise(
+    (width, height): (usize, usize),
+    cells: usize,
+    x: usize,
+    y: usize,
+    corner: impl Fn(usize, usize, f32, f32) -> f32,
+) -> f32 {
+    let cells = cells.max(1);
+    let u = (x as f32 + 0.5) / width as f32 * cells as f32;
+    let v = (y as f32 + 0.5) / height as f32 * cells as f32;
+    let (cx, cy) = (floor(u), floor(v));
+    let (s, t) = (u - cx, v - cy);
+    let (cx, cy) = (cx as usize, cy as usize);
+    let corner = |i: usize, j: usize| {
+        corner(
+            (cx + i) % cells,
+            (cy + j) % cells,
+            s - i as f32,
+            t - j as f32,
+        )
+    };
+
+    let smooth = |t: f32| t * t * (3.0 - 2.0 * t);
+    let (s, t) = (smooth(s), smooth(t));
+    let bottom = corner(0, 0) * (1.0 - s) + corner(1, 0) * s;
+    let top = corner(0, 1) * (1.0 - s) + corner(1, 1) * s;
+    bottom * (1.0 - t) + top * t
+}
+
+/// Scrambles a lattice point and seed into a pseudo-random number.
+fn hash(x: usize, y: usize, seed: u32) -> u32 {
+    let mut h = seed ^ (x as u32).wrapping_mul(0x9e37_79b1) ^ (y as u32).wrapping_mul(0x85eb_ca77);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2c1b_3c6d);
+    h ^= h >> 12;
+    h = h.wrapping_mul(0x297a_2d39);
+    h ^ (h >> 15)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::texture::{Image, Texture};
+
+    #[test]
+    fn checkerboard_alternates_in_both_directions() {
+        let board = checkerboard(2, BLACK, WHITE);
+        let white = |x, y| board(x, y).x == 255;
+        // Starts with `a` in the bottom left
+        assert!(!white(0, 0) && !white(1, 1));
+        assert!(white(2, 0) && white(0, 2) && !white(2, 2));
+        assert!(white(5, 3) && !white(5, 5));
+    }
+
+    #[test]
+    fn gen_checkerboard_is_half_white() {
+        // Only cells odd in both x and y used to be white
+        let tex = Texture::<16, 16>::gen_checkerboard();
+        let white = (0..16)
+            .flat_map(|y| (0..16).map(move |x| (x, y)))
+            .filter(|&(x, y)| tex.texel(x, y).x == 255)
+            .count();
+        assert_eq!(white, 128);
+        assert_eq!(tex.texel(8, 0).x, 255);
+        assert_eq!(tex.texel(0, 8).x, 255);
+        assert_eq!(tex.texel(8, 8).x, 0);
+    }
+
+    #[test]
+    fn zero_sizes_are_taken_as_one() {
+        // These used to divide or take the remainder by zero
+        let board = checkerboard(0, BLACK, WHITE);
+        assert_eq!([board(0, 0).x, board(1, 0).x, board(1, 1).x], [0, 255, 0]);
+
+        let lines = grid(0, 1, WHITE, BLACK);
+        assert_eq!([lines(0, 0).x, lines(3, 5).x], [255, 255]);
+
+        let value = value_noise((4, 4), 0, 7, BLACK, WHITE);
+        let value_one = value_noise((4, 4), 1, 7, BLACK, WHITE);
+        let perlin = perlin_noise((4, 4), 0, 7, BLACK, WHITE);
+        let perlin_one = perlin_noise((4, 4), 1, 7, BLACK, WHITE);
+        for (x, y) in [(0, 0), (1, 2), (3, 3)] {
+            assert_eq!(value(x, y).x, value_one(x, y).x);
+            assert_eq!(perlin(x, y).x, perlin_one(x, y).x);
+            // A single lattice cell wraps onto itself, so is one value
+            assert_eq!(value(x, y).x, value(0, 0).x);
+        }
+    }
+}
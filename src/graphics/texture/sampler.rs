@@ -0,0 +1,320 @@
+use defmt::Format;
+
+use crate::{
+    graphics::texture::Image,
+    math::{floor, log2, sqrt},
+    types::vector::{Vec2, Vec3},
+};
+
+#[derive(Clone, Copy, Format)]
+/// How texture coordinates outside [0, 1) are mapped onto the texture.
+pub enum Wrap {
+    /// Tiles the texture.
+    Repeat,
+    /// Tiles the texture, flipping every other tile so edges meet.
+    MirroredRepeat,
+    /// Stretches the edge texels outwards.
+    ClampToEdge,
+    /// Uses a fixed colour outside the texture.
+    Border(Vec3<u8>),
+}
+
+impl Wrap {
+    /// Maps a texel index onto one within `size`, or
+    /// `None` where the border colour shows.
+    fn resolve(self, index: i32, size: usize) -> Option<usize> {
+        let size = size as i32;
+        match self {
+            Wrap::Repeat => Some(index.rem_euclid(size) as usize),
+            Wrap::MirroredRepeat => {
+                let index = index.rem_euclid(size * 2);
+                Some(if index < size {
+                    index
+                } else {
+                    size * 2 - 1 - index
+                } as usize)
+            }
+            Wrap::ClampToEdge => Some(index.clamp(0, size - 1) as usize),
+            Wrap::Border(_) => (0..size).contains(&index).then_some(index as usize),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Format)]
+/// How texels are combined when a sample falls between them.
+pub enum Filter {
+    /// Takes the texel containing the sample.
+    Nearest,
+    /// Blends the four texels with centres around the sample.
+    Bilinear,
+}
+
+#[derive(Clone, Copy, PartialEq, Format)]
+/// How mip levels are used when a texture is minified.
+pub enum MipFilter {
+    /// Always samples the full-size image.
+    None,
+    /// Samples the level closest to the texel-to-pixel ratio.
+    Nearest,
+    /// Blends the two closest levels. Trilinear filtering
+    /// when combined with `Filter::Bilinear`.
+    Linear,
+}
+
+#[derive(Clone, Copy, Format)]
+/// Reads colours from a texture at texture coordinates.
+pub struct Sampler {
+    pub wrap_u: Wrap,
+    pub wrap_v: Wrap,
+    pub filter: Filter,
+    pub mip_filter: MipFilter,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Sampler {
+            wrap_u: Wrap::Repeat,
+            wrap_v: Wrap::Repeat,
+            filter: Filter::Nearest,
+            mip_filter: MipFilter::Nearest,
+        }
+    }
+}
+
+impl Sampler {
+    /// Creates a sampler with the same wrap mode on both axes.
+    pub fn new(wrap: Wrap, filter: Filter) -> Sampler {
+        Sampler {
+            wrap_u: wrap,
+            wrap_v: wrap,
+            filter,
+            mip_filter: MipFilter::Nearest,
+        }
+    }
+
+    /// Samples the full-size level of `image` as a float colour. Any
+    /// texture coordinates are accepted; those outside [0, 1) are wrapped.
+    pub fn sample(&self, image: &dyn Image, tex_coords: Vec2<f32>) -> Vec3<f32> {
+        self.sample_level(image, 0, tex_coords)
+    }
+
+    /// Samples `image` at a mip level chosen from how far the texture
+    /// coordinates move per pixel, `dx` to the right and `dy` down.
+    pub fn sample_grad(
+        &self,
+        image: &dyn Image,
+        tex_coords: Vec2<f32>,
+        dx: Vec2<f32>,
+        dy: Vec2<f32>,
+    ) -> Vec3<f32> {
+        let levels = image.levels();
+        if levels == 1 || self.mip_filter == MipFilter::None {
+            return self.sample_level(image, 0, tex_coords);
+        }
+
+        // Texels covered by a pixel along its longer side
+        let (width, height) = (image.width() as f32, image.height() as f32);
+        let texels =
+            |d: Vec2<f32>| sqrt((d.x * width) * (d.x * width) + (d.y * height) * (d.y * height));
+        let lod = log2(f32::max(texels(dx), texels(dy)));
+        let max_level = (levels - 1) as f32;
+
+        match self.mip_filter {
+            MipFilter::Linear if lod > 0.0 && lod < max_level => {
+                let level = floor(lod);
+                let t = lod - level;
+                let level = level as usize;
+                self.sample_level(image, level, tex_coords) * (1.0 - t)
+                    + self.sample_level(image, level + 1, tex_coords) * t
+            }
+            _ => {
+                let level = floor(lod + 0.5).clamp(0.0, max_level);
+                self.sample_level(image, level as usize, tex_coords)
+            }
+        }
+    }
+
+    fn sample_level(&self, image: &dyn Image, level: usize, tex_coords: Vec2<f32>) -> Vec3<f32> {
+        let (width, height) = image.level_size(level);
+        // Past 2^24 floats are whole numbers anyway. Limiting them keeps
+        // the texel indices, and the neighbours read by bilinear
+        // filtering, within an i32 for huge or infinite coordinates.
+        let limit = (1 << 24) as f32;
+        let u = (tex_coords.x * width as f32).clamp(-limit, limit);
+        let v = (tex_coords.y * height as f32).clamp(-limit, limit);
+        let texel = |x, y| self.texel(image, level, x, y);
+        match self.filter {
+            Filter::Nearest => texel(floor(u) as i32, floor(v) as i32),
+            Filter::Bilinear => {
+                // Texel centres sit at half-integer coordinates
+                let u = u - 0.5;
+                let v = v - 0.5;
+                let (x, y) = (floor(u), floor(v));
+                let (s, t) = (u - x, v - y);
+                let (x, y) = (x as i32, y as i32);
+
+                let bottom = texel(x, y) * (1.0 - s) + texel(x + 1, y) * s;
+                let top = texel(x, y + 1) * (1.0 - s) + texel(x + 1, y + 1) * s;
+                bottom * (1.0 - t) + top * t
+            }
+        }
+    }
+
+    /// Reads a texel of a mip level by index, applying the wrap modes.
+    fn texel(&self, image: &dyn Image, level: usize, x: i32, y: i32) -> Vec3<f32> {
+        let (width, height) = image.level_size(level);
+        let texel = match (
+            self.wrap_u.resolve(x, width),
+            self.wrap_v.resolve(y, height),
+        ) {
+            (Some(x), Some(y)) => image.level_texel(level, x, y),
+            // The u border takes priority where both apply
+            (None, _) => self.border(self.wrap_u),
+            (_, None) => self.border(self.wrap_v),
+        };
+        texel.to_float_colour()
+    }
+
+    fn border(&self, wrap: Wrap) -> Vec3<u8> {
+        match wrap {
+            Wrap::Border(colour) => colour,
+            _ => Vec3 { x: 0, y: 0, z: 0 },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::texture::Texture;
+
+    #[test]
+    fn wrap_modes_resolve_indices_outside() {
+        let resolve = |wrap: Wrap| [-5, -1, 0, 3, 4, 9].map(|i| wrap.resolve(i, 4));
+        assert_eq!(
+            resolve(Wrap::Repeat),
+            [3, 3, 0, 3, 0, 1].map(Some),
+            "Repeat"
+        );
+        assert_eq!(
+            resolve(Wrap::MirroredRepeat),
+            [3, 0, 0, 3, 3, 1].map(Some),
+            "MirroredRepeat"
+        );
+        assert_eq!(
+            resolve(Wrap::ClampToEdge),
+            [0, 0, 0, 3, 3, 3].map(Some),
+            "ClampToEdge"
+        );
+        let red = Vec3 { x: 255, y: 0, z: 0 };
+        assert_eq!(
+            resolve(Wrap::Border(red)),
+            [None, None, Some(0), Some(3), None, None],
+            "Border"
+        );
+    }
+
+    #[test]
+    fn border_colour_shows_outside_the_texture() {
+        let texture = Texture::<2, 2>::from_fn(|_, _| Vec3 { x: 0, y: 0, z: 0 });
+        let sampler = Sampler::new(Wrap::Border(Vec3 { x: 0, y: 255, z: 0 }), Filter::Nearest);
+        let outside = sampler.sample(&texture, Vec2 { x: 1.5, y: 0.5 });
+        let inside = sampler.sample(&texture, Vec2 { x: 0.5, y: 0.5 });
+        assert_eq!((outside.x, outside.y), (0.0, 1.0));
+        assert_eq!((inside.x, inside.y), (0.0, 0.0));
+    }
+
+    #[test]
+    fn bilinear_blends_between_texel_centres() {
+        // Black on the left column, white on the right
+        let texture = Texture::<2, 1>::from_fn(|x, _| {
+            let value = x as u8 * 255;
+            Vec3 {
+                x: value,
+                y: value,
+                z: value,
+            }
+        });
+        let sampler = Sampler::new(Wrap::ClampToEdge, Filter::Bilinear);
+        let sample = |u| sampler.sample(&texture, Vec2 { x: u, y: 0.5 }).x;
+        assert_eq!(sample(0.25), 0.0);
+        assert!((sample(0.5) - 0.5).abs() < 1e-6);
+        assert_eq!(sample(0.75), 1.0);
+        // Clamped beyond the last centre
+        assert_eq!(sample(1.0), 1.0);
+    }
+
+    /// An 8x8 image whose mip levels are each one grey, 60 per level,
+    /// so a sample shows which level it was read from.
+    struct LevelMarked;
+
+    impl Image for LevelMarked {
+        fn width(&self) -> usize {
+            8
+        }
+
+        fn height(&self) -> usize {
+            8
+        }
+
+        fn texel(&self, x: usize, y: usize) -> Vec3<u8> {
+            self.level_texel(0, x, y)
+        }
+
+        fn levels(&self) -> usize {
+            4
+        }
+
+        fn level_texel(&self, level: usize, _x: usize, _y: usize) -> Vec3<u8> {
+            let value = level as u8 * 60;
+            Vec3 {
+                x: value,
+                y: value,
+                z: value,
+            }
+        }
+    }
+
+    #[test]
+    fn lod_follows_the_texels_covered_per_pixel() {
+        let mut sampler = Sampler::default();
+        // The level sampled when u moves `texels` texels per pixel
+        let level = |sampler: &Sampler, texels: f32| {
+            let centre = Vec2 { x: 0.5, y: 0.5 };
+            let dx = Vec2 {
+                x: texels / 8.0,
+                y: 0.0,
+            };
+            let dy = Vec2 { x: 0.0, y: 0.0 };
+            sampler.sample_grad(&LevelMarked, centre, dx, dy).x * 255.0 / 60.0
+        };
+
+        assert_eq!(level(&sampler, 0.0), 0.0, "no movement");
+        assert_eq!(level(&sampler, 1.0), 0.0, "one texel");
+        assert_eq!(level(&sampler, 2.0), 1.0, "two texels");
+        assert_eq!(level(&sampler, 8.0), 3.0, "the whole image");
+        assert_eq!(level(&sampler, 1000.0), 3.0, "past the last level");
+
+        // Halfway between levels 1 and 2, give or take the
+        // error of the approximate log2
+        sampler.mip_filter = MipFilter::Linear;
+        let between = level(&sampler, 2.0 * 2f32.sqrt());
+        assert!((between - 1.5).abs() < 0.1, "{}", between);
+
+        sampler.mip_filter = MipFilter::None;
+        assert_eq!(level(&sampler, 8.0), 0.0, "mipmapping off");
+    }
+
+    #[test]
+    fn huge_texture_coordinates_still_wrap() {
+        // Indices used to saturate, and the bilinear neighbour overflow
+        let texture = Texture::<2, 2>::from_fn(|_, _| Vec3 { x: 255, y: 0, z: 0 });
+        for filter in [Filter::Nearest, Filter::Bilinear] {
+            let sampler = Sampler::new(Wrap::Repeat, filter);
+            for u in [1e20, -1e20, f32::INFINITY, f32::NEG_INFINITY] {
+                let sample = sampler.sample(&texture, Vec2 { x: u, y: u });
+                assert_eq!(sample.x, 1.0, "{}", u);
+            }
+        }
+    }
+}
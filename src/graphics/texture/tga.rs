@@ -0,0 +1,211 @@
+use defmt::debug;
+
+use crate::{
+    graphics::texture::{
+        format::{texture_bytes, PixelFormat},
+        Texture, TextureError,
+    },
+    types::vector::Vec3,
+};
+
+const HEADER_LEN: usize = 18;
+
+impl<const W: usize, const H: usize, P: PixelFormat<Palette = ()>> Texture<W, H, P>
+where
+    [(); texture_bytes(W, H, P::BITS)]:,
+{
+    /// Loads an uncompressed or run-length encoded TGA image, which
+    /// must be the size of the texture. True-colour images of 24 or
+    /// 32 bits and 8-bit greyscale are supported; alpha is dropped.
+    pub fn from_tga(data: &[u8]) -> Result<Texture<W, H, P>, TextureError> {
+        let header = data.get(..HEADER_LEN).ok_or(TextureError::BadHeader)?;
+        let id_len = header[0] as usize;
+        let colour_map = header[1];
+        let image_type = header[2];
+        let map_len = u16::from_le_bytes([header[5], header[6]]) as usize;
+        let map_entry_bits = header[7] as usize;
+        let width = u16::from_le_bytes([header[12], header[13]]) as usize;
+        let height = u16::from_le_bytes([header[14], header[15]]) as usize;
+        let bits = header[16];
+        let descriptor = header[17];
+
+        if colour_map > 1 {
+            return Err(TextureError::BadHeader);
+        }
+        let (run_length, grey) = match image_type {
+            2 => (false, false),
+            3 => (false, true),
+            10 => (true, false),
+            11 => (true, true),
+            // Colour-mapped images, or no image at all
+            _ => return Err(TextureError::Unsupported),
+        };
+        let bytes = match (grey, bits) {
+            (false, 24) => 3,
+            (false, 32) => 4,
+            (true, 8) => 1,
+            _ => return Err(TextureError::Unsupported),
+        };
+        Self::check_size(width, height)?;
+        debug!("Loading {}x{} TGA", width, height);
+
+        // True-colour images may still carry an unused colour map
+        let map_bytes = if colour_map == 1 {
+            map_len * map_entry_bits.div_ceil(8)
+        } else {
+            0
+        };
+        let mut pixels = Pixels {
+            data: data
+                .get(HEADER_LEN + id_len + map_bytes..)
+                .ok_or(TextureError::Truncated)?,
+            bytes,
+            run_length,
+            count: 0,
+            repeat: false,
+        };
+
+        // Rows run bottom to top and left to right unless flagged
+        let right_to_left = descriptor & 0x10 != 0;
+        let top_to_bottom = descriptor & 0x20 != 0;
+
+        let mut tex = Texture::from_fn(|_, _| Vec3 { x: 0, y: 0, z: 0 });
+        for row in 0..H {
+            let y = if top_to_bottom { H - 1 - row } else { row };
+            for column in 0..W {
+                let x = if right_to_left {
+                    W - 1 - column
+                } else {
+                    column
+                };
+                let pixel = pixels.next().ok_or(TextureError::Truncated)?;
+                let colour = if grey {
+                    Vec3 {
+                        x: pixel[0],
+                        y: pixel[0],
+                        z: pixel[0],
+                    }
+                } else {
+                    Vec3 {
+                        x: pixel[2],
+                        y: pixel[1],
+                        z: pixel[0],
+                    }
+                };
+                tex.set(x, y, colour);
+            }
+        }
+        Ok(tex)
+    }
+}
+
+/// Reads pixels in file order, expanding run-length packets.
+struct Pixels<'a> {
+    data: &'a [u8],
+    bytes: usize,
+    run_length: bool,
+    /// Pixels left in the current packet.
+    count: usize,
+    /// Whether the current packet repeats one pixel.
+    repeat: bool,
+}
+
+impl<'a> Iterator for Pixels<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if !self.run_length {
+            let (pixel, rest) = self.data.split_at_checked(self.bytes)?;
+            self.data = rest;
+            return Some(pixel);
+        }
+
+        if self.count == 0 {
+            let (&packet, rest) = self.data.split_first()?;
+            self.data = rest;
+            self.count = (packet & 0x7f) as usize + 1;
+            self.repeat = packet & 0x80 != 0;
+        }
+        self.count -= 1;
+
+        let pixel = self.data.get(..self.bytes)?;
+        // A repeated pixel is stored once, after its packet header
+        if !self.repeat || self.count == 0 {
+            self.data = &self.data[self.bytes..];
+        }
+        Some(pixel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::texture::Image;
+    use heapless::Vec;
+
+    /// Builds a 2x2 TGA file around `pixels`.
+    fn tga(image_type: u8, bits: u8, descriptor: u8, pixels: &[u8]) -> Vec<u8, 64> {
+        let mut data = Vec::new();
+        let mut header = [0; HEADER_LEN];
+        header[2] = image_type;
+        header[12] = 2;
+        header[14] = 2;
+        header[16] = bits;
+        header[17] = descriptor;
+        data.extend_from_slice(&header).unwrap();
+        data.extend_from_slice(pixels).unwrap();
+        data
+    }
+
+    /// Returns the texels from the bottom left, row by row.
+    fn texels(tex: &Texture<2, 2>) -> [(u8, u8, u8); 4] {
+        [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(x, y)| {
+            let texel = tex.texel(x, y);
+            (texel.x, texel.y, texel.z)
+        })
+    }
+
+    #[test]
+    fn raw_pixels_are_bgr_from_the_bottom_left() {
+        let data = tga(2, 24, 0, &[3, 2, 1, 6, 5, 4, 9, 8, 7, 12, 11, 10]);
+        let tex = Texture::<2, 2>::from_tga(&data).unwrap();
+        assert_eq!(
+            texels(&tex),
+            [(1, 2, 3), (4, 5, 6), (7, 8, 9), (10, 11, 12)]
+        );
+    }
+
+    #[test]
+    fn run_length_packets_repeat_or_copy_pixels() {
+        // Two copies of one pixel, then two literal pixels
+        let data = tga(
+            10,
+            32,
+            0,
+            &[0x81, 3, 2, 1, 255, 0x01, 6, 5, 4, 255, 9, 8, 7, 255],
+        );
+        let tex = Texture::<2, 2>::from_tga(&data).unwrap();
+        assert_eq!(texels(&tex), [(1, 2, 3), (1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+    }
+
+    #[test]
+    fn descriptor_bits_flip_the_pixel_order() {
+        let grey = |v| (v, v, v);
+        let pixels = [1, 2, 3, 4];
+        let load = |descriptor| Texture::<2, 2>::from_tga(&tga(3, 8, descriptor, &pixels)).unwrap();
+        assert_eq!(texels(&load(0x00)), [1, 2, 3, 4].map(grey));
+        assert_eq!(texels(&load(0x10)), [2, 1, 4, 3].map(grey));
+        assert_eq!(texels(&load(0x20)), [3, 4, 1, 2].map(grey));
+        assert_eq!(texels(&load(0x30)), [4, 3, 2, 1].map(grey));
+    }
+
+    #[test]
+    fn bad_files_are_rejected() {
+        // Returns the error, as Texture isn't Debug for unwrap_err
+        let load = |data: &[u8]| Texture::<2, 2>::from_tga(data).err().unwrap();
+        assert_eq!(load(&[0; 10]), TextureError::BadHeader);
+        assert_eq!(load(&tga(1, 8, 0, &[])), TextureError::Unsupported);
+        assert_eq!(load(&tga(2, 24, 0, &[0; 9])), TextureError::Truncated);
+        assert_eq!(load(&tga(11, 8, 0, &[0x82, 7])), TextureError::Truncated);
+    }
+}
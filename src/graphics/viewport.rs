@@ -1,26 +1,34 @@
-use core::usize;
+use core::slice::ChunksExact;
 use heapless::Vec;
 
+use crate::{display::sink::PixelSink, types::vector::Vec3};
+
 /// Represents a render target.
 pub struct FrameBuffer<T: Copy, const W: usize, const H: usize>
 where
     [(); W * H]:,
 {
     buffer: Vec<T, { W * H }>,
-    // aspect: f32,
+    /// Position of the next pixel written through `PixelSink`.
+    cursor: (usize, usize),
 }
 
 impl<T: Copy, const W: usize, const H: usize> FrameBuffer<T, W, H>
 where
     [(); W * H]:,
 {
-    pub fn new() -> FrameBuffer<T, W, H>
+    /// Creates a new framebuffer with every element set to `value`.
+    pub fn new(value: T) -> FrameBuffer<T, W, H>
     where
         [(); W * H]:,
     {
+        let mut buffer = Vec::new();
+        buffer
+            .resize(W * H, value)
+            .expect("framebuffer capacity should be W * H");
         FrameBuffer {
-            buffer: Vec::new(),
-            // aspect: W as f32 / H as f32,
+            buffer,
+            cursor: (0, 0),
         }
     }
 
@@ -30,4 +38,136 @@ where
             .get(y * W + x)
             .expect("coordinates specified should be within bounds of the framebuffer")
     }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        *self
+            .buffer
+            .get_mut(y * W + x)
+            .expect("coordinates specified should be within bounds of the framebuffer") = value;
+    }
+
+    /// Sets every element to `value`.
+    pub fn clear(&mut self, value: T) {
+        self.buffer.fill(value);
+    }
+
+    /// Sets every element in a `w`x`h` rectangle with its top-left
+    /// corner at (`x`, `y`). The rectangle is clipped to the framebuffer.
+    pub fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, value: T) {
+        let x_end = usize::min(x.saturating_add(w), W);
+        let y_end = usize::min(y.saturating_add(h), H);
+        if x >= x_end {
+            return;
+        }
+        for row in y..y_end {
+            self.buffer[row * W + x..row * W + x_end].fill(value);
+        }
+    }
+
+    /// Returns a single row.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.buffer[y * W..(y + 1) * W]
+    }
+
+    /// Iterates over the rows from top to bottom.
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.buffer.chunks_exact(W)
+    }
+}
+
+impl<const W: usize, const H: usize> FrameBuffer<Vec3<u8>, W, H>
+where
+    [(); W * H]:,
+{
+    /// Streams the whole framebuffer out scanline by scanline,
+    /// finishing with the end of the frame.
+    pub fn scan_out(&self, output: &mut impl PixelSink) {
+        for row in self.rows() {
+            for pixel in row {
+                output.render_pixel(*pixel);
+            }
+            output.end_row();
+        }
+        output.end_frame();
+    }
+}
+
+/// Lets a framebuffer be rendered into like a display.
+/// Pixels outside of the framebuffer are dropped.
+impl<const W: usize, const H: usize> PixelSink for FrameBuffer<Vec3<u8>, W, H>
+where
+    [(); W * H]:,
+{
+    fn render_pixel(&mut self, pixel: Vec3<u8>) {
+        let (x, y) = self.cursor;
+        if x < W && y < H {
+            self.buffer[y * W + x] = pixel;
+        }
+        self.cursor.0 += 1;
+    }
+
+    fn end_row(&mut self) {
+        self.cursor = (0, self.cursor.1 + 1);
+    }
+
+    fn end_frame(&mut self) {
+        self.cursor = (0, 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::sink::PixelRecorder;
+
+    /// Encodes a pixel's column and row in its colour.
+    fn position(x: usize, y: usize) -> Vec3<u8> {
+        Vec3 {
+            x: x as u8,
+            y: y as u8,
+            z: 1,
+        }
+    }
+
+    #[test]
+    fn scan_out_streams_rows_from_the_top() {
+        let mut frame = FrameBuffer::<Vec3<u8>, 4, 3>::new(Vec3 { x: 0, y: 0, z: 0 });
+        for y in 0..3 {
+            for x in 0..4 {
+                frame.set(x, y, position(x, y));
+            }
+        }
+
+        let mut recorder = PixelRecorder::<4, 3>::new();
+        frame.scan_out(&mut recorder);
+        assert_eq!(recorder.frames, 1);
+        for y in 0..3 {
+            for x in 0..4 {
+                let pixel = recorder.get(x, y);
+                assert_eq!((pixel.x, pixel.y), (x as u8, y as u8));
+            }
+        }
+
+        // Scanning into another framebuffer copies it
+        let mut copy = FrameBuffer::<Vec3<u8>, 4, 3>::new(Vec3 { x: 0, y: 0, z: 0 });
+        frame.scan_out(&mut copy);
+        frame.scan_out(&mut copy);
+        assert!(frame
+            .rows()
+            .zip(copy.rows())
+            .all(|(a, b)| a
+                .iter()
+                .zip(b)
+                .all(|(a, b)| (a.x, a.y, a.z) == (b.x, b.y, b.z))));
+    }
+
+    #[test]
+    fn fill_rect_is_clipped_to_the_buffer() {
+        let mut frame = FrameBuffer::<u8, 4, 3>::new(0);
+        frame.fill_rect(2, 1, 10, 10, 1);
+        frame.fill_rect(5, 0, 1, 1, 2);
+        assert_eq!(frame.row(0), [0, 0, 0, 0]);
+        assert_eq!(frame.row(1), [0, 0, 1, 1]);
+        assert_eq!(frame.row(2), [0, 0, 1, 1]);
+    }
 }
@@ -1 +1,12 @@
 #![no_std]
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+pub mod display;
+pub mod graphics;
+pub mod math;
+pub mod model;
+pub mod types;
+
+pub type EdidBuffer = [u8; EDID_BUFFER_LEN];
+pub const EDID_BUFFER_LEN: usize = 256;
@@ -2,35 +2,46 @@
 #![no_main]
 #![feature(generic_const_exprs)]
 
-use crate::{
-    display::{dvi::DviInterface, edid::read_edid, tmds::TMDS},
-    graphics::camera::Camera,
+use defmt::info;
+use esp_backtrace as _;
+use esp_hal::{clock::CpuClock, time::Instant, timer::timg::TimerGroup};
+use esp_println as _;
+use portgl::{
+    display::{dvi::DviInterface, edid::read_edid},
+    graphics::{
+        cache::VertexCache,
+        camera::Camera,
+        light::{Attenuation, Light, Lights},
+        scene::{Node, Scene, Spin, Transform},
+        shader::{blinn_phong::BlinnPhong, StandardUniforms},
+        texture::{
+            baked::textures,
+            sampler::{Filter, MipFilter, Sampler, Wrap},
+            Texture,
+        },
+    },
+    model::baked::meshes,
     types::{
+        angle::Angle,
         matrix::Mat4,
         quat::Quaternion,
-        vector::{VEC3_X, VEC3_Y},
+        vector::{Vec3, VEC3_X, VEC3_Y},
     },
+    EdidBuffer,
 };
-use defmt::info;
-use esp_backtrace as _;
-use esp_hal::{
-    clock::CpuClock,
-    i2c::master::{Config, I2c},
-    timer::{timg::TimerGroup, OneShotTimer},
-};
-use esp_println as _;
-use types::vector::VEC3_ZERO;
 
 use {esp_backtrace as _, esp_println as _};
 
-pub mod display;
-pub mod graphics;
-pub mod math;
-pub mod model;
-pub mod types;
-
-pub type EdidBuffer = [u8; EDID_BUFFER_LEN];
-pub const EDID_BUFFER_LEN: usize = 256;
+/// Axis the model spins about, in world space.
+const SPIN_AXIS: Vec3<f32> = Vec3 {
+    x: 0.3,
+    y: 1.0,
+    z: 0.0,
+};
+/// How fast the model spins, in degrees per second.
+const SPIN_RATE: f32 = 45.0;
+/// How many frames are averaged for each frame time report.
+const FRAME_TIME_WINDOW: u32 = 60;
 
 #[esp_hal::main]
 fn main() -> ! {
@@ -42,8 +53,8 @@ fn main() -> ! {
     let mut cam = Camera::<80, 60>::perspective(90.0, VEC3_X * 5.0, -VEC3_X, VEC3_Y, 0.1, 50.0);
     info!("Created camera");
 
-    let model = crate::model::model::from_obj(&crate::model::CUBE_OBJ);
-    info!("Loaded model");
+    // Baked at build time, so there's nothing to parse
+    let model = &meshes::CUBE;
 
     let mut display = DviInterface::new(
         peripherals.GPIO1.into(),
@@ -72,16 +83,114 @@ fn main() -> ! {
 
     read_edid(edid_buffer);
 
+    let texture = Texture::<16, 16>::gen_checkerboard();
+
+    // Warm key light with a cool fill from the opposite side
+    let mut lights = Lights::new();
+    let _ = lights.push(Light::point(
+        Vec3 {
+            x: 1.5,
+            y: 0.0,
+            z: -1.5,
+        },
+        Vec3 {
+            x: 1.0,
+            y: 0.9,
+            z: 0.75,
+        },
+        Attenuation {
+            constant: 1.0,
+            linear: 0.05,
+            quadratic: 0.01,
+        },
+    ));
+    let _ = lights.push(Light::directional(
+        Vec3 {
+            x: -1.0,
+            y: -0.5,
+            z: -1.0,
+        },
+        Vec3 {
+            x: 0.2,
+            y: 0.25,
+            z: 0.4,
+        },
+    ));
+
+    // A tilted cube carrying a smaller brick one beside it
+    let mut scene = Scene::new();
+    let parent = scene
+        .add(Node::new(
+            Transform {
+                rotation: Quaternion::of(VEC3_Y, Angle::from_deg(30.0)),
+                ..Transform::idt()
+            },
+            Some(model),
+        ))
+        .expect("scene should have room for the cube");
+    let mut small = Node::new(
+        Transform {
+            translation: Vec3 {
+                x: 0.0,
+                y: 1.5,
+                z: 0.0,
+            },
+            scale: 0.5,
+            ..Transform::idt()
+        },
+        Some(model),
+    );
+    // Read straight from flash
+    small.texture = Some(&textures::BRICKS);
+    let _ = scene.add_child(parent, small);
+    let spin = Spin {
+        axis: SPIN_AXIS,
+        rate: Angle::from_deg(SPIN_RATE),
+    };
+
+    let mut cache = VertexCache::<_, 24>::new();
+
     info!("Beginning loop");
 
-    cam.render(
-        &model,
-        Mat4::<f32>::idt(),
-        &mut display,
-    );
+    let mut last_frame = Instant::now();
+    let mut window_start = last_frame;
+    let mut window_frames = 0;
 
     loop {
-        // cam.render_test(&mut display);
-        
+        // Advance by real time, so the speed doesn't depend on frame rate
+        let now = Instant::now();
+        let elapsed = (now - last_frame).as_micros() as f32 / 1_000_000.0;
+        last_frame = now;
+
+        spin.advance(&mut scene.node_mut(parent).transform, elapsed);
+        scene.update();
+
+        // Trilinear, as the baked textures carry mip levels
+        let uniforms = StandardUniforms {
+            sampler: Sampler {
+                mip_filter: MipFilter::Linear,
+                ..Sampler::new(Wrap::Repeat, Filter::Bilinear)
+            },
+            ..cam.uniforms(Mat4::idt(), &lights, &texture)
+        };
+        cam.render_scene(
+            &scene,
+            &uniforms,
+            &BlinnPhong::default(),
+            &mut cache,
+            &mut display,
+        );
+
+        window_frames += 1;
+        if window_frames == FRAME_TIME_WINDOW {
+            let window = (Instant::now() - window_start).as_micros();
+            info!(
+                "Frame time: {} us ({} frames)",
+                window / FRAME_TIME_WINDOW as u64,
+                FRAME_TIME_WINDOW
+            );
+            window_start = Instant::now();
+            window_frames = 0;
+        }
     }
 }
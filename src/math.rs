@@ -22,3 +22,24 @@ pub fn powi(base: f32, exp: u8) -> f32 {
         base * powi(base, exp - 1)
     }
 }
+
+/// Rounds a float down to the nearest integer.
+/// Only exact for values that fit in an i32.
+pub fn floor(num: f32) -> f32 {
+    let truncated = num as i32 as f32;
+    if truncated > num {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Approximates the base 2 logarithm of a positive float,
+/// to within 0.09. Returns a large negative number for 0.
+pub fn log2(num: f32) -> f32 {
+    let bits = num.to_bits();
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127;
+    // The mantissa as a value from 1 to 2, taken as linear
+    let mantissa = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+    exponent as f32 + (mantissa - 1.0)
+}
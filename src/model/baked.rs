@@ -0,0 +1,199 @@
+use crate::{
+    model::{
+        mesh::Mesh,
+        model::{Face, Vertex},
+    },
+    types::vector::{Vec2, Vec4},
+};
+
+/// A mesh converted from an OBJ file by `build.rs`. The packed
+/// data is `'static`, so it stays in flash, and vertices are
+/// decoded as they're read rather than copied into RAM.
+///
+/// Materials aren't baked; every face is drawn without one.
+#[derive(Clone, Copy)]
+pub struct BakedMesh {
+    /// Position of a quantised value of 0.
+    pub pos_offset: [f32; 3],
+    /// Model-space size of one quantisation step.
+    pub pos_scale: [f32; 3],
+    pub uv_offset: [f32; 2],
+    pub uv_scale: [f32; 2],
+    pub positions: &'static [[i16; 3]],
+    /// Unit normals scaled to -127..=127.
+    pub normals: &'static [[i8; 3]],
+    pub tex_coords: &'static [[u16; 2]],
+    /// Vertex indices of each face.
+    pub indices: &'static [[u16; 3]],
+}
+
+impl Mesh for BakedMesh {
+    fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn vertex(&self, index: usize) -> Vertex {
+        let [x, y, z] = self.positions[index];
+        let [n_x, n_y, n_z] = self.normals[index];
+        let [u, v] = self.tex_coords[index];
+
+        Vertex {
+            pos: Vec4 {
+                x: self.pos_offset[0] + x as f32 * self.pos_scale[0],
+                y: self.pos_offset[1] + y as f32 * self.pos_scale[1],
+                z: self.pos_offset[2] + z as f32 * self.pos_scale[2],
+                w: 1.0,
+            },
+            tex_coords: Vec2 {
+                x: self.uv_offset[0] + u as f32 * self.uv_scale[0],
+                y: self.uv_offset[1] + v as f32 * self.uv_scale[1],
+            },
+            normal: Vec4 {
+                x: n_x as f32 / 127.0,
+                y: n_y as f32 / 127.0,
+                z: n_z as f32 / 127.0,
+                w: 0.0,
+            },
+        }
+    }
+
+    fn face_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn face(&self, index: usize) -> Face {
+        let mut face = Face::new();
+        face.verts = self.indices[index];
+        face
+    }
+}
+
+/// Meshes baked from the OBJ files in `assets/`, named
+/// after each file in upper case.
+pub mod meshes {
+    use super::BakedMesh;
+
+    include!(concat!(env!("OUT_DIR"), "/baked_meshes.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        math::sqrt,
+        model::obj::{read_obj, Capacity, NormalKey, ObjSink, VertexKey},
+    };
+    use heapless::Vec;
+
+    /// Checks each triangle of an OBJ file against the same face of
+    /// its baked mesh as the file is read, so the parsed vertices
+    /// needn't all be kept.
+    struct Compare<'a> {
+        baked: &'a BakedMesh,
+        points: Vec<[f32; 3], 4096>,
+        tex_coords: Vec<[f32; 2], 4096>,
+        normals: Vec<[f32; 3], 4096>,
+        /// The corners of the triangle being read.
+        corners: [Vertex; 3],
+        next_corner: usize,
+        faces: usize,
+    }
+
+    impl ObjSink for Compare<'_> {
+        fn point(&mut self, point: [f32; 3]) -> Result<(), Capacity> {
+            self.points.push(point).map_err(|_| Capacity::Points)
+        }
+
+        fn tex_coord(&mut self, tex_coord: [f32; 2]) -> Result<(), Capacity> {
+            self.tex_coords
+                .push(tex_coord)
+                .map_err(|_| Capacity::TexCoords)
+        }
+
+        fn normal(&mut self, normal: [f32; 3]) -> Result<(), Capacity> {
+            self.normals.push(normal).map_err(|_| Capacity::Normals)
+        }
+
+        fn point_at(&self, index: usize) -> [f32; 3] {
+            self.points[index]
+        }
+
+        fn vertex(&mut self, key: VertexKey, polygon_normal: [f32; 3]) -> Result<u16, Capacity> {
+            let [x, y, z] = self.points[key.point];
+            let [u, v] = key.tex_coord.map_or([0.0; 2], |i| self.tex_coords[i]);
+            let [n_x, n_y, n_z] = match key.normal {
+                NormalKey::Given(i) => self.normals[i],
+                NormalKey::Polygon(_) => polygon_normal,
+            };
+            let len = sqrt(n_x * n_x + n_y * n_y + n_z * n_z);
+            let slot = self.next_corner;
+            self.corners[slot] = Vertex {
+                pos: Vec4 { x, y, z, w: 1.0 },
+                tex_coords: Vec2 { x: u, y: v },
+                normal: Vec4 {
+                    x: n_x / len,
+                    y: n_y / len,
+                    z: n_z / len,
+                    w: 0.0,
+                },
+            };
+            self.next_corner = (slot + 1) % 3;
+            Ok(slot as u16)
+        }
+
+        fn triangle(&mut self, verts: [u16; 3]) -> Result<(), Capacity> {
+            let face = self.baked.face(self.faces);
+            let attributes = |v: Vertex| {
+                [
+                    v.pos.x,
+                    v.pos.y,
+                    v.pos.z,
+                    v.tex_coords.x,
+                    v.tex_coords.y,
+                    v.normal.x,
+                    v.normal.y,
+                    v.normal.z,
+                ]
+            };
+            let [s_x, s_y, s_z] = self.baked.pos_scale;
+            let [t_u, t_v] = self.baked.uv_scale;
+            // Normals are quantised to steps of 1/127
+            let n = 1.0 / 127.0;
+            let steps = [s_x, s_y, s_z, t_u, t_v, n, n, n];
+
+            for (slot, index) in verts.into_iter().zip(face.verts) {
+                let parsed = attributes(self.corners[slot as usize]);
+                let baked = attributes(self.baked.vertex(index as usize));
+                assert!(
+                    (0..8).all(|i| (parsed[i] - baked[i]).abs() <= steps[i]),
+                    "face {}: {:?} baked as {:?}",
+                    self.faces,
+                    parsed,
+                    baked
+                );
+            }
+            self.faces += 1;
+            Ok(())
+        }
+    }
+
+    fn assert_round_trips(obj_file: &str, baked: &BakedMesh) {
+        let mut compare = Compare {
+            baked,
+            points: Vec::new(),
+            tex_coords: Vec::new(),
+            normals: Vec::new(),
+            corners: [baked.vertex(0); 3],
+            next_corner: 0,
+            faces: 0,
+        };
+        read_obj(obj_file, &mut compare).unwrap();
+        assert_eq!(compare.faces, baked.face_count());
+    }
+
+    #[test]
+    fn baked_meshes_decode_within_a_step_of_their_source() {
+        assert_round_trips(include_str!("../../assets/cube.obj"), &meshes::CUBE);
+        assert_round_trips(include_str!("../../assets/teapot.obj"), &meshes::TEAPOT);
+    }
+}
@@ -0,0 +1,245 @@
+use defmt::debug;
+use heapless::{String, Vec};
+
+use crate::{
+    model::parse::{Capacity, ObjError, ObjErrorKind, Tokens},
+    types::vector::Vec3,
+};
+
+/// The most materials a model can hold, across all of its libraries.
+pub const MAX_MATERIALS: usize = 16;
+/// The longest material or texture file name, in bytes.
+pub const MATERIAL_NAME_LEN: usize = 32;
+
+pub type MaterialName = String<MATERIAL_NAME_LEN>;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Surface properties for a group of faces, as
+/// authored in an MTL file.
+pub struct Material {
+    pub name: MaterialName,
+    /// `Ka`
+    pub ambient: Vec3<f32>,
+    /// `Kd`
+    pub diffuse: Vec3<f32>,
+    /// `Ks`
+    pub specular: Vec3<f32>,
+    /// `Ns`, the specular exponent from 0 to 1000.
+    pub shininess: f32,
+    /// `d`, from 0 (transparent) to 1 (opaque). The renderer
+    /// has no blending, so it is only kept for the application.
+    pub dissolve: f32,
+    /// `map_Kd`, the file name of the diffuse texture.
+    /// The diffuse colour tints the texture when set.
+    pub diffuse_map: Option<MaterialName>,
+}
+
+impl Material {
+    /// Creates a material with the MTL defaults, except that
+    /// `Ns` defaults to 32. An exponent of 0 would light every
+    /// surface facing a light with the full specular colour.
+    /// Names longer than `MATERIAL_NAME_LEN` are truncated.
+    pub fn new(name: &str) -> Material {
+        Material {
+            name: material_name(name),
+            ambient: Vec3 {
+                x: 0.2,
+                y: 0.2,
+                z: 0.2,
+            },
+            diffuse: Vec3 {
+                x: 0.8,
+                y: 0.8,
+                z: 0.8,
+            },
+            specular: Vec3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+            shininess: 32.0,
+            dissolve: 1.0,
+            diffuse_map: None,
+        }
+    }
+}
+
+/// Creates a list of materials from an MTL file.
+/// Unknown directives are skipped. Files with more than
+/// `MAX_MATERIALS` materials, or names longer than
+/// `MATERIAL_NAME_LEN`, are rejected rather than cut short.
+pub fn from_mtl(mtl_file: &str) -> Result<Vec<Material, MAX_MATERIALS>, ObjError> {
+    debug!("Loading materials");
+
+    let mut materials: Vec<Material, MAX_MATERIALS> = Vec::new();
+
+    for (number, line) in mtl_file.lines().enumerate() {
+        let mut tokens = Tokens::new(number, line);
+        let Some(token) = tokens.next() else {
+            continue;
+        };
+
+        if token == "newmtl" {
+            let name = tokens.rest();
+            if name.is_empty() {
+                return Err(tokens.error_at_end(ObjErrorKind::MissingValue));
+            }
+            if name.len() > MATERIAL_NAME_LEN {
+                return Err(tokens.error_at(name, ObjErrorKind::NameTooLong));
+            }
+            materials.push(Material::new(name)).map_err(|_| {
+                let required = mtl_file
+                    .lines()
+                    .filter(|l| Tokens::new(0, l).next() == Some("newmtl"))
+                    .count();
+                tokens.error_at(
+                    token,
+                    ObjErrorKind::CapacityExceeded {
+                        capacity: Capacity::Materials,
+                        required,
+                    },
+                )
+            })?;
+            debug!("Added material {}", name);
+            continue;
+        }
+
+        // Directives before the first material have nothing to apply to
+        let Some(material) = materials.last_mut() else {
+            continue;
+        };
+
+        match token {
+            "Ka" => material.ambient = parse_colour(&mut tokens)?,
+            "Kd" => material.diffuse = parse_colour(&mut tokens)?,
+            "Ks" => material.specular = parse_colour(&mut tokens)?,
+            "Ns" => material.shininess = tokens.parse()?,
+            "d" => material.dissolve = tokens.parse()?,
+            // Some exporters write transparency instead of dissolve
+            "Tr" => material.dissolve = 1.0 - tokens.parse::<f32>()?,
+            "map_Kd" => {
+                // Options such as `-s` come before the file name
+                let file = tokens
+                    .rest()
+                    .rsplit(char::is_whitespace)
+                    .next()
+                    .unwrap_or("");
+                if file.is_empty() {
+                    return Err(tokens.error_at_end(ObjErrorKind::MissingValue));
+                }
+                if file.len() > MATERIAL_NAME_LEN {
+                    return Err(tokens.error_at(file, ObjErrorKind::NameTooLong));
+                }
+                material.diffuse_map = Some(material_name(file));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(materials)
+}
+
+/// Reads an RGB triple. A single value is used for all three channels.
+fn parse_colour(tokens: &mut Tokens) -> Result<Vec3<f32>, ObjError> {
+    let x: f32 = tokens.parse()?;
+    Ok(Vec3 {
+        x,
+        y: tokens.parse_or(x)?,
+        z: tokens.parse_or(x)?,
+    })
+}
+
+/// Copies a name, truncated to fit in a `MaterialName`.
+pub fn material_name(name: &str) -> MaterialName {
+    let mut out = MaterialName::new();
+    for c in name.chars() {
+        if out.push(c).is_err() {
+            break;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(v: Vec3<f32>) -> (f32, f32, f32) {
+        (v.x, v.y, v.z)
+    }
+
+    #[test]
+    fn directives_set_their_values() {
+        let mtl = "
+# Ignored until the first material
+Kd 0 0 0
+newmtl red
+Ka 0.1 0.2 0.3
+Kd 1 0 0
+Ks 0.5
+Ns 100
+d 0.25
+map_Kd -s 2 2 1 textures/red.png
+newmtl glass
+Tr 0.75
+";
+        let materials = from_mtl(mtl).unwrap();
+        assert_eq!(materials.len(), 2);
+
+        let red = &materials[0];
+        assert_eq!(red.name, "red");
+        assert_eq!(rgb(red.ambient), (0.1, 0.2, 0.3));
+        assert_eq!(rgb(red.diffuse), (1.0, 0.0, 0.0));
+        // A single value sets all three channels
+        assert_eq!(rgb(red.specular), (0.5, 0.5, 0.5));
+        assert_eq!(red.shininess, 100.0);
+        assert_eq!(red.dissolve, 0.25);
+        // Options before the file name are skipped
+        assert_eq!(red.diffuse_map.as_deref(), Some("textures/red.png"));
+
+        let glass = &materials[1];
+        assert_eq!(glass.name, "glass");
+        assert_eq!(glass.dissolve, 0.25);
+        assert_eq!(glass.diffuse_map, None);
+        assert_eq!(
+            *glass,
+            Material {
+                dissolve: 0.25,
+                ..Material::new("glass")
+            }
+        );
+    }
+
+    #[test]
+    fn limits_are_errors() {
+        let long = "newmtl a_material_name_longer_than_32_bytes";
+        let e = from_mtl(long).unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (1, 8, ObjErrorKind::NameTooLong)
+        );
+
+        let long = "newmtl a\nmap_Kd -bm 1 a_texture_file_name_over_32_bytes.png";
+        let e = from_mtl(long).unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (2, 14, ObjErrorKind::NameTooLong)
+        );
+
+        let mut many = heapless::String::<512>::new();
+        for i in 0..=MAX_MATERIALS {
+            core::fmt::Write::write_fmt(&mut many, format_args!("newmtl m{}\n", i)).unwrap();
+        }
+        let e = from_mtl(&many).unwrap_err();
+        assert_eq!(
+            (e.line, e.kind),
+            (
+                MAX_MATERIALS + 1,
+                ObjErrorKind::CapacityExceeded {
+                    capacity: Capacity::Materials,
+                    required: MAX_MATERIALS + 1,
+                }
+            )
+        );
+    }
+}
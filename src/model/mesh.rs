@@ -0,0 +1,40 @@
+use crate::model::{
+    material::Material,
+    model::{Face, Vertex},
+};
+
+/// Read access to triangle geometry, however it is stored.
+/// Implemented by models parsed at runtime and by meshes
+/// baked into flash at build time. Meshes of different
+/// types can be mixed as `&dyn Mesh`.
+pub trait Mesh {
+    fn vertex_count(&self) -> usize;
+
+    /// Returns a vertex in model space.
+    fn vertex(&self, index: usize) -> Vertex;
+
+    fn face_count(&self) -> usize;
+
+    fn face(&self, index: usize) -> Face;
+
+    /// Returns the material of a face, if it has one.
+    fn material(&self, _face: &Face) -> Option<&Material> {
+        None
+    }
+
+    /// Iterates over every vertex in order.
+    fn vertices(&self) -> impl Iterator<Item = Vertex> + '_
+    where
+        Self: Sized,
+    {
+        (0..self.vertex_count()).map(|i| self.vertex(i))
+    }
+
+    /// Iterates over every face in order.
+    fn faces(&self) -> impl Iterator<Item = Face> + '_
+    where
+        Self: Sized,
+    {
+        (0..self.face_count()).map(|i| self.face(i))
+    }
+}
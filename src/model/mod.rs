@@ -1,16266 +1,9 @@
+pub mod baked;
+pub mod material;
+pub mod mesh;
+#[allow(clippy::module_inception)]
 pub mod model;
+mod obj;
+pub mod parse;
 
-pub const CUBE_OBJ: &str = "v -1.000000 -1.000000 1.000000
-v -1.000000 1.000000 1.000000
-v -1.000000 -1.000000 -1.000000
-v -1.000000 1.000000 -1.000000
-v 1.000000 -1.000000 1.000000
-v 1.000000 1.000000 1.000000
-v 1.000000 -1.000000 -1.000000
-v 1.000000 1.000000 -1.000000
-vn -1.0000 -0.0000 -0.0000
-vn -0.0000 -0.0000 -1.0000
-vn 1.0000 -0.0000 -0.0000
-vn -0.0000 -0.0000 1.0000
-vn -0.0000 -1.0000 -0.0000
-vn -0.0000 1.0000 -0.0000
-vt 0.625000 0.000000
-vt 0.375000 0.250000
-vt 0.375000 0.000000
-vt 0.625000 0.250000
-vt 0.375000 0.500000
-vt 0.625000 0.500000
-vt 0.375000 0.750000
-vt 0.625000 0.750000
-vt 0.375000 1.000000
-vt 0.125000 0.750000
-vt 0.125000 0.500000
-vt 0.875000 0.500000
-vt 0.625000 1.000000
-vt 0.875000 0.750000
-s 0
-f 2/1/1 3/2/1 1/3/1
-f 4/4/2 7/5/2 3/2/2
-f 8/6/3 5/7/3 7/5/3
-f 6/8/4 1/9/4 5/7/4
-f 7/5/5 1/10/5 3/11/5
-f 4/12/6 6/8/6 8/6/6
-f 2/1/1 4/4/1 3/2/1
-f 4/4/2 8/6/2 7/5/2
-f 8/6/3 6/8/3 5/7/3
-f 6/8/4 2/13/4 1/9/4
-f 7/5/5 5/7/5 1/10/5
-f 4/12/6 2/14/6 6/8/6
-";
-
-/* pub const TEAPOT_OBJ: &str = "v 3.5 6 -2.62268e-07
-v 3.45492 6 0.57428
-v 3.42019 6.08859 0.568506
-v 3.46481 6.08859 -2.66183e-07
-v 3.40655 6.1575 0.56624
-v 3.451 6.1575 -2.69111e-07
-v 3.41105 6.20672 0.566988
-v 3.45556 6.20672 -2.71286e-07
-v 3.43074 6.23625 0.57026
-v 3.4755 6.23625 -2.7259e-07
-v 3.46263 6.24609 0.575562
-v 3.50781 6.24609 -2.73025e-07
-v 3.50378 6.23625 0.582402
-v 3.5495 6.23625 -2.7259e-07
-v 3.55123 6.20672 0.590288
-v 3.59756 6.20672 -2.71286e-07
-v 3.602 6.1575 0.598728
-v 3.649 6.1575 -2.69111e-07
-v 3.65315 6.08859 0.607229
-v 3.70081 6.08859 -2.66183e-07
-v 3.7017 6 0.6153
-v 3.75 6 -2.62268e-07
-v 3.32416 6 1.11664
-v 3.29074 6.08859 1.10541
-v 3.27762 6.1575 1.10101
-v 3.28196 6.20672 1.10246
-v 3.30089 6.23625 1.10882
-v 3.33158 6.24609 1.11913
-v 3.37117 6.23625 1.13243
-v 3.41682 6.20672 1.14777
-v 3.46567 6.1575 1.16418
-v 3.51488 6.08859 1.18071
-v 3.5616 6 1.1964
-v 3.11444 6 1.62036
-v 3.08313 6.08859 1.60407
-v 3.07084 6.1575 1.59767
-v 3.0749 6.20672 1.59979
-v 3.09264 6.23625 1.60902
-v 3.12139 6.24609 1.62398
-v 3.15849 6.23625 1.64328
-v 3.20125 6.20672 1.66553
-v 3.24703 6.1575 1.68934
-v 3.29313 6.08859 1.71333
-v 3.3369 6 1.7361
-v 2.83248 6 2.07872
-v 2.804 6.08859 2.05782
-v 2.79283 6.1575 2.04962
-v 2.79652 6.20672 2.05233
-v 2.81265 6.23625 2.06417
-v 2.8388 6.24609 2.08336
-v 2.87254 6.23625 2.10812
-v 2.91144 6.20672 2.13666
-v 2.95306 6.1575 2.16721
-v 2.99499 6.08859 2.19799
-v 3.0348 6 2.2272
-v 2.485 6 2.485
-v 2.46002 6.08859 2.46002
-v 2.45021 6.1575 2.45021
-v 2.45345 6.20672 2.45345
-v 2.46761 6.23625 2.4676
-v 2.49055 6.24609 2.49055
-v 2.52014 6.23625 2.52014
-v 2.55427 6.20672 2.55427
-v 2.59079 6.1575 2.59079
-v 2.62758 6.08859 2.62758
-v 2.6625 6 2.6625
-v 2.07872 6 2.83248
-v 2.05782 6.08859 2.804
-v 2.04962 6.1575 2.79283
-v 2.05233 6.20672 2.79652
-v 2.06417 6.23625 2.81265
-v 2.08336 6.24609 2.8388
-v 2.10812 6.23625 2.87254
-v 2.13666 6.20672 2.91144
-v 2.16721 6.1575 2.95306
-v 2.19799 6.08859 2.99499
-v 2.2272 6 3.0348
-v 1.62036 6 3.11444
-v 1.60407 6.08859 3.08313
-v 1.59767 6.1575 3.07084
-v 1.59979 6.20672 3.0749
-v 1.60902 6.23625 3.09264
-v 1.62398 6.24609 3.12139
-v 1.64328 6.23625 3.15849
-v 1.66553 6.20672 3.20126
-v 1.68934 6.1575 3.24703
-v 1.71333 6.08859 3.29313
-v 1.7361 6 3.3369
-v 1.11664 6 3.32416
-v 1.10541 6.08859 3.29074
-v 1.10101 6.1575 3.27762
-v 1.10246 6.20672 3.28196
-v 1.10882 6.23625 3.30089
-v 1.11913 6.24609 3.33158
-v 1.13243 6.23625 3.37117
-v 1.14777 6.20672 3.41682
-v 1.16418 6.1575 3.46567
-v 1.18071 6.08859 3.51488
-v 1.1964 6 3.5616
-v 0.574279 6 3.45492
-v 0.568506 6.08859 3.42019
-v 0.56624 6.1575 3.40655
-v 0.566988 6.20672 3.41105
-v 0.57026 6.23625 3.43074
-v 0.575561 6.24609 3.46263
-v 0.582401 6.23625 3.50378
-v 0.590288 6.20672 3.55123
-v 0.598727 6.1575 3.602
-v 0.607229 6.08859 3.65315
-v 0.615299 6 3.7017
-v -7.00937e-07 6 3.5
-v -6.93952e-07 6.08859 3.46481
-v -6.91158e-07 6.1575 3.451
-v -6.92089e-07 6.20672 3.45556
-v -6.96047e-07 6.23625 3.4755
-v -7.02566e-07 6.24609 3.50781
-v -7.10832e-07 6.23625 3.5495
-v -7.20494e-07 6.20672 3.59756
-v -7.30739e-07 6.1575 3.649
-v -7.41216e-07 6.08859 3.70081
-v -7.50995e-07 6 3.75
-v -0.628955 6 3.45492
-v -0.608364 6.08859 3.42019
-v -0.594234 6.1575 3.40655
-v -0.585742 6.20672 3.41105
-v -0.58207 6.23625 3.43074
-v -0.582396 6.24609 3.46263
-v -0.585901 6.23625 3.50378
-v -0.591764 6.20672 3.55123
-v -0.599165 6.1575 3.602
-v -0.607284 6.08859 3.65315
-v -0.6153 6 3.7017
-v -1.20304 6 3.32416
-v -1.1684 6.08859 3.29074
-v -1.14524 6.1575 3.27762
-v -1.1321 6.20672 3.28196
-v -1.12749 6.23625 3.30089
-v -1.12993 6.24609 3.33158
-v -1.13796 6.23625 3.37117
-v -1.1501 6.20672 3.41682
-v -1.16487 6.1575 3.46567
-v -1.18079 6.08859 3.51488
-v -1.1964 6 3.5616
-v -1.71959 6 3.11444
-v -1.6764 6.08859 3.08313
-v -1.64848 6.1575 3.07084
-v -1.63382 6.20672 3.0749
-v -1.63045 6.23625 3.09264
-v -1.63638 6.24609 3.12139
-v -1.64963 6.23625 3.15849
-v -1.66821 6.20672 3.20125
-v -1.69014 6.1575 3.24703
-v -1.71343 6.08859 3.29313
-v -1.7361 6 3.3369
-v -2.17592 6 2.83248
-v -2.12868 6.08859 2.804
-v -2.09938 6.1575 2.79283
-v -2.08567 6.20672 2.79652
-v -2.08516 6.23625 2.81265
-v -2.09551 6.24609 2.8388
-v -2.11434 6.23625 2.87254
-v -2.13929 6.20672 2.91144
-v -2.16799 6.1575 2.95306
-v -2.19808 6.08859 2.99499
-v -2.2272 6 3.0348
-v -2.56938 6 2.485
-v -2.52153 6.08859 2.46002
-v -2.49341 6.1575 2.45021
-v -2.48239 6.20672 2.45345
-v -2.48583 6.23625 2.4676
-v -2.50109 6.24609 2.49055
-v -2.52555 6.23625 2.52014
-v -2.55655 6.20672 2.55427
-v -2.59146 6.1575 2.59079
-v -2.62766 6.08859 2.62758
-v -2.6625 6 2.6625
-v -2.89728 6 2.07872
-v -2.85124 6.08859 2.05782
-v -2.826 6.1575 2.04962
-v -2.81874 6.20672 2.05233
-v -2.82665 6.23625 2.06417
-v -2.8469 6.24609 2.08336
-v -2.87669 6.23625 2.10812
-v -2.91319 6.20672 2.13666
-v -2.95358 6.1575 2.16721
-v -2.99506 6.08859 2.19799
-v -3.0348 6 2.2272
-v -3.15697 6 1.62036
-v -3.11413 6.08859 1.60407
-v -3.09261 6.1575 1.59767
-v -3.08948 6.20672 1.59979
-v -3.10182 6.23625 1.60902
-v -3.12671 6.24609 1.62398
-v -3.16121 6.23625 1.64328
-v -3.2024 6.20672 1.66553
-v -3.24737 6.1575 1.68934
-v -3.29317 6.08859 1.71333
-v -3.3369 6 1.7361
-v -3.34576 6 1.11664
-v -3.30649 6.08859 1.10541
-v -3.28868 6.1575 1.10101
-v -3.28936 6.20672 1.10246
-v -3.30556 6.23625 1.10882
-v -3.33428 6.24609 1.11913
-v -3.37256 6.23625 1.13243
-v -3.4174 6.20672 1.14777
-v -3.46585 6.1575 1.16418
-v -3.51491 6.08859 1.18071
-v -3.5616 6 1.1964
-v -3.46099 6 0.574279
-v -3.42461 6.08859 0.568506
-v -3.40966 6.1575 0.566239
-v -3.41314 6.20672 0.566988
-v -3.43205 6.23625 0.570259
-v -3.46339 6.24609 0.575561
-v -3.50417 6.23625 0.582401
-v -3.55139 6.20672 0.590287
-v -3.60205 6.1575 0.598727
-v -3.65315 6.08859 0.607229
-v -3.7017 6 0.615299
-v -3.5 6 -9.63205e-07
-v -3.46481 6.08859 -9.60018e-07
-v -3.451 6.1575 -9.60269e-07
-v -3.45556 6.20672 -9.63375e-07
-v -3.4755 6.23625 -9.68638e-07
-v -3.50781 6.24609 -9.75592e-07
-v -3.5495 6.23625 -9.83423e-07
-v -3.59756 6.20672 -9.9178e-07
-v -3.649 6.1575 -9.99966e-07
-v -3.70081 6.08859 -1.00728e-06
-v -3.75 6 -1.01326e-06
-v -3.45492 6 -0.57428
-v -3.42019 6.08859 -0.568507
-v -3.40655 6.1575 -0.56624
-v -3.41105 6.20672 -0.566989
-v -3.43074 6.23625 -0.57026
-v -3.46263 6.24609 -0.575562
-v -3.50378 6.23625 -0.582402
-v -3.55123 6.20672 -0.590288
-v -3.602 6.1575 -0.598728
-v -3.65315 6.08859 -0.60723
-v -3.7017 6 -0.6153
-v -3.32416 6 -1.11664
-v -3.29074 6.08859 -1.10541
-v -3.27762 6.1575 -1.10101
-v -3.28196 6.20672 -1.10246
-v -3.30089 6.23625 -1.10882
-v -3.33158 6.24609 -1.11913
-v -3.37117 6.23625 -1.13243
-v -3.41682 6.20672 -1.14777
-v -3.46567 6.1575 -1.16418
-v -3.51488 6.08859 -1.18071
-v -3.5616 6 -1.1964
-v -3.11444 6 -1.62036
-v -3.08313 6.08859 -1.60407
-v -3.07084 6.1575 -1.59768
-v -3.0749 6.20672 -1.59979
-v -3.09264 6.23625 -1.60902
-v -3.12139 6.24609 -1.62398
-v -3.15849 6.23625 -1.64328
-v -3.20125 6.20672 -1.66553
-v -3.24703 6.1575 -1.68934
-v -3.29313 6.08859 -1.71333
-v -3.3369 6 -1.7361
-v -2.83248 6 -2.07872
-v -2.804 6.08859 -2.05782
-v -2.79283 6.1575 -2.04962
-v -2.79652 6.20672 -2.05233
-v -2.81265 6.23625 -2.06417
-v -2.8388 6.24609 -2.08336
-v -2.87254 6.23625 -2.10812
-v -2.91144 6.20672 -2.13666
-v -2.95306 6.1575 -2.16721
-v -2.99499 6.08859 -2.19799
-v -3.0348 6 -2.2272
-v -2.485 6 -2.485
-v -2.46002 6.08859 -2.46002
-v -2.45021 6.1575 -2.45021
-v -2.45345 6.20672 -2.45345
-v -2.46761 6.23625 -2.46761
-v -2.49055 6.24609 -2.49055
-v -2.52014 6.23625 -2.52015
-v -2.55427 6.20672 -2.55427
-v -2.59079 6.1575 -2.59079
-v -2.62758 6.08859 -2.62758
-v -2.6625 6 -2.6625
-v -2.07872 6 -2.83248
-v -2.05782 6.08859 -2.804
-v -2.04962 6.1575 -2.79283
-v -2.05233 6.20672 -2.79652
-v -2.06417 6.23625 -2.81265
-v -2.08336 6.24609 -2.8388
-v -2.10812 6.23625 -2.87254
-v -2.13666 6.20672 -2.91144
-v -2.16721 6.1575 -2.95306
-v -2.19799 6.08859 -2.99499
-v -2.2272 6 -3.0348
-v -1.62036 6 -3.11444
-v -1.60407 6.08859 -3.08313
-v -1.59767 6.1575 -3.07084
-v -1.59979 6.20672 -3.0749
-v -1.60902 6.23625 -3.09264
-v -1.62398 6.24609 -3.12139
-v -1.64328 6.23625 -3.15849
-v -1.66553 6.20672 -3.20126
-v -1.68934 6.1575 -3.24703
-v -1.71333 6.08859 -3.29313
-v -1.7361 6 -3.3369
-v -1.11664 6 -3.32416
-v -1.10541 6.08859 -3.29074
-v -1.10101 6.1575 -3.27762
-v -1.10246 6.20672 -3.28196
-v -1.10882 6.23625 -3.30089
-v -1.11913 6.24609 -3.33158
-v -1.13243 6.23625 -3.37117
-v -1.14777 6.20672 -3.41682
-v -1.16418 6.1575 -3.46567
-v -1.18071 6.08859 -3.51488
-v -1.1964 6 -3.5616
-v -0.574279 6 -3.45492
-v -0.568506 6.08859 -3.42019
-v -0.56624 6.1575 -3.40655
-v -0.566988 6.20672 -3.41106
-v -0.57026 6.23625 -3.43074
-v -0.575561 6.24609 -3.46263
-v -0.582401 6.23625 -3.50378
-v -0.590288 6.20672 -3.55123
-v -0.598727 6.1575 -3.602
-v -0.607229 6.08859 -3.65315
-v -0.615299 6 -3.7017
-v 7.00937e-07 6 -3.5
-v 6.93952e-07 6.08859 -3.46481
-v 6.91158e-07 6.1575 -3.451
-v 6.92089e-07 6.20672 -3.45556
-v 6.96047e-07 6.23625 -3.4755
-v 7.02566e-07 6.24609 -3.50781
-v 7.10832e-07 6.23625 -3.5495
-v 7.20494e-07 6.20672 -3.59756
-v 7.30739e-07 6.1575 -3.649
-v 7.41216e-07 6.08859 -3.70081
-v 7.50995e-07 6 -3.75
-v 0.57428 6 -3.45492
-v 0.568506 6.08859 -3.42019
-v 0.56624 6.1575 -3.40655
-v 0.566989 6.20672 -3.41106
-v 0.57026 6.23625 -3.43074
-v 0.575562 6.24609 -3.46263
-v 0.582402 6.23625 -3.50378
-v 0.590288 6.20672 -3.55123
-v 0.598728 6.1575 -3.602
-v 0.607229 6.08859 -3.65315
-v 0.6153 6 -3.7017
-v 1.11664 6 -3.32416
-v 1.10541 6.08859 -3.29074
-v 1.10101 6.1575 -3.27762
-v 1.10246 6.20672 -3.28196
-v 1.10882 6.23625 -3.30089
-v 1.11913 6.24609 -3.33158
-v 1.13243 6.23625 -3.37117
-v 1.14777 6.20672 -3.41682
-v 1.16418 6.1575 -3.46567
-v 1.18071 6.08859 -3.51488
-v 1.1964 6 -3.5616
-v 1.62036 6 -3.11444
-v 1.60407 6.08859 -3.08313
-v 1.59768 6.1575 -3.07084
-v 1.59979 6.20672 -3.0749
-v 1.60902 6.23625 -3.09264
-v 1.62398 6.24609 -3.12139
-v 1.64328 6.23625 -3.15849
-v 1.66553 6.20672 -3.20126
-v 1.68934 6.1575 -3.24703
-v 1.71333 6.08859 -3.29313
-v 1.7361 6 -3.3369
-v 2.07872 6 -2.83248
-v 2.05782 6.08859 -2.804
-v 2.04962 6.1575 -2.79283
-v 2.05233 6.20672 -2.79652
-v 2.06417 6.23625 -2.81265
-v 2.08336 6.24609 -2.8388
-v 2.10812 6.23625 -2.87254
-v 2.13666 6.20672 -2.91144
-v 2.16721 6.1575 -2.95306
-v 2.19799 6.08859 -2.99499
-v 2.2272 6 -3.0348
-v 2.485 6 -2.485
-v 2.46002 6.08859 -2.46002
-v 2.45021 6.1575 -2.45021
-v 2.45345 6.20672 -2.45345
-v 2.46761 6.23625 -2.46761
-v 2.49055 6.24609 -2.49055
-v 2.52014 6.23625 -2.52015
-v 2.55427 6.20672 -2.55427
-v 2.59079 6.1575 -2.59079
-v 2.62758 6.08859 -2.62758
-v 2.6625 6 -2.6625
-v 2.83248 6 -2.07872
-v 2.804 6.08859 -2.05782
-v 2.79283 6.1575 -2.04962
-v 2.79652 6.20672 -2.05233
-v 2.81265 6.23625 -2.06417
-v 2.8388 6.24609 -2.08336
-v 2.87254 6.23625 -2.10812
-v 2.91144 6.20672 -2.13666
-v 2.95306 6.1575 -2.16721
-v 2.99499 6.08859 -2.19799
-v 3.0348 6 -2.2272
-v 3.11444 6 -1.62036
-v 3.08313 6.08859 -1.60407
-v 3.07084 6.1575 -1.59768
-v 3.0749 6.20672 -1.59979
-v 3.09264 6.23625 -1.60902
-v 3.12139 6.24609 -1.62398
-v 3.15849 6.23625 -1.64328
-v 3.20126 6.20672 -1.66553
-v 3.24703 6.1575 -1.68934
-v 3.29313 6.08859 -1.71333
-v 3.3369 6 -1.7361
-v 3.32416 6 -1.11664
-v 3.29074 6.08859 -1.10541
-v 3.27762 6.1575 -1.10101
-v 3.28196 6.20672 -1.10246
-v 3.30089 6.23625 -1.10882
-v 3.33158 6.24609 -1.11913
-v 3.37117 6.23625 -1.13243
-v 3.41682 6.20672 -1.14777
-v 3.46567 6.1575 -1.16418
-v 3.51488 6.08859 -1.18071
-v 3.5616 6 -1.1964
-v 3.45492 6 -0.57428
-v 3.42019 6.08859 -0.568506
-v 3.40655 6.1575 -0.56624
-v 3.41105 6.20672 -0.566988
-v 3.43074 6.23625 -0.57026
-v 3.46263 6.24609 -0.575562
-v 3.50378 6.23625 -0.582402
-v 3.55123 6.20672 -0.590288
-v 3.602 6.1575 -0.598728
-v 3.65315 6.08859 -0.607229
-v 3.7017 6 -0.6153
-v 3.88617 5.60644 0.645962
-v 3.93687 5.60644 -2.45123e-07
-v 4.06693 5.214 0.676009
-v 4.12 5.214 -2.27861e-07
-v 4.2403 4.82381 0.704826
-v 4.29563 4.82381 -2.10831e-07
-v 4.40256 4.437 0.731797
-v 4.46 4.437 -1.93918e-07
-v 4.55001 4.05469 0.756306
-v 4.60938 4.05469 -1.77236e-07
-v 4.67895 3.678 0.777739
-v 4.74 3.678 -1.60786e-07
-v 4.78568 3.30806 0.79548
-v 4.84812 3.30806 -1.44568e-07
-v 4.8665 2.946 0.808914
-v 4.93 2.946 -1.28815e-07
-v 4.91771 2.59294 0.817426
-v 4.98187 2.59294 -1.13292e-07
-v 4.9356 2.25 0.8204
-v 5 2.25 -9.83506e-08
-v 3.73909 5.60644 1.25602
-v 3.91301 5.214 1.31444
-v 4.07981 4.82381 1.37048
-v 4.23593 4.437 1.42292
-v 4.3778 4.05469 1.47057
-v 4.50186 3.678 1.51225
-v 4.60456 3.30806 1.54675
-v 4.68232 2.946 1.57287
-v 4.73159 2.59294 1.58942
-v 4.7488 2.25 1.5952
-v 3.50319 5.60644 1.82262
-v 3.66614 5.214 1.9074
-v 3.82242 4.82381 1.9887
-v 3.96869 4.437 2.0648
-v 4.10161 4.05469 2.13396
-v 4.21784 3.678 2.19443
-v 4.31406 3.30806 2.24449
-v 4.38691 2.946 2.28239
-v 4.43307 2.59294 2.30641
-v 4.4492 2.25 2.3148
-v 3.18603 5.60644 2.33819
-v 3.33423 5.214 2.44695
-v 3.47636 4.82381 2.55126
-v 3.60939 4.437 2.64888
-v 3.73028 4.05469 2.7376
-v 3.83599 3.678 2.81518
-v 3.92349 3.30806 2.8794
-v 3.98975 2.946 2.92803
-v 4.03173 2.59294 2.95884
-v 4.0464 2.25 2.9696
-v 2.79518 5.60644 2.79518
-v 2.9252 5.214 2.9252
-v 3.04989 4.82381 3.04989
-v 3.1666 4.437 3.1666
-v 3.27266 4.05469 3.27266
-v 3.3654 3.678 3.3654
-v 3.44217 3.30806 3.44217
-v 3.5003 2.946 3.5003
-v 3.53713 2.59294 3.53713
-v 3.55 2.25 3.55
-v 2.33819 5.60644 3.18603
-v 2.44695 5.214 3.33423
-v 2.55126 4.82381 3.47636
-v 2.64888 4.437 3.60939
-v 2.7376 4.05469 3.73027
-v 2.81518 3.678 3.83599
-v 2.8794 3.30806 3.92349
-v 2.92803 2.946 3.98975
-v 2.95884 2.59294 4.03173
-v 2.9696 2.25 4.0464
-v 1.82262 5.60644 3.50319
-v 1.9074 5.214 3.66614
-v 1.9887 4.82381 3.82242
-v 2.0648 4.437 3.96869
-v 2.13396 4.05469 4.10161
-v 2.19443 3.678 4.21784
-v 2.24449 3.30806 4.31406
-v 2.28239 2.946 4.38691
-v 2.30641 2.59294 4.43307
-v 2.3148 2.25 4.4492
-v 1.25602 5.60644 3.73909
-v 1.31444 5.214 3.91301
-v 1.37048 4.82381 4.07981
-v 1.42292 4.437 4.23593
-v 1.47057 4.05469 4.3778
-v 1.51225 3.678 4.50186
-v 1.54675 3.30806 4.60456
-v 1.57287 2.946 4.68232
-v 1.58942 2.59294 4.73159
-v 1.5952 2.25 4.7488
-v 0.645962 5.60644 3.88617
-v 0.676009 5.214 4.06693
-v 0.704826 4.82381 4.2403
-v 0.731796 4.437 4.40256
-v 0.756306 4.05469 4.55001
-v 0.777738 3.678 4.67895
-v 0.79548 3.30806 4.78568
-v 0.808914 2.946 4.8665
-v 0.817425 2.59294 4.91771
-v 0.820399 2.25 4.9356
-v -7.88481e-07 5.60644 3.93687
-v -8.25152e-07 5.214 4.12
-v -8.60309e-07 4.82381 4.29563
-v -8.93255e-07 4.437 4.46
-v -9.23174e-07 4.05469 4.60938
-v -9.49251e-07 3.678 4.74
-v -9.70904e-07 3.30806 4.84812
-v -9.87318e-07 2.946 4.93
-v -9.97679e-07 2.59294 4.98187
-v -1.0014e-06 2.25 5
-v -0.645962 5.60644 3.88617
-v -0.67601 5.214 4.06693
-v -0.704826 4.82381 4.2403
-v -0.731797 4.437 4.40256
-v -0.756306 4.05469 4.55001
-v -0.777739 3.678 4.67895
-v -0.79548 3.30806 4.78568
-v -0.808914 2.946 4.8665
-v -0.817426 2.59294 4.91771
-v -0.8204 2.25 4.9356
-v -1.25602 5.60644 3.73909
-v -1.31444 5.214 3.91301
-v -1.37048 4.82381 4.07981
-v -1.42292 4.437 4.23593
-v -1.47058 4.05469 4.3778
-v -1.51225 3.678 4.50186
-v -1.54675 3.30806 4.60456
-v -1.57287 2.946 4.68232
-v -1.58942 2.59294 4.73159
-v -1.5952 2.25 4.7488
-v -1.82262 5.60644 3.50319
-v -1.9074 5.214 3.66614
-v -1.9887 4.82381 3.82242
-v -2.0648 4.437 3.96869
-v -2.13396 4.05469 4.10161
-v -2.19443 3.678 4.21784
-v -2.24449 3.30806 4.31406
-v -2.28239 2.946 4.38691
-v -2.30641 2.59294 4.43307
-v -2.3148 2.25 4.4492
-v -2.33819 5.60644 3.18603
-v -2.44695 5.214 3.33423
-v -2.55126 4.82381 3.47636
-v -2.64888 4.437 3.60939
-v -2.7376 4.05469 3.73027
-v -2.81518 3.678 3.83599
-v -2.8794 3.30806 3.92349
-v -2.92803 2.946 3.98975
-v -2.95884 2.59294 4.03173
-v -2.9696 2.25 4.0464
-v -2.79518 5.60644 2.79518
-v -2.9252 5.214 2.9252
-v -3.04989 4.82381 3.04989
-v -3.1666 4.437 3.1666
-v -3.27266 4.05469 3.27266
-v -3.3654 3.678 3.3654
-v -3.44217 3.30806 3.44217
-v -3.5003 2.946 3.5003
-v -3.53713 2.59294 3.53713
-v -3.55 2.25 3.55
-v -3.18603 5.60644 2.33819
-v -3.33423 5.214 2.44695
-v -3.47636 4.82381 2.55126
-v -3.60939 4.437 2.64888
-v -3.73028 4.05469 2.7376
-v -3.83599 3.678 2.81518
-v -3.92349 3.30806 2.8794
-v -3.98975 2.946 2.92803
-v -4.03173 2.59294 2.95884
-v -4.0464 2.25 2.9696
-v -3.50319 5.60644 1.82262
-v -3.66614 5.214 1.90739
-v -3.82242 4.82381 1.9887
-v -3.96869 4.437 2.0648
-v -4.10161 4.05469 2.13396
-v -4.21784 3.678 2.19443
-v -4.31406 3.30806 2.24449
-v -4.38691 2.946 2.28239
-v -4.43307 2.59294 2.30641
-v -4.4492 2.25 2.3148
-v -3.73909 5.60644 1.25602
-v -3.91301 5.214 1.31444
-v -4.07981 4.82381 1.37048
-v -4.23593 4.437 1.42292
-v -4.3778 4.05469 1.47057
-v -4.50186 3.678 1.51225
-v -4.60456 3.30806 1.54675
-v -4.68232 2.946 1.57287
-v -4.73159 2.59294 1.58942
-v -4.7488 2.25 1.5952
-v -3.88617 5.60644 0.645962
-v -4.06693 5.214 0.676009
-v -4.2403 4.82381 0.704825
-v -4.40256 4.437 0.731796
-v -4.55001 4.05469 0.756305
-v -4.67895 3.678 0.777738
-v -4.78568 3.30806 0.79548
-v -4.8665 2.946 0.808914
-v -4.91771 2.59294 0.817425
-v -4.9356 2.25 0.820399
-v -3.93687 5.60644 -1.03349e-06
-v -4.12 5.214 -1.05301e-06
-v -4.29563 4.82381 -1.07114e-06
-v -4.46 4.437 -1.08717e-06
-v -4.60938 4.05469 -1.10041e-06
-v -4.74 3.678 -1.11004e-06
-v -4.84812 3.30806 -1.11559e-06
-v -4.93 2.946 -1.11613e-06
-v -4.98187 2.59294 -1.11109e-06
-v -5 2.25 -1.09976e-06
-v -3.88617 5.60644 -0.645963
-v -4.06693 5.214 -0.67601
-v -4.2403 4.82381 -0.704826
-v -4.40256 4.437 -0.731797
-v -4.55001 4.05469 -0.756306
-v -4.67895 3.678 -0.777739
-v -4.78568 3.30806 -0.79548
-v -4.8665 2.946 -0.808915
-v -4.91771 2.59294 -0.817426
-v -4.9356 2.25 -0.8204
-v -3.73909 5.60644 -1.25602
-v -3.91301 5.214 -1.31445
-v -4.07981 4.82381 -1.37048
-v -4.23593 4.437 -1.42292
-v -4.3778 4.05469 -1.47058
-v -4.50186 3.678 -1.51225
-v -4.60456 3.30806 -1.54675
-v -4.68232 2.946 -1.57287
-v -4.73159 2.59294 -1.58942
-v -4.7488 2.25 -1.5952
-v -3.50319 5.60644 -1.82262
-v -3.66614 5.214 -1.9074
-v -3.82242 4.82381 -1.9887
-v -3.96869 4.437 -2.0648
-v -4.10161 4.05469 -2.13396
-v -4.21784 3.678 -2.19443
-v -4.31406 3.30806 -2.24449
-v -4.38691 2.946 -2.28239
-v -4.43307 2.59294 -2.30641
-v -4.4492 2.25 -2.3148
-v -3.18603 5.60644 -2.33819
-v -3.33423 5.214 -2.44695
-v -3.47636 4.82381 -2.55126
-v -3.60939 4.437 -2.64888
-v -3.73028 4.05469 -2.7376
-v -3.83599 3.678 -2.81518
-v -3.92349 3.30806 -2.8794
-v -3.98975 2.946 -2.92803
-v -4.03173 2.59294 -2.95884
-v -4.0464 2.25 -2.9696
-v -2.79518 5.60644 -2.79518
-v -2.9252 5.214 -2.9252
-v -3.04989 4.82381 -3.04989
-v -3.1666 4.437 -3.1666
-v -3.27266 4.05469 -3.27266
-v -3.3654 3.678 -3.3654
-v -3.44217 3.30806 -3.44217
-v -3.5003 2.946 -3.5003
-v -3.53713 2.59294 -3.53713
-v -3.55 2.25 -3.55
-v -2.33819 5.60644 -3.18603
-v -2.44695 5.214 -3.33423
-v -2.55126 4.82381 -3.47636
-v -2.64888 4.437 -3.60939
-v -2.7376 4.05469 -3.73028
-v -2.81518 3.678 -3.83599
-v -2.8794 3.30806 -3.92349
-v -2.92803 2.946 -3.98975
-v -2.95884 2.59294 -4.03173
-v -2.9696 2.25 -4.0464
-v -1.82262 5.60644 -3.50319
-v -1.9074 5.214 -3.66614
-v -1.9887 4.82381 -3.82242
-v -2.0648 4.437 -3.96869
-v -2.13396 4.05469 -4.10161
-v -2.19443 3.678 -4.21784
-v -2.24449 3.30806 -4.31406
-v -2.28239 2.946 -4.38691
-v -2.30641 2.59294 -4.43307
-v -2.3148 2.25 -4.4492
-v -1.25602 5.60644 -3.73909
-v -1.31444 5.214 -3.91301
-v -1.37048 4.82381 -4.07981
-v -1.42292 4.437 -4.23593
-v -1.47057 4.05469 -4.3778
-v -1.51225 3.678 -4.50186
-v -1.54675 3.30806 -4.60456
-v -1.57287 2.946 -4.68232
-v -1.58942 2.59294 -4.73159
-v -1.5952 2.25 -4.7488
-v -0.645962 5.60644 -3.88617
-v -0.676009 5.214 -4.06693
-v -0.704826 4.82381 -4.2403
-v -0.731796 4.437 -4.40256
-v -0.756306 4.05469 -4.55001
-v -0.777738 3.678 -4.67895
-v -0.79548 3.30806 -4.78568
-v -0.808914 2.946 -4.8665
-v -0.817425 2.59294 -4.91771
-v -0.820399 2.25 -4.9356
-v 7.88481e-07 5.60644 -3.93688
-v 8.25152e-07 5.214 -4.12
-v 8.60309e-07 4.82381 -4.29563
-v 8.93255e-07 4.437 -4.46
-v 9.23174e-07 4.05469 -4.60938
-v 9.49251e-07 3.678 -4.74
-v 9.70904e-07 3.30806 -4.84812
-v 9.87318e-07 2.946 -4.93
-v 9.97679e-07 2.59294 -4.98187
-v 1.0014e-06 2.25 -5
-v 0.645962 5.60644 -3.88617
-v 0.67601 5.214 -4.06693
-v 0.704826 4.82381 -4.2403
-v 0.731797 4.437 -4.40256
-v 0.756306 4.05469 -4.55001
-v 0.777739 3.678 -4.67895
-v 0.79548 3.30806 -4.78568
-v 0.808914 2.946 -4.8665
-v 0.817426 2.59294 -4.91771
-v 0.8204 2.25 -4.9356
-v 1.25602 5.60644 -3.73909
-v 1.31444 5.214 -3.91301
-v 1.37048 4.82381 -4.07981
-v 1.42292 4.437 -4.23593
-v 1.47058 4.05469 -4.3778
-v 1.51225 3.678 -4.50186
-v 1.54675 3.30806 -4.60456
-v 1.57287 2.946 -4.68232
-v 1.58942 2.59294 -4.73159
-v 1.5952 2.25 -4.7488
-v 1.82262 5.60644 -3.50319
-v 1.9074 5.214 -3.66614
-v 1.9887 4.82381 -3.82242
-v 2.0648 4.437 -3.96869
-v 2.13396 4.05469 -4.10161
-v 2.19443 3.678 -4.21784
-v 2.24449 3.30806 -4.31406
-v 2.28239 2.946 -4.38691
-v 2.30641 2.59294 -4.43307
-v 2.3148 2.25 -4.4492
-v 2.33819 5.60644 -3.18603
-v 2.44695 5.214 -3.33423
-v 2.55126 4.82381 -3.47636
-v 2.64888 4.437 -3.60939
-v 2.7376 4.05469 -3.73028
-v 2.81518 3.678 -3.83599
-v 2.8794 3.30806 -3.92349
-v 2.92803 2.946 -3.98975
-v 2.95884 2.59294 -4.03173
-v 2.9696 2.25 -4.0464
-v 2.79518 5.60644 -2.79518
-v 2.9252 5.214 -2.9252
-v 3.04989 4.82381 -3.04989
-v 3.1666 4.437 -3.1666
-v 3.27266 4.05469 -3.27266
-v 3.3654 3.678 -3.3654
-v 3.44217 3.30806 -3.44217
-v 3.5003 2.946 -3.5003
-v 3.53713 2.59294 -3.53713
-v 3.55 2.25 -3.55
-v 3.18603 5.60644 -2.33819
-v 3.33423 5.214 -2.44695
-v 3.47636 4.82381 -2.55126
-v 3.60939 4.437 -2.64888
-v 3.73028 4.05469 -2.7376
-v 3.83599 3.678 -2.81518
-v 3.92349 3.30806 -2.8794
-v 3.98975 2.946 -2.92803
-v 4.03173 2.59294 -2.95884
-v 4.0464 2.25 -2.9696
-v 3.50319 5.60644 -1.82262
-v 3.66614 5.214 -1.9074
-v 3.82242 4.82381 -1.9887
-v 3.96869 4.437 -2.0648
-v 4.10161 4.05469 -2.13396
-v 4.21784 3.678 -2.19443
-v 4.31406 3.30806 -2.24449
-v 4.38691 2.946 -2.28239
-v 4.43307 2.59294 -2.30641
-v 4.4492 2.25 -2.3148
-v 3.73909 5.60644 -1.25602
-v 3.91301 5.214 -1.31444
-v 4.07981 4.82381 -1.37048
-v 4.23593 4.437 -1.42292
-v 4.3778 4.05469 -1.47057
-v 4.50186 3.678 -1.51225
-v 4.60456 3.30806 -1.54675
-v 4.68232 2.946 -1.57287
-v 4.73159 2.59294 -1.58942
-v 4.7488 2.25 -1.5952
-v 3.88617 5.60644 -0.645962
-v 4.06693 5.214 -0.676009
-v 4.2403 4.82381 -0.704826
-v 4.40256 4.437 -0.731796
-v 4.55001 4.05469 -0.756306
-v 4.67895 3.678 -0.777739
-v 4.78568 3.30806 -0.79548
-v 4.8665 2.946 -0.808914
-v 4.91771 2.59294 -0.817425
-v 4.9356 2.25 -0.820399
-v 4.90105 1.92919 0.814657
-v 4.965 1.92919 -8.42977e-08
-v 4.80727 1.641 0.79907
-v 4.87 1.641 -7.17527e-08
-v 4.66908 1.38431 0.776098
-v 4.73 1.38431 -6.04832e-08
-v 4.50127 1.158 0.748205
-v 4.56 1.158 -5.06057e-08
-v 4.31865 0.960938 0.71785
-v 4.375 0.960937 -4.20039e-08
-v 4.13603 0.792 0.687495
-v 4.19 0.792 -3.45617e-08
-v 3.96822 0.650062 0.659602
-v 4.02 0.650062 -2.83956e-08
-v 3.83003 0.534 0.63663
-v 3.88 0.534 -2.33894e-08
-v 3.73625 0.442687 0.621043
-v 3.785 0.442687 -1.93104e-08
-v 3.7017 0.375 0.6153
-v 3.75 0.375 -1.63918e-08
-v 4.71556 1.92919 1.58403
-v 4.62533 1.641 1.55372
-v 4.49236 1.38431 1.50906
-v 4.33091 1.158 1.45482
-v 4.1552 0.960938 1.3958
-v 3.97949 0.792 1.33678
-v 3.81804 0.650063 1.28254
-v 3.68507 0.534 1.23788
-v 3.59484 0.442687 1.20757
-v 3.5616 0.375 1.1964
-v 4.41806 1.92919 2.2986
-v 4.33352 1.641 2.25462
-v 4.20894 1.38431 2.1898
-v 4.05767 1.158 2.1111
-v 3.89305 0.960938 2.02545
-v 3.72843 0.792 1.9398
-v 3.57716 0.650063 1.8611
-v 3.45258 0.534 1.79628
-v 3.36804 0.442688 1.7523
-v 3.3369 0.375 1.7361
-v 4.01807 1.92919 2.94881
-v 3.94119 1.641 2.89239
-v 3.82789 1.38431 2.80924
-v 3.69032 1.158 2.70828
-v 3.5406 0.960938 2.5984
-v 3.39088 0.792 2.48852
-v 3.25331 0.650063 2.38756
-v 3.14001 0.534 2.30441
-v 3.06312 0.442688 2.24799
-v 3.0348 0.375 2.2272
-v 3.52515 1.92919 3.52515
-v 3.4577 1.641 3.4577
-v 3.3583 1.38431 3.3583
-v 3.2376 1.158 3.2376
-v 3.10625 0.960938 3.10625
-v 2.9749 0.792 2.9749
-v 2.8542 0.650063 2.8542
-v 2.7548 0.534 2.7548
-v 2.68735 0.442688 2.68735
-v 2.6625 0.375 2.6625
-v 2.94881 1.92919 4.01808
-v 2.89239 1.641 3.94119
-v 2.80924 1.38431 3.82789
-v 2.70828 1.158 3.69032
-v 2.5984 0.960938 3.5406
-v 2.48852 0.792 3.39088
-v 2.38756 0.650063 3.25331
-v 2.30441 0.534 3.14001
-v 2.24799 0.442688 3.06312
-v 2.2272 0.375 3.0348
-v 2.2986 1.92919 4.41806
-v 2.25462 1.641 4.33352
-v 2.1898 1.38431 4.20894
-v 2.1111 1.158 4.05767
-v 2.02545 0.960938 3.89305
-v 1.9398 0.792 3.72843
-v 1.8611 0.650063 3.57716
-v 1.79628 0.534 3.45258
-v 1.7523 0.442688 3.36804
-v 1.7361 0.375 3.3369
-v 1.58403 1.92919 4.71556
-v 1.55372 1.641 4.62533
-v 1.50906 1.38431 4.49236
-v 1.45482 1.158 4.33091
-v 1.3958 0.960938 4.1552
-v 1.33678 0.792 3.97949
-v 1.28254 0.650063 3.81804
-v 1.23787 0.534 3.68507
-v 1.20757 0.442688 3.59484
-v 1.1964 0.375 3.5616
-v 0.814656 1.92919 4.90105
-v 0.799069 1.641 4.80727
-v 0.776098 1.38431 4.66908
-v 0.748204 1.158 4.50127
-v 0.717849 0.960938 4.31865
-v 0.687495 0.792 4.13603
-v 0.659601 0.650063 3.96822
-v 0.63663 0.534 3.83003
-v 0.621042 0.442688 3.73625
-v 0.615299 0.375 3.7017
-v -9.94303e-07 1.92919 4.965
-v -9.75328e-07 1.641 4.87
-v -9.47271e-07 1.38431 4.73
-v -9.13278e-07 1.158 4.56
-v -8.76142e-07 0.960938 4.375
-v -8.39122e-07 0.792 4.19
-v -8.05128e-07 0.650063 4.02
-v -7.77072e-07 0.534 3.88
-v -7.5798e-07 0.442688 3.785
-v -7.50995e-07 0.375 3.75
-v -0.814657 1.92919 4.90105
-v -0.79907 1.641 4.80727
-v -0.776098 1.38431 4.66908
-v -0.748205 1.158 4.50127
-v -0.71785 0.960938 4.31865
-v -0.687495 0.792 4.13603
-v -0.659602 0.650063 3.96822
-v -0.63663 0.534 3.83003
-v -0.621043 0.442688 3.73625
-v -0.6153 0.375 3.7017
-v -1.58403 1.92919 4.71556
-v -1.55372 1.641 4.62533
-v -1.50906 1.38431 4.49236
-v -1.45482 1.158 4.33091
-v -1.3958 0.960938 4.1552
-v -1.33678 0.792 3.97949
-v -1.28254 0.650063 3.81804
-v -1.23788 0.534 3.68507
-v -1.20757 0.442688 3.59484
-v -1.1964 0.375 3.5616
-v -2.2986 1.92919 4.41806
-v -2.25462 1.641 4.33352
-v -2.1898 1.38431 4.20894
-v -2.1111 1.158 4.05767
-v -2.02545 0.960938 3.89305
-v -1.9398 0.792 3.72843
-v -1.8611 0.650063 3.57716
-v -1.79628 0.534 3.45258
-v -1.7523 0.442688 3.36804
-v -1.7361 0.375 3.3369
-v -2.94881 1.92919 4.01807
-v -2.89239 1.641 3.94119
-v -2.80924 1.38431 3.82789
-v -2.70828 1.158 3.69032
-v -2.5984 0.960938 3.5406
-v -2.48852 0.792 3.39088
-v -2.38756 0.650063 3.25331
-v -2.30441 0.534 3.14001
-v -2.24799 0.442688 3.06312
-v -2.2272 0.375 3.0348
-v -3.52515 1.92919 3.52515
-v -3.4577 1.641 3.4577
-v -3.3583 1.38431 3.3583
-v -3.2376 1.158 3.2376
-v -3.10625 0.960938 3.10625
-v -2.9749 0.792 2.9749
-v -2.8542 0.650063 2.8542
-v -2.7548 0.534 2.7548
-v -2.68735 0.442688 2.68735
-v -2.6625 0.375 2.6625
-v -4.01808 1.92919 2.94881
-v -3.94119 1.641 2.89239
-v -3.82789 1.38431 2.80924
-v -3.69032 1.158 2.70828
-v -3.5406 0.960938 2.5984
-v -3.39088 0.792 2.48852
-v -3.25331 0.650063 2.38756
-v -3.14001 0.534 2.30441
-v -3.06312 0.442688 2.24799
-v -3.0348 0.375 2.2272
-v -4.41806 1.92919 2.2986
-v -4.33352 1.641 2.25462
-v -4.20894 1.38431 2.1898
-v -4.05767 1.158 2.1111
-v -3.89305 0.960938 2.02545
-v -3.72843 0.792 1.9398
-v -3.57716 0.650063 1.8611
-v -3.45258 0.534 1.79628
-v -3.36804 0.442688 1.7523
-v -3.3369 0.375 1.7361
-v -4.71556 1.92919 1.58403
-v -4.62533 1.641 1.55372
-v -4.49236 1.38431 1.50906
-v -4.33091 1.158 1.45482
-v -4.1552 0.960938 1.3958
-v -3.97949 0.792 1.33678
-v -3.81804 0.650063 1.28254
-v -3.68507 0.534 1.23787
-v -3.59484 0.442687 1.20757
-v -3.5616 0.375 1.1964
-v -4.90105 1.92919 0.814656
-v -4.80727 1.641 0.799069
-v -4.66908 1.38431 0.776098
-v -4.50127 1.158 0.748204
-v -4.31865 0.960938 0.717849
-v -4.13603 0.792 0.687495
-v -3.96822 0.650062 0.659601
-v -3.83003 0.534 0.63663
-v -3.73625 0.442687 0.621042
-v -3.7017 0.375 0.615299
-v -4.965 1.92919 -1.07872e-06
-v -4.87 1.641 -1.04708e-06
-v -4.73 1.38431 -1.00775e-06
-v -4.56 1.158 -9.63884e-07
-v -4.375 0.960937 -9.18146e-07
-v -4.19 0.792 -8.738e-07
-v -4.02 0.650062 -8.33524e-07
-v -3.88 0.534 -8.00345e-07
-v -3.785 0.442687 -7.77407e-07
-v -3.75 0.375 -7.67387e-07
-v -4.90105 1.92919 -0.814657
-v -4.80727 1.641 -0.79907
-v -4.66908 1.38431 -0.776098
-v -4.50127 1.158 -0.748205
-v -4.31865 0.960937 -0.71785
-v -4.13603 0.792 -0.687495
-v -3.96822 0.650062 -0.659602
-v -3.83003 0.534 -0.63663
-v -3.73625 0.442687 -0.621043
-v -3.7017 0.375 -0.6153
-v -4.71556 1.92919 -1.58403
-v -4.62533 1.641 -1.55373
-v -4.49236 1.38431 -1.50906
-v -4.33091 1.158 -1.45482
-v -4.1552 0.960937 -1.3958
-v -3.97949 0.792 -1.33678
-v -3.81804 0.650062 -1.28254
-v -3.68507 0.534 -1.23788
-v -3.59484 0.442687 -1.20757
-v -3.5616 0.375 -1.1964
-v -4.41806 1.92919 -2.2986
-v -4.33352 1.641 -2.25462
-v -4.20894 1.38431 -2.1898
-v -4.05767 1.158 -2.1111
-v -3.89305 0.960937 -2.02545
-v -3.72843 0.792 -1.9398
-v -3.57716 0.650062 -1.8611
-v -3.45258 0.534 -1.79628
-v -3.36804 0.442687 -1.7523
-v -3.3369 0.375 -1.7361
-v -4.01807 1.92919 -2.94881
-v -3.94119 1.641 -2.89239
-v -3.82789 1.38431 -2.80924
-v -3.69032 1.158 -2.70828
-v -3.5406 0.960937 -2.5984
-v -3.39088 0.792 -2.48852
-v -3.25331 0.650062 -2.38756
-v -3.14001 0.534 -2.30441
-v -3.06312 0.442687 -2.24799
-v -3.0348 0.375 -2.2272
-v -3.52515 1.92919 -3.52515
-v -3.4577 1.641 -3.4577
-v -3.3583 1.38431 -3.3583
-v -3.2376 1.158 -3.2376
-v -3.10625 0.960937 -3.10625
-v -2.9749 0.792 -2.9749
-v -2.8542 0.650062 -2.8542
-v -2.7548 0.534 -2.7548
-v -2.68735 0.442687 -2.68735
-v -2.6625 0.375 -2.6625
-v -2.94881 1.92919 -4.01808
-v -2.89239 1.641 -3.94119
-v -2.80924 1.38431 -3.82789
-v -2.70828 1.158 -3.69032
-v -2.5984 0.960937 -3.5406
-v -2.48852 0.792 -3.39088
-v -2.38756 0.650062 -3.25331
-v -2.30441 0.534 -3.14001
-v -2.24799 0.442687 -3.06312
-v -2.2272 0.375 -3.0348
-v -2.2986 1.92919 -4.41806
-v -2.25462 1.641 -4.33352
-v -2.1898 1.38431 -4.20894
-v -2.1111 1.158 -4.05767
-v -2.02545 0.960937 -3.89305
-v -1.9398 0.792 -3.72843
-v -1.8611 0.650062 -3.57716
-v -1.79628 0.534 -3.45258
-v -1.7523 0.442687 -3.36804
-v -1.7361 0.375 -3.3369
-v -1.58403 1.92919 -4.71556
-v -1.55372 1.641 -4.62533
-v -1.50906 1.38431 -4.49236
-v -1.45482 1.158 -4.33091
-v -1.3958 0.960937 -4.1552
-v -1.33678 0.792 -3.97949
-v -1.28254 0.650062 -3.81804
-v -1.23787 0.534 -3.68507
-v -1.20757 0.442687 -3.59484
-v -1.1964 0.375 -3.5616
-v -0.814656 1.92919 -4.90105
-v -0.799069 1.641 -4.80727
-v -0.776098 1.38431 -4.66908
-v -0.748204 1.158 -4.50127
-v -0.717849 0.960937 -4.31865
-v -0.687495 0.792 -4.13603
-v -0.659601 0.650062 -3.96822
-v -0.63663 0.534 -3.83003
-v -0.621042 0.442687 -3.73625
-v -0.615299 0.375 -3.7017
-v 9.94303e-07 1.92919 -4.965
-v 9.75328e-07 1.641 -4.87
-v 9.47271e-07 1.38431 -4.73
-v 9.13278e-07 1.158 -4.56
-v 8.76142e-07 0.960937 -4.375
-v 8.39122e-07 0.792 -4.19
-v 8.05128e-07 0.650062 -4.02
-v 7.77072e-07 0.534 -3.88
-v 7.5798e-07 0.442687 -3.785
-v 7.50995e-07 0.375 -3.75
-v 0.814657 1.92919 -4.90105
-v 0.79907 1.641 -4.80727
-v 0.776098 1.38431 -4.66908
-v 0.748205 1.158 -4.50127
-v 0.71785 0.960937 -4.31865
-v 0.687495 0.792 -4.13603
-v 0.659602 0.650062 -3.96822
-v 0.63663 0.534 -3.83003
-v 0.621043 0.442687 -3.73625
-v 0.6153 0.375 -3.7017
-v 1.58403 1.92919 -4.71556
-v 1.55372 1.641 -4.62533
-v 1.50906 1.38431 -4.49236
-v 1.45482 1.158 -4.33091
-v 1.3958 0.960937 -4.1552
-v 1.33678 0.792 -3.97949
-v 1.28254 0.650062 -3.81804
-v 1.23788 0.534 -3.68507
-v 1.20757 0.442687 -3.59484
-v 1.1964 0.375 -3.5616
-v 2.2986 1.92919 -4.41806
-v 2.25462 1.641 -4.33352
-v 2.1898 1.38431 -4.20894
-v 2.1111 1.158 -4.05767
-v 2.02545 0.960937 -3.89305
-v 1.9398 0.792 -3.72843
-v 1.8611 0.650062 -3.57716
-v 1.79628 0.534 -3.45258
-v 1.7523 0.442687 -3.36804
-v 1.7361 0.375 -3.3369
-v 2.94881 1.92919 -4.01807
-v 2.89239 1.641 -3.94119
-v 2.80924 1.38431 -3.82789
-v 2.70828 1.158 -3.69032
-v 2.5984 0.960937 -3.5406
-v 2.48852 0.792 -3.39088
-v 2.38756 0.650062 -3.25331
-v 2.30441 0.534 -3.14001
-v 2.24799 0.442687 -3.06312
-v 2.2272 0.375 -3.0348
-v 3.52515 1.92919 -3.52515
-v 3.4577 1.641 -3.4577
-v 3.3583 1.38431 -3.3583
-v 3.2376 1.158 -3.2376
-v 3.10625 0.960937 -3.10625
-v 2.9749 0.792 -2.9749
-v 2.8542 0.650062 -2.8542
-v 2.7548 0.534 -2.7548
-v 2.68735 0.442687 -2.68735
-v 2.6625 0.375 -2.6625
-v 4.01808 1.92919 -2.94881
-v 3.94119 1.641 -2.89239
-v 3.82789 1.38431 -2.80924
-v 3.69032 1.158 -2.70828
-v 3.5406 0.960937 -2.5984
-v 3.39088 0.792 -2.48852
-v 3.25331 0.650062 -2.38756
-v 3.14001 0.534 -2.30441
-v 3.06312 0.442687 -2.24799
-v 3.0348 0.375 -2.2272
-v 4.41806 1.92919 -2.2986
-v 4.33352 1.641 -2.25462
-v 4.20894 1.38431 -2.1898
-v 4.05767 1.158 -2.1111
-v 3.89305 0.960937 -2.02545
-v 3.72843 0.792 -1.9398
-v 3.57716 0.650062 -1.8611
-v 3.45258 0.534 -1.79628
-v 3.36804 0.442687 -1.7523
-v 3.3369 0.375 -1.7361
-v 4.71556 1.92919 -1.58403
-v 4.62533 1.641 -1.55372
-v 4.49236 1.38431 -1.50906
-v 4.33091 1.158 -1.45482
-v 4.1552 0.960937 -1.3958
-v 3.97949 0.792 -1.33678
-v 3.81804 0.650062 -1.28254
-v 3.68507 0.534 -1.23787
-v 3.59484 0.442687 -1.20757
-v 3.5616 0.375 -1.1964
-v 4.90105 1.92919 -0.814657
-v 4.80727 1.641 -0.799069
-v 4.66908 1.38431 -0.776098
-v 4.50127 1.158 -0.748204
-v 4.31865 0.960937 -0.717849
-v 4.13603 0.792 -0.687495
-v 3.96822 0.650062 -0.659601
-v 3.83003 0.534 -0.63663
-v 3.73625 0.442687 -0.621042
-v 3.7017 0.375 -0.615299
-v 3.693 0.318938 0.613854
-v 3.74119 0.318938 -1.39757e-08
-v 3.65432 0.264 0.607424
-v 3.702 0.264 -1.15594e-08
-v 3.56677 0.211313 0.592872
-v 3.61331 0.211313 -9.25912e-09
-v 3.41149 0.162 0.56706
-v 3.456 0.162 -7.07473e-09
-v 3.16958 0.117188 0.526851
-v 3.21094 0.117188 -5.12243e-09
-v 2.82218 0.078 0.469105
-v 2.859 0.078 -3.40204e-09
-v 2.35039 0.0455625 0.390685
-v 2.38106 0.0455625 -2.02978e-09
-v 1.73536 0.021 0.288453
-v 1.758 0.021 -8.89067e-10
-v 0.958184 0.0054375 0.15927
-v 0.970687 0.00543749 -2.12534e-10
-v 2.69049e-43 -5.02926e-20 5.02926e-20
-v 3.55323 0.318938 1.19359
-v 3.51601 0.264 1.18109
-v 3.43178 0.211313 1.15279
-v 3.28237 0.162 1.1026
-v 3.04962 0.117188 1.02442
-v 2.71536 0.078 0.912135
-v 2.26144 0.0455625 0.759654
-v 1.66968 0.021 0.560872
-v 0.921919 0.0054375 0.309688
-v 3.32906 0.318938 1.73202
-v 3.29419 0.264 1.71388
-v 3.21527 0.211313 1.67282
-v 3.07529 0.162 1.59999
-v 2.85722 0.117188 1.48654
-v 2.54405 0.0780001 1.3236
-v 2.11876 0.0455625 1.10234
-v 1.56434 0.021 0.813883
-v 0.863756 0.00543751 0.449389
-v 3.02767 0.318938 2.22197
-v 2.99595 0.264 2.19869
-v 2.92418 0.211313 2.14602
-v 2.79687 0.162 2.05259
-v 2.59855 0.117188 1.90704
-v 2.31373 0.0780001 1.69802
-v 1.92695 0.0455626 1.41416
-v 1.42271 0.021 1.04411
-v 0.785557 0.00543752 0.57651
-v 2.65624 0.318938 2.65624
-v 2.62842 0.264 2.62842
-v 2.56545 0.211313 2.56545
-v 2.45376 0.162 2.45376
-v 2.27977 0.117188 2.27977
-v 2.02989 0.0780001 2.02989
-v 1.69055 0.0455626 1.69055
-v 1.24818 0.021 1.24818
-v 0.689188 0.00543752 0.689188
-v 2.22197 0.318938 3.02767
-v 2.19869 0.264 2.99595
-v 2.14602 0.211313 2.92418
-v 2.05259 0.162 2.79687
-v 1.90704 0.117188 2.59855
-v 1.69802 0.0780001 2.31373
-v 1.41416 0.0455626 1.92695
-v 1.04411 0.021 1.42271
-v 0.57651 0.00543752 0.785557
-v 1.73202 0.318938 3.32906
-v 1.71388 0.264 3.29419
-v 1.67282 0.211313 3.21527
-v 1.59999 0.162 3.07529
-v 1.48654 0.117188 2.85722
-v 1.3236 0.0780001 2.54405
-v 1.10234 0.0455626 2.11876
-v 0.813883 0.0210001 1.56434
-v 0.449389 0.00543753 0.863756
-v 1.19359 0.318938 3.55323
-v 1.18109 0.264 3.51601
-v 1.15279 0.211313 3.43178
-v 1.1026 0.162 3.28237
-v 1.02442 0.117188 3.04962
-v 0.912135 0.0780001 2.71536
-v 0.759654 0.0455626 2.26144
-v 0.560872 0.0210001 1.66968
-v 0.309688 0.00543753 0.921919
-v 0.613853 0.318938 3.693
-v 0.607424 0.264 3.65432
-v 0.592872 0.211313 3.56677
-v 0.56706 0.162 3.41149
-v 0.52685 0.117188 3.16958
-v 0.469104 0.0780001 2.82218
-v 0.390684 0.0455626 2.35039
-v 0.288452 0.0210001 1.73536
-v 0.15927 0.00543753 0.958184
-v -7.49249e-07 0.318938 3.74119
-v -7.41449e-07 0.264 3.702
-v -7.23638e-07 0.211313 3.61331
-v -6.92089e-07 0.162 3.456
-v -6.43078e-07 0.117188 3.21094
-v -5.72531e-07 0.0780001 2.859
-v -4.76837e-07 0.0455626 2.38106
-v -3.5204e-07 0.0210001 1.758
-v -1.94414e-07 0.00543753 0.970687
-v -0.613854 0.318938 3.693
-v -0.607424 0.264 3.65432
-v -0.592872 0.211313 3.56677
-v -0.56706 0.162 3.41149
-v -0.526851 0.117188 3.16958
-v -0.469105 0.0780001 2.82218
-v -0.390685 0.0455626 2.35039
-v -0.288453 0.0210001 1.73536
-v -0.15927 0.00543753 0.958184
-v -1.19359 0.318938 3.55323
-v -1.18109 0.264 3.51601
-v -1.15279 0.211313 3.43178
-v -1.1026 0.162 3.28237
-v -1.02442 0.117188 3.04962
-v -0.912135 0.0780001 2.71536
-v -0.759654 0.0455626 2.26144
-v -0.560872 0.0210001 1.66968
-v -0.309688 0.00543753 0.921919
-v -1.73202 0.318938 3.32906
-v -1.71388 0.264 3.29419
-v -1.67282 0.211313 3.21527
-v -1.59999 0.162 3.07529
-v -1.48654 0.117188 2.85722
-v -1.3236 0.0780001 2.54405
-v -1.10234 0.0455626 2.11876
-v -0.813883 0.0210001 1.56434
-v -0.449389 0.00543753 0.863756
-v -2.22197 0.318938 3.02767
-v -2.19869 0.264 2.99595
-v -2.14602 0.211313 2.92418
-v -2.05259 0.162 2.79687
-v -1.90704 0.117188 2.59855
-v -1.69802 0.0780001 2.31373
-v -1.41416 0.0455626 1.92695
-v -1.04411 0.021 1.42271
-v -0.57651 0.00543752 0.785557
-v -2.65624 0.318938 2.65624
-v -2.62842 0.264 2.62842
-v -2.56545 0.211313 2.56545
-v -2.45376 0.162 2.45376
-v -2.27977 0.117188 2.27977
-v -2.02989 0.0780001 2.02989
-v -1.69055 0.0455626 1.69055
-v -1.24818 0.021 1.24818
-v -0.689188 0.00543752 0.689188
-v -3.02767 0.318938 2.22197
-v -2.99595 0.264 2.19869
-v -2.92418 0.211313 2.14602
-v -2.79687 0.162 2.05259
-v -2.59855 0.117188 1.90704
-v -2.31373 0.0780001 1.69802
-v -1.92695 0.0455626 1.41416
-v -1.42271 0.021 1.04411
-v -0.785557 0.00543752 0.57651
-v -3.32906 0.318938 1.73202
-v -3.29419 0.264 1.71388
-v -3.21527 0.211313 1.67282
-v -3.07529 0.162 1.59999
-v -2.85722 0.117188 1.48654
-v -2.54405 0.0780001 1.3236
-v -2.11876 0.0455625 1.10234
-v -1.56434 0.021 0.813883
-v -0.863756 0.00543751 0.449389
-v -3.55323 0.318938 1.19359
-v -3.51601 0.264 1.18109
-v -3.43178 0.211313 1.15279
-v -3.28237 0.162 1.1026
-v -3.04962 0.117188 1.02442
-v -2.71536 0.078 0.912135
-v -2.26144 0.0455625 0.759654
-v -1.66968 0.021 0.560872
-v -0.921919 0.0054375 0.309688
-v -3.693 0.318938 0.613853
-v -3.65432 0.264 0.607424
-v -3.56677 0.211313 0.592872
-v -3.41149 0.162 0.56706
-v -3.16958 0.117188 0.52685
-v -2.82218 0.078 0.469104
-v -2.35039 0.0455625 0.390684
-v -1.73536 0.021 0.288452
-v -0.958184 0.0054375 0.15927
-v -3.74119 0.318938 -7.63225e-07
-v -3.702 0.264 -7.52892e-07
-v -3.61331 0.211313 -7.32897e-07
-v -3.456 0.162 -6.99164e-07
-v -3.21094 0.117188 -6.48201e-07
-v -2.859 0.078 -5.75933e-07
-v -2.38106 0.0455625 -4.78867e-07
-v -1.758 0.021 -3.53045e-07
-v -0.970687 0.00543749 -1.94626e-07
-v -3.693 0.318938 -0.613854
-v -3.65432 0.264 -0.607424
-v -3.56677 0.211312 -0.592872
-v -3.41149 0.162 -0.56706
-v -3.16958 0.117187 -0.526851
-v -2.82218 0.078 -0.469105
-v -2.35039 0.0455625 -0.390685
-v -1.73536 0.021 -0.288453
-v -0.958184 0.00543748 -0.15927
-v -3.55323 0.318937 -1.19359
-v -3.51601 0.264 -1.18109
-v -3.43178 0.211312 -1.15279
-v -3.28237 0.162 -1.1026
-v -3.04962 0.117187 -1.02442
-v -2.71536 0.078 -0.912135
-v -2.26144 0.0455625 -0.759654
-v -1.66968 0.021 -0.560872
-v -0.921919 0.00543748 -0.309688
-v -3.32906 0.318937 -1.73202
-v -3.29419 0.264 -1.71388
-v -3.21527 0.211312 -1.67282
-v -3.07529 0.162 -1.59999
-v -2.85722 0.117187 -1.48654
-v -2.54405 0.0779999 -1.3236
-v -2.11876 0.0455624 -1.10234
-v -1.56434 0.0209999 -0.813883
-v -0.863756 0.00543747 -0.449389
-v -3.02767 0.318937 -2.22197
-v -2.99595 0.264 -2.19869
-v -2.92418 0.211312 -2.14602
-v -2.79687 0.162 -2.05259
-v -2.59855 0.117187 -1.90704
-v -2.31373 0.0779999 -1.69802
-v -1.92695 0.0455624 -1.41416
-v -1.42271 0.0209999 -1.04411
-v -0.785557 0.00543746 -0.57651
-v -2.65624 0.318937 -2.65624
-v -2.62842 0.264 -2.62842
-v -2.56545 0.211312 -2.56545
-v -2.45376 0.162 -2.45376
-v -2.27977 0.117187 -2.27977
-v -2.02989 0.0779999 -2.02989
-v -1.69055 0.0455624 -1.69055
-v -1.24818 0.0209999 -1.24818
-v -0.689188 0.00543746 -0.689188
-v -2.22197 0.318937 -3.02767
-v -2.19869 0.264 -2.99595
-v -2.14602 0.211312 -2.92418
-v -2.05259 0.162 -2.79687
-v -1.90704 0.117187 -2.59855
-v -1.69802 0.0779999 -2.31373
-v -1.41416 0.0455624 -1.92695
-v -1.04411 0.0209999 -1.42271
-v -0.57651 0.00543746 -0.785557
-v -1.73202 0.318937 -3.32906
-v -1.71388 0.264 -3.29419
-v -1.67282 0.211312 -3.21527
-v -1.59999 0.162 -3.07529
-v -1.48654 0.117187 -2.85722
-v -1.3236 0.0779999 -2.54405
-v -1.10234 0.0455624 -2.11876
-v -0.813883 0.0209999 -1.56434
-v -0.449389 0.00543745 -0.863756
-v -1.19359 0.318937 -3.55323
-v -1.18109 0.264 -3.51601
-v -1.15279 0.211312 -3.43178
-v -1.1026 0.162 -3.28237
-v -1.02442 0.117187 -3.04962
-v -0.912135 0.0779999 -2.71536
-v -0.759654 0.0455624 -2.26144
-v -0.560872 0.0209999 -1.66968
-v -0.309688 0.00543745 -0.921919
-v -0.613853 0.318937 -3.693
-v -0.607424 0.264 -3.65432
-v -0.592872 0.211312 -3.56677
-v -0.56706 0.162 -3.41149
-v -0.52685 0.117187 -3.16958
-v -0.469104 0.0779999 -2.82218
-v -0.390684 0.0455624 -2.35039
-v -0.288452 0.0209999 -1.73536
-v -0.15927 0.00543745 -0.958184
-v 7.49249e-07 0.318937 -3.74119
-v 7.41449e-07 0.264 -3.702
-v 7.23638e-07 0.211312 -3.61331
-v 6.92089e-07 0.162 -3.456
-v 6.43078e-07 0.117187 -3.21094
-v 5.72531e-07 0.0779999 -2.859
-v 4.76837e-07 0.0455624 -2.38106
-v 3.5204e-07 0.0209999 -1.758
-v 1.94414e-07 0.00543745 -0.970687
-v 0.613854 0.318937 -3.693
-v 0.607424 0.264 -3.65432
-v 0.592872 0.211312 -3.56677
-v 0.56706 0.162 -3.41149
-v 0.526851 0.117187 -3.16958
-v 0.469105 0.0779999 -2.82218
-v 0.390685 0.0455624 -2.35039
-v 0.288453 0.0209999 -1.73536
-v 0.15927 0.00543745 -0.958184
-v 1.19359 0.318937 -3.55323
-v 1.18109 0.264 -3.51601
-v 1.15279 0.211312 -3.43178
-v 1.1026 0.162 -3.28237
-v 1.02442 0.117187 -3.04962
-v 0.912135 0.0779999 -2.71536
-v 0.759654 0.0455624 -2.26144
-v 0.560872 0.0209999 -1.66968
-v 0.309688 0.00543745 -0.921919
-v 1.73202 0.318937 -3.32906
-v 1.71388 0.264 -3.29419
-v 1.67282 0.211312 -3.21527
-v 1.59999 0.162 -3.07529
-v 1.48654 0.117187 -2.85722
-v 1.3236 0.0779999 -2.54405
-v 1.10234 0.0455624 -2.11876
-v 0.813883 0.0209999 -1.56434
-v 0.449389 0.00543745 -0.863756
-v 2.22197 0.318937 -3.02767
-v 2.19869 0.264 -2.99595
-v 2.14602 0.211312 -2.92418
-v 2.05259 0.162 -2.79687
-v 1.90704 0.117187 -2.59855
-v 1.69802 0.0779999 -2.31373
-v 1.41416 0.0455624 -1.92695
-v 1.04411 0.0209999 -1.42271
-v 0.57651 0.00543746 -0.785557
-v 2.65624 0.318937 -2.65624
-v 2.62842 0.264 -2.62842
-v 2.56545 0.211312 -2.56545
-v 2.45376 0.162 -2.45376
-v 2.27977 0.117187 -2.27977
-v 2.02989 0.0779999 -2.02989
-v 1.69055 0.0455624 -1.69055
-v 1.24818 0.0209999 -1.24818
-v 0.689188 0.00543746 -0.689188
-v 3.02767 0.318937 -2.22197
-v 2.99595 0.264 -2.19869
-v 2.92418 0.211312 -2.14602
-v 2.79687 0.162 -2.05259
-v 2.59855 0.117187 -1.90704
-v 2.31373 0.0779999 -1.69802
-v 1.92695 0.0455624 -1.41416
-v 1.42271 0.0209999 -1.04411
-v 0.785557 0.00543746 -0.57651
-v 3.32906 0.318937 -1.73202
-v 3.29419 0.264 -1.71388
-v 3.21527 0.211312 -1.67282
-v 3.07529 0.162 -1.59999
-v 2.85722 0.117187 -1.48654
-v 2.54405 0.0779999 -1.3236
-v 2.11876 0.0455624 -1.10234
-v 1.56434 0.0209999 -0.813883
-v 0.863756 0.00543747 -0.449389
-v 3.55323 0.318937 -1.19359
-v 3.51601 0.264 -1.18109
-v 3.43178 0.211312 -1.15279
-v 3.28237 0.162 -1.1026
-v 3.04962 0.117187 -1.02442
-v 2.71536 0.078 -0.912135
-v 2.26144 0.0455625 -0.759654
-v 1.66968 0.021 -0.560872
-v 0.921919 0.00543748 -0.309688
-v 3.693 0.318938 -0.613853
-v 3.65432 0.264 -0.607424
-v 3.56677 0.211312 -0.592872
-v 3.41149 0.162 -0.56706
-v 3.16958 0.117187 -0.52685
-v 2.82218 0.078 -0.469104
-v 2.35039 0.0455625 -0.390684
-v 1.73536 0.021 -0.288452
-v 0.958184 0.00543748 -0.15927
-v -4 5.0625 -2.21289e-07
-v -3.993 5.07825 0.2025
-v -4.50114 5.07767 0.2025
-v -4.50225 5.06194 -2.21231e-07
-v -4.96198 5.07362 0.2025
-v -4.958 5.058 -2.21057e-07
-v -5.37406 5.06264 0.2025
-v -5.36575 5.04731 -2.20651e-07
-v -5.73593 5.04124 0.2025
-v -5.724 5.0265 -2.19665e-07
-v -6.04612 5.00597 0.2025
-v -6.03125 4.99219 -2.18215e-07
-v -6.30319 4.95335 0.2025
-v -6.286 4.941 -2.15954e-07
-v -6.50567 4.87991 0.2025
-v -6.48675 4.86956 -2.12881e-07
-v -6.6521 4.78219 0.2025
-v -6.632 4.7745 -2.08647e-07
-v -6.74103 4.65671 0.2025
-v -6.72025 4.65244 -2.0337e-07
-v -6.771 4.5 0.2025
-v -6.75 4.5 -1.96701e-07
-v -3.974 5.121 0.36
-v -4.49812 5.12038 0.36
-v -4.97277 5.11603 0.36
-v -5.39661 5.10423 0.36
-v -5.7683 5.08126 0.36
-v -6.0865 5.04338 0.36
-v -6.34986 4.98686 0.36
-v -6.55703 4.908 0.36
-v -6.70667 4.80305 0.36
-v -6.79744 4.66829 0.36
-v -6.828 4.5 0.36
-v -3.946 5.184 0.4725
-v -4.49366 5.18332 0.4725
-v -4.98867 5.17853 0.4725
-v -5.42985 5.16553 0.4725
-v -5.81602 5.14022 0.4725
-v -6.146 5.0985 0.4725
-v -6.41862 5.03626 0.4725
-v -6.63271 4.94939 0.4725
-v -6.78709 4.83379 0.4725
-v -6.88058 4.68536 0.4725
-v -6.912 4.5 0.4725
-v -3.912 5.2605 0.54
-v -4.48826 5.25974 0.54
-v -5.00798 5.25442 0.54
-v -5.47021 5.23997 0.54
-v -5.87395 5.21183 0.54
-v -6.21825 5.16544 0.54
-v -6.50213 5.09623 0.54
-v -6.72461 4.99965 0.54
-v -6.88474 4.87112 0.54
-v -6.98152 4.7061 0.54
-v -7.014 4.5 0.54
-v -3.875 5.34375 0.5625
-v -4.48238 5.34291 0.5625
-v -5.029 5.337 0.5625
-v -5.51413 5.32097 0.5625
-v -5.937 5.28975 0.5625
-v -6.29688 5.23828 0.5625
-v -6.593 5.1615 0.5625
-v -6.82463 5.05434 0.5625
-v -6.991 4.91175 0.5625
-v -7.09137 4.72866 0.5625
-v -7.125 4.5 0.5625
-v -3.838 5.427 0.54
-v -4.47649 5.42607 0.54
-v -5.05002 5.41958 0.54
-v -5.55804 5.40197 0.54
-v -6.00005 5.36767 0.54
-v -6.3755 5.31112 0.54
-v -6.68387 5.22677 0.54
-v -6.92464 5.10904 0.54
-v -7.09726 4.95238 0.54
-v -7.20123 4.75122 0.54
-v -7.236 4.5 0.54
-v -3.804 5.5035 0.4725
-v -4.47109 5.5025 0.4725
-v -5.06933 5.49547 0.4725
-v -5.5984 5.47641 0.4725
-v -6.05798 5.43928 0.4725
-v -6.44775 5.37806 0.4725
-v -6.76738 5.28674 0.4725
-v -7.01654 5.1593 0.4725
-v -7.19491 4.98971 0.4725
-v -7.30217 4.77195 0.4725
-v -7.338 4.5 0.4725
-v -3.776 5.5665 0.36
-v -4.46663 5.56543 0.36
-v -5.08523 5.55797 0.36
-v -5.63164 5.5377 0.36
-v -6.1057 5.49824 0.36
-v -6.50725 5.43319 0.36
-v -6.83614 5.33614 0.36
-v -7.09222 5.20069 0.36
-v -7.27533 5.02045 0.36
-v -7.38531 4.78902 0.36
-v -7.422 4.5 0.36
-v -3.757 5.60925 0.2025
-v -4.46361 5.60814 0.2025
-v -5.09602 5.60038 0.2025
-v -5.65419 5.5793 0.2025
-v -6.13807 5.53826 0.2025
-v -6.54763 5.47059 0.2025
-v -6.88281 5.36965 0.2025
-v -7.14358 5.22878 0.2025
-v -7.3299 5.04131 0.2025
-v -7.44172 4.80061 0.2025
-v -7.479 4.5 0.2025
-v -3.75 5.625 -5.14097e-07
-v -4.4625 5.62388 -5.14098e-07
-v -5.1 5.616 -5.1375e-07
-v -5.6625 5.59462 -5.12822e-07
-v -6.15 5.553 -5.10966e-07
-v -6.5625 5.48438 -5.07951e-07
-v -6.9 5.382 -5.03427e-07
-v -7.1625 5.23912 -4.97281e-07
-v -7.35 5.049 -4.8893e-07
-v -7.4625 4.80487 -4.7826e-07
-v -7.5 4.5 -4.64922e-07
-v -3.757 5.60925 -0.2025
-v -4.46361 5.60814 -0.2025
-v -5.09602 5.60038 -0.2025
-v -5.65419 5.5793 -0.2025
-v -6.13807 5.53826 -0.2025
-v -6.54763 5.47059 -0.2025
-v -6.88281 5.36965 -0.2025
-v -7.14358 5.22878 -0.2025
-v -7.3299 5.04131 -0.2025
-v -7.44172 4.80061 -0.2025
-v -7.479 4.5 -0.2025
-v -3.776 5.5665 -0.36
-v -4.46663 5.56543 -0.36
-v -5.08523 5.55797 -0.36
-v -5.63164 5.5377 -0.36
-v -6.1057 5.49824 -0.36
-v -6.50725 5.43319 -0.36
-v -6.83614 5.33614 -0.36
-v -7.09222 5.20069 -0.36
-v -7.27533 5.02045 -0.36
-v -7.38531 4.78902 -0.36
-v -7.422 4.5 -0.36
-v -3.804 5.5035 -0.4725
-v -4.47109 5.5025 -0.4725
-v -5.06933 5.49547 -0.4725
-v -5.5984 5.47641 -0.4725
-v -6.05798 5.43928 -0.4725
-v -6.44775 5.37806 -0.4725
-v -6.76738 5.28674 -0.4725
-v -7.01654 5.1593 -0.4725
-v -7.19491 4.98971 -0.4725
-v -7.30217 4.77195 -0.4725
-v -7.338 4.5 -0.4725
-v -3.838 5.427 -0.54
-v -4.47649 5.42607 -0.54
-v -5.05002 5.41958 -0.54
-v -5.55804 5.40197 -0.54
-v -6.00005 5.36767 -0.54
-v -6.3755 5.31112 -0.54
-v -6.68387 5.22677 -0.54
-v -6.92464 5.10904 -0.54
-v -7.09726 4.95238 -0.54
-v -7.20123 4.75122 -0.54
-v -7.236 4.5 -0.54
-v -3.875 5.34375 -0.5625
-v -4.48238 5.34291 -0.5625
-v -5.029 5.337 -0.5625
-v -5.51413 5.32097 -0.5625
-v -5.937 5.28975 -0.5625
-v -6.29688 5.23828 -0.5625
-v -6.593 5.1615 -0.5625
-v -6.82463 5.05434 -0.5625
-v -6.991 4.91175 -0.5625
-v -7.09137 4.72866 -0.5625
-v -7.125 4.5 -0.5625
-v -3.912 5.2605 -0.54
-v -4.48826 5.25974 -0.54
-v -5.00798 5.25442 -0.54
-v -5.47021 5.23997 -0.54
-v -5.87395 5.21183 -0.54
-v -6.21825 5.16544 -0.54
-v -6.50213 5.09623 -0.54
-v -6.72461 4.99965 -0.54
-v -6.88474 4.87112 -0.54
-v -6.98152 4.7061 -0.54
-v -7.014 4.5 -0.54
-v -3.946 5.184 -0.4725
-v -4.49366 5.18332 -0.4725
-v -4.98867 5.17853 -0.4725
-v -5.42985 5.16553 -0.4725
-v -5.81602 5.14022 -0.4725
-v -6.146 5.0985 -0.4725
-v -6.41862 5.03626 -0.4725
-v -6.63271 4.94939 -0.4725
-v -6.78709 4.83379 -0.4725
-v -6.88058 4.68536 -0.4725
-v -6.912 4.5 -0.4725
-v -3.974 5.121 -0.36
-v -4.49812 5.12038 -0.36
-v -4.97277 5.11603 -0.36
-v -5.39661 5.10423 -0.36
-v -5.7683 5.08126 -0.36
-v -6.0865 5.04338 -0.36
-v -6.34986 4.98686 -0.36
-v -6.55703 4.908 -0.36
-v -6.70667 4.80305 -0.36
-v -6.79744 4.66829 -0.36
-v -6.828 4.5 -0.36
-v -3.993 5.07825 -0.2025
-v -4.50114 5.07767 -0.2025
-v -4.96198 5.07362 -0.2025
-v -5.37406 5.06264 -0.2025
-v -5.73593 5.04124 -0.2025
-v -6.04612 5.00597 -0.2025
-v -6.30319 4.95335 -0.2025
-v -6.50567 4.87991 -0.2025
-v -6.6521 4.78219 -0.2025
-v -6.74103 4.65671 -0.2025
-v -6.771 4.5 -0.2025
-v -6.75544 4.3113 0.2025
-v -6.73475 4.3155 -1.88582e-07
-v -6.70777 4.09652 0.2025
-v -6.688 4.104 -1.7942e-07
-v -6.62651 3.86226 0.2025
-v -6.60825 3.87225 -1.69214e-07
-v -6.51018 3.61507 0.2025
-v -6.494 3.627 -1.58544e-07
-v -6.35731 3.36155 0.2025
-v -6.34375 3.375 -1.47526e-07
-v -6.16642 3.10826 0.2025
-v -6.156 3.123 -1.36508e-07
-v -5.93602 2.86178 0.2025
-v -5.92925 2.87775 -1.25838e-07
-v -5.66463 2.6287 0.2025
-v -5.662 2.646 -1.15631e-07
-v -5.35079 2.41558 0.2025
-v -5.35275 2.4345 -1.06469e-07
-v -4.993 2.229 0.2025
-v -5 2.25 -9.83506e-08
-v -6.81159 4.29989 0.36
-v -6.76142 4.07623 0.36
-v -6.67607 3.83513 0.36
-v -6.55411 3.5827 0.36
-v -6.39412 3.32503 0.36
-v -6.19469 3.06824 0.36
-v -5.95438 2.81844 0.36
-v -5.67178 2.58173 0.36
-v -5.34546 2.36421 0.36
-v -4.974 2.172 0.36
-v -6.89435 4.28308 0.4725
-v -6.8405 4.04633 0.4725
-v -6.74911 3.79516 0.4725
-v -6.61885 3.53498 0.4725
-v -6.44837 3.27122 0.4725
-v -6.23635 3.00928 0.4725
-v -5.98144 2.75457 0.4725
-v -5.6823 2.51251 0.4725
-v -5.3376 2.28852 0.4725
-v -4.946 2.088 0.4725
-v -6.99483 4.26267 0.54
-v -6.93651 4.01002 0.54
-v -6.8378 3.74662 0.54
-v -6.69746 3.47705 0.54
-v -6.51425 3.20588 0.54
-v -6.28694 2.93767 0.54
-v -6.0143 2.67701 0.54
-v -5.69509 2.42846 0.54
-v -5.32807 2.1966 0.54
-v -4.912 1.986 0.54
-v -7.10419 4.24045 0.5625
-v -7.041 3.9705 0.5625
-v -6.93431 3.6938 0.5625
-v -6.783 3.414 0.5625
-v -6.58594 3.13477 0.5625
-v -6.342 2.85975 0.5625
-v -6.05006 2.59261 0.5625
-v -5.709 2.337 0.5625
-v -5.31769 2.09658 0.5625
-v -4.875 1.875 0.5625
-v -7.21354 4.21824 0.54
-v -7.14549 3.93098 0.54
-v -7.03083 3.64097 0.54
-v -6.86854 3.35095 0.54
-v -6.65763 3.06366 0.54
-v -6.39706 2.78183 0.54
-v -6.08582 2.50821 0.54
-v -5.72291 2.24554 0.54
-v -5.30731 1.99655 0.54
-v -4.838 1.764 0.54
-v -7.31403 4.19783 0.4725
-v -7.2415 3.89467 0.4725
-v -7.11952 3.59244 0.4725
-v -6.94715 3.29302 0.4725
-v -6.7235 2.99831 0.4725
-v -6.44765 2.71022 0.4725
-v -6.11868 2.43065 0.4725
-v -5.7357 2.16149 0.4725
-v -5.29777 1.90464 0.4725
-v -4.804 1.662 0.4725
-v -7.39678 4.18102 0.36
-v -7.32058 3.86477 0.36
-v -7.19255 3.55246 0.36
-v -7.01189 3.2453 0.36
-v -6.77775 2.9445 0.36
-v -6.48931 2.65126 0.36
-v -6.14575 2.36678 0.36
-v -5.74622 2.09227 0.36
-v -5.28992 1.82894 0.36
-v -4.776 1.578 0.36
-v -7.45294 4.16961 0.2025
-v -7.37423 3.84448 0.2025
-v -7.24212 3.52534 0.2025
-v -7.05582 3.21293 0.2025
-v -6.81456 2.90798 0.2025
-v -6.51758 2.61124 0.2025
-v -6.16411 2.32344 0.2025
-v -5.75337 2.0453 0.2025
-v -5.28459 1.77758 0.2025
-v -4.757 1.521 0.2025
-v -7.47363 4.16541 -4.5026e-07
-v -7.394 3.837 -4.35888e-07
-v -7.26038 3.51534 -4.21865e-07
-v -7.072 3.201 -4.08131e-07
-v -6.82812 2.89453 -3.94745e-07
-v -6.528 2.5965 -3.81765e-07
-v -6.17087 2.30747 -3.69133e-07
-v -5.756 2.028 -3.56907e-07
-v -5.28262 1.75866 -3.45145e-07
-v -4.75 1.5 -3.33788e-07
-v -7.45294 4.16961 -0.2025
-v -7.37423 3.84448 -0.2025
-v -7.24212 3.52534 -0.2025
-v -7.05582 3.21293 -0.2025
-v -6.81456 2.90798 -0.2025
-v -6.51758 2.61124 -0.2025
-v -6.16411 2.32344 -0.2025
-v -5.75337 2.0453 -0.2025
-v -5.28459 1.77758 -0.2025
-v -4.757 1.521 -0.2025
-v -7.39678 4.18102 -0.36
-v -7.32058 3.86477 -0.36
-v -7.19255 3.55246 -0.36
-v -7.01189 3.2453 -0.36
-v -6.77775 2.9445 -0.36
-v -6.48931 2.65126 -0.36
-v -6.14575 2.36678 -0.36
-v -5.74622 2.09227 -0.36
-v -5.28992 1.82894 -0.36
-v -4.776 1.578 -0.36
-v -7.31403 4.19783 -0.4725
-v -7.2415 3.89467 -0.4725
-v -7.11952 3.59244 -0.4725
-v -6.94715 3.29302 -0.4725
-v -6.7235 2.99831 -0.4725
-v -6.44765 2.71022 -0.4725
-v -6.11868 2.43065 -0.4725
-v -5.7357 2.16149 -0.4725
-v -5.29777 1.90464 -0.4725
-v -4.804 1.662 -0.4725
-v -7.21354 4.21824 -0.54
-v -7.14549 3.93098 -0.54
-v -7.03083 3.64097 -0.54
-v -6.86854 3.35095 -0.54
-v -6.65763 3.06366 -0.54
-v -6.39706 2.78183 -0.54
-v -6.08582 2.50821 -0.54
-v -5.72291 2.24554 -0.54
-v -5.30731 1.99655 -0.54
-v -4.838 1.764 -0.54
-v -7.10419 4.24045 -0.5625
-v -7.041 3.9705 -0.5625
-v -6.93431 3.6938 -0.5625
-v -6.783 3.414 -0.5625
-v -6.58594 3.13477 -0.5625
-v -6.342 2.85975 -0.5625
-v -6.05006 2.59261 -0.5625
-v -5.709 2.337 -0.5625
-v -5.31769 2.09658 -0.5625
-v -4.875 1.875 -0.5625
-v -6.99483 4.26267 -0.54
-v -6.93651 4.01002 -0.54
-v -6.8378 3.74662 -0.54
-v -6.69746 3.47705 -0.54
-v -6.51425 3.20588 -0.54
-v -6.28694 2.93767 -0.54
-v -6.0143 2.67701 -0.54
-v -5.69509 2.42846 -0.54
-v -5.32807 2.1966 -0.54
-v -4.912 1.986 -0.54
-v -6.89435 4.28308 -0.4725
-v -6.8405 4.04633 -0.4725
-v -6.74911 3.79516 -0.4725
-v -6.61885 3.53498 -0.4725
-v -6.44837 3.27122 -0.4725
-v -6.23635 3.00928 -0.4725
-v -5.98144 2.75457 -0.4725
-v -5.6823 2.51251 -0.4725
-v -5.3376 2.28852 -0.4725
-v -4.946 2.088 -0.4725
-v -6.81159 4.29989 -0.36
-v -6.76142 4.07623 -0.36
-v -6.67607 3.83513 -0.36
-v -6.55411 3.5827 -0.36
-v -6.39412 3.32503 -0.36
-v -6.19469 3.06824 -0.36
-v -5.95438 2.81844 -0.36
-v -5.67178 2.58173 -0.36
-v -5.34546 2.36421 -0.36
-v -4.974 2.172 -0.36
-v -6.75544 4.3113 -0.2025
-v -6.70777 4.09652 -0.2025
-v -6.62651 3.86226 -0.2025
-v -6.51018 3.61507 -0.2025
-v -6.35731 3.36155 -0.2025
-v -6.16642 3.10826 -0.2025
-v -5.93602 2.86178 -0.2025
-v -5.66463 2.6287 -0.2025
-v -5.35079 2.41558 -0.2025
-v -4.993 2.229 -0.2025
-v 4.25 3.5625 -1.55722e-07
-v 4.25 3.50475 0.4455
-v 4.84849 3.55805 0.437751
-v 4.83975 3.6105 -1.57771e-07
-v 5.29245 3.6978 0.416718
-v 5.278 3.744 -1.63647e-07
-v 5.61114 3.90793 0.385722
-v 5.59325 3.94725 -1.72539e-07
-v 5.83382 4.17237 0.348084
-v 5.814 4.2045 -1.8375e-07
-v 5.98975 4.47506 0.307125
-v 5.96875 4.5 -1.96701e-07
-v 6.10818 4.79994 0.266166
-v 6.086 4.818 -2.1058e-07
-v 6.21836 5.13094 0.228528
-v 6.19425 5.14275 -2.24807e-07
-v 6.34955 5.45199 0.197532
-v 6.322 5.4585 -2.3857e-07
-v 6.53101 5.74703 0.176499
-v 6.49775 5.7495 -2.51289e-07
-v 6.792 6 0.16875
-v 6.75 6 -2.62268e-07
-v 4.25 3.348 0.792
-v 4.8722 3.41569 0.778224
-v 5.33166 3.5724 0.740832
-v 5.65971 3.8012 0.685728
-v 5.88763 4.08516 0.618816
-v 6.04675 4.40737 0.546
-v 6.16837 4.75092 0.473184
-v 6.28379 5.09888 0.406272
-v 6.42434 5.43432 0.351168
-v 6.6213 5.74034 0.313776
-v 6.906 6 0.3
-v 4.25 3.117 1.0395
-v 4.90714 3.2059 1.02142
-v 5.38946 3.3876 0.972342
-v 5.73127 3.6439 0.900018
-v 5.96693 3.95664 0.812196
-v 6.13075 4.30762 0.716625
-v 6.25707 4.67868 0.621054
-v 6.38023 5.05162 0.533232
-v 6.53454 5.40828 0.460908
-v 6.75436 5.73047 0.411831
-v 7.074 6 0.39375
-v 4.25 2.8365 1.188
-v 4.94957 2.95116 1.16734
-v 5.45963 3.1632 1.11125
-v 5.81818 3.45291 1.02859
-v 6.06322 3.80058 0.928224
-v 6.23275 4.1865 0.819
-v 6.36478 4.59096 0.709776
-v 6.49732 4.99425 0.609408
-v 6.66837 5.37666 0.526752
-v 6.91593 5.71848 0.470664
-v 7.278 6 0.45
-v 4.25 2.53125 1.2375
-v 4.99575 2.67394 1.21597
-v 5.536 2.919 1.15755
-v 5.91275 3.24506 1.07145
-v 6.168 3.63075 0.9669
-v 6.34375 4.05469 0.853125
-v 6.482 4.4955 0.73935
-v 6.62475 4.93181 0.6348
-v 6.814 5.34225 0.5487
-v 7.09175 5.70544 0.490275
-v 7.5 6 0.46875
-v 4.25 2.226 1.188
-v 5.04193 2.39671 1.16734
-v 5.61237 2.6748 1.11125
-v 6.00732 3.03721 1.02859
-v 6.27278 3.46092 0.928224
-v 6.45475 3.92287 0.819
-v 6.59922 4.40004 0.709776
-v 6.75218 4.86938 0.609408
-v 6.95963 5.30784 0.526752
-v 7.26757 5.6924 0.470664
-v 7.722 6 0.45
-v 4.25 1.9455 1.0395
-v 5.08436 2.14197 1.02142
-v 5.68254 2.4504 0.972342
-v 6.09423 2.84622 0.900018
-v 6.36907 3.30486 0.812196
-v 6.55675 3.80175 0.716625
-v 6.70693 4.31232 0.621054
-v 6.86927 4.812 0.533232
-v 7.09346 5.27622 0.460908
-v 7.42914 5.68041 0.411831
-v 7.926 6 0.39375
-v 4.25 1.7145 0.792
-v 5.1193 1.93218 0.778224
-v 5.74034 2.2656 0.740832
-v 6.16579 2.68893 0.685728
-v 6.44837 3.17634 0.618816
-v 6.64075 3.702 0.546
-v 6.79563 4.24008 0.473184
-v 6.96571 4.76475 0.406272
-v 7.20366 5.25018 0.351168
-v 7.5622 5.67054 0.313776
-v 8.094 6 0.3
-v 4.25 1.55775 0.4455
-v 5.14301 1.78982 0.437751
-v 5.77955 2.1402 0.416718
-v 6.21436 2.5822 0.385722
-v 6.50218 3.08913 0.348084
-v 6.69775 3.63431 0.307125
-v 6.85582 4.19106 0.266166
-v 7.03114 4.73269 0.228528
-v 7.27845 5.23251 0.197532
-v 7.65249 5.66384 0.176499
-v 8.208 6 0.16875
-v 4.25 1.5 -6.55676e-07
-v 5.15175 1.73738 -6.55754e-07
-v 5.794 2.094 -6.43472e-07
-v 6.23225 2.54288 -6.22094e-07
-v 6.522 3.057 -5.94652e-07
-v 6.71875 3.60938 -5.64526e-07
-v 6.878 4.173 -5.3498e-07
-v 7.05525 4.72088 -5.09046e-07
-v 7.306 5.226 -4.90104e-07
-v 7.68575 5.66138 -4.81301e-07
-v 8.25 6 -4.85786e-07
-v 4.25 1.55775 -0.4455
-v 5.14301 1.78982 -0.437751
-v 5.77955 2.1402 -0.416718
-v 6.21436 2.5822 -0.385722
-v 6.50218 3.08913 -0.348084
-v 6.69775 3.63431 -0.307125
-v 6.85582 4.19106 -0.266166
-v 7.03114 4.73269 -0.228528
-v 7.27845 5.23251 -0.197532
-v 7.65249 5.66384 -0.176499
-v 8.208 6 -0.16875
-v 4.25 1.7145 -0.792
-v 5.1193 1.93218 -0.778224
-v 5.74034 2.2656 -0.740832
-v 6.16579 2.68893 -0.685728
-v 6.44837 3.17634 -0.618816
-v 6.64075 3.702 -0.546
-v 6.79563 4.24008 -0.473184
-v 6.96571 4.76475 -0.406272
-v 7.20366 5.25018 -0.351168
-v 7.5622 5.67054 -0.313776
-v 8.094 6 -0.3
-v 4.25 1.9455 -1.0395
-v 5.08436 2.14197 -1.02142
-v 5.68254 2.4504 -0.972342
-v 6.09423 2.84622 -0.900018
-v 6.36907 3.30486 -0.812196
-v 6.55675 3.80175 -0.716625
-v 6.70693 4.31232 -0.621054
-v 6.86927 4.812 -0.533232
-v 7.09346 5.27622 -0.460908
-v 7.42914 5.68041 -0.411831
-v 7.926 6 -0.39375
-v 4.25 2.226 -1.188
-v 5.04193 2.39671 -1.16734
-v 5.61237 2.6748 -1.11125
-v 6.00732 3.03721 -1.02859
-v 6.27278 3.46092 -0.928224
-v 6.45475 3.92287 -0.819
-v 6.59922 4.40004 -0.709776
-v 6.75218 4.86938 -0.609408
-v 6.95963 5.30784 -0.526752
-v 7.26757 5.6924 -0.470664
-v 7.722 6 -0.45
-v 4.25 2.53125 -1.2375
-v 4.99575 2.67394 -1.21598
-v 5.536 2.919 -1.15755
-v 5.91275 3.24506 -1.07145
-v 6.168 3.63075 -0.9669
-v 6.34375 4.05469 -0.853125
-v 6.482 4.4955 -0.73935
-v 6.62475 4.93181 -0.6348
-v 6.814 5.34225 -0.5487
-v 7.09175 5.70544 -0.490275
-v 7.5 6 -0.46875
-v 4.25 2.8365 -1.188
-v 4.94957 2.95116 -1.16734
-v 5.45963 3.1632 -1.11125
-v 5.81818 3.45291 -1.02859
-v 6.06322 3.80058 -0.928224
-v 6.23275 4.1865 -0.819
-v 6.36478 4.59096 -0.709776
-v 6.49732 4.99425 -0.609408
-v 6.66837 5.37666 -0.526752
-v 6.91593 5.71848 -0.470664
-v 7.278 6 -0.45
-v 4.25 3.117 -1.0395
-v 4.90714 3.20591 -1.02142
-v 5.38946 3.3876 -0.972342
-v 5.73127 3.6439 -0.900018
-v 5.96693 3.95664 -0.812196
-v 6.13075 4.30762 -0.716625
-v 6.25707 4.67868 -0.621054
-v 6.38023 5.05163 -0.533232
-v 6.53454 5.40828 -0.460908
-v 6.75436 5.73047 -0.411831
-v 7.074 6 -0.39375
-v 4.25 3.348 -0.792
-v 4.8722 3.4157 -0.778224
-v 5.33166 3.5724 -0.740832
-v 5.65971 3.8012 -0.685728
-v 5.88763 4.08516 -0.618816
-v 6.04675 4.40738 -0.546
-v 6.16837 4.75092 -0.473184
-v 6.28379 5.09888 -0.406272
-v 6.42434 5.43432 -0.351168
-v 6.6213 5.74034 -0.313776
-v 6.906 6 -0.3
-v 4.25 3.50475 -0.4455
-v 4.84849 3.55805 -0.437751
-v 5.29245 3.6978 -0.416718
-v 5.61114 3.90793 -0.385722
-v 5.83382 4.17237 -0.348084
-v 5.98975 4.47506 -0.307125
-v 6.10818 4.79994 -0.266166
-v 6.21836 5.13094 -0.228528
-v 6.34955 5.45199 -0.197532
-v 6.53101 5.74703 -0.176499
-v 6.792 6 -0.16875
-v 6.86852 6.05101 0.16686
-v 6.8245 6.05062 -2.64472e-07
-v 6.94091 6.09076 0.16173
-v 6.896 6.09 -2.66212e-07
-v 7.00632 6.1192 0.15417
-v 6.9615 6.11812 -2.67488e-07
-v 7.06188 6.13632 0.14499
-v 7.018 6.135 -2.68183e-07
-v 7.10472 6.1421 0.135
-v 7.0625 6.14062 -2.68415e-07
-v 7.13198 6.13651 0.12501
-v 7.092 6.135 -2.68183e-07
-v 7.14081 6.11953 0.11583
-v 7.1035 6.11812 -2.67488e-07
-v 7.12833 6.09113 0.10827
-v 7.094 6.09 -2.66212e-07
-v 7.09168 6.0513 0.10314
-v 7.0605 6.05062 -2.64472e-07
-v 7.028 6 0.10125
-v 7 6 -2.62268e-07
-v 6.988 6.05207 0.29664
-v 7.06282 6.09281 0.28752
-v 7.12797 6.12212 0.27408
-v 7.18097 6.13991 0.25776
-v 7.21931 6.14611 0.24
-v 7.24051 6.14062 0.22224
-v 7.24207 6.12335 0.20592
-v 7.2215 6.09421 0.19248
-v 7.17631 6.05313 0.18336
-v 7.104 6 0.18
-v 7.16407 6.05363 0.38934
-v 7.24246 6.09583 0.37737
-v 7.30725 6.12642 0.35973
-v 7.35647 6.14521 0.33831
-v 7.38819 6.15202 0.315
-v 7.40045 6.14666 0.29169
-v 7.39131 6.12897 0.27027
-v 7.35882 6.09875 0.25263
-v 7.30103 6.05582 0.24066
-v 7.216 6 0.23625
-v 7.37787 6.05553 0.44496
-v 7.46061 6.0995 0.43128
-v 7.52494 6.13164 0.41112
-v 7.56958 6.15163 0.38664
-v 7.59325 6.15919 0.36
-v 7.59466 6.15401 0.33336
-v 7.57252 6.1358 0.30888
-v 7.52555 6.10426 0.28872
-v 7.45247 6.05909 0.27504
-v 7.352 6 0.27
-v 7.61053 6.05759 0.4635
-v 7.698 6.1035 0.44925
-v 7.76184 6.13732 0.42825
-v 7.8015 6.15862 0.40275
-v 7.81641 6.16699 0.375
-v 7.806 6.162 0.34725
-v 7.76972 6.14323 0.32175
-v 7.707 6.11025 0.30075
-v 7.61728 6.06265 0.2865
-v 7.5 6 0.28125
-v 7.8432 6.05965 0.44496
-v 7.93539 6.1075 0.43128
-v 7.99875 6.143 0.41112
-v 8.03342 6.16562 0.38664
-v 8.03956 6.1748 0.36
-v 8.01734 6.16999 0.33336
-v 7.96692 6.15066 0.30888
-v 7.88845 6.11624 0.28872
-v 7.78209 6.06621 0.27504
-v 7.648 6 0.27
-v 8.057 6.06154 0.38934
-v 8.15354 6.11117 0.37737
-v 8.21644 6.14822 0.35973
-v 8.24653 6.17204 0.33831
-v 8.24463 6.18197 0.315
-v 8.21155 6.17734 0.29169
-v 8.14813 6.15748 0.27027
-v 8.05518 6.12175 0.25263
-v 7.93353 6.06948 0.24066
-v 7.784 6 0.23625
-v 8.23307 6.0631 0.29664
-v 8.33318 6.11419 0.28752
-v 8.39572 6.15252 0.27408
-v 8.42203 6.17734 0.25776
-v 8.4135 6.18788 0.24
-v 8.37149 6.18338 0.22224
-v 8.29736 6.16311 0.20592
-v 8.1925 6.12629 0.19248
-v 8.05825 6.07217 0.18336
-v 7.896 6 0.18
-v 8.35254 6.06416 0.16686
-v 8.45509 6.11624 0.16173
-v 8.51737 6.15544 0.15417
-v 8.54112 6.18093 0.14499
-v 8.52809 6.19188 0.135
-v 8.48002 6.18749 0.12501
-v 8.39863 6.16692 0.11583
-v 8.28567 6.12937 0.10827
-v 8.14288 6.074 0.10314
-v 7.972 6 0.10125
-v 8.39656 6.06455 -4.86052e-07
-v 8.5 6.117 -4.81576e-07
-v 8.56219 6.15652 -4.73318e-07
-v 8.585 6.18225 -4.6224e-07
-v 8.57031 6.19336 -4.49534e-07
-v 8.52 6.189 -4.36162e-07
-v 8.43594 6.16833 -4.23083e-07
-v 8.32 6.1305 -4.11375e-07
-v 8.17406 6.07467 -4.02115e-07
-v 8 6 -3.96379e-07
-v 8.35254 6.06416 -0.16686
-v 8.45509 6.11624 -0.16173
-v 8.51737 6.15544 -0.15417
-v 8.54112 6.18093 -0.14499
-v 8.52809 6.19188 -0.135
-v 8.48002 6.18749 -0.12501
-v 8.39863 6.16692 -0.11583
-v 8.28567 6.12937 -0.10827
-v 8.14288 6.074 -0.10314
-v 7.972 6 -0.10125
-v 8.23307 6.0631 -0.29664
-v 8.33318 6.11419 -0.28752
-v 8.39572 6.15252 -0.27408
-v 8.42203 6.17734 -0.25776
-v 8.4135 6.18788 -0.24
-v 8.37149 6.18338 -0.22224
-v 8.29736 6.16311 -0.20592
-v 8.1925 6.12629 -0.19248
-v 8.05825 6.07217 -0.18336
-v 7.896 6 -0.18
-v 8.057 6.06154 -0.38934
-v 8.15354 6.11117 -0.37737
-v 8.21644 6.14822 -0.35973
-v 8.24653 6.17204 -0.33831
-v 8.24463 6.18197 -0.315
-v 8.21155 6.17734 -0.29169
-v 8.14813 6.15748 -0.27027
-v 8.05518 6.12175 -0.25263
-v 7.93353 6.06948 -0.24066
-v 7.784 6 -0.23625
-v 7.8432 6.05965 -0.44496
-v 7.93539 6.1075 -0.43128
-v 7.99875 6.143 -0.41112
-v 8.03342 6.16562 -0.38664
-v 8.03956 6.1748 -0.36
-v 8.01734 6.16999 -0.33336
-v 7.96692 6.15066 -0.30888
-v 7.88845 6.11624 -0.28872
-v 7.78209 6.06621 -0.27504
-v 7.648 6 -0.27
-v 7.61053 6.05759 -0.4635
-v 7.698 6.1035 -0.44925
-v 7.76184 6.13732 -0.42825
-v 7.8015 6.15862 -0.40275
-v 7.81641 6.16699 -0.375
-v 7.806 6.162 -0.34725
-v 7.76972 6.14323 -0.32175
-v 7.707 6.11025 -0.30075
-v 7.61728 6.06265 -0.2865
-v 7.5 6 -0.28125
-v 7.37787 6.05553 -0.44496
-v 7.46061 6.0995 -0.43128
-v 7.52494 6.13164 -0.41112
-v 7.56958 6.15163 -0.38664
-v 7.59325 6.15919 -0.36
-v 7.59466 6.15401 -0.33336
-v 7.57252 6.1358 -0.30888
-v 7.52555 6.10426 -0.28872
-v 7.45247 6.05909 -0.27504
-v 7.352 6 -0.27
-v 7.16407 6.05363 -0.38934
-v 7.24246 6.09583 -0.37737
-v 7.30725 6.12642 -0.35973
-v 7.35647 6.14521 -0.33831
-v 7.38819 6.15202 -0.315
-v 7.40045 6.14666 -0.29169
-v 7.39131 6.12897 -0.27027
-v 7.35882 6.09875 -0.25263
-v 7.30103 6.05582 -0.24066
-v 7.216 6 -0.23625
-v 6.98799 6.05207 -0.29664
-v 7.06282 6.09281 -0.28752
-v 7.12797 6.12212 -0.27408
-v 7.18097 6.13991 -0.25776
-v 7.21931 6.14611 -0.24
-v 7.24051 6.14062 -0.22224
-v 7.24207 6.12335 -0.20592
-v 7.2215 6.09421 -0.19248
-v 7.17631 6.05313 -0.18336
-v 7.104 6 -0.18
-v 6.86852 6.05101 -0.16686
-v 6.94091 6.09076 -0.16173
-v 7.00632 6.1192 -0.15417
-v 7.06188 6.13632 -0.14499
-v 7.10472 6.1421 -0.135
-v 7.13198 6.13651 -0.12501
-v 7.14081 6.11953 -0.11583
-v 7.12833 6.09113 -0.10827
-v 7.09168 6.0513 -0.10314
-v 7.028 6 -0.10125
-v 9.64804e-16 7.875 -3.44227e-07
-v 0.480267 7.85363 0.0801198
-v 0.4865 7.85363 -3.43299e-07
-v 0.762109 7.794 0.127136
-v 0.772 7.794 -3.40632e-07
-v 0.884026 7.70288 0.147469
-v 0.8955 7.70288 -3.36688e-07
-v 0.884518 7.587 0.14754
-v 0.896 7.587 -3.31585e-07
-v 0.802086 7.45312 0.13377
-v 0.8125 7.45312 -3.25786e-07
-v 0.675229 7.308 0.11258
-v 0.684 7.308 -3.19407e-07
-v 0.542448 7.15837 0.0903913
-v 0.5495 7.15837 -3.12913e-07
-v 0.442243 7.011 0.0736242
-v 0.448 7.011 -3.06418e-07
-v 0.413113 6.87262 0.0687
-v 0.4185 6.87262 -3.00387e-07
-v 0.49356 6.75 0.0820397
-v 0.5 6.75 -2.95052e-07
-v 0.462175 7.85363 0.155679
-v 0.733399 7.794 0.247036
-v 0.850722 7.70288 0.286547
-v 0.851192 7.587 0.286689
-v 0.77186 7.45312 0.25994
-v 0.649774 7.308 0.218776
-v 0.521984 7.15837 0.175675
-v 0.425538 7.011 0.143114
-v 0.397488 6.87262 0.13357
-v 0.47488 6.75 0.15952
-v 0.433137 7.85363 0.225766
-v 0.687319 7.794 0.358252
-v 0.797268 7.70288 0.415553
-v 0.797705 7.587 0.415764
-v 0.723349 7.45312 0.376982
-v 0.608923 7.308 0.317299
-v 0.489146 7.15837 0.254813
-v 0.398739 7.011 0.207617
-v 0.372424 6.87262 0.193808
-v 0.44492 6.75 0.23148
-v 0.394065 7.85363 0.289467
-v 0.625317 7.794 0.459335
-v 0.725345 7.70288 0.532808
-v 0.725737 7.587 0.533085
-v 0.65808 7.45312 0.48337
-v 0.553962 7.308 0.406863
-v 0.444971 7.15837 0.326767
-v 0.362696 7.011 0.266283
-v 0.338723 6.87262 0.248614
-v 0.40464 6.75 0.29696
-v 0.345871 7.85363 0.34587
-v 0.54884 7.794 0.54884
-v 0.636632 7.70288 0.636632
-v 0.63697 7.587 0.63697
-v 0.577578 7.45312 0.577578
-v 0.48618 7.308 0.48618
-v 0.390499 7.15837 0.390499
-v 0.31826 7.011 0.31826
-v 0.297186 6.87262 0.297185
-v 0.355 6.75 0.355
-v 0.289467 7.85363 0.394064
-v 0.459336 7.794 0.625317
-v 0.532808 7.70288 0.725345
-v 0.533085 7.587 0.725737
-v 0.48337 7.45312 0.65808
-v 0.406863 7.308 0.553962
-v 0.326767 7.15837 0.444971
-v 0.266283 7.011 0.362695
-v 0.248614 6.87262 0.338722
-v 0.29696 6.75 0.40464
-v 0.225766 7.85363 0.433136
-v 0.358252 7.794 0.687319
-v 0.415553 7.70288 0.797268
-v 0.415765 7.587 0.797705
-v 0.376982 7.45312 0.723349
-v 0.3173 7.308 0.608922
-v 0.254813 7.15837 0.489145
-v 0.207618 7.011 0.398739
-v 0.193808 6.87262 0.372423
-v 0.23148 6.75 0.44492
-v 0.155679 7.85363 0.462175
-v 0.247036 7.794 0.733399
-v 0.286547 7.70288 0.850721
-v 0.286689 7.587 0.851192
-v 0.25994 7.45312 0.77186
-v 0.218776 7.308 0.649774
-v 0.175675 7.15837 0.521984
-v 0.143114 7.011 0.425538
-v 0.13357 6.87262 0.397487
-v 0.15952 6.75 0.47488
-v 0.0801201 7.85363 0.480266
-v 0.127136 7.794 0.762108
-v 0.147469 7.70288 0.884025
-v 0.14754 7.587 0.884518
-v 0.13377 7.45312 0.802085
-v 0.112581 7.308 0.675229
-v 0.0903915 7.15837 0.542448
-v 0.0736244 7.011 0.442242
-v 0.0687002 6.87262 0.413113
-v 0.0820399 6.75 0.49356
-v -9.79053e-08 7.85363 0.4865
-v -1.55298e-07 7.794 0.772
-v -1.80095e-07 7.70288 0.8955
-v -1.80211e-07 7.587 0.896
-v -1.63447e-07 7.45312 0.8125
-v -1.37486e-07 7.308 0.684
-v -1.10362e-07 7.15837 0.5495
-v -8.98726e-08 7.011 0.448
-v -8.3819e-08 6.87262 0.4185
-v -1.00117e-07 6.75 0.5
-v -0.0801202 7.85363 0.480266
-v -0.127136 7.794 0.762108
-v -0.147469 7.70288 0.884025
-v -0.147541 7.587 0.884518
-v -0.133771 7.45312 0.802085
-v -0.112581 7.308 0.675229
-v -0.0903916 7.15837 0.542448
-v -0.0736245 7.011 0.442242
-v -0.0687003 6.87262 0.413113
-v -0.08204 6.75 0.49356
-v -0.15568 7.85363 0.462175
-v -0.247036 7.794 0.733399
-v -0.286547 7.70288 0.850721
-v -0.286689 7.587 0.851192
-v -0.25994 7.45312 0.77186
-v -0.218776 7.308 0.649774
-v -0.175675 7.15837 0.521983
-v -0.143114 7.011 0.425538
-v -0.13357 6.87262 0.397487
-v -0.15952 6.75 0.47488
-v -0.225766 7.85363 0.433136
-v -0.358252 7.794 0.687319
-v -0.415553 7.70288 0.797268
-v -0.415765 7.587 0.797705
-v -0.376982 7.45312 0.723349
-v -0.3173 7.308 0.608922
-v -0.254813 7.15837 0.489145
-v -0.207618 7.011 0.398739
-v -0.193808 6.87262 0.372423
-v -0.23148 6.75 0.44492
-v -0.289467 7.85363 0.394064
-v -0.459336 7.794 0.625317
-v -0.532808 7.70288 0.725345
-v -0.533085 7.587 0.725737
-v -0.48337 7.45312 0.65808
-v -0.406863 7.308 0.553962
-v -0.326767 7.15837 0.444971
-v -0.266283 7.011 0.362695
-v -0.248614 6.87262 0.338722
-v -0.29696 6.75 0.40464
-v -0.345871 7.85363 0.34587
-v -0.54884 7.794 0.54884
-v -0.636632 7.70288 0.636632
-v -0.63697 7.587 0.63697
-v -0.577578 7.45312 0.577578
-v -0.48618 7.308 0.48618
-v -0.390499 7.15837 0.390499
-v -0.31826 7.011 0.31826
-v -0.297186 6.87262 0.297185
-v -0.355 6.75 0.355
-v -0.394065 7.85363 0.289467
-v -0.625317 7.794 0.459335
-v -0.725345 7.70288 0.532808
-v -0.725737 7.587 0.533085
-v -0.65808 7.45312 0.48337
-v -0.553962 7.308 0.406863
-v -0.444971 7.15837 0.326767
-v -0.362696 7.011 0.266283
-v -0.338723 6.87262 0.248614
-v -0.40464 6.75 0.29696
-v -0.433137 7.85363 0.225765
-v -0.687319 7.794 0.358251
-v -0.797269 7.70288 0.415553
-v -0.797705 7.587 0.415764
-v -0.723349 7.45312 0.376981
-v -0.608923 7.308 0.317299
-v -0.489146 7.15837 0.254813
-v -0.398739 7.011 0.207617
-v -0.372424 6.87262 0.193808
-v -0.44492 6.75 0.23148
-v -0.462175 7.85363 0.155679
-v -0.733399 7.794 0.247036
-v -0.850722 7.70288 0.286547
-v -0.851192 7.587 0.286689
-v -0.77186 7.45312 0.25994
-v -0.649774 7.308 0.218776
-v -0.521984 7.15837 0.175675
-v -0.425539 7.011 0.143114
-v -0.397488 6.87262 0.13357
-v -0.47488 6.75 0.15952
-v -0.480267 7.85363 0.0801197
-v -0.762109 7.794 0.127136
-v -0.884026 7.70288 0.147469
-v -0.884518 7.587 0.14754
-v -0.802086 7.45312 0.13377
-v -0.675229 7.308 0.11258
-v -0.542448 7.15837 0.0903912
-v -0.442243 7.011 0.0736241
-v -0.413113 6.87262 0.0686999
-v -0.49356 6.75 0.0820396
-v -0.4865 7.85363 -4.41205e-07
-v -0.772 7.794 -4.9593e-07
-v -0.8955 7.70288 -5.16783e-07
-v -0.896 7.587 -5.11796e-07
-v -0.8125 7.45312 -4.89234e-07
-v -0.684 7.308 -4.56894e-07
-v -0.5495 7.15837 -4.23274e-07
-v -0.448 7.011 -3.96407e-07
-v -0.4185 6.87262 -3.84322e-07
-v -0.5 6.75 -3.95169e-07
-v -0.480267 7.85363 -0.0801205
-v -0.762109 7.794 -0.127137
-v -0.884026 7.70288 -0.14747
-v -0.884518 7.587 -0.147541
-v -0.802086 7.45312 -0.133771
-v -0.675229 7.308 -0.112581
-v -0.542448 7.15837 -0.0903919
-v -0.442243 7.011 -0.0736248
-v -0.413113 6.87262 -0.0687006
-v -0.49356 6.75 -0.0820403
-v -0.462175 7.85363 -0.15568
-v -0.733399 7.794 -0.247037
-v -0.850722 7.70288 -0.286547
-v -0.851192 7.587 -0.28669
-v -0.77186 7.45312 -0.25994
-v -0.649774 7.308 -0.218777
-v -0.521984 7.15837 -0.175676
-v -0.425538 7.011 -0.143115
-v -0.397488 6.87262 -0.13357
-v -0.47488 6.75 -0.15952
-v -0.433137 7.85363 -0.225766
-v -0.687319 7.794 -0.358252
-v -0.797268 7.70288 -0.415553
-v -0.797705 7.587 -0.415765
-v -0.723349 7.45312 -0.376982
-v -0.608923 7.308 -0.3173
-v -0.489146 7.15837 -0.254814
-v -0.398739 7.011 -0.207618
-v -0.372424 6.87262 -0.193809
-v -0.44492 6.75 -0.23148
-v -0.394065 7.85363 -0.289467
-v -0.625317 7.794 -0.459336
-v -0.725345 7.70288 -0.532808
-v -0.725737 7.587 -0.533086
-v -0.65808 7.45312 -0.48337
-v -0.553962 7.308 -0.406864
-v -0.444971 7.15837 -0.326768
-v -0.362696 7.011 -0.266284
-v -0.338723 6.87262 -0.248614
-v -0.40464 6.75 -0.29696
-v -0.345871 7.85363 -0.345871
-v -0.54884 7.794 -0.54884
-v -0.636632 7.70288 -0.636632
-v -0.63697 7.587 -0.63697
-v -0.577578 7.45312 -0.577578
-v -0.48618 7.308 -0.48618
-v -0.390499 7.15837 -0.3905
-v -0.31826 7.011 -0.31826
-v -0.297186 6.87262 -0.297186
-v -0.355 6.75 -0.355
-v -0.289467 7.85363 -0.394065
-v -0.459336 7.794 -0.625317
-v -0.532808 7.70288 -0.725346
-v -0.533085 7.587 -0.725737
-v -0.48337 7.45312 -0.65808
-v -0.406863 7.308 -0.553963
-v -0.326767 7.15837 -0.444972
-v -0.266283 7.011 -0.362696
-v -0.248614 6.87262 -0.338723
-v -0.29696 6.75 -0.40464
-v -0.225766 7.85363 -0.433137
-v -0.358252 7.794 -0.68732
-v -0.415553 7.70288 -0.797269
-v -0.415765 7.587 -0.797705
-v -0.376982 7.45312 -0.72335
-v -0.3173 7.308 -0.608923
-v -0.254813 7.15837 -0.489146
-v -0.207618 7.011 -0.398739
-v -0.193808 6.87262 -0.372424
-v -0.23148 6.75 -0.44492
-v -0.155679 7.85363 -0.462175
-v -0.247036 7.794 -0.733399
-v -0.286547 7.70288 -0.850722
-v -0.286689 7.587 -0.851193
-v -0.25994 7.45312 -0.77186
-v -0.218776 7.308 -0.649774
-v -0.175675 7.15837 -0.521984
-v -0.143114 7.011 -0.425539
-v -0.13357 6.87262 -0.397488
-v -0.15952 6.75 -0.47488
-v -0.0801201 7.85363 -0.480267
-v -0.127136 7.794 -0.762109
-v -0.147469 7.70288 -0.884026
-v -0.14754 7.587 -0.884518
-v -0.13377 7.45312 -0.802086
-v -0.112581 7.308 -0.675229
-v -0.0903915 7.15837 -0.542448
-v -0.0736244 7.011 -0.442243
-v -0.0687002 6.87262 -0.413114
-v -0.0820399 6.75 -0.49356
-v 9.79053e-08 7.85363 -0.4865
-v 1.55298e-07 7.794 -0.772
-v 1.80095e-07 7.70288 -0.8955
-v 1.80211e-07 7.587 -0.896
-v 1.63447e-07 7.45312 -0.8125
-v 1.37486e-07 7.308 -0.684
-v 1.10362e-07 7.15837 -0.5495
-v 8.98726e-08 7.011 -0.448
-v 8.3819e-08 6.87262 -0.4185
-v 1.00117e-07 6.75 -0.5
-v 0.0801202 7.85363 -0.480267
-v 0.127136 7.794 -0.762109
-v 0.147469 7.70288 -0.884026
-v 0.147541 7.587 -0.884518
-v 0.133771 7.45312 -0.802086
-v 0.112581 7.308 -0.675229
-v 0.0903916 7.15837 -0.542448
-v 0.0736245 7.011 -0.442243
-v 0.0687003 6.87262 -0.413114
-v 0.08204 6.75 -0.49356
-v 0.15568 7.85363 -0.462175
-v 0.247036 7.794 -0.733399
-v 0.286547 7.70288 -0.850722
-v 0.286689 7.587 -0.851193
-v 0.25994 7.45312 -0.77186
-v 0.218776 7.308 -0.649774
-v 0.175675 7.15837 -0.521984
-v 0.143114 7.011 -0.425539
-v 0.13357 6.87262 -0.397488
-v 0.15952 6.75 -0.47488
-v 0.225766 7.85363 -0.433137
-v 0.358252 7.794 -0.68732
-v 0.415553 7.70288 -0.797269
-v 0.415765 7.587 -0.797705
-v 0.376982 7.45312 -0.72335
-v 0.3173 7.308 -0.608923
-v 0.254813 7.15837 -0.489146
-v 0.207618 7.011 -0.398739
-v 0.193808 6.87262 -0.372424
-v 0.23148 6.75 -0.44492
-v 0.289467 7.85363 -0.394065
-v 0.459336 7.794 -0.625317
-v 0.532808 7.70288 -0.725346
-v 0.533085 7.587 -0.725737
-v 0.48337 7.45312 -0.65808
-v 0.406863 7.308 -0.553963
-v 0.326767 7.15837 -0.444972
-v 0.266283 7.011 -0.362696
-v 0.248614 6.87262 -0.338723
-v 0.29696 6.75 -0.40464
-v 0.345871 7.85363 -0.345871
-v 0.54884 7.794 -0.54884
-v 0.636632 7.70288 -0.636632
-v 0.63697 7.587 -0.63697
-v 0.577578 7.45312 -0.577578
-v 0.48618 7.308 -0.48618
-v 0.390499 7.15837 -0.3905
-v 0.31826 7.011 -0.31826
-v 0.297186 6.87262 -0.297186
-v 0.355 6.75 -0.355
-v 0.394065 7.85363 -0.289467
-v 0.625317 7.794 -0.459336
-v 0.725345 7.70288 -0.532808
-v 0.725737 7.587 -0.533086
-v 0.65808 7.45312 -0.48337
-v 0.553962 7.308 -0.406864
-v 0.444971 7.15837 -0.326768
-v 0.362696 7.011 -0.266284
-v 0.338723 6.87262 -0.248614
-v 0.40464 6.75 -0.29696
-v 0.433137 7.85363 -0.225766
-v 0.687319 7.794 -0.358252
-v 0.797269 7.70288 -0.415553
-v 0.797705 7.587 -0.415765
-v 0.723349 7.45312 -0.376982
-v 0.608923 7.308 -0.3173
-v 0.489146 7.15837 -0.254813
-v 0.398739 7.011 -0.207618
-v 0.372424 6.87262 -0.193809
-v 0.44492 6.75 -0.23148
-v 0.462175 7.85363 -0.15568
-v 0.733399 7.794 -0.247036
-v 0.850722 7.70288 -0.286547
-v 0.851192 7.587 -0.28669
-v 0.77186 7.45312 -0.25994
-v 0.649774 7.308 -0.218777
-v 0.521984 7.15837 -0.175676
-v 0.425539 7.011 -0.143114
-v 0.397488 6.87262 -0.13357
-v 0.47488 6.75 -0.15952
-v 0.480267 7.85363 -0.0801204
-v 0.762109 7.794 -0.127137
-v 0.884026 7.70288 -0.14747
-v 0.884518 7.587 -0.147541
-v 0.802086 7.45312 -0.133771
-v 0.675229 7.308 -0.112581
-v 0.542448 7.15837 -0.0903918
-v 0.442243 7.011 -0.0736247
-v 0.413113 6.87262 -0.0687005
-v 0.49356 6.75 -0.0820402
-v 0.689503 6.648 0.11461
-v 0.6985 6.648 -2.90567e-07
-v 0.965403 6.564 0.16047
-v 0.978 6.564 -2.86895e-07
-v 1.29757 6.4935 0.215683
-v 1.3145 6.4935 -2.83802e-07
-
-v 1.66231 6.432 0.27631
-v 1.684 6.432 -2.81173e-07
-v 2.03593 6.375 0.338415
-v 2.0625 6.375 -2.7866e-07
-v 2.39475 6.318 0.398058
-v 2.426 6.318 -2.76147e-07
-v 2.71507 6.2565 0.451302
-v 2.7505 6.2565 -2.73518e-07
-v 2.97321 6.186 0.494209
-v 3.012 6.186 -2.70426e-07
-v 3.14546 6.102 0.522841
-v 3.1865 6.102 -2.66753e-07
-v 3.20814 6 0.53326
-v 3.25 6 -2.62268e-07
-v 0.663407 6.648 0.222849
-v 0.928865 6.564 0.312021
-v 1.24846 6.4935 0.419378
-v 1.5994 6.432 0.537263
-v 1.95888 6.375 0.65802
-v 2.30412 6.318 0.773991
-v 2.61231 6.2565 0.877519
-v 2.86068 6.186 0.960948
-v 3.02641 6.102 1.01662
-v 3.08672 6 1.03688
-v 0.621553 6.648 0.323377
-v 0.870264 6.564 0.452775
-v 1.16969 6.4935 0.608561
-v 1.49849 6.432 0.779624
-v 1.83529 6.375 0.954855
-v 2.15875 6.318 1.12314
-v 2.4475 6.2565 1.27337
-v 2.6802 6.186 1.39444
-v 2.83548 6.102 1.47522
-v 2.89198 6 1.50462
-v 0.565282 6.648 0.414853
-v 0.791476 6.564 0.580853
-v 1.0638 6.4935 0.780708
-v 1.36283 6.432 1.00016
-v 1.66914 6.375 1.22496
-v 1.96331 6.318 1.44085
-v 2.22592 6.2565 1.63358
-v 2.43755 6.186 1.78889
-v 2.57877 6.102 1.89253
-v 2.63016 6 1.93024
-v 0.495935 6.648 0.495935
-v 0.69438 6.564 0.69438
-v 0.933295 6.4935 0.933295
-v 1.19564 6.432 1.19564
-v 1.46438 6.375 1.46437
-v 1.72246 6.318 1.72246
-v 1.95285 6.2565 1.95285
-v 2.13852 6.186 2.13852
-v 2.26241 6.102 2.26241
-v 2.3075 6 2.3075
-v 0.414853 6.648 0.565282
-v 0.580854 6.564 0.791476
-v 0.780708 6.4935 1.0638
-v 1.00016 6.432 1.36283
-v 1.22496 6.375 1.66914
-v 1.44085 6.318 1.96331
-v 1.63358 6.2565 2.22592
-v 1.78889 6.186 2.43755
-v 1.89253 6.102 2.57877
-v 1.93024 6 2.63016
-v 0.323378 6.648 0.621553
-v 0.452775 6.564 0.870263
-v 0.608561 6.4935 1.16969
-v 0.779625 6.432 1.49849
-v 0.954855 6.375 1.83529
-v 1.12314 6.318 2.15875
-v 1.27337 6.2565 2.4475
-v 1.39444 6.186 2.6802
-v 1.47522 6.102 2.83548
-v 1.50462 6 2.89198
-v 0.222849 6.648 0.663407
-v 0.312021 6.564 0.928865
-v 0.419378 6.4935 1.24846
-v 0.537263 6.432 1.5994
-v 0.65802 6.375 1.95888
-v 0.773991 6.318 2.30412
-v 0.877519 6.2565 2.61231
-v 0.960948 6.186 2.86068
-v 1.01662 6.102 3.02641
-v 1.03688 6 3.08672
-v 0.11461 6.648 0.689503
-v 0.16047 6.564 0.965403
-v 0.215683 6.4935 1.29757
-v 0.27631 6.432 1.66231
-v 0.338415 6.375 2.03593
-v 0.398058 6.318 2.39475
-v 0.451302 6.2565 2.71507
-v 0.494208 6.186 2.97321
-v 0.52284 6.102 3.14546
-v 0.533259 6 3.20814
-v -1.39931e-07 6.648 0.6985
-v -1.95811e-07 6.564 0.978
-v -2.63215e-07 6.4935 1.3145
-v -3.37255e-07 6.432 1.684
-v -4.13042e-07 6.375 2.0625
-v -4.85801e-07 6.318 2.426
-v -5.50877e-07 6.2565 2.7505
-v -6.03264e-07 6.186 3.012
-v -6.38189e-07 6.102 3.1865
-v -6.50878e-07 6 3.25
-v -0.11461 6.648 0.689503
-v -0.16047 6.564 0.965403
-v -0.215683 6.4935 1.29757
-v -0.276311 6.432 1.66231
-v -0.338415 6.375 2.03593
-v -0.398058 6.318 2.39475
-v -0.451302 6.2565 2.71507
-v -0.494209 6.186 2.97321
-v -0.522841 6.102 3.14546
-v -0.53326 6 3.20814
-v -0.222849 6.648 0.663407
-v -0.312021 6.564 0.928865
-v -0.419378 6.4935 1.24846
-v -0.537263 6.432 1.5994
-v -0.65802 6.375 1.95888
-v -0.773991 6.318 2.30412
-v -0.87752 6.2565 2.61231
-v -0.960949 6.186 2.86068
-v -1.01662 6.102 3.02641
-v -1.03688 6 3.08672
-v -0.323378 6.648 0.621553
-v -0.452775 6.564 0.870263
-v -0.608561 6.4935 1.16969
-v -0.779625 6.432 1.49849
-v -0.954855 6.375 1.83529
-v -1.12314 6.318 2.15875
-v -1.27337 6.2565 2.4475
-v -1.39444 6.186 2.6802
-v -1.47522 6.102 2.83547
-v -1.50462 6 2.89198
-v -0.414853 6.648 0.565282
-v -0.580854 6.564 0.791476
-v -0.780708 6.4935 1.0638
-v -1.00016 6.432 1.36283
-v -1.22496 6.375 1.66914
-v -1.44085 6.318 1.96331
-v -1.63358 6.2565 2.22592
-v -1.78889 6.186 2.43755
-v -1.89253 6.102 2.57877
-v -1.93024 6 2.63016
-v -0.495935 6.648 0.495935
-v -0.69438 6.564 0.69438
-v -0.933295 6.4935 0.933295
-v -1.19564 6.432 1.19564
-v -1.46438 6.375 1.46437
-v -1.72246 6.318 1.72246
-v -1.95285 6.2565 1.95285
-v -2.13852 6.186 2.13852
-v -2.26241 6.102 2.26241
-v -2.3075 6 2.3075
-v -0.565282 6.648 0.414853
-v -0.791476 6.564 0.580853
-v -1.0638 6.4935 0.780708
-v -1.36283 6.432 1.00016
-v -1.66914 6.375 1.22496
-v -1.96331 6.318 1.44085
-v -2.22592 6.2565 1.63358
-v -2.43755 6.186 1.78889
-v -2.57877 6.102 1.89253
-v -2.63016 6 1.93024
-v -0.621553 6.648 0.323377
-v -0.870264 6.564 0.452774
-v -1.16969 6.4935 0.608561
-v -1.49849 6.432 0.779624
-v -1.8353 6.375 0.954855
-v -2.15875 6.318 1.12314
-v -2.44751 6.2565 1.27337
-v -2.6802 6.186 1.39444
-v -2.83548 6.102 1.47522
-v -2.89198 6 1.50462
-v -0.663407 6.648 0.222849
-v -0.928865 6.564 0.312021
-v -1.24846 6.4935 0.419378
-v -1.5994 6.432 0.537263
-v -1.95888 6.375 0.658019
-v -2.30412 6.318 0.773991
-v -2.61232 6.2565 0.877519
-v -2.86068 6.186 0.960948
-v -3.02641 6.102 1.01662
-v -3.08672 6 1.03688
-v -0.689503 6.648 0.114609
-v -0.965403 6.564 0.16047
-v -1.29757 6.4935 0.215683
-v -1.66231 6.432 0.27631
-v -2.03593 6.375 0.338414
-v -2.39475 6.318 0.398057
-v -2.71507 6.2565 0.451301
-v -2.97321 6.186 0.494208
-v -3.14546 6.102 0.52284
-v -3.20814 6 0.533259
-v -0.6985 6.648 -4.30498e-07
-v -0.978 6.564 -4.82822e-07
-v -1.3145 6.4935 -5.47133e-07
-v -1.684 6.432 -6.18428e-07
-v -2.0625 6.375 -6.91702e-07
-v -2.426 6.318 -7.62065e-07
-v -2.7505 6.2565 -8.24279e-07
-v -3.012 6.186 -8.73573e-07
-v -3.1865 6.102 -9.04942e-07
-v -3.25 6 -9.13146e-07
-v -0.689503 6.648 -0.11461
-v -0.965403 6.564 -0.160471
-v -1.29757 6.4935 -0.215683
-v -1.66231 6.432 -0.276311
-v -2.03593 6.375 -0.338415
-v -2.39475 6.318 -0.398058
-v -2.71507 6.2565 -0.451302
-v -2.97321 6.186 -0.494209
-v -3.14546 6.102 -0.522841
-v -3.20814 6 -0.53326
-v -0.663407 6.648 -0.22285
-v -0.928865 6.564 -0.312021
-v -1.24846 6.4935 -0.419378
-v -1.5994 6.432 -0.537264
-v -1.95888 6.375 -0.65802
-v -2.30412 6.318 -0.773991
-v -2.61231 6.2565 -0.87752
-v -2.86068 6.186 -0.960949
-v -3.02641 6.102 -1.01662
-v -3.08672 6 -1.03688
-v -0.621553 6.648 -0.323378
-v -0.870264 6.564 -0.452775
-v -1.16969 6.4935 -0.608561
-v -1.49849 6.432 -0.779625
-v -1.83529 6.375 -0.954855
-v -2.15875 6.318 -1.12314
-v -2.4475 6.2565 -1.27337
-v -2.6802 6.186 -1.39444
-v -2.83548 6.102 -1.47522
-v -2.89198 6 -1.50462
-v -0.565282 6.648 -0.414853
-v -0.791476 6.564 -0.580854
-v -1.0638 6.4935 -0.780708
-v -1.36283 6.432 -1.00016
-v -1.66914 6.375 -1.22496
-v -1.96331 6.318 -1.44085
-v -2.22592 6.2565 -1.63358
-v -2.43755 6.186 -1.78889
-v -2.57877 6.102 -1.89253
-v -2.63016 6 -1.93024
-v -0.495935 6.648 -0.495935
-v -0.69438 6.564 -0.69438
-v -0.933295 6.4935 -0.933295
-v -1.19564 6.432 -1.19564
-v -1.46438 6.375 -1.46438
-v -1.72246 6.318 -1.72246
-v -1.95285 6.2565 -1.95286
-v -2.13852 6.186 -2.13852
-v -2.26241 6.102 -2.26242
-v -2.3075 6 -2.3075
-v -0.414853 6.648 -0.565282
-v -0.580854 6.564 -0.791476
-v -0.780708 6.4935 -1.0638
-v -1.00016 6.432 -1.36283
-v -1.22496 6.375 -1.66914
-v -1.44085 6.318 -1.96331
-v -1.63358 6.2565 -2.22592
-v -1.78889 6.186 -2.43755
-v -1.89253 6.102 -2.57877
-v -1.93024 6 -2.63016
-v -0.323378 6.648 -0.621554
-v -0.452775 6.564 -0.870264
-v -0.608561 6.4935 -1.1697
-v -0.779625 6.432 -1.49849
-v -0.954855 6.375 -1.8353
-v -1.12314 6.318 -2.15875
-v -1.27337 6.2565 -2.44751
-v -1.39444 6.186 -2.6802
-v -1.47522 6.102 -2.83548
-v -1.50462 6 -2.89198
-v -0.222849 6.648 -0.663408
-v -0.312021 6.564 -0.928866
-v -0.419378 6.4935 -1.24846
-v -0.537263 6.432 -1.5994
-v -0.65802 6.375 -1.95888
-v -0.773991 6.318 -2.30412
-v -0.877519 6.2565 -2.61232
-v -0.960948 6.186 -2.86068
-v -1.01662 6.102 -3.02641
-v -1.03688 6 -3.08672
-v -0.11461 6.648 -0.689504
-v -0.16047 6.564 -0.965404
-v -0.215683 6.4935 -1.29757
-v -0.27631 6.432 -1.66231
-v -0.338415 6.375 -2.03594
-v -0.398058 6.318 -2.39475
-v -0.451302 6.2565 -2.71507
-v -0.494208 6.186 -2.97321
-v -0.52284 6.102 -3.14546
-v -0.533259 6 -3.20814
-v 1.39931e-07 6.648 -0.6985
-v 1.95811e-07 6.564 -0.978
-v 2.63215e-07 6.4935 -1.3145
-v 3.37255e-07 6.432 -1.684
-v 4.13042e-07 6.375 -2.0625
-v 4.85801e-07 6.318 -2.426
-v 5.50877e-07 6.2565 -2.7505
-v 6.03264e-07 6.186 -3.012
-v 6.38189e-07 6.102 -3.1865
-v 6.50878e-07 6 -3.25
-v 0.11461 6.648 -0.689504
-v 0.16047 6.564 -0.965404
-v 0.215683 6.4935 -1.29757
-v 0.276311 6.432 -1.66231
-v 0.338415 6.375 -2.03594
-v 0.398058 6.318 -2.39475
-v 0.451302 6.2565 -2.71507
-v 0.494209 6.186 -2.97321
-v 0.522841 6.102 -3.14546
-v 0.53326 6 -3.20814
-v 0.222849 6.648 -0.663408
-v 0.312021 6.564 -0.928866
-v 0.419378 6.4935 -1.24846
-v 0.537263 6.432 -1.5994
-v 0.65802 6.375 -1.95888
-v 0.773991 6.318 -2.30412
-v 0.87752 6.2565 -2.61231
-v 0.960949 6.186 -2.86068
-v 1.01662 6.102 -3.02641
-v 1.03688 6 -3.08672
-v 0.323378 6.648 -0.621553
-v 0.452775 6.564 -0.870264
-v 0.608561 6.4935 -1.16969
-v 0.779625 6.432 -1.49849
-v 0.954855 6.375 -1.8353
-v 1.12314 6.318 -2.15875
-v 1.27337 6.2565 -2.4475
-v 1.39444 6.186 -2.6802
-v 1.47522 6.102 -2.83548
-v 1.50462 6 -2.89198
-v 0.414853 6.648 -0.565282
-v 0.580854 6.564 -0.791476
-v 0.780708 6.4935 -1.0638
-v 1.00016 6.432 -1.36283
-v 1.22496 6.375 -1.66914
-v 1.44085 6.318 -1.96331
-v 1.63358 6.2565 -2.22592
-v 1.78889 6.186 -2.43755
-v 1.89253 6.102 -2.57877
-v 1.93024 6 -2.63016
-v 0.495935 6.648 -0.495935
-v 0.69438 6.564 -0.69438
-v 0.933295 6.4935 -0.933295
-v 1.19564 6.432 -1.19564
-v 1.46438 6.375 -1.46438
-v 1.72246 6.318 -1.72246
-v 1.95285 6.2565 -1.95286
-v 2.13852 6.186 -2.13852
-v 2.26241 6.102 -2.26242
-v 2.3075 6 -2.3075
-v 0.565282 6.648 -0.414853
-v 0.791476 6.564 -0.580854
-v 1.0638 6.4935 -0.780708
-v 1.36283 6.432 -1.00016
-v 1.66914 6.375 -1.22496
-v 1.96331 6.318 -1.44085
-v 2.22592 6.2565 -1.63358
-v 2.43755 6.186 -1.78889
-v 2.57877 6.102 -1.89253
-v 2.63016 6 -1.93024
-v 0.621553 6.648 -0.323378
-v 0.870264 6.564 -0.452775
-v 1.16969 6.4935 -0.608561
-v 1.49849 6.432 -0.779625
-v 1.8353 6.375 -0.954855
-v 2.15875 6.318 -1.12314
-v 2.44751 6.2565 -1.27337
-v 2.6802 6.186 -1.39444
-v 2.83548 6.102 -1.47522
-v 2.89198 6 -1.50462
-v 0.663407 6.648 -0.22285
-v 0.928865 6.564 -0.312021
-v 1.24846 6.4935 -0.419378
-v 1.5994 6.432 -0.537264
-v 1.95888 6.375 -0.65802
-v 2.30412 6.318 -0.773991
-v 2.61232 6.2565 -0.87752
-v 2.86068 6.186 -0.960948
-v 3.02641 6.102 -1.01662
-v 3.08672 6 -1.03688
-v 0.689503 6.648 -0.11461
-v 0.965403 6.564 -0.16047
-v 1.29757 6.4935 -0.215683
-v 1.66231 6.432 -0.276311
-v 2.03593 6.375 -0.338415
-v 2.39475 6.318 -0.398058
-v 2.71507 6.2565 -0.451302
-v 2.97321 6.186 -0.494209
-v 3.14546 6.102 -0.522841
-v 3.20814 6 -0.53326
-# 3242 verticies
-vt 0.684148 0.5
-vt 0.681346 0.442629
-vt 0.679187 0.443206
-vt 0.681961 0.5
-vt 0.67834 0.443432
-vt 0.681102 0.5
-vt 0.67862 0.443358
-vt 0.681386 0.5
-vt 0.679843 0.443031
-vt 0.682625 0.5
-vt 0.681825 0.442501
-vt 0.684633 0.5
-vt 0.684383 0.441818
-vt 0.687224 0.5
-vt 0.687331 0.44103
-vt 0.690211 0.5
-vt 0.690487 0.440187
-vt 0.693408 0.5
-vt 0.693666 0.439338
-vt 0.696628 0.5
-vt 0.696683 0.438531
-vt 0.699685 0.5
-vt 0.673219 0.388447
-vt 0.671142 0.389569
-vt 0.670327 0.390009
-vt 0.670596 0.389864
-vt 0.671773 0.389228
-vt 0.67368 0.388198
-vt 0.676141 0.38687
-vt 0.678978 0.385338
-vt 0.682014 0.383699
-vt 0.685073 0.382047
-vt 0.687976 0.380479
-vt 0.660185 0.338126
-vt 0.658239 0.339753
-vt 0.657475 0.340392
-vt 0.657728 0.340181
-vt 0.65883 0.339259
-vt 0.660617 0.337765
-vt 0.662923 0.335836
-vt 0.665581 0.333614
-vt 0.668425 0.331235
-vt 0.671291 0.328838
-vt 0.674011 0.326563
-vt 0.642661 0.292336
-vt 0.640892 0.294423
-vt 0.640197 0.295243
-vt 0.640426 0.294972
-vt 0.641429 0.293789
-vt 0.643054 0.291872
-vt 0.645151 0.289399
-vt 0.647568 0.286547
-vt 0.650156 0.283495
-vt 0.652762 0.280421
-vt 0.655236 0.277502
-vt 0.621066 0.251748
-vt 0.619513 0.254244
-vt 0.618903 0.255224
-vt 0.619105 0.2549
-vt 0.619984 0.253486
-vt 0.62141 0.251194
-vt 0.62325 0.248237
-vt 0.625371 0.244828
-vt 0.62764 0.24118
-vt 0.629927 0.237505
-vt 0.632097 0.234016
-vt 0.595815 0.217035
-vt 0.594516 0.21988
-vt 0.594007 0.220996
-vt 0.594175 0.220628
-vt 0.594911 0.219016
-vt 0.596104 0.216403
-vt 0.597642 0.213033
-vt 0.599416 0.209147
-vt 0.601315 0.204989
-vt 0.603228 0.2008
-vt 0.605043 0.196823
-vt 0.567328 0.188867
-vt 0.566316 0.191995
-vt 0.565918 0.193223
-vt 0.56605 0.192817
-vt 0.566623 0.191045
-vt 0.567553 0.188173
-vt 0.568753 0.184467
-vt 0.570135 0.180194
-vt 0.571615 0.175622
-vt 0.573106 0.171016
-vt 0.574521 0.166643
-vt 0.536022 0.167916
-vt 0.535324 0.171255
-vt 0.535051 0.172565
-vt 0.535141 0.172132
-vt 0.535536 0.170241
-vt 0.536177 0.167175
-vt 0.537004 0.163219
-vt 0.537957 0.158659
-vt 0.538977 0.153779
-vt 0.540004 0.148863
-vt 0.540979 0.144196
-vt 0.502315 0.154853
-vt 0.501956 0.158323
-vt 0.501815 0.159685
-vt 0.501861 0.159235
-vt 0.502065 0.157269
-vt 0.502394 0.154083
-vt 0.502819 0.149972
-vt 0.503309 0.145232
-vt 0.503834 0.14016
-vt 0.504362 0.13505
-vt 0.504864 0.1302
-vt 0.466623 0.15035
-vt 0.466623 0.153865
-vt 0.466623 0.155245
-vt 0.466623 0.154789
-vt 0.466623 0.152797
-vt 0.466623 0.149569
-vt 0.466623 0.145405
-vt 0.466623 0.140603
-vt 0.466623 0.135465
-vt 0.466623 0.130288
-vt 0.466623 0.125375
-vt 0.427534 0.154853
-vt 0.428813 0.158323
-vt 0.429692 0.159685
-vt 0.430219 0.159235
-vt 0.430448 0.157269
-vt 0.430427 0.154083
-vt 0.43021 0.149972
-vt 0.429845 0.145232
-vt 0.429385 0.14016
-vt 0.428881 0.13505
-vt 0.428382 0.1302
-vt 0.391854 0.167916
-vt 0.394007 0.171255
-vt 0.395446 0.172565
-vt 0.396264 0.172132
-vt 0.39655 0.170241
-vt 0.396398 0.167175
-vt 0.395899 0.16322
-vt 0.395145 0.158659
-vt 0.394227 0.153779
-vt 0.393237 0.148863
-vt 0.392267 0.144196
-vt 0.359751 0.188867
-vt 0.362435 0.191995
-vt 0.364171 0.193223
-vt 0.365081 0.192817
-vt 0.365291 0.191045
-vt 0.364922 0.188173
-vt 0.364099 0.184467
-vt 0.362944 0.180194
-vt 0.361582 0.175622
-vt 0.360134 0.171016
-vt 0.358725 0.166643
-vt 0.33139 0.217035
-vt 0.334326 0.21988
-vt 0.336147 0.220996
-vt 0.336999 0.220628
-vt 0.337031 0.219016
-vt 0.336388 0.216403
-vt 0.335217 0.213033
-vt 0.333667 0.209147
-vt 0.331883 0.204989
-vt 0.330013 0.2008
-vt 0.328203 0.196823
-vt 0.306937 0.251748
-vt 0.309911 0.254244
-vt 0.311658 0.255224
-vt 0.312343 0.2549
-vt 0.312129 0.253486
-vt 0.311181 0.251194
-vt 0.309661 0.248237
-vt 0.307734 0.244828
-vt 0.305564 0.24118
-vt 0.303315 0.237505
-vt 0.301149 0.234016
-vt 0.286558 0.292336
-vt 0.289419 0.294424
-vt 0.290988 0.295243
-vt 0.291439 0.294972
-vt 0.290947 0.293789
-vt 0.289689 0.291872
-vt 0.287838 0.289399
-vt 0.285569 0.286547
-vt 0.283059 0.283495
-vt 0.280481 0.280421
-vt 0.278011 0.277503
-vt 0.270418 0.338126
-vt 0.273081 0.339753
-vt 0.274418 0.340392
-vt 0.274612 0.340181
-vt 0.273845 0.339259
-vt 0.272299 0.337765
-vt 0.270155 0.335837
-vt 0.267594 0.333614
-vt 0.2648 0.331235
-vt 0.261953 0.328838
-vt 0.259235 0.326564
-vt 0.258685 0.388448
-vt 0.261126 0.389569
-vt 0.262232 0.390009
-vt 0.26219 0.389864
-vt 0.261183 0.389229
-vt 0.259398 0.388199
-vt 0.257019 0.38687
-vt 0.254232 0.385338
-vt 0.251221 0.383699
-vt 0.248172 0.382047
-vt 0.24527 0.38048
-vt 0.251523 0.44263
-vt 0.253784 0.443206
-vt 0.254713 0.443433
-vt 0.254497 0.443358
-vt 0.253322 0.443031
-vt 0.251374 0.442501
-vt 0.24884 0.441818
-vt 0.245905 0.44103
-vt 0.242756 0.440187
-vt 0.23958 0.439338
-vt 0.236563 0.438532
-vt 0.249099 0.5
-vt 0.251286 0.5
-vt 0.252144 0.5
-vt 0.251861 0.5
-vt 0.250621 0.5
-vt 0.248613 0.5
-vt 0.246022 0.5
-vt 0.243035 0.5
-vt 0.239839 0.5
-vt 0.236618 0.5
-vt 0.233561 0.5
-vt 0.251901 0.557371
-vt 0.254059 0.556794
-vt 0.254907 0.556568
-vt 0.254627 0.556642
-vt 0.253404 0.556969
-vt 0.251421 0.557499
-vt 0.248864 0.558182
-vt 0.245915 0.55897
-vt 0.24276 0.559813
-vt 0.239581 0.560662
-vt 0.236563 0.561469
-vt 0.260027 0.611553
-vt 0.262104 0.610431
-vt 0.26292 0.609991
-vt 0.26265 0.610136
-vt 0.261473 0.610772
-vt 0.259566 0.611802
-vt 0.257105 0.61313
-vt 0.254268 0.614662
-vt 0.251232 0.616301
-vt 0.248174 0.617953
-vt 0.24527 0.619521
-vt 0.273061 0.661874
-vt 0.275007 0.660247
-vt 0.275771 0.659608
-vt 0.275519 0.659819
-vt 0.274416 0.660741
-vt 0.272629 0.662235
-vt 0.270324 0.664164
-vt 0.267666 0.666386
-vt 0.264821 0.668765
-vt 0.261956 0.671162
-vt 0.259236 0.673437
-vt 0.290585 0.707664
-vt 0.292355 0.705577
-vt 0.29305 0.704757
-vt 0.29282 0.705028
-vt 0.291817 0.706211
-vt 0.290192 0.708128
-vt 0.288095 0.710601
-vt 0.285678 0.713453
-vt 0.283091 0.716505
-vt 0.280485 0.719579
-vt 0.278011 0.722498
-vt 0.312181 0.748252
-vt 0.313734 0.745756
-vt 0.314343 0.744776
-vt 0.314142 0.7451
-vt 0.313262 0.746514
-vt 0.311836 0.748806
-vt 0.309997 0.751763
-vt 0.307876 0.755172
-vt 0.305606 0.75882
-vt 0.30332 0.762495
-vt 0.301149 0.765984
-vt 0.337431 0.782965
-vt 0.33873 0.78012
-vt 0.33924 0.779004
-vt 0.339071 0.779372
-vt 0.338336 0.780984
-vt 0.337143 0.783597
-vt 0.335604 0.786967
-vt 0.33383 0.790853
-vt 0.331931 0.795011
-vt 0.330019 0.7992
-vt 0.328203 0.803177
-vt 0.365918 0.811133
-vt 0.366931 0.808005
-vt 0.367328 0.806777
-vt 0.367197 0.807183
-vt 0.366623 0.808955
-vt 0.365693 0.811827
-vt 0.364494 0.815533
-vt 0.363111 0.819806
-vt 0.361631 0.824378
-vt 0.36014 0.828984
-vt 0.358725 0.833357
-vt 0.397224 0.832084
-vt 0.397922 0.828745
-vt 0.398196 0.827435
-vt 0.398105 0.827868
-vt 0.39771 0.829759
-vt 0.397069 0.832825
-vt 0.396243 0.836781
-vt 0.39529 0.841341
-vt 0.39427 0.846221
-vt 0.393243 0.851137
-vt 0.392267 0.855804
-vt 0.430932 0.845147
-vt 0.431291 0.841677
-vt 0.431432 0.840315
-vt 0.431385 0.840765
-vt 0.431182 0.842731
-vt 0.430852 0.845917
-vt 0.430427 0.850028
-vt 0.429937 0.854768
-vt 0.429412 0.85984
-vt 0.428884 0.86495
-vt 0.428383 0.8698
-vt 0.466623 0.84965
-vt 0.466623 0.846135
-vt 0.466623 0.844755
-vt 0.466623 0.845211
-vt 0.466623 0.847203
-vt 0.466623 0.850431
-vt 0.466623 0.854595
-vt 0.466623 0.859397
-vt 0.466623 0.864535
-vt 0.466623 0.869712
-vt 0.466623 0.874625
-vt 0.502315 0.845147
-vt 0.501956 0.841677
-vt 0.501815 0.840315
-vt 0.501862 0.840765
-vt 0.502065 0.842731
-vt 0.502394 0.845917
-vt 0.502819 0.850028
-vt 0.50331 0.854768
-vt 0.503834 0.85984
-vt 0.504363 0.86495
-vt 0.504864 0.8698
-vt 0.536022 0.832084
-vt 0.535325 0.828745
-vt 0.535051 0.827435
-vt 0.535141 0.827868
-vt 0.535536 0.829759
-vt 0.536177 0.832825
-vt 0.537004 0.83678
-vt 0.537957 0.841341
-vt 0.538977 0.846221
-vt 0.540004 0.851137
-vt 0.540979 0.855804
-vt 0.567328 0.811133
-vt 0.566316 0.808005
-vt 0.565919 0.806777
-vt 0.56605 0.807183
-vt 0.566623 0.808955
-vt 0.567553 0.811827
-vt 0.568753 0.815533
-vt 0.570136 0.819806
-vt 0.571616 0.824378
-vt 0.573106 0.828984
-vt 0.574522 0.833357
-vt 0.595815 0.782965
-vt 0.594517 0.78012
-vt 0.594007 0.779004
-vt 0.594175 0.779372
-vt 0.594911 0.780984
-vt 0.596104 0.783597
-vt 0.597643 0.786967
-vt 0.599417 0.790853
-vt 0.601315 0.795011
-vt 0.603228 0.7992
-vt 0.605043 0.803177
-vt 0.621066 0.748252
-vt 0.619513 0.745756
-vt 0.618903 0.744776
-vt 0.619105 0.7451
-vt 0.619985 0.746514
-vt 0.62141 0.748806
-vt 0.62325 0.751763
-vt 0.625371 0.755172
-vt 0.62764 0.75882
-vt 0.629927 0.762495
-vt 0.632097 0.765984
-vt 0.642661 0.707664
-vt 0.640892 0.705576
-vt 0.640197 0.704757
-vt 0.640426 0.705028
-vt 0.641429 0.706211
-vt 0.643054 0.708128
-vt 0.645151 0.710601
-vt 0.647568 0.713453
-vt 0.650156 0.716505
-vt 0.652762 0.719579
-vt 0.655236 0.722497
-vt 0.660185 0.661874
-vt 0.658239 0.660247
-vt 0.657475 0.659608
-vt 0.657728 0.659819
-vt 0.65883 0.660741
-vt 0.660617 0.662235
-vt 0.662923 0.664163
-vt 0.665581 0.666386
-vt 0.668425 0.668765
-vt 0.671291 0.671162
-vt 0.674011 0.673436
-vt 0.673219 0.611552
-vt 0.671142 0.610431
-vt 0.670327 0.609991
-vt 0.670596 0.610136
-vt 0.671773 0.610771
-vt 0.67368 0.611801
-vt 0.676141 0.61313
-vt 0.678978 0.614662
-vt 0.682014 0.616301
-vt 0.685073 0.617953
-vt 0.687976 0.61952
-vt 0.681346 0.55737
-vt 0.679187 0.556794
-vt 0.67834 0.556567
-vt 0.67862 0.556642
-vt 0.679843 0.556969
-vt 0.681825 0.557499
-vt 0.684383 0.558182
-vt 0.687331 0.55897
-vt 0.690487 0.559813
-vt 0.693666 0.560662
-vt 0.696683 0.561468
-vt 0.708148 0.435468
-vt 0.711299 0.5
-vt 0.719383 0.432467
-vt 0.722681 0.5
-vt 0.730157 0.429588
-vt 0.733596 0.5
-vt 0.740241 0.426893
-vt 0.743811 0.5
-vt 0.749405 0.424445
-vt 0.753095 0.5
-vt 0.757419 0.422304
-vt 0.761213 0.5
-vt 0.764053 0.420531
-vt 0.767933 0.5
-vt 0.769075 0.419189
-vt 0.773022 0.5
-vt 0.772258 0.418339
-vt 0.776246 0.5
-vt 0.77337 0.418042
-vt 0.777372 0.5
-vt 0.699007 0.374523
-vt 0.709816 0.368687
-vt 0.720183 0.363089
-vt 0.729886 0.35785
-vt 0.738703 0.353089
-vt 0.746413 0.348926
-vt 0.752796 0.34548
-vt 0.757628 0.34287
-vt 0.76069 0.341217
-vt 0.76176 0.340639
-vt 0.684346 0.31792
-vt 0.694473 0.309451
-vt 0.704186 0.301328
-vt 0.713276 0.293726
-vt 0.721537 0.286817
-vt 0.728761 0.280776
-vt 0.734741 0.275775
-vt 0.739269 0.271989
-vt 0.742138 0.269589
-vt 0.74314 0.268751
-vt 0.664635 0.266415
-vt 0.673845 0.255549
-vt 0.682679 0.245129
-vt 0.690946 0.235376
-vt 0.698459 0.226513
-vt 0.705029 0.218763
-vt 0.710467 0.212348
-vt 0.714586 0.20749
-vt 0.717195 0.204412
-vt 0.718106 0.203337
-vt 0.640343 0.220761
-vt 0.648424 0.207772
-vt 0.656174 0.195315
-vt 0.663427 0.183656
-vt 0.670018 0.173061
-vt 0.675782 0.163796
-vt 0.680553 0.156127
-vt 0.684166 0.15032
-vt 0.686455 0.14664
-vt 0.687255 0.145355
-vt 0.611941 0.181715
-vt 0.618701 0.16691
-vt 0.625183 0.152711
-vt 0.631251 0.139422
-vt 0.636765 0.127345
-vt 0.641586 0.116784
-vt 0.645577 0.108043
-vt 0.6486 0.101423
-vt 0.650514 0.0972295
-vt 0.651183 0.0957642
-vt 0.579898 0.150031
-vt 0.585167 0.133752
-vt 0.590221 0.11814
-vt 0.59495 0.103528
-vt 0.599248 0.0902491
-vt 0.603007 0.0786371
-vt 0.606118 0.0690253
-vt 0.608473 0.0617471
-vt 0.609966 0.0571357
-vt 0.610488 0.0555244
-vt 0.544685 0.126465
-vt 0.548316 0.10909
-vt 0.551798 0.0924262
-vt 0.555057 0.0768301
-vt 0.558019 0.0626574
-vt 0.560609 0.0502635
-vt 0.562753 0.0400045
-vt 0.564377 0.032236
-vt 0.565405 0.0273141
-vt 0.565765 0.0255944
-vt 0.50677 0.111771
-vt 0.508637 0.0937129
-vt 0.510428 0.0763938
-vt 0.512104 0.0601842
-vt 0.513627 0.0454539
-vt 0.51496 0.0325725
-vt 0.516062 0.02191
-vt 0.516897 0.013836
-vt 0.517426 0.0087204
-vt 0.517611 0.00693303
-vt 0.466623 0.106706
-vt 0.466623 0.0884116
-vt 0.466623 0.0708666
-vt 0.466623 0.0544456
-vt 0.466623 0.039523
-vt 0.466623 0.0264735
-vt 0.466623 0.0156718
-vt 0.466623 0.00749254
-vt 0.466623 0.00231022
-vt 0.466623 0.000499487
-vt 0.426477 0.111771
-vt 0.424609 0.0937129
-vt 0.422818 0.0763939
-vt 0.421142 0.0601843
-vt 0.419619 0.045454
-vt 0.418287 0.0325726
-vt 0.417184 0.02191
-vt 0.416349 0.013836
-vt 0.41582 0.00872052
-vt 0.415635 0.00693309
-vt 0.388562 0.126465
-vt 0.384931 0.10909
-vt 0.381448 0.0924263
-vt 0.378189 0.0768303
-vt 0.375227 0.0626574
-vt 0.372637 0.0502635
-vt 0.370493 0.0400045
-vt 0.36887 0.0322361
-vt 0.367841 0.0273142
-vt 0.367482 0.0255945
-vt 0.353348 0.150031
-vt 0.348079 0.133752
-vt 0.343026 0.11814
-vt 0.338296 0.103528
-vt 0.333998 0.0902492
-vt 0.33024 0.0786372
-vt 0.327129 0.0690255
-vt 0.324773 0.0617472
-vt 0.32328 0.0571358
-vt 0.322759 0.0555245
-vt 0.321305 0.181715
-vt 0.314546 0.16691
-vt 0.308063 0.152711
-vt 0.301996 0.139422
-vt 0.296482 0.127345
-vt 0.29166 0.116785
-vt 0.287669 0.108043
-vt 0.284647 0.101424
-vt 0.282732 0.0972297
-vt 0.282063 0.0957643
-vt 0.292903 0.220761
-vt 0.284823 0.207772
-vt 0.277073 0.195315
-vt 0.26982 0.183656
-vt 0.263228 0.173061
-vt 0.257464 0.163796
-vt 0.252693 0.156127
-vt 0.24908 0.15032
-vt 0.246791 0.14664
-vt 0.245991 0.145355
-vt 0.268612 0.266415
-vt 0.259401 0.25555
-vt 0.250568 0.245129
-vt 0.2423 0.235376
-vt 0.234787 0.226514
-vt 0.228217 0.218763
-vt 0.222779 0.212348
-vt 0.218661 0.20749
-vt 0.216052 0.204412
-vt 0.21514 0.203337
-vt 0.248901 0.317921
-vt 0.238773 0.309451
-vt 0.229061 0.301329
-vt 0.21997 0.293726
-vt 0.211709 0.286818
-vt 0.204485 0.280776
-vt 0.198505 0.275776
-vt 0.193977 0.271989
-vt 0.191109 0.26959
-vt 0.190106 0.268751
-vt 0.23424 0.374524
-vt 0.22343 0.368687
-vt 0.213064 0.363089
-vt 0.203361 0.35785
-vt 0.194544 0.35309
-vt 0.186833 0.348926
-vt 0.180451 0.34548
-vt 0.175618 0.342871
-vt 0.172556 0.341217
-vt 0.171486 0.34064
-vt 0.225099 0.435468
-vt 0.213864 0.432467
-vt 0.203089 0.429588
-vt 0.193005 0.426894
-vt 0.183841 0.424445
-vt 0.175827 0.422304
-vt 0.169194 0.420532
-vt 0.164171 0.41919
-vt 0.160988 0.418339
-vt 0.159877 0.418042
-vt 0.221947 0.5
-vt 0.210566 0.5
-vt 0.199651 0.5
-vt 0.189435 0.5
-vt 0.180151 0.5
-vt 0.172033 0.5
-vt 0.165313 0.5
-vt 0.160225 0.5
-vt 0.157001 0.5
-vt 0.155874 0.5
-vt 0.225099 0.564532
-vt 0.213864 0.567533
-vt 0.20309 0.570412
-vt 0.193005 0.573107
-vt 0.183841 0.575555
-vt 0.175827 0.577696
-vt 0.169194 0.579469
-vt 0.164171 0.580811
-vt 0.160989 0.581661
-vt 0.159877 0.581958
-vt 0.23424 0.625477
-vt 0.22343 0.631313
-vt 0.213064 0.636911
-vt 0.203361 0.64215
-vt 0.194544 0.646911
-vt 0.186833 0.651074
-vt 0.180451 0.65452
-vt 0.175618 0.65713
-vt 0.172556 0.658783
-vt 0.171486 0.659361
-vt 0.248901 0.68208
-vt 0.238773 0.690549
-vt 0.229061 0.698672
-vt 0.21997 0.706274
-vt 0.211709 0.713183
-vt 0.204485 0.719224
-vt 0.198505 0.724225
-vt 0.193977 0.728011
-vt 0.191109 0.730411
-vt 0.190106 0.731249
-vt 0.268612 0.733585
-vt 0.259401 0.744451
-vt 0.250568 0.754871
-vt 0.2423 0.764624
-vt 0.234787 0.773487
-vt 0.228217 0.781237
-vt 0.222779 0.787652
-vt 0.218661 0.79251
-vt 0.216052 0.795588
-vt 0.21514 0.796663
-vt 0.292903 0.779239
-vt 0.284823 0.792228
-vt 0.277073 0.804685
-vt 0.26982 0.816344
-vt 0.263228 0.826939
-vt 0.257464 0.836204
-vt 0.252693 0.843873
-vt 0.24908 0.84968
-vt 0.246791 0.85336
-vt 0.245991 0.854645
-vt 0.321305 0.818285
-vt 0.314546 0.83309
-vt 0.308063 0.847289
-vt 0.301996 0.860578
-vt 0.296482 0.872655
-vt 0.29166 0.883216
-vt 0.287669 0.891957
-vt 0.284647 0.898577
-vt 0.282732 0.902771
-vt 0.282063 0.904236
-vt 0.353348 0.849969
-vt 0.348079 0.866248
-vt 0.343026 0.88186
-vt 0.338296 0.896472
-vt 0.333998 0.909751
-vt 0.33024 0.921363
-vt 0.327129 0.930975
-vt 0.324773 0.938253
-vt 0.32328 0.942864
-vt 0.322759 0.944476
-vt 0.388562 0.873535
-vt 0.384931 0.89091
-vt 0.381448 0.907574
-vt 0.378189 0.92317
-vt 0.375227 0.937343
-vt 0.372637 0.949737
-vt 0.370493 0.959996
-vt 0.36887 0.967764
-vt 0.367841 0.972686
-vt 0.367482 0.974406
-vt 0.426477 0.888229
-vt 0.424609 0.906287
-vt 0.422819 0.923606
-vt 0.421142 0.939816
-vt 0.419619 0.954546
-vt 0.418287 0.967427
-vt 0.417184 0.97809
-vt 0.416349 0.986164
-vt 0.41582 0.99128
-vt 0.415636 0.993067
-vt 0.466623 0.893294
-vt 0.466623 0.911588
-vt 0.466623 0.929133
-vt 0.466623 0.945554
-vt 0.466623 0.960477
-vt 0.466623 0.973526
-vt 0.466623 0.984328
-vt 0.466623 0.992507
-vt 0.466623 0.99769
-vt 0.466623 0.999501
-vt 0.50677 0.888229
-vt 0.508637 0.906287
-vt 0.510428 0.923606
-vt 0.512104 0.939816
-vt 0.513628 0.954546
-vt 0.51496 0.967427
-vt 0.516062 0.97809
-vt 0.516897 0.986164
-vt 0.517426 0.991279
-vt 0.517611 0.993067
-vt 0.544685 0.873535
-vt 0.548316 0.89091
-vt 0.551798 0.907574
-vt 0.555057 0.92317
-vt 0.558019 0.937343
-vt 0.560609 0.949736
-vt 0.562753 0.959996
-vt 0.564377 0.967764
-vt 0.565405 0.972686
-vt 0.565765 0.974406
-vt 0.579899 0.849969
-vt 0.585168 0.866248
-vt 0.590221 0.88186
-vt 0.59495 0.896472
-vt 0.599248 0.909751
-vt 0.603007 0.921363
-vt 0.606118 0.930975
-vt 0.608474 0.938253
-vt 0.609966 0.942864
-vt 0.610488 0.944475
-vt 0.611941 0.818285
-vt 0.618701 0.83309
-vt 0.625184 0.847289
-vt 0.631251 0.860578
-vt 0.636765 0.872655
-vt 0.641586 0.883215
-vt 0.645577 0.891957
-vt 0.6486 0.898576
-vt 0.650514 0.90277
-vt 0.651183 0.904236
-vt 0.640343 0.779239
-vt 0.648424 0.792228
-vt 0.656174 0.804685
-vt 0.663427 0.816344
-vt 0.670018 0.826939
-vt 0.675782 0.836204
-vt 0.680553 0.843873
-vt 0.684166 0.84968
-vt 0.686455 0.85336
-vt 0.687255 0.854645
-vt 0.664635 0.733585
-vt 0.673845 0.74445
-vt 0.682679 0.754871
-vt 0.690946 0.764624
-vt 0.698459 0.773486
-vt 0.705029 0.781237
-vt 0.710468 0.787652
-vt 0.714586 0.79251
-vt 0.717195 0.795588
-vt 0.718106 0.796663
-vt 0.684346 0.682079
-vt 0.694473 0.690549
-vt 0.704186 0.698671
-vt 0.713277 0.706274
-vt 0.721537 0.713182
-vt 0.728761 0.719224
-vt 0.734741 0.724224
-vt 0.739269 0.728011
-vt 0.742138 0.73041
-vt 0.74314 0.731249
-vt 0.699007 0.625476
-vt 0.709816 0.631313
-vt 0.720183 0.636911
-vt 0.729886 0.64215
-vt 0.738703 0.64691
-vt 0.746413 0.651074
-vt 0.752796 0.65452
-vt 0.757629 0.657129
-vt 0.760691 0.658783
-vt 0.76176 0.65936
-vt 0.708148 0.564532
-vt 0.719383 0.567533
-vt 0.730157 0.570412
-vt 0.740241 0.573106
-vt 0.749405 0.575555
-vt 0.757419 0.577696
-vt 0.764053 0.579468
-vt 0.769076 0.58081
-vt 0.772258 0.581661
-vt 0.77337 0.581958
-vt 0.771223 0.418616
-vt 0.775197 0.5
-vt 0.765395 0.420173
-vt 0.769293 0.5
-vt 0.756806 0.422468
-vt 0.760592 0.5
-vt 0.746376 0.425254
-vt 0.750026 0.5
-vt 0.735027 0.428287
-vt 0.738529 0.5
-vt 0.723677 0.431319
-vt 0.727031 0.5
-vt 0.713248 0.434106
-vt 0.716466 0.5
-vt 0.704659 0.4364
-vt 0.707765 0.5
-vt 0.69883 0.437958
-vt 0.70186 0.5
-vt 0.759694 0.341755
-vt 0.754087 0.344783
-vt 0.745823 0.349245
-vt 0.735788 0.354663
-vt 0.724868 0.360559
-vt 0.713948 0.366456
-vt 0.703913 0.371874
-vt 0.69565 0.376336
-vt 0.690042 0.379364
-vt 0.741205 0.27037
-vt 0.735951 0.274764
-vt 0.728208 0.281239
-vt 0.718807 0.289101
-vt 0.708576 0.297657
-vt 0.698344 0.306213
-vt 0.688943 0.314076
-vt 0.6812 0.320551
-vt 0.675947 0.324945
-vt 0.716346 0.205413
-vt 0.711568 0.21105
-vt 0.704526 0.219356
-vt 0.695976 0.229443
-vt 0.686671 0.24042
-vt 0.677366 0.251396
-vt 0.668816 0.261483
-vt 0.661774 0.269789
-vt 0.656996 0.275426
-vt 0.685711 0.147837
-vt 0.681519 0.154575
-vt 0.675341 0.164505
-vt 0.667839 0.176563
-vt 0.659676 0.189685
-vt 0.651513 0.202807
-vt 0.644011 0.214865
-vt 0.637834 0.224795
-vt 0.633642 0.231533
-vt 0.649891 0.0985938
-vt 0.646385 0.106274
-vt 0.641217 0.117593
-vt 0.634942 0.131337
-vt 0.628113 0.146294
-vt 0.621285 0.16125
-vt 0.61501 0.174994
-vt 0.609842 0.186313
-vt 0.606335 0.193994
-vt 0.609481 0.0586358
-vt 0.606747 0.0670808
-vt 0.602719 0.0795261
-vt 0.597828 0.0946382
-vt 0.592505 0.111084
-vt 0.587182 0.127529
-vt 0.58229 0.142642
-vt 0.578262 0.155087
-vt 0.575529 0.163532
-vt 0.565071 0.0289152
-vt 0.563187 0.0379289
-vt 0.560411 0.0512123
-vt 0.55704 0.067342
-vt 0.553372 0.0848951
-vt 0.549704 0.102448
-vt 0.546333 0.118578
-vt 0.543557 0.131861
-vt 0.541673 0.140875
-vt 0.517254 0.0103845
-vt 0.516285 0.0197527
-vt 0.514858 0.0335586
-vt 0.513124 0.0503229
-vt 0.511237 0.0685664
-vt 0.509351 0.0868099
-vt 0.507617 0.103574
-vt 0.50619 0.11738
-vt 0.505221 0.126748
-vt 0.466623 0.00399601
-vt 0.466623 0.0134865
-vt 0.466623 0.0274726
-vt 0.466623 0.0444555
-vt 0.466623 0.0629371
-vt 0.466623 0.0814186
-vt 0.466623 0.0984016
-vt 0.466623 0.112388
-vt 0.466623 0.121878
-vt 0.415992 0.0103846
-vt 0.416961 0.0197529
-vt 0.418389 0.0335587
-vt 0.420122 0.050323
-vt 0.422009 0.0685665
-vt 0.423895 0.08681
-vt 0.425629 0.103574
-vt 0.427057 0.11738
-vt 0.428025 0.126748
-vt 0.368176 0.0289153
-vt 0.370059 0.0379291
-vt 0.372835 0.0512123
-vt 0.376206 0.0673421
-vt 0.379874 0.0848951
-vt 0.383543 0.102448
-vt 0.386914 0.118578
-vt 0.389689 0.131861
-vt 0.391573 0.140875
-vt 0.323766 0.0586359
-vt 0.326499 0.0670809
-vt 0.330527 0.0795262
-vt 0.335419 0.0946384
-vt 0.340742 0.111084
-vt 0.346065 0.12753
-vt 0.350956 0.142642
-vt 0.354984 0.155087
-vt 0.357718 0.163532
-vt 0.283355 0.098594
-vt 0.286862 0.106274
-vt 0.292029 0.117593
-vt 0.298304 0.131337
-vt 0.305133 0.146294
-vt 0.311962 0.16125
-vt 0.318237 0.174995
-vt 0.323405 0.186313
-vt 0.326911 0.193994
-vt 0.247536 0.147837
-vt 0.251728 0.154576
-vt 0.257905 0.164506
-vt 0.265407 0.176564
-vt 0.27357 0.189685
-vt 0.281734 0.202807
-vt 0.289235 0.214865
-vt 0.295413 0.224795
-vt 0.299605 0.231534
-vt 0.2169 0.205413
-vt 0.221679 0.21105
-vt 0.22872 0.219357
-vt 0.237271 0.229443
-vt 0.246576 0.24042
-vt 0.25588 0.251396
-vt 0.264431 0.261483
-vt 0.271472 0.269789
-vt 0.276251 0.275426
-vt 0.192042 0.27037
-vt 0.197296 0.274764
-vt 0.205038 0.281239
-vt 0.21444 0.289101
-vt 0.224671 0.297657
-vt 0.234902 0.306214
-vt 0.244304 0.314076
-vt 0.252046 0.320551
-vt 0.2573 0.324945
-vt 0.173552 0.341755
-vt 0.17916 0.344783
-vt 0.187423 0.349245
-vt 0.197458 0.354663
-vt 0.208378 0.36056
-vt 0.219298 0.366456
-vt 0.229333 0.371874
-vt 0.237597 0.376336
-vt 0.243204 0.379364
-vt 0.162024 0.418616
-vt 0.167852 0.420173
-vt 0.176441 0.422468
-vt 0.18687 0.425254
-vt 0.19822 0.428287
-vt 0.20957 0.431319
-vt 0.219999 0.434106
-vt 0.228588 0.436401
-vt 0.234416 0.437958
-vt 0.158049 0.5
-vt 0.163954 0.5
-vt 0.172655 0.5
-vt 0.18322 0.5
-vt 0.194718 0.5
-vt 0.206215 0.5
-vt 0.216781 0.5
-vt 0.225482 0.5
-vt 0.231386 0.5
-vt 0.162024 0.581384
-vt 0.167852 0.579827
-vt 0.176441 0.577532
-vt 0.18687 0.574746
-vt 0.19822 0.571713
-vt 0.20957 0.568681
-vt 0.219999 0.565894
-vt 0.228588 0.5636
-vt 0.234416 0.562042
-vt 0.173552 0.658245
-vt 0.17916 0.655217
-vt 0.187424 0.650755
-vt 0.197458 0.645337
-vt 0.208378 0.639441
-vt 0.219298 0.633544
-vt 0.229333 0.628126
-vt 0.237597 0.623664
-vt 0.243204 0.620636
-vt 0.192042 0.72963
-vt 0.197296 0.725236
-vt 0.205038 0.718761
-vt 0.21444 0.710899
-vt 0.224671 0.702343
-vt 0.234902 0.693787
-vt 0.244304 0.685924
-vt 0.252046 0.679449
-vt 0.2573 0.675055
-vt 0.216901 0.794587
-vt 0.221679 0.78895
-vt 0.22872 0.780644
-vt 0.237271 0.770557
-vt 0.246576 0.75958
-vt 0.25588 0.748604
-vt 0.264431 0.738517
-vt 0.271472 0.730211
-vt 0.276251 0.724574
-vt 0.247536 0.852163
-vt 0.251728 0.845425
-vt 0.257906 0.835495
-vt 0.265407 0.823437
-vt 0.27357 0.810315
-vt 0.281734 0.797193
-vt 0.289235 0.785135
-vt 0.295413 0.775205
-vt 0.299605 0.768467
-vt 0.283355 0.901406
-vt 0.286862 0.893726
-vt 0.292029 0.882407
-vt 0.298304 0.868663
-vt 0.305133 0.853706
-vt 0.311962 0.83875
-vt 0.318237 0.825006
-vt 0.323405 0.813687
-vt 0.326911 0.806006
-vt 0.323766 0.941364
-vt 0.326499 0.932919
-vt 0.330528 0.920474
-vt 0.335419 0.905362
-vt 0.340742 0.888916
-vt 0.346065 0.872471
-vt 0.350956 0.857358
-vt 0.354985 0.844913
-vt 0.357718 0.836468
-vt 0.368176 0.971085
-vt 0.37006 0.962071
-vt 0.372836 0.948788
-vt 0.376206 0.932658
-vt 0.379875 0.915105
-vt 0.383543 0.897552
-vt 0.386914 0.881422
-vt 0.38969 0.868139
-vt 0.391573 0.859125
-vt 0.415993 0.989615
-vt 0.416961 0.980247
-vt 0.418389 0.966441
-vt 0.420123 0.949677
-vt 0.422009 0.931434
-vt 0.423896 0.91319
-vt 0.425629 0.896426
-vt 0.427057 0.88262
-vt 0.428026 0.873252
-vt 0.466623 0.996004
-vt 0.466623 0.986513
-vt 0.466623 0.972527
-vt 0.466623 0.955544
-vt 0.466623 0.937063
-vt 0.466623 0.918581
-vt 0.466623 0.901598
-vt 0.466623 0.887612
-vt 0.466623 0.878122
-vt 0.517254 0.989615
-vt 0.516285 0.980247
-vt 0.514858 0.966441
-vt 0.513124 0.949677
-vt 0.511238 0.931433
-vt 0.509351 0.91319
-vt 0.507617 0.896426
-vt 0.50619 0.88262
-vt 0.505221 0.873252
-vt 0.565071 0.971085
-vt 0.563187 0.962071
-vt 0.560411 0.948788
-vt 0.55704 0.932658
-vt 0.553372 0.915105
-vt 0.549704 0.897552
-vt 0.546333 0.881422
-vt 0.543557 0.868139
-vt 0.541673 0.859125
-vt 0.609481 0.941364
-vt 0.606747 0.932919
-vt 0.602719 0.920474
-vt 0.597828 0.905362
-vt 0.592505 0.888916
-vt 0.587182 0.87247
-vt 0.58229 0.857358
-vt 0.578262 0.844913
-vt 0.575529 0.836468
-vt 0.649891 0.901406
-vt 0.646385 0.893726
-vt 0.641217 0.882407
-vt 0.634942 0.868663
-vt 0.628113 0.853706
-vt 0.621285 0.83875
-vt 0.61501 0.825005
-vt 0.609842 0.813687
-vt 0.606335 0.806006
-vt 0.685711 0.852163
-vt 0.681519 0.845424
-vt 0.675341 0.835494
-vt 0.66784 0.823436
-vt 0.659676 0.810315
-vt 0.651513 0.797193
-vt 0.644011 0.785135
-vt 0.637834 0.775205
-vt 0.633642 0.768466
-vt 0.716346 0.794587
-vt 0.711568 0.78895
-vt 0.704526 0.780643
-vt 0.695976 0.770557
-vt 0.686671 0.75958
-vt 0.677366 0.748604
-vt 0.668816 0.738517
-vt 0.661774 0.730211
-vt 0.656996 0.724574
-vt 0.741205 0.72963
-vt 0.735951 0.725236
-vt 0.728208 0.718761
-vt 0.718807 0.710899
-vt 0.708576 0.702343
-vt 0.698345 0.693786
-vt 0.688943 0.685924
-vt 0.681201 0.679449
-vt 0.675947 0.675055
-vt 0.759694 0.658245
-vt 0.754087 0.655217
-vt 0.745823 0.650755
-vt 0.735788 0.645337
-vt 0.724868 0.63944
-vt 0.713948 0.633544
-vt 0.703914 0.628126
-vt 0.69565 0.623664
-vt 0.690042 0.620636
-vt 0.771223 0.581384
-vt 0.765395 0.579827
-vt 0.756806 0.577532
-vt 0.746376 0.574746
-vt 0.735027 0.571713
-vt 0.723677 0.568681
-vt 0.713248 0.565894
-vt 0.704659 0.563599
-vt 0.69883 0.562042
-vt 0.696143 0.438676
-vt 0.699137 0.5
-vt 0.693738 0.439318
-vt 0.696702 0.5
-vt 0.688298 0.440772
-vt 0.69119 0.5
-vt 0.678647 0.443351
-vt 0.681413 0.5
-vt 0.663612 0.447367
-vt 0.666182 0.5
-vt 0.642021 0.453136
-vt 0.64431 0.5
-vt 0.6127 0.460971
-vt 0.614606 0.5
-vt 0.574475 0.471184
-vt 0.575883 0.5
-vt 0.526174 0.484089
-vt 0.526951 0.5
-vt 0.466623 0.5
-vt 0.687456 0.38076
-vt 0.685143 0.382009
-vt 0.679908 0.384836
-vt 0.670622 0.38985
-vt 0.656157 0.39766
-vt 0.635383 0.408877
-vt 0.607171 0.42411
-vt 0.570393 0.443969
-vt 0.52392 0.469062
-vt 0.673524 0.326971
-vt 0.671356 0.328783
-vt 0.666452 0.332885
-vt 0.657752 0.340161
-vt 0.644199 0.351495
-vt 0.624736 0.367772
-vt 0.598304 0.389876
-vt 0.563847 0.418693
-vt 0.520306 0.455106
-vt 0.654792 0.278025
-vt 0.652821 0.28035
-vt 0.648361 0.285612
-vt 0.640448 0.294946
-vt 0.628123 0.309486
-vt 0.610421 0.330368
-vt 0.586383 0.358725
-vt 0.555045 0.395693
-vt 0.515445 0.442407
-vt 0.631708 0.234641
-vt 0.629979 0.237421
-vt 0.626066 0.243711
-vt 0.619124 0.254869
-vt 0.60831 0.272251
-vt 0.592781 0.297214
-vt 0.571691 0.331113
-vt 0.544197 0.375307
-vt 0.509456 0.43115
-vt 0.604718 0.197536
-vt 0.603271 0.200704
-vt 0.599998 0.207874
-vt 0.594191 0.220592
-vt 0.585145 0.240405
-vt 0.572155 0.268858
-vt 0.554513 0.307498
-vt 0.531515 0.357871
-vt 0.502453 0.421523
-vt 0.574268 0.167427
-vt 0.57314 0.17091
-vt 0.570589 0.178794
-vt 0.566062 0.192778
-vt 0.559011 0.214563
-vt 0.548885 0.245849
-vt 0.535133 0.288335
-vt 0.517206 0.343722
-vt 0.494553 0.413711
-vt 0.540805 0.145032
-vt 0.540027 0.14875
-vt 0.538269 0.157165
-vt 0.53515 0.172091
-vt 0.530291 0.195343
-vt 0.523312 0.228735
-vt 0.513836 0.274082
-vt 0.501481 0.333199
-vt 0.48587 0.4079
-vt 0.504774 0.131069
-vt 0.504374 0.134933
-vt 0.50347 0.143679
-vt 0.501866 0.159192
-vt 0.499367 0.183359
-vt 0.495778 0.218064
-vt 0.490904 0.265195
-vt 0.48455 0.326638
-vt 0.476522 0.404277
-vt 0.466623 0.126255
-vt 0.466623 0.13017
-vt 0.466623 0.13903
-vt 0.466623 0.154745
-vt 0.466623 0.179227
-vt 0.466623 0.214386
-vt 0.466623 0.262132
-vt 0.466623 0.324376
-vt 0.466623 0.403028
-vt 0.428472 0.131069
-vt 0.428872 0.134933
-vt 0.429776 0.143679
-vt 0.43138 0.159192
-vt 0.43388 0.183359
-vt 0.437468 0.218064
-vt 0.442342 0.265195
-vt 0.448696 0.326638
-vt 0.456725 0.404277
-vt 0.392442 0.145032
-vt 0.393219 0.14875
-vt 0.394977 0.157165
-vt 0.398097 0.172091
-vt 0.402956 0.195343
-vt 0.409934 0.228735
-vt 0.419411 0.274082
-vt 0.431765 0.333199
-vt 0.447376 0.4079
-vt 0.358978 0.167427
-vt 0.360106 0.17091
-vt 0.362658 0.178794
-vt 0.367184 0.192779
-vt 0.374235 0.214563
-vt 0.384362 0.245849
-vt 0.398113 0.288335
-vt 0.416041 0.343722
-vt 0.438694 0.413711
-vt 0.328528 0.197536
-vt 0.329975 0.200704
-vt 0.333248 0.207874
-vt 0.339055 0.220592
-vt 0.348101 0.240405
-vt 0.361092 0.268858
-vt 0.378733 0.307498
-vt 0.401732 0.357871
-vt 0.430793 0.421523
-vt 0.301538 0.234641
-vt 0.303267 0.237421
-vt 0.307181 0.243711
-vt 0.314122 0.254869
-vt 0.324936 0.272251
-vt 0.340466 0.297214
-vt 0.361556 0.331114
-vt 0.389049 0.375307
-vt 0.42379 0.43115
-vt 0.278454 0.278025
-vt 0.280425 0.280351
-vt 0.284886 0.285613
-vt 0.292798 0.294946
-vt 0.305124 0.309487
-vt 0.322825 0.330368
-vt 0.346864 0.358725
-vt 0.378202 0.395693
-vt 0.417801 0.442407
-vt 0.259723 0.326971
-vt 0.26189 0.328784
-vt 0.266795 0.332885
-vt 0.275495 0.340161
-vt 0.289047 0.351495
-vt 0.308511 0.367772
-vt 0.334942 0.389877
-vt 0.3694 0.418693
-vt 0.412941 0.455106
-vt 0.245791 0.380761
-vt 0.248104 0.38201
-vt 0.253339 0.384836
-vt 0.262624 0.38985
-vt 0.27709 0.397661
-vt 0.297864 0.408878
-vt 0.326075 0.424111
-vt 0.362853 0.443969
-vt 0.409326 0.469062
-vt 0.237104 0.438676
-vt 0.239508 0.439318
-vt 0.244949 0.440772
-vt 0.2546 0.443351
-vt 0.269634 0.447368
-vt 0.291225 0.453137
-vt 0.320547 0.460971
-vt 0.358771 0.471184
-vt 0.407072 0.484089
-vt 0.234109 0.5
-vt 0.236545 0.5
-vt 0.242056 0.5
-vt 0.251833 0.5
-vt 0.267064 0.5
-vt 0.288937 0.5
-vt 0.318641 0.5
-vt 0.357364 0.5
-vt 0.406295 0.5
-vt 0.237104 0.561324
-vt 0.239508 0.560682
-vt 0.244949 0.559228
-vt 0.2546 0.556649
-vt 0.269634 0.552633
-vt 0.291225 0.546864
-vt 0.320547 0.539029
-vt 0.358771 0.528816
-vt 0.407072 0.515911
-vt 0.245791 0.61924
-vt 0.248104 0.617991
-vt 0.253339 0.615164
-vt 0.262625 0.61015
-vt 0.27709 0.60234
-vt 0.297864 0.591122
-vt 0.326075 0.57589
-vt 0.362853 0.556031
-vt 0.409326 0.530938
-vt 0.259723 0.673029
-vt 0.26189 0.671217
-vt 0.266795 0.667115
-vt 0.275495 0.659839
-vt 0.289048 0.648505
-vt 0.308511 0.632228
-vt 0.334942 0.610124
-vt 0.3694 0.581307
-vt 0.412941 0.544894
-vt 0.278454 0.721975
-vt 0.280425 0.71965
-vt 0.284886 0.714388
-vt 0.292798 0.705054
-vt 0.305124 0.690514
-vt 0.322825 0.669632
-vt 0.346864 0.641275
-vt 0.378202 0.604307
-vt 0.417801 0.557593
-vt 0.301538 0.765359
-vt 0.303267 0.762579
-vt 0.307181 0.756289
-vt 0.314123 0.745131
-vt 0.324936 0.727749
-vt 0.340466 0.702786
-vt 0.361556 0.668887
-vt 0.389049 0.624693
-vt 0.42379 0.56885
-vt 0.328528 0.802464
-vt 0.329975 0.799296
-vt 0.333249 0.792126
-vt 0.339055 0.779408
-vt 0.348101 0.759595
-vt 0.361092 0.731142
-vt 0.378733 0.692502
-vt 0.401732 0.642129
-vt 0.430793 0.578477
-vt 0.358979 0.832573
-vt 0.360106 0.82909
-vt 0.362658 0.821206
-vt 0.367184 0.807222
-vt 0.374235 0.785437
-vt 0.384362 0.754151
-vt 0.398113 0.711665
-vt 0.416041 0.656278
-vt 0.438694 0.586289
-vt 0.392442 0.854968
-vt 0.393219 0.85125
-vt 0.394978 0.842835
-vt 0.398097 0.827909
-vt 0.402956 0.804657
-vt 0.409934 0.771265
-vt 0.419411 0.725918
-vt 0.431765 0.666801
-vt 0.447376 0.5921
-vt 0.428472 0.868931
-vt 0.428872 0.865067
-vt 0.429776 0.856321
-vt 0.431381 0.840808
-vt 0.43388 0.816641
-vt 0.437469 0.781936
-vt 0.442342 0.734805
-vt 0.448696 0.673362
-vt 0.456725 0.595723
-vt 0.466623 0.873745
-vt 0.466623 0.86983
-vt 0.466623 0.86097
-vt 0.466623 0.845255
-vt 0.466623 0.820773
-vt 0.466623 0.785614
-vt 0.466623 0.737868
-vt 0.466623 0.675624
-vt 0.466623 0.596972
-vt 0.504774 0.868931
-vt 0.504375 0.865067
-vt 0.50347 0.856321
-vt 0.501866 0.840808
-vt 0.499367 0.816641
-vt 0.495778 0.781936
-vt 0.490904 0.734805
-vt 0.484551 0.673362
-vt 0.476522 0.595723
-vt 0.540805 0.854968
-vt 0.540028 0.85125
-vt 0.538269 0.842835
-vt 0.53515 0.827909
-vt 0.530291 0.804657
-vt 0.523312 0.771265
-vt 0.513836 0.725918
-vt 0.501481 0.666801
-vt 0.48587 0.5921
-vt 0.574268 0.832573
-vt 0.573141 0.82909
-vt 0.570589 0.821206
-vt 0.566062 0.807221
-vt 0.559011 0.785437
-vt 0.548885 0.754151
-vt 0.535133 0.711665
-vt 0.517206 0.656278
-vt 0.494553 0.586289
-vt 0.604718 0.802464
-vt 0.603272 0.799296
-vt 0.599998 0.792126
-vt 0.594191 0.779408
-vt 0.585145 0.759595
-vt 0.572155 0.731142
-vt 0.554513 0.692502
-vt 0.531515 0.642129
-vt 0.502453 0.578477
-vt 0.631708 0.765359
-vt 0.629979 0.762579
-vt 0.626066 0.756289
-vt 0.619124 0.745131
-vt 0.60831 0.727749
-vt 0.592781 0.702786
-vt 0.571691 0.668886
-vt 0.544197 0.624693
-vt 0.509456 0.56885
-vt 0.654792 0.721975
-vt 0.652821 0.719649
-vt 0.648361 0.714387
-vt 0.640448 0.705054
-vt 0.628123 0.690513
-vt 0.610421 0.669632
-vt 0.586383 0.641275
-vt 0.555045 0.604307
-vt 0.515446 0.557593
-vt 0.673524 0.673029
-vt 0.671356 0.671216
-vt 0.666452 0.667115
-vt 0.657752 0.659839
-vt 0.644199 0.648505
-vt 0.624736 0.632228
-vt 0.598304 0.610123
-vt 0.563847 0.581307
-vt 0.520306 0.544894
-vt 0.687456 0.619239
-vt 0.685143 0.61799
-vt 0.679908 0.615164
-vt 0.670622 0.61015
-vt 0.656157 0.602339
-vt 0.635383 0.591122
-vt 0.607171 0.575889
-vt 0.570393 0.556031
-vt 0.52392 0.530938
-vt 0.696143 0.561324
-vt 0.693739 0.560682
-vt 0.688298 0.559228
-vt 0.678647 0.556649
-vt 0.663612 0.552632
-vt 0.642021 0.546863
-vt 0.6127 0.539029
-vt 0.574475 0.528816
-vt 0.526174 0.515911
-vt 0.218024 0.5
-vt 0.218459 0.47977
-vt 0.186878 0.47977
-vt 0.186809 0.5
-vt 0.158237 0.47977
-vt 0.158484 0.5
-vt 0.132626 0.47977
-vt 0.133143 0.5
-vt 0.110136 0.47977
-vt 0.110878 0.5
-vt 0.0908576 0.47977
-vt 0.0917821 0.5
-vt 0.074881 0.47977
-vt 0.0759494 0.5
-vt 0.0622969 0.47977
-vt 0.0634728 0.5
-vt 0.0531961 0.47977
-vt 0.0544456 0.5
-vt 0.0476692 0.47977
-vt 0.0489608 0.5
-vt 0.0458067 0.47977
-vt 0.0471119 0.5
-vt 0.21964 0.464036
-vt 0.187066 0.464036
-vt 0.157567 0.464036
-vt 0.131225 0.464036
-vt 0.108124 0.464036
-vt 0.0883483 0.464036
-vt 0.0719807 0.464036
-vt 0.059105 0.464036
-vt 0.0498047 0.464036
-vt 0.0441632 0.464036
-vt 0.0422642 0.464036
-vt 0.22138 0.452797
-vt 0.187343 0.452797
-vt 0.156578 0.452797
-vt 0.129159 0.452797
-vt 0.105159 0.452797
-vt 0.0846504 0.452797
-vt 0.0677069 0.452797
-vt 0.0544013 0.452797
-vt 0.0448068 0.452797
-vt 0.0389966 0.452797
-vt 0.0370436 0.452797
-vt 0.223493 0.446054
-vt 0.187679 0.446054
-vt 0.155378 0.446054
-vt 0.126651 0.446054
-vt 0.101558 0.446054
-vt 0.0801601 0.446054
-vt 0.0625171 0.446054
-vt 0.0486896 0.446054
-vt 0.0387381 0.446054
-vt 0.0327229 0.446054
-vt 0.0307043 0.446054
-vt 0.225793 0.443806
-vt 0.188044 0.443806
-vt 0.154072 0.443806
-vt 0.123921 0.443806
-vt 0.0976397 0.443806
-vt 0.0752735 0.443806
-vt 0.0568694 0.443806
-vt 0.0424739 0.443806
-vt 0.0321338 0.443806
-vt 0.0258955 0.443806
-vt 0.0238057 0.443806
-vt 0.228092 0.446054
-vt 0.18841 0.446054
-vt 0.152766 0.446054
-vt 0.121192 0.446054
-vt 0.0937213 0.446054
-vt 0.070387 0.446054
-vt 0.0512217 0.446054
-vt 0.0362583 0.446054
-vt 0.0255295 0.446054
-vt 0.0190681 0.446054
-vt 0.0169071 0.446054
-vt 0.230205 0.452797
-vt 0.188746 0.452797
-vt 0.151565 0.452797
-vt 0.118684 0.452797
-vt 0.0901206 0.452797
-vt 0.0658967 0.452797
-vt 0.046032 0.452797
-vt 0.0305466 0.452797
-vt 0.0194607 0.452797
-vt 0.0127943 0.452797
-vt 0.0105678 0.452797
-vt 0.231945 0.464036
-vt 0.189023 0.464036
-vt 0.150577 0.464036
-vt 0.116618 0.464036
-vt 0.0871553 0.464036
-vt 0.0621988 0.464036
-vt 0.041758 0.464036
-vt 0.0258428 0.464036
-vt 0.0144628 0.464036
-vt 0.0076277 0.464036
-vt 0.00534719 0.464036
-vt 0.233126 0.47977
-vt 0.18921 0.47977
-vt 0.149906 0.47977
-vt 0.115216 0.47977
-vt 0.0851431 0.47977
-vt 0.0596895 0.47977
-vt 0.0388579 0.47977
-vt 0.022651 0.47977
-vt 0.0110714 0.47977
-vt 0.00412178 0.47977
-vt 0.00180465 0.47977
-vt 0.233561 0.5
-vt 0.18928 0.5
-vt 0.149659 0.5
-vt 0.1147 0.5
-vt 0.0844018 0.5
-vt 0.058765 0.5
-vt 0.0377894 0.5
-vt 0.0214751 0.5
-vt 0.00982198 0.5
-vt 0.00283012 0.5
-vt 0.000499517 0.5
-vt 0.233126 0.52023
-vt 0.18921 0.52023
-vt 0.149906 0.52023
-vt 0.115216 0.52023
-vt 0.0851431 0.52023
-vt 0.0596895 0.52023
-vt 0.0388579 0.52023
-vt 0.022651 0.52023
-vt 0.0110715 0.52023
-vt 0.00412178 0.52023
-vt 0.00180465 0.52023
-vt 0.231945 0.535964
-vt 0.189023 0.535964
-vt 0.150577 0.535964
-vt 0.116618 0.535964
-vt 0.0871553 0.535964
-vt 0.0621988 0.535964
-vt 0.041758 0.535964
-vt 0.0258428 0.535964
-vt 0.0144628 0.535964
-vt 0.00762773 0.535964
-vt 0.00534722 0.535964
-vt 0.230205 0.547203
-vt 0.188746 0.547203
-vt 0.151565 0.547203
-vt 0.118684 0.547203
-vt 0.0901206 0.547203
-vt 0.0658967 0.547203
-vt 0.046032 0.547203
-vt 0.0305466 0.547203
-vt 0.0194607 0.547203
-vt 0.0127944 0.547203
-vt 0.0105678 0.547203
-vt 0.228092 0.553946
-vt 0.18841 0.553946
-vt 0.152766 0.553946
-vt 0.121192 0.553946
-vt 0.0937213 0.553946
-vt 0.070387 0.553946
-vt 0.0512217 0.553946
-vt 0.0362583 0.553946
-vt 0.0255295 0.553946
-vt 0.0190682 0.553946
-vt 0.0169071 0.553946
-vt 0.225793 0.556194
-vt 0.188044 0.556194
-vt 0.154072 0.556194
-vt 0.123921 0.556194
-vt 0.0976397 0.556194
-vt 0.0752735 0.556194
-vt 0.0568694 0.556194
-vt 0.042474 0.556194
-vt 0.0321338 0.556194
-vt 0.0258955 0.556194
-vt 0.0238057 0.556194
-vt 0.223493 0.553946
-vt 0.187679 0.553946
-vt 0.155378 0.553946
-vt 0.126651 0.553946
-vt 0.101558 0.553946
-vt 0.0801601 0.553946
-vt 0.0625171 0.553946
-vt 0.0486896 0.553946
-vt 0.0387381 0.553946
-vt 0.0327229 0.553946
-vt 0.0307043 0.553946
-vt 0.22138 0.547203
-vt 0.187343 0.547203
-vt 0.156578 0.547203
-vt 0.129159 0.547203
-vt 0.105159 0.547203
-vt 0.0846504 0.547203
-vt 0.0677069 0.547203
-vt 0.0544013 0.547203
-vt 0.0448069 0.547203
-vt 0.0389966 0.547203
-vt 0.0370436 0.547203
-vt 0.21964 0.535964
-vt 0.187066 0.535964
-vt 0.157567 0.535964
-vt 0.131225 0.535964
-vt 0.108124 0.535964
-vt 0.0883483 0.535964
-vt 0.0719808 0.535964
-vt 0.0591051 0.535964
-vt 0.0498047 0.535964
-vt 0.0441632 0.535964
-vt 0.0422642 0.535964
-vt 0.218459 0.52023
-vt 0.186878 0.52023
-vt 0.158237 0.52023
-vt 0.132626 0.52023
-vt 0.110136 0.52023
-vt 0.0908577 0.52023
-vt 0.074881 0.52023
-vt 0.0622969 0.52023
-vt 0.0531961 0.52023
-vt 0.0476692 0.52023
-vt 0.0458068 0.52023
-vt 0.0467739 0.47977
-vt 0.0480597 0.5
-vt 0.0497366 0.47977
-vt 0.0509652 0.5
-vt 0.0547868 0.47977
-vt 0.0559216 0.5
-vt 0.0620164 0.47977
-vt 0.0630223 0.5
-vt 0.0715174 0.47977
-vt 0.0723602 0.5
-vt 0.0833816 0.47977
-vt 0.0840289 0.5
-vt 0.0977009 0.47977
-vt 0.0981213 0.5
-vt 0.114567 0.47977
-vt 0.114731 0.5
-vt 0.134073 0.47977
-vt 0.133951 0.5
-vt 0.156309 0.47977
-vt 0.155874 0.5
-vt 0.0432839 0.464036
-vt 0.0464019 0.464036
-vt 0.0517066 0.464036
-vt 0.0592863 0.464036
-vt 0.0692295 0.464036
-vt 0.0816244 0.464036
-vt 0.0965596 0.464036
-vt 0.114123 0.464036
-vt 0.134404 0.464036
-vt 0.15749 0.464036
-vt 0.0381407 0.452797
-vt 0.0414876 0.452797
-vt 0.0471672 0.452797
-vt 0.055263 0.452797
-vt 0.0658579 0.452797
-vt 0.079035 0.452797
-vt 0.0948777 0.452797
-vt 0.113469 0.452797
-vt 0.134892 0.452797
-vt 0.15923 0.452797
-vt 0.0318955 0.446054
-vt 0.0355202 0.446054
-vt 0.0416553 0.446054
-vt 0.0503775 0.446054
-vt 0.0617637 0.446054
-vt 0.0758908 0.446054
-vt 0.0928354 0.446054
-vt 0.112675 0.446054
-vt 0.135485 0.446054
-vt 0.161343 0.446054
-vt 0.0250992 0.443806
-vt 0.0290263 0.443806
-vt 0.0356569 0.443806
-vt 0.045061 0.443806
-vt 0.0573083 0.443806
-vt 0.072469 0.443806
-vt 0.0906129 0.443806
-vt 0.11181 0.443806
-vt 0.13613 0.443806
-vt 0.163643 0.443806
-vt 0.0183029 0.446054
-vt 0.0225323 0.446054
-vt 0.0296585 0.446054
-vt 0.0397444 0.446054
-vt 0.052853 0.446054
-vt 0.0690473 0.446054
-vt 0.0883904 0.446054
-vt 0.110945 0.446054
-vt 0.136775 0.446054
-vt 0.165942 0.446054
-vt 0.0120576 0.452797
-vt 0.016565 0.452797
-vt 0.0241465 0.452797
-vt 0.0348589 0.452797
-vt 0.0487589 0.452797
-vt 0.065903 0.452797
-vt 0.0863481 0.452797
-vt 0.110151 0.452797
-vt 0.137368 0.452797
-vt 0.168055 0.452797
-vt 0.0069145 0.464036
-vt 0.0116507 0.464036
-vt 0.0196072 0.464036
-vt 0.0308356 0.464036
-vt 0.0453872 0.464036
-vt 0.0633136 0.464036
-vt 0.0846662 0.464036
-vt 0.109496 0.464036
-vt 0.137856 0.464036
-vt 0.169796 0.464036
-vt 0.0034245 0.47977
-vt 0.00831598 0.47977
-vt 0.016527 0.47977
-vt 0.0281055 0.47977
-vt 0.0430993 0.47977
-vt 0.0615565 0.47977
-vt 0.0835249 0.47977
-vt 0.109052 0.47977
-vt 0.138187 0.47977
-vt 0.170976 0.47977
-vt 0.0021387 0.5
-vt 0.00708741 0.5
-vt 0.0153922 0.5
-vt 0.0270996 0.5
-vt 0.0422564 0.5
-vt 0.0609091 0.5
-vt 0.0831044 0.5
-vt 0.108889 0.5
-vt 0.138309 0.5
-vt 0.171412 0.5
-vt 0.0034245 0.52023
-vt 0.00831598 0.52023
-vt 0.016527 0.52023
-vt 0.0281055 0.52023
-vt 0.0430993 0.52023
-vt 0.0615565 0.52023
-vt 0.0835249 0.52023
-vt 0.109052 0.52023
-vt 0.138187 0.52023
-vt 0.170977 0.52023
-vt 0.00691453 0.535964
-vt 0.0116507 0.535964
-vt 0.0196072 0.535964
-vt 0.0308356 0.535964
-vt 0.0453872 0.535964
-vt 0.0633136 0.535964
-vt 0.0846662 0.535964
-vt 0.109496 0.535964
-vt 0.137856 0.535964
-vt 0.169796 0.535964
-vt 0.0120576 0.547203
-vt 0.016565 0.547203
-vt 0.0241465 0.547203
-vt 0.0348589 0.547203
-vt 0.0487589 0.547203
-vt 0.065903 0.547203
-vt 0.0863481 0.547203
-vt 0.110151 0.547203
-vt 0.137368 0.547203
-vt 0.168055 0.547203
-vt 0.0183029 0.553946
-vt 0.0225324 0.553946
-vt 0.0296585 0.553946
-vt 0.0397444 0.553946
-vt 0.052853 0.553946
-vt 0.0690473 0.553946
-vt 0.0883904 0.553946
-vt 0.110945 0.553946
-vt 0.136775 0.553946
-vt 0.165942 0.553946
-vt 0.0250992 0.556194
-vt 0.0290263 0.556194
-vt 0.0356569 0.556194
-vt 0.045061 0.556194
-vt 0.0573083 0.556194
-vt 0.072469 0.556194
-vt 0.0906129 0.556194
-vt 0.11181 0.556194
-vt 0.13613 0.556194
-vt 0.163643 0.556194
-vt 0.0318955 0.553946
-vt 0.0355202 0.553946
-vt 0.0416553 0.553946
-vt 0.0503775 0.553946
-vt 0.0617637 0.553946
-vt 0.0758908 0.553946
-vt 0.0928354 0.553946
-vt 0.112675 0.553946
-vt 0.135485 0.553946
-vt 0.161343 0.553946
-vt 0.0381407 0.547203
-vt 0.0414876 0.547203
-vt 0.0471673 0.547203
-vt 0.055263 0.547203
-vt 0.0658579 0.547203
-vt 0.079035 0.547203
-vt 0.0948777 0.547203
-vt 0.113469 0.547203
-vt 0.134892 0.547203
-vt 0.15923 0.547203
-vt 0.0432839 0.535964
-vt 0.0464019 0.535964
-vt 0.0517066 0.535964
-vt 0.0592863 0.535964
-vt 0.0692295 0.535964
-vt 0.0816244 0.535964
-vt 0.0965596 0.535964
-vt 0.114123 0.535964
-vt 0.134404 0.535964
-vt 0.15749 0.535964
-vt 0.0467739 0.52023
-vt 0.0497366 0.52023
-vt 0.0547868 0.52023
-vt 0.0620164 0.52023
-vt 0.0715174 0.52023
-vt 0.0833816 0.52023
-vt 0.0977009 0.52023
-vt 0.114567 0.52023
-vt 0.134073 0.52023
-vt 0.156309 0.52023
-vt 0.73076 0.5
-vt 0.73076 0.455494
-vt 0.767956 0.456269
-vt 0.767413 0.5
-vt 0.795548 0.45837
-vt 0.79465 0.5
-vt 0.815355 0.461466
-vt 0.814243 0.5
-vt 0.829194 0.465226
-vt 0.827962 0.5
-vt 0.838885 0.469318
-vt 0.83758 0.5
-vt 0.846245 0.47341
-vt 0.844867 0.5
-vt 0.853093 0.47717
-vt 0.851595 0.5
-vt 0.861247 0.480266
-vt 0.859535 0.5
-vt 0.872525 0.482368
-vt 0.870457 0.5
-vt 0.888745 0.483142
-vt 0.886135 0.5
-vt 0.73076 0.420879
-vt 0.76943 0.422255
-vt 0.797985 0.425991
-vt 0.818373 0.431496
-vt 0.832539 0.43818
-vt 0.842428 0.445454
-vt 0.849986 0.452729
-vt 0.85716 0.459413
-vt 0.865895 0.464918
-vt 0.878136 0.468654
-vt 0.89583 0.47003
-vt 0.73076 0.396154
-vt 0.771601 0.39796
-vt 0.801577 0.402863
-vt 0.822821 0.410088
-vt 0.837467 0.418861
-vt 0.847648 0.428409
-vt 0.855499 0.437956
-vt 0.863153 0.44673
-vt 0.872744 0.453955
-vt 0.886405 0.458858
-vt 0.906271 0.460664
-vt 0.73076 0.381319
-vt 0.774238 0.383383
-vt 0.805938 0.388986
-vt 0.828222 0.397243
-vt 0.843451 0.40727
-vt 0.853988 0.418182
-vt 0.862193 0.429093
-vt 0.870431 0.43912
-vt 0.881061 0.447377
-vt 0.896447 0.45298
-vt 0.91895 0.455045
-vt 0.73076 0.376374
-vt 0.777108 0.378524
-vt 0.810685 0.384361
-vt 0.8341 0.392962
-vt 0.849963 0.403406
-vt 0.860886 0.414773
-vt 0.869478 0.426139
-vt 0.87835 0.436583
-vt 0.890112 0.445185
-vt 0.907374 0.451021
-vt 0.932747 0.453172
-vt 0.779978 0.383383
-vt 0.815431 0.388986
-vt 0.839977 0.397243
-vt 0.856476 0.40727
-vt 0.867785 0.418182
-vt 0.876763 0.429093
-vt 0.88627 0.43912
-vt 0.899163 0.447377
-vt 0.918302 0.45298
-vt 0.946544 0.455045
-vt 0.782615 0.39796
-vt 0.819792 0.402863
-vt 0.845378 0.410088
-vt 0.86246 0.418861
-vt 0.874124 0.428409
-vt 0.883458 0.437956
-vt 0.893547 0.44673
-vt 0.90748 0.453955
-vt 0.928343 0.458858
-vt 0.959223 0.460664
-vt 0.784787 0.422255
-vt 0.823384 0.425991
-vt 0.849826 0.431496
-vt 0.867388 0.43818
-vt 0.879345 0.445454
-vt 0.888971 0.452729
-vt 0.899541 0.459413
-vt 0.91433 0.464918
-vt 0.936613 0.468654
-vt 0.969664 0.47003
-vt 0.786261 0.456269
-vt 0.825821 0.45837
-vt 0.852845 0.461466
-vt 0.870732 0.465226
-vt 0.882887 0.469318
-vt 0.892712 0.47341
-vt 0.903608 0.47717
-vt 0.918978 0.480266
-vt 0.942224 0.482368
-vt 0.976749 0.483142
-vt 0.73076 0.5
-vt 0.786804 0.5
-vt 0.826719 0.5
-vt 0.853957 0.5
-vt 0.871964 0.5
-vt 0.884192 0.5
-vt 0.89409 0.5
-vt 0.905106 0.5
-vt 0.92069 0.5
-vt 0.944291 0.5
-vt 0.979359 0.5
-vt 0.73076 0.544505
-vt 0.786261 0.543731
-vt 0.825821 0.54163
-vt 0.852845 0.538534
-vt 0.870732 0.534773
-vt 0.882887 0.530682
-vt 0.892712 0.52659
-vt 0.903608 0.52283
-vt 0.918978 0.519733
-vt 0.942224 0.517632
-vt 0.976749 0.516858
-vt 0.73076 0.579121
-vt 0.784787 0.577745
-vt 0.823384 0.574009
-vt 0.849826 0.568504
-vt 0.867388 0.56182
-vt 0.879345 0.554545
-vt 0.888971 0.547271
-vt 0.899541 0.540586
-vt 0.91433 0.535082
-vt 0.936613 0.531346
-vt 0.969664 0.52997
-vt 0.73076 0.603846
-vt 0.782615 0.60204
-vt 0.819792 0.597137
-vt 0.845378 0.589912
-vt 0.86246 0.581138
-vt 0.874124 0.571591
-vt 0.883458 0.562043
-vt 0.893547 0.55327
-vt 0.90748 0.546045
-vt 0.928343 0.541142
-vt 0.959223 0.539335
-vt 0.73076 0.618681
-vt 0.779978 0.616617
-vt 0.815431 0.611014
-vt 0.839977 0.602756
-vt 0.856476 0.59273
-vt 0.867785 0.581818
-vt 0.876763 0.570907
-vt 0.88627 0.56088
-vt 0.899163 0.552622
-vt 0.918302 0.547019
-vt 0.946544 0.544955
-vt 0.73076 0.623626
-vt 0.777108 0.621476
-vt 0.810685 0.615639
-vt 0.8341 0.607038
-vt 0.849963 0.596593
-vt 0.860886 0.585227
-vt 0.869478 0.573861
-vt 0.87835 0.563416
-vt 0.890112 0.554815
-vt 0.907374 0.548978
-vt 0.932747 0.546828
-vt 0.774238 0.616617
-vt 0.805938 0.611014
-vt 0.828222 0.602756
-vt 0.843451 0.59273
-vt 0.853988 0.581818
-vt 0.862194 0.570907
-vt 0.870431 0.56088
-vt 0.881061 0.552622
-vt 0.896447 0.547019
-vt 0.91895 0.544955
-vt 0.771601 0.60204
-vt 0.801577 0.597137
-vt 0.822821 0.589912
-vt 0.837467 0.581138
-vt 0.847648 0.571591
-vt 0.855499 0.562043
-vt 0.863153 0.55327
-vt 0.872744 0.546045
-vt 0.886405 0.541142
-vt 0.906271 0.539335
-vt 0.76943 0.577745
-vt 0.797985 0.574009
-vt 0.818373 0.568504
-vt 0.832539 0.56182
-vt 0.842428 0.554545
-vt 0.849986 0.547271
-vt 0.85716 0.540586
-vt 0.865895 0.535082
-vt 0.878136 0.531346
-vt 0.89583 0.52997
-vt 0.73076 0.544505
-vt 0.767956 0.543731
-vt 0.795548 0.54163
-vt 0.815355 0.538534
-vt 0.829194 0.534773
-vt 0.838885 0.530682
-vt 0.846245 0.52659
-vt 0.853093 0.52283
-vt 0.861247 0.519733
-vt 0.872525 0.517632
-vt 0.888745 0.516858
-vt 0.8935 0.48333
-vt 0.890765 0.5
-vt 0.898 0.483843
-vt 0.895208 0.5
-vt 0.902065 0.484598
-vt 0.899279 0.5
-vt 0.905518 0.485515
-vt 0.902791 0.5
-vt 0.90818 0.486513
-vt 0.905556 0.5
-vt 0.909875 0.487511
-vt 0.90739 0.5
-vt 0.910423 0.488428
-vt 0.908105 0.5
-vt 0.909648 0.489184
-vt 0.907514 0.5
-vt 0.90737 0.489696
-vt 0.905432 0.5
-vt 0.903412 0.489885
-vt 0.901672 0.5
-vt 0.900926 0.470365
-vt 0.905576 0.471277
-vt 0.909625 0.472619
-vt 0.912919 0.47425
-vt 0.915302 0.476024
-vt 0.91662 0.477798
-vt 0.916717 0.479428
-vt 0.915438 0.480771
-vt 0.91263 0.481682
-vt 0.908136 0.482018
-vt 0.911869 0.461105
-vt 0.916741 0.462301
-vt 0.920767 0.464063
-vt 0.923827 0.466203
-vt 0.925798 0.468531
-vt 0.92656 0.47086
-vt 0.925992 0.473
-vt 0.923972 0.474762
-vt 0.920381 0.475958
-vt 0.915096 0.476398
-vt 0.925156 0.455548
-vt 0.930299 0.456915
-vt 0.934297 0.458929
-vt 0.937072 0.461374
-vt 0.938542 0.464036
-vt 0.93863 0.466697
-vt 0.937254 0.469143
-vt 0.934335 0.471157
-vt 0.929793 0.472523
-vt 0.923549 0.473027
-vt 0.939616 0.453696
-vt 0.945053 0.45512
-vt 0.949021 0.457218
-vt 0.951485 0.459765
-vt 0.952412 0.462537
-vt 0.951765 0.46531
-vt 0.94951 0.467857
-vt 0.945612 0.469955
-vt 0.940036 0.471378
-vt 0.932747 0.471903
-vt 0.954077 0.455548
-vt 0.959807 0.456915
-vt 0.963744 0.458929
-vt 0.965899 0.461374
-vt 0.966281 0.464036
-vt 0.9649 0.466697
-vt 0.961766 0.469143
-vt 0.956889 0.471157
-vt 0.950279 0.472523
-vt 0.941945 0.473027
-vt 0.967364 0.461105
-vt 0.973364 0.462301
-vt 0.977274 0.464063
-vt 0.979144 0.466203
-vt 0.979025 0.468531
-vt 0.97697 0.47086
-vt 0.973028 0.473
-vt 0.967252 0.474762
-vt 0.959691 0.475958
-vt 0.950397 0.476398
-vt 0.978307 0.470365
-vt 0.984529 0.471277
-vt 0.988416 0.472619
-vt 0.990051 0.47425
-vt 0.989521 0.476024
-vt 0.98691 0.477798
-vt 0.982303 0.479428
-vt 0.975785 0.480771
-vt 0.967442 0.481682
-vt 0.957358 0.482018
-vt 0.985732 0.48333
-vt 0.992105 0.483843
-vt 0.995976 0.484598
-vt 0.997453 0.485515
-vt 0.996643 0.486513
-vt 0.993655 0.487511
-vt 0.988597 0.488428
-vt 0.981576 0.489184
-vt 0.972702 0.489696
-vt 0.962082 0.489885
-vt 0.988468 0.5
-vt 0.994897 0.5
-vt 0.998762 0.5
-vt 1.00018 0.5
-vt 0.999267 0.5
-vt 0.99614 0.5
-vt 0.990915 0.5
-vt 0.98371 0.5
-vt 0.97464 0.5
-vt 0.963822 0.5
-vt 0.985732 0.516669
-vt 0.992105 0.516157
-vt 0.995976 0.515401
-vt 0.997453 0.514484
-vt 0.996643 0.513486
-vt 0.993655 0.512488
-vt 0.988597 0.511571
-vt 0.981576 0.510816
-vt 0.972702 0.510303
-vt 0.962082 0.510115
-vt 0.978307 0.529634
-vt 0.984529 0.528723
-vt 0.988416 0.52738
-vt 0.990051 0.52575
-vt 0.989521 0.523976
-vt 0.98691 0.522202
-vt 0.982303 0.520571
-vt 0.975785 0.519229
-vt 0.967442 0.518317
-vt 0.957358 0.517982
-vt 0.967364 0.538895
-vt 0.973364 0.537699
-vt 0.977274 0.535937
-vt 0.979144 0.533797
-vt 0.979025 0.531468
-vt 0.97697 0.52914
-vt 0.973028 0.527
-vt 0.967252 0.525238
-vt 0.959691 0.524042
-vt 0.950397 0.523601
-vt 0.954077 0.544451
-vt 0.959806 0.543085
-vt 0.963744 0.541071
-vt 0.965899 0.538625
-vt 0.966281 0.535964
-vt 0.9649 0.533302
-vt 0.961766 0.530857
-vt 0.956889 0.528843
-vt 0.950279 0.527476
-vt 0.941945 0.526973
-vt 0.939616 0.546304
-vt 0.945053 0.54488
-vt 0.949021 0.542782
-vt 0.951485 0.540235
-vt 0.952412 0.537462
-vt 0.951765 0.53469
-vt 0.94951 0.532143
-vt 0.945612 0.530045
-vt 0.940036 0.528621
-vt 0.932747 0.528097
-vt 0.925156 0.544451
-vt 0.930299 0.543085
-vt 0.934297 0.541071
-vt 0.937072 0.538625
-vt 0.938542 0.535964
-vt 0.93863 0.533303
-vt 0.937254 0.530857
-vt 0.934335 0.528843
-vt 0.929793 0.527476
-vt 0.923549 0.526973
-vt 0.911869 0.538895
-vt 0.916741 0.537699
-vt 0.920768 0.535937
-vt 0.923827 0.533797
-vt 0.925798 0.531468
-vt 0.92656 0.52914
-vt 0.925992 0.527
-vt 0.923972 0.525238
-vt 0.920381 0.524042
-vt 0.915096 0.523601
-vt 0.900926 0.529634
-vt 0.905576 0.528723
-vt 0.909625 0.52738
-vt 0.912919 0.52575
-vt 0.915302 0.523976
-vt 0.91662 0.522202
-vt 0.916717 0.520571
-vt 0.915438 0.519229
-vt 0.91263 0.518318
-vt 0.908136 0.517982
-vt 0.8935 0.516669
-vt 0.898 0.516157
-vt 0.902065 0.515401
-vt 0.905518 0.514484
-vt 0.90818 0.513486
-vt 0.909875 0.512488
-vt 0.910423 0.511571
-vt 0.909648 0.510816
-vt 0.90737 0.510303
-vt 0.903412 0.510115
-vt 0.496472 0.491996
-vt 0.496859 0.5
-vt 0.513988 0.487299
-vt 0.514603 0.5
-vt 0.521565 0.485268
-vt 0.522278 0.5
-vt 0.521596 0.485261
-vt 0.522309 0.5
-vt 0.516473 0.486636
-vt 0.51712 0.5
-vt 0.508589 0.488753
-vt 0.509134 0.5
-vt 0.500336 0.49097
-vt 0.500775 0.5
-vt 0.494109 0.492645
-vt 0.494466 0.5
-vt 0.492298 0.493137
-vt 0.492633 0.5
-vt 0.497298 0.491804
-vt 0.497698 0.5
-vt 0.495347 0.484448
-vt 0.512204 0.475321
-vt 0.519495 0.471374
-vt 0.519525 0.47136
-vt 0.514594 0.474032
-vt 0.507007 0.478144
-vt 0.499064 0.48245
-vt 0.49307 0.485703
-vt 0.491327 0.486656
-vt 0.496137 0.484064
-vt 0.493543 0.477446
-vt 0.50934 0.464211
-vt 0.516173 0.458486
-vt 0.5162 0.458465
-vt 0.511579 0.462339
-vt 0.504468 0.468302
-vt 0.497024 0.474544
-vt 0.491405 0.479259
-vt 0.489769 0.480639
-vt 0.494275 0.476875
-vt 0.491114 0.471082
-vt 0.505487 0.454112
-vt 0.511703 0.446772
-vt 0.511728 0.446745
-vt 0.507523 0.451711
-vt 0.501052 0.459354
-vt 0.494278 0.467356
-vt 0.489165 0.473398
-vt 0.487675 0.475163
-vt 0.491772 0.470334
-vt 0.488119 0.465447
-vt 0.500734 0.445171
-vt 0.50619 0.4364
-vt 0.506211 0.436367
-vt 0.50252 0.4423
-vt 0.496839 0.451431
-vt 0.490893 0.460989
-vt 0.486403 0.468206
-vt 0.485093 0.470311
-vt 0.488686 0.464535
-vt 0.484614 0.460633
-vt 0.495171 0.437531
-vt 0.499737 0.427538
-vt 0.499754 0.427499
-vt 0.496665 0.434258
-vt 0.49191 0.444659
-vt 0.486932 0.455547
-vt 0.483173 0.463767
-vt 0.482075 0.466162
-vt 0.485079 0.459576
-vt 0.480655 0.45673
-vt 0.488889 0.431337
-vt 0.49245 0.420353
-vt 0.492463 0.420309
-vt 0.490053 0.427737
-vt 0.486343 0.439169
-vt 0.48246 0.451134
-vt 0.479527 0.460166
-vt 0.478668 0.462795
-vt 0.48101 0.455552
-vt 0.476299 0.453829
-vt 0.481976 0.426733
-vt 0.484432 0.415013
-vt 0.484441 0.414966
-vt 0.482778 0.422891
-vt 0.48022 0.435088
-vt 0.477541 0.447854
-vt 0.475518 0.457489
-vt 0.474925 0.460291
-vt 0.476537 0.452559
-vt 0.471603 0.452021
-vt 0.474525 0.423865
-vt 0.475788 0.411686
-vt 0.475793 0.411637
-vt 0.474937 0.419872
-vt 0.47362 0.432545
-vt 0.472241 0.445809
-vt 0.471199 0.45582
-vt 0.470893 0.45873
-vt 0.471722 0.450693
-vt 0.466623 0.451399
-vt 0.466623 0.422877
-vt 0.466623 0.410539
-vt 0.466623 0.410489
-vt 0.466623 0.418831
-vt 0.466623 0.431668
-vt 0.466623 0.445105
-vt 0.466623 0.455245
-vt 0.466623 0.458192
-vt 0.466623 0.45005
-vt 0.461644 0.452021
-vt 0.458722 0.423865
-vt 0.457458 0.411686
-vt 0.457454 0.411637
-vt 0.458309 0.419872
-vt 0.459626 0.432545
-vt 0.461005 0.445809
-vt 0.462047 0.45582
-vt 0.462354 0.45873
-vt 0.461524 0.450693
-vt 0.456948 0.453829
-vt 0.45127 0.426733
-vt 0.448814 0.415013
-vt 0.448806 0.414966
-vt 0.450468 0.422891
-vt 0.453026 0.435088
-vt 0.455705 0.447854
-vt 0.457729 0.457489
-vt 0.458322 0.460291
-vt 0.456709 0.452559
-vt 0.452592 0.45673
-vt 0.444358 0.431337
-vt 0.440797 0.420353
-vt 0.440784 0.420309
-vt 0.443194 0.427737
-vt 0.446903 0.439169
-vt 0.450787 0.451134
-vt 0.45372 0.460166
-vt 0.454578 0.462795
-vt 0.452237 0.455552
-vt 0.448633 0.460633
-vt 0.438076 0.437531
-vt 0.433509 0.427538
-vt 0.433492 0.427499
-vt 0.436582 0.434258
-vt 0.441337 0.444659
-vt 0.446315 0.455547
-vt 0.450074 0.463767
-vt 0.451172 0.466162
-vt 0.448167 0.459576
-vt 0.445127 0.465447
-vt 0.432513 0.445171
-vt 0.427057 0.4364
-vt 0.427036 0.436367
-vt 0.430727 0.4423
-vt 0.436407 0.451431
-vt 0.442354 0.460989
-vt 0.446843 0.468206
-vt 0.448153 0.470311
-vt 0.44456 0.464535
-vt 0.442132 0.471082
-vt 0.42776 0.454112
-vt 0.421543 0.446772
-vt 0.421519 0.446745
-vt 0.425724 0.451711
-vt 0.432195 0.459354
-vt 0.438968 0.467356
-vt 0.444082 0.473398
-vt 0.445572 0.475163
-vt 0.441475 0.470334
-vt 0.439704 0.477446
-vt 0.423906 0.464211
-vt 0.417073 0.458486
-vt 0.417046 0.458465
-vt 0.421667 0.46234
-vt 0.428779 0.468302
-vt 0.436223 0.474544
-vt 0.441842 0.479259
-vt 0.443477 0.480639
-vt 0.438972 0.476875
-vt 0.437899 0.484448
-vt 0.421043 0.475321
-vt 0.413751 0.471374
-vt 0.413722 0.47136
-vt 0.418652 0.474032
-vt 0.42624 0.478144
-vt 0.434182 0.48245
-vt 0.440176 0.485703
-vt 0.441919 0.486656
-vt 0.43711 0.484064
-vt 0.436775 0.491996
-vt 0.419258 0.487299
-vt 0.411681 0.485268
-vt 0.411651 0.485261
-vt 0.416774 0.486636
-vt 0.424658 0.488753
-vt 0.43291 0.49097
-vt 0.439138 0.492645
-vt 0.440948 0.493137
-vt 0.435949 0.491804
-vt 0.436387 0.5
-vt 0.418644 0.5
-vt 0.410968 0.5
-vt 0.410937 0.5
-vt 0.416126 0.5
-vt 0.424113 0.5
-vt 0.432472 0.5
-vt 0.43878 0.5
-vt 0.440614 0.5
-vt 0.435548 0.5
-vt 0.436775 0.508004
-vt 0.419258 0.512701
-vt 0.411681 0.514732
-vt 0.411651 0.514739
-vt 0.416774 0.513364
-vt 0.424658 0.511247
-vt 0.43291 0.50903
-vt 0.439138 0.507355
-vt 0.440948 0.506863
-vt 0.435949 0.508196
-vt 0.437899 0.515552
-vt 0.421043 0.524679
-vt 0.413751 0.528626
-vt 0.413722 0.52864
-vt 0.418652 0.525968
-vt 0.42624 0.521856
-vt 0.434182 0.51755
-vt 0.440176 0.514297
-vt 0.441919 0.513344
-vt 0.43711 0.515936
-vt 0.439704 0.522554
-vt 0.423906 0.535789
-vt 0.417073 0.541514
-vt 0.417046 0.541535
-vt 0.421667 0.537661
-vt 0.428779 0.531698
-vt 0.436223 0.525456
-vt 0.441842 0.520741
-vt 0.443477 0.519361
-vt 0.438972 0.523125
-vt 0.442132 0.528918
-vt 0.42776 0.545888
-vt 0.421543 0.553228
-vt 0.421519 0.553255
-vt 0.425724 0.548289
-vt 0.432195 0.540646
-vt 0.438968 0.532644
-vt 0.444082 0.526602
-vt 0.445572 0.524837
-vt 0.441475 0.529666
-vt 0.445127 0.534553
-vt 0.432513 0.554829
-vt 0.427057 0.5636
-vt 0.427036 0.563633
-vt 0.430727 0.5577
-vt 0.436407 0.548569
-vt 0.442354 0.539011
-vt 0.446843 0.531794
-vt 0.448153 0.529689
-vt 0.44456 0.535465
-vt 0.448633 0.539367
-vt 0.438076 0.562469
-vt 0.433509 0.572462
-vt 0.433492 0.572501
-vt 0.436582 0.565742
-vt 0.441337 0.555341
-vt 0.446315 0.544453
-vt 0.450074 0.536233
-vt 0.451172 0.533838
-vt 0.448167 0.540424
-vt 0.452592 0.54327
-vt 0.444358 0.568663
-vt 0.440797 0.579647
-vt 0.440784 0.579691
-vt 0.443194 0.572263
-vt 0.446903 0.560831
-vt 0.450787 0.548866
-vt 0.45372 0.539834
-vt 0.454578 0.537205
-vt 0.452237 0.544448
-vt 0.456948 0.546171
-vt 0.45127 0.573267
-vt 0.448814 0.584987
-vt 0.448806 0.585034
-vt 0.450468 0.577109
-vt 0.453026 0.564912
-vt 0.455705 0.552146
-vt 0.457729 0.542511
-vt 0.458322 0.539709
-vt 0.456709 0.547441
-vt 0.461644 0.547979
-vt 0.458722 0.576135
-vt 0.457458 0.588314
-vt 0.457454 0.588363
-vt 0.458309 0.580128
-vt 0.459626 0.567455
-vt 0.461005 0.554191
-vt 0.462048 0.54418
-vt 0.462354 0.54127
-vt 0.461524 0.549307
-vt 0.466623 0.548601
-vt 0.466623 0.577123
-vt 0.466623 0.589461
-vt 0.466623 0.589511
-vt 0.466623 0.581169
-vt 0.466623 0.568332
-vt 0.466623 0.554895
-vt 0.466623 0.544755
-vt 0.466623 0.541808
-vt 0.466623 0.54995
-vt 0.471603 0.547979
-vt 0.474525 0.576135
-vt 0.475788 0.588314
-vt 0.475793 0.588363
-vt 0.474937 0.580128
-vt 0.47362 0.567455
-vt 0.472241 0.554191
-vt 0.471199 0.54418
-vt 0.470893 0.54127
-vt 0.471722 0.549307
-vt 0.476299 0.546171
-vt 0.481977 0.573267
-vt 0.484432 0.584987
-vt 0.484441 0.585034
-vt 0.482778 0.577109
-vt 0.48022 0.564912
-vt 0.477541 0.552146
-vt 0.475518 0.542511
-vt 0.474925 0.539709
-vt 0.476537 0.547441
-vt 0.480655 0.54327
-vt 0.488889 0.568663
-vt 0.49245 0.579647
-vt 0.492463 0.579691
-vt 0.490053 0.572263
-vt 0.486343 0.560831
-vt 0.48246 0.548866
-vt 0.479527 0.539834
-vt 0.478668 0.537205
-vt 0.48101 0.544448
-vt 0.484614 0.539367
-vt 0.495171 0.562469
-vt 0.499737 0.572462
-vt 0.499754 0.572501
-vt 0.496665 0.565742
-vt 0.49191 0.555341
-vt 0.486932 0.544453
-vt 0.483173 0.536233
-vt 0.482075 0.533838
-vt 0.485079 0.540424
-vt 0.488119 0.534553
-vt 0.500734 0.554829
-vt 0.50619 0.5636
-vt 0.506211 0.563633
-vt 0.50252 0.5577
-vt 0.496839 0.548569
-vt 0.490893 0.539011
-vt 0.486403 0.531794
-vt 0.485093 0.529689
-vt 0.488686 0.535465
-vt 0.491114 0.528918
-vt 0.505487 0.545888
-vt 0.511703 0.553228
-vt 0.511728 0.553255
-vt 0.507523 0.548289
-vt 0.501052 0.540646
-vt 0.494278 0.532644
-vt 0.489165 0.526602
-vt 0.487675 0.524837
-vt 0.491772 0.529666
-vt 0.493543 0.522554
-vt 0.50934 0.535789
-vt 0.516173 0.541514
-vt 0.5162 0.541535
-vt 0.511579 0.53766
-vt 0.504468 0.531698
-vt 0.497024 0.525456
-vt 0.491405 0.520741
-vt 0.489769 0.519361
-vt 0.494275 0.523125
-vt 0.495347 0.515552
-vt 0.512204 0.524679
-vt 0.519495 0.528626
-vt 0.519525 0.52864
-vt 0.514594 0.525968
-vt 0.507007 0.521856
-vt 0.499064 0.51755
-vt 0.49307 0.514297
-vt 0.491327 0.513344
-vt 0.496137 0.515936
-vt 0.496472 0.508004
-vt 0.513988 0.512701
-vt 0.521565 0.514732
-vt 0.521596 0.514739
-vt 0.516473 0.513364
-vt 0.508589 0.511247
-vt 0.500336 0.50903
-vt 0.494109 0.507355
-vt 0.492298 0.506863
-vt 0.497298 0.508196
-vt 0.509476 0.48855
-vt 0.510035 0.5
-vt 0.526623 0.483969
-vt 0.527406 0.5
-vt 0.547267 0.478453
-vt 0.548319 0.5
-vt 0.569936 0.472396
-vt 0.571284 0.5
-vt 0.593156 0.466192
-vt 0.594807 0.5
-vt 0.615457 0.460234
-vt 0.617399 0.5
-vt 0.635365 0.454915
-vt 0.637566 0.5
-vt 0.651407 0.450628
-vt 0.653819 0.5
-vt 0.662113 0.447768
-vt 0.664664 0.5
-vt 0.666009 0.446727
-vt 0.66861 0.5
-vt 0.507854 0.477737
-vt 0.524352 0.468829
-vt 0.544215 0.458104
-vt 0.566025 0.446327
-vt 0.588367 0.434264
-vt 0.609824 0.422678
-vt 0.628978 0.412336
-vt 0.644414 0.404001
-vt 0.654714 0.398439
-vt 0.658462 0.396415
-vt 0.505253 0.467695
-vt 0.52071 0.454768
-vt 0.53932 0.439205
-vt 0.559754 0.422115
-vt 0.580687 0.40461
-vt 0.600789 0.387798
-vt 0.618735 0.37279
-vt 0.633197 0.360696
-vt 0.642848 0.352625
-vt 0.646359 0.349688
-vt 0.501755 0.458556
-vt 0.515813 0.441973
-vt 0.532738 0.422007
-vt 0.551323 0.400084
-vt 0.57036 0.377626
-vt 0.588643 0.356059
-vt 0.604964 0.336805
-vt 0.618117 0.32129
-vt 0.626893 0.310936
-vt 0.630087 0.307169
-vt 0.497446 0.450456
-vt 0.509779 0.430631
-vt 0.524627 0.406764
-vt 0.540932 0.380555
-vt 0.557634 0.353709
-vt 0.573674 0.327926
-vt 0.587993 0.30491
-vt 0.599532 0.286362
-vt 0.607232 0.273984
-vt 0.610034 0.26948
-vt 0.492406 0.443528
-vt 0.502723 0.420931
-vt 0.515144 0.393726
-vt 0.528783 0.363853
-vt 0.542754 0.333253
-vt 0.556172 0.303865
-vt 0.56815 0.27763
-vt 0.577802 0.256488
-vt 0.584243 0.24238
-vt 0.586587 0.237247
-vt 0.486721 0.437907
-vt 0.494763 0.413061
-vt 0.504445 0.383147
-vt 0.515077 0.350301
-vt 0.525967 0.316654
-vt 0.536426 0.28434
-vt 0.545763 0.255494
-vt 0.553287 0.232248
-vt 0.558308 0.216736
-vt 0.560135 0.211091
-vt 0.480473 0.433726
-vt 0.486015 0.407206
-vt 0.492687 0.375279
-vt 0.500014 0.34022
-vt 0.507519 0.304308
-vt 0.514727 0.269818
-vt 0.521161 0.239029
-vt 0.526346 0.214218
-vt 0.529806 0.197661
-vt 0.531065 0.191636
-vt 0.473746 0.431119
-vt 0.476596 0.403556
-vt 0.480028 0.370373
-vt 0.483796 0.333935
-vt 0.487656 0.29661
-vt 0.491362 0.260764
-vt 0.494672 0.228764
-vt 0.497338 0.202976
-vt 0.499118 0.185768
-vt 0.499765 0.179507
-vt 0.466623 0.43022
-vt 0.466623 0.402298
-vt 0.466623 0.368681
-vt 0.466623 0.331768
-vt 0.466623 0.293956
-vt 0.466623 0.257642
-vt 0.466623 0.225225
-vt 0.466623 0.199101
-vt 0.466623 0.181668
-vt 0.466623 0.175325
-vt 0.4595 0.431119
-vt 0.45665 0.403556
-vt 0.453219 0.370373
-vt 0.449451 0.333935
-vt 0.445591 0.29661
-vt 0.441884 0.260764
-vt 0.438575 0.228764
-vt 0.435908 0.202976
-vt 0.434129 0.185768
-vt 0.433481 0.179507
-vt 0.452773 0.433726
-vt 0.447231 0.407206
-vt 0.440559 0.375279
-vt 0.433232 0.34022
-vt 0.425727 0.304308
-vt 0.41852 0.269818
-vt 0.412086 0.23903
-vt 0.4069 0.214218
-vt 0.40344 0.197661
-vt 0.402181 0.191636
-vt 0.446525 0.437907
-vt 0.438483 0.413061
-vt 0.428801 0.383147
-vt 0.41817 0.350301
-vt 0.407279 0.316654
-vt 0.39682 0.284341
-vt 0.387483 0.255494
-vt 0.379959 0.232248
-vt 0.374938 0.216736
-vt 0.373111 0.211091
-vt 0.44084 0.443528
-vt 0.430523 0.420932
-vt 0.418102 0.393726
-vt 0.404463 0.363853
-vt 0.390492 0.333253
-vt 0.377075 0.303865
-vt 0.365097 0.27763
-vt 0.355444 0.256488
-vt 0.349003 0.242381
-vt 0.346659 0.237247
-vt 0.435801 0.450456
-vt 0.423468 0.430631
-vt 0.408619 0.406764
-vt 0.392314 0.380555
-vt 0.375613 0.353709
-vt 0.359573 0.327926
-vt 0.345254 0.30491
-vt 0.333715 0.286362
-vt 0.326015 0.273985
-vt 0.323213 0.269481
-vt 0.431491 0.458556
-vt 0.417433 0.441973
-vt 0.400508 0.422007
-vt 0.381924 0.400084
-vt 0.362886 0.377626
-vt 0.344604 0.356059
-vt 0.328282 0.336806
-vt 0.31513 0.32129
-vt 0.306353 0.310937
-vt 0.303159 0.307169
-vt 0.427994 0.467695
-vt 0.412537 0.454768
-vt 0.393927 0.439205
-vt 0.373492 0.422115
-vt 0.35256 0.40461
-vt 0.332457 0.387798
-vt 0.314511 0.37279
-vt 0.300049 0.360696
-vt 0.290399 0.352625
-vt 0.286887 0.349688
-vt 0.425393 0.477737
-vt 0.408894 0.468829
-vt 0.389032 0.458104
-vt 0.367221 0.446327
-vt 0.344879 0.434264
-vt 0.323423 0.422678
-vt 0.304268 0.412336
-vt 0.288833 0.404001
-vt 0.278532 0.39844
-vt 0.274784 0.396416
-vt 0.423771 0.48855
-vt 0.406624 0.483969
-vt 0.38598 0.478453
-vt 0.363311 0.472397
-vt 0.34009 0.466192
-vt 0.31779 0.460234
-vt 0.297882 0.454915
-vt 0.281839 0.450629
-vt 0.271134 0.447768
-vt 0.267238 0.446727
-vt 0.423212 0.5
-vt 0.405841 0.5
-vt 0.384927 0.5
-vt 0.361963 0.5
-vt 0.338439 0.5
-vt 0.315848 0.5
-vt 0.29568 0.5
-vt 0.279428 0.5
-vt 0.268583 0.5
-vt 0.264636 0.5
-vt 0.423771 0.51145
-vt 0.406624 0.516031
-vt 0.38598 0.521547
-vt 0.363311 0.527604
-vt 0.34009 0.533808
-vt 0.31779 0.539766
-vt 0.297882 0.545085
-vt 0.281839 0.549372
-vt 0.271134 0.552232
-vt 0.267238 0.553273
-vt 0.425393 0.522263
-vt 0.408894 0.531171
-vt 0.389032 0.541896
-vt 0.367221 0.553673
-vt 0.344879 0.565736
-vt 0.323423 0.577322
-vt 0.304268 0.587664
-vt 0.288833 0.595999
-vt 0.278532 0.601561
-vt 0.274784 0.603584
-vt 0.427994 0.532305
-vt 0.412537 0.545232
-vt 0.393927 0.560795
-vt 0.373492 0.577885
-vt 0.35256 0.59539
-vt 0.332457 0.612202
-vt 0.314511 0.62721
-vt 0.300049 0.639304
-vt 0.290399 0.647375
-vt 0.286887 0.650312
-vt 0.431491 0.541444
-vt 0.417433 0.558027
-vt 0.400508 0.577993
-vt 0.381924 0.599916
-vt 0.362886 0.622374
-vt 0.344604 0.643941
-vt 0.328282 0.663195
-vt 0.31513 0.67871
-vt 0.306353 0.689064
-vt 0.303159 0.692831
-vt 0.435801 0.549544
-vt 0.423468 0.569369
-vt 0.408619 0.593236
-vt 0.392314 0.619445
-vt 0.375613 0.646291
-vt 0.359573 0.672074
-vt 0.345254 0.69509
-vt 0.333715 0.713638
-vt 0.326015 0.726016
-vt 0.323213 0.73052
-vt 0.44084 0.556472
-vt 0.430523 0.579069
-vt 0.418102 0.606274
-vt 0.404463 0.636147
-vt 0.390492 0.666747
-vt 0.377075 0.696135
-vt 0.365097 0.72237
-vt 0.355444 0.743512
-vt 0.349003 0.75762
-vt 0.346659 0.762753
-vt 0.446525 0.562093
-vt 0.438483 0.586939
-vt 0.428801 0.616853
-vt 0.41817 0.649699
-vt 0.407279 0.683346
-vt 0.39682 0.71566
-vt 0.387483 0.744506
-vt 0.379959 0.767752
-vt 0.374938 0.783264
-vt 0.373111 0.788909
-vt 0.452773 0.566274
-vt 0.447231 0.592794
-vt 0.440559 0.624721
-vt 0.433232 0.65978
-vt 0.425727 0.695692
-vt 0.41852 0.730182
-vt 0.412086 0.760971
-vt 0.406901 0.785782
-vt 0.40344 0.802339
-vt 0.402181 0.808364
-vt 0.4595 0.568881
-vt 0.45665 0.596444
-vt 0.453219 0.629627
-vt 0.449451 0.666065
-vt 0.445591 0.70339
-vt 0.441884 0.739236
-vt 0.438575 0.771236
-vt 0.435908 0.797024
-vt 0.434129 0.814232
-vt 0.433481 0.820493
-vt 0.466623 0.56978
-vt 0.466623 0.597702
-vt 0.466623 0.631319
-vt 0.466623 0.668232
-vt 0.466623 0.706044
-vt 0.466623 0.742358
-vt 0.466623 0.774775
-vt 0.466623 0.800899
-vt 0.466623 0.818332
-vt 0.466623 0.824675
-vt 0.473746 0.568881
-vt 0.476596 0.596444
-vt 0.480028 0.629627
-vt 0.483796 0.666065
-vt 0.487656 0.70339
-vt 0.491363 0.739236
-vt 0.494672 0.771236
-vt 0.497338 0.797024
-vt 0.499118 0.814232
-vt 0.499765 0.820493
-vt 0.480473 0.566274
-vt 0.486015 0.592794
-vt 0.492688 0.624721
-vt 0.500014 0.65978
-vt 0.507519 0.695692
-vt 0.514727 0.730182
-vt 0.521161 0.76097
-vt 0.526346 0.785782
-vt 0.529806 0.802339
-vt 0.531065 0.808364
-vt 0.486721 0.562093
-vt 0.494763 0.586939
-vt 0.504445 0.616853
-vt 0.515077 0.649699
-vt 0.525967 0.683346
-vt 0.536426 0.715659
-vt 0.545763 0.744506
-vt 0.553287 0.767752
-vt 0.558308 0.783264
-vt 0.560135 0.788909
-vt 0.492406 0.556472
-vt 0.502723 0.579068
-vt 0.515144 0.606274
-vt 0.528783 0.636147
-vt 0.542754 0.666747
-vt 0.556172 0.696135
-vt 0.56815 0.72237
-vt 0.577802 0.743512
-vt 0.584243 0.757619
-vt 0.586587 0.762753
-vt 0.497446 0.549544
-vt 0.509779 0.569369
-vt 0.524627 0.593236
-vt 0.540932 0.619445
-vt 0.557634 0.646291
-vt 0.573674 0.672074
-vt 0.587993 0.69509
-vt 0.599532 0.713638
-vt 0.607232 0.726015
-vt 0.610034 0.730519
-vt 0.501755 0.541444
-vt 0.515813 0.558027
-vt 0.532738 0.577993
-vt 0.551323 0.599916
-vt 0.57036 0.622374
-vt 0.588643 0.643941
-vt 0.604964 0.663194
-vt 0.618117 0.67871
-vt 0.626893 0.689063
-vt 0.630087 0.692831
-vt 0.505253 0.532305
-vt 0.52071 0.545232
-vt 0.53932 0.560795
-vt 0.559754 0.577885
-vt 0.580687 0.59539
-vt 0.600789 0.612202
-vt 0.618735 0.62721
-vt 0.633197 0.639304
-vt 0.642848 0.647375
-vt 0.646359 0.650312
-vt 0.507854 0.522263
-vt 0.524352 0.531171
-vt 0.544215 0.541896
-vt 0.566025 0.553673
-vt 0.588367 0.565736
-vt 0.609824 0.577322
-vt 0.628978 0.587664
-vt 0.644414 0.595999
-vt 0.654714 0.60156
-vt 0.658462 0.603584
-vt 0.509476 0.51145
-vt 0.526623 0.516031
-vt 0.547267 0.521547
-vt 0.569936 0.527603
-vt 0.593156 0.533808
-vt 0.615457 0.539766
-vt 0.635365 0.545085
-vt 0.651407 0.549371
-vt 0.662113 0.552232
-vt 0.666009 0.553273
-# 3194 texture verticies
-vn -0.929104 -0.369018 -0.0243109
-vn -0.913652 -0.36928 -0.169918
-vn -0.946813 -0.284151 -0.151008
-vn -0.958824 -0.284002 -0.000667619
-vn -0.986226 -0.0526858 -0.15679
-vn -0.998612 -0.0526791 -0.000200335
-vn -0.930297 0.336757 -0.145404
-vn -0.941661 0.336556 0.00224288
-vn -0.821671 0.559583 -0.108278
-vn -0.828604 0.559416 0.0216812
-vn -0.291414 0.956567 -0.00762525
-vn -0.286386 0.956633 -0.0532517
-vn -0.0326484 0.999465 0.00203669
-vn -0.0318944 0.999466 0.00706347
-vn 0.375907 0.924469 0.0636451
-vn 0.381455 0.924379 0.00388849
-vn 0.602701 0.791935 0.0979287
-vn 0.610844 0.791748 0.00220324
-vn 0.738089 0.664195 0.118618
-vn 0.747765 0.663962 0.0014116
-vn 0.828078 0.544738 0.132468
-vn 0.83876 0.5445 0.000979634
-vn 0.878084 0.45763 0.139795
-vn 0.88926 0.457403 0.000378388
-vn -0.875373 -0.369687 -0.311536
-vn -0.911144 -0.284467 -0.298153
-vn -0.949241 -0.0527431 -0.310096
-vn -0.895693 0.337087 -0.29001
-vn -0.794068 0.560044 -0.236235
-vn -0.274117 0.956737 -0.097544
-vn -0.0325301 0.999466 -0.00314181
-vn 0.360837 0.924628 0.121899
-vn 0.579329 0.792293 0.191441
-vn 0.70983 0.664647 0.233208
-vn 0.796609 0.545206 0.261084
-vn 0.844909 0.458076 0.276217
-vn -0.815641 -0.370043 -0.444744
-vn -0.853043 -0.28477 -0.437292
-vn -0.888872 -0.0528108 -0.455102
-vn -0.83904 0.337382 -0.426832
-vn -0.746856 0.560516 -0.35781
-vn -0.25519 0.956827 -0.139138
-vn -0.0315724 0.999468 -0.00823025
-vn 0.336954 0.924774 0.176789
-vn 0.541761 0.792628 0.279707
-vn 0.664144 0.665075 0.341449
-vn 0.74556 0.545649 0.382631
-vn 0.790952 0.458498 0.405185
-vn -0.73636 -0.370265 -0.566284
-vn -0.774403 -0.284974 -0.56488
-vn -0.807065 -0.0528624 -0.588092
-vn -0.762189 0.337568 -0.552373
-vn -0.681694 0.560847 -0.469835
-vn -0.230259 0.956884 -0.177071
-vn -0.0298419 0.999469 -0.0130641
-vn 0.305102 0.924869 0.227001
-vn 0.491309 0.792849 0.360562
-vn 0.602597 0.665359 0.440651
-vn 0.676646 0.545946 0.494057
-vn 0.718006 0.458779 0.52344
-vn -0.639538 -0.370321 -0.673686
-vn -0.677298 -0.28505 -0.678243
-vn -0.705975 -0.0528878 -0.706259
-vn -0.667171 0.337622 -0.663999
-vn -0.600378 0.560989 -0.569946
-vn -0.199953 0.956898 -0.21063
-vn -0.0274164 0.99947 -0.0175297
-vn 0.266111 0.924899 0.271564
-vn 0.429302 0.792925 0.4324
-vn 0.526814 0.665457 0.528804
-vn 0.591689 0.546049 0.593072
-vn 0.627996 0.458878 0.628532
-vn -0.527171 -0.370205 -0.764879
-vn -0.563796 -0.284989 -0.775188
-vn -0.587767 -0.0528874 -0.8073
-vn -0.556014 0.337535 -0.759552
-vn -0.504677 0.56092 -0.656255
-vn -0.164864 0.956869 -0.239211
-vn -0.024367 0.999471 -0.0215501
-vn 0.220765 0.924861 0.309669
-vn 0.357015 0.792847 0.493897
-vn 0.438371 0.665361 0.604256
-vn 0.492471 0.54595 0.677798
-vn 0.522826 0.458782 0.718451
-vn -0.401417 -0.369931 -0.837864
-vn -0.436091 -0.284795 -0.853649
-vn -0.454741 -0.0528598 -0.889054
-vn -0.430862 0.337318 -0.837003
-vn -0.396406 0.56065 -0.727003
-vn -0.125616 0.956799 -0.262214
-vn -0.020751 0.99947 -0.0250646
-vn 0.169881 0.92476 0.340529
-vn 0.275779 0.792622 0.543779
-vn 0.338925 0.665078 0.665433
-vn 0.380875 0.545659 0.746452
-vn 0.404506 0.458504 0.791296
-vn -0.264971 -0.369543 -0.890634
-vn -0.296866 -0.2845 -0.911554
-vn -0.30971 -0.0528059 -0.949364
-vn -0.29433 0.337008 -0.894313
-vn -0.277729 0.560217 -0.7804
-vn -0.0829913 0.9567 -0.278992
-vn -0.016619 0.99947 -0.0280104
-vn 0.114469 0.924609 0.363311
-vn 0.18722 0.792286 0.580716
-vn 0.230498 0.664651 0.710711
-vn 0.2592 0.545217 0.797216
-vn 0.275488 0.458084 0.845142
-vn -0.121427 -0.369157 -0.9214
-vn -0.149673 -0.284179 -0.947016
-vn -0.156389 -0.0527397 -0.986286
-vn -0.149886 0.336692 -0.929609
-vn -0.15151 0.559732 -0.814705
-vn -0.0380637 0.956602 -0.288901
-vn -0.0120314 0.999468 -0.0303164
-vn 0.0558964 0.924453 0.377178
-vn 0.0935342 0.791929 0.603407
-vn 0.1158 0.664198 0.738533
-vn 0.130511 0.544747 0.828382
-vn 0.139039 0.457636 0.878201
-vn 0.0205305 -0.372124 -0.927956
-vn -0.00160004 -0.285907 -0.958256
-vn -0.00142711 -0.0550054 -0.998485
-vn -0.00316138 0.334579 -0.942362
-vn -0.0221184 0.55941 -0.828596
-vn 0.00746477 0.956236 -0.292502
-vn -0.00707374 0.999474 -0.0316562
-vn -0.00382869 0.924284 0.381686
-vn -0.00216455 0.791666 0.610951
-vn -0.00139872 0.663927 0.747796
-vn -0.000977992 0.544495 0.838763
-vn -0.000378475 0.457403 0.88926
-vn 0.158685 -0.397523 -0.903767
-vn 0.142822 -0.308073 -0.940581
-vn 0.151488 -0.0816775 -0.985079
-vn 0.144247 0.310037 -0.939718
-vn 0.108178 0.545475 -0.831116
-vn 0.0547505 0.953633 -0.29595
-vn -0.00212649 0.999458 -0.0328537
-vn -0.0640128 0.923282 0.378752
-vn -0.0981155 0.790627 0.604386
-vn -0.118681 0.663497 0.738706
-vn -0.132481 0.544537 0.828208
-vn -0.139796 0.457608 0.878096
-vn 0.292735 -0.439105 -0.849408
-vn 0.283228 -0.352746 -0.891825
-vn 0.302115 -0.136332 -0.943472
-vn 0.295146 0.255902 -0.920545
-vn 0.244858 0.502499 -0.82918
-vn 0.105712 0.948843 -0.297526
-vn 0.00398797 0.999328 -0.0364396
-vn -0.124436 0.921139 0.368808
-vn -0.192973 0.788201 0.584381
-vn -0.233779 0.66237 0.711768
-vn -0.261212 0.544492 0.797055
-vn -0.276228 0.457991 0.844951
-vn 0.41898 -0.476466 -0.77294
-vn 0.415731 -0.397642 -0.817954
-vn 0.445435 -0.192365 -0.874405
-vn 0.444472 0.196389 -0.874
-vn 0.386055 0.446993 -0.806944
-vn 0.158495 0.943797 -0.290047
-vn 0.0121022 0.999102 -0.0406172
-vn -0.183325 0.918807 0.349552
-vn -0.283831 0.785507 0.549926
-vn -0.343029 0.661068 0.667323
-vn -0.382999 0.544357 0.746316
-vn -0.40522 0.45834 0.791026
-vn 0.53525 -0.497547 -0.682608
-vn 0.537269 -0.426674 -0.727524
-vn 0.576837 -0.229031 -0.784094
-vn 0.583104 0.15513 -0.797449
-vn 0.521452 0.401178 -0.75309
-vn 0.207702 0.940608 -0.268545
-vn 0.0214381 0.998857 -0.0427127
-vn -0.237937 0.917183 0.319627
-vn -0.367608 0.783596 0.500841
-vn -0.443385 0.660123 0.606339
-vn -0.494705 0.544231 0.677554
-vn -0.523503 0.458567 0.718096
-vn 0.641737 -0.497955 -0.58328
-vn 0.648014 -0.432966 -0.626593
-vn 0.695144 -0.236736 -0.678772
-vn 0.704031 0.145832 -0.695035
-vn 0.639558 0.382797 -0.666657
-vn 0.247118 0.940684 -0.232478
-vn 0.0299298 0.998715 -0.0408996
-vn -0.285413 0.91682 0.279251
-vn -0.441481 0.78308 0.438042
-vn -0.532362 0.659849 0.530273
-vn -0.593929 0.54419 0.592542
-vn -0.628616 0.458645 0.628082
-vn 0.737789 -0.478678 -0.475956
-vn 0.747919 -0.416398 -0.516943
-vn 0.799264 -0.215255 -0.561107
-vn 0.802263 0.169509 -0.5724
-vn 0.731633 0.397703 -0.553666
-vn 0.273153 0.943825 -0.185965
-vn 0.0354826 0.99875 -0.0352169
-vn -0.323612 0.917783 0.230108
-vn -0.503239 0.784045 0.363352
-vn -0.607958 0.660295 0.440906
-vn -0.678704 0.544247 0.493109
-vn -0.718541 0.458567 0.522891
-vn 0.819937 -0.44511 -0.359973
-vn 0.834139 -0.381943 -0.39791
-vn 0.885602 -0.171692 -0.431544
-vn 0.873918 0.216382 -0.435254
-vn 0.79293 0.438289 -0.423278
-vn 0.286539 0.948485 -0.135169
-vn 0.0373474 0.998928 -0.0273655
-vn -0.351645 0.919672 0.174782
-vn -0.551448 0.7861 0.2792
-vn -0.668514 0.661268 0.340313
-vn -0.74722 0.544354 0.381236
-vn -0.791373 0.458337 0.404543
-vn 0.882351 -0.407226 -0.235846
-vn 0.901146 -0.339529 -0.269547
-vn 0.949045 -0.119391 -0.291648
-vn 0.917998 0.270177 -0.290318
-vn 0.824397 0.48851 -0.285882
-vn 0.291209 0.952874 -0.0850209
-vn 0.0363678 0.99915 -0.0193905
-vn -0.369883 0.921811 0.115977
-vn -0.585448 0.788534 0.188322
-vn -0.71265 0.662412 0.230955
-vn -0.797712 0.544431 0.259327
-vn -0.845194 0.457981 0.275502
-vn 0.919241 -0.378903 -0.106903
-vn 0.943253 -0.303518 -0.134722
-vn 0.986407 -0.0760091 -0.145685
-vn 0.938651 0.313183 -0.144395
-vn 0.833609 0.531485 -0.150399
-vn 0.292027 0.95567 -0.0376104
-vn 0.0341601 0.999338 -0.0125436
-vn -0.379549 0.923482 0.0558985
-vn -0.605234 0.790532 0.0935408
-vn -0.739307 0.663337 0.115802
-vn -0.828589 0.544432 0.130516
-vn -0.878223 0.457593 0.139039
-vn 0.929026 -0.368987 0.0275646
-vn 0.958072 -0.286486 0.00485249
-vn 0.998441 -0.0557104 0.00334572
-vn 0.942827 0.333282 -0.000181233
-vn 0.832035 0.554352 -0.0202931
-vn 0.291414 0.956565 0.00784855
-vn 0.0327113 0.99944 -0.00699775
-vn -0.381768 0.92425 -0.00399832
-vn -0.611123 0.791532 -0.00229458
-vn -0.747889 0.663822 -0.00145333
-vn -0.838796 0.544445 -0.000990303
-vn -0.889264 0.457395 -0.00037962
-vn 0.913652 -0.36928 0.169918
-vn 0.946813 -0.284151 0.151008
-vn 0.986226 -0.0526858 0.15679
-vn 0.930297 0.336757 0.145404
-vn 0.821671 0.559583 0.108278
-vn 0.286386 0.956633 0.0532517
-vn 0.0326484 0.999465 -0.00203668
-vn -0.375907 0.924469 -0.0636451
-vn -0.602701 0.791935 -0.0979288
-vn -0.738089 0.664195 -0.118618
-vn -0.828078 0.544738 -0.132468
-vn -0.878084 0.45763 -0.139795
-vn 0.875373 -0.369687 0.311536
-vn 0.911144 -0.284467 0.298153
-vn 0.949241 -0.0527431 0.310096
-vn 0.895693 0.337087 0.29001
-vn 0.794068 0.560044 0.236235
-vn 0.274117 0.956737 0.097544
-vn 0.0325301 0.999466 0.00314182
-vn -0.360837 0.924628 -0.121899
-vn -0.579329 0.792293 -0.191441
-vn -0.70983 0.664647 -0.233208
-vn -0.796609 0.545206 -0.261084
-vn -0.844909 0.458076 -0.276217
-vn 0.815641 -0.370043 0.444744
-vn 0.853043 -0.28477 0.437292
-vn 0.888872 -0.0528108 0.455102
-vn 0.83904 0.337382 0.426832
-vn 0.746856 0.560516 0.35781
-vn 0.25519 0.956827 0.139138
-vn 0.0315724 0.999468 0.00823025
-vn -0.336954 0.924774 -0.176789
-vn -0.541761 0.792628 -0.279707
-vn -0.664144 0.665075 -0.341449
-vn -0.74556 0.545649 -0.382631
-vn -0.790952 0.458498 -0.405185
-vn 0.73636 -0.370265 0.566284
-vn 0.774403 -0.284974 0.56488
-vn 0.807065 -0.0528624 0.588092
-vn 0.762189 0.337568 0.552373
-vn 0.681694 0.560847 0.469835
-vn 0.230259 0.956884 0.177071
-vn 0.0298419 0.999469 0.0130641
-vn -0.305102 0.924869 -0.227001
-vn -0.491309 0.792849 -0.360562
-vn -0.602597 0.665359 -0.440651
-vn -0.676646 0.545946 -0.494057
-vn -0.718006 0.458779 -0.52344
-vn 0.639538 -0.370321 0.673686
-vn 0.677298 -0.28505 0.678243
-vn 0.705975 -0.0528878 0.706259
-vn 0.667171 0.337622 0.663999
-vn 0.600378 0.560989 0.569946
-vn 0.199953 0.956898 0.21063
-vn 0.0274164 0.99947 0.0175297
-vn -0.266111 0.924899 -0.271564
-vn -0.429302 0.792925 -0.4324
-vn -0.526814 0.665457 -0.528804
-vn -0.591689 0.546049 -0.593072
-vn -0.627996 0.458878 -0.628532
-vn 0.527171 -0.370205 0.764879
-vn 0.563796 -0.284989 0.775188
-vn 0.587767 -0.0528874 0.8073
-vn 0.556014 0.337535 0.759552
-vn 0.504677 0.56092 0.656255
-vn 0.164864 0.956869 0.239211
-vn 0.024367 0.999471 0.0215501
-vn -0.220765 0.924861 -0.309669
-vn -0.357015 0.792847 -0.493897
-vn -0.438371 0.665361 -0.604256
-vn -0.492471 0.54595 -0.677798
-vn -0.522826 0.458782 -0.718451
-vn 0.401417 -0.369931 0.837864
-vn 0.436091 -0.284795 0.853649
-vn 0.454741 -0.0528598 0.889054
-vn 0.430862 0.337318 0.837003
-vn 0.396406 0.56065 0.727003
-vn 0.125616 0.956799 0.262214
-vn 0.020751 0.99947 0.0250646
-vn -0.169881 0.92476 -0.340529
-vn -0.275779 0.792622 -0.543779
-vn -0.338925 0.665078 -0.665433
-vn -0.380875 0.545659 -0.746452
-vn -0.404506 0.458504 -0.791296
-vn 0.264971 -0.369543 0.890634
-vn 0.296866 -0.2845 0.911554
-vn 0.30971 -0.0528059 0.949364
-vn 0.29433 0.337008 0.894313
-vn 0.277729 0.560217 0.7804
-vn 0.0829913 0.9567 0.278992
-vn 0.016619 0.99947 0.0280104
-vn -0.114469 0.924609 -0.363311
-vn -0.18722 0.792286 -0.580716
-vn -0.230498 0.664651 -0.710711
-vn -0.2592 0.545217 -0.797216
-vn -0.275488 0.458084 -0.845142
-vn 0.121427 -0.369157 0.9214
-vn 0.149673 -0.284179 0.947016
-vn 0.156389 -0.0527397 0.986286
-vn 0.149886 0.336692 0.929609
-vn 0.15151 0.559732 0.814705
-vn 0.0380637 0.956602 0.288901
-vn 0.0120314 0.999468 0.0303164
-vn -0.0558964 0.924453 -0.377178
-vn -0.0935342 0.791929 -0.603407
-vn -0.1158 0.664198 -0.738533
-vn -0.130511 0.544747 -0.828382
-vn -0.139039 0.457636 -0.878201
-vn -0.0243109 -0.369018 0.929104
-vn -0.000667711 -0.284002 0.958824
-vn -0.000200423 -0.0526791 0.998612
-vn 0.00224279 0.336556 0.941661
-vn 0.0216812 0.559416 0.828604
-vn -0.00762527 0.956567 0.291414
-vn 0.00706347 0.999466 0.0318944
-vn 0.00388852 0.924379 -0.381455
-vn 0.00220329 0.791748 -0.610844
-vn 0.00141167 0.663962 -0.747765
-vn 0.00097972 0.5445 -0.83876
-vn 0.000378475 0.457403 -0.88926
-vn -0.169918 -0.36928 0.913652
-vn -0.151008 -0.284151 0.946813
-vn -0.15679 -0.0526858 0.986226
-vn -0.145404 0.336757 0.930297
-vn -0.108278 0.559583 0.821671
-vn -0.0532517 0.956633 0.286386
-vn 0.00203668 0.999465 0.0326484
-vn 0.0636451 0.924469 -0.375907
-vn 0.0979288 0.791935 -0.602701
-vn 0.118618 0.664195 -0.738089
-vn 0.132468 0.544738 -0.828078
-vn 0.139795 0.45763 -0.878084
-vn -0.311536 -0.369687 0.875373
-vn -0.298153 -0.284467 0.911144
-vn -0.310096 -0.0527431 0.949241
-vn -0.29001 0.337087 0.895693
-vn -0.236235 0.560044 0.794068
-vn -0.097544 0.956737 0.274117
-vn -0.00314181 0.999466 0.0325301
-vn 0.121899 0.924628 -0.360837
-vn 0.191441 0.792293 -0.579329
-vn 0.233208 0.664647 -0.70983
-vn 0.261084 0.545206 -0.796609
-vn 0.276217 0.458076 -0.844909
-vn -0.444744 -0.370043 0.815641
-vn -0.437292 -0.28477 0.853043
-vn -0.455102 -0.0528108 0.888872
-vn -0.426832 0.337382 0.83904
-vn -0.35781 0.560516 0.746856
-vn -0.139138 0.956827 0.25519
-vn -0.00823025 0.999468 0.0315724
-vn 0.176789 0.924774 -0.336954
-vn 0.279707 0.792628 -0.541761
-vn 0.341449 0.665075 -0.664144
-vn 0.382631 0.545649 -0.74556
-vn 0.405185 0.458498 -0.790952
-vn -0.566284 -0.370265 0.73636
-vn -0.56488 -0.284974 0.774403
-vn -0.588092 -0.0528624 0.807065
-vn -0.552373 0.337568 0.762189
-vn -0.469835 0.560847 0.681694
-vn -0.177071 0.956884 0.230259
-vn -0.0130641 0.999469 0.0298419
-vn 0.227001 0.924869 -0.305102
-vn 0.360562 0.792849 -0.491309
-vn 0.440651 0.665359 -0.602597
-vn 0.494057 0.545946 -0.676646
-vn 0.52344 0.458779 -0.718006
-vn -0.673686 -0.370321 0.639538
-vn -0.678243 -0.28505 0.677298
-vn -0.706259 -0.0528878 0.705975
-vn -0.663999 0.337622 0.667171
-vn -0.569946 0.560989 0.600378
-vn -0.21063 0.956898 0.199953
-vn -0.0175297 0.99947 0.0274164
-vn 0.271564 0.924899 -0.266111
-vn 0.4324 0.792925 -0.429302
-vn 0.528804 0.665457 -0.526814
-vn 0.593072 0.546049 -0.591689
-vn 0.628532 0.458878 -0.627996
-vn -0.764879 -0.370205 0.527171
-vn -0.775188 -0.284989 0.563796
-vn -0.8073 -0.0528874 0.587767
-vn -0.759552 0.337535 0.556014
-vn -0.656255 0.56092 0.504677
-vn -0.239211 0.956869 0.164864
-vn -0.0215501 0.999471 0.024367
-vn 0.309669 0.924861 -0.220765
-vn 0.493897 0.792847 -0.357015
-vn 0.604256 0.665361 -0.438371
-vn 0.677798 0.54595 -0.492471
-vn 0.718451 0.458782 -0.522826
-vn -0.837864 -0.369931 0.401417
-vn -0.853649 -0.284795 0.436091
-vn -0.889054 -0.0528598 0.454741
-vn -0.837003 0.337318 0.430862
-vn -0.727003 0.56065 0.396406
-vn -0.262214 0.956799 0.125616
-vn -0.0250646 0.99947 0.020751
-vn 0.340529 0.92476 -0.169881
-vn 0.543779 0.792622 -0.275779
-vn 0.665433 0.665078 -0.338925
-vn 0.746452 0.545659 -0.380875
-vn 0.791296 0.458504 -0.404506
-vn -0.890634 -0.369543 0.264971
-vn -0.911554 -0.2845 0.296866
-vn -0.949364 -0.0528059 0.30971
-vn -0.894313 0.337008 0.29433
-vn -0.7804 0.560217 0.277729
-vn -0.278992 0.9567 0.0829913
-vn -0.0280104 0.99947 0.016619
-vn 0.363311 0.924609 -0.114469
-vn 0.580716 0.792286 -0.18722
-vn 0.710711 0.664651 -0.230498
-vn 0.797216 0.545217 -0.2592
-vn 0.845142 0.458084 -0.275488
-vn -0.9214 -0.369157 0.121427
-vn -0.947016 -0.284179 0.149673
-vn -0.986286 -0.0527396 0.156389
-vn -0.929609 0.336692 0.149886
-vn -0.814705 0.559732 0.15151
-vn -0.288901 0.956602 0.0380637
-vn -0.0303164 0.999468 0.0120314
-vn 0.377178 0.924453 -0.0558964
-vn 0.603407 0.791929 -0.0935343
-vn 0.738534 0.664198 -0.1158
-vn 0.828382 0.544747 -0.130511
-vn 0.878201 0.457636 -0.139039
-vn 0.89347 0.426121 0.141889
-vn 0.904771 0.425899 3.71926e-05
-vn 0.897706 0.416881 0.1426
-vn 0.909061 0.416663 7.41231e-05
-vn 0.904725 0.401008 0.143752
-vn 0.916168 0.400795 0.000110028
-vn 0.914429 0.377757 0.145328
-vn 0.925988 0.377552 0.000144216
-vn 0.926588 0.346034 0.14729
-vn 0.938292 0.345843 0.000174322
-vn 0.940745 0.304354 0.149561
-vn 0.952614 0.304181 0.00019566
-vn 0.956027 0.25082 0.151995
-vn 0.968071 0.250675 0.000202063
-vn 0.970884 0.183208 0.154335
-vn 0.983094 0.183102 0.000182465
-vn 0.982735 0.0992358 0.156154
-vn 0.995069 0.0991819 0.000120215
-vn 0.987252 -0.0279632 0.156688
-vn 0.99961 -0.0279302 -5.86337e-05
-vn 0.859797 0.426553 0.280715
-vn 0.863876 0.417308 0.282086
-vn 0.87064 0.401424 0.284333
-vn 0.879991 0.378157 0.287423
-vn 0.891714 0.346411 0.291283
-vn 0.905365 0.304694 0.295764
-vn 0.920105 0.25111 0.300584
-vn 0.934441 0.183425 0.305245
-vn 0.945886 0.0993553 0.308916
-vn 0.950273 -0.0279998 0.310158
-vn 0.804973 0.42696 0.411976
-vn 0.808793 0.41771 0.413972
-vn 0.815131 0.401817 0.417257
-vn 0.823899 0.378536 0.421783
-vn 0.834892 0.346767 0.427444
-vn 0.847696 0.305017 0.434022
-vn 0.861527 0.251385 0.441108
-vn 0.874985 0.183633 0.447974
-vn 0.885736 0.0994717 0.453407
-vn 0.889883 -0.0280281 0.455327
-vn 0.73081 0.42723 0.532344
-vn 0.734276 0.417977 0.534915
-vn 0.740032 0.402079 0.539152
-vn 0.747997 0.378788 0.544996
-vn 0.757986 0.347004 0.552309
-vn 0.769624 0.305233 0.560814
-vn 0.782198 0.251569 0.569982
-vn 0.794439 0.183774 0.578873
-vn 0.804226 0.0995515 0.585926
-vn 0.80803 -0.0280428 0.588474
-vn 0.639268 0.427324 0.639321
-vn 0.642294 0.41807 0.642399
-vn 0.647325 0.402169 0.64748
-vn 0.654288 0.378875 0.654492
-vn 0.663025 0.347088 0.663271
-vn 0.673207 0.305309 0.673483
-vn 0.684211 0.251634 0.684497
-vn 0.694928 0.183824 0.695186
-vn 0.703507 0.099582 0.703677
-vn 0.70687 -0.0280428 0.706787
-vn 0.532284 0.427231 0.730854
-vn 0.534794 0.417978 0.734363
-vn 0.538974 0.40208 0.740162
-vn 0.544762 0.37879 0.748166
-vn 0.552027 0.347007 0.75819
-vn 0.560496 0.305237 0.769854
-vn 0.569653 0.251574 0.782436
-vn 0.578577 0.18378 0.794653
-vn 0.585731 0.09956 0.804368
-vn 0.58857 -0.0280278 0.807961
-vn 0.411909 0.42696 0.805007
-vn 0.413839 0.417711 0.80886
-vn 0.41706 0.401819 0.815232
-vn 0.421524 0.378539 0.82403
-vn 0.427131 0.346772 0.83505
-vn 0.43367 0.305024 0.847874
-vn 0.440745 0.251394 0.861711
-vn 0.447646 0.183645 0.87515
-vn 0.45319 0.0994879 0.885845
-vn 0.455432 -0.0279999 0.88983
-vn 0.280644 0.426553 0.859821
-vn 0.281944 0.41731 0.863922
-vn 0.284121 0.401427 0.870707
-vn 0.287146 0.378161 0.88008
-vn 0.290948 0.346417 0.891821
-vn 0.295387 0.304703 0.905485
-vn 0.300194 0.251122 0.920229
-vn 0.304893 0.183441 0.934553
-vn 0.308684 0.0993756 0.945959
-vn 0.310271 -0.0279634 0.950237
-vn 0.141815 0.426121 0.893481
-vn 0.142452 0.416883 0.897728
-vn 0.143532 0.40101 0.904759
-vn 0.14504 0.377761 0.914473
-vn 0.146942 0.34604 0.926642
-vn 0.14917 0.304361 0.940804
-vn 0.151591 0.25083 0.956088
-vn 0.15397 0.183222 0.970939
-vn 0.155914 0.0992528 0.982771
-vn 0.156806 -0.0279319 0.987234
-vn -3.7276e-05 0.425899 0.904771
-vn -7.42164e-05 0.416663 0.909061
-vn -0.000110111 0.400795 0.916168
-vn -0.000144304 0.377552 0.925988
-vn -0.000174411 0.345843 0.938292
-vn -0.000195755 0.304181 0.952614
-vn -0.000202161 0.250675 0.968071
-vn -0.000182558 0.183102 0.983094
-vn -0.000120307 0.0991819 0.995069
-vn 5.85412e-05 -0.0279302 0.99961
-vn -0.141889 0.426121 0.89347
-vn -0.1426 0.416881 0.897705
-vn -0.143752 0.401008 0.904725
-vn -0.145328 0.377756 0.914429
-vn -0.14729 0.346034 0.926588
-vn -0.149561 0.304354 0.940745
-vn -0.151995 0.25082 0.956027
-vn -0.154335 0.183208 0.970884
-vn -0.156154 0.0992358 0.982735
-vn -0.156688 -0.0279632 0.987252
-vn -0.280715 0.426553 0.859797
-vn -0.282086 0.417308 0.863876
-vn -0.284333 0.401424 0.87064
-vn -0.287423 0.378157 0.879991
-vn -0.291283 0.346411 0.891714
-vn -0.295764 0.304694 0.905365
-vn -0.300584 0.25111 0.920105
-vn -0.305245 0.183425 0.934441
-vn -0.308916 0.0993553 0.945886
-vn -0.310158 -0.0279998 0.950273
-vn -0.411976 0.42696 0.804973
-vn -0.413972 0.41771 0.808793
-vn -0.417258 0.401817 0.815131
-vn -0.421784 0.378536 0.823899
-vn -0.427444 0.346767 0.834892
-vn -0.434022 0.305018 0.847696
-vn -0.441108 0.251385 0.861527
-vn -0.447974 0.183633 0.874985
-vn -0.453407 0.0994716 0.885736
-vn -0.455327 -0.0280281 0.889883
-vn -0.532344 0.42723 0.73081
-vn -0.534915 0.417977 0.734276
-vn -0.539152 0.402079 0.740032
-vn -0.544996 0.378788 0.747997
-vn -0.55231 0.347004 0.757986
-vn -0.560814 0.305233 0.769624
-vn -0.569982 0.251569 0.782198
-vn -0.578874 0.183774 0.794439
-vn -0.585926 0.0995515 0.804226
-vn -0.588474 -0.0280428 0.80803
-vn -0.639321 0.427324 0.639268
-vn -0.642399 0.41807 0.642294
-vn -0.64748 0.402169 0.647325
-vn -0.654492 0.378875 0.654288
-vn -0.663271 0.347088 0.663025
-vn -0.673483 0.305309 0.673207
-vn -0.684497 0.251634 0.684211
-vn -0.695186 0.183824 0.694928
-vn -0.703677 0.099582 0.703507
-vn -0.706787 -0.0280428 0.70687
-vn -0.730854 0.427231 0.532284
-vn -0.734363 0.417978 0.534794
-vn -0.740162 0.40208 0.538974
-vn -0.748166 0.378789 0.544762
-vn -0.75819 0.347007 0.552027
-vn -0.769854 0.305237 0.560496
-vn -0.782436 0.251574 0.569653
-vn -0.794653 0.18378 0.578577
-vn -0.804368 0.09956 0.585731
-vn -0.807961 -0.0280278 0.58857
-vn -0.805007 0.42696 0.411909
-vn -0.80886 0.417711 0.413839
-vn -0.815232 0.401819 0.41706
-vn -0.82403 0.378539 0.421524
-vn -0.83505 0.346772 0.427131
-vn -0.847874 0.305024 0.43367
-vn -0.861711 0.251394 0.440744
-vn -0.87515 0.183646 0.447646
-vn -0.885845 0.099488 0.45319
-vn -0.88983 -0.0279999 0.455432
-vn -0.859821 0.426553 0.280644
-vn -0.863922 0.417309 0.281943
-vn -0.870708 0.401427 0.284121
-vn -0.88008 0.378161 0.287146
-vn -0.891821 0.346417 0.290948
-vn -0.905485 0.304703 0.295387
-vn -0.920229 0.251122 0.300194
-vn -0.934553 0.183441 0.304893
-vn -0.945959 0.0993756 0.308684
-vn -0.950237 -0.0279634 0.310271
-vn -0.893481 0.426121 0.141815
-vn -0.897728 0.416883 0.142452
-vn -0.904759 0.40101 0.143532
-vn -0.914473 0.377761 0.14504
-vn -0.926642 0.346039 0.146942
-vn -0.940804 0.304361 0.14917
-vn -0.956088 0.25083 0.151591
-vn -0.970939 0.183222 0.15397
-vn -0.982771 0.0992528 0.155914
-vn -0.987234 -0.0279319 0.156806
-vn -0.904771 0.425899 -3.7281e-05
-vn -0.909061 0.416663 -7.42127e-05
-vn -0.916168 0.400795 -0.000110111
-vn -0.925988 0.377552 -0.000144304
-vn -0.938292 0.345843 -0.000174413
-vn -0.952614 0.304181 -0.00019575
-vn -0.968071 0.250675 -0.000202162
-vn -0.983094 0.183102 -0.000182558
-vn -0.995069 0.0991819 -0.000120306
-vn -0.99961 -0.0279302 5.854e-05
-vn -0.89347 0.426121 -0.141889
-vn -0.897706 0.416881 -0.1426
-vn -0.904725 0.401008 -0.143752
-vn -0.914429 0.377756 -0.145328
-vn -0.926588 0.346034 -0.14729
-vn -0.940745 0.304354 -0.149561
-vn -0.956027 0.25082 -0.151995
-vn -0.970884 0.183208 -0.154335
-vn -0.982735 0.0992358 -0.156154
-vn -0.987252 -0.0279632 -0.156688
-vn -0.859797 0.426553 -0.280715
-vn -0.863876 0.417308 -0.282086
-vn -0.87064 0.401424 -0.284333
-vn -0.879991 0.378157 -0.287423
-vn -0.891714 0.346411 -0.291283
-vn -0.905365 0.304694 -0.295764
-vn -0.920105 0.25111 -0.300584
-vn -0.934441 0.183425 -0.305245
-vn -0.945886 0.0993553 -0.308916
-vn -0.950273 -0.0279998 -0.310158
-vn -0.804973 0.42696 -0.411976
-vn -0.808793 0.41771 -0.413972
-vn -0.815131 0.401817 -0.417257
-vn -0.823899 0.378536 -0.421783
-vn -0.834892 0.346767 -0.427444
-vn -0.847696 0.305017 -0.434022
-vn -0.861527 0.251385 -0.441108
-vn -0.874985 0.183633 -0.447974
-vn -0.885736 0.0994715 -0.453407
-vn -0.889883 -0.0280281 -0.455327
-vn -0.73081 0.42723 -0.532344
-vn -0.734276 0.417977 -0.534915
-vn -0.740032 0.402079 -0.539152
-vn -0.747997 0.378788 -0.544996
-vn -0.757986 0.347004 -0.55231
-vn -0.769624 0.305233 -0.560814
-vn -0.782198 0.251569 -0.569982
-vn -0.794439 0.183773 -0.578873
-vn -0.804227 0.0995512 -0.585926
-vn -0.80803 -0.0280428 -0.588474
-vn -0.639268 0.427324 -0.639321
-vn -0.642294 0.41807 -0.642399
-vn -0.647325 0.402169 -0.64748
-vn -0.654288 0.378875 -0.654492
-vn -0.663025 0.347087 -0.663271
-vn -0.673207 0.305308 -0.673483
-vn -0.684211 0.251634 -0.684497
-vn -0.694928 0.183824 -0.695186
-vn -0.703507 0.0995815 -0.703677
-vn -0.70687 -0.0280428 -0.706787
-vn -0.532284 0.427231 -0.730854
-vn -0.534794 0.417978 -0.734363
-vn -0.538974 0.40208 -0.740162
-vn -0.544762 0.378789 -0.748166
-vn -0.552027 0.347007 -0.75819
-vn -0.560496 0.305237 -0.769854
-vn -0.569653 0.251574 -0.782436
-vn -0.578577 0.18378 -0.794653
-vn -0.585731 0.0995595 -0.804368
-vn -0.58857 -0.0280279 -0.807961
-vn -0.411909 0.42696 -0.805007
-vn -0.413839 0.417711 -0.80886
-vn -0.417059 0.401819 -0.815232
-vn -0.421524 0.378539 -0.82403
-vn -0.427131 0.346771 -0.83505
-vn -0.43367 0.305024 -0.847874
-vn -0.440744 0.251394 -0.861711
-vn -0.447646 0.183645 -0.87515
-vn -0.45319 0.0994877 -0.885845
-vn -0.455432 -0.0279999 -0.88983
-vn -0.280643 0.426553 -0.859821
-vn -0.281943 0.417309 -0.863922
-vn -0.284121 0.401427 -0.870708
-vn -0.287146 0.378161 -0.88008
-vn -0.290948 0.346417 -0.891821
-vn -0.295387 0.304703 -0.905485
-vn -0.300194 0.251122 -0.920229
-vn -0.304893 0.183441 -0.934553
-vn -0.308684 0.0993756 -0.945959
-vn -0.310271 -0.0279634 -0.950237
-vn -0.141815 0.426121 -0.893482
-vn -0.142452 0.416883 -0.897729
-vn -0.143532 0.40101 -0.904759
-vn -0.14504 0.377761 -0.914473
-vn -0.146942 0.346039 -0.926642
-vn -0.14917 0.30436 -0.940804
-vn -0.151591 0.25083 -0.956088
-vn -0.15397 0.183222 -0.970939
-vn -0.155914 0.0992528 -0.982771
-vn -0.156806 -0.0279319 -0.987234
-vn 3.7281e-05 0.425899 -0.904771
-vn 7.4209e-05 0.416662 -0.909061
-vn 0.000110111 0.400795 -0.916168
-vn 0.000144304 0.377552 -0.925988
-vn 0.000174409 0.345843 -0.938292
-vn 0.00019575 0.304181 -0.952614
-vn 0.000202161 0.250675 -0.968071
-vn 0.000182558 0.183102 -0.983094
-vn 0.000120307 0.0991819 -0.995069
-vn -5.85412e-05 -0.0279302 -0.99961
-vn 0.141889 0.42612 -0.89347
-vn 0.1426 0.416881 -0.897706
-vn 0.143752 0.401008 -0.904725
-vn 0.145328 0.377756 -0.914429
-vn 0.14729 0.346034 -0.926588
-vn 0.149561 0.304353 -0.940745
-vn 0.151995 0.25082 -0.956027
-vn 0.154335 0.183208 -0.970884
-vn 0.156154 0.0992358 -0.982735
-vn 0.156688 -0.0279632 -0.987252
-vn 0.280715 0.426553 -0.859797
-vn 0.282086 0.417307 -0.863876
-vn 0.284333 0.401424 -0.87064
-vn 0.287423 0.378157 -0.879991
-vn 0.291283 0.346411 -0.891714
-vn 0.295764 0.304694 -0.905365
-vn 0.300584 0.25111 -0.920105
-vn 0.305245 0.183425 -0.934441
-vn 0.308916 0.0993553 -0.945886
-vn 0.310158 -0.0279998 -0.950273
-vn 0.411976 0.42696 -0.804973
-vn 0.413972 0.41771 -0.808793
-vn 0.417257 0.401817 -0.815131
-vn 0.421783 0.378535 -0.823899
-vn 0.427444 0.346766 -0.834892
-vn 0.434022 0.305017 -0.847696
-vn 0.441108 0.251385 -0.861527
-vn 0.447974 0.183633 -0.874985
-vn 0.453407 0.0994716 -0.885736
-vn 0.455327 -0.0280281 -0.889883
-vn 0.532344 0.42723 -0.73081
-vn 0.534915 0.417977 -0.734276
-vn 0.539152 0.402079 -0.740032
-vn 0.544996 0.378788 -0.747997
-vn 0.552309 0.347004 -0.757986
-vn 0.560814 0.305233 -0.769624
-vn 0.569982 0.251569 -0.782198
-vn 0.578873 0.183773 -0.794439
-vn 0.585926 0.0995512 -0.804227
-vn 0.588474 -0.0280428 -0.80803
-vn 0.639321 0.427324 -0.639268
-vn 0.642399 0.41807 -0.642294
-vn 0.64748 0.402169 -0.647325
-vn 0.654492 0.378875 -0.654288
-vn 0.663271 0.347087 -0.663025
-vn 0.673483 0.305308 -0.673207
-vn 0.684497 0.251634 -0.684211
-vn 0.695186 0.183824 -0.694928
-vn 0.703677 0.0995815 -0.703507
-vn 0.706787 -0.0280428 -0.70687
-vn 0.730854 0.427231 -0.532284
-vn 0.734363 0.417978 -0.534794
-vn 0.740162 0.40208 -0.538974
-vn 0.748166 0.378789 -0.544762
-vn 0.75819 0.347007 -0.552027
-vn 0.769854 0.305237 -0.560496
-vn 0.782436 0.251574 -0.569653
-vn 0.794653 0.18378 -0.578577
-vn 0.804368 0.0995596 -0.585731
-vn 0.807961 -0.0280278 -0.58857
-vn 0.805007 0.42696 -0.411909
-vn 0.80886 0.417711 -0.413839
-vn 0.815232 0.401819 -0.41706
-vn 0.82403 0.378539 -0.421524
-vn 0.83505 0.346772 -0.427131
-vn 0.847874 0.305024 -0.43367
-vn 0.861711 0.251394 -0.440744
-vn 0.87515 0.183645 -0.447646
-vn 0.885845 0.0994877 -0.45319
-vn 0.88983 -0.0279999 -0.455432
-vn 0.859821 0.426553 -0.280644
-vn 0.863922 0.417309 -0.281943
-vn 0.870708 0.401427 -0.284121
-vn 0.88008 0.378161 -0.287146
-vn 0.891821 0.346417 -0.290948
-vn 0.905485 0.304703 -0.295387
-vn 0.920229 0.251122 -0.300194
-vn 0.934553 0.183441 -0.304893
-vn 0.945959 0.0993756 -0.308684
-vn 0.950237 -0.0279634 -0.310271
-vn 0.893481 0.426121 -0.141815
-vn 0.897728 0.416883 -0.142452
-vn 0.904759 0.40101 -0.143532
-vn 0.914473 0.377761 -0.14504
-vn 0.926642 0.346039 -0.146942
-vn 0.940804 0.30436 -0.14917
-vn 0.956088 0.25083 -0.151591
-vn 0.970939 0.183222 -0.15397
-vn 0.982771 0.0992528 -0.155914
-vn 0.987234 -0.0279319 -0.156806
-vn 0.965253 -0.212087 0.152659
-vn 0.977282 -0.211943 -0.00058016
-vn 0.906267 -0.397811 0.142923
-vn 0.917563 -0.39759 -0.000938314
-vn 0.830534 -0.54139 0.130808
-vn 0.840929 -0.541144 -0.00102321
-vn 0.75593 -0.643734 0.119065
-vn 0.765451 -0.643493 -0.000919955
-vn 0.693417 -0.712191 0.109348
-vn 0.702211 -0.711968 -0.000714335
-vn 0.649475 -0.753421 0.102656
-vn 0.657775 -0.753215 -0.00043602
-vn 0.631423 -0.768938 0.1002
-vn 0.639565 -0.768737 -3.50034e-05
-vn 0.657461 -0.746122 0.105101
-vn 0.666044 -0.745912 0.000714849
-vn 0.79084 -0.598448 0.128191
-vn 0.801327 -0.598221 0.00257989
-vn 0.938283 -0.311497 0.150313
-vn 0.950295 -0.311349 0.00131292
-vn 0.929132 -0.212336 0.302699
-vn 0.872297 -0.398221 0.283759
-vn 0.799297 -0.54186 0.259833
-vn 0.727389 -0.644197 0.236465
-vn 0.667137 -0.712624 0.217012
-vn 0.624777 -0.753824 0.203478
-vn 0.607331 -0.769326 0.198208
-vn 0.632282 -0.746531 0.207149
-vn 0.760479 -0.598896 0.250987
-vn 0.902818 -0.311823 0.29612
-vn 0.87013 -0.212562 0.444626
-vn 0.816862 -0.398602 0.416957
-vn 0.74841 -0.5423 0.381828
-vn 0.680981 -0.644634 0.347436
-vn 0.624484 -0.713032 0.318755
-vn 0.584752 -0.754203 0.298735
-vn 0.568344 -0.769692 0.29079
-vn 0.591595 -0.746915 0.303534
-vn 0.711438 -0.599321 0.366974
-vn 0.845126 -0.312141 0.433969
-vn 0.790158 -0.212708 0.574809
-vn 0.74179 -0.398853 0.539133
-vn 0.679585 -0.542592 0.493719
-vn 0.618295 -0.644923 0.449204
-vn 0.566935 -0.713303 0.412049
-vn 0.530798 -0.754455 0.386071
-vn 0.515827 -0.769935 0.375662
-vn 0.536816 -0.74717 0.391875
-vn 0.645372 -0.599604 0.473254
-vn 0.767094 -0.312358 0.560356
-vn 0.691329 -0.212752 0.690507
-vn 0.649065 -0.398936 0.647738
-vn 0.594644 -0.542691 0.593199
-vn 0.540994 -0.645022 0.539696
-vn 0.496016 -0.713396 0.49501
-vn 0.464347 -0.754542 0.463733
-vn 0.451174 -0.770019 0.451124
-vn 0.469394 -0.747257 0.4704
-vn 0.564019 -0.599705 0.567659
-vn 0.670777 -0.312441 0.672635
-vn 0.575752 -0.21269 0.789476
-vn 0.540655 -0.398842 0.740686
-vn 0.495376 -0.542587 0.678382
-vn 0.450691 -0.644922 0.617214
-vn 0.413201 -0.713303 0.566095
-vn 0.386774 -0.754455 0.530286
-vn 0.375718 -0.769936 0.515786
-vn 0.390722 -0.747169 0.537656
-vn 0.469082 -0.599612 0.648404
-vn 0.558224 -0.312379 0.768638
-vn 0.44567 -0.212529 0.869603
-vn 0.418643 -0.398582 0.816009
-vn 0.383662 -0.542291 0.747478
-vn 0.349082 -0.644631 0.680141
-vn 0.320031 -0.713032 0.623831
-vn 0.299514 -0.754204 0.584353
-vn 0.290853 -0.769692 0.568312
-vn 0.302258 -0.746914 0.592249
-vn 0.362355 -0.599334 0.713791
-vn 0.43161 -0.312182 0.846319
-vn 0.303818 -0.212294 0.928776
-vn 0.285566 -0.398195 0.871718
-vn 0.261801 -0.541848 0.798662
-vn 0.238232 -0.644194 0.726815
-vn 0.218383 -0.712625 0.66669
-vn 0.204314 -0.753825 0.624503
-vn 0.198275 -0.769327 0.607309
-vn 0.205779 -0.746529 0.632732
-vn 0.24603 -0.598913 0.762085
-vn 0.29359 -0.311878 0.903625
-vn 0.15382 -0.212051 0.965077
-vn 0.144799 -0.397789 0.905979
-vn 0.132852 -0.54138 0.830215
-vn 0.120902 -0.64373 0.755641
-vn 0.110774 -0.712192 0.69319
-vn 0.103526 -0.753422 0.649336
-vn 0.100269 -0.768938 0.631412
-vn 0.103675 -0.746121 0.657689
-vn 0.123039 -0.598461 0.791647
-vn 0.147689 -0.311544 0.938684
-vn 0.000580066 -0.211943 0.977282
-vn 0.000938226 -0.397589 0.917563
-vn 0.00102313 -0.541144 0.840929
-vn 0.000919885 -0.643493 0.765451
-vn 0.000714321 -0.711968 0.702211
-vn 0.000436013 -0.753215 0.657775
-vn 3.49512e-05 -0.768737 0.639565
-vn -0.000714901 -0.745912 0.666044
-vn -0.00257994 -0.598221 0.801327
-vn -0.00131299 -0.311349 0.950295
-vn -0.152659 -0.212087 0.965253
-vn -0.142923 -0.397811 0.906267
-vn -0.130808 -0.54139 0.830534
-vn -0.119065 -0.643734 0.75593
-vn -0.109348 -0.712191 0.693417
-vn -0.102656 -0.753422 0.649475
-vn -0.1002 -0.768938 0.631423
-vn -0.105101 -0.746122 0.657461
-vn -0.128191 -0.598447 0.79084
-vn -0.150313 -0.311497 0.938283
-vn -0.302699 -0.212336 0.929132
-vn -0.283759 -0.398221 0.872297
-vn -0.259833 -0.54186 0.799297
-vn -0.236465 -0.644197 0.727389
-vn -0.217012 -0.712624 0.667137
-vn -0.203478 -0.753824 0.624777
-vn -0.198208 -0.769326 0.607331
-vn -0.207149 -0.746531 0.632282
-vn -0.250987 -0.598896 0.760479
-vn -0.29612 -0.311823 0.902818
-vn -0.444626 -0.212562 0.87013
-vn -0.416957 -0.398602 0.816862
-vn -0.381828 -0.5423 0.74841
-vn -0.347436 -0.644634 0.680981
-vn -0.318755 -0.713032 0.624484
-vn -0.298735 -0.754203 0.584752
-vn -0.29079 -0.769692 0.568344
-vn -0.303534 -0.746915 0.591595
-vn -0.366974 -0.599321 0.711439
-vn -0.433969 -0.312141 0.845126
-vn -0.574809 -0.212708 0.790158
-vn -0.539133 -0.398853 0.74179
-vn -0.493719 -0.542592 0.679585
-vn -0.449204 -0.644923 0.618296
-vn -0.412049 -0.713303 0.566935
-vn -0.386071 -0.754455 0.530798
-vn -0.375661 -0.769935 0.515827
-vn -0.391874 -0.74717 0.536816
-vn -0.473254 -0.599604 0.645372
-vn -0.560356 -0.312358 0.767094
-vn -0.690507 -0.212752 0.691329
-vn -0.647738 -0.398936 0.649065
-vn -0.593199 -0.542691 0.594644
-vn -0.539696 -0.645022 0.540994
-vn -0.49501 -0.713396 0.496016
-vn -0.463733 -0.754542 0.464347
-vn -0.451124 -0.770019 0.451174
-vn -0.4704 -0.747257 0.469394
-vn -0.567659 -0.599705 0.564019
-vn -0.672635 -0.312441 0.670777
-vn -0.789476 -0.21269 0.575752
-vn -0.740686 -0.398842 0.540656
-vn -0.678382 -0.542587 0.495376
-vn -0.617214 -0.644922 0.450691
-vn -0.566095 -0.713303 0.413201
-vn -0.530286 -0.754456 0.386774
-vn -0.515786 -0.769936 0.375718
-vn -0.537656 -0.747169 0.390722
-vn -0.648404 -0.599611 0.469082
-vn -0.768638 -0.312379 0.558224
-vn -0.869603 -0.212529 0.44567
-vn -0.816009 -0.398582 0.418643
-vn -0.747478 -0.542291 0.383662
-vn -0.680141 -0.644631 0.349082
-vn -0.623831 -0.713032 0.320031
-vn -0.584353 -0.754204 0.299514
-vn -0.568312 -0.769692 0.290853
-vn -0.592249 -0.746914 0.302258
-vn -0.713791 -0.599334 0.362355
-vn -0.846319 -0.312182 0.43161
-vn -0.928776 -0.212294 0.303818
-vn -0.871718 -0.398195 0.285567
-vn -0.798662 -0.541848 0.261801
-vn -0.726815 -0.644194 0.238232
-vn -0.66669 -0.712625 0.218383
-vn -0.624503 -0.753825 0.204314
-vn -0.607309 -0.769327 0.198275
-vn -0.632731 -0.746529 0.205779
-vn -0.762085 -0.598913 0.24603
-vn -0.903625 -0.311878 0.29359
-vn -0.965077 -0.212051 0.15382
-vn -0.905979 -0.397789 0.144799
-vn -0.830215 -0.541381 0.132852
-vn -0.755641 -0.643731 0.120902
-vn -0.69319 -0.712192 0.110774
-vn -0.649336 -0.753422 0.103526
-vn -0.631412 -0.768938 0.10027
-vn -0.657689 -0.746121 0.103675
-vn -0.791647 -0.598461 0.123039
-vn -0.938684 -0.311544 0.147689
-vn -0.977282 -0.211943 0.000580064
-vn -0.917563 -0.397589 0.000938223
-vn -0.840929 -0.541144 0.00102313
-vn -0.765451 -0.643493 0.000919899
-vn -0.702211 -0.711968 0.000714369
-vn -0.657775 -0.753215 0.000436068
-vn -0.639565 -0.768737 3.49661e-05
-vn -0.666044 -0.745912 -0.000714905
-vn -0.801327 -0.598221 -0.00257992
-vn -0.950295 -0.311349 -0.00131298
-vn -0.965253 -0.212087 -0.152659
-vn -0.906267 -0.397811 -0.142923
-vn -0.830534 -0.54139 -0.130807
-vn -0.75593 -0.643734 -0.119065
-vn -0.693417 -0.712191 -0.109348
-vn -0.649475 -0.753422 -0.102656
-vn -0.631423 -0.768938 -0.1002
-vn -0.657461 -0.746122 -0.105101
-vn -0.79084 -0.598447 -0.128191
-vn -0.938283 -0.311497 -0.150313
-vn -0.929132 -0.212336 -0.302699
-vn -0.872297 -0.398221 -0.283759
-vn -0.799297 -0.54186 -0.259833
-vn -0.727389 -0.644197 -0.236465
-vn -0.667137 -0.712625 -0.217012
-vn -0.624777 -0.753824 -0.203478
-vn -0.607331 -0.769327 -0.198208
-vn -0.632282 -0.746531 -0.207149
-vn -0.760479 -0.598896 -0.250987
-vn -0.902818 -0.311823 -0.29612
-vn -0.87013 -0.212562 -0.444626
-vn -0.816862 -0.398602 -0.416957
-vn -0.74841 -0.5423 -0.381828
-vn -0.680981 -0.644634 -0.347436
-vn -0.624484 -0.713032 -0.318755
-vn -0.584752 -0.754203 -0.298735
-vn -0.568344 -0.769692 -0.29079
-vn -0.591595 -0.746915 -0.303534
-vn -0.711439 -0.599321 -0.366974
-vn -0.845126 -0.312141 -0.433969
-vn -0.790158 -0.212708 -0.574809
-vn -0.74179 -0.398853 -0.539133
-vn -0.679585 -0.542592 -0.493719
-vn -0.618295 -0.644923 -0.449204
-vn -0.566935 -0.713303 -0.412049
-vn -0.530798 -0.754455 -0.386071
-vn -0.515827 -0.769935 -0.375661
-vn -0.536816 -0.74717 -0.391874
-vn -0.645372 -0.599604 -0.473254
-vn -0.767094 -0.312358 -0.560356
-vn -0.691329 -0.212752 -0.690507
-vn -0.649065 -0.398936 -0.647738
-vn -0.594644 -0.542691 -0.593199
-vn -0.540994 -0.645022 -0.539696
-vn -0.496016 -0.713396 -0.49501
-vn -0.464347 -0.754542 -0.463733
-vn -0.451174 -0.770019 -0.451124
-vn -0.469394 -0.747257 -0.4704
-vn -0.564019 -0.599705 -0.567658
-vn -0.670777 -0.312441 -0.672635
-vn -0.575752 -0.21269 -0.789476
-vn -0.540656 -0.398842 -0.740686
-vn -0.495376 -0.542587 -0.678382
-vn -0.450691 -0.644922 -0.617214
-vn -0.413201 -0.713303 -0.566095
-vn -0.386774 -0.754456 -0.530285
-vn -0.375718 -0.769936 -0.515786
-vn -0.390722 -0.747169 -0.537656
-vn -0.469082 -0.599612 -0.648404
-vn -0.558224 -0.312379 -0.768638
-vn -0.44567 -0.212529 -0.869603
-vn -0.418643 -0.398582 -0.816009
-vn -0.383663 -0.542291 -0.747478
-vn -0.349082 -0.644632 -0.680141
-vn -0.320031 -0.713032 -0.623831
-vn -0.299514 -0.754204 -0.584353
-vn -0.290853 -0.769692 -0.568312
-vn -0.302258 -0.746914 -0.592249
-vn -0.362355 -0.599334 -0.713791
-vn -0.43161 -0.312182 -0.846319
-vn -0.303818 -0.212294 -0.928776
-vn -0.285566 -0.398195 -0.871718
-vn -0.261801 -0.541848 -0.798662
-vn -0.238232 -0.644194 -0.726815
-vn -0.218383 -0.712625 -0.66669
-vn -0.204314 -0.753825 -0.624503
-vn -0.198275 -0.769327 -0.607309
-vn -0.205779 -0.746529 -0.632731
-vn -0.24603 -0.598913 -0.762085
-vn -0.29359 -0.311878 -0.903625
-vn -0.15382 -0.212051 -0.965077
-vn -0.144799 -0.397789 -0.905979
-vn -0.132852 -0.54138 -0.830215
-vn -0.120902 -0.643731 -0.755641
-vn -0.110774 -0.712192 -0.69319
-vn -0.103526 -0.753422 -0.649336
-vn -0.100269 -0.768938 -0.631412
-vn -0.103675 -0.746121 -0.657689
-vn -0.123039 -0.598461 -0.791647
-vn -0.147689 -0.311544 -0.938684
-vn -0.000580066 -0.211943 -0.977282
-vn -0.000938226 -0.397589 -0.917563
-vn -0.00102313 -0.541144 -0.840929
-vn -0.00091989 -0.643493 -0.765451
-vn -0.000714318 -0.711968 -0.702211
-vn -0.000436013 -0.753215 -0.657775
-vn -3.49512e-05 -0.768737 -0.639565
-vn 0.00071491 -0.745912 -0.666044
-vn 0.00257995 -0.598221 -0.801327
-vn 0.00131299 -0.311349 -0.950295
-vn 0.152659 -0.212087 -0.965253
-vn 0.142923 -0.397811 -0.906267
-vn 0.130808 -0.54139 -0.830534
-vn 0.119065 -0.643734 -0.75593
-vn 0.109348 -0.712191 -0.693417
-vn 0.102656 -0.753422 -0.649475
-vn 0.1002 -0.768938 -0.631423
-vn 0.105101 -0.746122 -0.657461
-vn 0.128191 -0.598448 -0.79084
-vn 0.150313 -0.311497 -0.938283
-vn 0.302699 -0.212336 -0.929132
-vn 0.283759 -0.398221 -0.872297
-vn 0.259833 -0.54186 -0.799297
-vn 0.236465 -0.644197 -0.727389
-vn 0.217012 -0.712625 -0.667137
-vn 0.203478 -0.753824 -0.624777
-vn 0.198208 -0.769326 -0.607331
-vn 0.207149 -0.746531 -0.632282
-vn 0.250987 -0.598896 -0.760479
-vn 0.29612 -0.311823 -0.902818
-vn 0.444626 -0.212562 -0.87013
-vn 0.416957 -0.398602 -0.816862
-vn 0.381828 -0.5423 -0.74841
-vn 0.347436 -0.644634 -0.680981
-vn 0.318755 -0.713032 -0.624484
-vn 0.298735 -0.754203 -0.584752
-vn 0.29079 -0.769692 -0.568344
-vn 0.303534 -0.746915 -0.591595
-vn 0.366974 -0.599321 -0.711438
-vn 0.433969 -0.312141 -0.845126
-vn 0.574809 -0.212708 -0.790158
-vn 0.539133 -0.398853 -0.74179
-vn 0.493719 -0.542592 -0.679585
-vn 0.449204 -0.644923 -0.618295
-vn 0.412049 -0.713303 -0.566935
-vn 0.386071 -0.754455 -0.530798
-vn 0.375662 -0.769935 -0.515827
-vn 0.391875 -0.74717 -0.536816
-vn 0.473254 -0.599604 -0.645372
-vn 0.560356 -0.312358 -0.767094
-vn 0.690507 -0.212752 -0.691329
-vn 0.647738 -0.398936 -0.649065
-vn 0.593199 -0.542691 -0.594644
-vn 0.539696 -0.645022 -0.540994
-vn 0.49501 -0.713396 -0.496016
-vn 0.463733 -0.754542 -0.464347
-vn 0.451124 -0.770019 -0.451174
-vn 0.4704 -0.747257 -0.469394
-vn 0.567659 -0.599705 -0.564019
-vn 0.672635 -0.312441 -0.670777
-vn 0.789476 -0.21269 -0.575752
-vn 0.740686 -0.398842 -0.540656
-vn 0.678382 -0.542587 -0.495376
-vn 0.617214 -0.644922 -0.450691
-vn 0.566095 -0.713303 -0.413201
-vn 0.530286 -0.754456 -0.386774
-vn 0.515786 -0.769936 -0.375718
-vn 0.537656 -0.747169 -0.390722
-vn 0.648404 -0.599612 -0.469082
-vn 0.768638 -0.312379 -0.558224
-vn 0.869603 -0.212529 -0.44567
-vn 0.816009 -0.398582 -0.418643
-vn 0.747478 -0.542291 -0.383662
-vn 0.680141 -0.644632 -0.349082
-vn 0.623831 -0.713032 -0.320031
-vn 0.584353 -0.754204 -0.299514
-vn 0.568312 -0.769692 -0.290853
-vn 0.592249 -0.746914 -0.302258
-vn 0.713791 -0.599334 -0.362355
-vn 0.846319 -0.312182 -0.43161
-vn 0.928776 -0.212294 -0.303818
-vn 0.871718 -0.398195 -0.285566
-vn 0.798662 -0.541848 -0.261801
-vn 0.726815 -0.644194 -0.238232
-vn 0.66669 -0.712625 -0.218383
-vn 0.624503 -0.753825 -0.204314
-vn 0.607309 -0.769327 -0.198275
-vn 0.632732 -0.746529 -0.205779
-vn 0.762085 -0.598913 -0.24603
-vn 0.903625 -0.311878 -0.29359
-vn 0.965077 -0.212051 -0.15382
-vn 0.905979 -0.397789 -0.144799
-vn 0.830215 -0.54138 -0.132852
-vn 0.755641 -0.64373 -0.120902
-vn 0.69319 -0.712192 -0.110774
-vn 0.649336 -0.753422 -0.103526
-vn 0.631412 -0.768938 -0.100269
-vn 0.657689 -0.746121 -0.103675
-vn 0.791647 -0.598461 -0.123039
-vn 0.938684 -0.311544 -0.147689
-vn 0.914544 -0.378435 0.142819
-vn 0.925714 -0.378218 -0.00232416
-vn 0.668917 -0.736298 0.102053
-vn 0.676858 -0.736102 -0.00404493
-vn 0.402855 -0.913222 0.0611005
-vn 0.40765 -0.913134 -0.00278475
-vn 0.237094 -0.970818 0.0360369
-vn 0.239945 -0.970785 -0.00156235
-vn 0.143622 -0.989391 0.0218709
-vn 0.145359 -0.989379 -0.000905924
-vn 0.0881438 -0.996017 0.0134164
-vn 0.0892094 -0.996013 -0.000561969
-vn 0.0529236 -0.998566 0.00802169
-vn 0.0535581 -0.998565 -0.000370515
-vn 0.0292368 -0.999563 0.00437822
-vn 0.0295786 -0.999562 -0.000256766
-vn 0.0139668 -0.999901 0.00189983
-vn 0.0140986 -0.999901 -0.000310229
-vn -6.98503e-11 -1 4.37568e-08
-vn 0.57735 0.57735 0.57735
-vn 0.880514 -0.378797 0.284968
-vn 0.644113 -0.73668 0.205963
-vn 0.387824 -0.913404 0.123639
-vn 0.228202 -0.970886 0.0728308
-vn 0.138222 -0.989416 0.0441563
-vn 0.0848289 -0.996027 0.027093
-vn 0.0509383 -0.99857 0.0162339
-vn 0.0281483 -0.999564 0.00891572
-vn 0.0134771 -0.999901 0.0040705
-vn 0.824813 -0.379126 0.41946
-vn 0.60349 -0.737038 0.30426
-vn 0.363284 -0.913577 0.182764
-vn 0.213718 -0.970951 0.107605
-vn 0.129436 -0.989441 0.065215
-vn 0.0794365 -0.996036 0.0400165
-vn 0.0477053 -0.998573 0.0239947
-vn 0.0263701 -0.999565 0.0132053
-vn 0.0126559 -0.999901 0.00612783
-vn 0.749268 -0.379339 0.542862
-vn 0.548459 -0.737277 0.394482
-vn 0.330129 -0.913693 0.237023
-vn 0.19418 -0.970995 0.13951
-vn 0.117592 -0.989457 0.0845349
-vn 0.072168 -0.996043 0.0518729
-vn 0.0433456 -0.998575 0.0311154
-vn 0.0239689 -0.999566 0.0171423
-vn 0.011535 -0.999901 0.00802058
-vn 0.655878 -0.379404 0.652592
-vn 0.480489 -0.737359 0.474797
-vn 0.289251 -0.913735 0.285345
-vn 0.170114 -0.971011 0.167926
-vn 0.10301 -0.989463 0.101741
-vn 0.0632192 -0.996045 0.0624325
-vn 0.0379768 -0.998576 0.0374582
-vn 0.0210098 -0.999566 0.0206504
-vn 0.0101458 -0.999901 0.00971152
-vn 0.546633 -0.379313 0.746535
-vn 0.401003 -0.737279 0.543706
-vn 0.241491 -0.913699 0.326858
-vn 0.142012 -0.970997 0.192345
-vn 0.0859849 -0.989458 0.116529
-vn 0.0527722 -0.996043 0.0715081
-vn 0.0317083 -0.998576 0.0429105
-vn 0.0175532 -0.999566 0.0236674
-vn 0.00851697 -0.999901 0.0111708
-vn 0.423633 -0.379076 0.8227
-vn 0.311481 -0.737043 0.59979
-vn 0.187712 -0.913588 0.360723
-vn 0.110376 -0.970956 0.212279
-vn 0.0668211 -0.989443 0.128601
-vn 0.0410126 -0.996037 0.0789184
-vn 0.0246514 -0.998573 0.0473636
-vn 0.0136604 -0.999565 0.0261334
-vn 0.00667766 -0.999901 0.0123698
-vn 0.289443 -0.378732 0.879082
-vn 0.213718 -0.736685 0.641576
-vn 0.128961 -0.913418 0.386053
-vn 0.0758132 -0.970892 0.227203
-vn 0.045885 -0.989419 0.137642
-vn 0.0281652 -0.996028 0.0844689
-vn 0.0169408 -0.99857 0.0507009
-vn 0.00940561 -0.999564 0.0279838
-vn 0.00466237 -0.999901 0.0132783
-vn 0.147463 -0.378377 0.91383
-vn 0.110124 -0.736303 0.66763
-vn 0.06665 -0.913234 0.401947
-vn 0.0391491 -0.970823 0.23658
-vn 0.0236752 -0.989393 0.143323
-vn 0.0145357 -0.996018 0.0879579
-vn 0.00875966 -0.998567 0.0528011
-vn 0.00488963 -0.999563 0.0291518
-vn 0.0025177 -0.999901 0.0138641
-vn 0.00232409 -0.378218 0.925714
-vn 0.00404491 -0.736102 0.676858
-vn 0.00278475 -0.913134 0.40765
-vn 0.00156237 -0.970785 0.239945
-vn 0.000905956 -0.989379 0.145359
-vn 0.000562007 -0.996013 0.0892095
-vn 0.000370557 -0.998565 0.0535581
-vn 0.000256809 -0.999562 0.0295786
-vn 0.000310273 -0.999901 0.0140987
-vn -0.142819 -0.378435 0.914544
-vn -0.102053 -0.736298 0.668917
-vn -0.0611004 -0.913222 0.402855
-vn -0.0360369 -0.970818 0.237094
-vn -0.0218709 -0.989391 0.143622
-vn -0.0134164 -0.996017 0.0881439
-vn -0.00802165 -0.998566 0.0529237
-vn -0.00437818 -0.999563 0.0292368
-vn -0.00189979 -0.999901 0.0139668
-vn -0.284968 -0.378797 0.880514
-vn -0.205963 -0.73668 0.644113
-vn -0.123638 -0.913404 0.387824
-vn -0.0728307 -0.970886 0.228202
-vn -0.0441562 -0.989416 0.138222
-vn -0.027093 -0.996027 0.084829
-vn -0.0162338 -0.99857 0.0509384
-vn -0.00891568 -0.999564 0.0281484
-vn -0.00407045 -0.999901 0.0134771
-vn -0.41946 -0.379126 0.824813
-vn -0.30426 -0.737038 0.60349
-vn -0.182764 -0.913577 0.363284
-vn -0.107605 -0.970951 0.213718
-vn -0.0652149 -0.989441 0.129436
-vn -0.0400165 -0.996036 0.0794365
-vn -0.0239946 -0.998573 0.0477053
-vn -0.0132052 -0.999565 0.0263702
-vn -0.00612779 -0.999901 0.0126559
-vn -0.542862 -0.379339 0.749268
-vn -0.394482 -0.737277 0.548459
-vn -0.237023 -0.913693 0.330129
-vn -0.13951 -0.970995 0.19418
-vn -0.0845348 -0.989457 0.117592
-vn -0.0518728 -0.996043 0.072168
-vn -0.0311154 -0.998575 0.0433456
-vn -0.0171423 -0.999566 0.023969
-vn -0.00802054 -0.999901 0.0115351
-vn -0.652592 -0.379404 0.655878
-vn -0.474797 -0.737359 0.480489
-vn -0.285345 -0.913735 0.289251
-vn -0.167926 -0.971011 0.170114
-vn -0.101741 -0.989463 0.10301
-vn -0.0624324 -0.996045 0.0632192
-vn -0.0374581 -0.998576 0.0379769
-vn -0.0206503 -0.999566 0.0210098
-vn -0.00971148 -0.999901 0.0101458
-vn -0.746535 -0.379313 0.546633
-vn -0.543706 -0.737279 0.401003
-vn -0.326858 -0.913699 0.241491
-vn -0.192345 -0.970997 0.142012
-vn -0.116529 -0.989458 0.0859849
-vn -0.071508 -0.996043 0.0527722
-vn -0.0429105 -0.998576 0.0317084
-vn -0.0236674 -0.999566 0.0175532
-vn -0.0111707 -0.999901 0.00851701
-vn -0.8227 -0.379076 0.423633
-vn -0.59979 -0.737043 0.311481
-vn -0.360723 -0.913588 0.187713
-vn -0.212278 -0.970956 0.110376
-vn -0.128601 -0.989443 0.0668211
-vn -0.0789184 -0.996037 0.0410127
-vn -0.0473636 -0.998573 0.0246515
-vn -0.0261333 -0.999565 0.0136604
-vn -0.0123698 -0.999901 0.00667771
-vn -0.879082 -0.378732 0.289443
-vn -0.641576 -0.736685 0.213719
-vn -0.386053 -0.913418 0.128961
-vn -0.227203 -0.970892 0.0758132
-vn -0.137642 -0.989419 0.045885
-vn -0.0844689 -0.996028 0.0281653
-vn -0.0507009 -0.99857 0.0169408
-vn -0.0279838 -0.999564 0.00940565
-vn -0.0132782 -0.999901 0.00466241
-vn -0.91383 -0.378377 0.147463
-vn -0.66763 -0.736303 0.110124
-vn -0.401947 -0.913234 0.0666501
-vn -0.23658 -0.970823 0.0391492
-vn -0.143323 -0.989393 0.0236753
-vn -0.0879579 -0.996018 0.0145357
-vn -0.052801 -0.998567 0.0087597
-vn -0.0291518 -0.999563 0.00488967
-vn -0.0138641 -0.999901 0.00251775
-vn -0.925714 -0.378218 0.00232411
-vn -0.676858 -0.736102 0.00404494
-vn -0.40765 -0.913134 0.0027848
-vn -0.239945 -0.970785 0.00156243
-vn -0.145359 -0.989379 0.000906
-vn -0.0892094 -0.996013 0.000562053
-vn -0.0535581 -0.998565 0.000370605
-vn -0.0295786 -0.999562 0.000256854
-vn -0.0140986 -0.999901 0.000310316
-vn -0.914544 -0.378435 -0.142819
-vn -0.668917 -0.736298 -0.102053
-vn -0.402855 -0.913222 -0.0611004
-vn -0.237094 -0.970818 -0.0360369
-vn -0.143622 -0.989391 -0.0218708
-vn -0.0881438 -0.996017 -0.0134163
-vn -0.0529236 -0.998566 -0.0080216
-vn -0.0292368 -0.999563 -0.00437814
-vn -0.0139668 -0.999901 -0.00189974
-vn -0.880514 -0.378797 -0.284968
-vn -0.644113 -0.73668 -0.205963
-vn -0.387824 -0.913404 -0.123638
-vn -0.228202 -0.970886 -0.0728307
-vn -0.138222 -0.989416 -0.0441562
-vn -0.0848289 -0.996027 -0.0270929
-vn -0.0509383 -0.99857 -0.0162338
-vn -0.0281483 -0.999564 -0.00891563
-vn -0.0134771 -0.999901 -0.00407041
-vn -0.824813 -0.379126 -0.41946
-vn -0.60349 -0.737039 -0.30426
-vn -0.363284 -0.913577 -0.182764
-vn -0.213718 -0.970951 -0.107605
-vn -0.129436 -0.989441 -0.0652149
-vn -0.0794365 -0.996036 -0.0400164
-vn -0.0477053 -0.998573 -0.0239946
-vn -0.0263701 -0.999565 -0.0132052
-vn -0.0126559 -0.999901 -0.00612774
-vn -0.749268 -0.379339 -0.542862
-vn -0.548459 -0.737277 -0.394482
-vn -0.330129 -0.913693 -0.237023
-vn -0.19418 -0.970995 -0.13951
-vn -0.117592 -0.989457 -0.0845348
-vn -0.072168 -0.996043 -0.0518728
-vn -0.0433456 -0.998575 -0.0311153
-vn -0.0239689 -0.999566 -0.0171422
-vn -0.011535 -0.999901 -0.00802049
-vn -0.655878 -0.379404 -0.652592
-vn -0.480489 -0.737359 -0.474797
-vn -0.289251 -0.913735 -0.285345
-vn -0.170114 -0.971011 -0.167926
-vn -0.10301 -0.989463 -0.101741
-vn -0.0632192 -0.996045 -0.0624324
-vn -0.0379768 -0.998576 -0.0374581
-vn -0.0210098 -0.999566 -0.0206503
-vn -0.0101458 -0.999901 -0.00971144
-vn -0.546633 -0.379313 -0.746535
-vn -0.401003 -0.737279 -0.543706
-vn -0.241491 -0.913699 -0.326859
-vn -0.142012 -0.970997 -0.192345
-vn -0.0859849 -0.989458 -0.116529
-vn -0.0527722 -0.996043 -0.071508
-vn -0.0317083 -0.998576 -0.0429104
-vn -0.0175532 -0.999566 -0.0236673
-vn -0.00851696 -0.999901 -0.0111707
-vn -0.423633 -0.379076 -0.8227
-vn -0.311481 -0.737043 -0.59979
-vn -0.187712 -0.913588 -0.360723
-vn -0.110376 -0.970956 -0.212278
-vn -0.0668211 -0.989443 -0.128601
-vn -0.0410126 -0.996037 -0.0789183
-vn -0.0246514 -0.998573 -0.0473636
-vn -0.0136604 -0.999565 -0.0261333
-vn -0.00667767 -0.999901 -0.0123698
-vn -0.289443 -0.378732 -0.879082
-vn -0.213718 -0.736685 -0.641576
-vn -0.128961 -0.913418 -0.386053
-vn -0.0758132 -0.970892 -0.227203
-vn -0.045885 -0.989419 -0.137642
-vn -0.0281652 -0.996028 -0.0844688
-vn -0.0169408 -0.99857 -0.0507008
-vn -0.00940561 -0.999564 -0.0279837
-vn -0.00466237 -0.999901 -0.0132782
-vn -0.147463 -0.378377 -0.91383
-vn -0.110124 -0.736303 -0.66763
-vn -0.0666501 -0.913234 -0.401946
-vn -0.0391491 -0.970823 -0.23658
-vn -0.0236752 -0.989393 -0.143323
-vn -0.0145357 -0.996018 -0.0879578
-vn -0.00875966 -0.998567 -0.052801
-vn -0.00488963 -0.999563 -0.0291517
-vn -0.0025177 -0.999901 -0.013864
-vn -0.00232409 -0.378218 -0.925713
-vn -0.0040449 -0.736102 -0.676858
-vn -0.00278475 -0.913134 -0.40765
-vn -0.00156238 -0.970785 -0.239945
-vn -0.000905956 -0.989379 -0.145359
-vn -0.000562008 -0.996013 -0.0892094
-vn -0.000370558 -0.998565 -0.0535581
-vn -0.000256808 -0.999562 -0.0295785
-vn -0.000310271 -0.999901 -0.0140986
-vn 0.142819 -0.378435 -0.914544
-vn 0.102053 -0.736298 -0.668917
-vn 0.0611005 -0.913222 -0.402855
-vn 0.0360369 -0.970818 -0.237094
-vn 0.0218709 -0.989391 -0.143622
-vn 0.0134164 -0.996017 -0.0881438
-vn 0.00802164 -0.998566 -0.0529236
-vn 0.00437818 -0.999563 -0.0292368
-vn 0.00189979 -0.999901 -0.0139668
-vn 0.284968 -0.378797 -0.880514
-vn 0.205963 -0.73668 -0.644113
-vn 0.123639 -0.913404 -0.387824
-vn 0.0728307 -0.970886 -0.228201
-vn 0.0441562 -0.989416 -0.138222
-vn 0.027093 -0.996027 -0.0848289
-vn 0.0162338 -0.99857 -0.0509383
-vn 0.00891567 -0.999564 -0.0281483
-vn 0.00407045 -0.999901 -0.013477
-vn 0.41946 -0.379126 -0.824813
-vn 0.30426 -0.737039 -0.60349
-vn 0.182764 -0.913577 -0.363284
-vn 0.107605 -0.970951 -0.213718
-vn 0.0652149 -0.989441 -0.129436
-vn 0.0400165 -0.996036 -0.0794365
-vn 0.0239946 -0.998573 -0.0477052
-vn 0.0132052 -0.999565 -0.0263701
-vn 0.00612779 -0.999901 -0.0126558
-vn 0.542862 -0.379339 -0.749268
-vn 0.394482 -0.737277 -0.548459
-vn 0.237023 -0.913693 -0.330129
-vn 0.13951 -0.970995 -0.19418
-vn 0.0845349 -0.989457 -0.117592
-vn 0.0518728 -0.996043 -0.0721679
-vn 0.0311154 -0.998575 -0.0433455
-vn 0.0171423 -0.999566 -0.0239689
-vn 0.00802054 -0.999901 -0.011535
-vn 0.652592 -0.379404 -0.655878
-vn 0.474797 -0.737359 -0.480489
-vn 0.285345 -0.913735 -0.289251
-vn 0.167926 -0.971011 -0.170114
-vn 0.101741 -0.989463 -0.10301
-vn 0.0624325 -0.996045 -0.0632191
-vn 0.0374581 -0.998576 -0.0379768
-vn 0.0206503 -0.999566 -0.0210098
-vn 0.00971148 -0.999901 -0.0101457
-vn 0.746535 -0.379313 -0.546633
-vn 0.543706 -0.737279 -0.401003
-vn 0.326858 -0.913699 -0.241491
-vn 0.192345 -0.970997 -0.142012
-vn 0.116529 -0.989458 -0.0859848
-vn 0.0715081 -0.996043 -0.0527721
-vn 0.0429105 -0.998576 -0.0317083
-vn 0.0236674 -0.999566 -0.0175531
-vn 0.0111707 -0.999901 -0.00851692
-vn 0.8227 -0.379076 -0.423633
-vn 0.59979 -0.737043 -0.311481
-vn 0.360723 -0.913588 -0.187712
-vn 0.212278 -0.970956 -0.110376
-vn 0.128601 -0.989443 -0.0668211
-vn 0.0789184 -0.996037 -0.0410126
-vn 0.0473636 -0.998573 -0.0246514
-vn 0.0261333 -0.999565 -0.0136603
-vn 0.0123698 -0.999901 -0.00667762
-vn 0.879082 -0.378732 -0.289443
-vn 0.641576 -0.736685 -0.213718
-vn 0.386053 -0.913418 -0.128961
-vn 0.227203 -0.970892 -0.0758131
-vn 0.137642 -0.989419 -0.0458849
-vn 0.0844689 -0.996028 -0.0281652
-vn 0.0507009 -0.99857 -0.0169407
-vn 0.0279838 -0.999564 -0.00940557
-vn 0.0132782 -0.999901 -0.00466232
-vn 0.91383 -0.378377 -0.147463
-vn 0.66763 -0.736303 -0.110124
-vn 0.401947 -0.913234 -0.06665
-vn 0.23658 -0.970823 -0.0391491
-vn 0.143323 -0.989393 -0.0236752
-vn 0.0879579 -0.996018 -0.0145356
-vn 0.052801 -0.998567 -0.00875962
-vn 0.0291518 -0.999563 -0.00488959
-vn 0.0138641 -0.999901 -0.00251766
-vn 0.0011256 -0.999664 0.0258898
-vn 0.00112935 -0.979566 0.201122
-vn 0.00492557 -0.985415 0.1701
-vn 0.00490652 -0.999988 -6.91095e-05
-vn 0.0175808 -0.985367 0.169538
-vn 0.01752 -0.999846 -0.000152537
-vn 0.0424971 -0.984684 0.16909
-vn 0.0423504 -0.999103 -0.000253712
-vn 0.0853161 -0.981841 0.169437
-vn 0.0850248 -0.996379 -0.0003473
-vn 0.155487 -0.97278 0.171823
-vn 0.155058 -0.987905 -0.000381362
-vn 0.268463 -0.946639 0.17833
-vn 0.268351 -0.963321 -0.000269642
-vn 0.444328 -0.875179 0.191404
-vn 0.44677 -0.894649 3.86941e-05
-vn 0.681761 -0.700865 0.20974
-vn 0.692632 -0.721292 0.000217139
-vn 0.895939 -0.384289 0.222746
-vn 0.918226 -0.396058 -0.000222748
-vn 0.973102 -0.0539366 0.223973
-vn 0.998513 -0.0545125 -0.000219099
-vn 0.00109641 -0.909557 0.415577
-vn 0.00479516 -0.926025 0.377431
-vn 0.0171656 -0.926254 0.376508
-vn 0.0415692 -0.925686 0.376001
-vn 0.0834902 -0.922308 0.377328
-vn 0.151823 -0.911218 0.382925
-vn 0.260158 -0.880388 0.396528
-vn 0.423268 -0.801942 0.421583
-vn 0.632195 -0.628127 0.453637
-vn 0.812416 -0.339001 0.474404
-vn 0.878355 -0.0477065 0.475623
-vn 0.000942169 -0.741497 0.670956
-vn 0.00415781 -0.778683 0.627404
-vn 0.01502 -0.779266 0.626513
-vn 0.0365369 -0.778671 0.626368
-vn 0.0734108 -0.774139 0.628744
-vn 0.132752 -0.759814 0.636443
-vn 0.223981 -0.723407 0.653081
-vn 0.353828 -0.641804 0.680363
-vn 0.508453 -0.48589 0.710906
-vn 0.635741 -0.256651 0.727986
-vn 0.684507 -0.0367045 0.728082
-vn 0.000539554 -0.404099 0.914715
-vn 0.00245401 -0.474238 0.880393
-vn 0.00914383 -0.474779 0.880057
-vn 0.0225509 -0.474044 0.880212
-vn 0.0454641 -0.469447 0.88179
-vn 0.0816748 -0.456241 0.8861
-vn 0.13522 -0.426173 0.894478
-vn 0.206998 -0.367328 0.906765
-vn 0.287477 -0.270002 0.918943
-vn 0.352804 -0.141839 0.924884
-vn 0.380414 -0.0226627 0.924538
-vn -0.000130405 0.0898241 0.995958
-vn -0.0004527 0.000196864 1
-vn -0.00111253 0.000305297 0.999999
-vn -0.00210383 0.000226542 0.999998
-vn -0.00356013 -0.000304148 0.999994
-vn -0.00554914 -0.00170699 0.999983
-vn -0.00782007 -0.00443545 0.99996
-vn -0.00938856 -0.00838653 0.999921
-vn -0.00861652 -0.0119271 0.999892
-vn -0.00486228 -0.0123591 0.999912
-vn -0.000595288 -0.00792013 0.999968
-vn -0.000797265 0.540928 0.841069
-vn -0.00341393 0.474402 0.880302
-vn -0.0118469 0.474801 0.880013
-vn -0.0282624 0.473115 0.880547
-vn -0.0559201 0.465883 0.883078
-vn -0.0988415 0.447075 0.889019
-vn -0.16009 0.407137 0.899228
-vn -0.236629 0.335032 0.912009
-vn -0.312743 0.228412 0.921965
-vn -0.364824 0.105812 0.925045
-vn -0.381586 0.00491357 0.92432
-vn -0.00123432 0.812664 0.582732
-vn -0.00538275 0.778637 0.627452
-vn -0.0191367 0.778777 0.62701
-vn -0.0462962 0.776533 0.628373
-vn -0.0926964 0.767723 0.634042
-vn -0.166319 0.743996 0.647154
-vn -0.27499 0.68951 0.670042
-vn -0.416068 0.580809 0.699677
-vn -0.559051 0.4051 0.723434
-vn -0.655227 0.192999 0.730362
-vn -0.685656 0.0139889 0.727791
-vn -0.00146046 0.940592 0.339537
-vn -0.00641237 0.925961 0.377566
-vn -0.0230027 0.925827 0.377248
-vn -0.0559892 0.923855 0.378626
-vn -0.112878 0.916403 0.384011
-vn -0.204653 0.894963 0.396431
-vn -0.343654 0.840685 0.418511
-vn -0.529317 0.720504 0.447992
-vn -0.71934 0.509519 0.472166
-vn -0.843153 0.244714 0.478756
-vn -0.879532 0.0190927 0.475457
-vn -0.00155734 0.990228 0.139453
-vn -0.00685938 0.985379 0.17024
-vn -0.0247154 0.985116 0.170102
-vn -0.0603455 0.983445 0.170864
-vn -0.122098 0.97719 0.173755
-vn -0.222564 0.958079 0.180415
-vn -0.376709 0.90613 0.192404
-vn -0.585508 0.783315 0.208801
-vn -0.799868 0.557362 0.222617
-vn -0.93641 0.268184 0.226302
-vn -0.974341 0.0213034 0.224065
-vn -0.00157518 0.999664 -0.0258885
-vn -0.0069515 0.999976 5.45935e-05
-vn -0.0251118 0.999685 7.41651e-05
-vn -0.0614067 0.998113 5.11269e-05
-vn -0.124409 0.992231 -4.5433e-05
-vn -0.22715 0.97386 -0.000246465
-vn -0.385348 0.922771 -0.000513225
-vn -0.600526 0.799605 -0.000544751
-vn -0.821737 0.569867 3.83977e-05
-vn -0.961664 0.274229 0.000507742
-vn -0.999758 0.0220172 0.000156814
-vn -0.00152922 0.979569 -0.201101
-vn -0.00676195 0.985401 -0.170117
-vn -0.024479 0.985152 -0.169929
-vn -0.0599006 0.983496 -0.170726
-vn -0.121326 0.977278 -0.173802
-vn -0.221282 0.958291 -0.180868
-vn -0.374741 0.906731 -0.193413
-vn -0.583164 0.784767 -0.209905
-vn -0.798392 0.559493 -0.222572
-vn -0.936188 0.269816 -0.225281
-vn -0.974388 0.0225012 -0.223743
-vn -0.00139274 0.90957 -0.415549
-vn -0.00617783 0.926008 -0.377452
-vn -0.0224388 0.925915 -0.377066
-vn -0.0549378 0.923998 -0.37843
-vn -0.111077 0.916663 -0.383915
-vn -0.201689 0.895559 -0.396606
-vn -0.339098 0.842248 -0.419083
-vn -0.523671 0.72418 -0.4487
-vn -0.715194 0.515262 -0.472232
-vn -0.841973 0.2499 -0.478154
-vn -0.879557 0.0227677 -0.475248
-vn -0.00110393 0.741518 -0.670932
-vn -0.00493451 0.77867 -0.627414
-vn -0.0180659 0.77887 -0.626925
-vn -0.044322 0.776768 -0.628226
-vn -0.0893772 0.768289 -0.633833
-vn -0.161005 0.745374 -0.646912
-vn -0.267067 0.692828 -0.669824
-vn -0.406232 0.587848 -0.699578
-vn -0.550893 0.415901 -0.723563
-vn -0.651778 0.203726 -0.730534
-vn -0.68542 0.0215357 -0.72783
-vn -0.000581146 0.404108 -0.914711
-vn -0.00267336 0.47424 -0.880392
-vn -0.0100828 0.474601 -0.880143
-vn -0.0250288 0.473094 -0.880657
-vn -0.0505698 0.466528 -0.88306
-vn -0.0905647 0.449382 -0.888737
-vn -0.148341 0.412857 -0.898634
-vn -0.222448 0.346181 -0.911414
-vn -0.300065 0.244746 -0.921987
-vn -0.358069 0.122959 -0.925563
-vn -0.380805 0.0169151 -0.924501
-vn 0.00011855 -0.089821 -0.995958
-vn 0.000398352 -0.000195732 -1
-vn 0.00091189 -0.000354665 -1
-vn 0.00161207 -0.000476982 -0.999999
-vn 0.00260199 -0.000424255 -0.999997
-vn 0.00398164 0.000113289 -0.999992
-vn 0.00568807 0.00162739 -0.999982
-vn 0.00712988 0.00445688 -0.999965
-vn 0.00700293 0.00788093 -0.999944
-vn 0.00432156 0.00983667 -0.999942
-vn 0.000591649 0.00730836 -0.999973
-vn 0.000697994 -0.540904 -0.841084
-vn 0.00298874 -0.474406 -0.880301
-vn 0.010402 -0.475117 -0.879861
-vn 0.0248548 -0.474516 -0.879896
-vn 0.049357 -0.469826 -0.881378
-vn 0.0880349 -0.455805 -0.885715
-vn 0.145205 -0.423105 -0.89437
-vn 0.221036 -0.358184 -0.907109
-vn 0.302485 -0.251829 -0.919285
-vn 0.361963 -0.118363 -0.924647
-vn 0.381429 -0.00698852 -0.924372
-vn 0.000996217 -0.812639 -0.582767
-vn 0.00433483 -0.778641 -0.627455
-vn 0.0154202 -0.779207 -0.626577
-vn 0.0372502 -0.778621 -0.626388
-vn 0.0746294 -0.774114 -0.628632
-vn 0.134929 -0.759731 -0.636084
-vn 0.22815 -0.722657 -0.652468
-vn 0.361654 -0.637915 -0.679905
-vn 0.519456 -0.473767 -0.711133
-vn 0.64318 -0.236477 -0.728285
-vn 0.68507 -0.0233332 -0.728104
-vn 0.00109887 -0.940578 -0.339576
-vn 0.00479945 -0.925967 -0.377574
-vn 0.0171591 -0.926143 -0.376781
-vn 0.0415346 -0.925531 -0.376387
-vn 0.0834231 -0.922148 -0.377735
-vn 0.151808 -0.911128 -0.383145
-vn 0.260644 -0.880363 -0.396265
-vn 0.425695 -0.80105 -0.420835
-vn 0.638137 -0.622269 -0.45339
-vn 0.817622 -0.325186 -0.47513
-vn 0.878634 -0.0382942 -0.475958
-vn 0.00112054 -0.990225 -0.139476
-vn 0.0048919 -0.985388 -0.170254
-vn 0.0174935 -0.985312 -0.169864
-vn 0.0423239 -0.984601 -0.169614
-vn 0.0850125 -0.981749 -0.170121
-vn 0.155016 -0.972735 -0.172503
-vn 0.267915 -0.946731 -0.178668
-vn 0.444369 -0.875232 -0.191063
-vn 0.683861 -0.698956 -0.209271
-vn 0.898606 -0.377646 -0.223361
-vn 0.973245 -0.0492087 -0.224438
-vn 0.963664 0.145742 0.223857
-vn 0.988753 0.149555 -1.02956e-05
-vn 0.937833 0.266127 0.222815
-vn 0.962414 0.271585 -0.000482219
-vn 0.903461 0.368891 0.218353
-vn 0.92689 0.37533 -0.00115981
-vn 0.861603 0.46211 0.20999
-vn 0.883138 0.469111 -0.00182829
-vn 0.811469 0.549692 0.198385
-vn 0.830528 0.556973 -0.00233533
-vn 0.751697 0.632973 0.185197
-vn 0.767979 0.64047 -0.00254971
-vn 0.681064 0.711484 0.173038
-vn 0.694526 0.719463 -0.00235187
-vn 0.599033 0.783463 0.165367
-vn 0.609767 0.792579 -0.00164198
-vn 0.506148 0.846286 0.166173
-vn 0.514245 0.857643 -0.000354531
-vn 0.458372 0.878108 0.13719
-vn 0.462947 0.886001 -0.0261238
-vn 0.869962 0.131392 0.475291
-vn 0.846212 0.243168 0.474126
-vn 0.81605 0.340077 0.467344
-vn 0.78093 0.429351 0.453658
-vn 0.739673 0.514566 0.433712
-vn 0.69001 0.596482 0.409995
-vn 0.629665 0.67357 0.387073
-vn 0.557298 0.742547 0.371543
-vn 0.473152 0.798976 0.371165
-vn 0.433762 0.836027 0.336019
-vn 0.678287 0.101489 0.727755
-vn 0.659167 0.191836 0.727117
-vn 0.636756 0.272011 0.721492
-vn 0.61311 0.347907 0.709265
-vn 0.587023 0.422801 0.690394
-vn 0.555457 0.497112 0.666593
-vn 0.51467 0.568163 0.64211
-vn 0.461571 0.630309 0.62423
-vn 0.395178 0.675885 0.622104
-vn 0.37239 0.724486 0.580039
-vn 0.377598 0.0541752 0.924384
-vn 0.366363 0.106307 0.924379
-vn 0.353999 0.153465 0.922569
-vn 0.342515 0.199142 0.918164
-vn 0.331486 0.245742 0.910894
-vn 0.318869 0.293901 0.90108
-vn 0.301475 0.341671 0.890154
-vn 0.27579 0.383847 0.88125
-vn 0.239579 0.412502 0.878888
-vn 0.245475 0.48261 0.840732
-vn 0.0008269 -0.00507756 0.999987
-vn 0.000819577 -0.00506279 0.999987
-vn 0.00044675 -0.00555517 0.999985
-vn 7.08086e-05 -0.00640706 0.999979
-vn -8.32455e-05 -0.0073837 0.999973
-vn 0.00017449 -0.00810272 0.999967
-vn 0.000987742 -0.0079718 0.999968
-vn 0.00231017 -0.00631923 0.999977
-vn 0.00371605 -0.00291542 0.999989
-vn 0.0400147 0.0790321 0.996069
-vn -0.375928 -0.0639748 0.924438
-vn -0.362978 -0.119253 0.924135
-vn -0.34795 -0.170817 0.92182
-vn -0.332921 -0.22024 0.916874
-vn -0.317922 -0.268667 0.909254
-vn -0.301637 -0.31587 0.899578
-vn -0.282006 -0.359607 0.889469
-vn -0.256924 -0.395411 0.881839
-vn -0.225188 -0.417276 0.880438
-vn -0.179329 -0.361089 0.915126
-vn -0.676648 -0.110626 0.727949
-vn -0.652223 -0.212226 0.727713
-vn -0.619895 -0.3058 0.722645
-vn -0.583857 -0.391429 0.711263
-vn -0.545821 -0.469616 0.69393
-vn -0.505835 -0.540085 0.672636
-vn -0.463195 -0.601295 0.651072
-vn -0.417197 -0.650539 0.634622
-vn -0.367636 -0.684367 0.62967
-vn -0.326546 -0.662842 0.673801
-vn -0.868449 -0.14011 0.475569
-vn -0.836313 -0.272652 0.475648
-
-vn -0.789593 -0.393732 0.470657
-vn -0.734079 -0.50038 0.459073
-vn -0.674225 -0.591857 0.441731
-vn -0.612941 -0.668526 0.421161
-vn -0.551953 -0.731075 0.401095
-vn -0.492264 -0.780162 0.386036
-vn -0.43446 -0.816246 0.380771
-vn -0.39899 -0.815438 0.419366
-vn -0.962296 -0.154241 0.224044
-vn -0.926331 -0.30263 0.224335
-vn -0.871294 -0.437779 0.221801
-vn -0.803939 -0.5543 0.215487
-vn -0.730957 -0.650604 0.205953
-vn -0.657524 -0.727829 0.19475
-vn -0.586947 -0.788454 0.183939
-vn -0.52098 -0.835277 0.175761
-vn -0.460308 -0.870848 0.172454
-vn -0.428634 -0.880196 0.203785
-vn -0.98751 -0.157554 -3.14511e-05
-vn -0.95063 -0.310325 0.000325572
-vn -0.893262 -0.449536 0.000903403
-vn -0.822393 -0.568918 0.00147358
-vn -0.745478 -0.666528 0.00186691
-vn -0.66847 -0.743737 0.00198573
-vn -0.595181 -0.803589 0.00178647
-vn -0.527556 -0.849519 0.0012587
-vn -0.466258 -0.884649 0.000406485
-vn -0.436855 -0.899139 0.0265822
-vn -0.962552 -0.152551 -0.224103
-vn -0.927078 -0.300842 -0.223651
-vn -0.872501 -0.436328 -0.219909
-vn -0.80535 -0.553453 -0.212369
-vn -0.732276 -0.650378 -0.201942
-vn -0.658536 -0.728061 -0.190417
-vn -0.587553 -0.788913 -0.179993
-vn -0.521175 -0.835736 -0.172982
-vn -0.460131 -0.871106 -0.171621
-vn -0.432826 -0.890423 -0.140743
-vn -0.869081 -0.136023 -0.475601
-vn -0.837928 -0.268503 -0.475166
-vn -0.792087 -0.39029 -0.469332
-vn -0.737022 -0.498129 -0.4568
-vn -0.67711 -0.590881 -0.438613
-vn -0.615351 -0.668574 -0.417555
-vn -0.55363 -0.731696 -0.397637
-vn -0.493115 -0.780835 -0.38358
-vn -0.434524 -0.81647 -0.380217
-vn -0.411772 -0.84446 -0.342537
-vn -0.677766 -0.103651 -0.727935
-vn -0.65441 -0.205335 -0.727726
-vn -0.622828 -0.299662 -0.722695
-vn -0.587166 -0.386473 -0.711249
-vn -0.549177 -0.466052 -0.693686
-vn -0.508942 -0.53789 -0.672051
-vn -0.465769 -0.60017 -0.650273
-vn -0.419021 -0.649926 -0.634049
-vn -0.368671 -0.683631 -0.629865
-vn -0.356653 -0.727462 -0.586172
-vn -0.377472 -0.054565 -0.924412
-vn -0.364971 -0.110129 -0.924482
-vn -0.349636 -0.1619 -0.922791
-vn -0.334058 -0.211332 -0.918555
-vn -0.318658 -0.259726 -0.911592
-vn -0.302352 -0.307166 -0.902348
-vn -0.283153 -0.351746 -0.892244
-vn -0.258892 -0.38924 -0.884006
-vn -0.228233 -0.41376 -0.881313
-vn -0.238098 -0.482184 -0.843094
-vn -0.00079373 0.00492295 -0.999988
-vn -0.00053617 0.00431599 -0.999991
-vn 0.000342498 0.00410639 -0.999991
-vn 0.00138632 0.00441772 -0.999989
-vn 0.00225577 0.00516992 -0.999984
-vn 0.00262636 0.00608232 -0.999978
-vn 0.00214504 0.00657919 -0.999976
-vn 0.000566399 0.00579991 -0.999983
-vn -0.00191796 0.00303704 -0.999994
-vn -0.039001 -0.0787257 -0.996133
-vn 0.376324 0.0623797 -0.924386
-vn 0.365291 0.113423 -0.923958
-vn 0.353675 0.160545 -0.921488
-vn 0.342819 0.20689 -0.916336
-vn 0.331882 0.254432 -0.908361
-vn 0.318542 0.30327 -0.898086
-vn 0.299531 0.350843 -0.887237
-vn 0.271549 0.391405 -0.87924
-vn 0.232867 0.41673 -0.878698
-vn 0.182473 0.361387 -0.914386
-vn 0.677525 0.106593 -0.727735
-vn 0.658163 0.195619 -0.727018
-vn 0.635503 0.275297 -0.721351
-vn 0.611391 0.351011 -0.70922
-vn 0.584565 0.425738 -0.690675
-vn 0.552075 0.499775 -0.667412
-vn 0.510382 0.570505 -0.643454
-vn 0.456596 0.63248 -0.62569
-vn 0.389761 0.678131 -0.623077
-vn 0.337518 0.661458 -0.669742
-vn 0.869619 0.133693 -0.475278
-vn 0.845534 0.244383 -0.47471
-vn 0.814847 0.34083 -0.468891
-vn 0.779111 0.429833 -0.456321
-vn 0.737258 0.514854 -0.437465
-vn 0.687114 0.596685 -0.414538
-vn 0.626455 0.673887 -0.391701
-vn 0.553923 0.743269 -0.375128
-vn 0.469687 0.800437 -0.372416
-vn 0.417322 0.809226 -0.413517
-vn 0.963566 0.146365 -0.223868
-vn 0.937585 0.266186 -0.223783
-vn 0.902937 0.368761 -0.220726
-vn 0.860775 0.461907 -0.213797
-vn 0.810378 0.549488 -0.203345
-vn 0.750413 0.632854 -0.190724
-vn 0.679642 0.711559 -0.178242
-vn 0.597492 0.783844 -0.169091
-vn 0.504481 0.847096 -0.167118
-vn 0.452164 0.869302 -0.199656
-vn -0.0830307 0.995731 0.0403187
-vn -0.0849904 0.946261 0.312037
-vn -0.180544 0.949395 0.257007
-vn -0.188985 0.981978 -0.00217325
-vn -0.400513 0.882766 0.245587
-vn -0.421488 0.906833 -0.00160159
-vn -0.628362 0.742748 0.231269
-vn -0.657216 0.753701 -0.00141987
-vn -0.797136 0.563597 0.21664
-vn -0.827118 0.562028 -0.000525433
-vn -0.883652 0.417142 0.212489
-vn -0.913131 0.407663 0.00141358
-vn -0.910039 0.347152 0.226529
-vn -0.942531 0.334095 0.00400309
-vn -0.895976 0.361285 0.258262
-vn -0.936777 0.349862 0.00674916
-vn -0.840681 0.452663 0.297239
-vn -0.89189 0.452174 0.00835287
-vn -0.730742 0.602768 0.320446
-vn -0.782751 0.622303 0.00632962
-vn -0.59134 0.744997 0.308701
-vn -0.632406 0.774637 0.000652116
-vn -0.0797342 0.804237 0.588935
-vn -0.153906 0.833014 0.531414
-vn -0.333536 0.790541 0.513613
-vn -0.53088 0.689318 0.492957
-vn -0.689392 0.55058 0.470745
-vn -0.775032 0.430471 0.462623
-vn -0.794511 0.370364 0.481231
-vn -0.763864 0.377565 0.523409
-vn -0.692222 0.440678 0.571517
-vn -0.587628 0.543838 0.599111
-vn -0.477564 0.655954 0.584514
-vn -0.0583056 0.568929 0.820317
-vn -0.101713 0.621828 0.77652
-vn -0.212262 0.611004 0.762639
-vn -0.346574 0.564497 0.749153
-vn -0.470445 0.489088 0.734489
-vn -0.547575 0.414194 0.727052
-vn -0.56624 0.369943 0.736556
-vn -0.536207 0.36541 0.760893
-vn -0.474537 0.392768 0.787749
-vn -0.399116 0.441923 0.803374
-vn -0.328927 0.515527 0.791227
-vn -0.017504 0.269895 0.962731
-vn -0.0242475 0.335315 0.941794
-vn -0.0454564 0.35107 0.935245
-vn -0.0860527 0.357185 0.930061
-vn -0.141834 0.349597 0.926102
-vn -0.196587 0.331221 0.922847
-vn -0.231699 0.312277 0.921303
-vn -0.239822 0.301286 0.922882
-vn -0.225881 0.298315 0.927354
-vn -0.199467 0.295755 0.934206
-vn -0.169534 0.324935 0.930417
-vn 0.0396774 -0.0548496 0.997706
-vn 0.0704672 0.0118919 0.997443
-vn 0.141199 0.0469304 0.988868
-vn 0.1994 0.0972357 0.975082
-vn 0.221928 0.14756 0.963833
-vn 0.199518 0.181845 0.962873
-vn 0.144382 0.192963 0.970525
-vn 0.0814985 0.183495 0.979636
-vn 0.0320606 0.156345 0.987182
-vn 0.00439902 0.100204 0.994957
-vn -0.00177006 0.0729114 0.997337
-vn 0.106014 -0.371757 0.922257
-vn 0.170014 -0.308041 0.936059
-vn 0.316942 -0.252411 0.914241
-vn 0.449028 -0.159139 0.879232
-vn 0.525779 -0.0597841 0.848518
-vn 0.532339 0.0127749 0.846435
-vn 0.478105 0.0427321 0.877263
-vn 0.386705 0.0289514 0.921749
-vn 0.289627 -0.0271515 0.956755
-vn 0.211508 -0.141639 0.967059
-vn 0.169088 -0.233205 0.957614
-vn 0.170514 -0.645867 0.744164
-vn 0.259671 -0.585492 0.767965
-vn 0.45708 -0.502731 0.733716
-vn 0.629274 -0.366207 0.685497
-vn 0.730091 -0.226091 0.644864
-vn 0.7558 -0.130675 0.641631
-vn 0.721949 -0.0992317 0.684794
-vn 0.640766 -0.133117 0.756108
-vn 0.527647 -0.230341 0.817638
-vn 0.404337 -0.397539 0.823696
-vn 0.316283 -0.533164 0.784667
-vn 0.220498 -0.841965 0.492418
-vn 0.325823 -0.786436 0.524745
-vn 0.549115 -0.676157 0.491206
-vn 0.737332 -0.50491 0.448783
-vn 0.845398 -0.336843 0.414534
-vn 0.881904 -0.230381 0.411303
-vn 0.870133 -0.206564 0.447436
-vn 0.815542 -0.267861 0.512974
-vn 0.709171 -0.408972 0.574298
-vn 0.548614 -0.607971 0.573928
-vn 0.410491 -0.748998 0.520095
-vn 0.247963 -0.944131 0.217099
-vn 0.362229 -0.896377 0.255537
-vn 0.594973 -0.768272 0.236149
-vn 0.787758 -0.577953 0.213089
-vn 0.897385 -0.396054 0.194527
-vn 0.938934 -0.285663 0.191832
-vn 0.940068 -0.268949 0.209614
-vn 0.904335 -0.349924 0.244398
-vn 0.807289 -0.519877 0.279307
-vn 0.62401 -0.729875 0.279097
-vn 0.451694 -0.858089 0.244246
-vn 0.253685 -0.966446 -0.0403192
-vn 0.372574 -0.928001 0.00192203
-vn 0.607071 -0.794646 0.00168252
-vn 0.800566 -0.599244 0.00123863
-vn 0.910395 -0.41374 -0.000182253
-vn 0.953165 -0.302441 -0.00230446
-vn 0.957631 -0.287959 -0.00468342
-vn 0.927074 -0.374821 -0.00651548
-vn 0.833026 -0.553206 -0.00560462
-vn 0.643441 -0.765496 -0.000186367
-vn 0.461035 -0.887382 0.000817308
-vn 0.24009 -0.920741 -0.307558
-vn 0.36165 -0.89769 -0.251718
-vn 0.594605 -0.769623 -0.232647
-vn 0.78763 -0.579098 -0.210439
-vn 0.897108 -0.396463 -0.19497
-vn 0.938067 -0.285052 -0.196917
-vn 0.938314 -0.267063 -0.219646
-vn 0.901962 -0.346476 -0.257718
-vn 0.805977 -0.515882 -0.290289
-vn 0.624919 -0.729088 -0.279119
-vn 0.452498 -0.858136 -0.242585
-vn 0.20395 -0.786792 -0.582548
-vn 0.323173 -0.788945 -0.522614
-vn 0.547029 -0.679573 -0.488815
-vn 0.736317 -0.508172 -0.446766
-vn 0.844483 -0.338385 -0.415143
-vn 0.879914 -0.229643 -0.415952
-vn 0.866731 -0.203285 -0.45547
-vn 0.812066 -0.261755 -0.521568
-vn 0.708928 -0.401464 -0.579869
-vn 0.553006 -0.604737 -0.573129
-vn 0.413202 -0.747877 -0.519562
-vn 0.147656 -0.559699 -0.815436
-vn 0.253477 -0.587509 -0.768494
-vn 0.45138 -0.507719 -0.73381
-vn 0.625903 -0.372071 -0.685426
-vn 0.728105 -0.229764 -0.64581
-vn 0.753527 -0.131148 -0.644202
-vn 0.71984 -0.0966997 -0.687371
-vn 0.641526 -0.128009 -0.756345
-vn 0.533309 -0.223637 -0.815824
-vn 0.413553 -0.391564 -0.82198
-vn 0.321132 -0.528607 -0.785779
-vn 0.0819047 -0.265455 -0.960638
-vn 0.159874 -0.308089 -0.937828
-vn 0.306151 -0.2576 -0.916468
-vn 0.441258 -0.167182 -0.881669
-vn 0.522246 -0.06667 -0.850185
-vn 0.532475 0.00894978 -0.846399
-vn 0.482869 0.0417759 -0.874696
-vn 0.396956 0.0301497 -0.917343
-vn 0.302974 -0.0240944 -0.952694
-vn 0.224229 -0.135278 -0.965102
-vn 0.175205 -0.226395 -0.958148
-vn 0.0193861 0.058169 -0.998118
-vn 0.0573306 0.0130583 -0.99827
-vn 0.125711 0.0419791 -0.991178
-vn 0.186252 0.0877475 -0.978576
-vn 0.216311 0.137129 -0.966646
-vn 0.204239 0.173709 -0.963386
-vn 0.158471 0.188369 -0.969229
-vn 0.100255 0.181898 -0.978193
-vn 0.0499633 0.157069 -0.986323
-vn 0.0183452 0.105089 -0.994294
-vn 0.0049783 0.077842 -0.996953
-vn -0.0305805 0.377272 -0.925597
-vn -0.038558 0.335452 -0.941268
-vn -0.0628576 0.345402 -0.936347
-vn -0.101904 0.346587 -0.932466
-vn -0.149221 0.337384 -0.929465
-vn -0.191466 0.321867 -0.927223
-vn -0.21668 0.307782 -0.926455
-vn -0.22109 0.300496 -0.927805
-vn -0.208643 0.299715 -0.930934
-vn -0.186237 0.300265 -0.935498
-vn -0.162779 0.327704 -0.930652
-vn -0.0630028 0.657513 -0.750805
-vn -0.114875 0.619592 -0.776472
-vn -0.227688 0.604291 -0.763538
-vn -0.360017 0.553903 -0.750719
-vn -0.477387 0.478369 -0.737065
-vn -0.547667 0.407231 -0.730906
-vn -0.561271 0.367716 -0.741458
-vn -0.527424 0.367088 -0.766205
-vn -0.463634 0.397099 -0.792058
-vn -0.388509 0.448744 -0.804792
-vn -0.32272 0.518505 -0.791836
-vn -0.07798 0.862425 -0.500142
-vn -0.163645 0.829655 -0.533754
-vn -0.344231 0.784518 -0.515786
-vn -0.539268 0.681163 -0.495184
-vn -0.693912 0.543459 -0.472375
-vn -0.77772 0.426072 -0.462184
-vn -0.796905 0.368777 -0.478483
-vn -0.765231 0.379557 -0.51996
-vn -0.690407 0.447034 -0.568769
-vn -0.581456 0.553559 -0.596223
-vn -0.472271 0.659999 -0.584262
-vn -0.081666 0.971625 -0.221983
-vn -0.185456 0.94729 -0.261242
-vn -0.405661 0.879507 -0.248811
-vn -0.632071 0.738677 -0.234185
-vn -0.799118 0.560309 -0.217863
-vn -0.885316 0.414967 -0.209803
-vn -0.912395 0.345895 -0.218841
-vn -0.899138 0.361854 -0.246197
-vn -0.843034 0.456946 -0.283714
-vn -0.728762 0.610455 -0.310243
-vn -0.588002 0.74813 -0.307498
-vn -0.487867 0.82954 0.271752
-vn -0.519788 0.854294 -0.00156289
-vn -0.409763 0.881855 0.233295
-vn -0.436597 0.899653 -0.00268054
-vn -0.319137 0.930361 0.180498
-vn -0.33931 0.940664 -0.00439078
-vn -0.196991 0.975317 0.0997579
-vn -0.20608 0.978504 -0.00778499
-vn 0.00990056 0.998878 -0.0463017
-vn 0.0292285 0.999436 -0.01655
-vn 0.165254 0.980262 -0.108527
-vn 0.183719 0.982733 0.021984
-vn 0.82102 0.563756 -0.0900265
-vn 0.622361 0.601573 -0.500776
-vn 0.719006 0.577833 -0.386185
-vn 0.820909 0.564015 0.0894149
-vn 0.941213 -0.323269 -0.0980556
-vn 0.771077 -0.188082 -0.60833
-vn 0.769744 -0.411062 -0.488388
-vn 0.866519 -0.498994 0.0122453
-vn 0.642692 -0.656297 -0.395248
-vn 0.700111 -0.713975 0.00916361
-vn 0.607835 -0.735821 -0.298504
-vn 0.636777 -0.768569 0.0617763
-vn -0.400386 0.751205 0.524768
-vn -0.337271 0.825899 0.451817
-vn -0.264488 0.897971 0.351701
-vn -0.168317 0.964719 0.202449
-vn -0.0160887 0.998807 -0.0461111
-vn 0.239851 0.861397 -0.447734
-vn 0.489968 0.359195 -0.794299
-vn 0.536316 -0.194375 -0.821331
-vn 0.49016 -0.49223 -0.719342
-vn 0.49093 -0.611675 -0.620355
-vn -0.282897 0.624274 0.728183
-vn -0.241457 0.736789 0.631538
-vn -0.193127 0.847562 0.494309
-vn -0.12941 0.947929 0.291005
-vn -0.0338021 0.999178 -0.0223857
-vn 0.10732 0.882902 -0.457129
-vn 0.245983 0.497389 -0.831924
-vn 0.307075 0.0395129 -0.950865
-vn 0.311643 -0.277043 -0.908915
-vn 0.334718 -0.426184 -0.840435
-vn -0.150542 0.445436 0.882567
-vn -0.133371 0.61061 0.78062
-vn -0.11289 0.77734 0.618869
-vn -0.0845525 0.92478 0.370989
-vn -0.0410072 0.999081 0.0124781
-vn 0.0213196 0.906183 -0.422348
-vn 0.0857432 0.61246 -0.785838
-vn 0.127557 0.249985 -0.959811
-vn 0.145607 -0.0447897 -0.988328
-vn 0.176606 -0.206398 -0.962398
-vn -0.00128934 0.192542 0.981288
-vn -0.00566313 0.420728 0.907169
-vn -0.0154446 0.669937 0.742258
-vn -0.0288077 0.890896 0.453293
-vn -0.0386274 0.997872 0.0525368
-vn -0.0373668 0.927654 -0.371566
-vn -0.0263309 0.708826 -0.704892
-vn -0.0133015 0.439546 -0.898121
-vn -0.00424289 0.198378 -0.980116
-vn 0.0242873 0.0431108 -0.998775
-vn 0.162853 -0.145306 0.975892
-vn 0.155048 0.123197 0.980195
-vn 0.122268 0.483323 0.866862
-vn 0.0533 0.835033 0.547612
-vn -0.0253337 0.995074 0.0958396
-vn -0.0811102 0.946766 -0.311536
-vn -0.111804 0.792168 -0.599975
-vn -0.127644 0.610923 -0.781332
-vn -0.138077 0.442481 -0.886084
-vn -0.120221 0.313872 -0.941823
-vn 0.307986 -0.500791 0.808921
-vn 0.330783 -0.279187 0.901464
-vn 0.325608 0.1473 0.93396
-vn 0.19596 0.730588 0.654096
-vn 0.00504647 0.990116 0.140161
-vn -0.115677 0.963402 -0.241816
-vn -0.17982 0.863586 -0.471046
-vn -0.218926 0.757092 -0.615535
-vn -0.248298 0.659888 -0.709152
-vn -0.245304 0.572236 -0.782542
-vn 0.393001 -0.754237 0.526001
-vn 0.450494 -0.637306 0.625216
-vn 0.542314 -0.323499 0.7754
-vn 0.514917 0.0618092 0.855009
-vn 0.286966 0.864901 0.411821
-vn 0.0666932 0.982419 0.17437
-vn -0.14212 0.976546 -0.161742
-vn -0.231467 0.918883 -0.319494
-vn -0.284635 0.864835 -0.413575
-vn -0.324661 0.817817 -0.475154
-vn -0.334056 0.769756 -0.54395
-vn 0.423577 -0.873621 0.239519
-vn 0.491206 -0.822052 0.288007
-vn 0.639494 -0.665508 0.384898
-vn 0.718642 -0.431101 0.545624
-vn 0.510284 0.818052 0.265332
-vn 0.172363 0.972424 0.157105
-vn -0.158541 0.984464 -0.0754703
-vn -0.263741 0.951972 -0.155532
-vn -0.322272 0.925365 -0.199602
-vn -0.364835 0.902835 -0.227561
-vn -0.380649 0.881072 -0.280745
-vn 0.429318 -0.903147 -0.00354342
-vn 0.497245 -0.867592 -0.00554108
-vn 0.653499 -0.756819 -0.0128425
-vn 0.753958 -0.652837 0.073155
-vn 0.588025 0.806501 -0.061497
-vn 0.587144 0.807351 0.0587076
-vn -0.0867536 0.996216 0.00532369
-vn -0.163089 0.986578 0.0080724
-vn -0.274529 0.961571 0.00397476
-vn -0.333992 0.942573 0.00229158
-vn -0.376358 0.926474 0.000963687
-vn -0.394668 0.918042 -0.0378846
-vn 0.424651 -0.870515 -0.248748
-vn 0.49375 -0.815087 -0.303058
-vn 0.645156 -0.640604 -0.416413
-vn 0.734214 -0.562849 -0.379646
-vn 0.416847 0.848164 -0.32689
-vn 0.50892 0.817653 -0.269154
-vn -0.0841265 0.995674 0.0394371
-vn -0.15549 0.983507 0.0923966
-vn -0.263751 0.950319 0.165316
-vn -0.32283 0.923794 0.205879
-vn -0.36567 0.90164 0.230934
-vn -0.38735 0.901371 0.193622
-vn 0.393697 -0.745941 -0.537192
-vn 0.45145 -0.620593 -0.641138
-vn 0.540186 -0.296357 -0.787637
-vn 0.601561 -0.164349 -0.781738
-vn 0.211196 0.893285 -0.396784
-vn 0.108843 0.977957 -0.178196
-vn -0.136629 0.974762 0.176557
-vn -0.23054 0.91487 0.331459
-vn -0.284705 0.860414 0.422647
-vn -0.325244 0.813827 0.481562
-vn -0.352809 0.819268 0.452024
-vn 0.307449 -0.49055 -0.815374
-vn 0.330077 -0.267386 -0.905292
-vn 0.330039 0.139007 -0.933676
-vn 0.336588 0.324141 -0.884105
-vn 0.0821683 0.926495 -0.367228
-vn 0.0287861 0.987836 -0.152814
-vn -0.108775 0.961382 0.252811
-vn -0.177199 0.857184 0.483566
-vn -0.217436 0.749039 0.62583
-vn -0.247247 0.651696 0.71705
-vn -0.278629 0.650985 0.706105
-vn 0.16377 -0.143114 -0.976062
-vn 0.157958 0.119163 -0.980229
-vn 0.131003 0.466655 -0.874684
-vn 0.0697089 0.818471 -0.570304
-vn -0.0112109 0.994226 -0.106719
-vn -0.0733859 0.944909 0.319001
-vn -0.107364 0.784253 0.611081
-vn -0.124521 0.600451 0.789907
-vn -0.135383 0.431831 0.891736
-vn -0.164063 0.406048 0.899004
-vn 0.00179321 0.187608 -0.982242
-vn -0.00111138 0.412665 -0.910882
-vn -0.00856401 0.659803 -0.75139
-vn -0.0194059 0.884076 -0.46694
-vn -0.0282565 0.997791 -0.0601185
-vn -0.0284903 0.92587 0.376766
-vn -0.0201708 0.700374 0.713491
-vn -0.00939145 0.429203 0.903159
-vn -0.00136048 0.189102 0.981956
-vn -0.0237959 0.128925 0.991369
-vn -0.147027 0.440027 -0.885867
-vn -0.129161 0.604806 -0.785829
-vn -0.107487 0.772028 -0.626433
-vn -0.0771945 0.921772 -0.37997
-vn -0.0308344 0.999365 -0.0178318
-vn 0.0329864 0.903899 0.426472
-vn 0.0938605 0.603699 0.791668
-vn 0.131064 0.241996 0.961385
-vn 0.147173 -0.050239 0.987834
-vn 0.126304 -0.13583 0.982648
-vn -0.279752 0.620786 -0.732368
-vn -0.237724 0.7333 -0.636991
-vn -0.18824 0.844551 -0.501297
-vn -0.122085 0.94633 -0.299256
-vn -0.0212378 0.999644 0.0161742
-vn 0.12572 0.87912 0.45972
-vn 0.256927 0.487013 0.83475
-vn 0.308473 0.0352588 0.950579
-vn 0.310672 -0.277951 0.90897
-vn 0.280432 -0.368897 0.886156
-vn -0.397934 0.749558 -0.528972
-vn -0.334175 0.823981 -0.457583
-vn -0.260143 0.896107 -0.359607
-vn -0.161015 0.9636 -0.213424
-vn -0.000282529 0.999468 0.0326103
-vn 0.27218 0.855209 0.441062
-vn 0.503968 0.344295 0.792134
-vn 0.532621 -0.193514 0.823934
-vn 0.485905 -0.488765 0.724572
-vn 0.435364 -0.565205 0.700715
-vn -0.486561 0.829083 -0.275463
-vn -0.407986 0.881106 -0.239163
-vn -0.316445 0.929479 -0.189554
-vn -0.191948 0.974681 -0.114681
-vn 0.023404 0.999546 0.0189881
-vn 0.141046 0.980105 0.139644
-vn 0.719358 0.577923 0.385394
-vn 0.621798 0.601961 0.501009
-vn 0.855279 -0.249652 0.45406
-vn 0.762158 -0.405838 0.504392
-vn 0.638009 -0.65178 0.410033
-vn 0.569712 -0.708678 0.416176
-vn 0.485154 0.727497 0.485154
-vn 0.269344 0.946195 0.179357
-vn 0.271887 0.948531 0.16238
-vn 0.402316 0.91292 0.0686913
-vn 0.408377 0.912799 0.00516256
-vn 0.588636 0.804725 0.0769758
-vn 0.593655 0.804572 -0.015396
-vn 0.999654 0.00430715 0.0259322
-vn 0.98336 0.00427649 0.181619
-vn 0.950447 -0.273534 0.147752
-vn 0.961931 -0.273287 -0.00202827
-vn 0.791548 -0.598499 0.123496
-vn 0.801364 -0.598175 -0.00128343
-vn 0.736801 -0.666049 0.116201
-vn 0.74619 -0.665733 -4.44422e-05
-vn 0.775202 -0.619513 0.123551
-vn 0.78525 -0.619177 0.00107502
-vn 0.906681 -0.395876 0.145642
-vn 0.918432 -0.395574 0.0021026
-vn 0.969637 -0.208623 0.127592
-vn 0.977697 -0.20847 -0.0254845
-vn 0.832643 0.553381 0.0217873
-vn 0.818734 0.553639 0.152178
-vn 0.658671 0.745841 0.0993684
-vn 0.666302 0.745665 -0.0050548
-vn 0.264169 0.944368 0.195918
-vn 0.386081 0.913141 0.130826
-vn 0.568719 0.805153 0.168188
-vn 0.942652 0.00422392 0.333749
-vn 0.915375 -0.273941 0.295034
-vn 0.761893 -0.59915 0.246044
-vn 0.708877 -0.666692 0.230252
-vn 0.745659 -0.6202 0.243605
-vn 0.872242 -0.396543 0.286264
-vn 0.937383 -0.209154 0.278509
-vn 0.784348 0.554032 0.279011
-vn 0.634471 0.746166 0.201701
-vn 0.256594 0.943079 0.211569
-vn 0.360354 0.91335 0.18957
-vn 0.534707 0.805603 0.25513
-vn 0.878627 0.00417088 0.47749
-vn 0.857637 -0.274323 0.434978
-vn 0.713414 -0.599793 0.362338
-vn 0.66346 -0.667328 0.338369
-vn 0.697731 -0.620883 0.357318
-vn 0.816292 -0.397226 0.419379
-vn 0.881935 -0.209763 0.422125
-vn 0.730757 0.55437 0.398332
-vn 0.594687 0.746462 0.298568
-vn 0.24687 0.942337 0.225957
-vn 0.326002 0.91349 0.243432
-vn 0.487792 0.805932 0.335459
-vn 0.793139 0.00413565 0.609026
-vn 0.77895 -0.274574 0.563778
-vn 0.64767 -0.600236 0.469297
-vn 0.602058 -0.667768 0.437736
-vn 0.633011 -0.621356 0.46175
-vn 0.740642 -0.397711 0.541549
-vn 0.805121 -0.210241 0.554597
-vn 0.659672 0.554577 0.507225
-vn 0.54068 0.746652 0.387524
-vn 0.235232 0.942143 0.238815
-vn 0.283929 0.913534 0.291273
-vn 0.429279 0.806072 0.407391
-vn 0.688369 0.00412918 0.725349
-vn 0.681382 -0.274643 0.678447
-vn 0.56641 -0.600391 0.564545
-vn 0.526308 -0.667922 0.526195
-vn 0.553224 -0.621522 0.554665
-vn 0.647295 -0.397888 0.65015
-vn 0.70905 -0.210464 0.673018
-vn 0.572886 0.554634 0.603475
-vn 0.473888 0.746714 0.466742
-vn 0.221903 0.942497 0.249918
-vn 0.235036 0.913476 0.332144
-vn 0.360482 0.806003 0.469481
-vn 0.566666 0.00415282 0.823937
-vn 0.567147 -0.274517 0.776521
-vn 0.471434 -0.600231 0.646122
-vn 0.437863 -0.667765 0.601968
-vn 0.460096 -0.621352 0.634218
-vn 0.538267 -0.397726 0.743023
-vn 0.595857 -0.210385 0.775044
-vn 0.472189 0.554537 0.68522
-vn 0.395716 0.746644 0.534726
-vn 0.207114 0.943399 0.259042
-vn 0.180292 0.913325 0.365148
-vn 0.282786 0.805734 0.520408
-vn 0.430673 0.00420011 0.902498
-vn 0.438694 -0.274219 0.855775
-vn 0.364692 -0.599783 0.712222
-vn 0.3385 -0.667324 0.663398
-vn 0.355471 -0.620877 0.698679
-vn 0.415722 -0.397256 0.818146
-vn 0.467781 -0.210017 0.85853
-vn 0.35952 0.554289 0.750672
-vn 0.307633 0.746448 0.590066
-vn 0.191153 0.944847 0.265943
-vn 0.120883 0.913109 0.389383
-vn 0.197833 0.805314 0.558866
-vn 0.28364 0.00425563 0.958921
-vn 0.298991 -0.273813 0.914128
-vn 0.24855 -0.599138 0.761089
-vn 0.230374 -0.666687 0.708842
-vn 0.241597 -0.620194 0.746318
-vn 0.282302 -0.396583 0.873515
-vn 0.327496 -0.209447 0.921346
-vn 0.237294 0.55392 0.798038
-vn 0.211423 0.746151 0.631315
-vn 0.174409 0.946827 0.27037
-vn 0.0583684 0.912895 0.404001
-vn 0.107758 0.804856 0.583605
-vn 0.129703 0.00429851 0.991544
-vn 0.151827 -0.273429 0.949834
-vn 0.126076 -0.598489 0.791148
-vn 0.116306 -0.666045 0.736788
-vn 0.121429 -0.61951 0.77554
-vn 0.14148 -0.395912 0.907324
-vn 0.178502 -0.208841 0.961521
-vn 0.108728 0.553535 0.825698
-vn 0.109466 0.745832 0.657079
-vn 0.157424 0.949302 0.272108
-vn -0.0051626 0.912799 0.408377
-vn 0.015396 0.804572 0.593655
-vn -0.0259322 0.00430715 0.999654
-vn 0.00202818 -0.273286 0.961931
-vn 0.00128336 -0.598175 0.801364
-vn 4.43713e-05 -0.665733 0.74619
-vn -0.00107509 -0.619177 0.78525
-vn -0.00210269 -0.395574 0.918432
-vn 0.0254844 -0.20847 0.977697
-vn -0.0217873 0.553381 0.832643
-vn 0.00505474 0.745665 0.666302
-vn 0.14042 0.952262 0.271071
-vn -0.0686913 0.91292 0.402316
-vn -0.0769758 0.804725 0.588636
-vn -0.181619 0.00427647 0.98336
-vn -0.147752 -0.273533 0.950447
-vn -0.123496 -0.598499 0.791548
-vn -0.116201 -0.666049 0.736801
-vn -0.123551 -0.619513 0.775202
-vn -0.145642 -0.395876 0.906681
-vn -0.127592 -0.208623 0.969637
-vn -0.152178 0.553639 0.818734
-vn -0.0993684 0.745841 0.658671
-vn 0.123796 0.955623 0.267318
-vn -0.130826 0.913141 0.386081
-vn -0.168188 0.805153 0.568719
-vn -0.333749 0.00422392 0.942652
-vn -0.295034 -0.273941 0.915375
-vn -0.246044 -0.59915 0.761893
-vn -0.230252 -0.666692 0.708877
-vn -0.243605 -0.6202 0.745659
-vn -0.286264 -0.396543 0.872242
-vn -0.278509 -0.209154 0.937383
-vn -0.279011 0.554032 0.784348
-vn -0.201701 0.746166 0.634471
-vn 0.108066 0.959271 0.261
-vn -0.18957 0.91335 0.360354
-vn -0.25513 0.805603 0.534707
-vn -0.47749 0.00417088 0.878627
-vn -0.434978 -0.274323 0.857637
-vn -0.362338 -0.599793 0.713414
-vn -0.338369 -0.667328 0.66346
-vn -0.357318 -0.620883 0.697731
-vn -0.419379 -0.397226 0.816292
-vn -0.422125 -0.209763 0.881935
-vn -0.398332 0.55437 0.730757
-vn -0.298568 0.746462 0.594687
-vn 0.0936157 0.963107 0.252311
-vn -0.243432 0.91349 0.326002
-vn -0.335459 0.805932 0.487792
-vn -0.609026 0.00413565 0.793139
-vn -0.563778 -0.274574 0.77895
-vn -0.469297 -0.600236 0.64767
-vn -0.437736 -0.667768 0.602058
-vn -0.46175 -0.621356 0.633011
-vn -0.541549 -0.397711 0.740642
-vn -0.554597 -0.210241 0.805121
-vn -0.507225 0.554577 0.659672
-vn -0.387524 0.746652 0.54068
-vn 0.0807511 0.967048 0.24145
-vn -0.291273 0.913534 0.283929
-vn -0.407391 0.806072 0.429279
-vn -0.725349 0.00412918 0.688369
-vn -0.678447 -0.274643 0.681382
-vn -0.564545 -0.600391 0.56641
-vn -0.526195 -0.667922 0.526308
-vn -0.554665 -0.621522 0.553224
-vn -0.65015 -0.397888 0.647295
-vn -0.673018 -0.210464 0.70905
-vn -0.603475 0.554634 0.572886
-vn -0.466742 0.746714 0.473888
-vn 0.0697392 0.971015 0.228617
-vn -0.332144 0.913476 0.235036
-vn -0.469481 0.806003 0.360482
-vn -0.823937 0.00415282 0.566666
-vn -0.77652 -0.274517 0.567147
-vn -0.646122 -0.600231 0.471434
-vn -0.601968 -0.667765 0.437863
-vn -0.634218 -0.621352 0.460096
-vn -0.743023 -0.397726 0.538267
-vn -0.775044 -0.210385 0.595857
-vn -0.68522 0.554537 0.472189
-vn -0.534726 0.746644 0.395716
-vn 0.0608475 0.974929 0.214036
-vn -0.365148 0.913325 0.180292
-vn -0.520408 0.805734 0.282786
-vn -0.902498 0.00420011 0.430673
-vn -0.855775 -0.274219 0.438694
-vn -0.712222 -0.599783 0.364692
-vn -0.663398 -0.667324 0.3385
-vn -0.698679 -0.620877 0.355471
-vn -0.818146 -0.397256 0.415722
-vn -0.85853 -0.210017 0.467781
-vn -0.750672 0.554289 0.35952
-vn -0.590066 0.746448 0.307633
-vn 0.0543577 0.978693 0.198001
-vn -0.389383 0.913109 0.120883
-vn -0.558866 0.805314 0.197833
-vn -0.958921 0.00425564 0.28364
-vn -0.914128 -0.273813 0.298991
-vn -0.761089 -0.599138 0.24855
-vn -0.708842 -0.666687 0.230374
-vn -0.746318 -0.620194 0.241597
-vn -0.873515 -0.396583 0.282302
-vn -0.921346 -0.209447 0.327496
-vn -0.798038 0.55392 0.237294
-vn -0.631315 0.746151 0.211423
-vn 0.0505483 0.982197 0.180924
-vn -0.404001 0.912895 0.0583684
-vn -0.583605 0.804856 0.107758
-vn -0.991544 0.00429853 0.129703
-vn -0.949834 -0.27343 0.151827
-vn -0.791148 -0.598489 0.126076
-vn -0.736788 -0.666045 0.116306
-vn -0.77554 -0.61951 0.121429
-vn -0.907324 -0.395912 0.14148
-vn -0.961521 -0.208841 0.178502
-vn -0.825698 0.553535 0.108728
-vn -0.657079 0.745832 0.109466
-vn 0.0496422 0.985311 0.163395
-vn -0.408377 0.912799 -0.0051626
-vn -0.593655 0.804572 0.015396
-vn -0.999654 0.00430715 -0.0259322
-vn -0.961931 -0.273287 0.00202818
-vn -0.801364 -0.598175 0.00128335
-vn -0.74619 -0.665733 4.43763e-05
-vn -0.78525 -0.619177 -0.00107509
-vn -0.918432 -0.395574 -0.00210269
-vn -0.977697 -0.20847 0.0254844
-vn -0.832643 0.553381 -0.0217874
-vn -0.666302 0.745665 0.00505473
-vn 0.0517256 0.987978 0.145686
-vn -0.402316 0.91292 -0.0686913
-vn -0.588636 0.804725 -0.0769758
-vn -0.98336 0.00427646 -0.181619
-vn -0.950447 -0.273534 -0.147752
-vn -0.791548 -0.598499 -0.123496
-vn -0.736801 -0.666049 -0.116201
-vn -0.775202 -0.619513 -0.123551
-vn -0.906681 -0.395876 -0.145642
-vn -0.969637 -0.208623 -0.127592
-vn -0.818734 0.553639 -0.152178
-vn -0.658671 0.745841 -0.0993684
-vn 0.0567063 0.990117 0.128264
-vn -0.386081 0.913141 -0.130826
-vn -0.568719 0.805153 -0.168188
-vn -0.942652 0.00422392 -0.333749
-vn -0.915375 -0.273941 -0.295034
-vn -0.761893 -0.59915 -0.246044
-vn -0.708877 -0.666692 -0.230252
-vn -0.745659 -0.6202 -0.243605
-vn -0.872242 -0.396543 -0.286264
-vn -0.937383 -0.209154 -0.278509
-vn -0.784348 0.554032 -0.279011
-vn -0.634471 0.746166 -0.201701
-vn 0.0643694 0.991654 0.111714
-vn -0.360354 0.91335 -0.18957
-vn -0.534707 0.805603 -0.25513
-vn -0.878627 0.00417088 -0.47749
-vn -0.857637 -0.274323 -0.434978
-vn -0.713414 -0.599793 -0.362338
-vn -0.66346 -0.667328 -0.338369
-vn -0.697731 -0.620883 -0.357318
-vn -0.816292 -0.397226 -0.419379
-vn -0.881935 -0.209763 -0.422125
-vn -0.730757 0.55437 -0.398332
-vn -0.594687 0.746462 -0.298568
-vn 0.0744504 0.992547 0.0964774
-vn -0.326002 0.91349 -0.243431
-vn -0.487792 0.805932 -0.335459
-vn -0.793139 0.00413565 -0.609026
-vn -0.77895 -0.274574 -0.563778
-vn -0.64767 -0.600236 -0.469297
-vn -0.602058 -0.667768 -0.437736
-vn -0.633011 -0.621356 -0.46175
-vn -0.740642 -0.397711 -0.541549
-vn -0.805121 -0.210241 -0.554597
-vn -0.659672 0.554577 -0.507225
-vn -0.54068 0.746652 -0.387524
-vn 0.0866756 0.992781 0.0828999
-vn -0.283929 0.913534 -0.291272
-vn -0.42928 0.806072 -0.40739
-vn -0.688369 0.00412918 -0.725349
-vn -0.681382 -0.274643 -0.678447
-vn -0.56641 -0.600391 -0.564545
-vn -0.526307 -0.667922 -0.526195
-vn -0.553224 -0.621522 -0.554665
-vn -0.647295 -0.397888 -0.65015
-vn -0.70905 -0.210464 -0.673018
-vn -0.572886 0.554634 -0.603475
-vn -0.473888 0.746714 -0.466742
-vn 0.100769 0.992354 0.0712717
-vn -0.235036 0.913476 -0.332144
-vn -0.360482 0.806003 -0.469481
-vn -0.566666 0.00415282 -0.823937
-vn -0.567147 -0.274518 -0.77652
-vn -0.471434 -0.600231 -0.646121
-vn -0.437863 -0.667765 -0.601968
-vn -0.460096 -0.621353 -0.634218
-vn -0.538267 -0.397726 -0.743023
-vn -0.595857 -0.210385 -0.775044
-vn -0.472189 0.554537 -0.68522
-vn -0.395716 0.746644 -0.534726
-vn 0.11643 0.99127 0.0618674
-vn -0.180292 0.913325 -0.365148
-vn -0.282786 0.805734 -0.520408
-vn -0.430673 0.00420011 -0.902498
-vn -0.438694 -0.274219 -0.855775
-vn -0.364692 -0.599783 -0.712222
-vn -0.3385 -0.667324 -0.663398
-vn -0.355471 -0.620877 -0.698679
-vn -0.415722 -0.397256 -0.818146
-vn -0.467781 -0.210017 -0.85853
-vn -0.35952 0.554289 -0.750672
-vn -0.307633 0.746448 -0.590066
-vn 0.133289 0.989552 0.0549608
-vn -0.120883 0.913109 -0.389383
-vn -0.197833 0.805314 -0.558866
-vn -0.28364 0.00425563 -0.958921
-vn -0.298991 -0.273813 -0.914128
-vn -0.24855 -0.599138 -0.761089
-vn -0.230374 -0.666687 -0.708842
-vn -0.241597 -0.620194 -0.746318
-vn -0.282302 -0.396583 -0.873515
-vn -0.327496 -0.209447 -0.921346
-vn -0.237294 0.55392 -0.798038
-vn -0.211423 0.746151 -0.631315
-vn 0.150866 0.987248 0.0508083
-vn -0.0583684 0.912895 -0.404001
-vn -0.107758 0.804856 -0.583605
-vn -0.129703 0.00429851 -0.991544
-vn -0.151827 -0.27343 -0.949834
-vn -0.126076 -0.59849 -0.791148
-vn -0.116306 -0.666045 -0.736788
-vn -0.121429 -0.61951 -0.77554
-vn -0.14148 -0.395912 -0.907324
-vn -0.178502 -0.208841 -0.961521
-vn -0.108728 0.553535 -0.825698
-vn -0.109466 0.745832 -0.657079
-vn 0.168529 0.984448 0.0495987
-vn 0.0051626 0.912799 -0.408377
-vn -0.015396 0.804572 -0.593655
-vn 0.0259322 0.00430715 -0.999654
-vn -0.00202818 -0.273287 -0.961931
-vn -0.00128335 -0.598175 -0.801364
-vn -4.43713e-05 -0.665733 -0.74619
-vn 0.00107509 -0.619177 -0.78525
-vn 0.00210269 -0.395574 -0.918432
-vn -0.0254844 -0.20847 -0.977697
-vn 0.0217874 0.553381 -0.832643
-vn -0.00505473 0.745665 -0.666302
-vn 0.185994 0.981207 0.0513712
-vn 0.0686913 0.91292 -0.402316
-vn 0.0769758 0.804725 -0.588636
-vn 0.181619 0.00427647 -0.98336
-vn 0.147752 -0.273534 -0.950447
-vn 0.123496 -0.598499 -0.791548
-vn 0.116201 -0.666049 -0.736801
-vn 0.123551 -0.619513 -0.775202
-vn 0.145642 -0.395876 -0.906681
-vn 0.127592 -0.208623 -0.969637
-vn 0.152178 0.553639 -0.818734
-vn 0.0993684 0.745841 -0.658671
-vn 0.202815 0.977615 0.0559903
-vn 0.130826 0.913141 -0.386081
-vn 0.168188 0.805153 -0.568719
-vn 0.333749 0.00422392 -0.942652
-vn 0.295034 -0.273941 -0.915375
-vn 0.246044 -0.599151 -0.761893
-vn 0.230252 -0.666692 -0.708877
-vn 0.243605 -0.6202 -0.745659
-vn 0.286264 -0.396543 -0.872242
-vn 0.278509 -0.209154 -0.937383
-vn 0.279011 0.554032 -0.784348
-vn 0.201701 0.746166 -0.634471
-vn 0.21846 0.973796 0.0632103
-vn 0.18957 0.91335 -0.360354
-vn 0.25513 0.805603 -0.534707
-vn 0.47749 0.00417088 -0.878627
-vn 0.434978 -0.274324 -0.857636
-vn 0.362338 -0.599793 -0.713414
-vn 0.338369 -0.667328 -0.66346
-vn 0.357318 -0.620883 -0.697731
-vn 0.419379 -0.397226 -0.816292
-vn 0.422125 -0.209763 -0.881935
-vn 0.398332 0.55437 -0.730757
-vn 0.298568 0.746462 -0.594687
-vn 0.232556 0.969858 0.0727485
-vn 0.243432 0.91349 -0.326002
-vn 0.335459 0.805932 -0.487792
-vn 0.609026 0.00413565 -0.793139
-vn 0.563778 -0.274574 -0.77895
-vn 0.469297 -0.600236 -0.64767
-vn 0.437736 -0.667768 -0.602058
-vn 0.46175 -0.621356 -0.633011
-vn 0.541549 -0.397711 -0.740642
-vn 0.554597 -0.210241 -0.805121
-vn 0.507225 0.554577 -0.659672
-vn 0.387524 0.746652 -0.54068
-vn 0.244835 0.965891 0.0843279
-vn 0.291273 0.913534 -0.283929
-vn 0.407391 0.806072 -0.429279
-vn 0.725349 0.00412918 -0.688369
-vn 0.678447 -0.274643 -0.681382
-vn 0.564545 -0.600391 -0.56641
-vn 0.526195 -0.667922 -0.526308
-vn 0.554665 -0.621522 -0.553224
-vn 0.65015 -0.397888 -0.647295
-vn 0.673018 -0.210464 -0.70905
-vn 0.603475 0.554634 -0.572886
-vn 0.466742 0.746714 -0.473888
-vn 0.255083 0.961972 0.0976841
-vn 0.332144 0.913476 -0.235036
-vn 0.469481 0.806003 -0.360482
-vn 0.823937 0.00415282 -0.566666
-vn 0.776521 -0.274517 -0.567147
-vn 0.646121 -0.600231 -0.471434
-vn 0.601968 -0.667765 -0.437863
-vn 0.634218 -0.621353 -0.460096
-vn 0.743023 -0.397726 -0.538267
-vn 0.775044 -0.210385 -0.595857
-vn 0.68522 0.554537 -0.472189
-vn 0.534726 0.746644 -0.395716
-vn 0.263101 0.958182 0.112544
-vn 0.365148 0.913325 -0.180292
-vn 0.520408 0.805734 -0.282786
-vn 0.902498 0.00420011 -0.430672
-vn 0.855775 -0.274219 -0.438694
-vn 0.712222 -0.599783 -0.364692
-vn 0.663398 -0.667324 -0.3385
-vn 0.698679 -0.620877 -0.355471
-vn 0.818146 -0.397256 -0.415723
-vn 0.85853 -0.210017 -0.467781
-vn 0.750672 0.554289 -0.35952
-vn 0.590066 0.746448 -0.307633
-vn 0.26869 0.954606 0.128582
-vn 0.389383 0.913109 -0.120883
-vn 0.558866 0.805314 -0.197833
-vn 0.958921 0.00425562 -0.28364
-vn 0.914128 -0.273813 -0.298991
-vn 0.761089 -0.599138 -0.24855
-vn 0.708842 -0.666687 -0.230374
-vn 0.746318 -0.620194 -0.241597
-vn 0.873515 -0.396583 -0.282302
-vn 0.921346 -0.209447 -0.327496
-vn 0.798038 0.55392 -0.237294
-vn 0.631315 0.746151 -0.211423
-vn 0.271662 0.951349 0.14538
-vn 0.404001 0.912895 -0.0583685
-vn 0.583605 0.804856 -0.107758
-vn 0.991544 0.0042985 -0.129703
-vn 0.949834 -0.27343 -0.151827
-vn 0.791148 -0.598489 -0.126076
-vn 0.736788 -0.666045 -0.116306
-vn 0.77554 -0.61951 -0.121429
-vn 0.907324 -0.395912 -0.14148
-vn 0.961521 -0.208841 -0.178502
-vn 0.825698 0.553535 -0.108728
-vn 0.657079 0.745832 -0.109466
-vn 0.369514 0.927513 0.0563833
-vn 0.373974 0.927437 -0.00222136
-vn 0.243644 0.969137 0.0375661
-vn 0.246661 0.969101 -0.00108334
-vn 0.182353 0.982823 0.0283979
-vn 0.18466 0.982802 -0.0005348
-vn 0.154555 0.987685 0.0243279
-vn 0.156553 0.98767 -0.000199722
-vn 0.149931 0.988408 0.0238785
-vn 0.151916 0.988393 7.85074e-05
-vn 0.168302 0.985362 0.0271327
-vn 0.170583 0.985343 0.000409367
-vn 0.22036 0.974755 0.0359688
-vn 0.223415 0.974723 0.000969794
-vn 0.343628 0.937382 0.0568728
-vn 0.348499 0.937306 0.00227713
-vn 0.658294 0.744639 0.110276
-vn 0.66765 0.744454 0.00564208
-vn 0.841632 0.528539 0.11091
-vn 0.848722 0.528372 -0.0222079
-vn 0.355658 0.92767 0.113736
-vn 0.234421 0.969209 0.0753679
-vn 0.175399 0.982864 0.0566839
-vn 0.148618 0.987715 0.0482972
-vn 0.144129 0.988436 0.0471262
-vn 0.161738 0.985397 0.0532242
-vn 0.211701 0.974815 0.0701258
-vn 0.330036 0.937521 0.110137
-vn 0.632361 0.744986 0.212406
-vn 0.81339 0.528998 0.241985
-vn 0.333086 0.927819 0.167945
-vn 0.219458 0.969278 0.11108
-vn 0.164153 0.982903 0.0833962
-vn 0.139047 0.987743 0.0709253
-vn 0.134802 0.988463 0.0690655
-vn 0.151221 0.985431 0.0778385
-vn 0.197871 0.974871 0.102338
-vn 0.30838 0.93765 0.160356
-vn 0.590925 0.745309 0.308743
-vn 0.765064 0.529462 0.366534
-vn 0.302622 0.92792 0.217682
-vn 0.199307 0.969324 0.143833
-vn 0.149031 0.982929 0.107888
-vn 0.126193 0.987761 0.0916662
-vn 0.122294 0.98848 0.0891684
-vn 0.137137 0.985452 0.100385
-vn 0.179371 0.974908 0.131835
-vn 0.279436 0.937734 0.206325
-vn 0.535397 0.745523 0.39692
-vn 0.698333 0.52979 0.481304
-vn 0.265085 0.927956 0.26197
-vn 0.174509 0.96934 0.172991
-vn 0.130435 0.982938 0.129686
-vn 0.1104 0.987768 0.11012
-vn 0.106938 0.988486 0.107048
-vn 0.119857 0.98546 0.12043
-vn 0.156689 0.97492 0.158048
-vn 0.243962 0.937762 0.247154
-vn 0.46723 0.745595 0.475168
-vn 0.615041 0.529929 0.583866
-vn 0.221244 0.927924 0.300013
-vn 0.145568 0.969326 0.198035
-vn 0.108744 0.98293 0.148403
-vn 0.0919859 0.987762 0.125958
-vn 0.0890428 0.98848 0.122387
-vn 0.0997296 0.985452 0.137617
-vn 0.130282 0.974907 0.18051
-vn 0.202674 0.93773 0.28211
-vn 0.387828 0.745519 0.542025
-vn 0.517 0.529862 0.672278
-vn 0.17189 0.927828 0.331042
-vn 0.113001 0.969281 0.21846
-vn 0.0843445 0.982904 0.163661
-vn 0.0712793 0.987743 0.138863
-vn 0.0689263 0.988462 0.134874
-vn 0.0771126 0.98543 0.151598
-vn 0.100618 0.974869 0.198764
-vn 0.156312 0.937642 0.310475
-vn 0.298674 0.745303 0.596085
-vn 0.406076 0.529597 0.744735
-vn 0.11798 0.927681 0.354244
-vn 0.0774359 0.969213 0.233729
-vn 0.0577044 0.982866 0.175057
-vn 0.0486782 0.987715 0.14849
-vn 0.0469764 0.988436 0.144179
-vn 0.052443 0.985396 0.162
-vn 0.0682746 0.974812 0.212321
-vn 0.105787 0.93751 0.331487
-vn 0.20159 0.744977 0.635901
-vn 0.284492 0.52917 0.799402
-vn 0.0608097 0.927523 0.368787
-vn 0.039724 0.96914 0.243287
-vn 0.029463 0.982824 0.182177
-vn 0.0247257 0.987685 0.154489
-vn 0.0237221 0.988408 0.149957
-vn 0.0263172 0.985361 0.168438
-vn 0.0340369 0.974752 0.22068
-vn 0.0523355 0.937373 0.344373
-vn 0.0990193 0.744632 0.66009
-vn 0.155194 0.528686 0.83451
-vn 0.0022213 0.927437 0.373974
-vn 0.00108332 0.969101 0.246661
-vn 0.000534782 0.982802 0.18466
-vn 0.000199707 0.98767 0.156553
-vn -7.85217e-05 0.988393 0.151916
-vn -0.000409384 0.985343 0.170583
-vn -0.000969815 0.974723 0.223415
-vn -0.00227717 0.937306 0.348499
-vn -0.00564214 0.744454 0.66765
-vn 0.0222078 0.528372 0.848722
-vn -0.0563833 0.927513 0.369514
-vn -0.0375661 0.969137 0.243644
-vn -0.0283979 0.982823 0.182353
-vn -0.0243279 0.987685 0.154555
-vn -0.0238785 0.988408 0.149931
-vn -0.0271327 0.985362 0.168302
-vn -0.0359688 0.974755 0.22036
-vn -0.0568728 0.937382 0.343628
-vn -0.110276 0.744639 0.658294
-vn -0.11091 0.528539 0.841632
-vn -0.113736 0.92767 0.355658
-vn -0.0753679 0.969209 0.234421
-vn -0.0566839 0.982864 0.175399
-vn -0.0482972 0.987715 0.148618
-vn -0.0471262 0.988436 0.144129
-vn -0.0532242 0.985397 0.161738
-vn -0.0701258 0.974815 0.211701
-vn -0.110137 0.937521 0.330036
-vn -0.212406 0.744985 0.632361
-vn -0.241985 0.528998 0.81339
-vn -0.167945 0.927819 0.333086
-vn -0.11108 0.969278 0.219458
-vn -0.0833962 0.982903 0.164153
-vn -0.0709253 0.987743 0.139047
-vn -0.0690655 0.988463 0.134802
-vn -0.0778385 0.985431 0.151221
-vn -0.102338 0.974871 0.197871
-vn -0.160356 0.93765 0.30838
-vn -0.308743 0.745309 0.590925
-vn -0.366534 0.529462 0.765064
-vn -0.217682 0.92792 0.302622
-vn -0.143833 0.969324 0.199307
-vn -0.107888 0.982929 0.149031
-vn -0.0916662 0.987761 0.126193
-vn -0.0891684 0.98848 0.122294
-vn -0.100385 0.985452 0.137137
-vn -0.131835 0.974908 0.179371
-vn -0.206325 0.937734 0.279436
-vn -0.39692 0.745523 0.535397
-vn -0.481304 0.52979 0.698333
-vn -0.26197 0.927956 0.265085
-vn -0.172991 0.96934 0.174509
-vn -0.129686 0.982938 0.130435
-vn -0.11012 0.987768 0.1104
-vn -0.107048 0.988486 0.106938
-vn -0.12043 0.98546 0.119857
-vn -0.158048 0.97492 0.156689
-vn -0.247154 0.937762 0.243962
-vn -0.475168 0.745595 0.46723
-vn -0.583866 0.529929 0.615041
-vn -0.300013 0.927924 0.221244
-vn -0.198035 0.969326 0.145568
-vn -0.148403 0.98293 0.108744
-vn -0.125958 0.987762 0.0919859
-vn -0.122387 0.98848 0.0890428
-vn -0.137617 0.985452 0.0997296
-vn -0.18051 0.974907 0.130282
-vn -0.28211 0.93773 0.202674
-vn -0.542025 0.745519 0.387828
-vn -0.672278 0.529862 0.517
-vn -0.331042 0.927828 0.17189
-vn -0.21846 0.969281 0.113001
-vn -0.163661 0.982904 0.0843445
-vn -0.138863 0.987743 0.0712793
-vn -0.134874 0.988462 0.0689263
-vn -0.151598 0.98543 0.0771126
-vn -0.198764 0.974869 0.100618
-vn -0.310475 0.937642 0.156312
-vn -0.596085 0.745303 0.298674
-vn -0.744735 0.529597 0.406076
-vn -0.354244 0.927681 0.11798
-vn -0.233729 0.969213 0.077436
-vn -0.175057 0.982866 0.0577045
-vn -0.14849 0.987715 0.0486782
-vn -0.144179 0.988436 0.0469764
-vn -0.162 0.985396 0.052443
-vn -0.212321 0.974812 0.0682746
-vn -0.331487 0.93751 0.105787
-vn -0.635901 0.744977 0.20159
-vn -0.799402 0.52917 0.284492
-vn -0.368787 0.927523 0.0608097
-vn -0.243287 0.96914 0.0397241
-vn -0.182177 0.982824 0.029463
-vn -0.154489 0.987685 0.0247257
-vn -0.149957 0.988408 0.0237221
-vn -0.168438 0.985361 0.0263172
-vn -0.22068 0.974752 0.0340369
-vn -0.344373 0.937373 0.0523355
-vn -0.66009 0.744632 0.0990193
-vn -0.83451 0.528686 0.155194
-vn -0.373974 0.927437 0.00222133
-vn -0.246661 0.969101 0.00108332
-vn -0.18466 0.982802 0.000534782
-vn -0.156553 0.98767 0.000199707
-vn -0.151916 0.988393 -7.85227e-05
-vn -0.170583 0.985343 -0.000409384
-vn -0.223415 0.974723 -0.000969816
-vn -0.348499 0.937306 -0.00227717
-vn -0.66765 0.744454 -0.00564214
-vn -0.848722 0.528372 0.0222078
-vn -0.369514 0.927513 -0.0563833
-vn -0.243644 0.969137 -0.0375661
-vn -0.182353 0.982823 -0.0283979
-vn -0.154555 0.987685 -0.0243279
-vn -0.149931 0.988408 -0.0238785
-vn -0.168302 0.985362 -0.0271327
-vn -0.22036 0.974755 -0.0359688
-vn -0.343628 0.937382 -0.0568728
-vn -0.658294 0.744639 -0.110276
-vn -0.841632 0.528539 -0.11091
-vn -0.355658 0.92767 -0.113736
-vn -0.234421 0.969209 -0.0753679
-vn -0.175399 0.982864 -0.0566839
-vn -0.148618 0.987715 -0.0482972
-vn -0.144129 0.988436 -0.0471262
-vn -0.161738 0.985397 -0.0532242
-vn -0.211701 0.974815 -0.0701258
-vn -0.330036 0.937521 -0.110137
-vn -0.632361 0.744985 -0.212406
-vn -0.81339 0.528998 -0.241985
-vn -0.333086 0.927819 -0.167945
-vn -0.219458 0.969278 -0.11108
-vn -0.164153 0.982903 -0.0833962
-vn -0.139047 0.987743 -0.0709253
-vn -0.134802 0.988463 -0.0690655
-vn -0.151221 0.985431 -0.0778385
-vn -0.197871 0.974871 -0.102338
-vn -0.30838 0.93765 -0.160356
-vn -0.590925 0.745309 -0.308743
-vn -0.765064 0.529462 -0.366534
-vn -0.302622 0.92792 -0.217682
-vn -0.199307 0.969324 -0.143833
-vn -0.149031 0.982929 -0.107888
-vn -0.126193 0.987761 -0.0916662
-vn -0.122294 0.98848 -0.0891684
-vn -0.137137 0.985452 -0.100385
-vn -0.179371 0.974908 -0.131835
-vn -0.279436 0.937734 -0.206325
-vn -0.535397 0.745523 -0.39692
-vn -0.698333 0.52979 -0.481304
-vn -0.265085 0.927956 -0.26197
-vn -0.174509 0.96934 -0.172991
-vn -0.130435 0.982938 -0.129686
-vn -0.1104 0.987768 -0.11012
-vn -0.106938 0.988486 -0.107048
-vn -0.119857 0.98546 -0.12043
-vn -0.156689 0.97492 -0.158048
-vn -0.243962 0.937762 -0.247154
-vn -0.46723 0.745595 -0.475168
-vn -0.615041 0.529929 -0.583866
-vn -0.221244 0.927924 -0.300013
-vn -0.145568 0.969326 -0.198035
-vn -0.108744 0.98293 -0.148403
-vn -0.0919859 0.987762 -0.125958
-vn -0.0890428 0.98848 -0.122387
-vn -0.0997296 0.985452 -0.137617
-vn -0.130282 0.974907 -0.18051
-vn -0.202674 0.93773 -0.28211
-vn -0.387828 0.745519 -0.542025
-vn -0.517 0.529862 -0.672278
-vn -0.17189 0.927828 -0.331042
-vn -0.113001 0.969281 -0.21846
-vn -0.0843445 0.982904 -0.163661
-vn -0.0712793 0.987743 -0.138863
-vn -0.0689263 0.988462 -0.134874
-vn -0.0771126 0.98543 -0.151598
-vn -0.100618 0.974869 -0.198764
-vn -0.156312 0.937642 -0.310475
-vn -0.298674 0.745303 -0.596085
-vn -0.406076 0.529597 -0.744735
-vn -0.11798 0.927681 -0.354244
-vn -0.077436 0.969213 -0.233729
-vn -0.0577045 0.982866 -0.175057
-vn -0.0486782 0.987715 -0.14849
-vn -0.0469764 0.988436 -0.144179
-vn -0.052443 0.985396 -0.162
-vn -0.0682746 0.974812 -0.212321
-vn -0.105787 0.93751 -0.331487
-vn -0.20159 0.744977 -0.635901
-vn -0.284492 0.52917 -0.799402
-vn -0.0608097 0.927523 -0.368787
-vn -0.0397241 0.96914 -0.243287
-vn -0.029463 0.982824 -0.182177
-vn -0.0247257 0.987685 -0.154489
-vn -0.0237221 0.988408 -0.149957
-vn -0.0263172 0.985361 -0.168438
-vn -0.0340369 0.974752 -0.22068
-vn -0.0523355 0.937373 -0.344373
-vn -0.0990193 0.744632 -0.66009
-vn -0.155194 0.528686 -0.83451
-vn -0.00222133 0.927437 -0.373974
-vn -0.00108332 0.969101 -0.246661
-vn -0.000534783 0.982802 -0.18466
-vn -0.000199707 0.98767 -0.156553
-vn 7.85217e-05 0.988393 -0.151916
-vn 0.000409384 0.985343 -0.170583
-vn 0.000969815 0.974723 -0.223415
-vn 0.00227717 0.937306 -0.348499
-vn 0.00564214 0.744454 -0.66765
-vn -0.0222078 0.528372 -0.848722
-vn 0.0563833 0.927513 -0.369514
-vn 0.0375661 0.969137 -0.243644
-vn 0.0283979 0.982823 -0.182353
-vn 0.0243279 0.987685 -0.154555
-vn 0.0238785 0.988408 -0.149931
-vn 0.0271327 0.985362 -0.168302
-vn 0.0359688 0.974755 -0.22036
-vn 0.0568728 0.937382 -0.343628
-vn 0.110276 0.744639 -0.658294
-vn 0.11091 0.528539 -0.841632
-vn 0.113736 0.92767 -0.355658
-vn 0.0753679 0.969209 -0.234421
-vn 0.0566839 0.982864 -0.175399
-vn 0.0482972 0.987715 -0.148618
-vn 0.0471262 0.988436 -0.144129
-vn 0.0532242 0.985397 -0.161738
-vn 0.0701258 0.974815 -0.211701
-vn 0.110137 0.937521 -0.330036
-vn 0.212406 0.744985 -0.632361
-vn 0.241985 0.528998 -0.81339
-vn 0.167945 0.927819 -0.333086
-vn 0.11108 0.969278 -0.219458
-vn 0.0833963 0.982903 -0.164153
-vn 0.0709253 0.987743 -0.139047
-vn 0.0690655 0.988463 -0.134802
-vn 0.0778385 0.985431 -0.151221
-vn 0.102338 0.974871 -0.197871
-vn 0.160356 0.93765 -0.30838
-vn 0.308743 0.745309 -0.590925
-vn 0.366534 0.529462 -0.765064
-vn 0.217682 0.92792 -0.302622
-vn 0.143833 0.969324 -0.199307
-vn 0.107888 0.982929 -0.149031
-vn 0.0916662 0.987761 -0.126193
-vn 0.0891684 0.98848 -0.122294
-vn 0.100385 0.985452 -0.137137
-vn 0.131835 0.974908 -0.179371
-vn 0.206325 0.937734 -0.279436
-vn 0.39692 0.745523 -0.535397
-vn 0.481304 0.52979 -0.698333
-vn 0.26197 0.927956 -0.265085
-vn 0.172991 0.96934 -0.174509
-vn 0.129686 0.982938 -0.130435
-vn 0.11012 0.987768 -0.1104
-vn 0.107048 0.988486 -0.106938
-vn 0.12043 0.98546 -0.119857
-vn 0.158048 0.97492 -0.156689
-vn 0.247154 0.937762 -0.243962
-vn 0.475168 0.745595 -0.46723
-vn 0.583866 0.529929 -0.615041
-vn 0.300013 0.927924 -0.221244
-vn 0.198035 0.969326 -0.145568
-vn 0.148403 0.98293 -0.108744
-vn 0.125958 0.987762 -0.0919859
-vn 0.122387 0.98848 -0.0890428
-vn 0.137617 0.985452 -0.0997296
-vn 0.18051 0.974907 -0.130282
-vn 0.28211 0.93773 -0.202674
-vn 0.542025 0.745519 -0.387828
-vn 0.672278 0.529862 -0.517
-vn 0.331042 0.927828 -0.17189
-vn 0.21846 0.969281 -0.113001
-vn 0.163661 0.982904 -0.0843445
-vn 0.138863 0.987743 -0.0712793
-vn 0.134874 0.988462 -0.0689263
-vn 0.151598 0.98543 -0.0771126
-vn 0.198764 0.974869 -0.100618
-vn 0.310475 0.937642 -0.156312
-vn 0.596085 0.745303 -0.298674
-vn 0.744735 0.529597 -0.406076
-vn 0.354244 0.927681 -0.11798
-vn 0.233729 0.969213 -0.0774359
-vn 0.175057 0.982866 -0.0577044
-vn 0.14849 0.987715 -0.0486782
-vn 0.144179 0.988436 -0.0469764
-vn 0.162 0.985396 -0.052443
-vn 0.212321 0.974812 -0.0682746
-vn 0.331487 0.93751 -0.105787
-vn 0.635901 0.744977 -0.20159
-vn 0.799402 0.52917 -0.284492
-vn 0.368787 0.927523 -0.0608097
-vn 0.243287 0.96914 -0.0397241
-vn 0.182177 0.982824 -0.029463
-vn 0.154489 0.987685 -0.0247257
-vn 0.149957 0.988408 -0.0237221
-vn 0.168438 0.985361 -0.0263172
-vn 0.22068 0.974752 -0.0340369
-vn 0.344373 0.937373 -0.0523356
-vn 0.66009 0.744632 -0.0990194
-vn 0.83451 0.528686 -0.155194
-# 3377 normals
-usemtl m1
-f 1/1/1 2/2/2 3/3/3
-f 3/3/3 4/4/4 1/1/1
-f 4/4/4 3/3/3 5/5/5
-f 5/5/5 6/6/6 4/4/4
-f 6/6/6 5/5/5 7/7/7
-f 7/7/7 8/8/8 6/6/6
-f 8/8/8 7/7/7 9/9/9
-f 9/9/9 10/10/10 8/8/8
-f 10/10/11 9/9/12 11/11/13
-f 11/11/13 12/12/14 10/10/11
-f 12/12/14 11/11/13 13/13/15
-f 13/13/15 14/14/16 12/12/14
-f 14/14/16 13/13/15 15/15/17
-f 15/15/17 16/16/18 14/14/16
-f 16/16/18 15/15/17 17/17/19
-f 17/17/19 18/18/20 16/16/18
-f 18/18/20 17/17/19 19/19/21
-f 19/19/21 20/20/22 18/18/20
-f 20/20/22 19/19/21 21/21/23
-f 21/21/23 22/22/24 20/20/22
-f 2/2/2 23/23/25 24/24/26
-f 24/24/26 3/3/3 2/2/2
-f 3/3/3 24/24/26 25/25/27
-f 25/25/27 5/5/5 3/3/3
-f 5/5/5 25/25/27 26/26/28
-f 26/26/28 7/7/7 5/5/5
-f 7/7/7 26/26/28 27/27/29
-f 27/27/29 9/9/9 7/7/7
-f 9/9/12 27/27/30 28/28/31
-f 28/28/31 11/11/13 9/9/12
-f 11/11/13 28/28/31 29/29/32
-f 29/29/32 13/13/15 11/11/13
-f 13/13/15 29/29/32 30/30/33
-f 30/30/33 15/15/17 13/13/15
-f 15/15/17 30/30/33 31/31/34
-f 31/31/34 17/17/19 15/15/17
-f 17/17/19 31/31/34 32/32/35
-f 32/32/35 19/19/21 17/17/19
-f 19/19/21 32/32/35 33/33/36
-f 33/33/36 21/21/23 19/19/21
-f 23/23/25 34/34/37 35/35/38
-f 35/35/38 24/24/26 23/23/25
-f 24/24/26 35/35/38 36/36/39
-f 36/36/39 25/25/27 24/24/26
-f 25/25/27 36/36/39 37/37/40
-f 37/37/40 26/26/28 25/25/27
-f 26/26/28 37/37/40 38/38/41
-f 38/38/41 27/27/29 26/26/28
-f 27/27/30 38/38/42 39/39/43
-f 39/39/43 28/28/31 27/27/30
-f 28/28/31 39/39/43 40/40/44
-f 40/40/44 29/29/32 28/28/31
-f 29/29/32 40/40/44 41/41/45
-f 41/41/45 30/30/33 29/29/32
-f 30/30/33 41/41/45 42/42/46
-f 42/42/46 31/31/34 30/30/33
-f 31/31/34 42/42/46 43/43/47
-f 43/43/47 32/32/35 31/31/34
-f 32/32/35 43/43/47 44/44/48
-f 44/44/48 33/33/36 32/32/35
-f 34/34/37 45/45/49 46/46/50
-f 46/46/50 35/35/38 34/34/37
-f 35/35/38 46/46/50 47/47/51
-f 47/47/51 36/36/39 35/35/38
-f 36/36/39 47/47/51 48/48/52
-f 48/48/52 37/37/40 36/36/39
-f 37/37/40 48/48/52 49/49/53
-f 49/49/53 38/38/41 37/37/40
-f 38/38/42 49/49/54 50/50/55
-f 50/50/55 39/39/43 38/38/42
-f 39/39/43 50/50/55 51/51/56
-f 51/51/56 40/40/44 39/39/43
-f 40/40/44 51/51/56 52/52/57
-f 52/52/57 41/41/45 40/40/44
-f 41/41/45 52/52/57 53/53/58
-f 53/53/58 42/42/46 41/41/45
-f 42/42/46 53/53/58 54/54/59
-f 54/54/59 43/43/47 42/42/46
-f 43/43/47 54/54/59 55/55/60
-f 55/55/60 44/44/48 43/43/47
-f 45/45/49 56/56/61 57/57/62
-f 57/57/62 46/46/50 45/45/49
-f 46/46/50 57/57/62 58/58/63
-f 58/58/63 47/47/51 46/46/50
-f 47/47/51 58/58/63 59/59/64
-f 59/59/64 48/48/52 47/47/51
-f 48/48/52 59/59/64 60/60/65
-f 60/60/65 49/49/53 48/48/52
-f 49/49/54 60/60/66 61/61/67
-f 61/61/67 50/50/55 49/49/54
-f 50/50/55 61/61/67 62/62/68
-f 62/62/68 51/51/56 50/50/55
-f 51/51/56 62/62/68 63/63/69
-f 63/63/69 52/52/57 51/51/56
-f 52/52/57 63/63/69 64/64/70
-f 64/64/70 53/53/58 52/52/57
-f 53/53/58 64/64/70 65/65/71
-f 65/65/71 54/54/59 53/53/58
-f 54/54/59 65/65/71 66/66/72
-f 66/66/72 55/55/60 54/54/59
-f 56/56/61 67/67/73 68/68/74
-f 68/68/74 57/57/62 56/56/61
-f 57/57/62 68/68/74 69/69/75
-f 69/69/75 58/58/63 57/57/62
-f 58/58/63 69/69/75 70/70/76
-f 70/70/76 59/59/64 58/58/63
-f 59/59/64 70/70/76 71/71/77
-f 71/71/77 60/60/65 59/59/64
-f 60/60/66 71/71/78 72/72/79
-f 72/72/79 61/61/67 60/60/66
-f 61/61/67 72/72/79 73/73/80
-f 73/73/80 62/62/68 61/61/67
-f 62/62/68 73/73/80 74/74/81
-f 74/74/81 63/63/69 62/62/68
-f 63/63/69 74/74/81 75/75/82
-f 75/75/82 64/64/70 63/63/69
-f 64/64/70 75/75/82 76/76/83
-f 76/76/83 65/65/71 64/64/70
-f 65/65/71 76/76/83 77/77/84
-f 77/77/84 66/66/72 65/65/71
-f 67/67/73 78/78/85 79/79/86
-f 79/79/86 68/68/74 67/67/73
-f 68/68/74 79/79/86 80/80/87
-f 80/80/87 69/69/75 68/68/74
-f 69/69/75 80/80/87 81/81/88
-f 81/81/88 70/70/76 69/69/75
-f 70/70/76 81/81/88 82/82/89
-f 82/82/89 71/71/77 70/70/76
-f 71/71/78 82/82/90 83/83/91
-f 83/83/91 72/72/79 71/71/78
-f 72/72/79 83/83/91 84/84/92
-f 84/84/92 73/73/80 72/72/79
-f 73/73/80 84/84/92 85/85/93
-f 85/85/93 74/74/81 73/73/80
-f 74/74/81 85/85/93 86/86/94
-f 86/86/94 75/75/82 74/74/81
-f 75/75/82 86/86/94 87/87/95
-f 87/87/95 76/76/83 75/75/82
-f 76/76/83 87/87/95 88/88/96
-f 88/88/96 77/77/84 76/76/83
-f 78/78/85 89/89/97 90/90/98
-f 90/90/98 79/79/86 78/78/85
-f 79/79/86 90/90/98 91/91/99
-f 91/91/99 80/80/87 79/79/86
-f 80/80/87 91/91/99 92/92/100
-f 92/92/100 81/81/88 80/80/87
-f 81/81/88 92/92/100 93/93/101
-f 93/93/101 82/82/89 81/81/88
-f 82/82/90 93/93/102 94/94/103
-f 94/94/103 83/83/91 82/82/90
-f 83/83/91 94/94/103 95/95/104
-f 95/95/104 84/84/92 83/83/91
-f 84/84/92 95/95/104 96/96/105
-f 96/96/105 85/85/93 84/84/92
-f 85/85/93 96/96/105 97/97/106
-f 97/97/106 86/86/94 85/85/93
-f 86/86/94 97/97/106 98/98/107
-f 98/98/107 87/87/95 86/86/94
-f 87/87/95 98/98/107 99/99/108
-f 99/99/108 88/88/96 87/87/95
-f 89/89/97 100/100/109 101/101/110
-f 101/101/110 90/90/98 89/89/97
-f 90/90/98 101/101/110 102/102/111
-f 102/102/111 91/91/99 90/90/98
-f 91/91/99 102/102/111 103/103/112
-f 103/103/112 92/92/100 91/91/99
-f 92/92/100 103/103/112 104/104/113
-f 104/104/113 93/93/101 92/92/100
-f 93/93/102 104/104/114 105/105/115
-f 105/105/115 94/94/103 93/93/102
-f 94/94/103 105/105/115 106/106/116
-f 106/106/116 95/95/104 94/94/103
-f 95/95/104 106/106/116 107/107/117
-f 107/107/117 96/96/105 95/95/104
-f 96/96/105 107/107/117 108/108/118
-f 108/108/118 97/97/106 96/96/105
-f 97/97/106 108/108/118 109/109/119
-f 109/109/119 98/98/107 97/97/106
-f 98/98/107 109/109/119 110/110/120
-f 110/110/120 99/99/108 98/98/107
-f 100/100/109 111/111/121 112/112/122
-f 112/112/122 101/101/110 100/100/109
-f 101/101/110 112/112/122 113/113/123
-f 113/113/123 102/102/111 101/101/110
-f 102/102/111 113/113/123 114/114/124
-f 114/114/124 103/103/112 102/102/111
-f 103/103/112 114/114/124 115/115/125
-f 115/115/125 104/104/113 103/103/112
-f 104/104/114 115/115/126 116/116/127
-f 116/116/127 105/105/115 104/104/114
-f 105/105/115 116/116/127 117/117/128
-f 117/117/128 106/106/116 105/105/115
-f 106/106/116 117/117/128 118/118/129
-f 118/118/129 107/107/117 106/106/116
-f 107/107/117 118/118/129 119/119/130
-f 119/119/130 108/108/118 107/107/117
-f 108/108/118 119/119/130 120/120/131
-f 120/120/131 109/109/119 108/108/118
-f 109/109/119 120/120/131 121/121/132
-f 121/121/132 110/110/120 109/109/119
-f 111/111/121 122/122/133 123/123/134
-f 123/123/134 112/112/122 111/111/121
-f 112/112/122 123/123/134 124/124/135
-f 124/124/135 113/113/123 112/112/122
-f 113/113/123 124/124/135 125/125/136
-f 125/125/136 114/114/124 113/113/123
-f 114/114/124 125/125/136 126/126/137
-f 126/126/137 115/115/125 114/114/124
-f 115/115/126 126/126/138 127/127/139
-f 127/127/139 116/116/127 115/115/126
-f 116/116/127 127/127/139 128/128/140
-f 128/128/140 117/117/128 116/116/127
-f 117/117/128 128/128/140 129/129/141
-f 129/129/141 118/118/129 117/117/128
-f 118/118/129 129/129/141 130/130/142
-f 130/130/142 119/119/130 118/118/129
-f 119/119/130 130/130/142 131/131/143
-f 131/131/143 120/120/131 119/119/130
-f 120/120/131 131/131/143 132/132/144
-f 132/132/144 121/121/132 120/120/131
-f 122/122/133 133/133/145 134/134/146
-f 134/134/146 123/123/134 122/122/133
-f 123/123/134 134/134/146 135/135/147
-f 135/135/147 124/124/135 123/123/134
-f 124/124/135 135/135/147 136/136/148
-f 136/136/148 125/125/136 124/124/135
-f 125/125/136 136/136/148 137/137/149
-f 137/137/149 126/126/137 125/125/136
-f 126/126/138 137/137/150 138/138/151
-f 138/138/151 127/127/139 126/126/138
-f 127/127/139 138/138/151 139/139/152
-f 139/139/152 128/128/140 127/127/139
-f 128/128/140 139/139/152 140/140/153
-f 140/140/153 129/129/141 128/128/140
-f 129/129/141 140/140/153 141/141/154
-f 141/141/154 130/130/142 129/129/141
-f 130/130/142 141/141/154 142/142/155
-f 142/142/155 131/131/143 130/130/142
-f 131/131/143 142/142/155 143/143/156
-f 143/143/156 132/132/144 131/131/143
-f 133/133/145 144/144/157 145/145/158
-f 145/145/158 134/134/146 133/133/145
-f 134/134/146 145/145/158 146/146/159
-f 146/146/159 135/135/147 134/134/146
-f 135/135/147 146/146/159 147/147/160
-f 147/147/160 136/136/148 135/135/147
-f 136/136/148 147/147/160 148/148/161
-f 148/148/161 137/137/149 136/136/148
-f 137/137/150 148/148/162 149/149/163
-f 149/149/163 138/138/151 137/137/150
-f 138/138/151 149/149/163 150/150/164
-f 150/150/164 139/139/152 138/138/151
-f 139/139/152 150/150/164 151/151/165
-f 151/151/165 140/140/153 139/139/152
-f 140/140/153 151/151/165 152/152/166
-f 152/152/166 141/141/154 140/140/153
-f 141/141/154 152/152/166 153/153/167
-f 153/153/167 142/142/155 141/141/154
-f 142/142/155 153/153/167 154/154/168
-f 154/154/168 143/143/156 142/142/155
-f 144/144/157 155/155/169 156/156/170
-f 156/156/170 145/145/158 144/144/157
-f 145/145/158 156/156/170 157/157/171
-f 157/157/171 146/146/159 145/145/158
-f 146/146/159 157/157/171 158/158/172
-f 158/158/172 147/147/160 146/146/159
-f 147/147/160 158/158/172 159/159/173
-f 159/159/173 148/148/161 147/147/160
-f 148/148/162 159/159/174 160/160/175
-f 160/160/175 149/149/163 148/148/162
-f 149/149/163 160/160/175 161/161/176
-f 161/161/176 150/150/164 149/149/163
-f 150/150/164 161/161/176 162/162/177
-f 162/162/177 151/151/165 150/150/164
-f 151/151/165 162/162/177 163/163/178
-f 163/163/178 152/152/166 151/151/165
-f 152/152/166 163/163/178 164/164/179
-f 164/164/179 153/153/167 152/152/166
-f 153/153/167 164/164/179 165/165/180
-f 165/165/180 154/154/168 153/153/167
-f 155/155/169 166/166/181 167/167/182
-f 167/167/182 156/156/170 155/155/169
-f 156/156/170 167/167/182 168/168/183
-f 168/168/183 157/157/171 156/156/170
-f 157/157/171 168/168/183 169/169/184
-f 169/169/184 158/158/172 157/157/171
-f 158/158/172 169/169/184 170/170/185
-f 170/170/185 159/159/173 158/158/172
-f 159/159/174 170/170/186 171/171/187
-f 171/171/187 160/160/175 159/159/174
-f 160/160/175 171/171/187 172/172/188
-f 172/172/188 161/161/176 160/160/175
-f 161/161/176 172/172/188 173/173/189
-f 173/173/189 162/162/177 161/161/176
-f 162/162/177 173/173/189 174/174/190
-f 174/174/190 163/163/178 162/162/177
-f 163/163/178 174/174/190 175/175/191
-f 175/175/191 164/164/179 163/163/178
-f 164/164/179 175/175/191 176/176/192
-f 176/176/192 165/165/180 164/164/179
-f 166/166/181 177/177/193 178/178/194
-f 178/178/194 167/167/182 166/166/181
-f 167/167/182 178/178/194 179/179/195
-f 179/179/195 168/168/183 167/167/182
-f 168/168/183 179/179/195 180/180/196
-f 180/180/196 169/169/184 168/168/183
-f 169/169/184 180/180/196 181/181/197
-f 181/181/197 170/170/185 169/169/184
-f 170/170/186 181/181/198 182/182/199
-f 182/182/199 171/171/187 170/170/186
-f 171/171/187 182/182/199 183/183/200
-f 183/183/200 172/172/188 171/171/187
-f 172/172/188 183/183/200 184/184/201
-f 184/184/201 173/173/189 172/172/188
-f 173/173/189 184/184/201 185/185/202
-f 185/185/202 174/174/190 173/173/189
-f 174/174/190 185/185/202 186/186/203
-f 186/186/203 175/175/191 174/174/190
-f 175/175/191 186/186/203 187/187/204
-f 187/187/204 176/176/192 175/175/191
-f 177/177/193 188/188/205 189/189/206
-f 189/189/206 178/178/194 177/177/193
-f 178/178/194 189/189/206 190/190/207
-f 190/190/207 179/179/195 178/178/194
-f 179/179/195 190/190/207 191/191/208
-f 191/191/208 180/180/196 179/179/195
-f 180/180/196 191/191/208 192/192/209
-f 192/192/209 181/181/197 180/180/196
-f 181/181/198 192/192/210 193/193/211
-f 193/193/211 182/182/199 181/181/198
-f 182/182/199 193/193/211 194/194/212
-f 194/194/212 183/183/200 182/182/199
-f 183/183/200 194/194/212 195/195/213
-f 195/195/213 184/184/201 183/183/200
-f 184/184/201 195/195/213 196/196/214
-f 196/196/214 185/185/202 184/184/201
-f 185/185/202 196/196/214 197/197/215
-f 197/197/215 186/186/203 185/185/202
-f 186/186/203 197/197/215 198/198/216
-f 198/198/216 187/187/204 186/186/203
-f 188/188/205 199/199/217 200/200/218
-f 200/200/218 189/189/206 188/188/205
-f 189/189/206 200/200/218 201/201/219
-f 201/201/219 190/190/207 189/189/206
-f 190/190/207 201/201/219 202/202/220
-f 202/202/220 191/191/208 190/190/207
-f 191/191/208 202/202/220 203/203/221
-f 203/203/221 192/192/209 191/191/208
-f 192/192/210 203/203/222 204/204/223
-f 204/204/223 193/193/211 192/192/210
-f 193/193/211 204/204/223 205/205/224
-f 205/205/224 194/194/212 193/193/211
-f 194/194/212 205/205/224 206/206/225
-f 206/206/225 195/195/213 194/194/212
-f 195/195/213 206/206/225 207/207/226
-f 207/207/226 196/196/214 195/195/213
-f 196/196/214 207/207/226 208/208/227
-f 208/208/227 197/197/215 196/196/214
-f 197/197/215 208/208/227 209/209/228
-f 209/209/228 198/198/216 197/197/215
-f 199/199/217 210/210/229 211/211/230
-f 211/211/230 200/200/218 199/199/217
-f 200/200/218 211/211/230 212/212/231
-f 212/212/231 201/201/219 200/200/218
-f 201/201/219 212/212/231 213/213/232
-f 213/213/232 202/202/220 201/201/219
-f 202/202/220 213/213/232 214/214/233
-f 214/214/233 203/203/221 202/202/220
-f 203/203/222 214/214/234 215/215/235
-f 215/215/235 204/204/223 203/203/222
-f 204/204/223 215/215/235 216/216/236
-f 216/216/236 205/205/224 204/204/223
-f 205/205/224 216/216/236 217/217/237
-f 217/217/237 206/206/225 205/205/224
-f 206/206/225 217/217/237 218/218/238
-f 218/218/238 207/207/226 206/206/225
-f 207/207/226 218/218/238 219/219/239
-f 219/219/239 208/208/227 207/207/226
-f 208/208/227 219/219/239 220/220/240
-f 220/220/240 209/209/228 208/208/227
-f 210/210/229 221/221/241 222/222/242
-f 222/222/242 211/211/230 210/210/229
-f 211/211/230 222/222/242 223/223/243
-f 223/223/243 212/212/231 211/211/230
-f 212/212/231 223/223/243 224/224/244
-f 224/224/244 213/213/232 212/212/231
-f 213/213/232 224/224/244 225/225/245
-f 225/225/245 214/214/233 213/213/232
-f 214/214/234 225/225/246 226/226/247
-f 226/226/247 215/215/235 214/214/234
-f 215/215/235 226/226/247 227/227/248
-f 227/227/248 216/216/236 215/215/235
-f 216/216/236 227/227/248 228/228/249
-f 228/228/249 217/217/237 216/216/236
-f 217/217/237 228/228/249 229/229/250
-f 229/229/250 218/218/238 217/217/237
-f 218/218/238 229/229/250 230/230/251
-f 230/230/251 219/219/239 218/218/238
-f 219/219/239 230/230/251 231/231/252
-f 231/231/252 220/220/240 219/219/239
-f 221/221/241 232/232/253 233/233/254
-f 233/233/254 222/222/242 221/221/241
-f 222/222/242 233/233/254 234/234/255
-f 234/234/255 223/223/243 222/222/242
-f 223/223/243 234/234/255 235/235/256
-f 235/235/256 224/224/244 223/223/243
-f 224/224/244 235/235/256 236/236/257
-f 236/236/257 225/225/245 224/224/244
-f 225/225/246 236/236/258 237/237/259
-f 237/237/259 226/226/247 225/225/246
-f 226/226/247 237/237/259 238/238/260
-f 238/238/260 227/227/248 226/226/247
-f 227/227/248 238/238/260 239/239/261
-f 239/239/261 228/228/249 227/227/248
-f 228/228/249 239/239/261 240/240/262
-f 240/240/262 229/229/250 228/228/249
-f 229/229/250 240/240/262 241/241/263
-f 241/241/263 230/230/251 229/229/250
-f 230/230/251 241/241/263 242/242/264
-f 242/242/264 231/231/252 230/230/251
-f 232/232/253 243/243/265 244/244/266
-f 244/244/266 233/233/254 232/232/253
-f 233/233/254 244/244/266 245/245/267
-f 245/245/267 234/234/255 233/233/254
-f 234/234/255 245/245/267 246/246/268
-f 246/246/268 235/235/256 234/234/255
-f 235/235/256 246/246/268 247/247/269
-f 247/247/269 236/236/257 235/235/256
-f 236/236/258 247/247/270 248/248/271
-f 248/248/271 237/237/259 236/236/258
-f 237/237/259 248/248/271 249/249/272
-f 249/249/272 238/238/260 237/237/259
-f 238/238/260 249/249/272 250/250/273
-f 250/250/273 239/239/261 238/238/260
-f 239/239/261 250/250/273 251/251/274
-f 251/251/274 240/240/262 239/239/261
-f 240/240/262 251/251/274 252/252/275
-f 252/252/275 241/241/263 240/240/262
-f 241/241/263 252/252/275 253/253/276
-f 253/253/276 242/242/264 241/241/263
-f 243/243/265 254/254/277 255/255/278
-f 255/255/278 244/244/266 243/243/265
-f 244/244/266 255/255/278 256/256/279
-f 256/256/279 245/245/267 244/244/266
-f 245/245/267 256/256/279 257/257/280
-f 257/257/280 246/246/268 245/245/267
-f 246/246/268 257/257/280 258/258/281
-f 258/258/281 247/247/269 246/246/268
-f 247/247/270 258/258/282 259/259/283
-f 259/259/283 248/248/271 247/247/270
-f 248/248/271 259/259/283 260/260/284
-f 260/260/284 249/249/272 248/248/271
-f 249/249/272 260/260/284 261/261/285
-f 261/261/285 250/250/273 249/249/272
-f 250/250/273 261/261/285 262/262/286
-f 262/262/286 251/251/274 250/250/273
-f 251/251/274 262/262/286 263/263/287
-f 263/263/287 252/252/275 251/251/274
-f 252/252/275 263/263/287 264/264/288
-f 264/264/288 253/253/276 252/252/275
-f 254/254/277 265/265/289 266/266/290
-f 266/266/290 255/255/278 254/254/277
-f 255/255/278 266/266/290 267/267/291
-f 267/267/291 256/256/279 255/255/278
-f 256/256/279 267/267/291 268/268/292
-f 268/268/292 257/257/280 256/256/279
-f 257/257/280 268/268/292 269/269/293
-f 269/269/293 258/258/281 257/257/280
-f 258/258/282 269/269/294 270/270/295
-f 270/270/295 259/259/283 258/258/282
-f 259/259/283 270/270/295 271/271/296
-f 271/271/296 260/260/284 259/259/283
-f 260/260/284 271/271/296 272/272/297
-f 272/272/297 261/261/285 260/260/284
-f 261/261/285 272/272/297 273/273/298
-f 273/273/298 262/262/286 261/261/285
-f 262/262/286 273/273/298 274/274/299
-f 274/274/299 263/263/287 262/262/286
-f 263/263/287 274/274/299 275/275/300
-f 275/275/300 264/264/288 263/263/287
-f 265/265/289 276/276/301 277/277/302
-f 277/277/302 266/266/290 265/265/289
-f 266/266/290 277/277/302 278/278/303
-f 278/278/303 267/267/291 266/266/290
-f 267/267/291 278/278/303 279/279/304
-f 279/279/304 268/268/292 267/267/291
-f 268/268/292 279/279/304 280/280/305
-f 280/280/305 269/269/293 268/268/292
-f 269/269/294 280/280/306 281/281/307
-f 281/281/307 270/270/295 269/269/294
-f 270/270/295 281/281/307 282/282/308
-f 282/282/308 271/271/296 270/270/295
-f 271/271/296 282/282/308 283/283/309
-f 283/283/309 272/272/297 271/271/296
-f 272/272/297 283/283/309 284/284/310
-f 284/284/310 273/273/298 272/272/297
-f 273/273/298 284/284/310 285/285/311
-f 285/285/311 274/274/299 273/273/298
-f 274/274/299 285/285/311 286/286/312
-f 286/286/312 275/275/300 274/274/299
-f 276/276/301 287/287/313 288/288/314
-f 288/288/314 277/277/302 276/276/301
-f 277/277/302 288/288/314 289/289/315
-f 289/289/315 278/278/303 277/277/302
-f 278/278/303 289/289/315 290/290/316
-f 290/290/316 279/279/304 278/278/303
-f 279/279/304 290/290/316 291/291/317
-f 291/291/317 280/280/305 279/279/304
-f 280/280/306 291/291/318 292/292/319
-f 292/292/319 281/281/307 280/280/306
-f 281/281/307 292/292/319 293/293/320
-f 293/293/320 282/282/308 281/281/307
-f 282/282/308 293/293/320 294/294/321
-f 294/294/321 283/283/309 282/282/308
-f 283/283/309 294/294/321 295/295/322
-f 295/295/322 284/284/310 283/283/309
-f 284/284/310 295/295/322 296/296/323
-f 296/296/323 285/285/311 284/284/310
-f 285/285/311 296/296/323 297/297/324
-f 297/297/324 286/286/312 285/285/311
-f 287/287/313 298/298/325 299/299/326
-f 299/299/326 288/288/314 287/287/313
-f 288/288/314 299/299/326 300/300/327
-f 300/300/327 289/289/315 288/288/314
-f 289/289/315 300/300/327 301/301/328
-f 301/301/328 290/290/316 289/289/315
-f 290/290/316 301/301/328 302/302/329
-f 302/302/329 291/291/317 290/290/316
-f 291/291/318 302/302/330 303/303/331
-f 303/303/331 292/292/319 291/291/318
-f 292/292/319 303/303/331 304/304/332
-f 304/304/332 293/293/320 292/292/319
-f 293/293/320 304/304/332 305/305/333
-f 305/305/333 294/294/321 293/293/320
-f 294/294/321 305/305/333 306/306/334
-f 306/306/334 295/295/322 294/294/321
-f 295/295/322 306/306/334 307/307/335
-f 307/307/335 296/296/323 295/295/322
-f 296/296/323 307/307/335 308/308/336
-f 308/308/336 297/297/324 296/296/323
-f 298/298/325 309/309/337 310/310/338
-f 310/310/338 299/299/326 298/298/325
-f 299/299/326 310/310/338 311/311/339
-f 311/311/339 300/300/327 299/299/326
-f 300/300/327 311/311/339 312/312/340
-f 312/312/340 301/301/328 300/300/327
-f 301/301/328 312/312/340 313/313/341
-f 313/313/341 302/302/329 301/301/328
-f 302/302/330 313/313/342 314/314/343
-f 314/314/343 303/303/331 302/302/330
-f 303/303/331 314/314/343 315/315/344
-f 315/315/344 304/304/332 303/303/331
-f 304/304/332 315/315/344 316/316/345
-f 316/316/345 305/305/333 304/304/332
-f 305/305/333 316/316/345 317/317/346
-f 317/317/346 306/306/334 305/305/333
-f 306/306/334 317/317/346 318/318/347
-f 318/318/347 307/307/335 306/306/334
-f 307/307/335 318/318/347 319/319/348
-f 319/319/348 308/308/336 307/307/335
-f 309/309/337 320/320/349 321/321/350
-f 321/321/350 310/310/338 309/309/337
-f 310/310/338 321/321/350 322/322/351
-f 322/322/351 311/311/339 310/310/338
-f 311/311/339 322/322/351 323/323/352
-f 323/323/352 312/312/340 311/311/339
-f 312/312/340 323/323/352 324/324/353
-f 324/324/353 313/313/341 312/312/340
-f 313/313/342 324/324/354 325/325/355
-f 325/325/355 314/314/343 313/313/342
-f 314/314/343 325/325/355 326/326/356
-f 326/326/356 315/315/344 314/314/343
-f 315/315/344 326/326/356 327/327/357
-f 327/327/357 316/316/345 315/315/344
-f 316/316/345 327/327/357 328/328/358
-f 328/328/358 317/317/346 316/316/345
-f 317/317/346 328/328/358 329/329/359
-f 329/329/359 318/318/347 317/317/346
-f 318/318/347 329/329/359 330/330/360
-f 330/330/360 319/319/348 318/318/347
-f 320/320/349 331/331/361 332/332/362
-f 332/332/362 321/321/350 320/320/349
-f 321/321/350 332/332/362 333/333/363
-f 333/333/363 322/322/351 321/321/350
-f 322/322/351 333/333/363 334/334/364
-f 334/334/364 323/323/352 322/322/351
-f 323/323/352 334/334/364 335/335/365
-f 335/335/365 324/324/353 323/323/352
-f 324/324/354 335/335/366 336/336/367
-f 336/336/367 325/325/355 324/324/354
-f 325/325/355 336/336/367 337/337/368
-f 337/337/368 326/326/356 325/325/355
-f 326/326/356 337/337/368 338/338/369
-f 338/338/369 327/327/357 326/326/356
-f 327/327/357 338/338/369 339/339/370
-f 339/339/370 328/328/358 327/327/357
-f 328/328/358 339/339/370 340/340/371
-f 340/340/371 329/329/359 328/328/358
-f 329/329/359 340/340/371 341/341/372
-f 341/341/372 330/330/360 329/329/359
-f 331/331/361 342/342/373 343/343/374
-f 343/343/374 332/332/362 331/331/361
-f 332/332/362 343/343/374 344/344/375
-f 344/344/375 333/333/363 332/332/362
-f 333/333/363 344/344/375 345/345/376
-f 345/345/376 334/334/364 333/333/363
-f 334/334/364 345/345/376 346/346/377
-f 346/346/377 335/335/365 334/334/364
-f 335/335/366 346/346/378 347/347/379
-f 347/347/379 336/336/367 335/335/366
-f 336/336/367 347/347/379 348/348/380
-f 348/348/380 337/337/368 336/336/367
-f 337/337/368 348/348/380 349/349/381
-f 349/349/381 338/338/369 337/337/368
-f 338/338/369 349/349/381 350/350/382
-f 350/350/382 339/339/370 338/338/369
-f 339/339/370 350/350/382 351/351/383
-f 351/351/383 340/340/371 339/339/370
-f 340/340/371 351/351/383 352/352/384
-f 352/352/384 341/341/372 340/340/371
-f 342/342/373 353/353/385 354/354/386
-f 354/354/386 343/343/374 342/342/373
-f 343/343/374 354/354/386 355/355/387
-f 355/355/387 344/344/375 343/343/374
-f 344/344/375 355/355/387 356/356/388
-f 356/356/388 345/345/376 344/344/375
-f 345/345/376 356/356/388 357/357/389
-f 357/357/389 346/346/377 345/345/376
-f 346/346/378 357/357/390 358/358/391
-f 358/358/391 347/347/379 346/346/378
-f 347/347/379 358/358/391 359/359/392
-f 359/359/392 348/348/380 347/347/379
-f 348/348/380 359/359/392 360/360/393
-f 360/360/393 349/349/381 348/348/380
-f 349/349/381 360/360/393 361/361/394
-f 361/361/394 350/350/382 349/349/381
-f 350/350/382 361/361/394 362/362/395
-f 362/362/395 351/351/383 350/350/382
-f 351/351/383 362/362/395 363/363/396
-f 363/363/396 352/352/384 351/351/383
-f 353/353/385 364/364/397 365/365/398
-f 365/365/398 354/354/386 353/353/385
-f 354/354/386 365/365/398 366/366/399
-f 366/366/399 355/355/387 354/354/386
-f 355/355/387 366/366/399 367/367/400
-f 367/367/400 356/356/388 355/355/387
-f 356/356/388 367/367/400 368/368/401
-f 368/368/401 357/357/389 356/356/388
-f 357/357/390 368/368/402 369/369/403
-f 369/369/403 358/358/391 357/357/390
-f 358/358/391 369/369/403 370/370/404
-f 370/370/404 359/359/392 358/358/391
-f 359/359/392 370/370/404 371/371/405
-f 371/371/405 360/360/393 359/359/392
-f 360/360/393 371/371/405 372/372/406
-f 372/372/406 361/361/394 360/360/393
-f 361/361/394 372/372/406 373/373/407
-f 373/373/407 362/362/395 361/361/394
-f 362/362/395 373/373/407 374/374/408
-f 374/374/408 363/363/396 362/362/395
-f 364/364/397 375/375/409 376/376/410
-f 376/376/410 365/365/398 364/364/397
-f 365/365/398 376/376/410 377/377/411
-f 377/377/411 366/366/399 365/365/398
-f 366/366/399 377/377/411 378/378/412
-f 378/378/412 367/367/400 366/366/399
-f 367/367/400 378/378/412 379/379/413
-f 379/379/413 368/368/401 367/367/400
-f 368/368/402 379/379/414 380/380/415
-f 380/380/415 369/369/403 368/368/402
-f 369/369/403 380/380/415 381/381/416
-f 381/381/416 370/370/404 369/369/403
-f 370/370/404 381/381/416 382/382/417
-f 382/382/417 371/371/405 370/370/404
-f 371/371/405 382/382/417 383/383/418
-f 383/383/418 372/372/406 371/371/405
-f 372/372/406 383/383/418 384/384/419
-f 384/384/419 373/373/407 372/372/406
-f 373/373/407 384/384/419 385/385/420
-f 385/385/420 374/374/408 373/373/407
-f 375/375/409 386/386/421 387/387/422
-f 387/387/422 376/376/410 375/375/409
-f 376/376/410 387/387/422 388/388/423
-f 388/388/423 377/377/411 376/376/410
-f 377/377/411 388/388/423 389/389/424
-f 389/389/424 378/378/412 377/377/411
-f 378/378/412 389/389/424 390/390/425
-f 390/390/425 379/379/413 378/378/412
-f 379/379/414 390/390/426 391/391/427
-f 391/391/427 380/380/415 379/379/414
-f 380/380/415 391/391/427 392/392/428
-f 392/392/428 381/381/416 380/380/415
-f 381/381/416 392/392/428 393/393/429
-f 393/393/429 382/382/417 381/381/416
-f 382/382/417 393/393/429 394/394/430
-f 394/394/430 383/383/418 382/382/417
-f 383/383/418 394/394/430 395/395/431
-f 395/395/431 384/384/419 383/383/418
-f 384/384/419 395/395/431 396/396/432
-f 396/396/432 385/385/420 384/384/419
-f 386/386/421 397/397/433 398/398/434
-f 398/398/434 387/387/422 386/386/421
-f 387/387/422 398/398/434 399/399/435
-f 399/399/435 388/388/423 387/387/422
-f 388/388/423 399/399/435 400/400/436
-f 400/400/436 389/389/424 388/388/423
-f 389/389/424 400/400/436 401/401/437
-f 401/401/437 390/390/425 389/389/424
-f 390/390/426 401/401/438 402/402/439
-f 402/402/439 391/391/427 390/390/426
-f 391/391/427 402/402/439 403/403/440
-f 403/403/440 392/392/428 391/391/427
-f 392/392/428 403/403/440 404/404/441
-f 404/404/441 393/393/429 392/392/428
-f 393/393/429 404/404/441 405/405/442
-f 405/405/442 394/394/430 393/393/429
-f 394/394/430 405/405/442 406/406/443
-f 406/406/443 395/395/431 394/394/430
-f 395/395/431 406/406/443 407/407/444
-f 407/407/444 396/396/432 395/395/431
-f 397/397/433 408/408/445 409/409/446
-f 409/409/446 398/398/434 397/397/433
-f 398/398/434 409/409/446 410/410/447
-f 410/410/447 399/399/435 398/398/434
-f 399/399/435 410/410/447 411/411/448
-f 411/411/448 400/400/436 399/399/435
-f 400/400/436 411/411/448 412/412/449
-f 412/412/449 401/401/437 400/400/436
-f 401/401/438 412/412/450 413/413/451
-f 413/413/451 402/402/439 401/401/438
-f 402/402/439 413/413/451 414/414/452
-f 414/414/452 403/403/440 402/402/439
-f 403/403/440 414/414/452 415/415/453
-f 415/415/453 404/404/441 403/403/440
-f 404/404/441 415/415/453 416/416/454
-f 416/416/454 405/405/442 404/404/441
-f 405/405/442 416/416/454 417/417/455
-f 417/417/455 406/406/443 405/405/442
-f 406/406/443 417/417/455 418/418/456
-f 418/418/456 407/407/444 406/406/443
-f 408/408/445 419/419/457 420/420/458
-f 420/420/458 409/409/446 408/408/445
-f 409/409/446 420/420/458 421/421/459
-f 421/421/459 410/410/447 409/409/446
-f 410/410/447 421/421/459 422/422/460
-f 422/422/460 411/411/448 410/410/447
-f 411/411/448 422/422/460 423/423/461
-f 423/423/461 412/412/449 411/411/448
-f 412/412/450 423/423/462 424/424/463
-f 424/424/463 413/413/451 412/412/450
-f 413/413/451 424/424/463 425/425/464
-f 425/425/464 414/414/452 413/413/451
-f 414/414/452 425/425/464 426/426/465
-f 426/426/465 415/415/453 414/414/452
-f 415/415/453 426/426/465 427/427/466
-f 427/427/466 416/416/454 415/415/453
-f 416/416/454 427/427/466 428/428/467
-f 428/428/467 417/417/455 416/416/454
-f 417/417/455 428/428/467 429/429/468
-f 429/429/468 418/418/456 417/417/455
-f 419/419/457 430/430/469 431/431/470
-f 431/431/470 420/420/458 419/419/457
-f 420/420/458 431/431/470 432/432/471
-f 432/432/471 421/421/459 420/420/458
-f 421/421/459 432/432/471 433/433/472
-f 433/433/472 422/422/460 421/421/459
-f 422/422/460 433/433/472 434/434/473
-f 434/434/473 423/423/461 422/422/460
-f 423/423/462 434/434/474 435/435/475
-f 435/435/475 424/424/463 423/423/462
-f 424/424/463 435/435/475 436/436/476
-f 436/436/476 425/425/464 424/424/463
-f 425/425/464 436/436/476 437/437/477
-f 437/437/477 426/426/465 425/425/464
-f 426/426/465 437/437/477 438/438/478
-f 438/438/478 427/427/466 426/426/465
-f 427/427/466 438/438/478 439/439/479
-f 439/439/479 428/428/467 427/427/466
-f 428/428/467 439/439/479 440/440/480
-f 440/440/480 429/429/468 428/428/467
-f 430/430/469 1/1/1 4/4/4
-f 4/4/4 431/431/470 430/430/469
-f 431/431/470 4/4/4 6/6/6
-f 6/6/6 432/432/471 431/431/470
-f 432/432/471 6/6/6 8/8/8
-f 8/8/8 433/433/472 432/432/471
-f 433/433/472 8/8/8 10/10/10
-f 10/10/10 434/434/473 433/433/472
-f 434/434/474 10/10/11 12/12/14
-f 12/12/14 435/435/475 434/434/474
-f 435/435/475 12/12/14 14/14/16
-f 14/14/16 436/436/476 435/435/475
-f 436/436/476 14/14/16 16/16/18
-f 16/16/18 437/437/477 436/436/476
-f 437/437/477 16/16/18 18/18/20
-f 18/18/20 438/438/478 437/437/477
-f 438/438/478 18/18/20 20/20/22
-f 20/20/22 439/439/479 438/438/478
-f 439/439/479 20/20/22 22/22/24
-f 22/22/24 440/440/480 439/439/479
-f 22/22/24 21/21/23 441/441/481
-f 441/441/481 442/442/482 22/22/24
-f 442/442/482 441/441/481 443/443/483
-f 443/443/483 444/444/484 442/442/482
-f 444/444/484 443/443/483 445/445/485
-f 445/445/485 446/446/486 444/444/484
-f 446/446/486 445/445/485 447/447/487
-f 447/447/487 448/448/488 446/446/486
-f 448/448/488 447/447/487 449/449/489
-f 449/449/489 450/450/490 448/448/488
-f 450/450/490 449/449/489 451/451/491
-f 451/451/491 452/452/492 450/450/490
-f 452/452/492 451/451/491 453/453/493
-f 453/453/493 454/454/494 452/452/492
-f 454/454/494 453/453/493 455/455/495
-f 455/455/495 456/456/496 454/454/494
-f 456/456/496 455/455/495 457/457/497
-f 457/457/497 458/458/498 456/456/496
-f 458/458/498 457/457/497 459/459/499
-f 459/459/499 460/460/500 458/458/498
-f 21/21/23 33/33/36 461/461/501
-f 461/461/501 441/441/481 21/21/23
-f 441/441/481 461/461/501 462/462/502
-f 462/462/502 443/443/483 441/441/481
-f 443/443/483 462/462/502 463/463/503
-f 463/463/503 445/445/485 443/443/483
-f 445/445/485 463/463/503 464/464/504
-f 464/464/504 447/447/487 445/445/485
-f 447/447/487 464/464/504 465/465/505
-f 465/465/505 449/449/489 447/447/487
-f 449/449/489 465/465/505 466/466/506
-f 466/466/506 451/451/491 449/449/489
-f 451/451/491 466/466/506 467/467/507
-f 467/467/507 453/453/493 451/451/491
-f 453/453/493 467/467/507 468/468/508
-f 468/468/508 455/455/495 453/453/493
-f 455/455/495 468/468/508 469/469/509
-f 469/469/509 457/457/497 455/455/495
-f 457/457/497 469/469/509 470/470/510
-f 470/470/510 459/459/499 457/457/497
-f 33/33/36 44/44/48 471/471/511
-f 471/471/511 461/461/501 33/33/36
-f 461/461/501 471/471/511 472/472/512
-f 472/472/512 462/462/502 461/461/501
-f 462/462/502 472/472/512 473/473/513
-f 473/473/513 463/463/503 462/462/502
-f 463/463/503 473/473/513 474/474/514
-f 474/474/514 464/464/504 463/463/503
-f 464/464/504 474/474/514 475/475/515
-f 475/475/515 465/465/505 464/464/504
-f 465/465/505 475/475/515 476/476/516
-f 476/476/516 466/466/506 465/465/505
-f 466/466/506 476/476/516 477/477/517
-f 477/477/517 467/467/507 466/466/506
-f 467/467/507 477/477/517 478/478/518
-f 478/478/518 468/468/508 467/467/507
-f 468/468/508 478/478/518 479/479/519
-f 479/479/519 469/469/509 468/468/508
-f 469/469/509 479/479/519 480/480/520
-f 480/480/520 470/470/510 469/469/509
-f 44/44/48 55/55/60 481/481/521
-f 481/481/521 471/471/511 44/44/48
-f 471/471/511 481/481/521 482/482/522
-f 482/482/522 472/472/512 471/471/511
-f 472/472/512 482/482/522 483/483/523
-f 483/483/523 473/473/513 472/472/512
-f 473/473/513 483/483/523 484/484/524
-f 484/484/524 474/474/514 473/473/513
-f 474/474/514 484/484/524 485/485/525
-f 485/485/525 475/475/515 474/474/514
-f 475/475/515 485/485/525 486/486/526
-f 486/486/526 476/476/516 475/475/515
-f 476/476/516 486/486/526 487/487/527
-f 487/487/527 477/477/517 476/476/516
-f 477/477/517 487/487/527 488/488/528
-f 488/488/528 478/478/518 477/477/517
-f 478/478/518 488/488/528 489/489/529
-f 489/489/529 479/479/519 478/478/518
-f 479/479/519 489/489/529 490/490/530
-f 490/490/530 480/480/520 479/479/519
-f 55/55/60 66/66/72 491/491/531
-f 491/491/531 481/481/521 55/55/60
-f 481/481/521 491/491/531 492/492/532
-f 492/492/532 482/482/522 481/481/521
-f 482/482/522 492/492/532 493/493/533
-f 493/493/533 483/483/523 482/482/522
-f 483/483/523 493/493/533 494/494/534
-f 494/494/534 484/484/524 483/483/523
-f 484/484/524 494/494/534 495/495/535
-f 495/495/535 485/485/525 484/484/524
-f 485/485/525 495/495/535 496/496/536
-f 496/496/536 486/486/526 485/485/525
-f 486/486/526 496/496/536 497/497/537
-f 497/497/537 487/487/527 486/486/526
-f 487/487/527 497/497/537 498/498/538
-f 498/498/538 488/488/528 487/487/527
-f 488/488/528 498/498/538 499/499/539
-f 499/499/539 489/489/529 488/488/528
-f 489/489/529 499/499/539 500/500/540
-f 500/500/540 490/490/530 489/489/529
-f 66/66/72 77/77/84 501/501/541
-f 501/501/541 491/491/531 66/66/72
-f 491/491/531 501/501/541 502/502/542
-f 502/502/542 492/492/532 491/491/531
-f 492/492/532 502/502/542 503/503/543
-f 503/503/543 493/493/533 492/492/532
-f 493/493/533 503/503/543 504/504/544
-f 504/504/544 494/494/534 493/493/533
-f 494/494/534 504/504/544 505/505/545
-f 505/505/545 495/495/535 494/494/534
-f 495/495/535 505/505/545 506/506/546
-f 506/506/546 496/496/536 495/495/535
-f 496/496/536 506/506/546 507/507/547
-f 507/507/547 497/497/537 496/496/536
-f 497/497/537 507/507/547 508/508/548
-f 508/508/548 498/498/538 497/497/537
-f 498/498/538 508/508/548 509/509/549
-f 509/509/549 499/499/539 498/498/538
-f 499/499/539 509/509/549 510/510/550
-f 510/510/550 500/500/540 499/499/539
-f 77/77/84 88/88/96 511/511/551
-f 511/511/551 501/501/541 77/77/84
-f 501/501/541 511/511/551 512/512/552
-f 512/512/552 502/502/542 501/501/541
-f 502/502/542 512/512/552 513/513/553
-f 513/513/553 503/503/543 502/502/542
-f 503/503/543 513/513/553 514/514/554
-f 514/514/554 504/504/544 503/503/543
-f 504/504/544 514/514/554 515/515/555
-f 515/515/555 505/505/545 504/504/544
-f 505/505/545 515/515/555 516/516/556
-f 516/516/556 506/506/546 505/505/545
-f 506/506/546 516/516/556 517/517/557
-f 517/517/557 507/507/547 506/506/546
-f 507/507/547 517/517/557 518/518/558
-f 518/518/558 508/508/548 507/507/547
-f 508/508/548 518/518/558 519/519/559
-f 519/519/559 509/509/549 508/508/548
-f 509/509/549 519/519/559 520/520/560
-f 520/520/560 510/510/550 509/509/549
-f 88/88/96 99/99/108 521/521/561
-f 521/521/561 511/511/551 88/88/96
-f 511/511/551 521/521/561 522/522/562
-f 522/522/562 512/512/552 511/511/551
-f 512/512/552 522/522/562 523/523/563
-f 523/523/563 513/513/553 512/512/552
-f 513/513/553 523/523/563 524/524/564
-f 524/524/564 514/514/554 513/513/553
-f 514/514/554 524/524/564 525/525/565
-f 525/525/565 515/515/555 514/514/554
-f 515/515/555 525/525/565 526/526/566
-f 526/526/566 516/516/556 515/515/555
-f 516/516/556 526/526/566 527/527/567
-f 527/527/567 517/517/557 516/516/556
-f 517/517/557 527/527/567 528/528/568
-f 528/528/568 518/518/558 517/517/557
-f 518/518/558 528/528/568 529/529/569
-f 529/529/569 519/519/559 518/518/558
-f 519/519/559 529/529/569 530/530/570
-f 530/530/570 520/520/560 519/519/559
-f 99/99/108 110/110/120 531/531/571
-f 531/531/571 521/521/561 99/99/108
-f 521/521/561 531/531/571 532/532/572
-f 532/532/572 522/522/562 521/521/561
-f 522/522/562 532/532/572 533/533/573
-f 533/533/573 523/523/563 522/522/562
-f 523/523/563 533/533/573 534/534/574
-f 534/534/574 524/524/564 523/523/563
-f 524/524/564 534/534/574 535/535/575
-f 535/535/575 525/525/565 524/524/564
-f 525/525/565 535/535/575 536/536/576
-f 536/536/576 526/526/566 525/525/565
-f 526/526/566 536/536/576 537/537/577
-f 537/537/577 527/527/567 526/526/566
-f 527/527/567 537/537/577 538/538/578
-f 538/538/578 528/528/568 527/527/567
-f 528/528/568 538/538/578 539/539/579
-f 539/539/579 529/529/569 528/528/568
-f 529/529/569 539/539/579 540/540/580
-f 540/540/580 530/530/570 529/529/569
-f 110/110/120 121/121/132 541/541/581
-f 541/541/581 531/531/571 110/110/120
-f 531/531/571 541/541/581 542/542/582
-f 542/542/582 532/532/572 531/531/571
-f 532/532/572 542/542/582 543/543/583
-f 543/543/583 533/533/573 532/532/572
-f 533/533/573 543/543/583 544/544/584
-f 544/544/584 534/534/574 533/533/573
-f 534/534/574 544/544/584 545/545/585
-f 545/545/585 535/535/575 534/534/574
-f 535/535/575 545/545/585 546/546/586
-f 546/546/586 536/536/576 535/535/575
-f 536/536/576 546/546/586 547/547/587
-f 547/547/587 537/537/577 536/536/576
-f 537/537/577 547/547/587 548/548/588
-f 548/548/588 538/538/578 537/537/577
-f 538/538/578 548/548/588 549/549/589
-f 549/549/589 539/539/579 538/538/578
-f 539/539/579 549/549/589 550/550/590
-f 550/550/590 540/540/580 539/539/579
-f 121/121/132 132/132/144 551/551/591
-f 551/551/591 541/541/581 121/121/132
-f 541/541/581 551/551/591 552/552/592
-f 552/552/592 542/542/582 541/541/581
-f 542/542/582 552/552/592 553/553/593
-f 553/553/593 543/543/583 542/542/582
-f 543/543/583 553/553/593 554/554/594
-f 554/554/594 544/544/584 543/543/583
-f 544/544/584 554/554/594 555/555/595
-f 555/555/595 545/545/585 544/544/584
-f 545/545/585 555/555/595 556/556/596
-f 556/556/596 546/546/586 545/545/585
-f 546/546/586 556/556/596 557/557/597
-f 557/557/597 547/547/587 546/546/586
-f 547/547/587 557/557/597 558/558/598
-f 558/558/598 548/548/588 547/547/587
-f 548/548/588 558/558/598 559/559/599
-f 559/559/599 549/549/589 548/548/588
-f 549/549/589 559/559/599 560/560/600
-f 560/560/600 550/550/590 549/549/589
-f 132/132/144 143/143/156 561/561/601
-f 561/561/601 551/551/591 132/132/144
-f 551/551/591 561/561/601 562/562/602
-f 562/562/602 552/552/592 551/551/591
-f 552/552/592 562/562/602 563/563/603
-f 563/563/603 553/553/593 552/552/592
-f 553/553/593 563/563/603 564/564/604
-f 564/564/604 554/554/594 553/553/593
-f 554/554/594 564/564/604 565/565/605
-f 565/565/605 555/555/595 554/554/594
-f 555/555/595 565/565/605 566/566/606
-f 566/566/606 556/556/596 555/555/595
-f 556/556/596 566/566/606 567/567/607
-f 567/567/607 557/557/597 556/556/596
-f 557/557/597 567/567/607 568/568/608
-f 568/568/608 558/558/598 557/557/597
-f 558/558/598 568/568/608 569/569/609
-f 569/569/609 559/559/599 558/558/598
-f 559/559/599 569/569/609 570/570/610
-f 570/570/610 560/560/600 559/559/599
-f 143/143/156 154/154/168 571/571/611
-f 571/571/611 561/561/601 143/143/156
-f 561/561/601 571/571/611 572/572/612
-f 572/572/612 562/562/602 561/561/601
-f 562/562/602 572/572/612 573/573/613
-f 573/573/613 563/563/603 562/562/602
-f 563/563/603 573/573/613 574/574/614
-f 574/574/614 564/564/604 563/563/603
-f 564/564/604 574/574/614 575/575/615
-f 575/575/615 565/565/605 564/564/604
-f 565/565/605 575/575/615 576/576/616
-f 576/576/616 566/566/606 565/565/605
-f 566/566/606 576/576/616 577/577/617
-f 577/577/617 567/567/607 566/566/606
-f 567/567/607 577/577/617 578/578/618
-f 578/578/618 568/568/608 567/567/607
-f 568/568/608 578/578/618 579/579/619
-f 579/579/619 569/569/609 568/568/608
-f 569/569/609 579/579/619 580/580/620
-f 580/580/620 570/570/610 569/569/609
-f 154/154/168 165/165/180 581/581/621
-f 581/581/621 571/571/611 154/154/168
-f 571/571/611 581/581/621 582/582/622
-f 582/582/622 572/572/612 571/571/611
-f 572/572/612 582/582/622 583/583/623
-f 583/583/623 573/573/613 572/572/612
-f 573/573/613 583/583/623 584/584/624
-f 584/584/624 574/574/614 573/573/613
-f 574/574/614 584/584/624 585/585/625
-f 585/585/625 575/575/615 574/574/614
-f 575/575/615 585/585/625 586/586/626
-f 586/586/626 576/576/616 575/575/615
-f 576/576/616 586/586/626 587/587/627
-f 587/587/627 577/577/617 576/576/616
-f 577/577/617 587/587/627 588/588/628
-f 588/588/628 578/578/618 577/577/617
-f 578/578/618 588/588/628 589/589/629
-f 589/589/629 579/579/619 578/578/618
-f 579/579/619 589/589/629 590/590/630
-f 590/590/630 580/580/620 579/579/619
-f 165/165/180 176/176/192 591/591/631
-f 591/591/631 581/581/621 165/165/180
-f 581/581/621 591/591/631 592/592/632
-f 592/592/632 582/582/622 581/581/621
-f 582/582/622 592/592/632 593/593/633
-f 593/593/633 583/583/623 582/582/622
-f 583/583/623 593/593/633 594/594/634
-f 594/594/634 584/584/624 583/583/623
-f 584/584/624 594/594/634 595/595/635
-f 595/595/635 585/585/625 584/584/624
-f 585/585/625 595/595/635 596/596/636
-f 596/596/636 586/586/626 585/585/625
-f 586/586/626 596/596/636 597/597/637
-f 597/597/637 587/587/627 586/586/626
-f 587/587/627 597/597/637 598/598/638
-f 598/598/638 588/588/628 587/587/627
-f 588/588/628 598/598/638 599/599/639
-f 599/599/639 589/589/629 588/588/628
-f 589/589/629 599/599/639 600/600/640
-f 600/600/640 590/590/630 589/589/629
-f 176/176/192 187/187/204 601/601/641
-f 601/601/641 591/591/631 176/176/192
-f 591/591/631 601/601/641 602/602/642
-f 602/602/642 592/592/632 591/591/631
-f 592/592/632 602/602/642 603/603/643
-f 603/603/643 593/593/633 592/592/632
-f 593/593/633 603/603/643 604/604/644
-f 604/604/644 594/594/634 593/593/633
-f 594/594/634 604/604/644 605/605/645
-f 605/605/645 595/595/635 594/594/634
-f 595/595/635 605/605/645 606/606/646
-f 606/606/646 596/596/636 595/595/635
-f 596/596/636 606/606/646 607/607/647
-f 607/607/647 597/597/637 596/596/636
-f 597/597/637 607/607/647 608/608/648
-f 608/608/648 598/598/638 597/597/637
-f 598/598/638 608/608/648 609/609/649
-f 609/609/649 599/599/639 598/598/638
-f 599/599/639 609/609/649 610/610/650
-f 610/610/650 600/600/640 599/599/639
-f 187/187/204 198/198/216 611/611/651
-f 611/611/651 601/601/641 187/187/204
-f 601/601/641 611/611/651 612/612/652
-f 612/612/652 602/602/642 601/601/641
-f 602/602/642 612/612/652 613/613/653
-f 613/613/653 603/603/643 602/602/642
-f 603/603/643 613/613/653 614/614/654
-f 614/614/654 604/604/644 603/603/643
-f 604/604/644 614/614/654 615/615/655
-f 615/615/655 605/605/645 604/604/644
-f 605/605/645 615/615/655 616/616/656
-f 616/616/656 606/606/646 605/605/645
-f 606/606/646 616/616/656 617/617/657
-f 617/617/657 607/607/647 606/606/646
-f 607/607/647 617/617/657 618/618/658
-f 618/618/658 608/608/648 607/607/647
-f 608/608/648 618/618/658 619/619/659
-f 619/619/659 609/609/649 608/608/648
-f 609/609/649 619/619/659 620/620/660
-f 620/620/660 610/610/650 609/609/649
-f 198/198/216 209/209/228 621/621/661
-f 621/621/661 611/611/651 198/198/216
-f 611/611/651 621/621/661 622/622/662
-f 622/622/662 612/612/652 611/611/651
-f 612/612/652 622/622/662 623/623/663
-f 623/623/663 613/613/653 612/612/652
-f 613/613/653 623/623/663 624/624/664
-f 624/624/664 614/614/654 613/613/653
-f 614/614/654 624/624/664 625/625/665
-f 625/625/665 615/615/655 614/614/654
-f 615/615/655 625/625/665 626/626/666
-f 626/626/666 616/616/656 615/615/655
-f 616/616/656 626/626/666 627/627/667
-f 627/627/667 617/617/657 616/616/656
-f 617/617/657 627/627/667 628/628/668
-f 628/628/668 618/618/658 617/617/657
-f 618/618/658 628/628/668 629/629/669
-f 629/629/669 619/619/659 618/618/658
-f 619/619/659 629/629/669 630/630/670
-f 630/630/670 620/620/660 619/619/659
-f 209/209/228 220/220/240 631/631/671
-f 631/631/671 621/621/661 209/209/228
-f 621/621/661 631/631/671 632/632/672
-f 632/632/672 622/622/662 621/621/661
-f 622/622/662 632/632/672 633/633/673
-f 633/633/673 623/623/663 622/622/662
-f 623/623/663 633/633/673 634/634/674
-f 634/634/674 624/624/664 623/623/663
-f 624/624/664 634/634/674 635/635/675
-f 635/635/675 625/625/665 624/624/664
-f 625/625/665 635/635/675 636/636/676
-f 636/636/676 626/626/666 625/625/665
-f 626/626/666 636/636/676 637/637/677
-f 637/637/677 627/627/667 626/626/666
-f 627/627/667 637/637/677 638/638/678
-f 638/638/678 628/628/668 627/627/667
-f 628/628/668 638/638/678 639/639/679
-f 639/639/679 629/629/669 628/628/668
-f 629/629/669 639/639/679 640/640/680
-f 640/640/680 630/630/670 629/629/669
-f 220/220/240 231/231/252 641/641/681
-f 641/641/681 631/631/671 220/220/240
-f 631/631/671 641/641/681 642/642/682
-f 642/642/682 632/632/672 631/631/671
-f 632/632/672 642/642/682 643/643/683
-f 643/643/683 633/633/673 632/632/672
-f 633/633/673 643/643/683 644/644/684
-f 644/644/684 634/634/674 633/633/673
-f 634/634/674 644/644/684 645/645/685
-f 645/645/685 635/635/675 634/634/674
-f 635/635/675 645/645/685 646/646/686
-f 646/646/686 636/636/676 635/635/675
-f 636/636/676 646/646/686 647/647/687
-f 647/647/687 637/637/677 636/636/676
-f 637/637/677 647/647/687 648/648/688
-f 648/648/688 638/638/678 637/637/677
-f 638/638/678 648/648/688 649/649/689
-f 649/649/689 639/639/679 638/638/678
-f 639/639/679 649/649/689 650/650/690
-f 650/650/690 640/640/680 639/639/679
-f 231/231/252 242/242/264 651/651/691
-f 651/651/691 641/641/681 231/231/252
-f 641/641/681 651/651/691 652/652/692
-f 652/652/692 642/642/682 641/641/681
-f 642/642/682 652/652/692 653/653/693
-f 653/653/693 643/643/683 642/642/682
-f 643/643/683 653/653/693 654/654/694
-f 654/654/694 644/644/684 643/643/683
-f 644/644/684 654/654/694 655/655/695
-f 655/655/695 645/645/685 644/644/684
-f 645/645/685 655/655/695 656/656/696
-f 656/656/696 646/646/686 645/645/685
-f 646/646/686 656/656/696 657/657/697
-f 657/657/697 647/647/687 646/646/686
-f 647/647/687 657/657/697 658/658/698
-f 658/658/698 648/648/688 647/647/687
-f 648/648/688 658/658/698 659/659/699
-f 659/659/699 649/649/689 648/648/688
-f 649/649/689 659/659/699 660/660/700
-f 660/660/700 650/650/690 649/649/689
-f 242/242/264 253/253/276 661/661/701
-f 661/661/701 651/651/691 242/242/264
-f 651/651/691 661/661/701 662/662/702
-f 662/662/702 652/652/692 651/651/691
-f 652/652/692 662/662/702 663/663/703
-f 663/663/703 653/653/693 652/652/692
-f 653/653/693 663/663/703 664/664/704
-f 664/664/704 654/654/694 653/653/693
-f 654/654/694 664/664/704 665/665/705
-f 665/665/705 655/655/695 654/654/694
-f 655/655/695 665/665/705 666/666/706
-f 666/666/706 656/656/696 655/655/695
-f 656/656/696 666/666/706 667/667/707
-f 667/667/707 657/657/697 656/656/696
-f 657/657/697 667/667/707 668/668/708
-f 668/668/708 658/658/698 657/657/697
-f 658/658/698 668/668/708 669/669/709
-f 669/669/709 659/659/699 658/658/698
-f 659/659/699 669/669/709 670/670/710
-f 670/670/710 660/660/700 659/659/699
-f 253/253/276 264/264/288 671/671/711
-f 671/671/711 661/661/701 253/253/276
-f 661/661/701 671/671/711 672/672/712
-f 672/672/712 662/662/702 661/661/701
-f 662/662/702 672/672/712 673/673/713
-f 673/673/713 663/663/703 662/662/702
-f 663/663/703 673/673/713 674/674/714
-f 674/674/714 664/664/704 663/663/703
-f 664/664/704 674/674/714 675/675/715
-f 675/675/715 665/665/705 664/664/704
-f 665/665/705 675/675/715 676/676/716
-f 676/676/716 666/666/706 665/665/705
-f 666/666/706 676/676/716 677/677/717
-f 677/677/717 667/667/707 666/666/706
-f 667/667/707 677/677/717 678/678/718
-f 678/678/718 668/668/708 667/667/707
-f 668/668/708 678/678/718 679/679/719
-f 679/679/719 669/669/709 668/668/708
-f 669/669/709 679/679/719 680/680/720
-f 680/680/720 670/670/710 669/669/709
-f 264/264/288 275/275/300 681/681/721
-f 681/681/721 671/671/711 264/264/288
-f 671/671/711 681/681/721 682/682/722
-f 682/682/722 672/672/712 671/671/711
-f 672/672/712 682/682/722 683/683/723
-f 683/683/723 673/673/713 672/672/712
-f 673/673/713 683/683/723 684/684/724
-f 684/684/724 674/674/714 673/673/713
-f 674/674/714 684/684/724 685/685/725
-f 685/685/725 675/675/715 674/674/714
-f 675/675/715 685/685/725 686/686/726
-f 686/686/726 676/676/716 675/675/715
-f 676/676/716 686/686/726 687/687/727
-f 687/687/727 677/677/717 676/676/716
-f 677/677/717 687/687/727 688/688/728
-f 688/688/728 678/678/718 677/677/717
-f 678/678/718 688/688/728 689/689/729
-f 689/689/729 679/679/719 678/678/718
-f 679/679/719 689/689/729 690/690/730
-f 690/690/730 680/680/720 679/679/719
-f 275/275/300 286/286/312 691/691/731
-f 691/691/731 681/681/721 275/275/300
-f 681/681/721 691/691/731 692/692/732
-f 692/692/732 682/682/722 681/681/721
-f 682/682/722 692/692/732 693/693/733
-f 693/693/733 683/683/723 682/682/722
-f 683/683/723 693/693/733 694/694/734
-f 694/694/734 684/684/724 683/683/723
-f 684/684/724 694/694/734 695/695/735
-f 695/695/735 685/685/725 684/684/724
-f 685/685/725 695/695/735 696/696/736
-f 696/696/736 686/686/726 685/685/725
-f 686/686/726 696/696/736 697/697/737
-f 697/697/737 687/687/727 686/686/726
-f 687/687/727 697/697/737 698/698/738
-f 698/698/738 688/688/728 687/687/727
-f 688/688/728 698/698/738 699/699/739
-f 699/699/739 689/689/729 688/688/728
-f 689/689/729 699/699/739 700/700/740
-f 700/700/740 690/690/730 689/689/729
-f 286/286/312 297/297/324 701/701/741
-f 701/701/741 691/691/731 286/286/312
-f 691/691/731 701/701/741 702/702/742
-f 702/702/742 692/692/732 691/691/731
-f 692/692/732 702/702/742 703/703/743
-f 703/703/743 693/693/733 692/692/732
-f 693/693/733 703/703/743 704/704/744
-f 704/704/744 694/694/734 693/693/733
-f 694/694/734 704/704/744 705/705/745
-f 705/705/745 695/695/735 694/694/734
-f 695/695/735 705/705/745 706/706/746
-f 706/706/746 696/696/736 695/695/735
-f 696/696/736 706/706/746 707/707/747
-f 707/707/747 697/697/737 696/696/736
-f 697/697/737 707/707/747 708/708/748
-f 708/708/748 698/698/738 697/697/737
-f 698/698/738 708/708/748 709/709/749
-f 709/709/749 699/699/739 698/698/738
-f 699/699/739 709/709/749 710/710/750
-f 710/710/750 700/700/740 699/699/739
-f 297/297/324 308/308/336 711/711/751
-f 711/711/751 701/701/741 297/297/324
-f 701/701/741 711/711/751 712/712/752
-f 712/712/752 702/702/742 701/701/741
-f 702/702/742 712/712/752 713/713/753
-f 713/713/753 703/703/743 702/702/742
-f 703/703/743 713/713/753 714/714/754
-f 714/714/754 704/704/744 703/703/743
-f 704/704/744 714/714/754 715/715/755
-f 715/715/755 705/705/745 704/704/744
-f 705/705/745 715/715/755 716/716/756
-f 716/716/756 706/706/746 705/705/745
-f 706/706/746 716/716/756 717/717/757
-f 717/717/757 707/707/747 706/706/746
-f 707/707/747 717/717/757 718/718/758
-f 718/718/758 708/708/748 707/707/747
-f 708/708/748 718/718/758 719/719/759
-f 719/719/759 709/709/749 708/708/748
-f 709/709/749 719/719/759 720/720/760
-f 720/720/760 710/710/750 709/709/749
-f 308/308/336 319/319/348 721/721/761
-f 721/721/761 711/711/751 308/308/336
-f 711/711/751 721/721/761 722/722/762
-f 722/722/762 712/712/752 711/711/751
-f 712/712/752 722/722/762 723/723/763
-f 723/723/763 713/713/753 712/712/752
-f 713/713/753 723/723/763 724/724/764
-f 724/724/764 714/714/754 713/713/753
-f 714/714/754 724/724/764 725/725/765
-f 725/725/765 715/715/755 714/714/754
-f 715/715/755 725/725/765 726/726/766
-f 726/726/766 716/716/756 715/715/755
-f 716/716/756 726/726/766 727/727/767
-f 727/727/767 717/717/757 716/716/756
-f 717/717/757 727/727/767 728/728/768
-f 728/728/768 718/718/758 717/717/757
-f 718/718/758 728/728/768 729/729/769
-f 729/729/769 719/719/759 718/718/758
-f 719/719/759 729/729/769 730/730/770
-f 730/730/770 720/720/760 719/719/759
-f 319/319/348 330/330/360 731/731/771
-f 731/731/771 721/721/761 319/319/348
-f 721/721/761 731/731/771 732/732/772
-f 732/732/772 722/722/762 721/721/761
-f 722/722/762 732/732/772 733/733/773
-f 733/733/773 723/723/763 722/722/762
-f 723/723/763 733/733/773 734/734/774
-f 734/734/774 724/724/764 723/723/763
-f 724/724/764 734/734/774 735/735/775
-f 735/735/775 725/725/765 724/724/764
-f 725/725/765 735/735/775 736/736/776
-f 736/736/776 726/726/766 725/725/765
-f 726/726/766 736/736/776 737/737/777
-f 737/737/777 727/727/767 726/726/766
-f 727/727/767 737/737/777 738/738/778
-f 738/738/778 728/728/768 727/727/767
-f 728/728/768 738/738/778 739/739/779
-f 739/739/779 729/729/769 728/728/768
-f 729/729/769 739/739/779 740/740/780
-f 740/740/780 730/730/770 729/729/769
-f 330/330/360 341/341/372 741/741/781
-f 741/741/781 731/731/771 330/330/360
-f 731/731/771 741/741/781 742/742/782
-f 742/742/782 732/732/772 731/731/771
-f 732/732/772 742/742/782 743/743/783
-f 743/743/783 733/733/773 732/732/772
-f 733/733/773 743/743/783 744/744/784
-f 744/744/784 734/734/774 733/733/773
-f 734/734/774 744/744/784 745/745/785
-f 745/745/785 735/735/775 734/734/774
-f 735/735/775 745/745/785 746/746/786
-f 746/746/786 736/736/776 735/735/775
-f 736/736/776 746/746/786 747/747/787
-f 747/747/787 737/737/777 736/736/776
-f 737/737/777 747/747/787 748/748/788
-f 748/748/788 738/738/778 737/737/777
-f 738/738/778 748/748/788 749/749/789
-f 749/749/789 739/739/779 738/738/778
-f 739/739/779 749/749/789 750/750/790
-f 750/750/790 740/740/780 739/739/779
-f 341/341/372 352/352/384 751/751/791
-f 751/751/791 741/741/781 341/341/372
-f 741/741/781 751/751/791 752/752/792
-f 752/752/792 742/742/782 741/741/781
-f 742/742/782 752/752/792 753/753/793
-f 753/753/793 743/743/783 742/742/782
-f 743/743/783 753/753/793 754/754/794
-f 754/754/794 744/744/784 743/743/783
-f 744/744/784 754/754/794 755/755/795
-f 755/755/795 745/745/785 744/744/784
-f 745/745/785 755/755/795 756/756/796
-f 756/756/796 746/746/786 745/745/785
-f 746/746/786 756/756/796 757/757/797
-f 757/757/797 747/747/787 746/746/786
-f 747/747/787 757/757/797 758/758/798
-f 758/758/798 748/748/788 747/747/787
-f 748/748/788 758/758/798 759/759/799
-f 759/759/799 749/749/789 748/748/788
-f 749/749/789 759/759/799 760/760/800
-f 760/760/800 750/750/790 749/749/789
-f 352/352/384 363/363/396 761/761/801
-f 761/761/801 751/751/791 352/352/384
-f 751/751/791 761/761/801 762/762/802
-f 762/762/802 752/752/792 751/751/791
-f 752/752/792 762/762/802 763/763/803
-f 763/763/803 753/753/793 752/752/792
-f 753/753/793 763/763/803 764/764/804
-f 764/764/804 754/754/794 753/753/793
-f 754/754/794 764/764/804 765/765/805
-f 765/765/805 755/755/795 754/754/794
-f 755/755/795 765/765/805 766/766/806
-f 766/766/806 756/756/796 755/755/795
-f 756/756/796 766/766/806 767/767/807
-f 767/767/807 757/757/797 756/756/796
-f 757/757/797 767/767/807 768/768/808
-f 768/768/808 758/758/798 757/757/797
-f 758/758/798 768/768/808 769/769/809
-f 769/769/809 759/759/799 758/758/798
-f 759/759/799 769/769/809 770/770/810
-f 770/770/810 760/760/800 759/759/799
-f 363/363/396 374/374/408 771/771/811
-f 771/771/811 761/761/801 363/363/396
-f 761/761/801 771/771/811 772/772/812
-f 772/772/812 762/762/802 761/761/801
-f 762/762/802 772/772/812 773/773/813
-f 773/773/813 763/763/803 762/762/802
-f 763/763/803 773/773/813 774/774/814
-f 774/774/814 764/764/804 763/763/803
-f 764/764/804 774/774/814 775/775/815
-f 775/775/815 765/765/805 764/764/804
-f 765/765/805 775/775/815 776/776/816
-f 776/776/816 766/766/806 765/765/805
-f 766/766/806 776/776/816 777/777/817
-f 777/777/817 767/767/807 766/766/806
-f 767/767/807 777/777/817 778/778/818
-f 778/778/818 768/768/808 767/767/807
-f 768/768/808 778/778/818 779/779/819
-f 779/779/819 769/769/809 768/768/808
-f 769/769/809 779/779/819 780/780/820
-f 780/780/820 770/770/810 769/769/809
-f 374/374/408 385/385/420 781/781/821
-f 781/781/821 771/771/811 374/374/408
-f 771/771/811 781/781/821 782/782/822
-f 782/782/822 772/772/812 771/771/811
-f 772/772/812 782/782/822 783/783/823
-f 783/783/823 773/773/813 772/772/812
-f 773/773/813 783/783/823 784/784/824
-f 784/784/824 774/774/814 773/773/813
-f 774/774/814 784/784/824 785/785/825
-f 785/785/825 775/775/815 774/774/814
-f 775/775/815 785/785/825 786/786/826
-f 786/786/826 776/776/816 775/775/815
-f 776/776/816 786/786/826 787/787/827
-f 787/787/827 777/777/817 776/776/816
-f 777/777/817 787/787/827 788/788/828
-f 788/788/828 778/778/818 777/777/817
-f 778/778/818 788/788/828 789/789/829
-f 789/789/829 779/779/819 778/778/818
-f 779/779/819 789/789/829 790/790/830
-f 790/790/830 780/780/820 779/779/819
-f 385/385/420 396/396/432 791/791/831
-f 791/791/831 781/781/821 385/385/420
-f 781/781/821 791/791/831 792/792/832
-f 792/792/832 782/782/822 781/781/821
-f 782/782/822 792/792/832 793/793/833
-f 793/793/833 783/783/823 782/782/822
-f 783/783/823 793/793/833 794/794/834
-f 794/794/834 784/784/824 783/783/823
-f 784/784/824 794/794/834 795/795/835
-f 795/795/835 785/785/825 784/784/824
-f 785/785/825 795/795/835 796/796/836
-f 796/796/836 786/786/826 785/785/825
-f 786/786/826 796/796/836 797/797/837
-f 797/797/837 787/787/827 786/786/826
-f 787/787/827 797/797/837 798/798/838
-f 798/798/838 788/788/828 787/787/827
-f 788/788/828 798/798/838 799/799/839
-f 799/799/839 789/789/829 788/788/828
-f 789/789/829 799/799/839 800/800/840
-f 800/800/840 790/790/830 789/789/829
-f 396/396/432 407/407/444 801/801/841
-f 801/801/841 791/791/831 396/396/432
-f 791/791/831 801/801/841 802/802/842
-f 802/802/842 792/792/832 791/791/831
-f 792/792/832 802/802/842 803/803/843
-f 803/803/843 793/793/833 792/792/832
-f 793/793/833 803/803/843 804/804/844
-f 804/804/844 794/794/834 793/793/833
-f 794/794/834 804/804/844 805/805/845
-f 805/805/845 795/795/835 794/794/834
-f 795/795/835 805/805/845 806/806/846
-f 806/806/846 796/796/836 795/795/835
-f 796/796/836 806/806/846 807/807/847
-f 807/807/847 797/797/837 796/796/836
-f 797/797/837 807/807/847 808/808/848
-f 808/808/848 798/798/838 797/797/837
-f 798/798/838 808/808/848 809/809/849
-f 809/809/849 799/799/839 798/798/838
-f 799/799/839 809/809/849 810/810/850
-f 810/810/850 800/800/840 799/799/839
-f 407/407/444 418/418/456 811/811/851
-f 811/811/851 801/801/841 407/407/444
-f 801/801/841 811/811/851 812/812/852
-f 812/812/852 802/802/842 801/801/841
-f 802/802/842 812/812/852 813/813/853
-f 813/813/853 803/803/843 802/802/842
-f 803/803/843 813/813/853 814/814/854
-f 814/814/854 804/804/844 803/803/843
-f 804/804/844 814/814/854 815/815/855
-f 815/815/855 805/805/845 804/804/844
-f 805/805/845 815/815/855 816/816/856
-f 816/816/856 806/806/846 805/805/845
-f 806/806/846 816/816/856 817/817/857
-f 817/817/857 807/807/847 806/806/846
-f 807/807/847 817/817/857 818/818/858
-f 818/818/858 808/808/848 807/807/847
-f 808/808/848 818/818/858 819/819/859
-f 819/819/859 809/809/849 808/808/848
-f 809/809/849 819/819/859 820/820/860
-f 820/820/860 810/810/850 809/809/849
-f 418/418/456 429/429/468 821/821/861
-f 821/821/861 811/811/851 418/418/456
-f 811/811/851 821/821/861 822/822/862
-f 822/822/862 812/812/852 811/811/851
-f 812/812/852 822/822/862 823/823/863
-f 823/823/863 813/813/853 812/812/852
-f 813/813/853 823/823/863 824/824/864
-f 824/824/864 814/814/854 813/813/853
-f 814/814/854 824/824/864 825/825/865
-f 825/825/865 815/815/855 814/814/854
-f 815/815/855 825/825/865 826/826/866
-f 826/826/866 816/816/856 815/815/855
-f 816/816/856 826/826/866 827/827/867
-f 827/827/867 817/817/857 816/816/856
-f 817/817/857 827/827/867 828/828/868
-f 828/828/868 818/818/858 817/817/857
-f 818/818/858 828/828/868 829/829/869
-f 829/829/869 819/819/859 818/818/858
-f 819/819/859 829/829/869 830/830/870
-f 830/830/870 820/820/860 819/819/859
-f 429/429/468 440/440/480 831/831/871
-f 831/831/871 821/821/861 429/429/468
-f 821/821/861 831/831/871 832/832/872
-f 832/832/872 822/822/862 821/821/861
-f 822/822/862 832/832/872 833/833/873
-f 833/833/873 823/823/863 822/822/862
-f 823/823/863 833/833/873 834/834/874
-f 834/834/874 824/824/864 823/823/863
-f 824/824/864 834/834/874 835/835/875
-f 835/835/875 825/825/865 824/824/864
-f 825/825/865 835/835/875 836/836/876
-f 836/836/876 826/826/866 825/825/865
-f 826/826/866 836/836/876 837/837/877
-f 837/837/877 827/827/867 826/826/866
-f 827/827/867 837/837/877 838/838/878
-f 838/838/878 828/828/868 827/827/867
-f 828/828/868 838/838/878 839/839/879
-f 839/839/879 829/829/869 828/828/868
-f 829/829/869 839/839/879 840/840/880
-f 840/840/880 830/830/870 829/829/869
-f 440/440/480 22/22/24 442/442/482
-f 442/442/482 831/831/871 440/440/480
-f 831/831/871 442/442/482 444/444/484
-f 444/444/484 832/832/872 831/831/871
-f 832/832/872 444/444/484 446/446/486
-f 446/446/486 833/833/873 832/832/872
-f 833/833/873 446/446/486 448/448/488
-f 448/448/488 834/834/874 833/833/873
-f 834/834/874 448/448/488 450/450/490
-f 450/450/490 835/835/875 834/834/874
-f 835/835/875 450/450/490 452/452/492
-f 452/452/492 836/836/876 835/835/875
-f 836/836/876 452/452/492 454/454/494
-f 454/454/494 837/837/877 836/836/876
-f 837/837/877 454/454/494 456/456/496
-f 456/456/496 838/838/878 837/837/877
-f 838/838/878 456/456/496 458/458/498
-f 458/458/498 839/839/879 838/838/878
-f 839/839/879 458/458/498 460/460/500
-f 460/460/500 840/840/880 839/839/879
-f 460/460/500 459/459/499 841/841/881
-f 841/841/881 842/842/882 460/460/500
-f 842/842/882 841/841/881 843/843/883
-f 843/843/883 844/844/884 842/842/882
-f 844/844/884 843/843/883 845/845/885
-f 845/845/885 846/846/886 844/844/884
-f 846/846/886 845/845/885 847/847/887
-f 847/847/887 848/848/888 846/846/886
-f 848/848/888 847/847/887 849/849/889
-f 849/849/889 850/850/890 848/848/888
-f 850/850/890 849/849/889 851/851/891
-f 851/851/891 852/852/892 850/850/890
-f 852/852/892 851/851/891 853/853/893
-f 853/853/893 854/854/894 852/852/892
-f 854/854/894 853/853/893 855/855/895
-f 855/855/895 856/856/896 854/854/894
-f 856/856/896 855/855/895 857/857/897
-f 857/857/897 858/858/898 856/856/896
-f 858/858/898 857/857/897 859/21/899
-f 859/21/899 860/22/900 858/858/898
-f 459/459/499 470/470/510 861/859/901
-f 861/859/901 841/841/881 459/459/499
-f 841/841/881 861/859/901 862/860/902
-f 862/860/902 843/843/883 841/841/881
-f 843/843/883 862/860/902 863/861/903
-f 863/861/903 845/845/885 843/843/883
-f 845/845/885 863/861/903 864/862/904
-f 864/862/904 847/847/887 845/845/885
-f 847/847/887 864/862/904 865/863/905
-f 865/863/905 849/849/889 847/847/887
-f 849/849/889 865/863/905 866/864/906
-f 866/864/906 851/851/891 849/849/889
-f 851/851/891 866/864/906 867/865/907
-f 867/865/907 853/853/893 851/851/891
-f 853/853/893 867/865/907 868/866/908
-f 868/866/908 855/855/895 853/853/893
-f 855/855/895 868/866/908 869/867/909
-f 869/867/909 857/857/897 855/855/895
-f 857/857/897 869/867/909 870/33/910
-f 870/33/910 859/21/899 857/857/897
-f 470/470/510 480/480/520 871/868/911
-f 871/868/911 861/859/901 470/470/510
-f 861/859/901 871/868/911 872/869/912
-f 872/869/912 862/860/902 861/859/901
-f 862/860/902 872/869/912 873/870/913
-f 873/870/913 863/861/903 862/860/902
-f 863/861/903 873/870/913 874/871/914
-f 874/871/914 864/862/904 863/861/903
-f 864/862/904 874/871/914 875/872/915
-f 875/872/915 865/863/905 864/862/904
-f 865/863/905 875/872/915 876/873/916
-f 876/873/916 866/864/906 865/863/905
-f 866/864/906 876/873/916 877/874/917
-f 877/874/917 867/865/907 866/864/906
-f 867/865/907 877/874/917 878/875/918
-f 878/875/918 868/866/908 867/865/907
-f 868/866/908 878/875/918 879/876/919
-f 879/876/919 869/867/909 868/866/908
-f 869/867/909 879/876/919 880/44/920
-f 880/44/920 870/33/910 869/867/909
-f 480/480/520 490/490/530 881/877/921
-f 881/877/921 871/868/911 480/480/520
-f 871/868/911 881/877/921 882/878/922
-f 882/878/922 872/869/912 871/868/911
-f 872/869/912 882/878/922 883/879/923
-f 883/879/923 873/870/913 872/869/912
-f 873/870/913 883/879/923 884/880/924
-f 884/880/924 874/871/914 873/870/913
-f 874/871/914 884/880/924 885/881/925
-f 885/881/925 875/872/915 874/871/914
-f 875/872/915 885/881/925 886/882/926
-f 886/882/926 876/873/916 875/872/915
-f 876/873/916 886/882/926 887/883/927
-f 887/883/927 877/874/917 876/873/916
-f 877/874/917 887/883/927 888/884/928
-f 888/884/928 878/875/918 877/874/917
-f 878/875/918 888/884/928 889/885/929
-f 889/885/929 879/876/919 878/875/918
-f 879/876/919 889/885/929 890/55/930
-f 890/55/930 880/44/920 879/876/919
-f 490/490/530 500/500/540 891/886/931
-f 891/886/931 881/877/921 490/490/530
-f 881/877/921 891/886/931 892/887/932
-f 892/887/932 882/878/922 881/877/921
-f 882/878/922 892/887/932 893/888/933
-f 893/888/933 883/879/923 882/878/922
-f 883/879/923 893/888/933 894/889/934
-f 894/889/934 884/880/924 883/879/923
-f 884/880/924 894/889/934 895/890/935
-f 895/890/935 885/881/925 884/880/924
-f 885/881/925 895/890/935 896/891/936
-f 896/891/936 886/882/926 885/881/925
-f 886/882/926 896/891/936 897/892/937
-f 897/892/937 887/883/927 886/882/926
-f 887/883/927 897/892/937 898/893/938
-f 898/893/938 888/884/928 887/883/927
-f 888/884/928 898/893/938 899/894/939
-f 899/894/939 889/885/929 888/884/928
-f 889/885/929 899/894/939 900/66/940
-f 900/66/940 890/55/930 889/885/929
-f 500/500/540 510/510/550 901/895/941
-f 901/895/941 891/886/931 500/500/540
-f 891/886/931 901/895/941 902/896/942
-f 902/896/942 892/887/932 891/886/931
-f 892/887/932 902/896/942 903/897/943
-f 903/897/943 893/888/933 892/887/932
-f 893/888/933 903/897/943 904/898/944
-f 904/898/944 894/889/934 893/888/933
-f 894/889/934 904/898/944 905/899/945
-f 905/899/945 895/890/935 894/889/934
-f 895/890/935 905/899/945 906/900/946
-f 906/900/946 896/891/936 895/890/935
-f 896/891/936 906/900/946 907/901/947
-f 907/901/947 897/892/937 896/891/936
-f 897/892/937 907/901/947 908/902/948
-f 908/902/948 898/893/938 897/892/937
-f 898/893/938 908/902/948 909/903/949
-f 909/903/949 899/894/939 898/893/938
-f 899/894/939 909/903/949 910/77/950
-f 910/77/950 900/66/940 899/894/939
-f 510/510/550 520/520/560 911/904/951
-f 911/904/951 901/895/941 510/510/550
-f 901/895/941 911/904/951 912/905/952
-f 912/905/952 902/896/942 901/895/941
-f 902/896/942 912/905/952 913/906/953
-f 913/906/953 903/897/943 902/896/942
-f 903/897/943 913/906/953 914/907/954
-f 914/907/954 904/898/944 903/897/943
-f 904/898/944 914/907/954 915/908/955
-f 915/908/955 905/899/945 904/898/944
-f 905/899/945 915/908/955 916/909/956
-f 916/909/956 906/900/946 905/899/945
-f 906/900/946 916/909/956 917/910/957
-f 917/910/957 907/901/947 906/900/946
-f 907/901/947 917/910/957 918/911/958
-f 918/911/958 908/902/948 907/901/947
-f 908/902/948 918/911/958 919/912/959
-f 919/912/959 909/903/949 908/902/948
-f 909/903/949 919/912/959 920/88/960
-f 920/88/960 910/77/950 909/903/949
-f 520/520/560 530/530/570 921/913/961
-f 921/913/961 911/904/951 520/520/560
-f 911/904/951 921/913/961 922/914/962
-f 922/914/962 912/905/952 911/904/951
-f 912/905/952 922/914/962 923/915/963
-f 923/915/963 913/906/953 912/905/952
-f 913/906/953 923/915/963 924/916/964
-f 924/916/964 914/907/954 913/906/953
-f 914/907/954 924/916/964 925/917/965
-f 925/917/965 915/908/955 914/907/954
-f 915/908/955 925/917/965 926/918/966
-f 926/918/966 916/909/956 915/908/955
-f 916/909/956 926/918/966 927/919/967
-f 927/919/967 917/910/957 916/909/956
-f 917/910/957 927/919/967 928/920/968
-f 928/920/968 918/911/958 917/910/957
-f 918/911/958 928/920/968 929/921/969
-f 929/921/969 919/912/959 918/911/958
-f 919/912/959 929/921/969 930/99/970
-f 930/99/970 920/88/960 919/912/959
-f 530/530/570 540/540/580 931/922/971
-f 931/922/971 921/913/961 530/530/570
-f 921/913/961 931/922/971 932/923/972
-f 932/923/972 922/914/962 921/913/961
-f 922/914/962 932/923/972 933/924/973
-f 933/924/973 923/915/963 922/914/962
-f 923/915/963 933/924/973 934/925/974
-f 934/925/974 924/916/964 923/915/963
-f 924/916/964 934/925/974 935/926/975
-f 935/926/975 925/917/965 924/916/964
-f 925/917/965 935/926/975 936/927/976
-f 936/927/976 926/918/966 925/917/965
-f 926/918/966 936/927/976 937/928/977
-f 937/928/977 927/919/967 926/918/966
-f 927/919/967 937/928/977 938/929/978
-f 938/929/978 928/920/968 927/919/967
-f 928/920/968 938/929/978 939/930/979
-f 939/930/979 929/921/969 928/920/968
-f 929/921/969 939/930/979 940/110/980
-f 940/110/980 930/99/970 929/921/969
-f 540/540/580 550/550/590 941/931/981
-f 941/931/981 931/922/971 540/540/580
-f 931/922/971 941/931/981 942/932/982
-f 942/932/982 932/923/972 931/922/971
-f 932/923/972 942/932/982 943/933/983
-f 943/933/983 933/924/973 932/923/972
-f 933/924/973 943/933/983 944/934/984
-f 944/934/984 934/925/974 933/924/973
-f 934/925/974 944/934/984 945/935/985
-f 945/935/985 935/926/975 934/925/974
-f 935/926/975 945/935/985 946/936/986
-f 946/936/986 936/927/976 935/926/975
-f 936/927/976 946/936/986 947/937/987
-f 947/937/987 937/928/977 936/927/976
-f 937/928/977 947/937/987 948/938/988
-f 948/938/988 938/929/978 937/928/977
-f 938/929/978 948/938/988 949/939/989
-f 949/939/989 939/930/979 938/929/978
-f 939/930/979 949/939/989 950/121/990
-f 950/121/990 940/110/980 939/930/979
-f 550/550/590 560/560/600 951/940/991
-f 951/940/991 941/931/981 550/550/590
-f 941/931/981 951/940/991 952/941/992
-f 952/941/992 942/932/982 941/931/981
-f 942/932/982 952/941/992 953/942/993
-f 953/942/993 943/933/983 942/932/982
-f 943/933/983 953/942/993 954/943/994
-f 954/943/994 944/934/984 943/933/983
-f 944/934/984 954/943/994 955/944/995
-f 955/944/995 945/935/985 944/934/984
-f 945/935/985 955/944/995 956/945/996
-f 956/945/996 946/936/986 945/935/985
-f 946/936/986 956/945/996 957/946/997
-f 957/946/997 947/937/987 946/936/986
-f 947/937/987 957/946/997 958/947/998
-f 958/947/998 948/938/988 947/937/987
-f 948/938/988 958/947/998 959/948/999
-f 959/948/999 949/939/989 948/938/988
-f 949/939/989 959/948/999 960/132/1000
-f 960/132/1000 950/121/990 949/939/989
-f 560/560/600 570/570/610 961/949/1001
-f 961/949/1001 951/940/991 560/560/600
-f 951/940/991 961/949/1001 962/950/1002
-f 962/950/1002 952/941/992 951/940/991
-f 952/941/992 962/950/1002 963/951/1003
-f 963/951/1003 953/942/993 952/941/992
-f 953/942/993 963/951/1003 964/952/1004
-f 964/952/1004 954/943/994 953/942/993
-f 954/943/994 964/952/1004 965/953/1005
-f 965/953/1005 955/944/995 954/943/994
-f 955/944/995 965/953/1005 966/954/1006
-f 966/954/1006 956/945/996 955/944/995
-f 956/945/996 966/954/1006 967/955/1007
-f 967/955/1007 957/946/997 956/945/996
-f 957/946/997 967/955/1007 968/956/1008
-f 968/956/1008 958/947/998 957/946/997
-f 958/947/998 968/956/1008 969/957/1009
-f 969/957/1009 959/948/999 958/947/998
-f 959/948/999 969/957/1009 970/143/1010
-f 970/143/1010 960/132/1000 959/948/999
-f 570/570/610 580/580/620 971/958/1011
-f 971/958/1011 961/949/1001 570/570/610
-f 961/949/1001 971/958/1011 972/959/1012
-f 972/959/1012 962/950/1002 961/949/1001
-f 962/950/1002 972/959/1012 973/960/1013
-f 973/960/1013 963/951/1003 962/950/1002
-f 963/951/1003 973/960/1013 974/961/1014
-f 974/961/1014 964/952/1004 963/951/1003
-f 964/952/1004 974/961/1014 975/962/1015
-f 975/962/1015 965/953/1005 964/952/1004
-f 965/953/1005 975/962/1015 976/963/1016
-f 976/963/1016 966/954/1006 965/953/1005
-f 966/954/1006 976/963/1016 977/964/1017
-f 977/964/1017 967/955/1007 966/954/1006
-f 967/955/1007 977/964/1017 978/965/1018
-f 978/965/1018 968/956/1008 967/955/1007
-f 968/956/1008 978/965/1018 979/966/1019
-f 979/966/1019 969/957/1009 968/956/1008
-f 969/957/1009 979/966/1019 980/154/1020
-f 980/154/1020 970/143/1010 969/957/1009
-f 580/580/620 590/590/630 981/967/1021
-f 981/967/1021 971/958/1011 580/580/620
-f 971/958/1011 981/967/1021 982/968/1022
-f 982/968/1022 972/959/1012 971/958/1011
-f 972/959/1012 982/968/1022 983/969/1023
-f 983/969/1023 973/960/1013 972/959/1012
-f 973/960/1013 983/969/1023 984/970/1024
-f 984/970/1024 974/961/1014 973/960/1013
-f 974/961/1014 984/970/1024 985/971/1025
-f 985/971/1025 975/962/1015 974/961/1014
-f 975/962/1015 985/971/1025 986/972/1026
-f 986/972/1026 976/963/1016 975/962/1015
-f 976/963/1016 986/972/1026 987/973/1027
-f 987/973/1027 977/964/1017 976/963/1016
-f 977/964/1017 987/973/1027 988/974/1028
-f 988/974/1028 978/965/1018 977/964/1017
-f 978/965/1018 988/974/1028 989/975/1029
-f 989/975/1029 979/966/1019 978/965/1018
-f 979/966/1019 989/975/1029 990/165/1030
-f 990/165/1030 980/154/1020 979/966/1019
-f 590/590/630 600/600/640 991/976/1031
-f 991/976/1031 981/967/1021 590/590/630
-f 981/967/1021 991/976/1031 992/977/1032
-f 992/977/1032 982/968/1022 981/967/1021
-f 982/968/1022 992/977/1032 993/978/1033
-f 993/978/1033 983/969/1023 982/968/1022
-f 983/969/1023 993/978/1033 994/979/1034
-f 994/979/1034 984/970/1024 983/969/1023
-f 984/970/1024 994/979/1034 995/980/1035
-f 995/980/1035 985/971/1025 984/970/1024
-f 985/971/1025 995/980/1035 996/981/1036
-f 996/981/1036 986/972/1026 985/971/1025
-f 986/972/1026 996/981/1036 997/982/1037
-f 997/982/1037 987/973/1027 986/972/1026
-f 987/973/1027 997/982/1037 998/983/1038
-f 998/983/1038 988/974/1028 987/973/1027
-f 988/974/1028 998/983/1038 999/984/1039
-f 999/984/1039 989/975/1029 988/974/1028
-f 989/975/1029 999/984/1039 1000/176/1040
-f 1000/176/1040 990/165/1030 989/975/1029
-f 600/600/640 610/610/650 1001/985/1041
-f 1001/985/1041 991/976/1031 600/600/640
-f 991/976/1031 1001/985/1041 1002/986/1042
-f 1002/986/1042 992/977/1032 991/976/1031
-f 992/977/1032 1002/986/1042 1003/987/1043
-f 1003/987/1043 993/978/1033 992/977/1032
-f 993/978/1033 1003/987/1043 1004/988/1044
-f 1004/988/1044 994/979/1034 993/978/1033
-f 994/979/1034 1004/988/1044 1005/989/1045
-f 1005/989/1045 995/980/1035 994/979/1034
-f 995/980/1035 1005/989/1045 1006/990/1046
-f 1006/990/1046 996/981/1036 995/980/1035
-f 996/981/1036 1006/990/1046 1007/991/1047
-f 1007/991/1047 997/982/1037 996/981/1036
-f 997/982/1037 1007/991/1047 1008/992/1048
-f 1008/992/1048 998/983/1038 997/982/1037
-f 998/983/1038 1008/992/1048 1009/993/1049
-f 1009/993/1049 999/984/1039 998/983/1038
-f 999/984/1039 1009/993/1049 1010/187/1050
-f 1010/187/1050 1000/176/1040 999/984/1039
-f 610/610/650 620/620/660 1011/994/1051
-f 1011/994/1051 1001/985/1041 610/610/650
-f 1001/985/1041 1011/994/1051 1012/995/1052
-f 1012/995/1052 1002/986/1042 1001/985/1041
-f 1002/986/1042 1012/995/1052 1013/996/1053
-f 1013/996/1053 1003/987/1043 1002/986/1042
-f 1003/987/1043 1013/996/1053 1014/997/1054
-f 1014/997/1054 1004/988/1044 1003/987/1043
-f 1004/988/1044 1014/997/1054 1015/998/1055
-f 1015/998/1055 1005/989/1045 1004/988/1044
-f 1005/989/1045 1015/998/1055 1016/999/1056
-f 1016/999/1056 1006/990/1046 1005/989/1045
-f 1006/990/1046 1016/999/1056 1017/1000/1057
-f 1017/1000/1057 1007/991/1047 1006/990/1046
-f 1007/991/1047 1017/1000/1057 1018/1001/1058
-f 1018/1001/1058 1008/992/1048 1007/991/1047
-f 1008/992/1048 1018/1001/1058 1019/1002/1059
-f 1019/1002/1059 1009/993/1049 1008/992/1048
-f 1009/993/1049 1019/1002/1059 1020/198/1060
-f 1020/198/1060 1010/187/1050 1009/993/1049
-f 620/620/660 630/630/670 1021/1003/1061
-f 1021/1003/1061 1011/994/1051 620/620/660
-f 1011/994/1051 1021/1003/1061 1022/1004/1062
-f 1022/1004/1062 1012/995/1052 1011/994/1051
-f 1012/995/1052 1022/1004/1062 1023/1005/1063
-f 1023/1005/1063 1013/996/1053 1012/995/1052
-f 1013/996/1053 1023/1005/1063 1024/1006/1064
-f 1024/1006/1064 1014/997/1054 1013/996/1053
-f 1014/997/1054 1024/1006/1064 1025/1007/1065
-f 1025/1007/1065 1015/998/1055 1014/997/1054
-f 1015/998/1055 1025/1007/1065 1026/1008/1066
-f 1026/1008/1066 1016/999/1056 1015/998/1055
-f 1016/999/1056 1026/1008/1066 1027/1009/1067
-f 1027/1009/1067 1017/1000/1057 1016/999/1056
-f 1017/1000/1057 1027/1009/1067 1028/1010/1068
-f 1028/1010/1068 1018/1001/1058 1017/1000/1057
-f 1018/1001/1058 1028/1010/1068 1029/1011/1069
-f 1029/1011/1069 1019/1002/1059 1018/1001/1058
-f 1019/1002/1059 1029/1011/1069 1030/209/1070
-f 1030/209/1070 1020/198/1060 1019/1002/1059
-f 630/630/670 640/640/680 1031/1012/1071
-f 1031/1012/1071 1021/1003/1061 630/630/670
-f 1021/1003/1061 1031/1012/1071 1032/1013/1072
-f 1032/1013/1072 1022/1004/1062 1021/1003/1061
-f 1022/1004/1062 1032/1013/1072 1033/1014/1073
-f 1033/1014/1073 1023/1005/1063 1022/1004/1062
-f 1023/1005/1063 1033/1014/1073 1034/1015/1074
-f 1034/1015/1074 1024/1006/1064 1023/1005/1063
-f 1024/1006/1064 1034/1015/1074 1035/1016/1075
-f 1035/1016/1075 1025/1007/1065 1024/1006/1064
-f 1025/1007/1065 1035/1016/1075 1036/1017/1076
-f 1036/1017/1076 1026/1008/1066 1025/1007/1065
-f 1026/1008/1066 1036/1017/1076 1037/1018/1077
-f 1037/1018/1077 1027/1009/1067 1026/1008/1066
-f 1027/1009/1067 1037/1018/1077 1038/1019/1078
-f 1038/1019/1078 1028/1010/1068 1027/1009/1067
-f 1028/1010/1068 1038/1019/1078 1039/1020/1079
-f 1039/1020/1079 1029/1011/1069 1028/1010/1068
-f 1029/1011/1069 1039/1020/1079 1040/220/1080
-f 1040/220/1080 1030/209/1070 1029/1011/1069
-f 640/640/680 650/650/690 1041/1021/1081
-f 1041/1021/1081 1031/1012/1071 640/640/680
-f 1031/1012/1071 1041/1021/1081 1042/1022/1082
-f 1042/1022/1082 1032/1013/1072 1031/1012/1071
-f 1032/1013/1072 1042/1022/1082 1043/1023/1083
-f 1043/1023/1083 1033/1014/1073 1032/1013/1072
-f 1033/1014/1073 1043/1023/1083 1044/1024/1084
-f 1044/1024/1084 1034/1015/1074 1033/1014/1073
-f 1034/1015/1074 1044/1024/1084 1045/1025/1085
-f 1045/1025/1085 1035/1016/1075 1034/1015/1074
-f 1035/1016/1075 1045/1025/1085 1046/1026/1086
-f 1046/1026/1086 1036/1017/1076 1035/1016/1075
-f 1036/1017/1076 1046/1026/1086 1047/1027/1087
-f 1047/1027/1087 1037/1018/1077 1036/1017/1076
-f 1037/1018/1077 1047/1027/1087 1048/1028/1088
-f 1048/1028/1088 1038/1019/1078 1037/1018/1077
-f 1038/1019/1078 1048/1028/1088 1049/1029/1089
-f 1049/1029/1089 1039/1020/1079 1038/1019/1078
-f 1039/1020/1079 1049/1029/1089 1050/231/1090
-f 1050/231/1090 1040/220/1080 1039/1020/1079
-f 650/650/690 660/660/700 1051/1030/1091
-f 1051/1030/1091 1041/1021/1081 650/650/690
-f 1041/1021/1081 1051/1030/1091 1052/1031/1092
-f 1052/1031/1092 1042/1022/1082 1041/1021/1081
-f 1042/1022/1082 1052/1031/1092 1053/1032/1093
-f 1053/1032/1093 1043/1023/1083 1042/1022/1082
-f 1043/1023/1083 1053/1032/1093 1054/1033/1094
-f 1054/1033/1094 1044/1024/1084 1043/1023/1083
-f 1044/1024/1084 1054/1033/1094 1055/1034/1095
-f 1055/1034/1095 1045/1025/1085 1044/1024/1084
-f 1045/1025/1085 1055/1034/1095 1056/1035/1096
-f 1056/1035/1096 1046/1026/1086 1045/1025/1085
-f 1046/1026/1086 1056/1035/1096 1057/1036/1097
-f 1057/1036/1097 1047/1027/1087 1046/1026/1086
-f 1047/1027/1087 1057/1036/1097 1058/1037/1098
-f 1058/1037/1098 1048/1028/1088 1047/1027/1087
-f 1048/1028/1088 1058/1037/1098 1059/1038/1099
-f 1059/1038/1099 1049/1029/1089 1048/1028/1088
-f 1049/1029/1089 1059/1038/1099 1060/242/1100
-f 1060/242/1100 1050/231/1090 1049/1029/1089
-f 660/660/700 670/670/710 1061/1039/1101
-f 1061/1039/1101 1051/1030/1091 660/660/700
-f 1051/1030/1091 1061/1039/1101 1062/1040/1102
-f 1062/1040/1102 1052/1031/1092 1051/1030/1091
-f 1052/1031/1092 1062/1040/1102 1063/1041/1103
-f 1063/1041/1103 1053/1032/1093 1052/1031/1092
-f 1053/1032/1093 1063/1041/1103 1064/1042/1104
-f 1064/1042/1104 1054/1033/1094 1053/1032/1093
-f 1054/1033/1094 1064/1042/1104 1065/1043/1105
-f 1065/1043/1105 1055/1034/1095 1054/1033/1094
-f 1055/1034/1095 1065/1043/1105 1066/1044/1106
-f 1066/1044/1106 1056/1035/1096 1055/1034/1095
-f 1056/1035/1096 1066/1044/1106 1067/1045/1107
-f 1067/1045/1107 1057/1036/1097 1056/1035/1096
-f 1057/1036/1097 1067/1045/1107 1068/1046/1108
-f 1068/1046/1108 1058/1037/1098 1057/1036/1097
-f 1058/1037/1098 1068/1046/1108 1069/1047/1109
-f 1069/1047/1109 1059/1038/1099 1058/1037/1098
-f 1059/1038/1099 1069/1047/1109 1070/253/1110
-f 1070/253/1110 1060/242/1100 1059/1038/1099
-f 670/670/710 680/680/720 1071/1048/1111
-f 1071/1048/1111 1061/1039/1101 670/670/710
-f 1061/1039/1101 1071/1048/1111 1072/1049/1112
-f 1072/1049/1112 1062/1040/1102 1061/1039/1101
-f 1062/1040/1102 1072/1049/1112 1073/1050/1113
-f 1073/1050/1113 1063/1041/1103 1062/1040/1102
-f 1063/1041/1103 1073/1050/1113 1074/1051/1114
-f 1074/1051/1114 1064/1042/1104 1063/1041/1103
-f 1064/1042/1104 1074/1051/1114 1075/1052/1115
-f 1075/1052/1115 1065/1043/1105 1064/1042/1104
-f 1065/1043/1105 1075/1052/1115 1076/1053/1116
-f 1076/1053/1116 1066/1044/1106 1065/1043/1105
-f 1066/1044/1106 1076/1053/1116 1077/1054/1117
-f 1077/1054/1117 1067/1045/1107 1066/1044/1106
-f 1067/1045/1107 1077/1054/1117 1078/1055/1118
-f 1078/1055/1118 1068/1046/1108 1067/1045/1107
-f 1068/1046/1108 1078/1055/1118 1079/1056/1119
-f 1079/1056/1119 1069/1047/1109 1068/1046/1108
-f 1069/1047/1109 1079/1056/1119 1080/264/1120
-f 1080/264/1120 1070/253/1110 1069/1047/1109
-f 680/680/720 690/690/730 1081/1057/1121
-f 1081/1057/1121 1071/1048/1111 680/680/720
-f 1071/1048/1111 1081/1057/1121 1082/1058/1122
-f 1082/1058/1122 1072/1049/1112 1071/1048/1111
-f 1072/1049/1112 1082/1058/1122 1083/1059/1123
-f 1083/1059/1123 1073/1050/1113 1072/1049/1112
-f 1073/1050/1113 1083/1059/1123 1084/1060/1124
-f 1084/1060/1124 1074/1051/1114 1073/1050/1113
-f 1074/1051/1114 1084/1060/1124 1085/1061/1125
-f 1085/1061/1125 1075/1052/1115 1074/1051/1114
-f 1075/1052/1115 1085/1061/1125 1086/1062/1126
-f 1086/1062/1126 1076/1053/1116 1075/1052/1115
-f 1076/1053/1116 1086/1062/1126 1087/1063/1127
-f 1087/1063/1127 1077/1054/1117 1076/1053/1116
-f 1077/1054/1117 1087/1063/1127 1088/1064/1128
-f 1088/1064/1128 1078/1055/1118 1077/1054/1117
-f 1078/1055/1118 1088/1064/1128 1089/1065/1129
-f 1089/1065/1129 1079/1056/1119 1078/1055/1118
-f 1079/1056/1119 1089/1065/1129 1090/275/1130
-f 1090/275/1130 1080/264/1120 1079/1056/1119
-f 690/690/730 700/700/740 1091/1066/1131
-f 1091/1066/1131 1081/1057/1121 690/690/730
-f 1081/1057/1121 1091/1066/1131 1092/1067/1132
-f 1092/1067/1132 1082/1058/1122 1081/1057/1121
-f 1082/1058/1122 1092/1067/1132 1093/1068/1133
-f 1093/1068/1133 1083/1059/1123 1082/1058/1122
-f 1083/1059/1123 1093/1068/1133 1094/1069/1134
-f 1094/1069/1134 1084/1060/1124 1083/1059/1123
-f 1084/1060/1124 1094/1069/1134 1095/1070/1135
-f 1095/1070/1135 1085/1061/1125 1084/1060/1124
-f 1085/1061/1125 1095/1070/1135 1096/1071/1136
-f 1096/1071/1136 1086/1062/1126 1085/1061/1125
-f 1086/1062/1126 1096/1071/1136 1097/1072/1137
-f 1097/1072/1137 1087/1063/1127 1086/1062/1126
-f 1087/1063/1127 1097/1072/1137 1098/1073/1138
-f 1098/1073/1138 1088/1064/1128 1087/1063/1127
-f 1088/1064/1128 1098/1073/1138 1099/1074/1139
-f 1099/1074/1139 1089/1065/1129 1088/1064/1128
-f 1089/1065/1129 1099/1074/1139 1100/286/1140
-f 1100/286/1140 1090/275/1130 1089/1065/1129
-f 700/700/740 710/710/750 1101/1075/1141
-f 1101/1075/1141 1091/1066/1131 700/700/740
-f 1091/1066/1131 1101/1075/1141 1102/1076/1142
-f 1102/1076/1142 1092/1067/1132 1091/1066/1131
-f 1092/1067/1132 1102/1076/1142 1103/1077/1143
-f 1103/1077/1143 1093/1068/1133 1092/1067/1132
-f 1093/1068/1133 1103/1077/1143 1104/1078/1144
-f 1104/1078/1144 1094/1069/1134 1093/1068/1133
-f 1094/1069/1134 1104/1078/1144 1105/1079/1145
-f 1105/1079/1145 1095/1070/1135 1094/1069/1134
-f 1095/1070/1135 1105/1079/1145 1106/1080/1146
-f 1106/1080/1146 1096/1071/1136 1095/1070/1135
-f 1096/1071/1136 1106/1080/1146 1107/1081/1147
-f 1107/1081/1147 1097/1072/1137 1096/1071/1136
-f 1097/1072/1137 1107/1081/1147 1108/1082/1148
-f 1108/1082/1148 1098/1073/1138 1097/1072/1137
-f 1098/1073/1138 1108/1082/1148 1109/1083/1149
-f 1109/1083/1149 1099/1074/1139 1098/1073/1138
-f 1099/1074/1139 1109/1083/1149 1110/297/1150
-f 1110/297/1150 1100/286/1140 1099/1074/1139
-f 710/710/750 720/720/760 1111/1084/1151
-f 1111/1084/1151 1101/1075/1141 710/710/750
-f 1101/1075/1141 1111/1084/1151 1112/1085/1152
-f 1112/1085/1152 1102/1076/1142 1101/1075/1141
-f 1102/1076/1142 1112/1085/1152 1113/1086/1153
-f 1113/1086/1153 1103/1077/1143 1102/1076/1142
-f 1103/1077/1143 1113/1086/1153 1114/1087/1154
-f 1114/1087/1154 1104/1078/1144 1103/1077/1143
-f 1104/1078/1144 1114/1087/1154 1115/1088/1155
-f 1115/1088/1155 1105/1079/1145 1104/1078/1144
-f 1105/1079/1145 1115/1088/1155 1116/1089/1156
-f 1116/1089/1156 1106/1080/1146 1105/1079/1145
-f 1106/1080/1146 1116/1089/1156 1117/1090/1157
-f 1117/1090/1157 1107/1081/1147 1106/1080/1146
-f 1107/1081/1147 1117/1090/1157 1118/1091/1158
-f 1118/1091/1158 1108/1082/1148 1107/1081/1147
-f 1108/1082/1148 1118/1091/1158 1119/1092/1159
-f 1119/1092/1159 1109/1083/1149 1108/1082/1148
-f 1109/1083/1149 1119/1092/1159 1120/308/1160
-f 1120/308/1160 1110/297/1150 1109/1083/1149
-f 720/720/760 730/730/770 1121/1093/1161
-f 1121/1093/1161 1111/1084/1151 720/720/760
-f 1111/1084/1151 1121/1093/1161 1122/1094/1162
-f 1122/1094/1162 1112/1085/1152 1111/1084/1151
-f 1112/1085/1152 1122/1094/1162 1123/1095/1163
-f 1123/1095/1163 1113/1086/1153 1112/1085/1152
-f 1113/1086/1153 1123/1095/1163 1124/1096/1164
-f 1124/1096/1164 1114/1087/1154 1113/1086/1153
-f 1114/1087/1154 1124/1096/1164 1125/1097/1165
-f 1125/1097/1165 1115/1088/1155 1114/1087/1154
-f 1115/1088/1155 1125/1097/1165 1126/1098/1166
-f 1126/1098/1166 1116/1089/1156 1115/1088/1155
-f 1116/1089/1156 1126/1098/1166 1127/1099/1167
-f 1127/1099/1167 1117/1090/1157 1116/1089/1156
-f 1117/1090/1157 1127/1099/1167 1128/1100/1168
-f 1128/1100/1168 1118/1091/1158 1117/1090/1157
-f 1118/1091/1158 1128/1100/1168 1129/1101/1169
-f 1129/1101/1169 1119/1092/1159 1118/1091/1158
-f 1119/1092/1159 1129/1101/1169 1130/319/1170
-f 1130/319/1170 1120/308/1160 1119/1092/1159
-f 730/730/770 740/740/780 1131/1102/1171
-f 1131/1102/1171 1121/1093/1161 730/730/770
-f 1121/1093/1161 1131/1102/1171 1132/1103/1172
-f 1132/1103/1172 1122/1094/1162 1121/1093/1161
-f 1122/1094/1162 1132/1103/1172 1133/1104/1173
-f 1133/1104/1173 1123/1095/1163 1122/1094/1162
-f 1123/1095/1163 1133/1104/1173 1134/1105/1174
-f 1134/1105/1174 1124/1096/1164 1123/1095/1163
-f 1124/1096/1164 1134/1105/1174 1135/1106/1175
-f 1135/1106/1175 1125/1097/1165 1124/1096/1164
-f 1125/1097/1165 1135/1106/1175 1136/1107/1176
-f 1136/1107/1176 1126/1098/1166 1125/1097/1165
-f 1126/1098/1166 1136/1107/1176 1137/1108/1177
-f 1137/1108/1177 1127/1099/1167 1126/1098/1166
-f 1127/1099/1167 1137/1108/1177 1138/1109/1178
-f 1138/1109/1178 1128/1100/1168 1127/1099/1167
-f 1128/1100/1168 1138/1109/1178 1139/1110/1179
-f 1139/1110/1179 1129/1101/1169 1128/1100/1168
-f 1129/1101/1169 1139/1110/1179 1140/330/1180
-f 1140/330/1180 1130/319/1170 1129/1101/1169
-f 740/740/780 750/750/790 1141/1111/1181
-f 1141/1111/1181 1131/1102/1171 740/740/780
-f 1131/1102/1171 1141/1111/1181 1142/1112/1182
-f 1142/1112/1182 1132/1103/1172 1131/1102/1171
-f 1132/1103/1172 1142/1112/1182 1143/1113/1183
-f 1143/1113/1183 1133/1104/1173 1132/1103/1172
-f 1133/1104/1173 1143/1113/1183 1144/1114/1184
-f 1144/1114/1184 1134/1105/1174 1133/1104/1173
-f 1134/1105/1174 1144/1114/1184 1145/1115/1185
-f 1145/1115/1185 1135/1106/1175 1134/1105/1174
-f 1135/1106/1175 1145/1115/1185 1146/1116/1186
-f 1146/1116/1186 1136/1107/1176 1135/1106/1175
-f 1136/1107/1176 1146/1116/1186 1147/1117/1187
-f 1147/1117/1187 1137/1108/1177 1136/1107/1176
-f 1137/1108/1177 1147/1117/1187 1148/1118/1188
-f 1148/1118/1188 1138/1109/1178 1137/1108/1177
-f 1138/1109/1178 1148/1118/1188 1149/1119/1189
-f 1149/1119/1189 1139/1110/1179 1138/1109/1178
-f 1139/1110/1179 1149/1119/1189 1150/341/1190
-f 1150/341/1190 1140/330/1180 1139/1110/1179
-f 750/750/790 760/760/800 1151/1120/1191
-f 1151/1120/1191 1141/1111/1181 750/750/790
-f 1141/1111/1181 1151/1120/1191 1152/1121/1192
-f 1152/1121/1192 1142/1112/1182 1141/1111/1181
-f 1142/1112/1182 1152/1121/1192 1153/1122/1193
-f 1153/1122/1193 1143/1113/1183 1142/1112/1182
-f 1143/1113/1183 1153/1122/1193 1154/1123/1194
-f 1154/1123/1194 1144/1114/1184 1143/1113/1183
-f 1144/1114/1184 1154/1123/1194 1155/1124/1195
-f 1155/1124/1195 1145/1115/1185 1144/1114/1184
-f 1145/1115/1185 1155/1124/1195 1156/1125/1196
-f 1156/1125/1196 1146/1116/1186 1145/1115/1185
-f 1146/1116/1186 1156/1125/1196 1157/1126/1197
-f 1157/1126/1197 1147/1117/1187 1146/1116/1186
-f 1147/1117/1187 1157/1126/1197 1158/1127/1198
-f 1158/1127/1198 1148/1118/1188 1147/1117/1187
-f 1148/1118/1188 1158/1127/1198 1159/1128/1199
-f 1159/1128/1199 1149/1119/1189 1148/1118/1188
-f 1149/1119/1189 1159/1128/1199 1160/352/1200
-f 1160/352/1200 1150/341/1190 1149/1119/1189
-f 760/760/800 770/770/810 1161/1129/1201
-f 1161/1129/1201 1151/1120/1191 760/760/800
-f 1151/1120/1191 1161/1129/1201 1162/1130/1202
-f 1162/1130/1202 1152/1121/1192 1151/1120/1191
-f 1152/1121/1192 1162/1130/1202 1163/1131/1203
-f 1163/1131/1203 1153/1122/1193 1152/1121/1192
-f 1153/1122/1193 1163/1131/1203 1164/1132/1204
-f 1164/1132/1204 1154/1123/1194 1153/1122/1193
-f 1154/1123/1194 1164/1132/1204 1165/1133/1205
-f 1165/1133/1205 1155/1124/1195 1154/1123/1194
-f 1155/1124/1195 1165/1133/1205 1166/1134/1206
-f 1166/1134/1206 1156/1125/1196 1155/1124/1195
-f 1156/1125/1196 1166/1134/1206 1167/1135/1207
-f 1167/1135/1207 1157/1126/1197 1156/1125/1196
-f 1157/1126/1197 1167/1135/1207 1168/1136/1208
-f 1168/1136/1208 1158/1127/1198 1157/1126/1197
-f 1158/1127/1198 1168/1136/1208 1169/1137/1209
-f 1169/1137/1209 1159/1128/1199 1158/1127/1198
-f 1159/1128/1199 1169/1137/1209 1170/363/1210
-f 1170/363/1210 1160/352/1200 1159/1128/1199
-f 770/770/810 780/780/820 1171/1138/1211
-f 1171/1138/1211 1161/1129/1201 770/770/810
-f 1161/1129/1201 1171/1138/1211 1172/1139/1212
-f 1172/1139/1212 1162/1130/1202 1161/1129/1201
-f 1162/1130/1202 1172/1139/1212 1173/1140/1213
-f 1173/1140/1213 1163/1131/1203 1162/1130/1202
-f 1163/1131/1203 1173/1140/1213 1174/1141/1214
-f 1174/1141/1214 1164/1132/1204 1163/1131/1203
-f 1164/1132/1204 1174/1141/1214 1175/1142/1215
-f 1175/1142/1215 1165/1133/1205 1164/1132/1204
-f 1165/1133/1205 1175/1142/1215 1176/1143/1216
-f 1176/1143/1216 1166/1134/1206 1165/1133/1205
-f 1166/1134/1206 1176/1143/1216 1177/1144/1217
-f 1177/1144/1217 1167/1135/1207 1166/1134/1206
-f 1167/1135/1207 1177/1144/1217 1178/1145/1218
-f 1178/1145/1218 1168/1136/1208 1167/1135/1207
-f 1168/1136/1208 1178/1145/1218 1179/1146/1219
-f 1179/1146/1219 1169/1137/1209 1168/1136/1208
-f 1169/1137/1209 1179/1146/1219 1180/374/1220
-f 1180/374/1220 1170/363/1210 1169/1137/1209
-f 780/780/820 790/790/830 1181/1147/1221
-f 1181/1147/1221 1171/1138/1211 780/780/820
-f 1171/1138/1211 1181/1147/1221 1182/1148/1222
-f 1182/1148/1222 1172/1139/1212 1171/1138/1211
-f 1172/1139/1212 1182/1148/1222 1183/1149/1223
-f 1183/1149/1223 1173/1140/1213 1172/1139/1212
-f 1173/1140/1213 1183/1149/1223 1184/1150/1224
-f 1184/1150/1224 1174/1141/1214 1173/1140/1213
-f 1174/1141/1214 1184/1150/1224 1185/1151/1225
-f 1185/1151/1225 1175/1142/1215 1174/1141/1214
-f 1175/1142/1215 1185/1151/1225 1186/1152/1226
-f 1186/1152/1226 1176/1143/1216 1175/1142/1215
-f 1176/1143/1216 1186/1152/1226 1187/1153/1227
-f 1187/1153/1227 1177/1144/1217 1176/1143/1216
-f 1177/1144/1217 1187/1153/1227 1188/1154/1228
-f 1188/1154/1228 1178/1145/1218 1177/1144/1217
-f 1178/1145/1218 1188/1154/1228 1189/1155/1229
-f 1189/1155/1229 1179/1146/1219 1178/1145/1218
-f 1179/1146/1219 1189/1155/1229 1190/385/1230
-f 1190/385/1230 1180/374/1220 1179/1146/1219
-f 790/790/830 800/800/840 1191/1156/1231
-f 1191/1156/1231 1181/1147/1221 790/790/830
-f 1181/1147/1221 1191/1156/1231 1192/1157/1232
-f 1192/1157/1232 1182/1148/1222 1181/1147/1221
-f 1182/1148/1222 1192/1157/1232 1193/1158/1233
-f 1193/1158/1233 1183/1149/1223 1182/1148/1222
-f 1183/1149/1223 1193/1158/1233 1194/1159/1234
-f 1194/1159/1234 1184/1150/1224 1183/1149/1223
-f 1184/1150/1224 1194/1159/1234 1195/1160/1235
-f 1195/1160/1235 1185/1151/1225 1184/1150/1224
-f 1185/1151/1225 1195/1160/1235 1196/1161/1236
-f 1196/1161/1236 1186/1152/1226 1185/1151/1225
-f 1186/1152/1226 1196/1161/1236 1197/1162/1237
-f 1197/1162/1237 1187/1153/1227 1186/1152/1226
-f 1187/1153/1227 1197/1162/1237 1198/1163/1238
-f 1198/1163/1238 1188/1154/1228 1187/1153/1227
-f 1188/1154/1228 1198/1163/1238 1199/1164/1239
-f 1199/1164/1239 1189/1155/1229 1188/1154/1228
-f 1189/1155/1229 1199/1164/1239 1200/396/1240
-f 1200/396/1240 1190/385/1230 1189/1155/1229
-f 800/800/840 810/810/850 1201/1165/1241
-f 1201/1165/1241 1191/1156/1231 800/800/840
-f 1191/1156/1231 1201/1165/1241 1202/1166/1242
-f 1202/1166/1242 1192/1157/1232 1191/1156/1231
-f 1192/1157/1232 1202/1166/1242 1203/1167/1243
-f 1203/1167/1243 1193/1158/1233 1192/1157/1232
-f 1193/1158/1233 1203/1167/1243 1204/1168/1244
-f 1204/1168/1244 1194/1159/1234 1193/1158/1233
-f 1194/1159/1234 1204/1168/1244 1205/1169/1245
-f 1205/1169/1245 1195/1160/1235 1194/1159/1234
-f 1195/1160/1235 1205/1169/1245 1206/1170/1246
-f 1206/1170/1246 1196/1161/1236 1195/1160/1235
-f 1196/1161/1236 1206/1170/1246 1207/1171/1247
-f 1207/1171/1247 1197/1162/1237 1196/1161/1236
-f 1197/1162/1237 1207/1171/1247 1208/1172/1248
-f 1208/1172/1248 1198/1163/1238 1197/1162/1237
-f 1198/1163/1238 1208/1172/1248 1209/1173/1249
-f 1209/1173/1249 1199/1164/1239 1198/1163/1238
-f 1199/1164/1239 1209/1173/1249 1210/407/1250
-f 1210/407/1250 1200/396/1240 1199/1164/1239
-f 810/810/850 820/820/860 1211/1174/1251
-f 1211/1174/1251 1201/1165/1241 810/810/850
-f 1201/1165/1241 1211/1174/1251 1212/1175/1252
-f 1212/1175/1252 1202/1166/1242 1201/1165/1241
-f 1202/1166/1242 1212/1175/1252 1213/1176/1253
-f 1213/1176/1253 1203/1167/1243 1202/1166/1242
-f 1203/1167/1243 1213/1176/1253 1214/1177/1254
-f 1214/1177/1254 1204/1168/1244 1203/1167/1243
-f 1204/1168/1244 1214/1177/1254 1215/1178/1255
-f 1215/1178/1255 1205/1169/1245 1204/1168/1244
-f 1205/1169/1245 1215/1178/1255 1216/1179/1256
-f 1216/1179/1256 1206/1170/1246 1205/1169/1245
-f 1206/1170/1246 1216/1179/1256 1217/1180/1257
-f 1217/1180/1257 1207/1171/1247 1206/1170/1246
-f 1207/1171/1247 1217/1180/1257 1218/1181/1258
-f 1218/1181/1258 1208/1172/1248 1207/1171/1247
-f 1208/1172/1248 1218/1181/1258 1219/1182/1259
-f 1219/1182/1259 1209/1173/1249 1208/1172/1248
-f 1209/1173/1249 1219/1182/1259 1220/418/1260
-f 1220/418/1260 1210/407/1250 1209/1173/1249
-f 820/820/860 830/830/870 1221/1183/1261
-f 1221/1183/1261 1211/1174/1251 820/820/860
-f 1211/1174/1251 1221/1183/1261 1222/1184/1262
-f 1222/1184/1262 1212/1175/1252 1211/1174/1251
-f 1212/1175/1252 1222/1184/1262 1223/1185/1263
-f 1223/1185/1263 1213/1176/1253 1212/1175/1252
-f 1213/1176/1253 1223/1185/1263 1224/1186/1264
-f 1224/1186/1264 1214/1177/1254 1213/1176/1253
-f 1214/1177/1254 1224/1186/1264 1225/1187/1265
-f 1225/1187/1265 1215/1178/1255 1214/1177/1254
-f 1215/1178/1255 1225/1187/1265 1226/1188/1266
-f 1226/1188/1266 1216/1179/1256 1215/1178/1255
-f 1216/1179/1256 1226/1188/1266 1227/1189/1267
-f 1227/1189/1267 1217/1180/1257 1216/1179/1256
-f 1217/1180/1257 1227/1189/1267 1228/1190/1268
-f 1228/1190/1268 1218/1181/1258 1217/1180/1257
-f 1218/1181/1258 1228/1190/1268 1229/1191/1269
-f 1229/1191/1269 1219/1182/1259 1218/1181/1258
-f 1219/1182/1259 1229/1191/1269 1230/429/1270
-f 1230/429/1270 1220/418/1260 1219/1182/1259
-f 830/830/870 840/840/880 1231/1192/1271
-f 1231/1192/1271 1221/1183/1261 830/830/870
-f 1221/1183/1261 1231/1192/1271 1232/1193/1272
-f 1232/1193/1272 1222/1184/1262 1221/1183/1261
-f 1222/1184/1262 1232/1193/1272 1233/1194/1273
-f 1233/1194/1273 1223/1185/1263 1222/1184/1262
-f 1223/1185/1263 1233/1194/1273 1234/1195/1274
-f 1234/1195/1274 1224/1186/1264 1223/1185/1263
-f 1224/1186/1264 1234/1195/1274 1235/1196/1275
-f 1235/1196/1275 1225/1187/1265 1224/1186/1264
-f 1225/1187/1265 1235/1196/1275 1236/1197/1276
-f 1236/1197/1276 1226/1188/1266 1225/1187/1265
-f 1226/1188/1266 1236/1197/1276 1237/1198/1277
-f 1237/1198/1277 1227/1189/1267 1226/1188/1266
-f 1227/1189/1267 1237/1198/1277 1238/1199/1278
-f 1238/1199/1278 1228/1190/1268 1227/1189/1267
-f 1228/1190/1268 1238/1199/1278 1239/1200/1279
-f 1239/1200/1279 1229/1191/1269 1228/1190/1268
-f 1229/1191/1269 1239/1200/1279 1240/440/1280
-f 1240/440/1280 1230/429/1270 1229/1191/1269
-f 840/840/880 460/460/500 842/842/882
-f 842/842/882 1231/1192/1271 840/840/880
-f 1231/1192/1271 842/842/882 844/844/884
-f 844/844/884 1232/1193/1272 1231/1192/1271
-f 1232/1193/1272 844/844/884 846/846/886
-f 846/846/886 1233/1194/1273 1232/1193/1272
-f 1233/1194/1273 846/846/886 848/848/888
-f 848/848/888 1234/1195/1274 1233/1194/1273
-f 1234/1195/1274 848/848/888 850/850/890
-f 850/850/890 1235/1196/1275 1234/1195/1274
-f 1235/1196/1275 850/850/890 852/852/892
-f 852/852/892 1236/1197/1276 1235/1196/1275
-f 1236/1197/1276 852/852/892 854/854/894
-f 854/854/894 1237/1198/1277 1236/1197/1276
-f 1237/1198/1277 854/854/894 856/856/896
-f 856/856/896 1238/1199/1278 1237/1198/1277
-f 1238/1199/1278 856/856/896 858/858/898
-f 858/858/898 1239/1200/1279 1238/1199/1278
-f 1239/1200/1279 858/858/898 860/22/900
-f 860/22/900 1240/440/1280 1239/1200/1279
-f 860/22/900 859/21/899 1241/1201/1281
-f 1241/1201/1281 1242/1202/1282 860/22/900
-f 1242/1202/1282 1241/1201/1281 1243/1203/1283
-f 1243/1203/1283 1244/1204/1284 1242/1202/1282
-f 1244/1204/1284 1243/1203/1283 1245/1205/1285
-f 1245/1205/1285 1246/1206/1286 1244/1204/1284
-f 1246/1206/1286 1245/1205/1285 1247/1207/1287
-f 1247/1207/1287 1248/1208/1288 1246/1206/1286
-f 1248/1208/1288 1247/1207/1287 1249/1209/1289
-f 1249/1209/1289 1250/1210/1290 1248/1208/1288
-f 1250/1210/1290 1249/1209/1289 1251/1211/1291
-f 1251/1211/1291 1252/1212/1292 1250/1210/1290
-f 1252/1212/1292 1251/1211/1291 1253/1213/1293
-f 1253/1213/1293 1254/1214/1294 1252/1212/1292
-f 1254/1214/1294 1253/1213/1293 1255/1215/1295
-f 1255/1215/1295 1256/1216/1296 1254/1214/1294
-f 1256/1216/1296 1255/1215/1295 1257/1217/1297
-f 1257/1217/1297 1258/1218/1298 1256/1216/1296
-f 1258/1218/1298 1257/1217/1297 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1258/1218/1300
-f 859/21/899 870/33/910 1260/1220/1301
-f 1260/1220/1301 1241/1201/1281 859/21/899
-f 1241/1201/1281 1260/1220/1301 1261/1221/1302
-f 1261/1221/1302 1243/1203/1283 1241/1201/1281
-f 1243/1203/1283 1261/1221/1302 1262/1222/1303
-f 1262/1222/1303 1245/1205/1285 1243/1203/1283
-f 1245/1205/1285 1262/1222/1303 1263/1223/1304
-f 1263/1223/1304 1247/1207/1287 1245/1205/1285
-f 1247/1207/1287 1263/1223/1304 1264/1224/1305
-f 1264/1224/1305 1249/1209/1289 1247/1207/1287
-f 1249/1209/1289 1264/1224/1305 1265/1225/1306
-f 1265/1225/1306 1251/1211/1291 1249/1209/1289
-f 1251/1211/1291 1265/1225/1306 1266/1226/1307
-f 1266/1226/1307 1253/1213/1293 1251/1211/1291
-f 1253/1213/1293 1266/1226/1307 1267/1227/1308
-f 1267/1227/1308 1255/1215/1295 1253/1213/1293
-f 1255/1215/1295 1267/1227/1308 1268/1228/1309
-f 1268/1228/1309 1257/1217/1297 1255/1215/1295
-f 1257/1217/1297 1268/1228/1309 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1257/1217/1300
-f 870/33/910 880/44/920 1269/1229/1310
-f 1269/1229/1310 1260/1220/1301 870/33/910
-f 1260/1220/1301 1269/1229/1310 1270/1230/1311
-f 1270/1230/1311 1261/1221/1302 1260/1220/1301
-f 1261/1221/1302 1270/1230/1311 1271/1231/1312
-f 1271/1231/1312 1262/1222/1303 1261/1221/1302
-f 1262/1222/1303 1271/1231/1312 1272/1232/1313
-f 1272/1232/1313 1263/1223/1304 1262/1222/1303
-f 1263/1223/1304 1272/1232/1313 1273/1233/1314
-f 1273/1233/1314 1264/1224/1305 1263/1223/1304
-f 1264/1224/1305 1273/1233/1314 1274/1234/1315
-f 1274/1234/1315 1265/1225/1306 1264/1224/1305
-f 1265/1225/1306 1274/1234/1315 1275/1235/1316
-f 1275/1235/1316 1266/1226/1307 1265/1225/1306
-f 1266/1226/1307 1275/1235/1316 1276/1236/1317
-f 1276/1236/1317 1267/1227/1308 1266/1226/1307
-f 1267/1227/1308 1276/1236/1317 1277/1237/1318
-f 1277/1237/1318 1268/1228/1309 1267/1227/1308
-f 1268/1228/1309 1277/1237/1318 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1268/1228/1300
-f 880/44/920 890/55/930 1278/1238/1319
-f 1278/1238/1319 1269/1229/1310 880/44/920
-f 1269/1229/1310 1278/1238/1319 1279/1239/1320
-f 1279/1239/1320 1270/1230/1311 1269/1229/1310
-f 1270/1230/1311 1279/1239/1320 1280/1240/1321
-f 1280/1240/1321 1271/1231/1312 1270/1230/1311
-f 1271/1231/1312 1280/1240/1321 1281/1241/1322
-f 1281/1241/1322 1272/1232/1313 1271/1231/1312
-f 1272/1232/1313 1281/1241/1322 1282/1242/1323
-f 1282/1242/1323 1273/1233/1314 1272/1232/1313
-f 1273/1233/1314 1282/1242/1323 1283/1243/1324
-f 1283/1243/1324 1274/1234/1315 1273/1233/1314
-f 1274/1234/1315 1283/1243/1324 1284/1244/1325
-f 1284/1244/1325 1275/1235/1316 1274/1234/1315
-f 1275/1235/1316 1284/1244/1325 1285/1245/1326
-f 1285/1245/1326 1276/1236/1317 1275/1235/1316
-f 1276/1236/1317 1285/1245/1326 1286/1246/1327
-f 1286/1246/1327 1277/1237/1318 1276/1236/1317
-f 1277/1237/1318 1286/1246/1327 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1277/1237/1300
-f 890/55/930 900/66/940 1287/1247/1328
-f 1287/1247/1328 1278/1238/1319 890/55/930
-f 1278/1238/1319 1287/1247/1328 1288/1248/1329
-f 1288/1248/1329 1279/1239/1320 1278/1238/1319
-f 1279/1239/1320 1288/1248/1329 1289/1249/1330
-f 1289/1249/1330 1280/1240/1321 1279/1239/1320
-f 1280/1240/1321 1289/1249/1330 1290/1250/1331
-f 1290/1250/1331 1281/1241/1322 1280/1240/1321
-f 1281/1241/1322 1290/1250/1331 1291/1251/1332
-f 1291/1251/1332 1282/1242/1323 1281/1241/1322
-f 1282/1242/1323 1291/1251/1332 1292/1252/1333
-f 1292/1252/1333 1283/1243/1324 1282/1242/1323
-f 1283/1243/1324 1292/1252/1333 1293/1253/1334
-f 1293/1253/1334 1284/1244/1325 1283/1243/1324
-f 1284/1244/1325 1293/1253/1334 1294/1254/1335
-f 1294/1254/1335 1285/1245/1326 1284/1244/1325
-f 1285/1245/1326 1294/1254/1335 1295/1255/1336
-f 1295/1255/1336 1286/1246/1327 1285/1245/1326
-f 1286/1246/1327 1295/1255/1336 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1286/1246/1300
-f 900/66/940 910/77/950 1296/1256/1337
-f 1296/1256/1337 1287/1247/1328 900/66/940
-f 1287/1247/1328 1296/1256/1337 1297/1257/1338
-f 1297/1257/1338 1288/1248/1329 1287/1247/1328
-f 1288/1248/1329 1297/1257/1338 1298/1258/1339
-f 1298/1258/1339 1289/1249/1330 1288/1248/1329
-f 1289/1249/1330 1298/1258/1339 1299/1259/1340
-f 1299/1259/1340 1290/1250/1331 1289/1249/1330
-f 1290/1250/1331 1299/1259/1340 1300/1260/1341
-f 1300/1260/1341 1291/1251/1332 1290/1250/1331
-f 1291/1251/1332 1300/1260/1341 1301/1261/1342
-f 1301/1261/1342 1292/1252/1333 1291/1251/1332
-f 1292/1252/1333 1301/1261/1342 1302/1262/1343
-f 1302/1262/1343 1293/1253/1334 1292/1252/1333
-f 1293/1253/1334 1302/1262/1343 1303/1263/1344
-f 1303/1263/1344 1294/1254/1335 1293/1253/1334
-f 1294/1254/1335 1303/1263/1344 1304/1264/1345
-f 1304/1264/1345 1295/1255/1336 1294/1254/1335
-f 1295/1255/1336 1304/1264/1345 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1295/1255/1300
-f 910/77/950 920/88/960 1305/1265/1346
-f 1305/1265/1346 1296/1256/1337 910/77/950
-f 1296/1256/1337 1305/1265/1346 1306/1266/1347
-f 1306/1266/1347 1297/1257/1338 1296/1256/1337
-f 1297/1257/1338 1306/1266/1347 1307/1267/1348
-f 1307/1267/1348 1298/1258/1339 1297/1257/1338
-f 1298/1258/1339 1307/1267/1348 1308/1268/1349
-f 1308/1268/1349 1299/1259/1340 1298/1258/1339
-f 1299/1259/1340 1308/1268/1349 1309/1269/1350
-f 1309/1269/1350 1300/1260/1341 1299/1259/1340
-f 1300/1260/1341 1309/1269/1350 1310/1270/1351
-f 1310/1270/1351 1301/1261/1342 1300/1260/1341
-f 1301/1261/1342 1310/1270/1351 1311/1271/1352
-f 1311/1271/1352 1302/1262/1343 1301/1261/1342
-f 1302/1262/1343 1311/1271/1352 1312/1272/1353
-f 1312/1272/1353 1303/1263/1344 1302/1262/1343
-f 1303/1263/1344 1312/1272/1353 1313/1273/1354
-f 1313/1273/1354 1304/1264/1345 1303/1263/1344
-f 1304/1264/1345 1313/1273/1354 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1304/1264/1300
-f 920/88/960 930/99/970 1314/1274/1355
-f 1314/1274/1355 1305/1265/1346 920/88/960
-f 1305/1265/1346 1314/1274/1355 1315/1275/1356
-f 1315/1275/1356 1306/1266/1347 1305/1265/1346
-f 1306/1266/1347 1315/1275/1356 1316/1276/1357
-f 1316/1276/1357 1307/1267/1348 1306/1266/1347
-f 1307/1267/1348 1316/1276/1357 1317/1277/1358
-f 1317/1277/1358 1308/1268/1349 1307/1267/1348
-f 1308/1268/1349 1317/1277/1358 1318/1278/1359
-f 1318/1278/1359 1309/1269/1350 1308/1268/1349
-f 1309/1269/1350 1318/1278/1359 1319/1279/1360
-f 1319/1279/1360 1310/1270/1351 1309/1269/1350
-f 1310/1270/1351 1319/1279/1360 1320/1280/1361
-f 1320/1280/1361 1311/1271/1352 1310/1270/1351
-f 1311/1271/1352 1320/1280/1361 1321/1281/1362
-f 1321/1281/1362 1312/1272/1353 1311/1271/1352
-f 1312/1272/1353 1321/1281/1362 1322/1282/1363
-f 1322/1282/1363 1313/1273/1354 1312/1272/1353
-f 1313/1273/1354 1322/1282/1363 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1313/1273/1300
-f 930/99/970 940/110/980 1323/1283/1364
-f 1323/1283/1364 1314/1274/1355 930/99/970
-f 1314/1274/1355 1323/1283/1364 1324/1284/1365
-f 1324/1284/1365 1315/1275/1356 1314/1274/1355
-f 1315/1275/1356 1324/1284/1365 1325/1285/1366
-f 1325/1285/1366 1316/1276/1357 1315/1275/1356
-f 1316/1276/1357 1325/1285/1366 1326/1286/1367
-f 1326/1286/1367 1317/1277/1358 1316/1276/1357
-f 1317/1277/1358 1326/1286/1367 1327/1287/1368
-f 1327/1287/1368 1318/1278/1359 1317/1277/1358
-f 1318/1278/1359 1327/1287/1368 1328/1288/1369
-f 1328/1288/1369 1319/1279/1360 1318/1278/1359
-f 1319/1279/1360 1328/1288/1369 1329/1289/1370
-f 1329/1289/1370 1320/1280/1361 1319/1279/1360
-f 1320/1280/1361 1329/1289/1370 1330/1290/1371
-f 1330/1290/1371 1321/1281/1362 1320/1280/1361
-f 1321/1281/1362 1330/1290/1371 1331/1291/1372
-f 1331/1291/1372 1322/1282/1363 1321/1281/1362
-f 1322/1282/1363 1331/1291/1372 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1322/1282/1300
-f 940/110/980 950/121/990 1332/1292/1373
-f 1332/1292/1373 1323/1283/1364 940/110/980
-f 1323/1283/1364 1332/1292/1373 1333/1293/1374
-f 1333/1293/1374 1324/1284/1365 1323/1283/1364
-f 1324/1284/1365 1333/1293/1374 1334/1294/1375
-f 1334/1294/1375 1325/1285/1366 1324/1284/1365
-f 1325/1285/1366 1334/1294/1375 1335/1295/1376
-f 1335/1295/1376 1326/1286/1367 1325/1285/1366
-f 1326/1286/1367 1335/1295/1376 1336/1296/1377
-f 1336/1296/1377 1327/1287/1368 1326/1286/1367
-f 1327/1287/1368 1336/1296/1377 1337/1297/1378
-f 1337/1297/1378 1328/1288/1369 1327/1287/1368
-f 1328/1288/1369 1337/1297/1378 1338/1298/1379
-f 1338/1298/1379 1329/1289/1370 1328/1288/1369
-f 1329/1289/1370 1338/1298/1379 1339/1299/1380
-f 1339/1299/1380 1330/1290/1371 1329/1289/1370
-f 1330/1290/1371 1339/1299/1380 1340/1300/1381
-f 1340/1300/1381 1331/1291/1372 1330/1290/1371
-f 1331/1291/1372 1340/1300/1381 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1331/1291/1300
-f 950/121/990 960/132/1000 1341/1301/1382
-f 1341/1301/1382 1332/1292/1373 950/121/990
-f 1332/1292/1373 1341/1301/1382 1342/1302/1383
-f 1342/1302/1383 1333/1293/1374 1332/1292/1373
-f 1333/1293/1374 1342/1302/1383 1343/1303/1384
-f 1343/1303/1384 1334/1294/1375 1333/1293/1374
-f 1334/1294/1375 1343/1303/1384 1344/1304/1385
-f 1344/1304/1385 1335/1295/1376 1334/1294/1375
-f 1335/1295/1376 1344/1304/1385 1345/1305/1386
-f 1345/1305/1386 1336/1296/1377 1335/1295/1376
-f 1336/1296/1377 1345/1305/1386 1346/1306/1387
-f 1346/1306/1387 1337/1297/1378 1336/1296/1377
-f 1337/1297/1378 1346/1306/1387 1347/1307/1388
-f 1347/1307/1388 1338/1298/1379 1337/1297/1378
-f 1338/1298/1379 1347/1307/1388 1348/1308/1389
-f 1348/1308/1389 1339/1299/1380 1338/1298/1379
-f 1339/1299/1380 1348/1308/1389 1349/1309/1390
-f 1349/1309/1390 1340/1300/1381 1339/1299/1380
-f 1340/1300/1381 1349/1309/1390 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1340/1300/1300
-f 960/132/1000 970/143/1010 1350/1310/1391
-f 1350/1310/1391 1341/1301/1382 960/132/1000
-f 1341/1301/1382 1350/1310/1391 1351/1311/1392
-f 1351/1311/1392 1342/1302/1383 1341/1301/1382
-f 1342/1302/1383 1351/1311/1392 1352/1312/1393
-f 1352/1312/1393 1343/1303/1384 1342/1302/1383
-f 1343/1303/1384 1352/1312/1393 1353/1313/1394
-f 1353/1313/1394 1344/1304/1385 1343/1303/1384
-f 1344/1304/1385 1353/1313/1394 1354/1314/1395
-f 1354/1314/1395 1345/1305/1386 1344/1304/1385
-f 1345/1305/1386 1354/1314/1395 1355/1315/1396
-f 1355/1315/1396 1346/1306/1387 1345/1305/1386
-f 1346/1306/1387 1355/1315/1396 1356/1316/1397
-f 1356/1316/1397 1347/1307/1388 1346/1306/1387
-f 1347/1307/1388 1356/1316/1397 1357/1317/1398
-f 1357/1317/1398 1348/1308/1389 1347/1307/1388
-f 1348/1308/1389 1357/1317/1398 1358/1318/1399
-f 1358/1318/1399 1349/1309/1390 1348/1308/1389
-f 1349/1309/1390 1358/1318/1399 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1349/1309/1300
-f 970/143/1010 980/154/1020 1359/1319/1400
-f 1359/1319/1400 1350/1310/1391 970/143/1010
-f 1350/1310/1391 1359/1319/1400 1360/1320/1401
-f 1360/1320/1401 1351/1311/1392 1350/1310/1391
-f 1351/1311/1392 1360/1320/1401 1361/1321/1402
-f 1361/1321/1402 1352/1312/1393 1351/1311/1392
-f 1352/1312/1393 1361/1321/1402 1362/1322/1403
-f 1362/1322/1403 1353/1313/1394 1352/1312/1393
-f 1353/1313/1394 1362/1322/1403 1363/1323/1404
-f 1363/1323/1404 1354/1314/1395 1353/1313/1394
-f 1354/1314/1395 1363/1323/1404 1364/1324/1405
-f 1364/1324/1405 1355/1315/1396 1354/1314/1395
-f 1355/1315/1396 1364/1324/1405 1365/1325/1406
-f 1365/1325/1406 1356/1316/1397 1355/1315/1396
-f 1356/1316/1397 1365/1325/1406 1366/1326/1407
-f 1366/1326/1407 1357/1317/1398 1356/1316/1397
-f 1357/1317/1398 1366/1326/1407 1367/1327/1408
-f 1367/1327/1408 1358/1318/1399 1357/1317/1398
-f 1358/1318/1399 1367/1327/1408 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1358/1318/1300
-f 980/154/1020 990/165/1030 1368/1328/1409
-f 1368/1328/1409 1359/1319/1400 980/154/1020
-f 1359/1319/1400 1368/1328/1409 1369/1329/1410
-f 1369/1329/1410 1360/1320/1401 1359/1319/1400
-f 1360/1320/1401 1369/1329/1410 1370/1330/1411
-f 1370/1330/1411 1361/1321/1402 1360/1320/1401
-f 1361/1321/1402 1370/1330/1411 1371/1331/1412
-f 1371/1331/1412 1362/1322/1403 1361/1321/1402
-f 1362/1322/1403 1371/1331/1412 1372/1332/1413
-f 1372/1332/1413 1363/1323/1404 1362/1322/1403
-f 1363/1323/1404 1372/1332/1413 1373/1333/1414
-f 1373/1333/1414 1364/1324/1405 1363/1323/1404
-f 1364/1324/1405 1373/1333/1414 1374/1334/1415
-f 1374/1334/1415 1365/1325/1406 1364/1324/1405
-f 1365/1325/1406 1374/1334/1415 1375/1335/1416
-f 1375/1335/1416 1366/1326/1407 1365/1325/1406
-f 1366/1326/1407 1375/1335/1416 1376/1336/1417
-f 1376/1336/1417 1367/1327/1408 1366/1326/1407
-f 1367/1327/1408 1376/1336/1417 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1367/1327/1300
-f 990/165/1030 1000/176/1040 1377/1337/1418
-f 1377/1337/1418 1368/1328/1409 990/165/1030
-f 1368/1328/1409 1377/1337/1418 1378/1338/1419
-f 1378/1338/1419 1369/1329/1410 1368/1328/1409
-f 1369/1329/1410 1378/1338/1419 1379/1339/1420
-f 1379/1339/1420 1370/1330/1411 1369/1329/1410
-f 1370/1330/1411 1379/1339/1420 1380/1340/1421
-f 1380/1340/1421 1371/1331/1412 1370/1330/1411
-f 1371/1331/1412 1380/1340/1421 1381/1341/1422
-f 1381/1341/1422 1372/1332/1413 1371/1331/1412
-f 1372/1332/1413 1381/1341/1422 1382/1342/1423
-f 1382/1342/1423 1373/1333/1414 1372/1332/1413
-f 1373/1333/1414 1382/1342/1423 1383/1343/1424
-f 1383/1343/1424 1374/1334/1415 1373/1333/1414
-f 1374/1334/1415 1383/1343/1424 1384/1344/1425
-f 1384/1344/1425 1375/1335/1416 1374/1334/1415
-f 1375/1335/1416 1384/1344/1425 1385/1345/1426
-f 1385/1345/1426 1376/1336/1417 1375/1335/1416
-f 1376/1336/1417 1385/1345/1426 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1376/1336/1300
-f 1000/176/1040 1010/187/1050 1386/1346/1427
-f 1386/1346/1427 1377/1337/1418 1000/176/1040
-f 1377/1337/1418 1386/1346/1427 1387/1347/1428
-f 1387/1347/1428 1378/1338/1419 1377/1337/1418
-f 1378/1338/1419 1387/1347/1428 1388/1348/1429
-f 1388/1348/1429 1379/1339/1420 1378/1338/1419
-f 1379/1339/1420 1388/1348/1429 1389/1349/1430
-f 1389/1349/1430 1380/1340/1421 1379/1339/1420
-f 1380/1340/1421 1389/1349/1430 1390/1350/1431
-f 1390/1350/1431 1381/1341/1422 1380/1340/1421
-f 1381/1341/1422 1390/1350/1431 1391/1351/1432
-f 1391/1351/1432 1382/1342/1423 1381/1341/1422
-f 1382/1342/1423 1391/1351/1432 1392/1352/1433
-f 1392/1352/1433 1383/1343/1424 1382/1342/1423
-f 1383/1343/1424 1392/1352/1433 1393/1353/1434
-f 1393/1353/1434 1384/1344/1425 1383/1343/1424
-f 1384/1344/1425 1393/1353/1434 1394/1354/1435
-f 1394/1354/1435 1385/1345/1426 1384/1344/1425
-f 1385/1345/1426 1394/1354/1435 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1385/1345/1300
-f 1010/187/1050 1020/198/1060 1395/1355/1436
-f 1395/1355/1436 1386/1346/1427 1010/187/1050
-f 1386/1346/1427 1395/1355/1436 1396/1356/1437
-f 1396/1356/1437 1387/1347/1428 1386/1346/1427
-f 1387/1347/1428 1396/1356/1437 1397/1357/1438
-f 1397/1357/1438 1388/1348/1429 1387/1347/1428
-f 1388/1348/1429 1397/1357/1438 1398/1358/1439
-f 1398/1358/1439 1389/1349/1430 1388/1348/1429
-f 1389/1349/1430 1398/1358/1439 1399/1359/1440
-f 1399/1359/1440 1390/1350/1431 1389/1349/1430
-f 1390/1350/1431 1399/1359/1440 1400/1360/1441
-f 1400/1360/1441 1391/1351/1432 1390/1350/1431
-f 1391/1351/1432 1400/1360/1441 1401/1361/1442
-f 1401/1361/1442 1392/1352/1433 1391/1351/1432
-f 1392/1352/1433 1401/1361/1442 1402/1362/1443
-f 1402/1362/1443 1393/1353/1434 1392/1352/1433
-f 1393/1353/1434 1402/1362/1443 1403/1363/1444
-f 1403/1363/1444 1394/1354/1435 1393/1353/1434
-f 1394/1354/1435 1403/1363/1444 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1394/1354/1300
-f 1020/198/1060 1030/209/1070 1404/1364/1445
-f 1404/1364/1445 1395/1355/1436 1020/198/1060
-f 1395/1355/1436 1404/1364/1445 1405/1365/1446
-f 1405/1365/1446 1396/1356/1437 1395/1355/1436
-f 1396/1356/1437 1405/1365/1446 1406/1366/1447
-f 1406/1366/1447 1397/1357/1438 1396/1356/1437
-f 1397/1357/1438 1406/1366/1447 1407/1367/1448
-f 1407/1367/1448 1398/1358/1439 1397/1357/1438
-f 1398/1358/1439 1407/1367/1448 1408/1368/1449
-f 1408/1368/1449 1399/1359/1440 1398/1358/1439
-f 1399/1359/1440 1408/1368/1449 1409/1369/1450
-f 1409/1369/1450 1400/1360/1441 1399/1359/1440
-f 1400/1360/1441 1409/1369/1450 1410/1370/1451
-f 1410/1370/1451 1401/1361/1442 1400/1360/1441
-f 1401/1361/1442 1410/1370/1451 1411/1371/1452
-f 1411/1371/1452 1402/1362/1443 1401/1361/1442
-f 1402/1362/1443 1411/1371/1452 1412/1372/1453
-f 1412/1372/1453 1403/1363/1444 1402/1362/1443
-f 1403/1363/1444 1412/1372/1453 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1403/1363/1300
-f 1030/209/1070 1040/220/1080 1413/1373/1454
-f 1413/1373/1454 1404/1364/1445 1030/209/1070
-f 1404/1364/1445 1413/1373/1454 1414/1374/1455
-f 1414/1374/1455 1405/1365/1446 1404/1364/1445
-f 1405/1365/1446 1414/1374/1455 1415/1375/1456
-f 1415/1375/1456 1406/1366/1447 1405/1365/1446
-f 1406/1366/1447 1415/1375/1456 1416/1376/1457
-f 1416/1376/1457 1407/1367/1448 1406/1366/1447
-f 1407/1367/1448 1416/1376/1457 1417/1377/1458
-f 1417/1377/1458 1408/1368/1449 1407/1367/1448
-f 1408/1368/1449 1417/1377/1458 1418/1378/1459
-f 1418/1378/1459 1409/1369/1450 1408/1368/1449
-f 1409/1369/1450 1418/1378/1459 1419/1379/1460
-f 1419/1379/1460 1410/1370/1451 1409/1369/1450
-f 1410/1370/1451 1419/1379/1460 1420/1380/1461
-f 1420/1380/1461 1411/1371/1452 1410/1370/1451
-f 1411/1371/1452 1420/1380/1461 1421/1381/1462
-f 1421/1381/1462 1412/1372/1453 1411/1371/1452
-f 1412/1372/1453 1421/1381/1462 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1412/1372/1300
-f 1040/220/1080 1050/231/1090 1422/1382/1463
-f 1422/1382/1463 1413/1373/1454 1040/220/1080
-f 1413/1373/1454 1422/1382/1463 1423/1383/1464
-f 1423/1383/1464 1414/1374/1455 1413/1373/1454
-f 1414/1374/1455 1423/1383/1464 1424/1384/1465
-f 1424/1384/1465 1415/1375/1456 1414/1374/1455
-f 1415/1375/1456 1424/1384/1465 1425/1385/1466
-f 1425/1385/1466 1416/1376/1457 1415/1375/1456
-f 1416/1376/1457 1425/1385/1466 1426/1386/1467
-f 1426/1386/1467 1417/1377/1458 1416/1376/1457
-f 1417/1377/1458 1426/1386/1467 1427/1387/1468
-f 1427/1387/1468 1418/1378/1459 1417/1377/1458
-f 1418/1378/1459 1427/1387/1468 1428/1388/1469
-f 1428/1388/1469 1419/1379/1460 1418/1378/1459
-f 1419/1379/1460 1428/1388/1469 1429/1389/1470
-f 1429/1389/1470 1420/1380/1461 1419/1379/1460
-f 1420/1380/1461 1429/1389/1470 1430/1390/1471
-f 1430/1390/1471 1421/1381/1462 1420/1380/1461
-f 1421/1381/1462 1430/1390/1471 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1421/1381/1300
-f 1050/231/1090 1060/242/1100 1431/1391/1472
-f 1431/1391/1472 1422/1382/1463 1050/231/1090
-f 1422/1382/1463 1431/1391/1472 1432/1392/1473
-f 1432/1392/1473 1423/1383/1464 1422/1382/1463
-f 1423/1383/1464 1432/1392/1473 1433/1393/1474
-f 1433/1393/1474 1424/1384/1465 1423/1383/1464
-f 1424/1384/1465 1433/1393/1474 1434/1394/1475
-f 1434/1394/1475 1425/1385/1466 1424/1384/1465
-f 1425/1385/1466 1434/1394/1475 1435/1395/1476
-f 1435/1395/1476 1426/1386/1467 1425/1385/1466
-f 1426/1386/1467 1435/1395/1476 1436/1396/1477
-f 1436/1396/1477 1427/1387/1468 1426/1386/1467
-f 1427/1387/1468 1436/1396/1477 1437/1397/1478
-f 1437/1397/1478 1428/1388/1469 1427/1387/1468
-f 1428/1388/1469 1437/1397/1478 1438/1398/1479
-f 1438/1398/1479 1429/1389/1470 1428/1388/1469
-f 1429/1389/1470 1438/1398/1479 1439/1399/1480
-f 1439/1399/1480 1430/1390/1471 1429/1389/1470
-f 1430/1390/1471 1439/1399/1480 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1430/1390/1300
-f 1060/242/1100 1070/253/1110 1440/1400/1481
-f 1440/1400/1481 1431/1391/1472 1060/242/1100
-f 1431/1391/1472 1440/1400/1481 1441/1401/1482
-f 1441/1401/1482 1432/1392/1473 1431/1391/1472
-f 1432/1392/1473 1441/1401/1482 1442/1402/1483
-f 1442/1402/1483 1433/1393/1474 1432/1392/1473
-f 1433/1393/1474 1442/1402/1483 1443/1403/1484
-f 1443/1403/1484 1434/1394/1475 1433/1393/1474
-f 1434/1394/1475 1443/1403/1484 1444/1404/1485
-f 1444/1404/1485 1435/1395/1476 1434/1394/1475
-f 1435/1395/1476 1444/1404/1485 1445/1405/1486
-f 1445/1405/1486 1436/1396/1477 1435/1395/1476
-f 1436/1396/1477 1445/1405/1486 1446/1406/1487
-f 1446/1406/1487 1437/1397/1478 1436/1396/1477
-f 1437/1397/1478 1446/1406/1487 1447/1407/1488
-f 1447/1407/1488 1438/1398/1479 1437/1397/1478
-f 1438/1398/1479 1447/1407/1488 1448/1408/1489
-f 1448/1408/1489 1439/1399/1480 1438/1398/1479
-f 1439/1399/1480 1448/1408/1489 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1439/1399/1300
-f 1070/253/1110 1080/264/1120 1449/1409/1490
-f 1449/1409/1490 1440/1400/1481 1070/253/1110
-f 1440/1400/1481 1449/1409/1490 1450/1410/1491
-f 1450/1410/1491 1441/1401/1482 1440/1400/1481
-f 1441/1401/1482 1450/1410/1491 1451/1411/1492
-f 1451/1411/1492 1442/1402/1483 1441/1401/1482
-f 1442/1402/1483 1451/1411/1492 1452/1412/1493
-f 1452/1412/1493 1443/1403/1484 1442/1402/1483
-f 1443/1403/1484 1452/1412/1493 1453/1413/1494
-f 1453/1413/1494 1444/1404/1485 1443/1403/1484
-f 1444/1404/1485 1453/1413/1494 1454/1414/1495
-f 1454/1414/1495 1445/1405/1486 1444/1404/1485
-f 1445/1405/1486 1454/1414/1495 1455/1415/1496
-f 1455/1415/1496 1446/1406/1487 1445/1405/1486
-f 1446/1406/1487 1455/1415/1496 1456/1416/1497
-f 1456/1416/1497 1447/1407/1488 1446/1406/1487
-f 1447/1407/1488 1456/1416/1497 1457/1417/1498
-f 1457/1417/1498 1448/1408/1489 1447/1407/1488
-f 1448/1408/1489 1457/1417/1498 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1448/1408/1300
-f 1080/264/1120 1090/275/1130 1458/1418/1499
-f 1458/1418/1499 1449/1409/1490 1080/264/1120
-f 1449/1409/1490 1458/1418/1499 1459/1419/1500
-f 1459/1419/1500 1450/1410/1491 1449/1409/1490
-f 1450/1410/1491 1459/1419/1500 1460/1420/1501
-f 1460/1420/1501 1451/1411/1492 1450/1410/1491
-f 1451/1411/1492 1460/1420/1501 1461/1421/1502
-f 1461/1421/1502 1452/1412/1493 1451/1411/1492
-f 1452/1412/1493 1461/1421/1502 1462/1422/1503
-f 1462/1422/1503 1453/1413/1494 1452/1412/1493
-f 1453/1413/1494 1462/1422/1503 1463/1423/1504
-f 1463/1423/1504 1454/1414/1495 1453/1413/1494
-f 1454/1414/1495 1463/1423/1504 1464/1424/1505
-f 1464/1424/1505 1455/1415/1496 1454/1414/1495
-f 1455/1415/1496 1464/1424/1505 1465/1425/1506
-f 1465/1425/1506 1456/1416/1497 1455/1415/1496
-f 1456/1416/1497 1465/1425/1506 1466/1426/1507
-f 1466/1426/1507 1457/1417/1498 1456/1416/1497
-f 1457/1417/1498 1466/1426/1507 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1457/1417/1300
-f 1090/275/1130 1100/286/1140 1467/1427/1508
-f 1467/1427/1508 1458/1418/1499 1090/275/1130
-f 1458/1418/1499 1467/1427/1508 1468/1428/1509
-f 1468/1428/1509 1459/1419/1500 1458/1418/1499
-f 1459/1419/1500 1468/1428/1509 1469/1429/1510
-f 1469/1429/1510 1460/1420/1501 1459/1419/1500
-f 1460/1420/1501 1469/1429/1510 1470/1430/1511
-f 1470/1430/1511 1461/1421/1502 1460/1420/1501
-f 1461/1421/1502 1470/1430/1511 1471/1431/1512
-f 1471/1431/1512 1462/1422/1503 1461/1421/1502
-f 1462/1422/1503 1471/1431/1512 1472/1432/1513
-f 1472/1432/1513 1463/1423/1504 1462/1422/1503
-f 1463/1423/1504 1472/1432/1513 1473/1433/1514
-f 1473/1433/1514 1464/1424/1505 1463/1423/1504
-f 1464/1424/1505 1473/1433/1514 1474/1434/1515
-f 1474/1434/1515 1465/1425/1506 1464/1424/1505
-f 1465/1425/1506 1474/1434/1515 1475/1435/1516
-f 1475/1435/1516 1466/1426/1507 1465/1425/1506
-f 1466/1426/1507 1475/1435/1516 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1466/1426/1300
-f 1100/286/1140 1110/297/1150 1476/1436/1517
-f 1476/1436/1517 1467/1427/1508 1100/286/1140
-f 1467/1427/1508 1476/1436/1517 1477/1437/1518
-f 1477/1437/1518 1468/1428/1509 1467/1427/1508
-f 1468/1428/1509 1477/1437/1518 1478/1438/1519
-f 1478/1438/1519 1469/1429/1510 1468/1428/1509
-f 1469/1429/1510 1478/1438/1519 1479/1439/1520
-f 1479/1439/1520 1470/1430/1511 1469/1429/1510
-f 1470/1430/1511 1479/1439/1520 1480/1440/1521
-f 1480/1440/1521 1471/1431/1512 1470/1430/1511
-f 1471/1431/1512 1480/1440/1521 1481/1441/1522
-f 1481/1441/1522 1472/1432/1513 1471/1431/1512
-f 1472/1432/1513 1481/1441/1522 1482/1442/1523
-f 1482/1442/1523 1473/1433/1514 1472/1432/1513
-f 1473/1433/1514 1482/1442/1523 1483/1443/1524
-f 1483/1443/1524 1474/1434/1515 1473/1433/1514
-f 1474/1434/1515 1483/1443/1524 1484/1444/1525
-f 1484/1444/1525 1475/1435/1516 1474/1434/1515
-f 1475/1435/1516 1484/1444/1525 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1475/1435/1300
-f 1110/297/1150 1120/308/1160 1485/1445/1526
-f 1485/1445/1526 1476/1436/1517 1110/297/1150
-f 1476/1436/1517 1485/1445/1526 1486/1446/1527
-f 1486/1446/1527 1477/1437/1518 1476/1436/1517
-f 1477/1437/1518 1486/1446/1527 1487/1447/1528
-f 1487/1447/1528 1478/1438/1519 1477/1437/1518
-f 1478/1438/1519 1487/1447/1528 1488/1448/1529
-f 1488/1448/1529 1479/1439/1520 1478/1438/1519
-f 1479/1439/1520 1488/1448/1529 1489/1449/1530
-f 1489/1449/1530 1480/1440/1521 1479/1439/1520
-f 1480/1440/1521 1489/1449/1530 1490/1450/1531
-f 1490/1450/1531 1481/1441/1522 1480/1440/1521
-f 1481/1441/1522 1490/1450/1531 1491/1451/1532
-f 1491/1451/1532 1482/1442/1523 1481/1441/1522
-f 1482/1442/1523 1491/1451/1532 1492/1452/1533
-f 1492/1452/1533 1483/1443/1524 1482/1442/1523
-f 1483/1443/1524 1492/1452/1533 1493/1453/1534
-f 1493/1453/1534 1484/1444/1525 1483/1443/1524
-f 1484/1444/1525 1493/1453/1534 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1484/1444/1300
-f 1120/308/1160 1130/319/1170 1494/1454/1535
-f 1494/1454/1535 1485/1445/1526 1120/308/1160
-f 1485/1445/1526 1494/1454/1535 1495/1455/1536
-f 1495/1455/1536 1486/1446/1527 1485/1445/1526
-f 1486/1446/1527 1495/1455/1536 1496/1456/1537
-f 1496/1456/1537 1487/1447/1528 1486/1446/1527
-f 1487/1447/1528 1496/1456/1537 1497/1457/1538
-f 1497/1457/1538 1488/1448/1529 1487/1447/1528
-f 1488/1448/1529 1497/1457/1538 1498/1458/1539
-f 1498/1458/1539 1489/1449/1530 1488/1448/1529
-f 1489/1449/1530 1498/1458/1539 1499/1459/1540
-f 1499/1459/1540 1490/1450/1531 1489/1449/1530
-f 1490/1450/1531 1499/1459/1540 1500/1460/1541
-f 1500/1460/1541 1491/1451/1532 1490/1450/1531
-f 1491/1451/1532 1500/1460/1541 1501/1461/1542
-f 1501/1461/1542 1492/1452/1533 1491/1451/1532
-f 1492/1452/1533 1501/1461/1542 1502/1462/1543
-f 1502/1462/1543 1493/1453/1534 1492/1452/1533
-f 1493/1453/1534 1502/1462/1543 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1493/1453/1300
-f 1130/319/1170 1140/330/1180 1503/1463/1544
-f 1503/1463/1544 1494/1454/1535 1130/319/1170
-f 1494/1454/1535 1503/1463/1544 1504/1464/1545
-f 1504/1464/1545 1495/1455/1536 1494/1454/1535
-f 1495/1455/1536 1504/1464/1545 1505/1465/1546
-f 1505/1465/1546 1496/1456/1537 1495/1455/1536
-f 1496/1456/1537 1505/1465/1546 1506/1466/1547
-f 1506/1466/1547 1497/1457/1538 1496/1456/1537
-f 1497/1457/1538 1506/1466/1547 1507/1467/1548
-f 1507/1467/1548 1498/1458/1539 1497/1457/1538
-f 1498/1458/1539 1507/1467/1548 1508/1468/1549
-f 1508/1468/1549 1499/1459/1540 1498/1458/1539
-f 1499/1459/1540 1508/1468/1549 1509/1469/1550
-f 1509/1469/1550 1500/1460/1541 1499/1459/1540
-f 1500/1460/1541 1509/1469/1550 1510/1470/1551
-f 1510/1470/1551 1501/1461/1542 1500/1460/1541
-f 1501/1461/1542 1510/1470/1551 1511/1471/1552
-f 1511/1471/1552 1502/1462/1543 1501/1461/1542
-f 1502/1462/1543 1511/1471/1552 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1502/1462/1300
-f 1140/330/1180 1150/341/1190 1512/1472/1553
-f 1512/1472/1553 1503/1463/1544 1140/330/1180
-f 1503/1463/1544 1512/1472/1553 1513/1473/1554
-f 1513/1473/1554 1504/1464/1545 1503/1463/1544
-f 1504/1464/1545 1513/1473/1554 1514/1474/1555
-f 1514/1474/1555 1505/1465/1546 1504/1464/1545
-f 1505/1465/1546 1514/1474/1555 1515/1475/1556
-f 1515/1475/1556 1506/1466/1547 1505/1465/1546
-f 1506/1466/1547 1515/1475/1556 1516/1476/1557
-f 1516/1476/1557 1507/1467/1548 1506/1466/1547
-f 1507/1467/1548 1516/1476/1557 1517/1477/1558
-f 1517/1477/1558 1508/1468/1549 1507/1467/1548
-f 1508/1468/1549 1517/1477/1558 1518/1478/1559
-f 1518/1478/1559 1509/1469/1550 1508/1468/1549
-f 1509/1469/1550 1518/1478/1559 1519/1479/1560
-f 1519/1479/1560 1510/1470/1551 1509/1469/1550
-f 1510/1470/1551 1519/1479/1560 1520/1480/1561
-f 1520/1480/1561 1511/1471/1552 1510/1470/1551
-f 1511/1471/1552 1520/1480/1561 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1511/1471/1300
-f 1150/341/1190 1160/352/1200 1521/1481/1562
-f 1521/1481/1562 1512/1472/1553 1150/341/1190
-f 1512/1472/1553 1521/1481/1562 1522/1482/1563
-f 1522/1482/1563 1513/1473/1554 1512/1472/1553
-f 1513/1473/1554 1522/1482/1563 1523/1483/1564
-f 1523/1483/1564 1514/1474/1555 1513/1473/1554
-f 1514/1474/1555 1523/1483/1564 1524/1484/1565
-f 1524/1484/1565 1515/1475/1556 1514/1474/1555
-f 1515/1475/1556 1524/1484/1565 1525/1485/1566
-f 1525/1485/1566 1516/1476/1557 1515/1475/1556
-f 1516/1476/1557 1525/1485/1566 1526/1486/1567
-f 1526/1486/1567 1517/1477/1558 1516/1476/1557
-f 1517/1477/1558 1526/1486/1567 1527/1487/1568
-f 1527/1487/1568 1518/1478/1559 1517/1477/1558
-f 1518/1478/1559 1527/1487/1568 1528/1488/1569
-f 1528/1488/1569 1519/1479/1560 1518/1478/1559
-f 1519/1479/1560 1528/1488/1569 1529/1489/1570
-f 1529/1489/1570 1520/1480/1561 1519/1479/1560
-f 1520/1480/1561 1529/1489/1570 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1520/1480/1300
-f 1160/352/1200 1170/363/1210 1530/1490/1571
-f 1530/1490/1571 1521/1481/1562 1160/352/1200
-f 1521/1481/1562 1530/1490/1571 1531/1491/1572
-f 1531/1491/1572 1522/1482/1563 1521/1481/1562
-f 1522/1482/1563 1531/1491/1572 1532/1492/1573
-f 1532/1492/1573 1523/1483/1564 1522/1482/1563
-f 1523/1483/1564 1532/1492/1573 1533/1493/1574
-f 1533/1493/1574 1524/1484/1565 1523/1483/1564
-f 1524/1484/1565 1533/1493/1574 1534/1494/1575
-f 1534/1494/1575 1525/1485/1566 1524/1484/1565
-f 1525/1485/1566 1534/1494/1575 1535/1495/1576
-f 1535/1495/1576 1526/1486/1567 1525/1485/1566
-f 1526/1486/1567 1535/1495/1576 1536/1496/1577
-f 1536/1496/1577 1527/1487/1568 1526/1486/1567
-f 1527/1487/1568 1536/1496/1577 1537/1497/1578
-f 1537/1497/1578 1528/1488/1569 1527/1487/1568
-f 1528/1488/1569 1537/1497/1578 1538/1498/1579
-f 1538/1498/1579 1529/1489/1570 1528/1488/1569
-f 1529/1489/1570 1538/1498/1579 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1529/1489/1300
-f 1170/363/1210 1180/374/1220 1539/1499/1580
-f 1539/1499/1580 1530/1490/1571 1170/363/1210
-f 1530/1490/1571 1539/1499/1580 1540/1500/1581
-f 1540/1500/1581 1531/1491/1572 1530/1490/1571
-f 1531/1491/1572 1540/1500/1581 1541/1501/1582
-f 1541/1501/1582 1532/1492/1573 1531/1491/1572
-f 1532/1492/1573 1541/1501/1582 1542/1502/1583
-f 1542/1502/1583 1533/1493/1574 1532/1492/1573
-f 1533/1493/1574 1542/1502/1583 1543/1503/1584
-f 1543/1503/1584 1534/1494/1575 1533/1493/1574
-f 1534/1494/1575 1543/1503/1584 1544/1504/1585
-f 1544/1504/1585 1535/1495/1576 1534/1494/1575
-f 1535/1495/1576 1544/1504/1585 1545/1505/1586
-f 1545/1505/1586 1536/1496/1577 1535/1495/1576
-f 1536/1496/1577 1545/1505/1586 1546/1506/1587
-f 1546/1506/1587 1537/1497/1578 1536/1496/1577
-f 1537/1497/1578 1546/1506/1587 1547/1507/1588
-f 1547/1507/1588 1538/1498/1579 1537/1497/1578
-f 1538/1498/1579 1547/1507/1588 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1538/1498/1300
-f 1180/374/1220 1190/385/1230 1548/1508/1589
-f 1548/1508/1589 1539/1499/1580 1180/374/1220
-f 1539/1499/1580 1548/1508/1589 1549/1509/1590
-f 1549/1509/1590 1540/1500/1581 1539/1499/1580
-f 1540/1500/1581 1549/1509/1590 1550/1510/1591
-f 1550/1510/1591 1541/1501/1582 1540/1500/1581
-f 1541/1501/1582 1550/1510/1591 1551/1511/1592
-f 1551/1511/1592 1542/1502/1583 1541/1501/1582
-f 1542/1502/1583 1551/1511/1592 1552/1512/1593
-f 1552/1512/1593 1543/1503/1584 1542/1502/1583
-f 1543/1503/1584 1552/1512/1593 1553/1513/1594
-f 1553/1513/1594 1544/1504/1585 1543/1503/1584
-f 1544/1504/1585 1553/1513/1594 1554/1514/1595
-f 1554/1514/1595 1545/1505/1586 1544/1504/1585
-f 1545/1505/1586 1554/1514/1595 1555/1515/1596
-f 1555/1515/1596 1546/1506/1587 1545/1505/1586
-f 1546/1506/1587 1555/1515/1596 1556/1516/1597
-f 1556/1516/1597 1547/1507/1588 1546/1506/1587
-f 1547/1507/1588 1556/1516/1597 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1547/1507/1300
-f 1190/385/1230 1200/396/1240 1557/1517/1598
-f 1557/1517/1598 1548/1508/1589 1190/385/1230
-f 1548/1508/1589 1557/1517/1598 1558/1518/1599
-f 1558/1518/1599 1549/1509/1590 1548/1508/1589
-f 1549/1509/1590 1558/1518/1599 1559/1519/1600
-f 1559/1519/1600 1550/1510/1591 1549/1509/1590
-f 1550/1510/1591 1559/1519/1600 1560/1520/1601
-f 1560/1520/1601 1551/1511/1592 1550/1510/1591
-f 1551/1511/1592 1560/1520/1601 1561/1521/1602
-f 1561/1521/1602 1552/1512/1593 1551/1511/1592
-f 1552/1512/1593 1561/1521/1602 1562/1522/1603
-f 1562/1522/1603 1553/1513/1594 1552/1512/1593
-f 1553/1513/1594 1562/1522/1603 1563/1523/1604
-f 1563/1523/1604 1554/1514/1595 1553/1513/1594
-f 1554/1514/1595 1563/1523/1604 1564/1524/1605
-f 1564/1524/1605 1555/1515/1596 1554/1514/1595
-f 1555/1515/1596 1564/1524/1605 1565/1525/1606
-f 1565/1525/1606 1556/1516/1597 1555/1515/1596
-f 1556/1516/1597 1565/1525/1606 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1556/1516/1300
-f 1200/396/1240 1210/407/1250 1566/1526/1607
-f 1566/1526/1607 1557/1517/1598 1200/396/1240
-f 1557/1517/1598 1566/1526/1607 1567/1527/1608
-f 1567/1527/1608 1558/1518/1599 1557/1517/1598
-f 1558/1518/1599 1567/1527/1608 1568/1528/1609
-f 1568/1528/1609 1559/1519/1600 1558/1518/1599
-f 1559/1519/1600 1568/1528/1609 1569/1529/1610
-f 1569/1529/1610 1560/1520/1601 1559/1519/1600
-f 1560/1520/1601 1569/1529/1610 1570/1530/1611
-f 1570/1530/1611 1561/1521/1602 1560/1520/1601
-f 1561/1521/1602 1570/1530/1611 1571/1531/1612
-f 1571/1531/1612 1562/1522/1603 1561/1521/1602
-f 1562/1522/1603 1571/1531/1612 1572/1532/1613
-f 1572/1532/1613 1563/1523/1604 1562/1522/1603
-f 1563/1523/1604 1572/1532/1613 1573/1533/1614
-f 1573/1533/1614 1564/1524/1605 1563/1523/1604
-f 1564/1524/1605 1573/1533/1614 1574/1534/1615
-f 1574/1534/1615 1565/1525/1606 1564/1524/1605
-f 1565/1525/1606 1574/1534/1615 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1565/1525/1300
-f 1210/407/1250 1220/418/1260 1575/1535/1616
-f 1575/1535/1616 1566/1526/1607 1210/407/1250
-f 1566/1526/1607 1575/1535/1616 1576/1536/1617
-f 1576/1536/1617 1567/1527/1608 1566/1526/1607
-f 1567/1527/1608 1576/1536/1617 1577/1537/1618
-f 1577/1537/1618 1568/1528/1609 1567/1527/1608
-f 1568/1528/1609 1577/1537/1618 1578/1538/1619
-f 1578/1538/1619 1569/1529/1610 1568/1528/1609
-f 1569/1529/1610 1578/1538/1619 1579/1539/1620
-f 1579/1539/1620 1570/1530/1611 1569/1529/1610
-f 1570/1530/1611 1579/1539/1620 1580/1540/1621
-f 1580/1540/1621 1571/1531/1612 1570/1530/1611
-f 1571/1531/1612 1580/1540/1621 1581/1541/1622
-f 1581/1541/1622 1572/1532/1613 1571/1531/1612
-f 1572/1532/1613 1581/1541/1622 1582/1542/1623
-f 1582/1542/1623 1573/1533/1614 1572/1532/1613
-f 1573/1533/1614 1582/1542/1623 1583/1543/1624
-f 1583/1543/1624 1574/1534/1615 1573/1533/1614
-f 1574/1534/1615 1583/1543/1624 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1574/1534/1300
-f 1220/418/1260 1230/429/1270 1584/1544/1625
-f 1584/1544/1625 1575/1535/1616 1220/418/1260
-f 1575/1535/1616 1584/1544/1625 1585/1545/1626
-f 1585/1545/1626 1576/1536/1617 1575/1535/1616
-f 1576/1536/1617 1585/1545/1626 1586/1546/1627
-f 1586/1546/1627 1577/1537/1618 1576/1536/1617
-f 1577/1537/1618 1586/1546/1627 1587/1547/1628
-f 1587/1547/1628 1578/1538/1619 1577/1537/1618
-f 1578/1538/1619 1587/1547/1628 1588/1548/1629
-f 1588/1548/1629 1579/1539/1620 1578/1538/1619
-f 1579/1539/1620 1588/1548/1629 1589/1549/1630
-f 1589/1549/1630 1580/1540/1621 1579/1539/1620
-f 1580/1540/1621 1589/1549/1630 1590/1550/1631
-f 1590/1550/1631 1581/1541/1622 1580/1540/1621
-f 1581/1541/1622 1590/1550/1631 1591/1551/1632
-f 1591/1551/1632 1582/1542/1623 1581/1541/1622
-f 1582/1542/1623 1591/1551/1632 1592/1552/1633
-f 1592/1552/1633 1583/1543/1624 1582/1542/1623
-f 1583/1543/1624 1592/1552/1633 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1583/1543/1300
-f 1230/429/1270 1240/440/1280 1593/1553/1634
-f 1593/1553/1634 1584/1544/1625 1230/429/1270
-f 1584/1544/1625 1593/1553/1634 1594/1554/1635
-f 1594/1554/1635 1585/1545/1626 1584/1544/1625
-f 1585/1545/1626 1594/1554/1635 1595/1555/1636
-f 1595/1555/1636 1586/1546/1627 1585/1545/1626
-f 1586/1546/1627 1595/1555/1636 1596/1556/1637
-f 1596/1556/1637 1587/1547/1628 1586/1546/1627
-f 1587/1547/1628 1596/1556/1637 1597/1557/1638
-f 1597/1557/1638 1588/1548/1629 1587/1547/1628
-f 1588/1548/1629 1597/1557/1638 1598/1558/1639
-f 1598/1558/1639 1589/1549/1630 1588/1548/1629
-f 1589/1549/1630 1598/1558/1639 1599/1559/1640
-f 1599/1559/1640 1590/1550/1631 1589/1549/1630
-f 1590/1550/1631 1599/1559/1640 1600/1560/1641
-f 1600/1560/1641 1591/1551/1632 1590/1550/1631
-f 1591/1551/1632 1600/1560/1641 1601/1561/1642
-f 1601/1561/1642 1592/1552/1633 1591/1551/1632
-f 1592/1552/1633 1601/1561/1642 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1592/1552/1300
-f 1240/440/1280 860/22/900 1242/1202/1282
-f 1242/1202/1282 1593/1553/1634 1240/440/1280
-f 1593/1553/1634 1242/1202/1282 1244/1204/1284
-f 1244/1204/1284 1594/1554/1635 1593/1553/1634
-f 1594/1554/1635 1244/1204/1284 1246/1206/1286
-f 1246/1206/1286 1595/1555/1636 1594/1554/1635
-f 1595/1555/1636 1246/1206/1286 1248/1208/1288
-f 1248/1208/1288 1596/1556/1637 1595/1555/1636
-f 1596/1556/1637 1248/1208/1288 1250/1210/1290
-f 1250/1210/1290 1597/1557/1638 1596/1556/1637
-f 1597/1557/1638 1250/1210/1290 1252/1212/1292
-f 1252/1212/1292 1598/1558/1639 1597/1557/1638
-f 1598/1558/1639 1252/1212/1292 1254/1214/1294
-f 1254/1214/1294 1599/1559/1640 1598/1558/1639
-f 1599/1559/1640 1254/1214/1294 1256/1216/1296
-f 1256/1216/1296 1600/1560/1641 1599/1559/1640
-f 1600/1560/1641 1256/1216/1296 1258/1218/1298
-f 1258/1218/1298 1601/1561/1642 1600/1560/1641
-f 1601/1561/1642 1258/1218/1298 1259/1219/1299
-f 1259/1219/1300 1259/1219/1300 1601/1561/1300
-f 1602/1562/1643 1603/1563/1644 1604/1564/1645
-f 1604/1564/1645 1605/1565/1646 1602/1562/1643
-f 1605/1565/1646 1604/1564/1645 1606/1566/1647
-f 1606/1566/1647 1607/1567/1648 1605/1565/1646
-f 1607/1567/1648 1606/1566/1647 1608/1568/1649
-f 1608/1568/1649 1609/1569/1650 1607/1567/1648
-f 1609/1569/1650 1608/1568/1649 1610/1570/1651
-f 1610/1570/1651 1611/1571/1652 1609/1569/1650
-f 1611/1571/1652 1610/1570/1651 1612/1572/1653
-f 1612/1572/1653 1613/1573/1654 1611/1571/1652
-f 1613/1573/1654 1612/1572/1653 1614/1574/1655
-f 1614/1574/1655 1615/1575/1656 1613/1573/1654
-f 1615/1575/1656 1614/1574/1655 1616/1576/1657
-f 1616/1576/1657 1617/1577/1658 1615/1575/1656
-f 1617/1577/1658 1616/1576/1657 1618/1578/1659
-f 1618/1578/1659 1619/1579/1660 1617/1577/1658
-f 1619/1579/1660 1618/1578/1659 1620/1580/1661
-f 1620/1580/1661 1621/1581/1662 1619/1579/1660
-f 1621/1581/1662 1620/1580/1661 1622/1582/1663
-f 1622/1582/1663 1623/1583/1664 1621/1581/1662
-f 1603/1563/1644 1624/1584/1665 1625/1585/1666
-f 1625/1585/1666 1604/1564/1645 1603/1563/1644
-f 1604/1564/1645 1625/1585/1666 1626/1586/1667
-f 1626/1586/1667 1606/1566/1647 1604/1564/1645
-f 1606/1566/1647 1626/1586/1667 1627/1587/1668
-f 1627/1587/1668 1608/1568/1649 1606/1566/1647
-f 1608/1568/1649 1627/1587/1668 1628/1588/1669
-f 1628/1588/1669 1610/1570/1651 1608/1568/1649
-f 1610/1570/1651 1628/1588/1669 1629/1589/1670
-f 1629/1589/1670 1612/1572/1653 1610/1570/1651
-f 1612/1572/1653 1629/1589/1670 1630/1590/1671
-f 1630/1590/1671 1614/1574/1655 1612/1572/1653
-f 1614/1574/1655 1630/1590/1671 1631/1591/1672
-f 1631/1591/1672 1616/1576/1657 1614/1574/1655
-f 1616/1576/1657 1631/1591/1672 1632/1592/1673
-f 1632/1592/1673 1618/1578/1659 1616/1576/1657
-f 1618/1578/1659 1632/1592/1673 1633/1593/1674
-f 1633/1593/1674 1620/1580/1661 1618/1578/1659
-f 1620/1580/1661 1633/1593/1674 1634/1594/1675
-f 1634/1594/1675 1622/1582/1663 1620/1580/1661
-f 1624/1584/1665 1635/1595/1676 1636/1596/1677
-f 1636/1596/1677 1625/1585/1666 1624/1584/1665
-f 1625/1585/1666 1636/1596/1677 1637/1597/1678
-f 1637/1597/1678 1626/1586/1667 1625/1585/1666
-f 1626/1586/1667 1637/1597/1678 1638/1598/1679
-f 1638/1598/1679 1627/1587/1668 1626/1586/1667
-f 1627/1587/1668 1638/1598/1679 1639/1599/1680
-f 1639/1599/1680 1628/1588/1669 1627/1587/1668
-f 1628/1588/1669 1639/1599/1680 1640/1600/1681
-f 1640/1600/1681 1629/1589/1670 1628/1588/1669
-f 1629/1589/1670 1640/1600/1681 1641/1601/1682
-f 1641/1601/1682 1630/1590/1671 1629/1589/1670
-f 1630/1590/1671 1641/1601/1682 1642/1602/1683
-f 1642/1602/1683 1631/1591/1672 1630/1590/1671
-f 1631/1591/1672 1642/1602/1683 1643/1603/1684
-f 1643/1603/1684 1632/1592/1673 1631/1591/1672
-f 1632/1592/1673 1643/1603/1684 1644/1604/1685
-f 1644/1604/1685 1633/1593/1674 1632/1592/1673
-f 1633/1593/1674 1644/1604/1685 1645/1605/1686
-f 1645/1605/1686 1634/1594/1675 1633/1593/1674
-f 1635/1595/1676 1646/1606/1687 1647/1607/1688
-f 1647/1607/1688 1636/1596/1677 1635/1595/1676
-f 1636/1596/1677 1647/1607/1688 1648/1608/1689
-f 1648/1608/1689 1637/1597/1678 1636/1596/1677
-f 1637/1597/1678 1648/1608/1689 1649/1609/1690
-f 1649/1609/1690 1638/1598/1679 1637/1597/1678
-f 1638/1598/1679 1649/1609/1690 1650/1610/1691
-f 1650/1610/1691 1639/1599/1680 1638/1598/1679
-f 1639/1599/1680 1650/1610/1691 1651/1611/1692
-f 1651/1611/1692 1640/1600/1681 1639/1599/1680
-f 1640/1600/1681 1651/1611/1692 1652/1612/1693
-f 1652/1612/1693 1641/1601/1682 1640/1600/1681
-f 1641/1601/1682 1652/1612/1693 1653/1613/1694
-f 1653/1613/1694 1642/1602/1683 1641/1601/1682
-f 1642/1602/1683 1653/1613/1694 1654/1614/1695
-f 1654/1614/1695 1643/1603/1684 1642/1602/1683
-f 1643/1603/1684 1654/1614/1695 1655/1615/1696
-f 1655/1615/1696 1644/1604/1685 1643/1603/1684
-f 1644/1604/1685 1655/1615/1696 1656/1616/1697
-f 1656/1616/1697 1645/1605/1686 1644/1604/1685
-f 1646/1606/1687 1657/1617/1698 1658/1618/1699
-f 1658/1618/1699 1647/1607/1688 1646/1606/1687
-f 1647/1607/1688 1658/1618/1699 1659/1619/1700
-f 1659/1619/1700 1648/1608/1689 1647/1607/1688
-f 1648/1608/1689 1659/1619/1700 1660/1620/1701
-f 1660/1620/1701 1649/1609/1690 1648/1608/1689
-f 1649/1609/1690 1660/1620/1701 1661/1621/1702
-f 1661/1621/1702 1650/1610/1691 1649/1609/1690
-f 1650/1610/1691 1661/1621/1702 1662/1622/1703
-f 1662/1622/1703 1651/1611/1692 1650/1610/1691
-f 1651/1611/1692 1662/1622/1703 1663/1623/1704
-f 1663/1623/1704 1652/1612/1693 1651/1611/1692
-f 1652/1612/1693 1663/1623/1704 1664/1624/1705
-f 1664/1624/1705 1653/1613/1694 1652/1612/1693
-f 1653/1613/1694 1664/1624/1705 1665/1625/1706
-f 1665/1625/1706 1654/1614/1695 1653/1613/1694
-f 1654/1614/1695 1665/1625/1706 1666/1626/1707
-f 1666/1626/1707 1655/1615/1696 1654/1614/1695
-f 1655/1615/1696 1666/1626/1707 1667/1627/1708
-f 1667/1627/1708 1656/1616/1697 1655/1615/1696
-f 1657/1617/1698 1668/1628/1709 1669/1629/1710
-f 1669/1629/1710 1658/1618/1699 1657/1617/1698
-f 1658/1618/1699 1669/1629/1710 1670/1630/1711
-f 1670/1630/1711 1659/1619/1700 1658/1618/1699
-f 1659/1619/1700 1670/1630/1711 1671/1631/1712
-f 1671/1631/1712 1660/1620/1701 1659/1619/1700
-f 1660/1620/1701 1671/1631/1712 1672/1632/1713
-f 1672/1632/1713 1661/1621/1702 1660/1620/1701
-f 1661/1621/1702 1672/1632/1713 1673/1633/1714
-f 1673/1633/1714 1662/1622/1703 1661/1621/1702
-f 1662/1622/1703 1673/1633/1714 1674/1634/1715
-f 1674/1634/1715 1663/1623/1704 1662/1622/1703
-f 1663/1623/1704 1674/1634/1715 1675/1635/1716
-f 1675/1635/1716 1664/1624/1705 1663/1623/1704
-f 1664/1624/1705 1675/1635/1716 1676/1636/1717
-f 1676/1636/1717 1665/1625/1706 1664/1624/1705
-f 1665/1625/1706 1676/1636/1717 1677/1637/1718
-f 1677/1637/1718 1666/1626/1707 1665/1625/1706
-f 1666/1626/1707 1677/1637/1718 1678/1638/1719
-f 1678/1638/1719 1667/1627/1708 1666/1626/1707
-f 1668/1628/1709 1679/1639/1720 1680/1640/1721
-f 1680/1640/1721 1669/1629/1710 1668/1628/1709
-f 1669/1629/1710 1680/1640/1721 1681/1641/1722
-f 1681/1641/1722 1670/1630/1711 1669/1629/1710
-f 1670/1630/1711 1681/1641/1722 1682/1642/1723
-f 1682/1642/1723 1671/1631/1712 1670/1630/1711
-f 1671/1631/1712 1682/1642/1723 1683/1643/1724
-f 1683/1643/1724 1672/1632/1713 1671/1631/1712
-f 1672/1632/1713 1683/1643/1724 1684/1644/1725
-f 1684/1644/1725 1673/1633/1714 1672/1632/1713
-f 1673/1633/1714 1684/1644/1725 1685/1645/1726
-f 1685/1645/1726 1674/1634/1715 1673/1633/1714
-f 1674/1634/1715 1685/1645/1726 1686/1646/1727
-f 1686/1646/1727 1675/1635/1716 1674/1634/1715
-f 1675/1635/1716 1686/1646/1727 1687/1647/1728
-f 1687/1647/1728 1676/1636/1717 1675/1635/1716
-f 1676/1636/1717 1687/1647/1728 1688/1648/1729
-f 1688/1648/1729 1677/1637/1718 1676/1636/1717
-f 1677/1637/1718 1688/1648/1729 1689/1649/1730
-f 1689/1649/1730 1678/1638/1719 1677/1637/1718
-f 1679/1639/1720 1690/1650/1731 1691/1651/1732
-f 1691/1651/1732 1680/1640/1721 1679/1639/1720
-f 1680/1640/1721 1691/1651/1732 1692/1652/1733
-f 1692/1652/1733 1681/1641/1722 1680/1640/1721
-f 1681/1641/1722 1692/1652/1733 1693/1653/1734
-f 1693/1653/1734 1682/1642/1723 1681/1641/1722
-f 1682/1642/1723 1693/1653/1734 1694/1654/1735
-f 1694/1654/1735 1683/1643/1724 1682/1642/1723
-f 1683/1643/1724 1694/1654/1735 1695/1655/1736
-f 1695/1655/1736 1684/1644/1725 1683/1643/1724
-f 1684/1644/1725 1695/1655/1736 1696/1656/1737
-f 1696/1656/1737 1685/1645/1726 1684/1644/1725
-f 1685/1645/1726 1696/1656/1737 1697/1657/1738
-f 1697/1657/1738 1686/1646/1727 1685/1645/1726
-f 1686/1646/1727 1697/1657/1738 1698/1658/1739
-f 1698/1658/1739 1687/1647/1728 1686/1646/1727
-f 1687/1647/1728 1698/1658/1739 1699/1659/1740
-f 1699/1659/1740 1688/1648/1729 1687/1647/1728
-f 1688/1648/1729 1699/1659/1740 1700/1660/1741
-f 1700/1660/1741 1689/1649/1730 1688/1648/1729
-f 1690/1650/1731 1701/1661/1742 1702/1662/1743
-f 1702/1662/1743 1691/1651/1732 1690/1650/1731
-f 1691/1651/1732 1702/1662/1743 1703/1663/1744
-f 1703/1663/1744 1692/1652/1733 1691/1651/1732
-f 1692/1652/1733 1703/1663/1744 1704/1664/1745
-f 1704/1664/1745 1693/1653/1734 1692/1652/1733
-f 1693/1653/1734 1704/1664/1745 1705/1665/1746
-f 1705/1665/1746 1694/1654/1735 1693/1653/1734
-f 1694/1654/1735 1705/1665/1746 1706/1666/1747
-f 1706/1666/1747 1695/1655/1736 1694/1654/1735
-f 1695/1655/1736 1706/1666/1747 1707/1667/1748
-f 1707/1667/1748 1696/1656/1737 1695/1655/1736
-f 1696/1656/1737 1707/1667/1748 1708/1668/1749
-f 1708/1668/1749 1697/1657/1738 1696/1656/1737
-f 1697/1657/1738 1708/1668/1749 1709/1669/1750
-f 1709/1669/1750 1698/1658/1739 1697/1657/1738
-f 1698/1658/1739 1709/1669/1750 1710/1670/1751
-f 1710/1670/1751 1699/1659/1740 1698/1658/1739
-f 1699/1659/1740 1710/1670/1751 1711/1671/1752
-f 1711/1671/1752 1700/1660/1741 1699/1659/1740
-f 1701/1661/1742 1712/1672/1753 1713/1673/1754
-f 1713/1673/1754 1702/1662/1743 1701/1661/1742
-f 1702/1662/1743 1713/1673/1754 1714/1674/1755
-f 1714/1674/1755 1703/1663/1744 1702/1662/1743
-f 1703/1663/1744 1714/1674/1755 1715/1675/1756
-f 1715/1675/1756 1704/1664/1745 1703/1663/1744
-f 1704/1664/1745 1715/1675/1756 1716/1676/1757
-f 1716/1676/1757 1705/1665/1746 1704/1664/1745
-f 1705/1665/1746 1716/1676/1757 1717/1677/1758
-f 1717/1677/1758 1706/1666/1747 1705/1665/1746
-f 1706/1666/1747 1717/1677/1758 1718/1678/1759
-f 1718/1678/1759 1707/1667/1748 1706/1666/1747
-f 1707/1667/1748 1718/1678/1759 1719/1679/1760
-f 1719/1679/1760 1708/1668/1749 1707/1667/1748
-f 1708/1668/1749 1719/1679/1760 1720/1680/1761
-f 1720/1680/1761 1709/1669/1750 1708/1668/1749
-f 1709/1669/1750 1720/1680/1761 1721/1681/1762
-f 1721/1681/1762 1710/1670/1751 1709/1669/1750
-f 1710/1670/1751 1721/1681/1762 1722/1682/1763
-f 1722/1682/1763 1711/1671/1752 1710/1670/1751
-f 1712/1672/1753 1723/1683/1764 1724/1684/1765
-f 1724/1684/1765 1713/1673/1754 1712/1672/1753
-f 1713/1673/1754 1724/1684/1765 1725/1685/1766
-f 1725/1685/1766 1714/1674/1755 1713/1673/1754
-f 1714/1674/1755 1725/1685/1766 1726/1686/1767
-f 1726/1686/1767 1715/1675/1756 1714/1674/1755
-f 1715/1675/1756 1726/1686/1767 1727/1687/1768
-f 1727/1687/1768 1716/1676/1757 1715/1675/1756
-f 1716/1676/1757 1727/1687/1768 1728/1688/1769
-f 1728/1688/1769 1717/1677/1758 1716/1676/1757
-f 1717/1677/1758 1728/1688/1769 1729/1689/1770
-f 1729/1689/1770 1718/1678/1759 1717/1677/1758
-f 1718/1678/1759 1729/1689/1770 1730/1690/1771
-f 1730/1690/1771 1719/1679/1760 1718/1678/1759
-f 1719/1679/1760 1730/1690/1771 1731/1691/1772
-f 1731/1691/1772 1720/1680/1761 1719/1679/1760
-f 1720/1680/1761 1731/1691/1772 1732/1692/1773
-f 1732/1692/1773 1721/1681/1762 1720/1680/1761
-f 1721/1681/1762 1732/1692/1773 1733/1693/1774
-f 1733/1693/1774 1722/1682/1763 1721/1681/1762
-f 1723/1683/1764 1734/1694/1775 1735/1695/1776
-f 1735/1695/1776 1724/1684/1765 1723/1683/1764
-f 1724/1684/1765 1735/1695/1776 1736/1696/1777
-f 1736/1696/1777 1725/1685/1766 1724/1684/1765
-f 1725/1685/1766 1736/1696/1777 1737/1697/1778
-f 1737/1697/1778 1726/1686/1767 1725/1685/1766
-f 1726/1686/1767 1737/1697/1778 1738/1698/1779
-f 1738/1698/1779 1727/1687/1768 1726/1686/1767
-f 1727/1687/1768 1738/1698/1779 1739/1699/1780
-f 1739/1699/1780 1728/1688/1769 1727/1687/1768
-f 1728/1688/1769 1739/1699/1780 1740/1700/1781
-f 1740/1700/1781 1729/1689/1770 1728/1688/1769
-f 1729/1689/1770 1740/1700/1781 1741/1701/1782
-f 1741/1701/1782 1730/1690/1771 1729/1689/1770
-f 1730/1690/1771 1741/1701/1782 1742/1702/1783
-f 1742/1702/1783 1731/1691/1772 1730/1690/1771
-f 1731/1691/1772 1742/1702/1783 1743/1703/1784
-f 1743/1703/1784 1732/1692/1773 1731/1691/1772
-f 1732/1692/1773 1743/1703/1784 1744/1704/1785
-f 1744/1704/1785 1733/1693/1774 1732/1692/1773
-f 1734/1694/1775 1745/1705/1786 1746/1706/1787
-f 1746/1706/1787 1735/1695/1776 1734/1694/1775
-f 1735/1695/1776 1746/1706/1787 1747/1707/1788
-f 1747/1707/1788 1736/1696/1777 1735/1695/1776
-f 1736/1696/1777 1747/1707/1788 1748/1708/1789
-f 1748/1708/1789 1737/1697/1778 1736/1696/1777
-f 1737/1697/1778 1748/1708/1789 1749/1709/1790
-f 1749/1709/1790 1738/1698/1779 1737/1697/1778
-f 1738/1698/1779 1749/1709/1790 1750/1710/1791
-f 1750/1710/1791 1739/1699/1780 1738/1698/1779
-f 1739/1699/1780 1750/1710/1791 1751/1711/1792
-f 1751/1711/1792 1740/1700/1781 1739/1699/1780
-f 1740/1700/1781 1751/1711/1792 1752/1712/1793
-f 1752/1712/1793 1741/1701/1782 1740/1700/1781
-f 1741/1701/1782 1752/1712/1793 1753/1713/1794
-f 1753/1713/1794 1742/1702/1783 1741/1701/1782
-f 1742/1702/1783 1753/1713/1794 1754/1714/1795
-f 1754/1714/1795 1743/1703/1784 1742/1702/1783
-f 1743/1703/1784 1754/1714/1795 1755/1715/1796
-f 1755/1715/1796 1744/1704/1785 1743/1703/1784
-f 1745/1705/1786 1756/1716/1797 1757/1717/1798
-f 1757/1717/1798 1746/1706/1787 1745/1705/1786
-f 1746/1706/1787 1757/1717/1798 1758/1718/1799
-f 1758/1718/1799 1747/1707/1788 1746/1706/1787
-f 1747/1707/1788 1758/1718/1799 1759/1719/1800
-f 1759/1719/1800 1748/1708/1789 1747/1707/1788
-f 1748/1708/1789 1759/1719/1800 1760/1720/1801
-f 1760/1720/1801 1749/1709/1790 1748/1708/1789
-f 1749/1709/1790 1760/1720/1801 1761/1721/1802
-f 1761/1721/1802 1750/1710/1791 1749/1709/1790
-f 1750/1710/1791 1761/1721/1802 1762/1722/1803
-f 1762/1722/1803 1751/1711/1792 1750/1710/1791
-f 1751/1711/1792 1762/1722/1803 1763/1723/1804
-f 1763/1723/1804 1752/1712/1793 1751/1711/1792
-f 1752/1712/1793 1763/1723/1804 1764/1724/1805
-f 1764/1724/1805 1753/1713/1794 1752/1712/1793
-f 1753/1713/1794 1764/1724/1805 1765/1725/1806
-f 1765/1725/1806 1754/1714/1795 1753/1713/1794
-f 1754/1714/1795 1765/1725/1806 1766/1726/1807
-f 1766/1726/1807 1755/1715/1796 1754/1714/1795
-f 1756/1716/1797 1767/1727/1808 1768/1728/1809
-f 1768/1728/1809 1757/1717/1798 1756/1716/1797
-f 1757/1717/1798 1768/1728/1809 1769/1729/1810
-f 1769/1729/1810 1758/1718/1799 1757/1717/1798
-f 1758/1718/1799 1769/1729/1810 1770/1730/1811
-f 1770/1730/1811 1759/1719/1800 1758/1718/1799
-f 1759/1719/1800 1770/1730/1811 1771/1731/1812
-f 1771/1731/1812 1760/1720/1801 1759/1719/1800
-f 1760/1720/1801 1771/1731/1812 1772/1732/1813
-f 1772/1732/1813 1761/1721/1802 1760/1720/1801
-f 1761/1721/1802 1772/1732/1813 1773/1733/1814
-f 1773/1733/1814 1762/1722/1803 1761/1721/1802
-f 1762/1722/1803 1773/1733/1814 1774/1734/1815
-f 1774/1734/1815 1763/1723/1804 1762/1722/1803
-f 1763/1723/1804 1774/1734/1815 1775/1735/1816
-f 1775/1735/1816 1764/1724/1805 1763/1723/1804
-f 1764/1724/1805 1775/1735/1816 1776/1736/1817
-f 1776/1736/1817 1765/1725/1806 1764/1724/1805
-f 1765/1725/1806 1776/1736/1817 1777/1737/1818
-f 1777/1737/1818 1766/1726/1807 1765/1725/1806
-f 1767/1727/1808 1778/1738/1819 1779/1739/1820
-f 1779/1739/1820 1768/1728/1809 1767/1727/1808
-f 1768/1728/1809 1779/1739/1820 1780/1740/1821
-f 1780/1740/1821 1769/1729/1810 1768/1728/1809
-f 1769/1729/1810 1780/1740/1821 1781/1741/1822
-f 1781/1741/1822 1770/1730/1811 1769/1729/1810
-f 1770/1730/1811 1781/1741/1822 1782/1742/1823
-f 1782/1742/1823 1771/1731/1812 1770/1730/1811
-f 1771/1731/1812 1782/1742/1823 1783/1743/1824
-f 1783/1743/1824 1772/1732/1813 1771/1731/1812
-f 1772/1732/1813 1783/1743/1824 1784/1744/1825
-f 1784/1744/1825 1773/1733/1814 1772/1732/1813
-f 1773/1733/1814 1784/1744/1825 1785/1745/1826
-f 1785/1745/1826 1774/1734/1815 1773/1733/1814
-f 1774/1734/1815 1785/1745/1826 1786/1746/1827
-f 1786/1746/1827 1775/1735/1816 1774/1734/1815
-f 1775/1735/1816 1786/1746/1827 1787/1747/1828
-f 1787/1747/1828 1776/1736/1817 1775/1735/1816
-f 1776/1736/1817 1787/1747/1828 1788/1748/1829
-f 1788/1748/1829 1777/1737/1818 1776/1736/1817
-f 1778/1738/1819 1789/1749/1830 1790/1750/1831
-f 1790/1750/1831 1779/1739/1820 1778/1738/1819
-f 1779/1739/1820 1790/1750/1831 1791/1751/1832
-f 1791/1751/1832 1780/1740/1821 1779/1739/1820
-f 1780/1740/1821 1791/1751/1832 1792/1752/1833
-f 1792/1752/1833 1781/1741/1822 1780/1740/1821
-f 1781/1741/1822 1792/1752/1833 1793/1753/1834
-f 1793/1753/1834 1782/1742/1823 1781/1741/1822
-f 1782/1742/1823 1793/1753/1834 1794/1754/1835
-f 1794/1754/1835 1783/1743/1824 1782/1742/1823
-f 1783/1743/1824 1794/1754/1835 1795/1755/1836
-f 1795/1755/1836 1784/1744/1825 1783/1743/1824
-f 1784/1744/1825 1795/1755/1836 1796/1756/1837
-f 1796/1756/1837 1785/1745/1826 1784/1744/1825
-f 1785/1745/1826 1796/1756/1837 1797/1757/1838
-f 1797/1757/1838 1786/1746/1827 1785/1745/1826
-f 1786/1746/1827 1797/1757/1838 1798/1758/1839
-f 1798/1758/1839 1787/1747/1828 1786/1746/1827
-f 1787/1747/1828 1798/1758/1839 1799/1759/1840
-f 1799/1759/1840 1788/1748/1829 1787/1747/1828
-f 1789/1749/1830 1800/1760/1841 1801/1761/1842
-f 1801/1761/1842 1790/1750/1831 1789/1749/1830
-f 1790/1750/1831 1801/1761/1842 1802/1762/1843
-f 1802/1762/1843 1791/1751/1832 1790/1750/1831
-f 1791/1751/1832 1802/1762/1843 1803/1763/1844
-f 1803/1763/1844 1792/1752/1833 1791/1751/1832
-f 1792/1752/1833 1803/1763/1844 1804/1764/1845
-f 1804/1764/1845 1793/1753/1834 1792/1752/1833
-f 1793/1753/1834 1804/1764/1845 1805/1765/1846
-f 1805/1765/1846 1794/1754/1835 1793/1753/1834
-f 1794/1754/1835 1805/1765/1846 1806/1766/1847
-f 1806/1766/1847 1795/1755/1836 1794/1754/1835
-f 1795/1755/1836 1806/1766/1847 1807/1767/1848
-f 1807/1767/1848 1796/1756/1837 1795/1755/1836
-f 1796/1756/1837 1807/1767/1848 1808/1768/1849
-f 1808/1768/1849 1797/1757/1838 1796/1756/1837
-f 1797/1757/1838 1808/1768/1849 1809/1769/1850
-f 1809/1769/1850 1798/1758/1839 1797/1757/1838
-f 1798/1758/1839 1809/1769/1850 1810/1770/1851
-f 1810/1770/1851 1799/1759/1840 1798/1758/1839
-f 1800/1760/1841 1811/1771/1852 1812/1772/1853
-f 1812/1772/1853 1801/1761/1842 1800/1760/1841
-f 1801/1761/1842 1812/1772/1853 1813/1773/1854
-f 1813/1773/1854 1802/1762/1843 1801/1761/1842
-f 1802/1762/1843 1813/1773/1854 1814/1774/1855
-f 1814/1774/1855 1803/1763/1844 1802/1762/1843
-f 1803/1763/1844 1814/1774/1855 1815/1775/1856
-f 1815/1775/1856 1804/1764/1845 1803/1763/1844
-f 1804/1764/1845 1815/1775/1856 1816/1776/1857
-f 1816/1776/1857 1805/1765/1846 1804/1764/1845
-f 1805/1765/1846 1816/1776/1857 1817/1777/1858
-f 1817/1777/1858 1806/1766/1847 1805/1765/1846
-f 1806/1766/1847 1817/1777/1858 1818/1778/1859
-f 1818/1778/1859 1807/1767/1848 1806/1766/1847
-f 1807/1767/1848 1818/1778/1859 1819/1779/1860
-f 1819/1779/1860 1808/1768/1849 1807/1767/1848
-f 1808/1768/1849 1819/1779/1860 1820/1780/1861
-f 1820/1780/1861 1809/1769/1850 1808/1768/1849
-f 1809/1769/1850 1820/1780/1861 1821/1781/1862
-f 1821/1781/1862 1810/1770/1851 1809/1769/1850
-f 1811/1771/1852 1602/1562/1643 1605/1565/1646
-f 1605/1565/1646 1812/1772/1853 1811/1771/1852
-f 1812/1772/1853 1605/1565/1646 1607/1567/1648
-f 1607/1567/1648 1813/1773/1854 1812/1772/1853
-f 1813/1773/1854 1607/1567/1648 1609/1569/1650
-f 1609/1569/1650 1814/1774/1855 1813/1773/1854
-f 1814/1774/1855 1609/1569/1650 1611/1571/1652
-f 1611/1571/1652 1815/1775/1856 1814/1774/1855
-f 1815/1775/1856 1611/1571/1652 1613/1573/1654
-f 1613/1573/1654 1816/1776/1857 1815/1775/1856
-f 1816/1776/1857 1613/1573/1654 1615/1575/1656
-f 1615/1575/1656 1817/1777/1858 1816/1776/1857
-f 1817/1777/1858 1615/1575/1656 1617/1577/1658
-f 1617/1577/1658 1818/1778/1859 1817/1777/1858
-f 1818/1778/1859 1617/1577/1658 1619/1579/1660
-f 1619/1579/1660 1819/1779/1860 1818/1778/1859
-f 1819/1779/1860 1619/1579/1660 1621/1581/1662
-f 1621/1581/1662 1820/1780/1861 1819/1779/1860
-f 1820/1780/1861 1621/1581/1662 1623/1583/1664
-f 1623/1583/1664 1821/1781/1862 1820/1780/1861
-f 1623/1583/1664 1622/1582/1663 1822/1782/1863
-f 1822/1782/1863 1823/1783/1864 1623/1583/1664
-f 1823/1783/1864 1822/1782/1863 1824/1784/1865
-f 1824/1784/1865 1825/1785/1866 1823/1783/1864
-f 1825/1785/1866 1824/1784/1865 1826/1786/1867
-f 1826/1786/1867 1827/1787/1868 1825/1785/1866
-f 1827/1787/1868 1826/1786/1867 1828/1788/1869
-f 1828/1788/1869 1829/1789/1870 1827/1787/1868
-f 1829/1789/1870 1828/1788/1869 1830/1790/1871
-f 1830/1790/1871 1831/1791/1872 1829/1789/1870
-f 1831/1791/1872 1830/1790/1871 1832/1792/1873
-f 1832/1792/1873 1833/1793/1874 1831/1791/1872
-f 1833/1793/1874 1832/1792/1873 1834/1794/1875
-f 1834/1794/1875 1835/1795/1876 1833/1793/1874
-f 1835/1795/1876 1834/1794/1875 1836/1796/1877
-f 1836/1796/1877 1837/1797/1878 1835/1795/1876
-f 1837/1797/1878 1836/1796/1877 1838/1798/1879
-f 1838/1798/1879 1839/1799/1880 1837/1797/1878
-f 1839/1799/1880 1838/1798/1879 1840/1800/1881
-f 1840/1800/1881 1841/1801/1882 1839/1799/1880
-f 1622/1582/1663 1634/1594/1675 1842/1802/1883
-f 1842/1802/1883 1822/1782/1863 1622/1582/1663
-f 1822/1782/1863 1842/1802/1883 1843/1803/1884
-f 1843/1803/1884 1824/1784/1865 1822/1782/1863
-f 1824/1784/1865 1843/1803/1884 1844/1804/1885
-f 1844/1804/1885 1826/1786/1867 1824/1784/1865
-f 1826/1786/1867 1844/1804/1885 1845/1805/1886
-f 1845/1805/1886 1828/1788/1869 1826/1786/1867
-f 1828/1788/1869 1845/1805/1886 1846/1806/1887
-f 1846/1806/1887 1830/1790/1871 1828/1788/1869
-f 1830/1790/1871 1846/1806/1887 1847/1807/1888
-f 1847/1807/1888 1832/1792/1873 1830/1790/1871
-f 1832/1792/1873 1847/1807/1888 1848/1808/1889
-f 1848/1808/1889 1834/1794/1875 1832/1792/1873
-f 1834/1794/1875 1848/1808/1889 1849/1809/1890
-f 1849/1809/1890 1836/1796/1877 1834/1794/1875
-f 1836/1796/1877 1849/1809/1890 1850/1810/1891
-f 1850/1810/1891 1838/1798/1879 1836/1796/1877
-f 1838/1798/1879 1850/1810/1891 1851/1811/1892
-f 1851/1811/1892 1840/1800/1881 1838/1798/1879
-f 1634/1594/1675 1645/1605/1686 1852/1812/1893
-f 1852/1812/1893 1842/1802/1883 1634/1594/1675
-f 1842/1802/1883 1852/1812/1893 1853/1813/1894
-f 1853/1813/1894 1843/1803/1884 1842/1802/1883
-f 1843/1803/1884 1853/1813/1894 1854/1814/1895
-f 1854/1814/1895 1844/1804/1885 1843/1803/1884
-f 1844/1804/1885 1854/1814/1895 1855/1815/1896
-f 1855/1815/1896 1845/1805/1886 1844/1804/1885
-f 1845/1805/1886 1855/1815/1896 1856/1816/1897
-f 1856/1816/1897 1846/1806/1887 1845/1805/1886
-f 1846/1806/1887 1856/1816/1897 1857/1817/1898
-f 1857/1817/1898 1847/1807/1888 1846/1806/1887
-f 1847/1807/1888 1857/1817/1898 1858/1818/1899
-f 1858/1818/1899 1848/1808/1889 1847/1807/1888
-f 1848/1808/1889 1858/1818/1899 1859/1819/1900
-f 1859/1819/1900 1849/1809/1890 1848/1808/1889
-f 1849/1809/1890 1859/1819/1900 1860/1820/1901
-f 1860/1820/1901 1850/1810/1891 1849/1809/1890
-f 1850/1810/1891 1860/1820/1901 1861/1821/1902
-f 1861/1821/1902 1851/1811/1892 1850/1810/1891
-f 1645/1605/1686 1656/1616/1697 1862/1822/1903
-f 1862/1822/1903 1852/1812/1893 1645/1605/1686
-f 1852/1812/1893 1862/1822/1903 1863/1823/1904
-f 1863/1823/1904 1853/1813/1894 1852/1812/1893
-f 1853/1813/1894 1863/1823/1904 1864/1824/1905
-f 1864/1824/1905 1854/1814/1895 1853/1813/1894
-f 1854/1814/1895 1864/1824/1905 1865/1825/1906
-f 1865/1825/1906 1855/1815/1896 1854/1814/1895
-f 1855/1815/1896 1865/1825/1906 1866/1826/1907
-f 1866/1826/1907 1856/1816/1897 1855/1815/1896
-f 1856/1816/1897 1866/1826/1907 1867/1827/1908
-f 1867/1827/1908 1857/1817/1898 1856/1816/1897
-f 1857/1817/1898 1867/1827/1908 1868/1828/1909
-f 1868/1828/1909 1858/1818/1899 1857/1817/1898
-f 1858/1818/1899 1868/1828/1909 1869/1829/1910
-f 1869/1829/1910 1859/1819/1900 1858/1818/1899
-f 1859/1819/1900 1869/1829/1910 1870/1830/1911
-f 1870/1830/1911 1860/1820/1901 1859/1819/1900
-f 1860/1820/1901 1870/1830/1911 1871/1831/1912
-f 1871/1831/1912 1861/1821/1902 1860/1820/1901
-f 1656/1616/1697 1667/1627/1708 1872/1832/1913
-f 1872/1832/1913 1862/1822/1903 1656/1616/1697
-f 1862/1822/1903 1872/1832/1913 1873/1833/1914
-f 1873/1833/1914 1863/1823/1904 1862/1822/1903
-f 1863/1823/1904 1873/1833/1914 1874/1834/1915
-f 1874/1834/1915 1864/1824/1905 1863/1823/1904
-f 1864/1824/1905 1874/1834/1915 1875/1835/1916
-f 1875/1835/1916 1865/1825/1906 1864/1824/1905
-f 1865/1825/1906 1875/1835/1916 1876/1836/1917
-f 1876/1836/1917 1866/1826/1907 1865/1825/1906
-f 1866/1826/1907 1876/1836/1917 1877/1837/1918
-f 1877/1837/1918 1867/1827/1908 1866/1826/1907
-f 1867/1827/1908 1877/1837/1918 1878/1838/1919
-f 1878/1838/1919 1868/1828/1909 1867/1827/1908
-f 1868/1828/1909 1878/1838/1919 1879/1839/1920
-f 1879/1839/1920 1869/1829/1910 1868/1828/1909
-f 1869/1829/1910 1879/1839/1920 1880/1840/1921
-f 1880/1840/1921 1870/1830/1911 1869/1829/1910
-f 1870/1830/1911 1880/1840/1921 1881/1841/1922
-f 1881/1841/1922 1871/1831/1912 1870/1830/1911
-f 1667/1627/1708 1678/1638/1719 1882/1842/1923
-f 1882/1842/1923 1872/1832/1913 1667/1627/1708
-f 1872/1832/1913 1882/1842/1923 1883/1843/1924
-f 1883/1843/1924 1873/1833/1914 1872/1832/1913
-f 1873/1833/1914 1883/1843/1924 1884/1844/1925
-f 1884/1844/1925 1874/1834/1915 1873/1833/1914
-f 1874/1834/1915 1884/1844/1925 1885/1845/1926
-f 1885/1845/1926 1875/1835/1916 1874/1834/1915
-f 1875/1835/1916 1885/1845/1926 1886/1846/1927
-f 1886/1846/1927 1876/1836/1917 1875/1835/1916
-f 1876/1836/1917 1886/1846/1927 1887/1847/1928
-f 1887/1847/1928 1877/1837/1918 1876/1836/1917
-f 1877/1837/1918 1887/1847/1928 1888/1848/1929
-f 1888/1848/1929 1878/1838/1919 1877/1837/1918
-f 1878/1838/1919 1888/1848/1929 1889/1849/1930
-f 1889/1849/1930 1879/1839/1920 1878/1838/1919
-f 1879/1839/1920 1889/1849/1930 1890/1850/1931
-f 1890/1850/1931 1880/1840/1921 1879/1839/1920
-f 1880/1840/1921 1890/1850/1931 1891/1851/1932
-f 1891/1851/1932 1881/1841/1922 1880/1840/1921
-f 1678/1638/1719 1689/1649/1730 1892/1852/1933
-f 1892/1852/1933 1882/1842/1923 1678/1638/1719
-f 1882/1842/1923 1892/1852/1933 1893/1853/1934
-f 1893/1853/1934 1883/1843/1924 1882/1842/1923
-f 1883/1843/1924 1893/1853/1934 1894/1854/1935
-f 1894/1854/1935 1884/1844/1925 1883/1843/1924
-f 1884/1844/1925 1894/1854/1935 1895/1855/1936
-f 1895/1855/1936 1885/1845/1926 1884/1844/1925
-f 1885/1845/1926 1895/1855/1936 1896/1856/1937
-f 1896/1856/1937 1886/1846/1927 1885/1845/1926
-f 1886/1846/1927 1896/1856/1937 1897/1857/1938
-f 1897/1857/1938 1887/1847/1928 1886/1846/1927
-f 1887/1847/1928 1897/1857/1938 1898/1858/1939
-f 1898/1858/1939 1888/1848/1929 1887/1847/1928
-f 1888/1848/1929 1898/1858/1939 1899/1859/1940
-f 1899/1859/1940 1889/1849/1930 1888/1848/1929
-f 1889/1849/1930 1899/1859/1940 1900/1860/1941
-f 1900/1860/1941 1890/1850/1931 1889/1849/1930
-f 1890/1850/1931 1900/1860/1941 1901/1861/1942
-f 1901/1861/1942 1891/1851/1932 1890/1850/1931
-f 1689/1649/1730 1700/1660/1741 1902/1862/1943
-f 1902/1862/1943 1892/1852/1933 1689/1649/1730
-f 1892/1852/1933 1902/1862/1943 1903/1863/1944
-f 1903/1863/1944 1893/1853/1934 1892/1852/1933
-f 1893/1853/1934 1903/1863/1944 1904/1864/1945
-f 1904/1864/1945 1894/1854/1935 1893/1853/1934
-f 1894/1854/1935 1904/1864/1945 1905/1865/1946
-f 1905/1865/1946 1895/1855/1936 1894/1854/1935
-f 1895/1855/1936 1905/1865/1946 1906/1866/1947
-f 1906/1866/1947 1896/1856/1937 1895/1855/1936
-f 1896/1856/1937 1906/1866/1947 1907/1867/1948
-f 1907/1867/1948 1897/1857/1938 1896/1856/1937
-f 1897/1857/1938 1907/1867/1948 1908/1868/1949
-f 1908/1868/1949 1898/1858/1939 1897/1857/1938
-f 1898/1858/1939 1908/1868/1949 1909/1869/1950
-f 1909/1869/1950 1899/1859/1940 1898/1858/1939
-f 1899/1859/1940 1909/1869/1950 1910/1870/1951
-f 1910/1870/1951 1900/1860/1941 1899/1859/1940
-f 1900/1860/1941 1910/1870/1951 1911/1871/1952
-f 1911/1871/1952 1901/1861/1942 1900/1860/1941
-f 1700/1660/1741 1711/1671/1752 1912/1872/1953
-f 1912/1872/1953 1902/1862/1943 1700/1660/1741
-f 1902/1862/1943 1912/1872/1953 1913/1873/1954
-f 1913/1873/1954 1903/1863/1944 1902/1862/1943
-f 1903/1863/1944 1913/1873/1954 1914/1874/1955
-f 1914/1874/1955 1904/1864/1945 1903/1863/1944
-f 1904/1864/1945 1914/1874/1955 1915/1875/1956
-f 1915/1875/1956 1905/1865/1946 1904/1864/1945
-f 1905/1865/1946 1915/1875/1956 1916/1876/1957
-f 1916/1876/1957 1906/1866/1947 1905/1865/1946
-f 1906/1866/1947 1916/1876/1957 1917/1877/1958
-f 1917/1877/1958 1907/1867/1948 1906/1866/1947
-f 1907/1867/1948 1917/1877/1958 1918/1878/1959
-f 1918/1878/1959 1908/1868/1949 1907/1867/1948
-f 1908/1868/1949 1918/1878/1959 1919/1879/1960
-f 1919/1879/1960 1909/1869/1950 1908/1868/1949
-f 1909/1869/1950 1919/1879/1960 1920/1880/1961
-f 1920/1880/1961 1910/1870/1951 1909/1869/1950
-f 1910/1870/1951 1920/1880/1961 1921/1881/1962
-f 1921/1881/1962 1911/1871/1952 1910/1870/1951
-f 1711/1671/1752 1722/1682/1763 1922/1882/1963
-f 1922/1882/1963 1912/1872/1953 1711/1671/1752
-f 1912/1872/1953 1922/1882/1963 1923/1883/1964
-f 1923/1883/1964 1913/1873/1954 1912/1872/1953
-f 1913/1873/1954 1923/1883/1964 1924/1884/1965
-f 1924/1884/1965 1914/1874/1955 1913/1873/1954
-f 1914/1874/1955 1924/1884/1965 1925/1885/1966
-f 1925/1885/1966 1915/1875/1956 1914/1874/1955
-f 1915/1875/1956 1925/1885/1966 1926/1886/1967
-f 1926/1886/1967 1916/1876/1957 1915/1875/1956
-f 1916/1876/1957 1926/1886/1967 1927/1887/1968
-f 1927/1887/1968 1917/1877/1958 1916/1876/1957
-f 1917/1877/1958 1927/1887/1968 1928/1888/1969
-f 1928/1888/1969 1918/1878/1959 1917/1877/1958
-f 1918/1878/1959 1928/1888/1969 1929/1889/1970
-f 1929/1889/1970 1919/1879/1960 1918/1878/1959
-f 1919/1879/1960 1929/1889/1970 1930/1890/1971
-f 1930/1890/1971 1920/1880/1961 1919/1879/1960
-f 1920/1880/1961 1930/1890/1971 1931/1891/1972
-f 1931/1891/1972 1921/1881/1962 1920/1880/1961
-f 1722/1682/1763 1733/1693/1774 1932/1892/1973
-f 1932/1892/1973 1922/1882/1963 1722/1682/1763
-f 1922/1882/1963 1932/1892/1973 1933/1893/1974
-f 1933/1893/1974 1923/1883/1964 1922/1882/1963
-f 1923/1883/1964 1933/1893/1974 1934/1894/1975
-f 1934/1894/1975 1924/1884/1965 1923/1883/1964
-f 1924/1884/1965 1934/1894/1975 1935/1895/1976
-f 1935/1895/1976 1925/1885/1966 1924/1884/1965
-f 1925/1885/1966 1935/1895/1976 1936/1896/1977
-f 1936/1896/1977 1926/1886/1967 1925/1885/1966
-f 1926/1886/1967 1936/1896/1977 1937/1897/1978
-f 1937/1897/1978 1927/1887/1968 1926/1886/1967
-f 1927/1887/1968 1937/1897/1978 1938/1898/1979
-f 1938/1898/1979 1928/1888/1969 1927/1887/1968
-f 1928/1888/1969 1938/1898/1979 1939/1899/1980
-f 1939/1899/1980 1929/1889/1970 1928/1888/1969
-f 1929/1889/1970 1939/1899/1980 1940/1900/1981
-f 1940/1900/1981 1930/1890/1971 1929/1889/1970
-f 1930/1890/1971 1940/1900/1981 1941/1901/1982
-f 1941/1901/1982 1931/1891/1972 1930/1890/1971
-f 1733/1693/1774 1744/1704/1785 1942/1902/1983
-f 1942/1902/1983 1932/1892/1973 1733/1693/1774
-f 1932/1892/1973 1942/1902/1983 1943/1903/1984
-f 1943/1903/1984 1933/1893/1974 1932/1892/1973
-f 1933/1893/1974 1943/1903/1984 1944/1904/1985
-f 1944/1904/1985 1934/1894/1975 1933/1893/1974
-f 1934/1894/1975 1944/1904/1985 1945/1905/1986
-f 1945/1905/1986 1935/1895/1976 1934/1894/1975
-f 1935/1895/1976 1945/1905/1986 1946/1906/1987
-f 1946/1906/1987 1936/1896/1977 1935/1895/1976
-f 1936/1896/1977 1946/1906/1987 1947/1907/1988
-f 1947/1907/1988 1937/1897/1978 1936/1896/1977
-f 1937/1897/1978 1947/1907/1988 1948/1908/1989
-f 1948/1908/1989 1938/1898/1979 1937/1897/1978
-f 1938/1898/1979 1948/1908/1989 1949/1909/1990
-f 1949/1909/1990 1939/1899/1980 1938/1898/1979
-f 1939/1899/1980 1949/1909/1990 1950/1910/1991
-f 1950/1910/1991 1940/1900/1981 1939/1899/1980
-f 1940/1900/1981 1950/1910/1991 1951/1911/1992
-f 1951/1911/1992 1941/1901/1982 1940/1900/1981
-f 1744/1704/1785 1755/1715/1796 1952/1912/1993
-f 1952/1912/1993 1942/1902/1983 1744/1704/1785
-f 1942/1902/1983 1952/1912/1993 1953/1913/1994
-f 1953/1913/1994 1943/1903/1984 1942/1902/1983
-f 1943/1903/1984 1953/1913/1994 1954/1914/1995
-f 1954/1914/1995 1944/1904/1985 1943/1903/1984
-f 1944/1904/1985 1954/1914/1995 1955/1915/1996
-f 1955/1915/1996 1945/1905/1986 1944/1904/1985
-f 1945/1905/1986 1955/1915/1996 1956/1916/1997
-f 1956/1916/1997 1946/1906/1987 1945/1905/1986
-f 1946/1906/1987 1956/1916/1997 1957/1917/1998
-f 1957/1917/1998 1947/1907/1988 1946/1906/1987
-f 1947/1907/1988 1957/1917/1998 1958/1918/1999
-f 1958/1918/1999 1948/1908/1989 1947/1907/1988
-f 1948/1908/1989 1958/1918/1999 1959/1919/2000
-f 1959/1919/2000 1949/1909/1990 1948/1908/1989
-f 1949/1909/1990 1959/1919/2000 1960/1920/2001
-f 1960/1920/2001 1950/1910/1991 1949/1909/1990
-f 1950/1910/1991 1960/1920/2001 1961/1921/2002
-f 1961/1921/2002 1951/1911/1992 1950/1910/1991
-f 1755/1715/1796 1766/1726/1807 1962/1922/2003
-f 1962/1922/2003 1952/1912/1993 1755/1715/1796
-f 1952/1912/1993 1962/1922/2003 1963/1923/2004
-f 1963/1923/2004 1953/1913/1994 1952/1912/1993
-f 1953/1913/1994 1963/1923/2004 1964/1924/2005
-f 1964/1924/2005 1954/1914/1995 1953/1913/1994
-f 1954/1914/1995 1964/1924/2005 1965/1925/2006
-f 1965/1925/2006 1955/1915/1996 1954/1914/1995
-f 1955/1915/1996 1965/1925/2006 1966/1926/2007
-f 1966/1926/2007 1956/1916/1997 1955/1915/1996
-f 1956/1916/1997 1966/1926/2007 1967/1927/2008
-f 1967/1927/2008 1957/1917/1998 1956/1916/1997
-f 1957/1917/1998 1967/1927/2008 1968/1928/2009
-f 1968/1928/2009 1958/1918/1999 1957/1917/1998
-f 1958/1918/1999 1968/1928/2009 1969/1929/2010
-f 1969/1929/2010 1959/1919/2000 1958/1918/1999
-f 1959/1919/2000 1969/1929/2010 1970/1930/2011
-f 1970/1930/2011 1960/1920/2001 1959/1919/2000
-f 1960/1920/2001 1970/1930/2011 1971/1931/2012
-f 1971/1931/2012 1961/1921/2002 1960/1920/2001
-f 1766/1726/1807 1777/1737/1818 1972/1932/2013
-f 1972/1932/2013 1962/1922/2003 1766/1726/1807
-f 1962/1922/2003 1972/1932/2013 1973/1933/2014
-f 1973/1933/2014 1963/1923/2004 1962/1922/2003
-f 1963/1923/2004 1973/1933/2014 1974/1934/2015
-f 1974/1934/2015 1964/1924/2005 1963/1923/2004
-f 1964/1924/2005 1974/1934/2015 1975/1935/2016
-f 1975/1935/2016 1965/1925/2006 1964/1924/2005
-f 1965/1925/2006 1975/1935/2016 1976/1936/2017
-f 1976/1936/2017 1966/1926/2007 1965/1925/2006
-f 1966/1926/2007 1976/1936/2017 1977/1937/2018
-f 1977/1937/2018 1967/1927/2008 1966/1926/2007
-f 1967/1927/2008 1977/1937/2018 1978/1938/2019
-f 1978/1938/2019 1968/1928/2009 1967/1927/2008
-f 1968/1928/2009 1978/1938/2019 1979/1939/2020
-f 1979/1939/2020 1969/1929/2010 1968/1928/2009
-f 1969/1929/2010 1979/1939/2020 1980/1940/2021
-f 1980/1940/2021 1970/1930/2011 1969/1929/2010
-f 1970/1930/2011 1980/1940/2021 1981/1941/2022
-f 1981/1941/2022 1971/1931/2012 1970/1930/2011
-f 1777/1737/1818 1788/1748/1829 1982/1942/2023
-f 1982/1942/2023 1972/1932/2013 1777/1737/1818
-f 1972/1932/2013 1982/1942/2023 1983/1943/2024
-f 1983/1943/2024 1973/1933/2014 1972/1932/2013
-f 1973/1933/2014 1983/1943/2024 1984/1944/2025
-f 1984/1944/2025 1974/1934/2015 1973/1933/2014
-f 1974/1934/2015 1984/1944/2025 1985/1945/2026
-f 1985/1945/2026 1975/1935/2016 1974/1934/2015
-f 1975/1935/2016 1985/1945/2026 1986/1946/2027
-f 1986/1946/2027 1976/1936/2017 1975/1935/2016
-f 1976/1936/2017 1986/1946/2027 1987/1947/2028
-f 1987/1947/2028 1977/1937/2018 1976/1936/2017
-f 1977/1937/2018 1987/1947/2028 1988/1948/2029
-f 1988/1948/2029 1978/1938/2019 1977/1937/2018
-f 1978/1938/2019 1988/1948/2029 1989/1949/2030
-f 1989/1949/2030 1979/1939/2020 1978/1938/2019
-f 1979/1939/2020 1989/1949/2030 1990/1950/2031
-f 1990/1950/2031 1980/1940/2021 1979/1939/2020
-f 1980/1940/2021 1990/1950/2031 1991/1951/2032
-f 1991/1951/2032 1981/1941/2022 1980/1940/2021
-f 1788/1748/1829 1799/1759/1840 1992/1952/2033
-f 1992/1952/2033 1982/1942/2023 1788/1748/1829
-f 1982/1942/2023 1992/1952/2033 1993/1953/2034
-f 1993/1953/2034 1983/1943/2024 1982/1942/2023
-f 1983/1943/2024 1993/1953/2034 1994/1954/2035
-f 1994/1954/2035 1984/1944/2025 1983/1943/2024
-f 1984/1944/2025 1994/1954/2035 1995/1955/2036
-f 1995/1955/2036 1985/1945/2026 1984/1944/2025
-f 1985/1945/2026 1995/1955/2036 1996/1956/2037
-f 1996/1956/2037 1986/1946/2027 1985/1945/2026
-f 1986/1946/2027 1996/1956/2037 1997/1957/2038
-f 1997/1957/2038 1987/1947/2028 1986/1946/2027
-f 1987/1947/2028 1997/1957/2038 1998/1958/2039
-f 1998/1958/2039 1988/1948/2029 1987/1947/2028
-f 1988/1948/2029 1998/1958/2039 1999/1959/2040
-f 1999/1959/2040 1989/1949/2030 1988/1948/2029
-f 1989/1949/2030 1999/1959/2040 2000/1960/2041
-f 2000/1960/2041 1990/1950/2031 1989/1949/2030
-f 1990/1950/2031 2000/1960/2041 2001/1961/2042
-f 2001/1961/2042 1991/1951/2032 1990/1950/2031
-f 1799/1759/1840 1810/1770/1851 2002/1962/2043
-f 2002/1962/2043 1992/1952/2033 1799/1759/1840
-f 1992/1952/2033 2002/1962/2043 2003/1963/2044
-f 2003/1963/2044 1993/1953/2034 1992/1952/2033
-f 1993/1953/2034 2003/1963/2044 2004/1964/2045
-f 2004/1964/2045 1994/1954/2035 1993/1953/2034
-f 1994/1954/2035 2004/1964/2045 2005/1965/2046
-f 2005/1965/2046 1995/1955/2036 1994/1954/2035
-f 1995/1955/2036 2005/1965/2046 2006/1966/2047
-f 2006/1966/2047 1996/1956/2037 1995/1955/2036
-f 1996/1956/2037 2006/1966/2047 2007/1967/2048
-f 2007/1967/2048 1997/1957/2038 1996/1956/2037
-f 1997/1957/2038 2007/1967/2048 2008/1968/2049
-f 2008/1968/2049 1998/1958/2039 1997/1957/2038
-f 1998/1958/2039 2008/1968/2049 2009/1969/2050
-f 2009/1969/2050 1999/1959/2040 1998/1958/2039
-f 1999/1959/2040 2009/1969/2050 2010/1970/2051
-f 2010/1970/2051 2000/1960/2041 1999/1959/2040
-f 2000/1960/2041 2010/1970/2051 2011/1971/2052
-f 2011/1971/2052 2001/1961/2042 2000/1960/2041
-f 1810/1770/1851 1821/1781/1862 2012/1972/2053
-f 2012/1972/2053 2002/1962/2043 1810/1770/1851
-f 2002/1962/2043 2012/1972/2053 2013/1973/2054
-f 2013/1973/2054 2003/1963/2044 2002/1962/2043
-f 2003/1963/2044 2013/1973/2054 2014/1974/2055
-f 2014/1974/2055 2004/1964/2045 2003/1963/2044
-f 2004/1964/2045 2014/1974/2055 2015/1975/2056
-f 2015/1975/2056 2005/1965/2046 2004/1964/2045
-f 2005/1965/2046 2015/1975/2056 2016/1976/2057
-f 2016/1976/2057 2006/1966/2047 2005/1965/2046
-f 2006/1966/2047 2016/1976/2057 2017/1977/2058
-f 2017/1977/2058 2007/1967/2048 2006/1966/2047
-f 2007/1967/2048 2017/1977/2058 2018/1978/2059
-f 2018/1978/2059 2008/1968/2049 2007/1967/2048
-f 2008/1968/2049 2018/1978/2059 2019/1979/2060
-f 2019/1979/2060 2009/1969/2050 2008/1968/2049
-f 2009/1969/2050 2019/1979/2060 2020/1980/2061
-f 2020/1980/2061 2010/1970/2051 2009/1969/2050
-f 2010/1970/2051 2020/1980/2061 2021/1981/2062
-f 2021/1981/2062 2011/1971/2052 2010/1970/2051
-f 1821/1781/1862 1623/1583/1664 1823/1783/1864
-f 1823/1783/1864 2012/1972/2053 1821/1781/1862
-f 2012/1972/2053 1823/1783/1864 1825/1785/1866
-f 1825/1785/1866 2013/1973/2054 2012/1972/2053
-f 2013/1973/2054 1825/1785/1866 1827/1787/1868
-f 1827/1787/1868 2014/1974/2055 2013/1973/2054
-f 2014/1974/2055 1827/1787/1868 1829/1789/1870
-f 1829/1789/1870 2015/1975/2056 2014/1974/2055
-f 2015/1975/2056 1829/1789/1870 1831/1791/1872
-f 1831/1791/1872 2016/1976/2057 2015/1975/2056
-f 2016/1976/2057 1831/1791/1872 1833/1793/1874
-f 1833/1793/1874 2017/1977/2058 2016/1976/2057
-f 2017/1977/2058 1833/1793/1874 1835/1795/1876
-f 1835/1795/1876 2018/1978/2059 2017/1977/2058
-f 2018/1978/2059 1835/1795/1876 1837/1797/1878
-f 1837/1797/1878 2019/1979/2060 2018/1978/2059
-f 2019/1979/2060 1837/1797/1878 1839/1799/1880
-f 1839/1799/1880 2020/1980/2061 2019/1979/2060
-f 2020/1980/2061 1839/1799/1880 1841/1801/1882
-f 1841/1801/1882 2021/1981/2062 2020/1980/2061
-f 2022/1982/2063 2023/1983/2064 2024/1984/2065
-f 2024/1984/2065 2025/1985/2066 2022/1982/2063
-f 2025/1985/2066 2024/1984/2065 2026/1986/2067
-f 2026/1986/2067 2027/1987/2068 2025/1985/2066
-f 2027/1987/2068 2026/1986/2067 2028/1988/2069
-f 2028/1988/2069 2029/1989/2070 2027/1987/2068
-f 2029/1989/2070 2028/1988/2069 2030/1990/2071
-f 2030/1990/2071 2031/1991/2072 2029/1989/2070
-f 2031/1991/2072 2030/1990/2071 2032/1992/2073
-f 2032/1992/2073 2033/1993/2074 2031/1991/2072
-f 2033/1993/2074 2032/1992/2073 2034/1994/2075
-f 2034/1994/2075 2035/1995/2076 2033/1993/2074
-f 2035/1995/2076 2034/1994/2075 2036/1996/2077
-f 2036/1996/2077 2037/1997/2078 2035/1995/2076
-f 2037/1997/2078 2036/1996/2077 2038/1998/2079
-f 2038/1998/2079 2039/1999/2080 2037/1997/2078
-f 2039/1999/2080 2038/1998/2079 2040/2000/2081
-f 2040/2000/2081 2041/2001/2082 2039/1999/2080
-f 2041/2001/2082 2040/2000/2081 2042/2002/2083
-f 2042/2002/2083 2043/2003/2084 2041/2001/2082
-f 2023/1983/2064 2044/2004/2085 2045/2005/2086
-f 2045/2005/2086 2024/1984/2065 2023/1983/2064
-f 2024/1984/2065 2045/2005/2086 2046/2006/2087
-f 2046/2006/2087 2026/1986/2067 2024/1984/2065
-f 2026/1986/2067 2046/2006/2087 2047/2007/2088
-f 2047/2007/2088 2028/1988/2069 2026/1986/2067
-f 2028/1988/2069 2047/2007/2088 2048/2008/2089
-f 2048/2008/2089 2030/1990/2071 2028/1988/2069
-f 2030/1990/2071 2048/2008/2089 2049/2009/2090
-f 2049/2009/2090 2032/1992/2073 2030/1990/2071
-f 2032/1992/2073 2049/2009/2090 2050/2010/2091
-f 2050/2010/2091 2034/1994/2075 2032/1992/2073
-f 2034/1994/2075 2050/2010/2091 2051/2011/2092
-f 2051/2011/2092 2036/1996/2077 2034/1994/2075
-f 2036/1996/2077 2051/2011/2092 2052/2012/2093
-f 2052/2012/2093 2038/1998/2079 2036/1996/2077
-f 2038/1998/2079 2052/2012/2093 2053/2013/2094
-f 2053/2013/2094 2040/2000/2081 2038/1998/2079
-f 2040/2000/2081 2053/2013/2094 2054/2014/2095
-f 2054/2014/2095 2042/2002/2083 2040/2000/2081
-f 2044/2004/2085 2055/2015/2096 2056/2016/2097
-f 2056/2016/2097 2045/2005/2086 2044/2004/2085
-f 2045/2005/2086 2056/2016/2097 2057/2017/2098
-f 2057/2017/2098 2046/2006/2087 2045/2005/2086
-f 2046/2006/2087 2057/2017/2098 2058/2018/2099
-f 2058/2018/2099 2047/2007/2088 2046/2006/2087
-f 2047/2007/2088 2058/2018/2099 2059/2019/2100
-f 2059/2019/2100 2048/2008/2089 2047/2007/2088
-f 2048/2008/2089 2059/2019/2100 2060/2020/2101
-f 2060/2020/2101 2049/2009/2090 2048/2008/2089
-f 2049/2009/2090 2060/2020/2101 2061/2021/2102
-f 2061/2021/2102 2050/2010/2091 2049/2009/2090
-f 2050/2010/2091 2061/2021/2102 2062/2022/2103
-f 2062/2022/2103 2051/2011/2092 2050/2010/2091
-f 2051/2011/2092 2062/2022/2103 2063/2023/2104
-f 2063/2023/2104 2052/2012/2093 2051/2011/2092
-f 2052/2012/2093 2063/2023/2104 2064/2024/2105
-f 2064/2024/2105 2053/2013/2094 2052/2012/2093
-f 2053/2013/2094 2064/2024/2105 2065/2025/2106
-f 2065/2025/2106 2054/2014/2095 2053/2013/2094
-f 2055/2015/2096 2066/2026/2107 2067/2027/2108
-f 2067/2027/2108 2056/2016/2097 2055/2015/2096
-f 2056/2016/2097 2067/2027/2108 2068/2028/2109
-f 2068/2028/2109 2057/2017/2098 2056/2016/2097
-f 2057/2017/2098 2068/2028/2109 2069/2029/2110
-f 2069/2029/2110 2058/2018/2099 2057/2017/2098
-f 2058/2018/2099 2069/2029/2110 2070/2030/2111
-f 2070/2030/2111 2059/2019/2100 2058/2018/2099
-f 2059/2019/2100 2070/2030/2111 2071/2031/2112
-f 2071/2031/2112 2060/2020/2101 2059/2019/2100
-f 2060/2020/2101 2071/2031/2112 2072/2032/2113
-f 2072/2032/2113 2061/2021/2102 2060/2020/2101
-f 2061/2021/2102 2072/2032/2113 2073/2033/2114
-f 2073/2033/2114 2062/2022/2103 2061/2021/2102
-f 2062/2022/2103 2073/2033/2114 2074/2034/2115
-f 2074/2034/2115 2063/2023/2104 2062/2022/2103
-f 2063/2023/2104 2074/2034/2115 2075/2035/2116
-f 2075/2035/2116 2064/2024/2105 2063/2023/2104
-f 2064/2024/2105 2075/2035/2116 2076/2036/2117
-f 2076/2036/2117 2065/2025/2106 2064/2024/2105
-f 2066/2026/2107 2077/2037/2118 2078/2038/2119
-f 2078/2038/2119 2067/2027/2108 2066/2026/2107
-f 2067/2027/2108 2078/2038/2119 2079/2039/2120
-f 2079/2039/2120 2068/2028/2109 2067/2027/2108
-f 2068/2028/2109 2079/2039/2120 2080/2040/2121
-f 2080/2040/2121 2069/2029/2110 2068/2028/2109
-f 2069/2029/2110 2080/2040/2121 2081/2041/2122
-f 2081/2041/2122 2070/2030/2111 2069/2029/2110
-f 2070/2030/2111 2081/2041/2122 2082/2042/2123
-f 2082/2042/2123 2071/2031/2112 2070/2030/2111
-f 2071/2031/2112 2082/2042/2123 2083/2043/2124
-f 2083/2043/2124 2072/2032/2113 2071/2031/2112
-f 2072/2032/2113 2083/2043/2124 2084/2044/2125
-f 2084/2044/2125 2073/2033/2114 2072/2032/2113
-f 2073/2033/2114 2084/2044/2125 2085/2045/2126
-f 2085/2045/2126 2074/2034/2115 2073/2033/2114
-f 2074/2034/2115 2085/2045/2126 2086/2046/2127
-f 2086/2046/2127 2075/2035/2116 2074/2034/2115
-f 2075/2035/2116 2086/2046/2127 2087/2047/2128
-f 2087/2047/2128 2076/2036/2117 2075/2035/2116
-f 2077/2037/2118 2088/2026/2129 2089/2048/2130
-f 2089/2048/2130 2078/2038/2119 2077/2037/2118
-f 2078/2038/2119 2089/2048/2130 2090/2049/2131
-f 2090/2049/2131 2079/2039/2120 2078/2038/2119
-f 2079/2039/2120 2090/2049/2131 2091/2050/2132
-f 2091/2050/2132 2080/2040/2121 2079/2039/2120
-f 2080/2040/2121 2091/2050/2132 2092/2051/2133
-f 2092/2051/2133 2081/2041/2122 2080/2040/2121
-f 2081/2041/2122 2092/2051/2133 2093/2052/2134
-f 2093/2052/2134 2082/2042/2123 2081/2041/2122
-f 2082/2042/2123 2093/2052/2134 2094/2053/2135
-f 2094/2053/2135 2083/2043/2124 2082/2042/2123
-f 2083/2043/2124 2094/2053/2135 2095/2054/2136
-f 2095/2054/2136 2084/2044/2125 2083/2043/2124
-f 2084/2044/2125 2095/2054/2136 2096/2055/2137
-f 2096/2055/2137 2085/2045/2126 2084/2044/2125
-f 2085/2045/2126 2096/2055/2137 2097/2056/2138
-f 2097/2056/2138 2086/2046/2127 2085/2045/2126
-f 2086/2046/2127 2097/2056/2138 2098/2057/2139
-f 2098/2057/2139 2087/2047/2128 2086/2046/2127
-f 2088/2026/2129 2099/2015/2140 2100/2058/2141
-f 2100/2058/2141 2089/2048/2130 2088/2026/2129
-f 2089/2048/2130 2100/2058/2141 2101/2059/2142
-f 2101/2059/2142 2090/2049/2131 2089/2048/2130
-f 2090/2049/2131 2101/2059/2142 2102/2060/2143
-f 2102/2060/2143 2091/2050/2132 2090/2049/2131
-f 2091/2050/2132 2102/2060/2143 2103/2061/2144
-f 2103/2061/2144 2092/2051/2133 2091/2050/2132
-f 2092/2051/2133 2103/2061/2144 2104/2062/2145
-f 2104/2062/2145 2093/2052/2134 2092/2051/2133
-f 2093/2052/2134 2104/2062/2145 2105/2063/2146
-f 2105/2063/2146 2094/2053/2135 2093/2052/2134
-f 2094/2053/2135 2105/2063/2146 2106/2064/2147
-f 2106/2064/2147 2095/2054/2136 2094/2053/2135
-f 2095/2054/2136 2106/2064/2147 2107/2065/2148
-f 2107/2065/2148 2096/2055/2137 2095/2054/2136
-f 2096/2055/2137 2107/2065/2148 2108/2066/2149
-f 2108/2066/2149 2097/2056/2138 2096/2055/2137
-f 2097/2056/2138 2108/2066/2149 2109/2067/2150
-f 2109/2067/2150 2098/2057/2139 2097/2056/2138
-f 2099/2015/2140 2110/2004/2151 2111/2068/2152
-f 2111/2068/2152 2100/2058/2141 2099/2015/2140
-f 2100/2058/2141 2111/2068/2152 2112/2069/2153
-f 2112/2069/2153 2101/2059/2142 2100/2058/2141
-f 2101/2059/2142 2112/2069/2153 2113/2070/2154
-f 2113/2070/2154 2102/2060/2143 2101/2059/2142
-f 2102/2060/2143 2113/2070/2154 2114/2071/2155
-f 2114/2071/2155 2103/2061/2144 2102/2060/2143
-f 2103/2061/2144 2114/2071/2155 2115/2072/2156
-f 2115/2072/2156 2104/2062/2145 2103/2061/2144
-f 2104/2062/2145 2115/2072/2156 2116/2073/2157
-f 2116/2073/2157 2105/2063/2146 2104/2062/2145
-f 2105/2063/2146 2116/2073/2157 2117/2074/2158
-f 2117/2074/2158 2106/2064/2147 2105/2063/2146
-f 2106/2064/2147 2117/2074/2158 2118/2075/2159
-f 2118/2075/2159 2107/2065/2148 2106/2064/2147
-f 2107/2065/2148 2118/2075/2159 2119/2076/2160
-f 2119/2076/2160 2108/2066/2149 2107/2065/2148
-f 2108/2066/2149 2119/2076/2160 2120/2077/2161
-f 2120/2077/2161 2109/2067/2150 2108/2066/2149
-f 2110/2004/2151 2121/1983/2162 2122/2078/2163
-f 2122/2078/2163 2111/2068/2152 2110/2004/2151
-f 2111/2068/2152 2122/2078/2163 2123/2079/2164
-f 2123/2079/2164 2112/2069/2153 2111/2068/2152
-f 2112/2069/2153 2123/2079/2164 2124/2080/2165
-f 2124/2080/2165 2113/2070/2154 2112/2069/2153
-f 2113/2070/2154 2124/2080/2165 2125/2081/2166
-f 2125/2081/2166 2114/2071/2155 2113/2070/2154
-f 2114/2071/2155 2125/2081/2166 2126/2082/2167
-f 2126/2082/2167 2115/2072/2156 2114/2071/2155
-f 2115/2072/2156 2126/2082/2167 2127/2083/2168
-f 2127/2083/2168 2116/2073/2157 2115/2072/2156
-f 2116/2073/2157 2127/2083/2168 2128/2084/2169
-f 2128/2084/2169 2117/2074/2158 2116/2073/2157
-f 2117/2074/2158 2128/2084/2169 2129/2085/2170
-f 2129/2085/2170 2118/2075/2159 2117/2074/2158
-f 2118/2075/2159 2129/2085/2170 2130/2086/2171
-f 2130/2086/2171 2119/2076/2160 2118/2075/2159
-f 2119/2076/2160 2130/2086/2171 2131/2087/2172
-f 2131/2087/2172 2120/2077/2161 2119/2076/2160
-f 2121/1983/2162 2132/2088/2173 2133/2089/2174
-f 2133/2089/2174 2122/2078/2163 2121/1983/2162
-f 2122/2078/2163 2133/2089/2174 2134/2090/2175
-f 2134/2090/2175 2123/2079/2164 2122/2078/2163
-f 2123/2079/2164 2134/2090/2175 2135/2091/2176
-f 2135/2091/2176 2124/2080/2165 2123/2079/2164
-f 2124/2080/2165 2135/2091/2176 2136/2092/2177
-f 2136/2092/2177 2125/2081/2166 2124/2080/2165
-f 2125/2081/2166 2136/2092/2177 2137/2093/2178
-f 2137/2093/2178 2126/2082/2167 2125/2081/2166
-f 2126/2082/2167 2137/2093/2178 2138/2094/2179
-f 2138/2094/2179 2127/2083/2168 2126/2082/2167
-f 2127/2083/2168 2138/2094/2179 2139/2095/2180
-f 2139/2095/2180 2128/2084/2169 2127/2083/2168
-f 2128/2084/2169 2139/2095/2180 2140/2096/2181
-f 2140/2096/2181 2129/2085/2170 2128/2084/2169
-f 2129/2085/2170 2140/2096/2181 2141/2097/2182
-f 2141/2097/2182 2130/2086/2171 2129/2085/2170
-f 2130/2086/2171 2141/2097/2182 2142/2098/2183
-f 2142/2098/2183 2131/2087/2172 2130/2086/2171
-f 2132/2088/2173 2143/2099/2184 2144/2100/2185
-f 2144/2100/2185 2133/2089/2174 2132/2088/2173
-f 2133/2089/2174 2144/2100/2185 2145/2101/2186
-f 2145/2101/2186 2134/2090/2175 2133/2089/2174
-f 2134/2090/2175 2145/2101/2186 2146/2102/2187
-f 2146/2102/2187 2135/2091/2176 2134/2090/2175
-f 2135/2091/2176 2146/2102/2187 2147/2103/2188
-f 2147/2103/2188 2136/2092/2177 2135/2091/2176
-f 2136/2092/2177 2147/2103/2188 2148/2104/2189
-f 2148/2104/2189 2137/2093/2178 2136/2092/2177
-f 2137/2093/2178 2148/2104/2189 2149/2105/2190
-f 2149/2105/2190 2138/2094/2179 2137/2093/2178
-f 2138/2094/2179 2149/2105/2190 2150/2106/2191
-f 2150/2106/2191 2139/2095/2180 2138/2094/2179
-f 2139/2095/2180 2150/2106/2191 2151/2107/2192
-f 2151/2107/2192 2140/2096/2181 2139/2095/2180
-f 2140/2096/2181 2151/2107/2192 2152/2108/2193
-f 2152/2108/2193 2141/2097/2182 2140/2096/2181
-f 2141/2097/2182 2152/2108/2193 2153/2109/2194
-f 2153/2109/2194 2142/2098/2183 2141/2097/2182
-f 2143/2099/2184 2154/2110/2195 2155/2111/2196
-f 2155/2111/2196 2144/2100/2185 2143/2099/2184
-f 2144/2100/2185 2155/2111/2196 2156/2112/2197
-f 2156/2112/2197 2145/2101/2186 2144/2100/2185
-f 2145/2101/2186 2156/2112/2197 2157/2113/2198
-f 2157/2113/2198 2146/2102/2187 2145/2101/2186
-f 2146/2102/2187 2157/2113/2198 2158/2114/2199
-f 2158/2114/2199 2147/2103/2188 2146/2102/2187
-f 2147/2103/2188 2158/2114/2199 2159/2115/2200
-f 2159/2115/2200 2148/2104/2189 2147/2103/2188
-f 2148/2104/2189 2159/2115/2200 2160/2116/2201
-f 2160/2116/2201 2149/2105/2190 2148/2104/2189
-f 2149/2105/2190 2160/2116/2201 2161/2117/2202
-f 2161/2117/2202 2150/2106/2191 2149/2105/2190
-f 2150/2106/2191 2161/2117/2202 2162/2118/2203
-f 2162/2118/2203 2151/2107/2192 2150/2106/2191
-f 2151/2107/2192 2162/2118/2203 2163/2119/2204
-f 2163/2119/2204 2152/2108/2193 2151/2107/2192
-f 2152/2108/2193 2163/2119/2204 2164/2120/2205
-f 2164/2120/2205 2153/2109/2194 2152/2108/2193
-f 2154/2110/2195 2165/2121/2206 2166/2122/2207
-f 2166/2122/2207 2155/2111/2196 2154/2110/2195
-f 2155/2111/2196 2166/2122/2207 2167/2123/2208
-f 2167/2123/2208 2156/2112/2197 2155/2111/2196
-f 2156/2112/2197 2167/2123/2208 2168/2124/2209
-f 2168/2124/2209 2157/2113/2198 2156/2112/2197
-f 2157/2113/2198 2168/2124/2209 2169/2125/2210
-f 2169/2125/2210 2158/2114/2199 2157/2113/2198
-f 2158/2114/2199 2169/2125/2210 2170/2126/2211
-f 2170/2126/2211 2159/2115/2200 2158/2114/2199
-f 2159/2115/2200 2170/2126/2211 2171/2127/2212
-f 2171/2127/2212 2160/2116/2201 2159/2115/2200
-f 2160/2116/2201 2171/2127/2212 2172/2128/2213
-f 2172/2128/2213 2161/2117/2202 2160/2116/2201
-f 2161/2117/2202 2172/2128/2213 2173/2129/2214
-f 2173/2129/2214 2162/2118/2203 2161/2117/2202
-f 2162/2118/2203 2173/2129/2214 2174/2130/2215
-f 2174/2130/2215 2163/2119/2204 2162/2118/2203
-f 2163/2119/2204 2174/2130/2215 2175/2131/2216
-f 2175/2131/2216 2164/2120/2205 2163/2119/2204
-f 2165/2121/2206 2176/2132/2217 2177/2133/2218
-f 2177/2133/2218 2166/2122/2207 2165/2121/2206
-f 2166/2122/2207 2177/2133/2218 2178/2134/2219
-f 2178/2134/2219 2167/2123/2208 2166/2122/2207
-f 2167/2123/2208 2178/2134/2219 2179/2135/2220
-f 2179/2135/2220 2168/2124/2209 2167/2123/2208
-f 2168/2124/2209 2179/2135/2220 2180/2136/2221
-f 2180/2136/2221 2169/2125/2210 2168/2124/2209
-f 2169/2125/2210 2180/2136/2221 2181/2137/2222
-f 2181/2137/2222 2170/2126/2211 2169/2125/2210
-f 2170/2126/2211 2181/2137/2222 2182/2138/2223
-f 2182/2138/2223 2171/2127/2212 2170/2126/2211
-f 2171/2127/2212 2182/2138/2223 2183/2139/2224
-f 2183/2139/2224 2172/2128/2213 2171/2127/2212
-f 2172/2128/2213 2183/2139/2224 2184/2140/2225
-f 2184/2140/2225 2173/2129/2214 2172/2128/2213
-f 2173/2129/2214 2184/2140/2225 2185/2141/2226
-f 2185/2141/2226 2174/2130/2215 2173/2129/2214
-f 2174/2130/2215 2185/2141/2226 2186/2142/2227
-f 2186/2142/2227 2175/2131/2216 2174/2130/2215
-f 2176/2132/2217 2187/2143/2228 2188/2144/2229
-f 2188/2144/2229 2177/2133/2218 2176/2132/2217
-f 2177/2133/2218 2188/2144/2229 2189/2145/2230
-f 2189/2145/2230 2178/2134/2219 2177/2133/2218
-f 2178/2134/2219 2189/2145/2230 2190/2146/2231
-f 2190/2146/2231 2179/2135/2220 2178/2134/2219
-f 2179/2135/2220 2190/2146/2231 2191/2147/2232
-f 2191/2147/2232 2180/2136/2221 2179/2135/2220
-f 2180/2136/2221 2191/2147/2232 2192/2148/2233
-f 2192/2148/2233 2181/2137/2222 2180/2136/2221
-f 2181/2137/2222 2192/2148/2233 2193/2149/2234
-f 2193/2149/2234 2182/2138/2223 2181/2137/2222
-f 2182/2138/2223 2193/2149/2234 2194/2150/2235
-f 2194/2150/2235 2183/2139/2224 2182/2138/2223
-f 2183/2139/2224 2194/2150/2235 2195/2151/2236
-f 2195/2151/2236 2184/2140/2225 2183/2139/2224
-f 2184/2140/2225 2195/2151/2236 2196/2152/2237
-f 2196/2152/2237 2185/2141/2226 2184/2140/2225
-f 2185/2141/2226 2196/2152/2237 2197/2153/2238
-f 2197/2153/2238 2186/2142/2227 2185/2141/2226
-f 2187/2143/2228 2198/2132/2239 2199/2154/2240
-f 2199/2154/2240 2188/2144/2229 2187/2143/2228
-f 2188/2144/2229 2199/2154/2240 2200/2155/2241
-f 2200/2155/2241 2189/2145/2230 2188/2144/2229
-f 2189/2145/2230 2200/2155/2241 2201/2156/2242
-f 2201/2156/2242 2190/2146/2231 2189/2145/2230
-f 2190/2146/2231 2201/2156/2242 2202/2157/2243
-f 2202/2157/2243 2191/2147/2232 2190/2146/2231
-f 2191/2147/2232 2202/2157/2243 2203/2158/2244
-f 2203/2158/2244 2192/2148/2233 2191/2147/2232
-f 2192/2148/2233 2203/2158/2244 2204/2159/2245
-f 2204/2159/2245 2193/2149/2234 2192/2148/2233
-f 2193/2149/2234 2204/2159/2245 2205/2160/2246
-f 2205/2160/2246 2194/2150/2235 2193/2149/2234
-f 2194/2150/2235 2205/2160/2246 2206/2161/2247
-f 2206/2161/2247 2195/2151/2236 2194/2150/2235
-f 2195/2151/2236 2206/2161/2247 2207/2162/2248
-f 2207/2162/2248 2196/2152/2237 2195/2151/2236
-f 2196/2152/2237 2207/2162/2248 2208/2163/2249
-f 2208/2163/2249 2197/2153/2238 2196/2152/2237
-f 2198/2132/2239 2209/2121/2250 2210/2164/2251
-f 2210/2164/2251 2199/2154/2240 2198/2132/2239
-f 2199/2154/2240 2210/2164/2251 2211/2165/2252
-f 2211/2165/2252 2200/2155/2241 2199/2154/2240
-f 2200/2155/2241 2211/2165/2252 2212/2166/2253
-f 2212/2166/2253 2201/2156/2242 2200/2155/2241
-f 2201/2156/2242 2212/2166/2253 2213/2167/2254
-f 2213/2167/2254 2202/2157/2243 2201/2156/2242
-f 2202/2157/2243 2213/2167/2254 2214/2168/2255
-f 2214/2168/2255 2203/2158/2244 2202/2157/2243
-f 2203/2158/2244 2214/2168/2255 2215/2169/2256
-f 2215/2169/2256 2204/2159/2245 2203/2158/2244
-f 2204/2159/2245 2215/2169/2256 2216/2170/2257
-f 2216/2170/2257 2205/2160/2246 2204/2159/2245
-f 2205/2160/2246 2216/2170/2257 2217/2171/2258
-f 2217/2171/2258 2206/2161/2247 2205/2160/2246
-f 2206/2161/2247 2217/2171/2258 2218/2172/2259
-f 2218/2172/2259 2207/2162/2248 2206/2161/2247
-f 2207/2162/2248 2218/2172/2259 2219/2173/2260
-f 2219/2173/2260 2208/2163/2249 2207/2162/2248
-f 2209/2121/2250 2220/2110/2261 2221/2174/2262
-f 2221/2174/2262 2210/2164/2251 2209/2121/2250
-f 2210/2164/2251 2221/2174/2262 2222/2175/2263
-f 2222/2175/2263 2211/2165/2252 2210/2164/2251
-f 2211/2165/2252 2222/2175/2263 2223/2176/2264
-f 2223/2176/2264 2212/2166/2253 2211/2165/2252
-f 2212/2166/2253 2223/2176/2264 2224/2177/2265
-f 2224/2177/2265 2213/2167/2254 2212/2166/2253
-f 2213/2167/2254 2224/2177/2265 2225/2178/2266
-f 2225/2178/2266 2214/2168/2255 2213/2167/2254
-f 2214/2168/2255 2225/2178/2266 2226/2179/2267
-f 2226/2179/2267 2215/2169/2256 2214/2168/2255
-f 2215/2169/2256 2226/2179/2267 2227/2180/2268
-f 2227/2180/2268 2216/2170/2257 2215/2169/2256
-f 2216/2170/2257 2227/2180/2268 2228/2181/2269
-f 2228/2181/2269 2217/2171/2258 2216/2170/2257
-f 2217/2171/2258 2228/2181/2269 2229/2182/2270
-f 2229/2182/2270 2218/2172/2259 2217/2171/2258
-f 2218/2172/2259 2229/2182/2270 2230/2183/2271
-f 2230/2183/2271 2219/2173/2260 2218/2172/2259
-f 2220/2110/2261 2231/2184/2272 2232/2185/2273
-f 2232/2185/2273 2221/2174/2262 2220/2110/2261
-f 2221/2174/2262 2232/2185/2273 2233/2186/2274
-f 2233/2186/2274 2222/2175/2263 2221/2174/2262
-f 2222/2175/2263 2233/2186/2274 2234/2187/2275
-f 2234/2187/2275 2223/2176/2264 2222/2175/2263
-f 2223/2176/2264 2234/2187/2275 2235/2188/2276
-f 2235/2188/2276 2224/2177/2265 2223/2176/2264
-f 2224/2177/2265 2235/2188/2276 2236/2189/2277
-f 2236/2189/2277 2225/2178/2266 2224/2177/2265
-f 2225/2178/2266 2236/2189/2277 2237/2190/2278
-f 2237/2190/2278 2226/2179/2267 2225/2178/2266
-f 2226/2179/2267 2237/2190/2278 2238/2191/2279
-f 2238/2191/2279 2227/2180/2268 2226/2179/2267
-f 2227/2180/2268 2238/2191/2279 2239/2192/2280
-f 2239/2192/2280 2228/2181/2269 2227/2180/2268
-f 2228/2181/2269 2239/2192/2280 2240/2193/2281
-f 2240/2193/2281 2229/2182/2270 2228/2181/2269
-f 2229/2182/2270 2240/2193/2281 2241/2194/2282
-f 2241/2194/2282 2230/2183/2271 2229/2182/2270
-f 2231/2184/2272 2022/1982/2063 2025/1985/2066
-f 2025/1985/2066 2232/2185/2273 2231/2184/2272
-f 2232/2185/2273 2025/1985/2066 2027/1987/2068
-f 2027/1987/2068 2233/2186/2274 2232/2185/2273
-f 2233/2186/2274 2027/1987/2068 2029/1989/2070
-f 2029/1989/2070 2234/2187/2275 2233/2186/2274
-f 2234/2187/2275 2029/1989/2070 2031/1991/2072
-f 2031/1991/2072 2235/2188/2276 2234/2187/2275
-f 2235/2188/2276 2031/1991/2072 2033/1993/2074
-f 2033/1993/2074 2236/2189/2277 2235/2188/2276
-f 2236/2189/2277 2033/1993/2074 2035/1995/2076
-f 2035/1995/2076 2237/2190/2278 2236/2189/2277
-f 2237/2190/2278 2035/1995/2076 2037/1997/2078
-f 2037/1997/2078 2238/2191/2279 2237/2190/2278
-f 2238/2191/2279 2037/1997/2078 2039/1999/2080
-f 2039/1999/2080 2239/2192/2280 2238/2191/2279
-f 2239/2192/2280 2039/1999/2080 2041/2001/2082
-f 2041/2001/2082 2240/2193/2281 2239/2192/2280
-f 2240/2193/2281 2041/2001/2082 2043/2003/2084
-f 2043/2003/2084 2241/2194/2282 2240/2193/2281
-f 2043/2003/2084 2042/2002/2083 2242/2195/2283
-f 2242/2195/2283 2243/2196/2284 2043/2003/2084
-f 2243/2196/2284 2242/2195/2283 2244/2197/2285
-f 2244/2197/2285 2245/2198/2286 2243/2196/2284
-f 2245/2198/2286 2244/2197/2285 2246/2199/2287
-f 2246/2199/2287 2247/2200/2288 2245/2198/2286
-f 2247/2200/2288 2246/2199/2287 2248/2201/2289
-f 2248/2201/2289 2249/2202/2290 2247/2200/2288
-f 2249/2202/2290 2248/2201/2289 2250/2203/2291
-f 2250/2203/2291 2251/2204/2292 2249/2202/2290
-f 2251/2204/2292 2250/2203/2291 2252/2205/2293
-f 2252/2205/2293 2253/2206/2294 2251/2204/2292
-f 2253/2206/2295 2252/2205/2296 2254/2207/2297
-f 2254/2207/2297 2255/2208/2298 2253/2206/2295
-f 2255/2208/2299 2254/2207/2300 2256/2209/2301
-f 2256/2209/2301 2257/2210/2302 2255/2208/2299
-f 2257/2210/2302 2256/2209/2301 2258/2211/2303
-f 2258/2211/2303 2259/2212/2304 2257/2210/2302
-f 2259/2212/2304 2258/2211/2303 2260/2213/2305
-f 2260/2213/2305 2261/2214/2306 2259/2212/2304
-f 2042/2002/2083 2054/2014/2095 2262/2215/2307
-f 2262/2215/2307 2242/2195/2283 2042/2002/2083
-f 2242/2195/2283 2262/2215/2307 2263/2216/2308
-f 2263/2216/2308 2244/2197/2285 2242/2195/2283
-f 2244/2197/2285 2263/2216/2308 2264/2217/2309
-f 2264/2217/2309 2246/2199/2287 2244/2197/2285
-f 2246/2199/2287 2264/2217/2309 2265/2218/2310
-f 2265/2218/2310 2248/2201/2289 2246/2199/2287
-f 2248/2201/2289 2265/2218/2310 2266/2219/2311
-f 2266/2219/2311 2250/2203/2291 2248/2201/2289
-f 2250/2203/2291 2266/2219/2311 2267/2220/2312
-f 2267/2220/2312 2252/2205/2293 2250/2203/2291
-f 2252/2205/2296 2267/2220/2312 2268/2221/2313
-f 2268/2221/2313 2254/2207/2297 2252/2205/2296
-f 2254/2207/2300 2268/2221/2313 2269/2222/2314
-f 2269/2222/2314 2256/2209/2301 2254/2207/2300
-f 2256/2209/2301 2269/2222/2314 2270/2223/2315
-f 2270/2223/2315 2258/2211/2303 2256/2209/2301
-f 2258/2211/2303 2270/2223/2315 2271/2224/2316
-f 2271/2224/2316 2260/2213/2305 2258/2211/2303
-f 2054/2014/2095 2065/2025/2106 2272/2225/2317
-f 2272/2225/2317 2262/2215/2307 2054/2014/2095
-f 2262/2215/2307 2272/2225/2317 2273/2226/2318
-f 2273/2226/2318 2263/2216/2308 2262/2215/2307
-f 2263/2216/2308 2273/2226/2318 2274/2227/2319
-f 2274/2227/2319 2264/2217/2309 2263/2216/2308
-f 2264/2217/2309 2274/2227/2319 2275/2228/2320
-f 2275/2228/2320 2265/2218/2310 2264/2217/2309
-f 2265/2218/2310 2275/2228/2320 2276/2229/2321
-f 2276/2229/2321 2266/2219/2311 2265/2218/2310
-f 2266/2219/2311 2276/2229/2321 2277/2230/2322
-f 2277/2230/2322 2267/2220/2312 2266/2219/2311
-f 2267/2220/2312 2277/2230/2322 2278/2231/2323
-f 2278/2231/2323 2268/2221/2313 2267/2220/2312
-f 2268/2221/2313 2278/2231/2323 2279/2232/2324
-f 2279/2232/2324 2269/2222/2314 2268/2221/2313
-f 2269/2222/2314 2279/2232/2324 2280/2233/2325
-f 2280/2233/2325 2270/2223/2315 2269/2222/2314
-f 2270/2223/2315 2280/2233/2325 2281/2234/2326
-f 2281/2234/2326 2271/2224/2316 2270/2223/2315
-f 2065/2025/2106 2076/2036/2117 2282/2235/2327
-f 2282/2235/2327 2272/2225/2317 2065/2025/2106
-f 2272/2225/2317 2282/2235/2327 2283/2236/2328
-f 2283/2236/2328 2273/2226/2318 2272/2225/2317
-f 2273/2226/2318 2283/2236/2328 2284/2237/2329
-f 2284/2237/2329 2274/2227/2319 2273/2226/2318
-f 2274/2227/2319 2284/2237/2329 2285/2238/2330
-f 2285/2238/2330 2275/2228/2320 2274/2227/2319
-f 2275/2228/2320 2285/2238/2330 2286/2239/2331
-f 2286/2239/2331 2276/2229/2321 2275/2228/2320
-f 2276/2229/2321 2286/2239/2331 2287/2240/2332
-f 2287/2240/2332 2277/2230/2322 2276/2229/2321
-f 2277/2230/2322 2287/2240/2332 2288/2241/2333
-f 2288/2241/2333 2278/2231/2323 2277/2230/2322
-f 2278/2231/2323 2288/2241/2333 2289/2242/2334
-f 2289/2242/2334 2279/2232/2324 2278/2231/2323
-f 2279/2232/2324 2289/2242/2334 2290/2243/2335
-f 2290/2243/2335 2280/2233/2325 2279/2232/2324
-f 2280/2233/2325 2290/2243/2335 2291/2244/2336
-f 2291/2244/2336 2281/2234/2326 2280/2233/2325
-f 2076/2036/2117 2087/2047/2128 2292/2245/2337
-f 2292/2245/2337 2282/2235/2327 2076/2036/2117
-f 2282/2235/2327 2292/2245/2337 2293/2246/2338
-f 2293/2246/2338 2283/2236/2328 2282/2235/2327
-f 2283/2236/2328 2293/2246/2338 2294/2247/2339
-f 2294/2247/2339 2284/2237/2329 2283/2236/2328
-f 2284/2237/2329 2294/2247/2339 2295/2248/2340
-f 2295/2248/2340 2285/2238/2330 2284/2237/2329
-f 2285/2238/2330 2295/2248/2340 2296/2249/2341
-f 2296/2249/2341 2286/2239/2331 2285/2238/2330
-f 2286/2239/2331 2296/2249/2341 2297/2250/2342
-f 2297/2250/2342 2287/2240/2332 2286/2239/2331
-f 2287/2240/2332 2297/2250/2342 2298/2251/2343
-f 2298/2251/2343 2288/2241/2333 2287/2240/2332
-f 2288/2241/2333 2298/2251/2343 2299/2252/2344
-f 2299/2252/2344 2289/2242/2334 2288/2241/2333
-f 2289/2242/2334 2299/2252/2344 2300/2253/2345
-f 2300/2253/2345 2290/2243/2335 2289/2242/2334
-f 2290/2243/2335 2300/2253/2345 2301/2254/2346
-f 2301/2254/2346 2291/2244/2336 2290/2243/2335
-f 2087/2047/2128 2098/2057/2139 2302/2255/2347
-f 2302/2255/2347 2292/2245/2337 2087/2047/2128
-f 2292/2245/2337 2302/2255/2347 2303/2256/2348
-f 2303/2256/2348 2293/2246/2338 2292/2245/2337
-f 2293/2246/2338 2303/2256/2348 2304/2257/2349
-f 2304/2257/2349 2294/2247/2339 2293/2246/2338
-f 2294/2247/2339 2304/2257/2349 2305/2258/2350
-f 2305/2258/2350 2295/2248/2340 2294/2247/2339
-f 2295/2248/2340 2305/2258/2350 2306/2259/2351
-f 2306/2259/2351 2296/2249/2341 2295/2248/2340
-f 2296/2249/2341 2306/2259/2351 2307/2260/2352
-f 2307/2260/2352 2297/2250/2342 2296/2249/2341
-f 2297/2250/2342 2307/2260/2352 2308/2261/2353
-f 2308/2261/2353 2298/2251/2343 2297/2250/2342
-f 2298/2251/2343 2308/2261/2353 2309/2262/2354
-f 2309/2262/2354 2299/2252/2344 2298/2251/2343
-f 2299/2252/2344 2309/2262/2354 2310/2263/2355
-f 2310/2263/2355 2300/2253/2345 2299/2252/2344
-f 2300/2253/2345 2310/2263/2355 2311/2264/2356
-f 2311/2264/2356 2301/2254/2346 2300/2253/2345
-f 2098/2057/2139 2109/2067/2150 2312/2265/2357
-f 2312/2265/2357 2302/2255/2347 2098/2057/2139
-f 2302/2255/2347 2312/2265/2357 2313/2266/2358
-f 2313/2266/2358 2303/2256/2348 2302/2255/2347
-f 2303/2256/2348 2313/2266/2358 2314/2267/2359
-f 2314/2267/2359 2304/2257/2349 2303/2256/2348
-f 2304/2257/2349 2314/2267/2359 2315/2268/2360
-f 2315/2268/2360 2305/2258/2350 2304/2257/2349
-f 2305/2258/2350 2315/2268/2360 2316/2269/2361
-f 2316/2269/2361 2306/2259/2351 2305/2258/2350
-f 2306/2259/2351 2316/2269/2361 2317/2270/2362
-f 2317/2270/2362 2307/2260/2352 2306/2259/2351
-f 2307/2260/2352 2317/2270/2362 2318/2271/2363
-f 2318/2271/2363 2308/2261/2353 2307/2260/2352
-f 2308/2261/2353 2318/2271/2363 2319/2272/2364
-f 2319/2272/2364 2309/2262/2354 2308/2261/2353
-f 2309/2262/2354 2319/2272/2364 2320/2273/2365
-f 2320/2273/2365 2310/2263/2355 2309/2262/2354
-f 2310/2263/2355 2320/2273/2365 2321/2274/2366
-f 2321/2274/2366 2311/2264/2356 2310/2263/2355
-f 2109/2067/2150 2120/2077/2161 2322/2275/2367
-f 2322/2275/2367 2312/2265/2357 2109/2067/2150
-f 2312/2265/2357 2322/2275/2367 2323/2276/2368
-f 2323/2276/2368 2313/2266/2358 2312/2265/2357
-f 2313/2266/2358 2323/2276/2368 2324/2277/2369
-f 2324/2277/2369 2314/2267/2359 2313/2266/2358
-f 2314/2267/2359 2324/2277/2369 2325/2278/2370
-f 2325/2278/2370 2315/2268/2360 2314/2267/2359
-f 2315/2268/2360 2325/2278/2371 2326/2279/2372
-f 2326/2279/2372 2316/2269/2361 2315/2268/2360
-f 2316/2269/2361 2326/2279/2372 2327/2280/2373
-f 2327/2280/2373 2317/2270/2362 2316/2269/2361
-f 2317/2270/2362 2327/2280/2373 2328/2281/2374
-f 2328/2281/2374 2318/2271/2363 2317/2270/2362
-f 2318/2271/2363 2328/2281/2374 2329/2282/2375
-f 2329/2282/2375 2319/2272/2364 2318/2271/2363
-f 2319/2272/2364 2329/2282/2375 2330/2283/2376
-f 2330/2283/2376 2320/2273/2365 2319/2272/2364
-f 2320/2273/2365 2330/2283/2376 2331/2284/2377
-f 2331/2284/2377 2321/2274/2366 2320/2273/2365
-f 2120/2077/2161 2131/2087/2172 2332/2285/2378
-f 2332/2285/2378 2322/2275/2367 2120/2077/2161
-f 2322/2275/2367 2332/2285/2378 2333/2286/2379
-f 2333/2286/2379 2323/2276/2368 2322/2275/2367
-f 2323/2276/2368 2333/2286/2379 2334/2287/2380
-f 2334/2287/2380 2324/2277/2369 2323/2276/2368
-f 2324/2277/2369 2334/2287/2380 2335/2288/2381
-f 2335/2288/2381 2325/2278/2370 2324/2277/2369
-f 2325/2278/2371 2335/2288/2382 2336/2289/2383
-f 2336/2289/2383 2326/2279/2372 2325/2278/2371
-f 2326/2279/2372 2336/2289/2383 2337/2290/2384
-f 2337/2290/2384 2327/2280/2373 2326/2279/2372
-f 2327/2280/2373 2337/2290/2384 2338/2291/2385
-f 2338/2291/2385 2328/2281/2374 2327/2280/2373
-f 2328/2281/2374 2338/2291/2385 2339/2292/2386
-f 2339/2292/2386 2329/2282/2375 2328/2281/2374
-f 2329/2282/2375 2339/2292/2386 2340/2293/2387
-f 2340/2293/2387 2330/2283/2376 2329/2282/2375
-f 2330/2283/2376 2340/2293/2387 2341/2294/2388
-f 2341/2294/2388 2331/2284/2377 2330/2283/2376
-f 2131/2087/2172 2142/2098/2183 2342/2295/2389
-f 2342/2295/2389 2332/2285/2378 2131/2087/2172
-f 2332/2285/2378 2342/2295/2389 2343/2296/2390
-f 2343/2296/2390 2333/2286/2379 2332/2285/2378
-f 2333/2286/2379 2343/2296/2390 2344/2297/2391
-f 2344/2297/2391 2334/2287/2380 2333/2286/2379
-f 2334/2287/2380 2344/2297/2391 2345/2298/2392
-f 2345/2298/2392 2335/2288/2381 2334/2287/2380
-f 2335/2288/2382 2345/2298/2393 2346/2299/2394
-f 2346/2299/2394 2336/2289/2383 2335/2288/2382
-f 2336/2289/2383 2346/2299/2395 2347/2300/2396
-f 2347/2300/2396 2337/2290/2384 2336/2289/2383
-f 2337/2290/2384 2347/2300/2396 2348/2301/2397
-f 2348/2301/2397 2338/2291/2385 2337/2290/2384
-f 2338/2291/2385 2348/2301/2397 2349/2302/2398
-f 2349/2302/2398 2339/2292/2386 2338/2291/2385
-f 2339/2292/2386 2349/2302/2398 2350/2303/2399
-f 2350/2303/2399 2340/2293/2387 2339/2292/2386
-f 2340/2293/2387 2350/2303/2399 2351/2304/2400
-f 2351/2304/2400 2341/2294/2388 2340/2293/2387
-f 2142/2098/2183 2153/2109/2194 2352/2305/2401
-f 2352/2305/2401 2342/2295/2389 2142/2098/2183
-f 2342/2295/2389 2352/2305/2401 2353/2306/2402
-f 2353/2306/2402 2343/2296/2390 2342/2295/2389
-f 2343/2296/2390 2353/2306/2402 2354/2307/2403
-f 2354/2307/2403 2344/2297/2391 2343/2296/2390
-f 2344/2297/2391 2354/2307/2403 2355/2308/2404
-f 2355/2308/2404 2345/2298/2392 2344/2297/2391
-f 2345/2298/2393 2355/2308/2405 2356/2309/2406
-f 2356/2309/2406 2346/2299/2394 2345/2298/2393
-f 2346/2299/2395 2356/2309/2407 2357/2310/2408
-f 2357/2310/2408 2347/2300/2396 2346/2299/2395
-f 2347/2300/2396 2357/2310/2408 2358/2311/2409
-f 2358/2311/2409 2348/2301/2397 2347/2300/2396
-f 2348/2301/2397 2358/2311/2409 2359/2312/2410
-f 2359/2312/2410 2349/2302/2398 2348/2301/2397
-f 2349/2302/2398 2359/2312/2410 2360/2313/2411
-f 2360/2313/2411 2350/2303/2399 2349/2302/2398
-f 2350/2303/2399 2360/2313/2411 2361/2314/2412
-f 2361/2314/2412 2351/2304/2400 2350/2303/2399
-f 2153/2109/2194 2164/2120/2205 2362/2315/2413
-f 2362/2315/2413 2352/2305/2401 2153/2109/2194
-f 2352/2305/2401 2362/2315/2413 2363/2316/2414
-f 2363/2316/2414 2353/2306/2402 2352/2305/2401
-f 2353/2306/2402 2363/2316/2414 2364/2317/2415
-f 2364/2317/2415 2354/2307/2403 2353/2306/2402
-f 2354/2307/2403 2364/2317/2415 2365/2318/2416
-f 2365/2318/2416 2355/2308/2404 2354/2307/2403
-f 2355/2308/2405 2365/2318/2417 2366/2319/2418
-f 2366/2319/2418 2356/2309/2406 2355/2308/2405
-f 2356/2309/2407 2366/2319/2418 2367/2320/2419
-f 2367/2320/2419 2357/2310/2408 2356/2309/2407
-f 2357/2310/2408 2367/2320/2419 2368/2321/2420
-f 2368/2321/2420 2358/2311/2409 2357/2310/2408
-f 2358/2311/2409 2368/2321/2420 2369/2322/2421
-f 2369/2322/2421 2359/2312/2410 2358/2311/2409
-f 2359/2312/2410 2369/2322/2421 2370/2323/2422
-f 2370/2323/2422 2360/2313/2411 2359/2312/2410
-f 2360/2313/2411 2370/2323/2422 2371/2324/2423
-f 2371/2324/2423 2361/2314/2412 2360/2313/2411
-f 2164/2120/2205 2175/2131/2216 2372/2325/2424
-f 2372/2325/2424 2362/2315/2413 2164/2120/2205
-f 2362/2315/2413 2372/2325/2424 2373/2326/2425
-f 2373/2326/2425 2363/2316/2414 2362/2315/2413
-f 2363/2316/2414 2373/2326/2425 2374/2327/2426
-f 2374/2327/2426 2364/2317/2415 2363/2316/2414
-f 2364/2317/2415 2374/2327/2426 2375/2328/2427
-f 2375/2328/2427 2365/2318/2416 2364/2317/2415
-f 2365/2318/2417 2375/2328/2428 2376/2329/2429
-f 2376/2329/2429 2366/2319/2418 2365/2318/2417
-f 2366/2319/2418 2376/2329/2429 2377/2330/2430
-f 2377/2330/2430 2367/2320/2419 2366/2319/2418
-f 2367/2320/2419 2377/2330/2430 2378/2331/2431
-f 2378/2331/2431 2368/2321/2420 2367/2320/2419
-f 2368/2321/2420 2378/2331/2431 2379/2332/2432
-f 2379/2332/2432 2369/2322/2421 2368/2321/2420
-f 2369/2322/2421 2379/2332/2432 2380/2333/2433
-f 2380/2333/2433 2370/2323/2422 2369/2322/2421
-f 2370/2323/2422 2380/2333/2433 2381/2334/2434
-f 2381/2334/2434 2371/2324/2423 2370/2323/2422
-f 2175/2131/2216 2186/2142/2227 2382/2335/2435
-f 2382/2335/2435 2372/2325/2424 2175/2131/2216
-f 2372/2325/2424 2382/2335/2435 2383/2336/2436
-f 2383/2336/2436 2373/2326/2425 2372/2325/2424
-f 2373/2326/2425 2383/2336/2436 2384/2337/2437
-f 2384/2337/2437 2374/2327/2426 2373/2326/2425
-f 2374/2327/2426 2384/2337/2437 2385/2338/2438
-f 2385/2338/2438 2375/2328/2427 2374/2327/2426
-f 2375/2328/2428 2385/2338/2438 2386/2339/2439
-f 2386/2339/2439 2376/2329/2429 2375/2328/2428
-f 2376/2329/2429 2386/2339/2439 2387/2340/2440
-f 2387/2340/2440 2377/2330/2430 2376/2329/2429
-f 2377/2330/2430 2387/2340/2440 2388/2341/2441
-f 2388/2341/2441 2378/2331/2431 2377/2330/2430
-f 2378/2331/2431 2388/2341/2441 2389/2342/2442
-f 2389/2342/2442 2379/2332/2432 2378/2331/2431
-f 2379/2332/2432 2389/2342/2442 2390/2343/2443
-f 2390/2343/2443 2380/2333/2433 2379/2332/2432
-f 2380/2333/2433 2390/2343/2443 2391/2344/2444
-f 2391/2344/2444 2381/2334/2434 2380/2333/2433
-f 2186/2142/2227 2197/2153/2238 2392/2345/2445
-f 2392/2345/2445 2382/2335/2435 2186/2142/2227
-f 2382/2335/2435 2392/2345/2445 2393/2346/2446
-f 2393/2346/2446 2383/2336/2436 2382/2335/2435
-f 2383/2336/2436 2393/2346/2446 2394/2347/2447
-f 2394/2347/2447 2384/2337/2437 2383/2336/2436
-f 2384/2337/2437 2394/2347/2447 2395/2348/2448
-f 2395/2348/2448 2385/2338/2438 2384/2337/2437
-f 2385/2338/2438 2395/2348/2448 2396/2349/2449
-f 2396/2349/2449 2386/2339/2439 2385/2338/2438
-f 2386/2339/2439 2396/2349/2449 2397/2350/2450
-f 2397/2350/2450 2387/2340/2440 2386/2339/2439
-f 2387/2340/2440 2397/2350/2450 2398/2351/2451
-f 2398/2351/2451 2388/2341/2441 2387/2340/2440
-f 2388/2341/2441 2398/2351/2451 2399/2352/2452
-f 2399/2352/2452 2389/2342/2442 2388/2341/2441
-f 2389/2342/2442 2399/2352/2452 2400/2353/2453
-f 2400/2353/2453 2390/2343/2443 2389/2342/2442
-f 2390/2343/2443 2400/2353/2453 2401/2354/2454
-f 2401/2354/2454 2391/2344/2444 2390/2343/2443
-f 2197/2153/2238 2208/2163/2249 2402/2355/2455
-f 2402/2355/2455 2392/2345/2445 2197/2153/2238
-f 2392/2345/2445 2402/2355/2455 2403/2356/2456
-f 2403/2356/2456 2393/2346/2446 2392/2345/2445
-f 2393/2346/2446 2403/2356/2456 2404/2357/2457
-f 2404/2357/2457 2394/2347/2447 2393/2346/2446
-f 2394/2347/2447 2404/2357/2457 2405/2358/2458
-f 2405/2358/2458 2395/2348/2448 2394/2347/2447
-f 2395/2348/2448 2405/2358/2458 2406/2359/2459
-f 2406/2359/2459 2396/2349/2449 2395/2348/2448
-f 2396/2349/2449 2406/2359/2459 2407/2360/2460
-f 2407/2360/2460 2397/2350/2450 2396/2349/2449
-f 2397/2350/2450 2407/2360/2460 2408/2361/2461
-f 2408/2361/2461 2398/2351/2451 2397/2350/2450
-f 2398/2351/2451 2408/2361/2461 2409/2362/2462
-f 2409/2362/2462 2399/2352/2452 2398/2351/2451
-f 2399/2352/2452 2409/2362/2462 2410/2363/2463
-f 2410/2363/2463 2400/2353/2453 2399/2352/2452
-f 2400/2353/2453 2410/2363/2463 2411/2364/2464
-f 2411/2364/2464 2401/2354/2454 2400/2353/2453
-f 2208/2163/2249 2219/2173/2260 2412/2365/2465
-f 2412/2365/2465 2402/2355/2455 2208/2163/2249
-f 2402/2355/2455 2412/2365/2465 2413/2366/2466
-f 2413/2366/2466 2403/2356/2456 2402/2355/2455
-f 2403/2356/2456 2413/2366/2466 2414/2367/2467
-f 2414/2367/2467 2404/2357/2457 2403/2356/2456
-f 2404/2357/2457 2414/2367/2467 2415/2368/2468
-f 2415/2368/2468 2405/2358/2458 2404/2357/2457
-f 2405/2358/2458 2415/2368/2468 2416/2369/2469
-f 2416/2369/2469 2406/2359/2459 2405/2358/2458
-f 2406/2359/2459 2416/2369/2469 2417/2370/2470
-f 2417/2370/2470 2407/2360/2460 2406/2359/2459
-f 2407/2360/2460 2417/2370/2470 2418/2371/2471
-f 2418/2371/2471 2408/2361/2461 2407/2360/2460
-f 2408/2361/2461 2418/2371/2471 2419/2372/2472
-f 2419/2372/2472 2409/2362/2462 2408/2361/2461
-f 2409/2362/2462 2419/2372/2472 2420/2373/2473
-f 2420/2373/2473 2410/2363/2463 2409/2362/2462
-f 2410/2363/2463 2420/2373/2473 2421/2374/2474
-f 2421/2374/2474 2411/2364/2464 2410/2363/2463
-f 2219/2173/2260 2230/2183/2271 2422/2375/2475
-f 2422/2375/2475 2412/2365/2465 2219/2173/2260
-f 2412/2365/2465 2422/2375/2475 2423/2376/2476
-f 2423/2376/2476 2413/2366/2466 2412/2365/2465
-f 2413/2366/2466 2423/2376/2476 2424/2377/2477
-f 2424/2377/2477 2414/2367/2467 2413/2366/2466
-f 2414/2367/2467 2424/2377/2477 2425/2378/2478
-f 2425/2378/2478 2415/2368/2468 2414/2367/2467
-f 2415/2368/2468 2425/2378/2478 2426/2379/2479
-f 2426/2379/2479 2416/2369/2469 2415/2368/2468
-f 2416/2369/2469 2426/2379/2479 2427/2380/2480
-f 2427/2380/2480 2417/2370/2470 2416/2369/2469
-f 2417/2370/2470 2427/2380/2480 2428/2381/2481
-f 2428/2381/2481 2418/2371/2471 2417/2370/2470
-f 2418/2371/2471 2428/2381/2481 2429/2382/2482
-f 2429/2382/2482 2419/2372/2472 2418/2371/2471
-f 2419/2372/2472 2429/2382/2482 2430/2383/2483
-f 2430/2383/2483 2420/2373/2473 2419/2372/2472
-f 2420/2373/2473 2430/2383/2483 2431/2384/2484
-f 2431/2384/2484 2421/2374/2474 2420/2373/2473
-f 2230/2183/2271 2241/2194/2282 2432/2385/2485
-f 2432/2385/2485 2422/2375/2475 2230/2183/2271
-f 2422/2375/2475 2432/2385/2485 2433/2386/2486
-f 2433/2386/2486 2423/2376/2476 2422/2375/2475
-f 2423/2376/2476 2433/2386/2486 2434/2387/2487
-f 2434/2387/2487 2424/2377/2477 2423/2376/2476
-f 2424/2377/2477 2434/2387/2487 2435/2388/2488
-f 2435/2388/2488 2425/2378/2478 2424/2377/2477
-f 2425/2378/2478 2435/2388/2488 2436/2389/2489
-f 2436/2389/2489 2426/2379/2479 2425/2378/2478
-f 2426/2379/2479 2436/2389/2489 2437/2390/2490
-f 2437/2390/2490 2427/2380/2480 2426/2379/2479
-f 2427/2380/2480 2437/2390/2491 2438/2391/2492
-f 2438/2391/2492 2428/2381/2481 2427/2380/2480
-f 2428/2381/2481 2438/2391/2493 2439/2392/2494
-f 2439/2392/2494 2429/2382/2482 2428/2381/2481
-f 2429/2382/2482 2439/2392/2494 2440/2393/2495
-f 2440/2393/2495 2430/2383/2483 2429/2382/2482
-f 2430/2383/2483 2440/2393/2495 2441/2394/2496
-f 2441/2394/2496 2431/2384/2484 2430/2383/2483
-f 2241/2194/2282 2043/2003/2084 2243/2196/2284
-f 2243/2196/2284 2432/2385/2485 2241/2194/2282
-f 2432/2385/2485 2243/2196/2284 2245/2198/2286
-f 2245/2198/2286 2433/2386/2486 2432/2385/2485
-f 2433/2386/2486 2245/2198/2286 2247/2200/2288
-f 2247/2200/2288 2434/2387/2487 2433/2386/2486
-f 2434/2387/2487 2247/2200/2288 2249/2202/2290
-f 2249/2202/2290 2435/2388/2488 2434/2387/2487
-f 2435/2388/2488 2249/2202/2290 2251/2204/2292
-f 2251/2204/2292 2436/2389/2489 2435/2388/2488
-f 2436/2389/2489 2251/2204/2292 2253/2206/2294
-f 2253/2206/2294 2437/2390/2490 2436/2389/2489
-f 2437/2390/2491 2253/2206/2295 2255/2208/2298
-f 2255/2208/2298 2438/2391/2492 2437/2390/2491
-f 2438/2391/2493 2255/2208/2299 2257/2210/2302
-f 2257/2210/2302 2439/2392/2494 2438/2391/2493
-f 2439/2392/2494 2257/2210/2302 2259/2212/2304
-f 2259/2212/2304 2440/2393/2495 2439/2392/2494
-f 2440/2393/2495 2259/2212/2304 2261/2214/2306
-f 2261/2214/2306 2441/2394/2496 2440/2393/2495
-f 2442/1219/2497 2442/1219/2497 2443/2395/2498
-f 2443/2395/2498 2444/2396/2499 2442/1219/2497
-f 2444/2396/2499 2443/2395/2498 2445/2397/2500
-f 2445/2397/2500 2446/2398/2501 2444/2396/2499
-f 2446/2398/2501 2445/2397/2500 2447/2399/2502
-f 2447/2399/2502 2448/2400/2503 2446/2398/2501
-f 2448/2400/2504 2447/2399/2505 2449/2401/2506
-f 2449/2401/2506 2450/2402/2507 2448/2400/2504
-f 2450/2402/2507 2449/2401/2506 2451/2403/2508
-f 2451/2403/2508 2452/2404/2509 2450/2402/2507
-f 2452/2404/2509 2451/2403/2508 2453/2405/2510
-f 2453/2405/2510 2454/2406/2511 2452/2404/2509
-f 2454/2406/2511 2453/2405/2510 2455/2407/2512
-f 2455/2407/2512 2456/2408/2513 2454/2406/2511
-f 2456/2408/2513 2455/2407/2512 2457/2409/2514
-f 2457/2409/2514 2458/2410/2515 2456/2408/2513
-f 2458/2410/2515 2457/2409/2514 2459/2411/2516
-f 2459/2411/2516 2460/2412/2517 2458/2410/2515
-f 2460/2412/2518 2459/2411/2519 2461/2413/2520
-f 2461/2413/2520 2462/2414/2521 2460/2412/2518
-f 2442/1219/2497 2442/1219/2497 2463/2415/2522
-f 2463/2415/2522 2443/2395/2498 2442/1219/2497
-f 2443/2395/2498 2463/2415/2522 2464/2416/2523
-f 2464/2416/2523 2445/2397/2500 2443/2395/2498
-f 2445/2397/2500 2464/2416/2523 2465/2417/2524
-f 2465/2417/2524 2447/2399/2502 2445/2397/2500
-f 2447/2399/2505 2465/2417/2525 2466/2418/2526
-f 2466/2418/2526 2449/2401/2506 2447/2399/2505
-f 2449/2401/2506 2466/2418/2526 2467/2419/2527
-f 2467/2419/2527 2451/2403/2508 2449/2401/2506
-f 2451/2403/2508 2467/2419/2527 2468/2420/2528
-f 2468/2420/2528 2453/2405/2510 2451/2403/2508
-f 2453/2405/2510 2468/2420/2528 2469/2421/2529
-f 2469/2421/2529 2455/2407/2512 2453/2405/2510
-f 2455/2407/2512 2469/2421/2529 2470/2422/2530
-f 2470/2422/2530 2457/2409/2514 2455/2407/2512
-f 2457/2409/2514 2470/2422/2530 2471/2423/2531
-f 2471/2423/2531 2459/2411/2516 2457/2409/2514
-f 2459/2411/2519 2471/2423/2532 2472/2424/2533
-f 2472/2424/2533 2461/2413/2520 2459/2411/2519
-f 2442/1219/2497 2442/1219/2497 2473/2425/2534
-f 2473/2425/2534 2463/2415/2522 2442/1219/2497
-f 2463/2415/2522 2473/2425/2534 2474/2426/2535
-f 2474/2426/2535 2464/2416/2523 2463/2415/2522
-f 2464/2416/2523 2474/2426/2535 2475/2427/2536
-f 2475/2427/2536 2465/2417/2524 2464/2416/2523
-f 2465/2417/2525 2475/2427/2537 2476/2428/2538
-f 2476/2428/2538 2466/2418/2526 2465/2417/2525
-f 2466/2418/2526 2476/2428/2538 2477/2429/2539
-f 2477/2429/2539 2467/2419/2527 2466/2418/2526
-f 2467/2419/2527 2477/2429/2539 2478/2430/2540
-f 2478/2430/2540 2468/2420/2528 2467/2419/2527
-f 2468/2420/2528 2478/2430/2540 2479/2431/2541
-f 2479/2431/2541 2469/2421/2529 2468/2420/2528
-f 2469/2421/2529 2479/2431/2541 2480/2432/2542
-f 2480/2432/2542 2470/2422/2530 2469/2421/2529
-f 2470/2422/2530 2480/2432/2542 2481/2433/2543
-f 2481/2433/2543 2471/2423/2531 2470/2422/2530
-f 2471/2423/2532 2481/2433/2544 2482/2434/2545
-f 2482/2434/2545 2472/2424/2533 2471/2423/2532
-f 2442/1219/2497 2442/1219/2497 2483/2435/2546
-f 2483/2435/2546 2473/2425/2534 2442/1219/2497
-f 2473/2425/2534 2483/2435/2546 2484/2436/2547
-f 2484/2436/2547 2474/2426/2535 2473/2425/2534
-f 2474/2426/2535 2484/2436/2547 2485/2437/2548
-f 2485/2437/2548 2475/2427/2536 2474/2426/2535
-f 2475/2427/2537 2485/2437/2549 2486/2438/2550
-f 2486/2438/2550 2476/2428/2538 2475/2427/2537
-f 2476/2428/2538 2486/2438/2550 2487/2439/2551
-f 2487/2439/2551 2477/2429/2539 2476/2428/2538
-f 2477/2429/2539 2487/2439/2551 2488/2440/2552
-f 2488/2440/2552 2478/2430/2540 2477/2429/2539
-f 2478/2430/2540 2488/2440/2552 2489/2441/2553
-f 2489/2441/2553 2479/2431/2541 2478/2430/2540
-f 2479/2431/2541 2489/2441/2553 2490/2442/2554
-f 2490/2442/2554 2480/2432/2542 2479/2431/2541
-f 2480/2432/2542 2490/2442/2554 2491/2443/2555
-f 2491/2443/2555 2481/2433/2543 2480/2432/2542
-f 2481/2433/2544 2491/2443/2556 2492/2444/2557
-f 2492/2444/2557 2482/2434/2545 2481/2433/2544
-f 2442/1219/2497 2442/1219/2497 2493/2445/2558
-f 2493/2445/2558 2483/2435/2546 2442/1219/2497
-f 2483/2435/2546 2493/2445/2558 2494/2446/2559
-f 2494/2446/2559 2484/2436/2547 2483/2435/2546
-f 2484/2436/2547 2494/2446/2559 2495/2447/2560
-f 2495/2447/2560 2485/2437/2548 2484/2436/2547
-f 2485/2437/2549 2495/2447/2561 2496/2448/2562
-f 2496/2448/2562 2486/2438/2550 2485/2437/2549
-f 2486/2438/2550 2496/2448/2562 2497/2449/2563
-f 2497/2449/2563 2487/2439/2551 2486/2438/2550
-f 2487/2439/2551 2497/2449/2563 2498/2450/2564
-f 2498/2450/2564 2488/2440/2552 2487/2439/2551
-f 2488/2440/2552 2498/2450/2564 2499/2451/2565
-f 2499/2451/2565 2489/2441/2553 2488/2440/2552
-f 2489/2441/2553 2499/2451/2565 2500/2452/2566
-f 2500/2452/2566 2490/2442/2554 2489/2441/2553
-f 2490/2442/2554 2500/2452/2566 2501/2453/2567
-f 2501/2453/2567 2491/2443/2555 2490/2442/2554
-f 2491/2443/2556 2501/2453/2568 2502/2454/2569
-f 2502/2454/2569 2492/2444/2557 2491/2443/2556
-f 2442/1219/2497 2442/1219/2497 2503/2455/2570
-f 2503/2455/2570 2493/2445/2558 2442/1219/2497
-f 2493/2445/2558 2503/2455/2570 2504/2456/2571
-f 2504/2456/2571 2494/2446/2559 2493/2445/2558
-f 2494/2446/2559 2504/2456/2571 2505/2457/2572
-f 2505/2457/2572 2495/2447/2560 2494/2446/2559
-f 2495/2447/2561 2505/2457/2573 2506/2458/2574
-f 2506/2458/2574 2496/2448/2562 2495/2447/2561
-f 2496/2448/2562 2506/2458/2574 2507/2459/2575
-f 2507/2459/2575 2497/2449/2563 2496/2448/2562
-f 2497/2449/2563 2507/2459/2575 2508/2460/2576
-f 2508/2460/2576 2498/2450/2564 2497/2449/2563
-f 2498/2450/2564 2508/2460/2576 2509/2461/2577
-f 2509/2461/2577 2499/2451/2565 2498/2450/2564
-f 2499/2451/2565 2509/2461/2577 2510/2462/2578
-f 2510/2462/2578 2500/2452/2566 2499/2451/2565
-f 2500/2452/2566 2510/2462/2578 2511/2463/2579
-f 2511/2463/2579 2501/2453/2567 2500/2452/2566
-f 2501/2453/2568 2511/2463/2580 2512/2464/2581
-f 2512/2464/2581 2502/2454/2569 2501/2453/2568
-f 2442/1219/2497 2442/1219/2497 2513/2465/2582
-f 2513/2465/2582 2503/2455/2570 2442/1219/2497
-f 2503/2455/2570 2513/2465/2582 2514/2466/2583
-f 2514/2466/2583 2504/2456/2571 2503/2455/2570
-f 2504/2456/2571 2514/2466/2583 2515/2467/2584
-f 2515/2467/2584 2505/2457/2572 2504/2456/2571
-f 2505/2457/2573 2515/2467/2585 2516/2468/2586
-f 2516/2468/2586 2506/2458/2574 2505/2457/2573
-f 2506/2458/2574 2516/2468/2586 2517/2469/2587
-f 2517/2469/2587 2507/2459/2575 2506/2458/2574
-f 2507/2459/2575 2517/2469/2587 2518/2470/2588
-f 2518/2470/2588 2508/2460/2576 2507/2459/2575
-f 2508/2460/2576 2518/2470/2588 2519/2471/2589
-f 2519/2471/2589 2509/2461/2577 2508/2460/2576
-f 2509/2461/2577 2519/2471/2589 2520/2472/2590
-f 2520/2472/2590 2510/2462/2578 2509/2461/2577
-f 2510/2462/2578 2520/2472/2590 2521/2473/2591
-f 2521/2473/2591 2511/2463/2579 2510/2462/2578
-f 2511/2463/2580 2521/2473/2592 2522/2474/2593
-f 2522/2474/2593 2512/2464/2581 2511/2463/2580
-f 2442/1219/2497 2442/1219/2497 2523/2475/2594
-f 2523/2475/2594 2513/2465/2582 2442/1219/2497
-f 2513/2465/2582 2523/2475/2594 2524/2476/2595
-f 2524/2476/2595 2514/2466/2583 2513/2465/2582
-f 2514/2466/2583 2524/2476/2595 2525/2477/2596
-f 2525/2477/2596 2515/2467/2584 2514/2466/2583
-f 2515/2467/2585 2525/2477/2597 2526/2478/2598
-f 2526/2478/2598 2516/2468/2586 2515/2467/2585
-f 2516/2468/2586 2526/2478/2598 2527/2479/2599
-f 2527/2479/2599 2517/2469/2587 2516/2468/2586
-f 2517/2469/2587 2527/2479/2599 2528/2480/2600
-f 2528/2480/2600 2518/2470/2588 2517/2469/2587
-f 2518/2470/2588 2528/2480/2600 2529/2481/2601
-f 2529/2481/2601 2519/2471/2589 2518/2470/2588
-f 2519/2471/2589 2529/2481/2601 2530/2482/2602
-f 2530/2482/2602 2520/2472/2590 2519/2471/2589
-f 2520/2472/2590 2530/2482/2602 2531/2483/2603
-f 2531/2483/2603 2521/2473/2591 2520/2472/2590
-f 2521/2473/2592 2531/2483/2604 2532/2484/2605
-f 2532/2484/2605 2522/2474/2593 2521/2473/2592
-f 2442/1219/2497 2442/1219/2497 2533/2485/2606
-f 2533/2485/2606 2523/2475/2594 2442/1219/2497
-f 2523/2475/2594 2533/2485/2606 2534/2486/2607
-f 2534/2486/2607 2524/2476/2595 2523/2475/2594
-f 2524/2476/2595 2534/2486/2607 2535/2487/2608
-f 2535/2487/2608 2525/2477/2596 2524/2476/2595
-f 2525/2477/2597 2535/2487/2609 2536/2488/2610
-f 2536/2488/2610 2526/2478/2598 2525/2477/2597
-f 2526/2478/2598 2536/2488/2610 2537/2489/2611
-f 2537/2489/2611 2527/2479/2599 2526/2478/2598
-f 2527/2479/2599 2537/2489/2611 2538/2490/2612
-f 2538/2490/2612 2528/2480/2600 2527/2479/2599
-f 2528/2480/2600 2538/2490/2612 2539/2491/2613
-f 2539/2491/2613 2529/2481/2601 2528/2480/2600
-f 2529/2481/2601 2539/2491/2613 2540/2492/2614
-f 2540/2492/2614 2530/2482/2602 2529/2481/2601
-f 2530/2482/2602 2540/2492/2614 2541/2493/2615
-f 2541/2493/2615 2531/2483/2603 2530/2482/2602
-f 2531/2483/2604 2541/2493/2616 2542/2494/2617
-f 2542/2494/2617 2532/2484/2605 2531/2483/2604
-f 2442/1219/2497 2442/1219/2497 2543/2495/2618
-f 2543/2495/2618 2533/2485/2606 2442/1219/2497
-f 2533/2485/2606 2543/2495/2618 2544/2496/2619
-f 2544/2496/2619 2534/2486/2607 2533/2485/2606
-f 2534/2486/2607 2544/2496/2619 2545/2497/2620
-f 2545/2497/2620 2535/2487/2608 2534/2486/2607
-f 2535/2487/2609 2545/2497/2621 2546/2498/2622
-f 2546/2498/2622 2536/2488/2610 2535/2487/2609
-f 2536/2488/2610 2546/2498/2622 2547/2499/2623
-f 2547/2499/2623 2537/2489/2611 2536/2488/2610
-f 2537/2489/2611 2547/2499/2623 2548/2500/2624
-f 2548/2500/2624 2538/2490/2612 2537/2489/2611
-f 2538/2490/2612 2548/2500/2624 2549/2501/2625
-f 2549/2501/2625 2539/2491/2613 2538/2490/2612
-f 2539/2491/2613 2549/2501/2625 2550/2502/2626
-f 2550/2502/2626 2540/2492/2614 2539/2491/2613
-f 2540/2492/2614 2550/2502/2626 2551/2503/2627
-f 2551/2503/2627 2541/2493/2615 2540/2492/2614
-f 2541/2493/2616 2551/2503/2628 2552/2504/2629
-f 2552/2504/2629 2542/2494/2617 2541/2493/2616
-f 2442/1219/2497 2442/1219/2497 2553/2505/2630
-f 2553/2505/2630 2543/2495/2618 2442/1219/2497
-f 2543/2495/2618 2553/2505/2630 2554/2506/2631
-f 2554/2506/2631 2544/2496/2619 2543/2495/2618
-f 2544/2496/2619 2554/2506/2631 2555/2507/2632
-f 2555/2507/2632 2545/2497/2620 2544/2496/2619
-f 2545/2497/2621 2555/2507/2633 2556/2508/2634
-f 2556/2508/2634 2546/2498/2622 2545/2497/2621
-f 2546/2498/2622 2556/2508/2634 2557/2509/2635
-f 2557/2509/2635 2547/2499/2623 2546/2498/2622
-f 2547/2499/2623 2557/2509/2635 2558/2510/2636
-f 2558/2510/2636 2548/2500/2624 2547/2499/2623
-f 2548/2500/2624 2558/2510/2636 2559/2511/2637
-f 2559/2511/2637 2549/2501/2625 2548/2500/2624
-f 2549/2501/2625 2559/2511/2637 2560/2512/2638
-f 2560/2512/2638 2550/2502/2626 2549/2501/2625
-f 2550/2502/2626 2560/2512/2638 2561/2513/2639
-f 2561/2513/2639 2551/2503/2627 2550/2502/2626
-f 2551/2503/2628 2561/2513/2640 2562/2514/2641
-f 2562/2514/2641 2552/2504/2629 2551/2503/2628
-f 2442/1219/2497 2442/1219/2497 2563/2515/2642
-f 2563/2515/2642 2553/2505/2630 2442/1219/2497
-f 2553/2505/2630 2563/2515/2642 2564/2516/2643
-f 2564/2516/2643 2554/2506/2631 2553/2505/2630
-f 2554/2506/2631 2564/2516/2643 2565/2517/2644
-f 2565/2517/2644 2555/2507/2632 2554/2506/2631
-f 2555/2507/2633 2565/2517/2645 2566/2518/2646
-f 2566/2518/2646 2556/2508/2634 2555/2507/2633
-f 2556/2508/2634 2566/2518/2646 2567/2519/2647
-f 2567/2519/2647 2557/2509/2635 2556/2508/2634
-f 2557/2509/2635 2567/2519/2647 2568/2520/2648
-f 2568/2520/2648 2558/2510/2636 2557/2509/2635
-f 2558/2510/2636 2568/2520/2648 2569/2521/2649
-f 2569/2521/2649 2559/2511/2637 2558/2510/2636
-f 2559/2511/2637 2569/2521/2649 2570/2522/2650
-f 2570/2522/2650 2560/2512/2638 2559/2511/2637
-f 2560/2512/2638 2570/2522/2650 2571/2523/2651
-f 2571/2523/2651 2561/2513/2639 2560/2512/2638
-f 2561/2513/2640 2571/2523/2652 2572/2524/2653
-f 2572/2524/2653 2562/2514/2641 2561/2513/2640
-f 2442/1219/2497 2442/1219/2497 2573/2525/2654
-f 2573/2525/2654 2563/2515/2642 2442/1219/2497
-f 2563/2515/2642 2573/2525/2654 2574/2526/2655
-f 2574/2526/2655 2564/2516/2643 2563/2515/2642
-f 2564/2516/2643 2574/2526/2655 2575/2527/2656
-f 2575/2527/2656 2565/2517/2644 2564/2516/2643
-f 2565/2517/2645 2575/2527/2657 2576/2528/2658
-f 2576/2528/2658 2566/2518/2646 2565/2517/2645
-f 2566/2518/2646 2576/2528/2658 2577/2529/2659
-f 2577/2529/2659 2567/2519/2647 2566/2518/2646
-f 2567/2519/2647 2577/2529/2659 2578/2530/2660
-f 2578/2530/2660 2568/2520/2648 2567/2519/2647
-f 2568/2520/2648 2578/2530/2660 2579/2531/2661
-f 2579/2531/2661 2569/2521/2649 2568/2520/2648
-f 2569/2521/2649 2579/2531/2661 2580/2532/2662
-f 2580/2532/2662 2570/2522/2650 2569/2521/2649
-f 2570/2522/2650 2580/2532/2662 2581/2533/2663
-f 2581/2533/2663 2571/2523/2651 2570/2522/2650
-f 2571/2523/2652 2581/2533/2664 2582/2534/2665
-f 2582/2534/2665 2572/2524/2653 2571/2523/2652
-f 2442/1219/2497 2442/1219/2497 2583/2535/2666
-f 2583/2535/2666 2573/2525/2654 2442/1219/2497
-f 2573/2525/2654 2583/2535/2666 2584/2536/2667
-f 2584/2536/2667 2574/2526/2655 2573/2525/2654
-f 2574/2526/2655 2584/2536/2667 2585/2537/2668
-f 2585/2537/2668 2575/2527/2656 2574/2526/2655
-f 2575/2527/2657 2585/2537/2669 2586/2538/2670
-f 2586/2538/2670 2576/2528/2658 2575/2527/2657
-f 2576/2528/2658 2586/2538/2670 2587/2539/2671
-f 2587/2539/2671 2577/2529/2659 2576/2528/2658
-f 2577/2529/2659 2587/2539/2671 2588/2540/2672
-f 2588/2540/2672 2578/2530/2660 2577/2529/2659
-f 2578/2530/2660 2588/2540/2672 2589/2541/2673
-f 2589/2541/2673 2579/2531/2661 2578/2530/2660
-f 2579/2531/2661 2589/2541/2673 2590/2542/2674
-f 2590/2542/2674 2580/2532/2662 2579/2531/2661
-f 2580/2532/2662 2590/2542/2674 2591/2543/2675
-f 2591/2543/2675 2581/2533/2663 2580/2532/2662
-f 2581/2533/2664 2591/2543/2676 2592/2544/2677
-f 2592/2544/2677 2582/2534/2665 2581/2533/2664
-f 2442/1219/2497 2442/1219/2497 2593/2545/2678
-f 2593/2545/2678 2583/2535/2666 2442/1219/2497
-f 2583/2535/2666 2593/2545/2678 2594/2546/2679
-f 2594/2546/2679 2584/2536/2667 2583/2535/2666
-f 2584/2536/2667 2594/2546/2679 2595/2547/2680
-f 2595/2547/2680 2585/2537/2668 2584/2536/2667
-f 2585/2537/2669 2595/2547/2681 2596/2548/2682
-f 2596/2548/2682 2586/2538/2670 2585/2537/2669
-f 2586/2538/2670 2596/2548/2682 2597/2549/2683
-f 2597/2549/2683 2587/2539/2671 2586/2538/2670
-f 2587/2539/2671 2597/2549/2683 2598/2550/2684
-f 2598/2550/2684 2588/2540/2672 2587/2539/2671
-f 2588/2540/2672 2598/2550/2684 2599/2551/2685
-f 2599/2551/2685 2589/2541/2673 2588/2540/2672
-f 2589/2541/2673 2599/2551/2685 2600/2552/2686
-f 2600/2552/2686 2590/2542/2674 2589/2541/2673
-f 2590/2542/2674 2600/2552/2686 2601/2553/2687
-f 2601/2553/2687 2591/2543/2675 2590/2542/2674
-f 2591/2543/2676 2601/2553/2688 2602/2554/2689
-f 2602/2554/2689 2592/2544/2677 2591/2543/2676
-f 2442/1219/2497 2442/1219/2497 2603/2555/2690
-f 2603/2555/2690 2593/2545/2678 2442/1219/2497
-f 2593/2545/2678 2603/2555/2690 2604/2556/2691
-f 2604/2556/2691 2594/2546/2679 2593/2545/2678
-f 2594/2546/2679 2604/2556/2691 2605/2557/2692
-f 2605/2557/2692 2595/2547/2680 2594/2546/2679
-f 2595/2547/2681 2605/2557/2693 2606/2558/2694
-f 2606/2558/2694 2596/2548/2682 2595/2547/2681
-f 2596/2548/2682 2606/2558/2694 2607/2559/2695
-f 2607/2559/2695 2597/2549/2683 2596/2548/2682
-f 2597/2549/2683 2607/2559/2695 2608/2560/2696
-f 2608/2560/2696 2598/2550/2684 2597/2549/2683
-f 2598/2550/2684 2608/2560/2696 2609/2561/2697
-f 2609/2561/2697 2599/2551/2685 2598/2550/2684
-f 2599/2551/2685 2609/2561/2697 2610/2562/2698
-f 2610/2562/2698 2600/2552/2686 2599/2551/2685
-f 2600/2552/2686 2610/2562/2698 2611/2563/2699
-f 2611/2563/2699 2601/2553/2687 2600/2552/2686
-f 2601/2553/2688 2611/2563/2700 2612/2564/2701
-f 2612/2564/2701 2602/2554/2689 2601/2553/2688
-f 2442/1219/2497 2442/1219/2497 2613/2565/2702
-f 2613/2565/2702 2603/2555/2690 2442/1219/2497
-f 2603/2555/2690 2613/2565/2702 2614/2566/2703
-f 2614/2566/2703 2604/2556/2691 2603/2555/2690
-f 2604/2556/2691 2614/2566/2703 2615/2567/2704
-f 2615/2567/2704 2605/2557/2692 2604/2556/2691
-f 2605/2557/2693 2615/2567/2705 2616/2568/2706
-f 2616/2568/2706 2606/2558/2694 2605/2557/2693
-f 2606/2558/2694 2616/2568/2706 2617/2569/2707
-f 2617/2569/2707 2607/2559/2695 2606/2558/2694
-f 2607/2559/2695 2617/2569/2707 2618/2570/2708
-f 2618/2570/2708 2608/2560/2696 2607/2559/2695
-f 2608/2560/2696 2618/2570/2708 2619/2571/2709
-f 2619/2571/2709 2609/2561/2697 2608/2560/2696
-f 2609/2561/2697 2619/2571/2709 2620/2572/2710
-f 2620/2572/2710 2610/2562/2698 2609/2561/2697
-f 2610/2562/2698 2620/2572/2710 2621/2573/2711
-f 2621/2573/2711 2611/2563/2699 2610/2562/2698
-f 2611/2563/2700 2621/2573/2712 2622/2574/2713
-f 2622/2574/2713 2612/2564/2701 2611/2563/2700
-f 2442/1219/2497 2442/1219/2497 2623/2575/2714
-f 2623/2575/2714 2613/2565/2702 2442/1219/2497
-f 2613/2565/2702 2623/2575/2714 2624/2576/2715
-f 2624/2576/2715 2614/2566/2703 2613/2565/2702
-f 2614/2566/2703 2624/2576/2715 2625/2577/2716
-f 2625/2577/2716 2615/2567/2704 2614/2566/2703
-f 2615/2567/2705 2625/2577/2717 2626/2578/2718
-f 2626/2578/2718 2616/2568/2706 2615/2567/2705
-f 2616/2568/2706 2626/2578/2718 2627/2579/2719
-f 2627/2579/2719 2617/2569/2707 2616/2568/2706
-f 2617/2569/2707 2627/2579/2719 2628/2580/2720
-f 2628/2580/2720 2618/2570/2708 2617/2569/2707
-f 2618/2570/2708 2628/2580/2720 2629/2581/2721
-f 2629/2581/2721 2619/2571/2709 2618/2570/2708
-f 2619/2571/2709 2629/2581/2721 2630/2582/2722
-f 2630/2582/2722 2620/2572/2710 2619/2571/2709
-f 2620/2572/2710 2630/2582/2722 2631/2583/2723
-f 2631/2583/2723 2621/2573/2711 2620/2572/2710
-f 2621/2573/2712 2631/2583/2724 2632/2584/2725
-f 2632/2584/2725 2622/2574/2713 2621/2573/2712
-f 2442/1219/2497 2442/1219/2497 2633/2585/2726
-f 2633/2585/2726 2623/2575/2714 2442/1219/2497
-f 2623/2575/2714 2633/2585/2726 2634/2586/2727
-f 2634/2586/2727 2624/2576/2715 2623/2575/2714
-f 2624/2576/2715 2634/2586/2727 2635/2587/2728
-f 2635/2587/2728 2625/2577/2716 2624/2576/2715
-f 2625/2577/2717 2635/2587/2729 2636/2588/2730
-f 2636/2588/2730 2626/2578/2718 2625/2577/2717
-f 2626/2578/2718 2636/2588/2730 2637/2589/2731
-f 2637/2589/2731 2627/2579/2719 2626/2578/2718
-f 2627/2579/2719 2637/2589/2731 2638/2590/2732
-f 2638/2590/2732 2628/2580/2720 2627/2579/2719
-f 2628/2580/2720 2638/2590/2732 2639/2591/2733
-f 2639/2591/2733 2629/2581/2721 2628/2580/2720
-f 2629/2581/2721 2639/2591/2733 2640/2592/2734
-f 2640/2592/2734 2630/2582/2722 2629/2581/2721
-f 2630/2582/2722 2640/2592/2734 2641/2593/2735
-f 2641/2593/2735 2631/2583/2723 2630/2582/2722
-f 2631/2583/2724 2641/2593/2736 2642/2594/2737
-f 2642/2594/2737 2632/2584/2725 2631/2583/2724
-f 2442/1219/2497 2442/1219/2497 2643/2595/2738
-f 2643/2595/2738 2633/2585/2726 2442/1219/2497
-f 2633/2585/2726 2643/2595/2738 2644/2596/2739
-f 2644/2596/2739 2634/2586/2727 2633/2585/2726
-f 2634/2586/2727 2644/2596/2739 2645/2597/2740
-f 2645/2597/2740 2635/2587/2728 2634/2586/2727
-f 2635/2587/2729 2645/2597/2741 2646/2598/2742
-f 2646/2598/2742 2636/2588/2730 2635/2587/2729
-f 2636/2588/2730 2646/2598/2742 2647/2599/2743
-f 2647/2599/2743 2637/2589/2731 2636/2588/2730
-f 2637/2589/2731 2647/2599/2743 2648/2600/2744
-f 2648/2600/2744 2638/2590/2732 2637/2589/2731
-f 2638/2590/2732 2648/2600/2744 2649/2601/2745
-f 2649/2601/2745 2639/2591/2733 2638/2590/2732
-f 2639/2591/2733 2649/2601/2745 2650/2602/2746
-f 2650/2602/2746 2640/2592/2734 2639/2591/2733
-f 2640/2592/2734 2650/2602/2746 2651/2603/2747
-f 2651/2603/2747 2641/2593/2735 2640/2592/2734
-f 2641/2593/2736 2651/2603/2748 2652/2604/2749
-f 2652/2604/2749 2642/2594/2737 2641/2593/2736
-f 2442/1219/2497 2442/1219/2497 2653/2605/2750
-f 2653/2605/2750 2643/2595/2738 2442/1219/2497
-f 2643/2595/2738 2653/2605/2750 2654/2606/2751
-f 2654/2606/2751 2644/2596/2739 2643/2595/2738
-f 2644/2596/2739 2654/2606/2751 2655/2607/2752
-f 2655/2607/2752 2645/2597/2740 2644/2596/2739
-f 2645/2597/2741 2655/2607/2753 2656/2608/2754
-f 2656/2608/2754 2646/2598/2742 2645/2597/2741
-f 2646/2598/2742 2656/2608/2754 2657/2609/2755
-f 2657/2609/2755 2647/2599/2743 2646/2598/2742
-f 2647/2599/2743 2657/2609/2755 2658/2610/2756
-f 2658/2610/2756 2648/2600/2744 2647/2599/2743
-f 2648/2600/2744 2658/2610/2756 2659/2611/2757
-f 2659/2611/2757 2649/2601/2745 2648/2600/2744
-f 2649/2601/2745 2659/2611/2757 2660/2612/2758
-f 2660/2612/2758 2650/2602/2746 2649/2601/2745
-f 2650/2602/2746 2660/2612/2758 2661/2613/2759
-f 2661/2613/2759 2651/2603/2747 2650/2602/2746
-f 2651/2603/2748 2661/2613/2760 2662/2614/2761
-f 2662/2614/2761 2652/2604/2749 2651/2603/2748
-f 2442/1219/2497 2442/1219/2497 2663/2615/2762
-f 2663/2615/2762 2653/2605/2750 2442/1219/2497
-f 2653/2605/2750 2663/2615/2762 2664/2616/2763
-f 2664/2616/2763 2654/2606/2751 2653/2605/2750
-f 2654/2606/2751 2664/2616/2763 2665/2617/2764
-f 2665/2617/2764 2655/2607/2752 2654/2606/2751
-f 2655/2607/2753 2665/2617/2765 2666/2618/2766
-f 2666/2618/2766 2656/2608/2754 2655/2607/2753
-f 2656/2608/2754 2666/2618/2766 2667/2619/2767
-f 2667/2619/2767 2657/2609/2755 2656/2608/2754
-f 2657/2609/2755 2667/2619/2767 2668/2620/2768
-f 2668/2620/2768 2658/2610/2756 2657/2609/2755
-f 2658/2610/2756 2668/2620/2768 2669/2621/2769
-f 2669/2621/2769 2659/2611/2757 2658/2610/2756
-f 2659/2611/2757 2669/2621/2769 2670/2622/2770
-f 2670/2622/2770 2660/2612/2758 2659/2611/2757
-f 2660/2612/2758 2670/2622/2770 2671/2623/2771
-f 2671/2623/2771 2661/2613/2759 2660/2612/2758
-f 2661/2613/2760 2671/2623/2772 2672/2624/2773
-f 2672/2624/2773 2662/2614/2761 2661/2613/2760
-f 2442/1219/2497 2442/1219/2497 2673/2625/2774
-f 2673/2625/2774 2663/2615/2762 2442/1219/2497
-f 2663/2615/2762 2673/2625/2774 2674/2626/2775
-f 2674/2626/2775 2664/2616/2763 2663/2615/2762
-f 2664/2616/2763 2674/2626/2775 2675/2627/2776
-f 2675/2627/2776 2665/2617/2764 2664/2616/2763
-f 2665/2617/2765 2675/2627/2777 2676/2628/2778
-f 2676/2628/2778 2666/2618/2766 2665/2617/2765
-f 2666/2618/2766 2676/2628/2778 2677/2629/2779
-f 2677/2629/2779 2667/2619/2767 2666/2618/2766
-f 2667/2619/2767 2677/2629/2779 2678/2630/2780
-f 2678/2630/2780 2668/2620/2768 2667/2619/2767
-f 2668/2620/2768 2678/2630/2780 2679/2631/2781
-f 2679/2631/2781 2669/2621/2769 2668/2620/2768
-f 2669/2621/2769 2679/2631/2781 2680/2632/2782
-f 2680/2632/2782 2670/2622/2770 2669/2621/2769
-f 2670/2622/2770 2680/2632/2782 2681/2633/2783
-f 2681/2633/2783 2671/2623/2771 2670/2622/2770
-f 2671/2623/2772 2681/2633/2784 2682/2634/2785
-f 2682/2634/2785 2672/2624/2773 2671/2623/2772
-f 2442/1219/2497 2442/1219/2497 2683/2635/2786
-f 2683/2635/2786 2673/2625/2774 2442/1219/2497
-f 2673/2625/2774 2683/2635/2786 2684/2636/2787
-f 2684/2636/2787 2674/2626/2775 2673/2625/2774
-f 2674/2626/2775 2684/2636/2787 2685/2637/2788
-f 2685/2637/2788 2675/2627/2776 2674/2626/2775
-f 2675/2627/2777 2685/2637/2789 2686/2638/2790
-f 2686/2638/2790 2676/2628/2778 2675/2627/2777
-f 2676/2628/2778 2686/2638/2790 2687/2639/2791
-f 2687/2639/2791 2677/2629/2779 2676/2628/2778
-f 2677/2629/2779 2687/2639/2791 2688/2640/2792
-f 2688/2640/2792 2678/2630/2780 2677/2629/2779
-f 2678/2630/2780 2688/2640/2792 2689/2641/2793
-f 2689/2641/2793 2679/2631/2781 2678/2630/2780
-f 2679/2631/2781 2689/2641/2793 2690/2642/2794
-f 2690/2642/2794 2680/2632/2782 2679/2631/2781
-f 2680/2632/2782 2690/2642/2794 2691/2643/2795
-f 2691/2643/2795 2681/2633/2783 2680/2632/2782
-f 2681/2633/2784 2691/2643/2796 2692/2644/2797
-f 2692/2644/2797 2682/2634/2785 2681/2633/2784
-f 2442/1219/2497 2442/1219/2497 2693/2645/2798
-f 2693/2645/2798 2683/2635/2786 2442/1219/2497
-f 2683/2635/2786 2693/2645/2798 2694/2646/2799
-f 2694/2646/2799 2684/2636/2787 2683/2635/2786
-f 2684/2636/2787 2694/2646/2799 2695/2647/2800
-f 2695/2647/2800 2685/2637/2788 2684/2636/2787
-f 2685/2637/2789 2695/2647/2801 2696/2648/2802
-f 2696/2648/2802 2686/2638/2790 2685/2637/2789
-f 2686/2638/2790 2696/2648/2802 2697/2649/2803
-f 2697/2649/2803 2687/2639/2791 2686/2638/2790
-f 2687/2639/2791 2697/2649/2803 2698/2650/2804
-f 2698/2650/2804 2688/2640/2792 2687/2639/2791
-f 2688/2640/2792 2698/2650/2804 2699/2651/2805
-f 2699/2651/2805 2689/2641/2793 2688/2640/2792
-f 2689/2641/2793 2699/2651/2805 2700/2652/2806
-f 2700/2652/2806 2690/2642/2794 2689/2641/2793
-f 2690/2642/2794 2700/2652/2806 2701/2653/2807
-f 2701/2653/2807 2691/2643/2795 2690/2642/2794
-f 2691/2643/2796 2701/2653/2808 2702/2654/2809
-f 2702/2654/2809 2692/2644/2797 2691/2643/2796
-f 2442/1219/2497 2442/1219/2497 2703/2655/2810
-f 2703/2655/2810 2693/2645/2798 2442/1219/2497
-f 2693/2645/2798 2703/2655/2810 2704/2656/2811
-f 2704/2656/2811 2694/2646/2799 2693/2645/2798
-f 2694/2646/2799 2704/2656/2811 2705/2657/2812
-f 2705/2657/2812 2695/2647/2800 2694/2646/2799
-f 2695/2647/2801 2705/2657/2813 2706/2658/2814
-f 2706/2658/2814 2696/2648/2802 2695/2647/2801
-f 2696/2648/2802 2706/2658/2814 2707/2659/2815
-f 2707/2659/2815 2697/2649/2803 2696/2648/2802
-f 2697/2649/2803 2707/2659/2815 2708/2660/2816
-f 2708/2660/2816 2698/2650/2804 2697/2649/2803
-f 2698/2650/2804 2708/2660/2816 2709/2661/2817
-f 2709/2661/2817 2699/2651/2805 2698/2650/2804
-f 2699/2651/2805 2709/2661/2817 2710/2662/2818
-f 2710/2662/2818 2700/2652/2806 2699/2651/2805
-f 2700/2652/2806 2710/2662/2818 2711/2663/2819
-f 2711/2663/2819 2701/2653/2807 2700/2652/2806
-f 2701/2653/2808 2711/2663/2820 2712/2664/2821
-f 2712/2664/2821 2702/2654/2809 2701/2653/2808
-f 2442/1219/2497 2442/1219/2497 2713/2665/2822
-f 2713/2665/2822 2703/2655/2810 2442/1219/2497
-f 2703/2655/2810 2713/2665/2822 2714/2666/2823
-f 2714/2666/2823 2704/2656/2811 2703/2655/2810
-f 2704/2656/2811 2714/2666/2823 2715/2667/2824
-f 2715/2667/2824 2705/2657/2812 2704/2656/2811
-f 2705/2657/2813 2715/2667/2825 2716/2668/2826
-f 2716/2668/2826 2706/2658/2814 2705/2657/2813
-f 2706/2658/2814 2716/2668/2826 2717/2669/2827
-f 2717/2669/2827 2707/2659/2815 2706/2658/2814
-f 2707/2659/2815 2717/2669/2827 2718/2670/2828
-f 2718/2670/2828 2708/2660/2816 2707/2659/2815
-f 2708/2660/2816 2718/2670/2828 2719/2671/2829
-f 2719/2671/2829 2709/2661/2817 2708/2660/2816
-f 2709/2661/2817 2719/2671/2829 2720/2672/2830
-f 2720/2672/2830 2710/2662/2818 2709/2661/2817
-f 2710/2662/2818 2720/2672/2830 2721/2673/2831
-f 2721/2673/2831 2711/2663/2819 2710/2662/2818
-f 2711/2663/2820 2721/2673/2832 2722/2674/2833
-f 2722/2674/2833 2712/2664/2821 2711/2663/2820
-f 2442/1219/2497 2442/1219/2497 2723/2675/2834
-f 2723/2675/2834 2713/2665/2822 2442/1219/2497
-f 2713/2665/2822 2723/2675/2834 2724/2676/2835
-f 2724/2676/2835 2714/2666/2823 2713/2665/2822
-f 2714/2666/2823 2724/2676/2835 2725/2677/2836
-f 2725/2677/2836 2715/2667/2824 2714/2666/2823
-f 2715/2667/2825 2725/2677/2837 2726/2678/2838
-f 2726/2678/2838 2716/2668/2826 2715/2667/2825
-f 2716/2668/2826 2726/2678/2838 2727/2679/2839
-f 2727/2679/2839 2717/2669/2827 2716/2668/2826
-f 2717/2669/2827 2727/2679/2839 2728/2680/2840
-f 2728/2680/2840 2718/2670/2828 2717/2669/2827
-f 2718/2670/2828 2728/2680/2840 2729/2681/2841
-f 2729/2681/2841 2719/2671/2829 2718/2670/2828
-f 2719/2671/2829 2729/2681/2841 2730/2682/2842
-f 2730/2682/2842 2720/2672/2830 2719/2671/2829
-f 2720/2672/2830 2730/2682/2842 2731/2683/2843
-f 2731/2683/2843 2721/2673/2831 2720/2672/2830
-f 2721/2673/2832 2731/2683/2844 2732/2684/2845
-f 2732/2684/2845 2722/2674/2833 2721/2673/2832
-f 2442/1219/2497 2442/1219/2497 2733/2685/2846
-f 2733/2685/2846 2723/2675/2834 2442/1219/2497
-f 2723/2675/2834 2733/2685/2846 2734/2686/2847
-f 2734/2686/2847 2724/2676/2835 2723/2675/2834
-f 2724/2676/2835 2734/2686/2847 2735/2687/2848
-f 2735/2687/2848 2725/2677/2836 2724/2676/2835
-f 2725/2677/2837 2735/2687/2849 2736/2688/2850
-f 2736/2688/2850 2726/2678/2838 2725/2677/2837
-f 2726/2678/2838 2736/2688/2850 2737/2689/2851
-f 2737/2689/2851 2727/2679/2839 2726/2678/2838
-f 2727/2679/2839 2737/2689/2851 2738/2690/2852
-f 2738/2690/2852 2728/2680/2840 2727/2679/2839
-f 2728/2680/2840 2738/2690/2852 2739/2691/2853
-f 2739/2691/2853 2729/2681/2841 2728/2680/2840
-f 2729/2681/2841 2739/2691/2853 2740/2692/2854
-f 2740/2692/2854 2730/2682/2842 2729/2681/2841
-f 2730/2682/2842 2740/2692/2854 2741/2693/2855
-f 2741/2693/2855 2731/2683/2843 2730/2682/2842
-f 2731/2683/2844 2741/2693/2856 2742/2694/2857
-f 2742/2694/2857 2732/2684/2845 2731/2683/2844
-f 2442/1219/2497 2442/1219/2497 2743/2695/2858
-f 2743/2695/2858 2733/2685/2846 2442/1219/2497
-f 2733/2685/2846 2743/2695/2858 2744/2696/2859
-f 2744/2696/2859 2734/2686/2847 2733/2685/2846
-f 2734/2686/2847 2744/2696/2859 2745/2697/2860
-f 2745/2697/2860 2735/2687/2848 2734/2686/2847
-f 2735/2687/2849 2745/2697/2861 2746/2698/2862
-f 2746/2698/2862 2736/2688/2850 2735/2687/2849
-f 2736/2688/2850 2746/2698/2862 2747/2699/2863
-f 2747/2699/2863 2737/2689/2851 2736/2688/2850
-f 2737/2689/2851 2747/2699/2863 2748/2700/2864
-f 2748/2700/2864 2738/2690/2852 2737/2689/2851
-f 2738/2690/2852 2748/2700/2864 2749/2701/2865
-f 2749/2701/2865 2739/2691/2853 2738/2690/2852
-f 2739/2691/2853 2749/2701/2865 2750/2702/2866
-f 2750/2702/2866 2740/2692/2854 2739/2691/2853
-f 2740/2692/2854 2750/2702/2866 2751/2703/2867
-f 2751/2703/2867 2741/2693/2855 2740/2692/2854
-f 2741/2693/2856 2751/2703/2868 2752/2704/2869
-f 2752/2704/2869 2742/2694/2857 2741/2693/2856
-f 2442/1219/2497 2442/1219/2497 2753/2705/2870
-f 2753/2705/2870 2743/2695/2858 2442/1219/2497
-f 2743/2695/2858 2753/2705/2870 2754/2706/2871
-f 2754/2706/2871 2744/2696/2859 2743/2695/2858
-f 2744/2696/2859 2754/2706/2871 2755/2707/2872
-f 2755/2707/2872 2745/2697/2860 2744/2696/2859
-f 2745/2697/2861 2755/2707/2873 2756/2708/2874
-f 2756/2708/2874 2746/2698/2862 2745/2697/2861
-f 2746/2698/2862 2756/2708/2874 2757/2709/2875
-f 2757/2709/2875 2747/2699/2863 2746/2698/2862
-f 2747/2699/2863 2757/2709/2875 2758/2710/2876
-f 2758/2710/2876 2748/2700/2864 2747/2699/2863
-f 2748/2700/2864 2758/2710/2876 2759/2711/2877
-f 2759/2711/2877 2749/2701/2865 2748/2700/2864
-f 2749/2701/2865 2759/2711/2877 2760/2712/2878
-f 2760/2712/2878 2750/2702/2866 2749/2701/2865
-f 2750/2702/2866 2760/2712/2878 2761/2713/2879
-f 2761/2713/2879 2751/2703/2867 2750/2702/2866
-f 2751/2703/2868 2761/2713/2880 2762/2714/2881
-f 2762/2714/2881 2752/2704/2869 2751/2703/2868
-f 2442/1219/2497 2442/1219/2497 2763/2715/2882
-f 2763/2715/2882 2753/2705/2870 2442/1219/2497
-f 2753/2705/2870 2763/2715/2882 2764/2716/2883
-f 2764/2716/2883 2754/2706/2871 2753/2705/2870
-f 2754/2706/2871 2764/2716/2883 2765/2717/2884
-f 2765/2717/2884 2755/2707/2872 2754/2706/2871
-f 2755/2707/2873 2765/2717/2885 2766/2718/2886
-f 2766/2718/2886 2756/2708/2874 2755/2707/2873
-f 2756/2708/2874 2766/2718/2886 2767/2719/2887
-f 2767/2719/2887 2757/2709/2875 2756/2708/2874
-f 2757/2709/2875 2767/2719/2887 2768/2720/2888
-f 2768/2720/2888 2758/2710/2876 2757/2709/2875
-f 2758/2710/2876 2768/2720/2888 2769/2721/2889
-f 2769/2721/2889 2759/2711/2877 2758/2710/2876
-f 2759/2711/2877 2769/2721/2889 2770/2722/2890
-f 2770/2722/2890 2760/2712/2878 2759/2711/2877
-f 2760/2712/2878 2770/2722/2890 2771/2723/2891
-f 2771/2723/2891 2761/2713/2879 2760/2712/2878
-f 2761/2713/2880 2771/2723/2892 2772/2724/2893
-f 2772/2724/2893 2762/2714/2881 2761/2713/2880
-f 2442/1219/2497 2442/1219/2497 2773/2725/2894
-f 2773/2725/2894 2763/2715/2882 2442/1219/2497
-f 2763/2715/2882 2773/2725/2894 2774/2726/2895
-f 2774/2726/2895 2764/2716/2883 2763/2715/2882
-f 2764/2716/2883 2774/2726/2895 2775/2727/2896
-f 2775/2727/2896 2765/2717/2884 2764/2716/2883
-f 2765/2717/2885 2775/2727/2897 2776/2728/2898
-f 2776/2728/2898 2766/2718/2886 2765/2717/2885
-f 2766/2718/2886 2776/2728/2898 2777/2729/2899
-f 2777/2729/2899 2767/2719/2887 2766/2718/2886
-f 2767/2719/2887 2777/2729/2899 2778/2730/2900
-f 2778/2730/2900 2768/2720/2888 2767/2719/2887
-f 2768/2720/2888 2778/2730/2900 2779/2731/2901
-f 2779/2731/2901 2769/2721/2889 2768/2720/2888
-f 2769/2721/2889 2779/2731/2901 2780/2732/2902
-f 2780/2732/2902 2770/2722/2890 2769/2721/2889
-f 2770/2722/2890 2780/2732/2902 2781/2733/2903
-f 2781/2733/2903 2771/2723/2891 2770/2722/2890
-f 2771/2723/2892 2781/2733/2904 2782/2734/2905
-f 2782/2734/2905 2772/2724/2893 2771/2723/2892
-f 2442/1219/2497 2442/1219/2497 2783/2735/2906
-f 2783/2735/2906 2773/2725/2894 2442/1219/2497
-f 2773/2725/2894 2783/2735/2906 2784/2736/2907
-f 2784/2736/2907 2774/2726/2895 2773/2725/2894
-f 2774/2726/2895 2784/2736/2907 2785/2737/2908
-f 2785/2737/2908 2775/2727/2896 2774/2726/2895
-f 2775/2727/2897 2785/2737/2909 2786/2738/2910
-f 2786/2738/2910 2776/2728/2898 2775/2727/2897
-f 2776/2728/2898 2786/2738/2910 2787/2739/2911
-f 2787/2739/2911 2777/2729/2899 2776/2728/2898
-f 2777/2729/2899 2787/2739/2911 2788/2740/2912
-f 2788/2740/2912 2778/2730/2900 2777/2729/2899
-f 2778/2730/2900 2788/2740/2912 2789/2741/2913
-f 2789/2741/2913 2779/2731/2901 2778/2730/2900
-f 2779/2731/2901 2789/2741/2913 2790/2742/2914
-f 2790/2742/2914 2780/2732/2902 2779/2731/2901
-f 2780/2732/2902 2790/2742/2914 2791/2743/2915
-f 2791/2743/2915 2781/2733/2903 2780/2732/2902
-f 2781/2733/2904 2791/2743/2916 2792/2744/2917
-f 2792/2744/2917 2782/2734/2905 2781/2733/2904
-f 2442/1219/2497 2442/1219/2497 2793/2745/2918
-f 2793/2745/2918 2783/2735/2906 2442/1219/2497
-f 2783/2735/2906 2793/2745/2918 2794/2746/2919
-f 2794/2746/2919 2784/2736/2907 2783/2735/2906
-f 2784/2736/2907 2794/2746/2919 2795/2747/2920
-f 2795/2747/2920 2785/2737/2908 2784/2736/2907
-f 2785/2737/2909 2795/2747/2921 2796/2748/2922
-f 2796/2748/2922 2786/2738/2910 2785/2737/2909
-f 2786/2738/2910 2796/2748/2922 2797/2749/2923
-f 2797/2749/2923 2787/2739/2911 2786/2738/2910
-f 2787/2739/2911 2797/2749/2923 2798/2750/2924
-f 2798/2750/2924 2788/2740/2912 2787/2739/2911
-f 2788/2740/2912 2798/2750/2924 2799/2751/2925
-f 2799/2751/2925 2789/2741/2913 2788/2740/2912
-f 2789/2741/2913 2799/2751/2925 2800/2752/2926
-f 2800/2752/2926 2790/2742/2914 2789/2741/2913
-f 2790/2742/2914 2800/2752/2926 2801/2753/2927
-f 2801/2753/2927 2791/2743/2915 2790/2742/2914
-f 2791/2743/2916 2801/2753/2928 2802/2754/2929
-f 2802/2754/2929 2792/2744/2917 2791/2743/2916
-f 2442/1219/2497 2442/1219/2497 2803/2755/2930
-f 2803/2755/2930 2793/2745/2918 2442/1219/2497
-f 2793/2745/2918 2803/2755/2930 2804/2756/2931
-f 2804/2756/2931 2794/2746/2919 2793/2745/2918
-f 2794/2746/2919 2804/2756/2931 2805/2757/2932
-f 2805/2757/2932 2795/2747/2920 2794/2746/2919
-f 2795/2747/2921 2805/2757/2933 2806/2758/2934
-f 2806/2758/2934 2796/2748/2922 2795/2747/2921
-f 2796/2748/2922 2806/2758/2934 2807/2759/2935
-f 2807/2759/2935 2797/2749/2923 2796/2748/2922
-f 2797/2749/2923 2807/2759/2935 2808/2760/2936
-f 2808/2760/2936 2798/2750/2924 2797/2749/2923
-f 2798/2750/2924 2808/2760/2936 2809/2761/2937
-f 2809/2761/2937 2799/2751/2925 2798/2750/2924
-f 2799/2751/2925 2809/2761/2937 2810/2762/2938
-f 2810/2762/2938 2800/2752/2926 2799/2751/2925
-f 2800/2752/2926 2810/2762/2938 2811/2763/2939
-f 2811/2763/2939 2801/2753/2927 2800/2752/2926
-f 2801/2753/2928 2811/2763/2940 2812/2764/2941
-f 2812/2764/2941 2802/2754/2929 2801/2753/2928
-f 2442/1219/2497 2442/1219/2497 2813/2765/2942
-f 2813/2765/2942 2803/2755/2930 2442/1219/2497
-f 2803/2755/2930 2813/2765/2942 2814/2766/2943
-f 2814/2766/2943 2804/2756/2931 2803/2755/2930
-f 2804/2756/2931 2814/2766/2943 2815/2767/2944
-f 2815/2767/2944 2805/2757/2932 2804/2756/2931
-f 2805/2757/2933 2815/2767/2945 2816/2768/2946
-f 2816/2768/2946 2806/2758/2934 2805/2757/2933
-f 2806/2758/2934 2816/2768/2946 2817/2769/2947
-f 2817/2769/2947 2807/2759/2935 2806/2758/2934
-f 2807/2759/2935 2817/2769/2947 2818/2770/2948
-f 2818/2770/2948 2808/2760/2936 2807/2759/2935
-f 2808/2760/2936 2818/2770/2948 2819/2771/2949
-f 2819/2771/2949 2809/2761/2937 2808/2760/2936
-f 2809/2761/2937 2819/2771/2949 2820/2772/2950
-f 2820/2772/2950 2810/2762/2938 2809/2761/2937
-f 2810/2762/2938 2820/2772/2950 2821/2773/2951
-f 2821/2773/2951 2811/2763/2939 2810/2762/2938
-f 2811/2763/2940 2821/2773/2952 2822/2774/2953
-f 2822/2774/2953 2812/2764/2941 2811/2763/2940
-f 2442/1219/2497 2442/1219/2497 2823/2775/2954
-f 2823/2775/2954 2813/2765/2942 2442/1219/2497
-f 2813/2765/2942 2823/2775/2954 2824/2776/2955
-f 2824/2776/2955 2814/2766/2943 2813/2765/2942
-f 2814/2766/2943 2824/2776/2955 2825/2777/2956
-f 2825/2777/2956 2815/2767/2944 2814/2766/2943
-f 2815/2767/2945 2825/2777/2957 2826/2778/2958
-f 2826/2778/2958 2816/2768/2946 2815/2767/2945
-f 2816/2768/2946 2826/2778/2958 2827/2779/2959
-f 2827/2779/2959 2817/2769/2947 2816/2768/2946
-f 2817/2769/2947 2827/2779/2959 2828/2780/2960
-f 2828/2780/2960 2818/2770/2948 2817/2769/2947
-f 2818/2770/2948 2828/2780/2960 2829/2781/2961
-f 2829/2781/2961 2819/2771/2949 2818/2770/2948
-f 2819/2771/2949 2829/2781/2961 2830/2782/2962
-f 2830/2782/2962 2820/2772/2950 2819/2771/2949
-f 2820/2772/2950 2830/2782/2962 2831/2783/2963
-f 2831/2783/2963 2821/2773/2951 2820/2772/2950
-f 2821/2773/2952 2831/2783/2964 2832/2784/2965
-f 2832/2784/2965 2822/2774/2953 2821/2773/2952
-f 2442/1219/2497 2442/1219/2497 2833/2785/2966
-f 2833/2785/2966 2823/2775/2954 2442/1219/2497
-f 2823/2775/2954 2833/2785/2966 2834/2786/2967
-f 2834/2786/2967 2824/2776/2955 2823/2775/2954
-f 2824/2776/2955 2834/2786/2967 2835/2787/2968
-f 2835/2787/2968 2825/2777/2956 2824/2776/2955
-f 2825/2777/2957 2835/2787/2969 2836/2788/2970
-f 2836/2788/2970 2826/2778/2958 2825/2777/2957
-f 2826/2778/2958 2836/2788/2970 2837/2789/2971
-f 2837/2789/2971 2827/2779/2959 2826/2778/2958
-f 2827/2779/2959 2837/2789/2971 2838/2790/2972
-f 2838/2790/2972 2828/2780/2960 2827/2779/2959
-f 2828/2780/2960 2838/2790/2972 2839/2791/2973
-f 2839/2791/2973 2829/2781/2961 2828/2780/2960
-f 2829/2781/2961 2839/2791/2973 2840/2792/2974
-f 2840/2792/2974 2830/2782/2962 2829/2781/2961
-f 2830/2782/2962 2840/2792/2974 2841/2793/2975
-f 2841/2793/2975 2831/2783/2963 2830/2782/2962
-f 2831/2783/2964 2841/2793/2976 2842/2794/2977
-f 2842/2794/2977 2832/2784/2965 2831/2783/2964
-f 2442/1219/2497 2442/1219/2497 2444/2396/2499
-f 2444/2396/2499 2833/2785/2966 2442/1219/2497
-f 2833/2785/2966 2444/2396/2499 2446/2398/2501
-f 2446/2398/2501 2834/2786/2967 2833/2785/2966
-f 2834/2786/2967 2446/2398/2501 2448/2400/2503
-f 2448/2400/2503 2835/2787/2968 2834/2786/2967
-f 2835/2787/2969 2448/2400/2504 2450/2402/2507
-f 2450/2402/2507 2836/2788/2970 2835/2787/2969
-f 2836/2788/2970 2450/2402/2507 2452/2404/2509
-f 2452/2404/2509 2837/2789/2971 2836/2788/2970
-f 2837/2789/2971 2452/2404/2509 2454/2406/2511
-f 2454/2406/2511 2838/2790/2972 2837/2789/2971
-f 2838/2790/2972 2454/2406/2511 2456/2408/2513
-f 2456/2408/2513 2839/2791/2973 2838/2790/2972
-f 2839/2791/2973 2456/2408/2513 2458/2410/2515
-f 2458/2410/2515 2840/2792/2974 2839/2791/2973
-f 2840/2792/2974 2458/2410/2515 2460/2412/2517
-f 2460/2412/2517 2841/2793/2975 2840/2792/2974
-f 2841/2793/2976 2460/2412/2518 2462/2414/2521
-f 2462/2414/2521 2842/2794/2977 2841/2793/2976
-f 2462/2414/2521 2461/2413/2520 2843/2795/2978
-f 2843/2795/2978 2844/2796/2979 2462/2414/2521
-f 2844/2796/2979 2843/2795/2978 2845/2797/2980
-f 2845/2797/2980 2846/2798/2981 2844/2796/2979
-f 2846/2798/2981 2845/2797/2980 2847/2799/2982
-f 2847/2799/2982 2848/2800/2983 2846/2798/2981
-f 2848/2800/2983 2847/2799/2982 2849/2801/2984
-f 2849/2801/2984 2850/2802/2985 2848/2800/2983
-f 2850/2802/2985 2849/2801/2984 2851/2803/2986
-f 2851/2803/2986 2852/2804/2987 2850/2802/2985
-f 2852/2804/2987 2851/2803/2986 2853/2805/2988
-f 2853/2805/2988 2854/2806/2989 2852/2804/2987
-f 2854/2806/2989 2853/2805/2988 2855/2807/2990
-f 2855/2807/2990 2856/2808/2991 2854/2806/2989
-f 2856/2808/2991 2855/2807/2990 2857/2809/2992
-f 2857/2809/2992 2858/2810/2993 2856/2808/2991
-f 2858/2810/2993 2857/2809/2992 2859/2811/2994
-f 2859/2811/2994 2860/2812/2995 2858/2810/2993
-f 2860/2812/2995 2859/2811/2994 2861/2813/2996
-f 2861/2813/2996 2862/2814/2997 2860/2812/2995
-f 2461/2413/2520 2472/2424/2533 2863/2815/2998
-f 2863/2815/2998 2843/2795/2978 2461/2413/2520
-f 2843/2795/2978 2863/2815/2998 2864/2816/2999
-f 2864/2816/2999 2845/2797/2980 2843/2795/2978
-f 2845/2797/2980 2864/2816/2999 2865/2817/3000
-f 2865/2817/3000 2847/2799/2982 2845/2797/2980
-f 2847/2799/2982 2865/2817/3000 2866/2818/3001
-f 2866/2818/3001 2849/2801/2984 2847/2799/2982
-f 2849/2801/2984 2866/2818/3001 2867/2819/3002
-f 2867/2819/3002 2851/2803/2986 2849/2801/2984
-f 2851/2803/2986 2867/2819/3002 2868/2820/3003
-f 2868/2820/3003 2853/2805/2988 2851/2803/2986
-f 2853/2805/2988 2868/2820/3003 2869/2821/3004
-f 2869/2821/3004 2855/2807/2990 2853/2805/2988
-f 2855/2807/2990 2869/2821/3004 2870/2822/3005
-f 2870/2822/3005 2857/2809/2992 2855/2807/2990
-f 2857/2809/2992 2870/2822/3005 2871/2823/3006
-f 2871/2823/3006 2859/2811/2994 2857/2809/2992
-f 2859/2811/2994 2871/2823/3006 2872/2824/3007
-f 2872/2824/3007 2861/2813/2996 2859/2811/2994
-f 2472/2424/2533 2482/2434/2545 2873/2825/3008
-f 2873/2825/3008 2863/2815/2998 2472/2424/2533
-f 2863/2815/2998 2873/2825/3008 2874/2826/3009
-f 2874/2826/3009 2864/2816/2999 2863/2815/2998
-f 2864/2816/2999 2874/2826/3009 2875/2827/3010
-f 2875/2827/3010 2865/2817/3000 2864/2816/2999
-f 2865/2817/3000 2875/2827/3010 2876/2828/3011
-f 2876/2828/3011 2866/2818/3001 2865/2817/3000
-f 2866/2818/3001 2876/2828/3011 2877/2829/3012
-f 2877/2829/3012 2867/2819/3002 2866/2818/3001
-f 2867/2819/3002 2877/2829/3012 2878/2830/3013
-f 2878/2830/3013 2868/2820/3003 2867/2819/3002
-f 2868/2820/3003 2878/2830/3013 2879/2831/3014
-f 2879/2831/3014 2869/2821/3004 2868/2820/3003
-f 2869/2821/3004 2879/2831/3014 2880/2832/3015
-f 2880/2832/3015 2870/2822/3005 2869/2821/3004
-f 2870/2822/3005 2880/2832/3015 2881/2833/3016
-f 2881/2833/3016 2871/2823/3006 2870/2822/3005
-f 2871/2823/3006 2881/2833/3016 2882/2834/3017
-f 2882/2834/3017 2872/2824/3007 2871/2823/3006
-f 2482/2434/2545 2492/2444/2557 2883/2835/3018
-f 2883/2835/3018 2873/2825/3008 2482/2434/2545
-f 2873/2825/3008 2883/2835/3018 2884/2836/3019
-f 2884/2836/3019 2874/2826/3009 2873/2825/3008
-f 2874/2826/3009 2884/2836/3019 2885/2837/3020
-f 2885/2837/3020 2875/2827/3010 2874/2826/3009
-f 2875/2827/3010 2885/2837/3020 2886/2838/3021
-f 2886/2838/3021 2876/2828/3011 2875/2827/3010
-f 2876/2828/3011 2886/2838/3021 2887/2839/3022
-f 2887/2839/3022 2877/2829/3012 2876/2828/3011
-f 2877/2829/3012 2887/2839/3022 2888/2840/3023
-f 2888/2840/3023 2878/2830/3013 2877/2829/3012
-f 2878/2830/3013 2888/2840/3023 2889/2841/3024
-f 2889/2841/3024 2879/2831/3014 2878/2830/3013
-f 2879/2831/3014 2889/2841/3024 2890/2842/3025
-f 2890/2842/3025 2880/2832/3015 2879/2831/3014
-f 2880/2832/3015 2890/2842/3025 2891/2843/3026
-f 2891/2843/3026 2881/2833/3016 2880/2832/3015
-f 2881/2833/3016 2891/2843/3026 2892/2844/3027
-f 2892/2844/3027 2882/2834/3017 2881/2833/3016
-f 2492/2444/2557 2502/2454/2569 2893/2845/3028
-f 2893/2845/3028 2883/2835/3018 2492/2444/2557
-f 2883/2835/3018 2893/2845/3028 2894/2846/3029
-f 2894/2846/3029 2884/2836/3019 2883/2835/3018
-f 2884/2836/3019 2894/2846/3029 2895/2847/3030
-f 2895/2847/3030 2885/2837/3020 2884/2836/3019
-f 2885/2837/3020 2895/2847/3030 2896/2848/3031
-f 2896/2848/3031 2886/2838/3021 2885/2837/3020
-f 2886/2838/3021 2896/2848/3031 2897/2849/3032
-f 2897/2849/3032 2887/2839/3022 2886/2838/3021
-f 2887/2839/3022 2897/2849/3032 2898/2850/3033
-f 2898/2850/3033 2888/2840/3023 2887/2839/3022
-f 2888/2840/3023 2898/2850/3033 2899/2851/3034
-f 2899/2851/3034 2889/2841/3024 2888/2840/3023
-f 2889/2841/3024 2899/2851/3034 2900/2852/3035
-f 2900/2852/3035 2890/2842/3025 2889/2841/3024
-f 2890/2842/3025 2900/2852/3035 2901/2853/3036
-f 2901/2853/3036 2891/2843/3026 2890/2842/3025
-f 2891/2843/3026 2901/2853/3036 2902/2854/3037
-f 2902/2854/3037 2892/2844/3027 2891/2843/3026
-f 2502/2454/2569 2512/2464/2581 2903/2855/3038
-f 2903/2855/3038 2893/2845/3028 2502/2454/2569
-f 2893/2845/3028 2903/2855/3038 2904/2856/3039
-f 2904/2856/3039 2894/2846/3029 2893/2845/3028
-f 2894/2846/3029 2904/2856/3039 2905/2857/3040
-f 2905/2857/3040 2895/2847/3030 2894/2846/3029
-f 2895/2847/3030 2905/2857/3040 2906/2858/3041
-f 2906/2858/3041 2896/2848/3031 2895/2847/3030
-f 2896/2848/3031 2906/2858/3041 2907/2859/3042
-f 2907/2859/3042 2897/2849/3032 2896/2848/3031
-f 2897/2849/3032 2907/2859/3042 2908/2860/3043
-f 2908/2860/3043 2898/2850/3033 2897/2849/3032
-f 2898/2850/3033 2908/2860/3043 2909/2861/3044
-f 2909/2861/3044 2899/2851/3034 2898/2850/3033
-f 2899/2851/3034 2909/2861/3044 2910/2862/3045
-f 2910/2862/3045 2900/2852/3035 2899/2851/3034
-f 2900/2852/3035 2910/2862/3045 2911/2863/3046
-f 2911/2863/3046 2901/2853/3036 2900/2852/3035
-f 2901/2853/3036 2911/2863/3046 2912/2864/3047
-f 2912/2864/3047 2902/2854/3037 2901/2853/3036
-f 2512/2464/2581 2522/2474/2593 2913/2865/3048
-f 2913/2865/3048 2903/2855/3038 2512/2464/2581
-f 2903/2855/3038 2913/2865/3048 2914/2866/3049
-f 2914/2866/3049 2904/2856/3039 2903/2855/3038
-f 2904/2856/3039 2914/2866/3049 2915/2867/3050
-f 2915/2867/3050 2905/2857/3040 2904/2856/3039
-f 2905/2857/3040 2915/2867/3050 2916/2868/3051
-f 2916/2868/3051 2906/2858/3041 2905/2857/3040
-f 2906/2858/3041 2916/2868/3051 2917/2869/3052
-f 2917/2869/3052 2907/2859/3042 2906/2858/3041
-f 2907/2859/3042 2917/2869/3052 2918/2870/3053
-f 2918/2870/3053 2908/2860/3043 2907/2859/3042
-f 2908/2860/3043 2918/2870/3053 2919/2871/3054
-f 2919/2871/3054 2909/2861/3044 2908/2860/3043
-f 2909/2861/3044 2919/2871/3054 2920/2872/3055
-f 2920/2872/3055 2910/2862/3045 2909/2861/3044
-f 2910/2862/3045 2920/2872/3055 2921/2873/3056
-f 2921/2873/3056 2911/2863/3046 2910/2862/3045
-f 2911/2863/3046 2921/2873/3056 2922/2874/3057
-f 2922/2874/3057 2912/2864/3047 2911/2863/3046
-f 2522/2474/2593 2532/2484/2605 2923/2875/3058
-f 2923/2875/3058 2913/2865/3048 2522/2474/2593
-f 2913/2865/3048 2923/2875/3058 2924/2876/3059
-f 2924/2876/3059 2914/2866/3049 2913/2865/3048
-f 2914/2866/3049 2924/2876/3059 2925/2877/3060
-f 2925/2877/3060 2915/2867/3050 2914/2866/3049
-f 2915/2867/3050 2925/2877/3060 2926/2878/3061
-f 2926/2878/3061 2916/2868/3051 2915/2867/3050
-f 2916/2868/3051 2926/2878/3061 2927/2879/3062
-f 2927/2879/3062 2917/2869/3052 2916/2868/3051
-f 2917/2869/3052 2927/2879/3062 2928/2880/3063
-f 2928/2880/3063 2918/2870/3053 2917/2869/3052
-f 2918/2870/3053 2928/2880/3063 2929/2881/3064
-f 2929/2881/3064 2919/2871/3054 2918/2870/3053
-f 2919/2871/3054 2929/2881/3064 2930/2882/3065
-f 2930/2882/3065 2920/2872/3055 2919/2871/3054
-f 2920/2872/3055 2930/2882/3065 2931/2883/3066
-f 2931/2883/3066 2921/2873/3056 2920/2872/3055
-f 2921/2873/3056 2931/2883/3066 2932/2884/3067
-f 2932/2884/3067 2922/2874/3057 2921/2873/3056
-f 2532/2484/2605 2542/2494/2617 2933/2885/3068
-f 2933/2885/3068 2923/2875/3058 2532/2484/2605
-f 2923/2875/3058 2933/2885/3068 2934/2886/3069
-f 2934/2886/3069 2924/2876/3059 2923/2875/3058
-f 2924/2876/3059 2934/2886/3069 2935/2887/3070
-f 2935/2887/3070 2925/2877/3060 2924/2876/3059
-f 2925/2877/3060 2935/2887/3070 2936/2888/3071
-f 2936/2888/3071 2926/2878/3061 2925/2877/3060
-f 2926/2878/3061 2936/2888/3071 2937/2889/3072
-f 2937/2889/3072 2927/2879/3062 2926/2878/3061
-f 2927/2879/3062 2937/2889/3072 2938/2890/3073
-f 2938/2890/3073 2928/2880/3063 2927/2879/3062
-f 2928/2880/3063 2938/2890/3073 2939/2891/3074
-f 2939/2891/3074 2929/2881/3064 2928/2880/3063
-f 2929/2881/3064 2939/2891/3074 2940/2892/3075
-f 2940/2892/3075 2930/2882/3065 2929/2881/3064
-f 2930/2882/3065 2940/2892/3075 2941/2893/3076
-f 2941/2893/3076 2931/2883/3066 2930/2882/3065
-f 2931/2883/3066 2941/2893/3076 2942/2894/3077
-f 2942/2894/3077 2932/2884/3067 2931/2883/3066
-f 2542/2494/2617 2552/2504/2629 2943/2895/3078
-f 2943/2895/3078 2933/2885/3068 2542/2494/2617
-f 2933/2885/3068 2943/2895/3078 2944/2896/3079
-f 2944/2896/3079 2934/2886/3069 2933/2885/3068
-f 2934/2886/3069 2944/2896/3079 2945/2897/3080
-f 2945/2897/3080 2935/2887/3070 2934/2886/3069
-f 2935/2887/3070 2945/2897/3080 2946/2898/3081
-f 2946/2898/3081 2936/2888/3071 2935/2887/3070
-f 2936/2888/3071 2946/2898/3081 2947/2899/3082
-f 2947/2899/3082 2937/2889/3072 2936/2888/3071
-f 2937/2889/3072 2947/2899/3082 2948/2900/3083
-f 2948/2900/3083 2938/2890/3073 2937/2889/3072
-f 2938/2890/3073 2948/2900/3083 2949/2901/3084
-f 2949/2901/3084 2939/2891/3074 2938/2890/3073
-f 2939/2891/3074 2949/2901/3084 2950/2902/3085
-f 2950/2902/3085 2940/2892/3075 2939/2891/3074
-f 2940/2892/3075 2950/2902/3085 2951/2903/3086
-f 2951/2903/3086 2941/2893/3076 2940/2892/3075
-f 2941/2893/3076 2951/2903/3086 2952/2904/3087
-f 2952/2904/3087 2942/2894/3077 2941/2893/3076
-f 2552/2504/2629 2562/2514/2641 2953/2905/3088
-f 2953/2905/3088 2943/2895/3078 2552/2504/2629
-f 2943/2895/3078 2953/2905/3088 2954/2906/3089
-f 2954/2906/3089 2944/2896/3079 2943/2895/3078
-f 2944/2896/3079 2954/2906/3089 2955/2907/3090
-f 2955/2907/3090 2945/2897/3080 2944/2896/3079
-f 2945/2897/3080 2955/2907/3090 2956/2908/3091
-f 2956/2908/3091 2946/2898/3081 2945/2897/3080
-f 2946/2898/3081 2956/2908/3091 2957/2909/3092
-f 2957/2909/3092 2947/2899/3082 2946/2898/3081
-f 2947/2899/3082 2957/2909/3092 2958/2910/3093
-f 2958/2910/3093 2948/2900/3083 2947/2899/3082
-f 2948/2900/3083 2958/2910/3093 2959/2911/3094
-f 2959/2911/3094 2949/2901/3084 2948/2900/3083
-f 2949/2901/3084 2959/2911/3094 2960/2912/3095
-f 2960/2912/3095 2950/2902/3085 2949/2901/3084
-f 2950/2902/3085 2960/2912/3095 2961/2913/3096
-f 2961/2913/3096 2951/2903/3086 2950/2902/3085
-f 2951/2903/3086 2961/2913/3096 2962/2914/3097
-f 2962/2914/3097 2952/2904/3087 2951/2903/3086
-f 2562/2514/2641 2572/2524/2653 2963/2915/3098
-f 2963/2915/3098 2953/2905/3088 2562/2514/2641
-f 2953/2905/3088 2963/2915/3098 2964/2916/3099
-f 2964/2916/3099 2954/2906/3089 2953/2905/3088
-f 2954/2906/3089 2964/2916/3099 2965/2917/3100
-f 2965/2917/3100 2955/2907/3090 2954/2906/3089
-f 2955/2907/3090 2965/2917/3100 2966/2918/3101
-f 2966/2918/3101 2956/2908/3091 2955/2907/3090
-f 2956/2908/3091 2966/2918/3101 2967/2919/3102
-f 2967/2919/3102 2957/2909/3092 2956/2908/3091
-f 2957/2909/3092 2967/2919/3102 2968/2920/3103
-f 2968/2920/3103 2958/2910/3093 2957/2909/3092
-f 2958/2910/3093 2968/2920/3103 2969/2921/3104
-f 2969/2921/3104 2959/2911/3094 2958/2910/3093
-f 2959/2911/3094 2969/2921/3104 2970/2922/3105
-f 2970/2922/3105 2960/2912/3095 2959/2911/3094
-f 2960/2912/3095 2970/2922/3105 2971/2923/3106
-f 2971/2923/3106 2961/2913/3096 2960/2912/3095
-f 2961/2913/3096 2971/2923/3106 2972/2924/3107
-f 2972/2924/3107 2962/2914/3097 2961/2913/3096
-f 2572/2524/2653 2582/2534/2665 2973/2925/3108
-f 2973/2925/3108 2963/2915/3098 2572/2524/2653
-f 2963/2915/3098 2973/2925/3108 2974/2926/3109
-f 2974/2926/3109 2964/2916/3099 2963/2915/3098
-f 2964/2916/3099 2974/2926/3109 2975/2927/3110
-f 2975/2927/3110 2965/2917/3100 2964/2916/3099
-f 2965/2917/3100 2975/2927/3110 2976/2928/3111
-f 2976/2928/3111 2966/2918/3101 2965/2917/3100
-f 2966/2918/3101 2976/2928/3111 2977/2929/3112
-f 2977/2929/3112 2967/2919/3102 2966/2918/3101
-f 2967/2919/3102 2977/2929/3112 2978/2930/3113
-f 2978/2930/3113 2968/2920/3103 2967/2919/3102
-f 2968/2920/3103 2978/2930/3113 2979/2931/3114
-f 2979/2931/3114 2969/2921/3104 2968/2920/3103
-f 2969/2921/3104 2979/2931/3114 2980/2932/3115
-f 2980/2932/3115 2970/2922/3105 2969/2921/3104
-f 2970/2922/3105 2980/2932/3115 2981/2933/3116
-f 2981/2933/3116 2971/2923/3106 2970/2922/3105
-f 2971/2923/3106 2981/2933/3116 2982/2934/3117
-f 2982/2934/3117 2972/2924/3107 2971/2923/3106
-f 2582/2534/2665 2592/2544/2677 2983/2935/3118
-f 2983/2935/3118 2973/2925/3108 2582/2534/2665
-f 2973/2925/3108 2983/2935/3118 2984/2936/3119
-f 2984/2936/3119 2974/2926/3109 2973/2925/3108
-f 2974/2926/3109 2984/2936/3119 2985/2937/3120
-f 2985/2937/3120 2975/2927/3110 2974/2926/3109
-f 2975/2927/3110 2985/2937/3120 2986/2938/3121
-f 2986/2938/3121 2976/2928/3111 2975/2927/3110
-f 2976/2928/3111 2986/2938/3121 2987/2939/3122
-f 2987/2939/3122 2977/2929/3112 2976/2928/3111
-f 2977/2929/3112 2987/2939/3122 2988/2940/3123
-f 2988/2940/3123 2978/2930/3113 2977/2929/3112
-f 2978/2930/3113 2988/2940/3123 2989/2941/3124
-f 2989/2941/3124 2979/2931/3114 2978/2930/3113
-f 2979/2931/3114 2989/2941/3124 2990/2942/3125
-f 2990/2942/3125 2980/2932/3115 2979/2931/3114
-f 2980/2932/3115 2990/2942/3125 2991/2943/3126
-f 2991/2943/3126 2981/2933/3116 2980/2932/3115
-f 2981/2933/3116 2991/2943/3126 2992/2944/3127
-f 2992/2944/3127 2982/2934/3117 2981/2933/3116
-f 2592/2544/2677 2602/2554/2689 2993/2945/3128
-f 2993/2945/3128 2983/2935/3118 2592/2544/2677
-f 2983/2935/3118 2993/2945/3128 2994/2946/3129
-f 2994/2946/3129 2984/2936/3119 2983/2935/3118
-f 2984/2936/3119 2994/2946/3129 2995/2947/3130
-f 2995/2947/3130 2985/2937/3120 2984/2936/3119
-f 2985/2937/3120 2995/2947/3130 2996/2948/3131
-f 2996/2948/3131 2986/2938/3121 2985/2937/3120
-f 2986/2938/3121 2996/2948/3131 2997/2949/3132
-f 2997/2949/3132 2987/2939/3122 2986/2938/3121
-f 2987/2939/3122 2997/2949/3132 2998/2950/3133
-f 2998/2950/3133 2988/2940/3123 2987/2939/3122
-f 2988/2940/3123 2998/2950/3133 2999/2951/3134
-f 2999/2951/3134 2989/2941/3124 2988/2940/3123
-f 2989/2941/3124 2999/2951/3134 3000/2952/3135
-f 3000/2952/3135 2990/2942/3125 2989/2941/3124
-f 2990/2942/3125 3000/2952/3135 3001/2953/3136
-f 3001/2953/3136 2991/2943/3126 2990/2942/3125
-f 2991/2943/3126 3001/2953/3136 3002/2954/3137
-f 3002/2954/3137 2992/2944/3127 2991/2943/3126
-f 2602/2554/2689 2612/2564/2701 3003/2955/3138
-f 3003/2955/3138 2993/2945/3128 2602/2554/2689
-f 2993/2945/3128 3003/2955/3138 3004/2956/3139
-f 3004/2956/3139 2994/2946/3129 2993/2945/3128
-f 2994/2946/3129 3004/2956/3139 3005/2957/3140
-f 3005/2957/3140 2995/2947/3130 2994/2946/3129
-f 2995/2947/3130 3005/2957/3140 3006/2958/3141
-f 3006/2958/3141 2996/2948/3131 2995/2947/3130
-f 2996/2948/3131 3006/2958/3141 3007/2959/3142
-f 3007/2959/3142 2997/2949/3132 2996/2948/3131
-f 2997/2949/3132 3007/2959/3142 3008/2960/3143
-f 3008/2960/3143 2998/2950/3133 2997/2949/3132
-f 2998/2950/3133 3008/2960/3143 3009/2961/3144
-f 3009/2961/3144 2999/2951/3134 2998/2950/3133
-f 2999/2951/3134 3009/2961/3144 3010/2962/3145
-f 3010/2962/3145 3000/2952/3135 2999/2951/3134
-f 3000/2952/3135 3010/2962/3145 3011/2963/3146
-f 3011/2963/3146 3001/2953/3136 3000/2952/3135
-f 3001/2953/3136 3011/2963/3146 3012/2964/3147
-f 3012/2964/3147 3002/2954/3137 3001/2953/3136
-f 2612/2564/2701 2622/2574/2713 3013/2965/3148
-f 3013/2965/3148 3003/2955/3138 2612/2564/2701
-f 3003/2955/3138 3013/2965/3148 3014/2966/3149
-f 3014/2966/3149 3004/2956/3139 3003/2955/3138
-f 3004/2956/3139 3014/2966/3149 3015/2967/3150
-f 3015/2967/3150 3005/2957/3140 3004/2956/3139
-f 3005/2957/3140 3015/2967/3150 3016/2968/3151
-f 3016/2968/3151 3006/2958/3141 3005/2957/3140
-f 3006/2958/3141 3016/2968/3151 3017/2969/3152
-f 3017/2969/3152 3007/2959/3142 3006/2958/3141
-f 3007/2959/3142 3017/2969/3152 3018/2970/3153
-f 3018/2970/3153 3008/2960/3143 3007/2959/3142
-f 3008/2960/3143 3018/2970/3153 3019/2971/3154
-f 3019/2971/3154 3009/2961/3144 3008/2960/3143
-f 3009/2961/3144 3019/2971/3154 3020/2972/3155
-f 3020/2972/3155 3010/2962/3145 3009/2961/3144
-f 3010/2962/3145 3020/2972/3155 3021/2973/3156
-f 3021/2973/3156 3011/2963/3146 3010/2962/3145
-f 3011/2963/3146 3021/2973/3156 3022/2974/3157
-f 3022/2974/3157 3012/2964/3147 3011/2963/3146
-f 2622/2574/2713 2632/2584/2725 3023/2975/3158
-f 3023/2975/3158 3013/2965/3148 2622/2574/2713
-f 3013/2965/3148 3023/2975/3158 3024/2976/3159
-f 3024/2976/3159 3014/2966/3149 3013/2965/3148
-f 3014/2966/3149 3024/2976/3159 3025/2977/3160
-f 3025/2977/3160 3015/2967/3150 3014/2966/3149
-f 3015/2967/3150 3025/2977/3160 3026/2978/3161
-f 3026/2978/3161 3016/2968/3151 3015/2967/3150
-f 3016/2968/3151 3026/2978/3161 3027/2979/3162
-f 3027/2979/3162 3017/2969/3152 3016/2968/3151
-f 3017/2969/3152 3027/2979/3162 3028/2980/3163
-f 3028/2980/3163 3018/2970/3153 3017/2969/3152
-f 3018/2970/3153 3028/2980/3163 3029/2981/3164
-f 3029/2981/3164 3019/2971/3154 3018/2970/3153
-f 3019/2971/3154 3029/2981/3164 3030/2982/3165
-f 3030/2982/3165 3020/2972/3155 3019/2971/3154
-f 3020/2972/3155 3030/2982/3165 3031/2983/3166
-f 3031/2983/3166 3021/2973/3156 3020/2972/3155
-f 3021/2973/3156 3031/2983/3166 3032/2984/3167
-f 3032/2984/3167 3022/2974/3157 3021/2973/3156
-f 2632/2584/2725 2642/2594/2737 3033/2985/3168
-f 3033/2985/3168 3023/2975/3158 2632/2584/2725
-f 3023/2975/3158 3033/2985/3168 3034/2986/3169
-f 3034/2986/3169 3024/2976/3159 3023/2975/3158
-f 3024/2976/3159 3034/2986/3169 3035/2987/3170
-f 3035/2987/3170 3025/2977/3160 3024/2976/3159
-f 3025/2977/3160 3035/2987/3170 3036/2988/3171
-f 3036/2988/3171 3026/2978/3161 3025/2977/3160
-f 3026/2978/3161 3036/2988/3171 3037/2989/3172
-f 3037/2989/3172 3027/2979/3162 3026/2978/3161
-f 3027/2979/3162 3037/2989/3172 3038/2990/3173
-f 3038/2990/3173 3028/2980/3163 3027/2979/3162
-f 3028/2980/3163 3038/2990/3173 3039/2991/3174
-f 3039/2991/3174 3029/2981/3164 3028/2980/3163
-f 3029/2981/3164 3039/2991/3174 3040/2992/3175
-f 3040/2992/3175 3030/2982/3165 3029/2981/3164
-f 3030/2982/3165 3040/2992/3175 3041/2993/3176
-f 3041/2993/3176 3031/2983/3166 3030/2982/3165
-f 3031/2983/3166 3041/2993/3176 3042/2994/3177
-f 3042/2994/3177 3032/2984/3167 3031/2983/3166
-f 2642/2594/2737 2652/2604/2749 3043/2995/3178
-f 3043/2995/3178 3033/2985/3168 2642/2594/2737
-f 3033/2985/3168 3043/2995/3178 3044/2996/3179
-f 3044/2996/3179 3034/2986/3169 3033/2985/3168
-f 3034/2986/3169 3044/2996/3179 3045/2997/3180
-f 3045/2997/3180 3035/2987/3170 3034/2986/3169
-f 3035/2987/3170 3045/2997/3180 3046/2998/3181
-f 3046/2998/3181 3036/2988/3171 3035/2987/3170
-f 3036/2988/3171 3046/2998/3181 3047/2999/3182
-f 3047/2999/3182 3037/2989/3172 3036/2988/3171
-f 3037/2989/3172 3047/2999/3182 3048/3000/3183
-f 3048/3000/3183 3038/2990/3173 3037/2989/3172
-f 3038/2990/3173 3048/3000/3183 3049/3001/3184
-f 3049/3001/3184 3039/2991/3174 3038/2990/3173
-f 3039/2991/3174 3049/3001/3184 3050/3002/3185
-f 3050/3002/3185 3040/2992/3175 3039/2991/3174
-f 3040/2992/3175 3050/3002/3185 3051/3003/3186
-f 3051/3003/3186 3041/2993/3176 3040/2992/3175
-f 3041/2993/3176 3051/3003/3186 3052/3004/3187
-f 3052/3004/3187 3042/2994/3177 3041/2993/3176
-f 2652/2604/2749 2662/2614/2761 3053/3005/3188
-f 3053/3005/3188 3043/2995/3178 2652/2604/2749
-f 3043/2995/3178 3053/3005/3188 3054/3006/3189
-f 3054/3006/3189 3044/2996/3179 3043/2995/3178
-f 3044/2996/3179 3054/3006/3189 3055/3007/3190
-f 3055/3007/3190 3045/2997/3180 3044/2996/3179
-f 3045/2997/3180 3055/3007/3190 3056/3008/3191
-f 3056/3008/3191 3046/2998/3181 3045/2997/3180
-f 3046/2998/3181 3056/3008/3191 3057/3009/3192
-f 3057/3009/3192 3047/2999/3182 3046/2998/3181
-f 3047/2999/3182 3057/3009/3192 3058/3010/3193
-f 3058/3010/3193 3048/3000/3183 3047/2999/3182
-f 3048/3000/3183 3058/3010/3193 3059/3011/3194
-f 3059/3011/3194 3049/3001/3184 3048/3000/3183
-f 3049/3001/3184 3059/3011/3194 3060/3012/3195
-f 3060/3012/3195 3050/3002/3185 3049/3001/3184
-f 3050/3002/3185 3060/3012/3195 3061/3013/3196
-f 3061/3013/3196 3051/3003/3186 3050/3002/3185
-f 3051/3003/3186 3061/3013/3196 3062/3014/3197
-f 3062/3014/3197 3052/3004/3187 3051/3003/3186
-f 2662/2614/2761 2672/2624/2773 3063/3015/3198
-f 3063/3015/3198 3053/3005/3188 2662/2614/2761
-f 3053/3005/3188 3063/3015/3198 3064/3016/3199
-f 3064/3016/3199 3054/3006/3189 3053/3005/3188
-f 3054/3006/3189 3064/3016/3199 3065/3017/3200
-f 3065/3017/3200 3055/3007/3190 3054/3006/3189
-f 3055/3007/3190 3065/3017/3200 3066/3018/3201
-f 3066/3018/3201 3056/3008/3191 3055/3007/3190
-f 3056/3008/3191 3066/3018/3201 3067/3019/3202
-f 3067/3019/3202 3057/3009/3192 3056/3008/3191
-f 3057/3009/3192 3067/3019/3202 3068/3020/3203
-f 3068/3020/3203 3058/3010/3193 3057/3009/3192
-f 3058/3010/3193 3068/3020/3203 3069/3021/3204
-f 3069/3021/3204 3059/3011/3194 3058/3010/3193
-f 3059/3011/3194 3069/3021/3204 3070/3022/3205
-f 3070/3022/3205 3060/3012/3195 3059/3011/3194
-f 3060/3012/3195 3070/3022/3205 3071/3023/3206
-f 3071/3023/3206 3061/3013/3196 3060/3012/3195
-f 3061/3013/3196 3071/3023/3206 3072/3024/3207
-f 3072/3024/3207 3062/3014/3197 3061/3013/3196
-f 2672/2624/2773 2682/2634/2785 3073/3025/3208
-f 3073/3025/3208 3063/3015/3198 2672/2624/2773
-f 3063/3015/3198 3073/3025/3208 3074/3026/3209
-f 3074/3026/3209 3064/3016/3199 3063/3015/3198
-f 3064/3016/3199 3074/3026/3209 3075/3027/3210
-f 3075/3027/3210 3065/3017/3200 3064/3016/3199
-f 3065/3017/3200 3075/3027/3210 3076/3028/3211
-f 3076/3028/3211 3066/3018/3201 3065/3017/3200
-f 3066/3018/3201 3076/3028/3211 3077/3029/3212
-f 3077/3029/3212 3067/3019/3202 3066/3018/3201
-f 3067/3019/3202 3077/3029/3212 3078/3030/3213
-f 3078/3030/3213 3068/3020/3203 3067/3019/3202
-f 3068/3020/3203 3078/3030/3213 3079/3031/3214
-f 3079/3031/3214 3069/3021/3204 3068/3020/3203
-f 3069/3021/3204 3079/3031/3214 3080/3032/3215
-f 3080/3032/3215 3070/3022/3205 3069/3021/3204
-f 3070/3022/3205 3080/3032/3215 3081/3033/3216
-f 3081/3033/3216 3071/3023/3206 3070/3022/3205
-f 3071/3023/3206 3081/3033/3216 3082/3034/3217
-f 3082/3034/3217 3072/3024/3207 3071/3023/3206
-f 2682/2634/2785 2692/2644/2797 3083/3035/3218
-f 3083/3035/3218 3073/3025/3208 2682/2634/2785
-f 3073/3025/3208 3083/3035/3218 3084/3036/3219
-f 3084/3036/3219 3074/3026/3209 3073/3025/3208
-f 3074/3026/3209 3084/3036/3219 3085/3037/3220
-f 3085/3037/3220 3075/3027/3210 3074/3026/3209
-f 3075/3027/3210 3085/3037/3220 3086/3038/3221
-f 3086/3038/3221 3076/3028/3211 3075/3027/3210
-f 3076/3028/3211 3086/3038/3221 3087/3039/3222
-f 3087/3039/3222 3077/3029/3212 3076/3028/3211
-f 3077/3029/3212 3087/3039/3222 3088/3040/3223
-f 3088/3040/3223 3078/3030/3213 3077/3029/3212
-f 3078/3030/3213 3088/3040/3223 3089/3041/3224
-f 3089/3041/3224 3079/3031/3214 3078/3030/3213
-f 3079/3031/3214 3089/3041/3224 3090/3042/3225
-f 3090/3042/3225 3080/3032/3215 3079/3031/3214
-f 3080/3032/3215 3090/3042/3225 3091/3043/3226
-f 3091/3043/3226 3081/3033/3216 3080/3032/3215
-f 3081/3033/3216 3091/3043/3226 3092/3044/3227
-f 3092/3044/3227 3082/3034/3217 3081/3033/3216
-f 2692/2644/2797 2702/2654/2809 3093/3045/3228
-f 3093/3045/3228 3083/3035/3218 2692/2644/2797
-f 3083/3035/3218 3093/3045/3228 3094/3046/3229
-f 3094/3046/3229 3084/3036/3219 3083/3035/3218
-f 3084/3036/3219 3094/3046/3229 3095/3047/3230
-f 3095/3047/3230 3085/3037/3220 3084/3036/3219
-f 3085/3037/3220 3095/3047/3230 3096/3048/3231
-f 3096/3048/3231 3086/3038/3221 3085/3037/3220
-f 3086/3038/3221 3096/3048/3231 3097/3049/3232
-f 3097/3049/3232 3087/3039/3222 3086/3038/3221
-f 3087/3039/3222 3097/3049/3232 3098/3050/3233
-f 3098/3050/3233 3088/3040/3223 3087/3039/3222
-f 3088/3040/3223 3098/3050/3233 3099/3051/3234
-f 3099/3051/3234 3089/3041/3224 3088/3040/3223
-f 3089/3041/3224 3099/3051/3234 3100/3052/3235
-f 3100/3052/3235 3090/3042/3225 3089/3041/3224
-f 3090/3042/3225 3100/3052/3235 3101/3053/3236
-f 3101/3053/3236 3091/3043/3226 3090/3042/3225
-f 3091/3043/3226 3101/3053/3236 3102/3054/3237
-f 3102/3054/3237 3092/3044/3227 3091/3043/3226
-f 2702/2654/2809 2712/2664/2821 3103/3055/3238
-f 3103/3055/3238 3093/3045/3228 2702/2654/2809
-f 3093/3045/3228 3103/3055/3238 3104/3056/3239
-f 3104/3056/3239 3094/3046/3229 3093/3045/3228
-f 3094/3046/3229 3104/3056/3239 3105/3057/3240
-f 3105/3057/3240 3095/3047/3230 3094/3046/3229
-f 3095/3047/3230 3105/3057/3240 3106/3058/3241
-f 3106/3058/3241 3096/3048/3231 3095/3047/3230
-f 3096/3048/3231 3106/3058/3241 3107/3059/3242
-f 3107/3059/3242 3097/3049/3232 3096/3048/3231
-f 3097/3049/3232 3107/3059/3242 3108/3060/3243
-f 3108/3060/3243 3098/3050/3233 3097/3049/3232
-f 3098/3050/3233 3108/3060/3243 3109/3061/3244
-f 3109/3061/3244 3099/3051/3234 3098/3050/3233
-f 3099/3051/3234 3109/3061/3244 3110/3062/3245
-f 3110/3062/3245 3100/3052/3235 3099/3051/3234
-f 3100/3052/3235 3110/3062/3245 3111/3063/3246
-f 3111/3063/3246 3101/3053/3236 3100/3052/3235
-f 3101/3053/3236 3111/3063/3246 3112/3064/3247
-f 3112/3064/3247 3102/3054/3237 3101/3053/3236
-f 2712/2664/2821 2722/2674/2833 3113/3065/3248
-f 3113/3065/3248 3103/3055/3238 2712/2664/2821
-f 3103/3055/3238 3113/3065/3248 3114/3066/3249
-f 3114/3066/3249 3104/3056/3239 3103/3055/3238
-f 3104/3056/3239 3114/3066/3249 3115/3067/3250
-f 3115/3067/3250 3105/3057/3240 3104/3056/3239
-f 3105/3057/3240 3115/3067/3250 3116/3068/3251
-f 3116/3068/3251 3106/3058/3241 3105/3057/3240
-f 3106/3058/3241 3116/3068/3251 3117/3069/3252
-f 3117/3069/3252 3107/3059/3242 3106/3058/3241
-f 3107/3059/3242 3117/3069/3252 3118/3070/3253
-f 3118/3070/3253 3108/3060/3243 3107/3059/3242
-f 3108/3060/3243 3118/3070/3253 3119/3071/3254
-f 3119/3071/3254 3109/3061/3244 3108/3060/3243
-f 3109/3061/3244 3119/3071/3254 3120/3072/3255
-f 3120/3072/3255 3110/3062/3245 3109/3061/3244
-f 3110/3062/3245 3120/3072/3255 3121/3073/3256
-f 3121/3073/3256 3111/3063/3246 3110/3062/3245
-f 3111/3063/3246 3121/3073/3256 3122/3074/3257
-f 3122/3074/3257 3112/3064/3247 3111/3063/3246
-f 2722/2674/2833 2732/2684/2845 3123/3075/3258
-f 3123/3075/3258 3113/3065/3248 2722/2674/2833
-f 3113/3065/3248 3123/3075/3258 3124/3076/3259
-f 3124/3076/3259 3114/3066/3249 3113/3065/3248
-f 3114/3066/3249 3124/3076/3259 3125/3077/3260
-f 3125/3077/3260 3115/3067/3250 3114/3066/3249
-f 3115/3067/3250 3125/3077/3260 3126/3078/3261
-f 3126/3078/3261 3116/3068/3251 3115/3067/3250
-f 3116/3068/3251 3126/3078/3261 3127/3079/3262
-f 3127/3079/3262 3117/3069/3252 3116/3068/3251
-f 3117/3069/3252 3127/3079/3262 3128/3080/3263
-f 3128/3080/3263 3118/3070/3253 3117/3069/3252
-f 3118/3070/3253 3128/3080/3263 3129/3081/3264
-f 3129/3081/3264 3119/3071/3254 3118/3070/3253
-f 3119/3071/3254 3129/3081/3264 3130/3082/3265
-f 3130/3082/3265 3120/3072/3255 3119/3071/3254
-f 3120/3072/3255 3130/3082/3265 3131/3083/3266
-f 3131/3083/3266 3121/3073/3256 3120/3072/3255
-f 3121/3073/3256 3131/3083/3266 3132/3084/3267
-f 3132/3084/3267 3122/3074/3257 3121/3073/3256
-f 2732/2684/2845 2742/2694/2857 3133/3085/3268
-f 3133/3085/3268 3123/3075/3258 2732/2684/2845
-f 3123/3075/3258 3133/3085/3268 3134/3086/3269
-f 3134/3086/3269 3124/3076/3259 3123/3075/3258
-f 3124/3076/3259 3134/3086/3269 3135/3087/3270
-f 3135/3087/3270 3125/3077/3260 3124/3076/3259
-f 3125/3077/3260 3135/3087/3270 3136/3088/3271
-f 3136/3088/3271 3126/3078/3261 3125/3077/3260
-f 3126/3078/3261 3136/3088/3271 3137/3089/3272
-f 3137/3089/3272 3127/3079/3262 3126/3078/3261
-f 3127/3079/3262 3137/3089/3272 3138/3090/3273
-f 3138/3090/3273 3128/3080/3263 3127/3079/3262
-f 3128/3080/3263 3138/3090/3273 3139/3091/3274
-f 3139/3091/3274 3129/3081/3264 3128/3080/3263
-f 3129/3081/3264 3139/3091/3274 3140/3092/3275
-f 3140/3092/3275 3130/3082/3265 3129/3081/3264
-f 3130/3082/3265 3140/3092/3275 3141/3093/3276
-f 3141/3093/3276 3131/3083/3266 3130/3082/3265
-f 3131/3083/3266 3141/3093/3276 3142/3094/3277
-f 3142/3094/3277 3132/3084/3267 3131/3083/3266
-f 2742/2694/2857 2752/2704/2869 3143/3095/3278
-f 3143/3095/3278 3133/3085/3268 2742/2694/2857
-f 3133/3085/3268 3143/3095/3278 3144/3096/3279
-f 3144/3096/3279 3134/3086/3269 3133/3085/3268
-f 3134/3086/3269 3144/3096/3279 3145/3097/3280
-f 3145/3097/3280 3135/3087/3270 3134/3086/3269
-f 3135/3087/3270 3145/3097/3280 3146/3098/3281
-f 3146/3098/3281 3136/3088/3271 3135/3087/3270
-f 3136/3088/3271 3146/3098/3281 3147/3099/3282
-f 3147/3099/3282 3137/3089/3272 3136/3088/3271
-f 3137/3089/3272 3147/3099/3282 3148/3100/3283
-f 3148/3100/3283 3138/3090/3273 3137/3089/3272
-f 3138/3090/3273 3148/3100/3283 3149/3101/3284
-f 3149/3101/3284 3139/3091/3274 3138/3090/3273
-f 3139/3091/3274 3149/3101/3284 3150/3102/3285
-f 3150/3102/3285 3140/3092/3275 3139/3091/3274
-f 3140/3092/3275 3150/3102/3285 3151/3103/3286
-f 3151/3103/3286 3141/3093/3276 3140/3092/3275
-f 3141/3093/3276 3151/3103/3286 3152/3104/3287
-f 3152/3104/3287 3142/3094/3277 3141/3093/3276
-f 2752/2704/2869 2762/2714/2881 3153/3105/3288
-f 3153/3105/3288 3143/3095/3278 2752/2704/2869
-f 3143/3095/3278 3153/3105/3288 3154/3106/3289
-f 3154/3106/3289 3144/3096/3279 3143/3095/3278
-f 3144/3096/3279 3154/3106/3289 3155/3107/3290
-f 3155/3107/3290 3145/3097/3280 3144/3096/3279
-f 3145/3097/3280 3155/3107/3290 3156/3108/3291
-f 3156/3108/3291 3146/3098/3281 3145/3097/3280
-f 3146/3098/3281 3156/3108/3291 3157/3109/3292
-f 3157/3109/3292 3147/3099/3282 3146/3098/3281
-f 3147/3099/3282 3157/3109/3292 3158/3110/3293
-f 3158/3110/3293 3148/3100/3283 3147/3099/3282
-f 3148/3100/3283 3158/3110/3293 3159/3111/3294
-f 3159/3111/3294 3149/3101/3284 3148/3100/3283
-f 3149/3101/3284 3159/3111/3294 3160/3112/3295
-f 3160/3112/3295 3150/3102/3285 3149/3101/3284
-f 3150/3102/3285 3160/3112/3295 3161/3113/3296
-f 3161/3113/3296 3151/3103/3286 3150/3102/3285
-f 3151/3103/3286 3161/3113/3296 3162/3114/3297
-f 3162/3114/3297 3152/3104/3287 3151/3103/3286
-f 2762/2714/2881 2772/2724/2893 3163/3115/3298
-f 3163/3115/3298 3153/3105/3288 2762/2714/2881
-f 3153/3105/3288 3163/3115/3298 3164/3116/3299
-f 3164/3116/3299 3154/3106/3289 3153/3105/3288
-f 3154/3106/3289 3164/3116/3299 3165/3117/3300
-f 3165/3117/3300 3155/3107/3290 3154/3106/3289
-f 3155/3107/3290 3165/3117/3300 3166/3118/3301
-f 3166/3118/3301 3156/3108/3291 3155/3107/3290
-f 3156/3108/3291 3166/3118/3301 3167/3119/3302
-f 3167/3119/3302 3157/3109/3292 3156/3108/3291
-f 3157/3109/3292 3167/3119/3302 3168/3120/3303
-f 3168/3120/3303 3158/3110/3293 3157/3109/3292
-f 3158/3110/3293 3168/3120/3303 3169/3121/3304
-f 3169/3121/3304 3159/3111/3294 3158/3110/3293
-f 3159/3111/3294 3169/3121/3304 3170/3122/3305
-f 3170/3122/3305 3160/3112/3295 3159/3111/3294
-f 3160/3112/3295 3170/3122/3305 3171/3123/3306
-f 3171/3123/3306 3161/3113/3296 3160/3112/3295
-f 3161/3113/3296 3171/3123/3306 3172/3124/3307
-f 3172/3124/3307 3162/3114/3297 3161/3113/3296
-f 2772/2724/2893 2782/2734/2905 3173/3125/3308
-f 3173/3125/3308 3163/3115/3298 2772/2724/2893
-f 3163/3115/3298 3173/3125/3308 3174/3126/3309
-f 3174/3126/3309 3164/3116/3299 3163/3115/3298
-f 3164/3116/3299 3174/3126/3309 3175/3127/3310
-f 3175/3127/3310 3165/3117/3300 3164/3116/3299
-f 3165/3117/3300 3175/3127/3310 3176/3128/3311
-f 3176/3128/3311 3166/3118/3301 3165/3117/3300
-f 3166/3118/3301 3176/3128/3311 3177/3129/3312
-f 3177/3129/3312 3167/3119/3302 3166/3118/3301
-f 3167/3119/3302 3177/3129/3312 3178/3130/3313
-f 3178/3130/3313 3168/3120/3303 3167/3119/3302
-f 3168/3120/3303 3178/3130/3313 3179/3131/3314
-f 3179/3131/3314 3169/3121/3304 3168/3120/3303
-f 3169/3121/3304 3179/3131/3314 3180/3132/3315
-f 3180/3132/3315 3170/3122/3305 3169/3121/3304
-f 3170/3122/3305 3180/3132/3315 3181/3133/3316
-f 3181/3133/3316 3171/3123/3306 3170/3122/3305
-f 3171/3123/3306 3181/3133/3316 3182/3134/3317
-f 3182/3134/3317 3172/3124/3307 3171/3123/3306
-f 2782/2734/2905 2792/2744/2917 3183/3135/3318
-f 3183/3135/3318 3173/3125/3308 2782/2734/2905
-f 3173/3125/3308 3183/3135/3318 3184/3136/3319
-f 3184/3136/3319 3174/3126/3309 3173/3125/3308
-f 3174/3126/3309 3184/3136/3319 3185/3137/3320
-f 3185/3137/3320 3175/3127/3310 3174/3126/3309
-f 3175/3127/3310 3185/3137/3320 3186/3138/3321
-f 3186/3138/3321 3176/3128/3311 3175/3127/3310
-f 3176/3128/3311 3186/3138/3321 3187/3139/3322
-f 3187/3139/3322 3177/3129/3312 3176/3128/3311
-f 3177/3129/3312 3187/3139/3322 3188/3140/3323
-f 3188/3140/3323 3178/3130/3313 3177/3129/3312
-f 3178/3130/3313 3188/3140/3323 3189/3141/3324
-f 3189/3141/3324 3179/3131/3314 3178/3130/3313
-f 3179/3131/3314 3189/3141/3324 3190/3142/3325
-f 3190/3142/3325 3180/3132/3315 3179/3131/3314
-f 3180/3132/3315 3190/3142/3325 3191/3143/3326
-f 3191/3143/3326 3181/3133/3316 3180/3132/3315
-f 3181/3133/3316 3191/3143/3326 3192/3144/3327
-f 3192/3144/3327 3182/3134/3317 3181/3133/3316
-f 2792/2744/2917 2802/2754/2929 3193/3145/3328
-f 3193/3145/3328 3183/3135/3318 2792/2744/2917
-f 3183/3135/3318 3193/3145/3328 3194/3146/3329
-f 3194/3146/3329 3184/3136/3319 3183/3135/3318
-f 3184/3136/3319 3194/3146/3329 3195/3147/3330
-f 3195/3147/3330 3185/3137/3320 3184/3136/3319
-f 3185/3137/3320 3195/3147/3330 3196/3148/3331
-f 3196/3148/3331 3186/3138/3321 3185/3137/3320
-f 3186/3138/3321 3196/3148/3331 3197/3149/3332
-f 3197/3149/3332 3187/3139/3322 3186/3138/3321
-f 3187/3139/3322 3197/3149/3332 3198/3150/3333
-f 3198/3150/3333 3188/3140/3323 3187/3139/3322
-f 3188/3140/3323 3198/3150/3333 3199/3151/3334
-f 3199/3151/3334 3189/3141/3324 3188/3140/3323
-f 3189/3141/3324 3199/3151/3334 3200/3152/3335
-f 3200/3152/3335 3190/3142/3325 3189/3141/3324
-f 3190/3142/3325 3200/3152/3335 3201/3153/3336
-f 3201/3153/3336 3191/3143/3326 3190/3142/3325
-f 3191/3143/3326 3201/3153/3336 3202/3154/3337
-f 3202/3154/3337 3192/3144/3327 3191/3143/3326
-f 2802/2754/2929 2812/2764/2941 3203/3155/3338
-f 3203/3155/3338 3193/3145/3328 2802/2754/2929
-f 3193/3145/3328 3203/3155/3338 3204/3156/3339
-f 3204/3156/3339 3194/3146/3329 3193/3145/3328
-f 3194/3146/3329 3204/3156/3339 3205/3157/3340
-f 3205/3157/3340 3195/3147/3330 3194/3146/3329
-f 3195/3147/3330 3205/3157/3340 3206/3158/3341
-f 3206/3158/3341 3196/3148/3331 3195/3147/3330
-f 3196/3148/3331 3206/3158/3341 3207/3159/3342
-f 3207/3159/3342 3197/3149/3332 3196/3148/3331
-f 3197/3149/3332 3207/3159/3342 3208/3160/3343
-f 3208/3160/3343 3198/3150/3333 3197/3149/3332
-f 3198/3150/3333 3208/3160/3343 3209/3161/3344
-f 3209/3161/3344 3199/3151/3334 3198/3150/3333
-f 3199/3151/3334 3209/3161/3344 3210/3162/3345
-f 3210/3162/3345 3200/3152/3335 3199/3151/3334
-f 3200/3152/3335 3210/3162/3345 3211/3163/3346
-f 3211/3163/3346 3201/3153/3336 3200/3152/3335
-f 3201/3153/3336 3211/3163/3346 3212/3164/3347
-f 3212/3164/3347 3202/3154/3337 3201/3153/3336
-f 2812/2764/2941 2822/2774/2953 3213/3165/3348
-f 3213/3165/3348 3203/3155/3338 2812/2764/2941
-f 3203/3155/3338 3213/3165/3348 3214/3166/3349
-f 3214/3166/3349 3204/3156/3339 3203/3155/3338
-f 3204/3156/3339 3214/3166/3349 3215/3167/3350
-f 3215/3167/3350 3205/3157/3340 3204/3156/3339
-f 3205/3157/3340 3215/3167/3350 3216/3168/3351
-f 3216/3168/3351 3206/3158/3341 3205/3157/3340
-f 3206/3158/3341 3216/3168/3351 3217/3169/3352
-f 3217/3169/3352 3207/3159/3342 3206/3158/3341
-f 3207/3159/3342 3217/3169/3352 3218/3170/3353
-f 3218/3170/3353 3208/3160/3343 3207/3159/3342
-f 3208/3160/3343 3218/3170/3353 3219/3171/3354
-f 3219/3171/3354 3209/3161/3344 3208/3160/3343
-f 3209/3161/3344 3219/3171/3354 3220/3172/3355
-f 3220/3172/3355 3210/3162/3345 3209/3161/3344
-f 3210/3162/3345 3220/3172/3355 3221/3173/3356
-f 3221/3173/3356 3211/3163/3346 3210/3162/3345
-f 3211/3163/3346 3221/3173/3356 3222/3174/3357
-f 3222/3174/3357 3212/3164/3347 3211/3163/3346
-f 2822/2774/2953 2832/2784/2965 3223/3175/3358
-f 3223/3175/3358 3213/3165/3348 2822/2774/2953
-f 3213/3165/3348 3223/3175/3358 3224/3176/3359
-f 3224/3176/3359 3214/3166/3349 3213/3165/3348
-f 3214/3166/3349 3224/3176/3359 3225/3177/3360
-f 3225/3177/3360 3215/3167/3350 3214/3166/3349
-f 3215/3167/3350 3225/3177/3360 3226/3178/3361
-f 3226/3178/3361 3216/3168/3351 3215/3167/3350
-f 3216/3168/3351 3226/3178/3361 3227/3179/3362
-f 3227/3179/3362 3217/3169/3352 3216/3168/3351
-f 3217/3169/3352 3227/3179/3362 3228/3180/3363
-f 3228/3180/3363 3218/3170/3353 3217/3169/3352
-f 3218/3170/3353 3228/3180/3363 3229/3181/3364
-f 3229/3181/3364 3219/3171/3354 3218/3170/3353
-f 3219/3171/3354 3229/3181/3364 3230/3182/3365
-f 3230/3182/3365 3220/3172/3355 3219/3171/3354
-f 3220/3172/3355 3230/3182/3365 3231/3183/3366
-f 3231/3183/3366 3221/3173/3356 3220/3172/3355
-f 3221/3173/3356 3231/3183/3366 3232/3184/3367
-f 3232/3184/3367 3222/3174/3357 3221/3173/3356
-f 2832/2784/2965 2842/2794/2977 3233/3185/3368
-f 3233/3185/3368 3223/3175/3358 2832/2784/2965
-f 3223/3175/3358 3233/3185/3368 3234/3186/3369
-f 3234/3186/3369 3224/3176/3359 3223/3175/3358
-f 3224/3176/3359 3234/3186/3369 3235/3187/3370
-f 3235/3187/3370 3225/3177/3360 3224/3176/3359
-f 3225/3177/3360 3235/3187/3370 3236/3188/3371
-f 3236/3188/3371 3226/3178/3361 3225/3177/3360
-f 3226/3178/3361 3236/3188/3371 3237/3189/3372
-f 3237/3189/3372 3227/3179/3362 3226/3178/3361
-f 3227/3179/3362 3237/3189/3372 3238/3190/3373
-f 3238/3190/3373 3228/3180/3363 3227/3179/3362
-f 3228/3180/3363 3238/3190/3373 3239/3191/3374
-f 3239/3191/3374 3229/3181/3364 3228/3180/3363
-f 3229/3181/3364 3239/3191/3374 3240/3192/3375
-f 3240/3192/3375 3230/3182/3365 3229/3181/3364
-f 3230/3182/3365 3240/3192/3375 3241/3193/3376
-f 3241/3193/3376 3231/3183/3366 3230/3182/3365
-f 3231/3183/3366 3241/3193/3376 3242/3194/3377
-f 3242/3194/3377 3232/3184/3367 3231/3183/3366
-f 2842/2794/2977 2462/2414/2521 2844/2796/2979
-f 2844/2796/2979 3233/3185/3368 2842/2794/2977
-f 3233/3185/3368 2844/2796/2979 2846/2798/2981
-f 2846/2798/2981 3234/3186/3369 3233/3185/3368
-f 3234/3186/3369 2846/2798/2981 2848/2800/2983
-f 2848/2800/2983 3235/3187/3370 3234/3186/3369
-f 3235/3187/3370 2848/2800/2983 2850/2802/2985
-f 2850/2802/2985 3236/3188/3371 3235/3187/3370
-f 3236/3188/3371 2850/2802/2985 2852/2804/2987
-f 2852/2804/2987 3237/3189/3372 3236/3188/3371
-f 3237/3189/3372 2852/2804/2987 2854/2806/2989
-f 2854/2806/2989 3238/3190/3373 3237/3189/3372
-f 3238/3190/3373 2854/2806/2989 2856/2808/2991
-f 2856/2808/2991 3239/3191/3374 3238/3190/3373
-f 3239/3191/3374 2856/2808/2991 2858/2810/2993
-f 2858/2810/2993 3240/3192/3375 3239/3191/3374
-f 3240/3192/3375 2858/2810/2993 2860/2812/2995
-f 2860/2812/2995 3241/3193/3376 3240/3192/3375
-f 3241/3193/3376 2860/2812/2995 2862/2814/2997
-f 2862/2814/2997 3242/3194/3377 3241/3193/3376
-";
-*/
+pub const CUBE_OBJ: &str = include_str!("../../assets/cube.obj");
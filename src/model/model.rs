@@ -1,16 +1,25 @@
-use crate::types::vector::{Vec2, Vec3, Vec4};
-use core::fmt::{Debug, Write};
-use defmt::{debug, Format};
-use heapless::Vec;
-
-pub const NUM_VERTS: usize = 256;
-pub const NUM_FACES: usize = 512;
+use crate::{
+    model::{
+        material::{from_mtl, Material, MAX_MATERIALS},
+        mesh::Mesh,
+        obj::{read_obj, NormalKey, ObjSink, VertexKey},
+        parse::{Capacity, ObjError, ObjErrorKind, Tokens},
+    },
+    types::vector::{Vec2, Vec3, Vec4, VEC2_ZERO},
+};
+use core::fmt::Debug;
+use defmt::{debug, warn, Format};
+use heapless::{FnvIndexMap, Vec};
+
+pub use crate::model::obj::{required_capacity, MAX_FACE_VERTS};
 
 #[derive(Clone)]
-/// Represents a 3D model.
-pub struct Model {
-    pub verts: Vec<Vertex, NUM_VERTS>,
-    pub faces: Vec<Face, NUM_FACES>,
+/// Represents a 3D model with room for
+/// `V` vertices and `F` faces.
+pub struct Model<const V: usize, const F: usize> {
+    pub verts: Vec<Vertex, V>,
+    pub faces: Vec<Face, F>,
+    pub materials: Vec<Material, MAX_MATERIALS>,
 }
 
 #[derive(Clone, Copy, Format)]
@@ -27,303 +36,500 @@ pub struct Vertex {
 /// Represents a face. Contains
 /// indices of 3 composing vertices.
 pub struct Face {
-    pub verts: [usize; 3],
+    pub verts: [u16; 3],
+    /// Index into the model's materials, if one
+    /// was in use when the face was declared.
+    pub material: Option<u8>,
+}
+
+impl Default for Face {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Face {
     /// Creates a new face initialized to all zeroes.
     pub fn new() -> Face {
-        Face { verts: [0, 0, 0] }
+        Face {
+            verts: [0, 0, 0],
+            material: None,
+        }
+    }
+
+    /// Returns the vertex indices widened for indexing.
+    pub fn indices(&self) -> [usize; 3] {
+        self.verts.map(usize::from)
     }
 
     /// Detects if a ray intersects with a triangular face.
     /// `direction` should be normalised.
     pub fn ray_intersects_face(
         &self,
-        model: &Model,
+        model: &impl Mesh,
         origin: Vec3<f32>,
         direction: Vec3<f32>,
     ) -> Option<Vec3<f32>> {
-        let tri_a = model
-            .verts
-            .get(self.verts[0])
-            .expect("face should have a valid vertex index")
-            .pos
-            .to_vec3();
-        let tri_b = model
-            .verts
-            .get(self.verts[1])
-            .expect("face should have a valid vertex index")
-            .pos
-            .to_vec3();
-        let tri_c = model
-            .verts
-            .get(self.verts[2])
-            .expect("face should have a valid vertex index")
-            .pos
-            .to_vec3();
-
-        let e1 = tri_b - tri_a;
-        let e2 = tri_c - tri_a;
-
-        let ray_cross_e2 = Vec3::<f32>::cross(direction, e2);
-        let det = Vec3::<f32>::dot(e1, ray_cross_e2);
-
-        if det > -f32::EPSILON && det < f32::EPSILON {
-            return None; // This ray is parallel to this triangle.
-        }
-
-        let inv_det = 1.0 / det;
-        let s = origin - tri_a;
-        let u = inv_det * Vec3::<f32>::dot(s, ray_cross_e2);
-        if u < 0.0 || u > 1.0 {
-            return None;
-        }
-
-        let s_cross_e1 = Vec3::<f32>::cross(s, e1);
-        let v = inv_det * Vec3::<f32>::dot(direction, s_cross_e1);
-        if v < 0.0 || u + v > 1.0 {
-            return None;
-        }
-        // At this stage we can compute t to find out where the intersection point is on the line.
-        let t = inv_det * Vec3::<f32>::dot(e2, s_cross_e1);
-
-        if t > f32::EPSILON {
-            // ray intersection
-            let intersection_point = origin + direction * t;
-            return Some(intersection_point);
-        } else {
-            // This means that there is a line intersection but not a ray intersection.
-            return None;
-        }
+        let [tri_a, tri_b, tri_c] = self.indices().map(|i| model.vertex(i).pos.to_vec3());
+        ray_intersects_triangle([tri_a, tri_b, tri_c], origin, direction)
     }
 
     /// Returns true if a given ray intersects the front side (towards the normal) of this face.
     /// Returns false if the ray is orthogonal to the normal, or is intersecting the back side.
     /// Does not check if the ray collides. For this, `ray_intersects_face` should be used.
-    pub fn ray_front_face(&self, model: &Model, direction: Vec3<f32>) -> bool {
+    pub fn ray_front_face(&self, model: &impl Mesh, direction: Vec3<f32>) -> bool {
         Vec3::<f32>::dot(
             direction,
-            model
-                .verts
-                .get(self.verts[0])
-                .expect("face should have a valid vertex index")
-                .normal
-                .to_vec3(),
+            model.vertex(self.verts[0] as usize).normal.to_vec3(),
         ) < 0.0
     }
 }
 
+/// Detects if a ray intersects with a triangle, returning
+/// the point of intersection. `direction` should be normalised.
+pub fn ray_intersects_triangle(
+    [tri_a, tri_b, tri_c]: [Vec3<f32>; 3],
+    origin: Vec3<f32>,
+    direction: Vec3<f32>,
+) -> Option<Vec3<f32>> {
+    let e1 = tri_b - tri_a;
+    let e2 = tri_c - tri_a;
+
+    let ray_cross_e2 = Vec3::<f32>::cross(direction, e2);
+    let det = Vec3::<f32>::dot(e1, ray_cross_e2);
+
+    if det > -f32::EPSILON && det < f32::EPSILON {
+        return None; // This ray is parallel to this triangle.
+    }
+
+    let inv_det = 1.0 / det;
+    let s = origin - tri_a;
+    let u = inv_det * Vec3::<f32>::dot(s, ray_cross_e2);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let s_cross_e1 = Vec3::<f32>::cross(s, e1);
+    let v = inv_det * Vec3::<f32>::dot(direction, s_cross_e1);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    // At this stage we can compute t to find out where the intersection point is on the line.
+    let t = inv_det * Vec3::<f32>::dot(e2, s_cross_e1);
+
+    if t > f32::EPSILON {
+        // ray intersection
+        let intersection_point = origin + direction * t;
+        Some(intersection_point)
+    } else {
+        // This means that there is a line intersection but not a ray intersection.
+        None
+    }
+}
+
+impl<const V: usize, const F: usize> Model<V, F> {
+    /// Creates an empty model.
+    pub fn new() -> Model<V, F> {
+        Model {
+            verts: Vec::new(),
+            faces: Vec::new(),
+            materials: Vec::new(),
+        }
+    }
+}
+
+impl<const V: usize, const F: usize> Mesh for Model<V, F> {
+    fn vertex_count(&self) -> usize {
+        self.verts.len()
+    }
+
+    fn vertex(&self, index: usize) -> Vertex {
+        self.verts[index]
+    }
+
+    fn face_count(&self) -> usize {
+        self.faces.len()
+    }
+
+    fn face(&self, index: usize) -> Face {
+        self.faces[index]
+    }
+
+    fn material(&self, face: &Face) -> Option<&Material> {
+        face.material.and_then(|i| self.materials.get(i as usize))
+    }
+}
+
+impl<const V: usize, const F: usize> Default for Model<V, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Creates a Model from an OBJ
-/// file.
-pub fn from_obj(obj_file: &str) -> Model {
-    debug!("Creating model");
+/// file. Materials are ignored.
+pub fn from_obj<const V: usize, const F: usize>(obj_file: &str) -> Result<Model<V, F>, ObjError>
+where
+    [(); index_capacity(V)]:,
+{
+    from_obj_with_mtl(obj_file, |_| None)
+}
 
-    let mut points: Vec<Vec4<f32>, NUM_VERTS> = Vec::new();
-    let mut normals: Vec<Vec4<f32>, NUM_VERTS> = Vec::new();
-    let mut tex_coords: Vec<Vec2<f32>, NUM_VERTS> = Vec::new();
+/// Creates a Model from an OBJ file, loading the
+/// contents of each `mtllib` file with `load_mtl`.
+/// Libraries it returns `None` for are skipped, as
+/// are unknown directives, lines, points and free-form
+/// geometry. Errors in a material library are reported
+/// with its own line numbers.
+///
+/// Points, texture coordinates and normals are
+/// buffered while loading, up to `V` of each.
+pub fn from_obj_with_mtl<'a, const V: usize, const F: usize>(
+    obj_file: &str,
+    load_mtl: impl Fn(&str) -> Option<&'a str>,
+) -> Result<Model<V, F>, ObjError>
+where
+    [(); index_capacity(V)]:,
+{
+    from_obj_with_options(obj_file, load_mtl, ObjOptions::default())
+}
 
-    debug!("Created vecs");
+#[derive(Debug, Clone, Copy, Default, PartialEq, Format)]
+/// Options for loading an OBJ file.
+pub struct ObjOptions {
+    /// Reject lines, points and free-form geometry with
+    /// `UnsupportedDirective` instead of skipping them.
+    pub strict: bool,
+}
 
-    let mut lines: core::str::Split<'_, &'static str> = obj_file.split("\n");
+/// Creates a Model from an OBJ file like
+/// `from_obj_with_mtl`, with the given options.
+pub fn from_obj_with_options<'a, const V: usize, const F: usize>(
+    obj_file: &str,
+    load_mtl: impl Fn(&str) -> Option<&'a str>,
+    options: ObjOptions,
+) -> Result<Model<V, F>, ObjError>
+where
+    [(); index_capacity(V)]:,
+{
+    debug!("Creating model");
 
-    debug!("Split lines");
+    let mut sink = ModelSink {
+        model: Model::new(),
+        points: Vec::new(),
+        tex_coords: Vec::new(),
+        normals: Vec::new(),
+        vertex_indices: FnvIndexMap::new(),
+        current_material: None,
+        load_mtl,
+        options,
+    };
+    read_obj(obj_file, &mut sink)?;
+    Ok(sink.model)
+}
 
-    let mut vertices: Vec<Vertex, NUM_VERTS> = Vec::new();
-    let mut faces: Vec<Face, NUM_FACES> = Vec::new();
+/// Size of the vertex lookup table for a model holding `v`
+/// vertices. Index maps need a power of two greater than 1.
+pub const fn index_capacity(v: usize) -> usize {
+    if v < 2 {
+        2
+    } else {
+        v.next_power_of_two()
+    }
+}
 
-    debug!("Created vecs 2");
+/// Builds a `Model` from the elements of an OBJ file.
+struct ModelSink<'a, L, const V: usize, const F: usize>
+where
+    L: Fn(&str) -> Option<&'a str>,
+    [(); index_capacity(V)]:,
+{
+    model: Model<V, F>,
+    points: Vec<Vec4<f32>, V>,
+    tex_coords: Vec<Vec2<f32>, V>,
+    normals: Vec<Vec4<f32>, V>,
+    vertex_indices: FnvIndexMap<VertexKey, u16, { index_capacity(V) }>,
+    current_material: Option<u8>,
+    load_mtl: L,
+    options: ObjOptions,
+}
 
-    let mut context = heapless::String::<16>::new();
+impl<'a, L, const V: usize, const F: usize> ObjSink for ModelSink<'a, L, V, F>
+where
+    L: Fn(&str) -> Option<&'a str>,
+    [(); index_capacity(V)]:,
+{
+    fn point(&mut self, [x, y, z]: [f32; 3]) -> Result<(), Capacity> {
+        debug!("Added point {} {} {}", x, y, z);
+        self.points
+            .push(Vec4 { x, y, z, w: 1.0 })
+            .map_err(|_| Capacity::Points)
+    }
 
-    debug!("Created context");
+    fn tex_coord(&mut self, [x, y]: [f32; 2]) -> Result<(), Capacity> {
+        debug!("Added tex coord {} {}", x, y);
+        self.tex_coords
+            .push(Vec2 { x, y })
+            .map_err(|_| Capacity::TexCoords)
+    }
 
-    while let Some(i) = lines.next() {
-        // debug!("{:?}", i);
-        let mut parts = i.split(" ");
+    fn normal(&mut self, [x, y, z]: [f32; 3]) -> Result<(), Capacity> {
+        debug!("Added vertex normal {} {} {}", x, y, z);
+        self.normals
+            .push(Vec4 { x, y, z, w: 0.0 })
+            .map_err(|_| Capacity::Normals)
+    }
 
-        let token: Option<&str> = parts.next();
+    fn point_at(&self, index: usize) -> [f32; 3] {
+        let p = self.points[index];
+        [p.x, p.y, p.z]
+    }
 
-        if token == Some("v") {
-            context.clear();
-            context
-                .write_str("vertex")
-                .expect("model parse context should be less than 16 chars");
+    fn vertex(&mut self, key: VertexKey, [x, y, z]: [f32; 3]) -> Result<u16, Capacity> {
+        if let Some(index) = self.vertex_indices.get(&key) {
+            return Ok(*index);
+        }
 
-            defmt::expect!(
-                points.push(Vec4 {
-                    x: parse_float(parts.next(), &context),
-                    y: parse_float(parts.next(), &context),
-                    z: parse_float(parts.next(), &context),
-                    w: 1.0
-                }),
-                "loaded model should not contain more than 4096 points"
-            );
+        // Faces hold 16-bit indices
+        let index = u16::try_from(self.model.verts.len()).map_err(|_| Capacity::Vertices)?;
+        let normal = match key.normal {
+            NormalKey::Given(n) => self.normals[n],
+            NormalKey::Polygon(_) => Vec4::of(*Vec3 { x, y, z }.nor(), 0.0),
+        };
+        let vertex = Vertex {
+            pos: self.points[key.point],
+            tex_coords: key.tex_coord.map_or(VEC2_ZERO, |t| self.tex_coords[t]),
+            normal,
+        };
+        self.model
+            .verts
+            .push(vertex)
+            .map_err(|_| Capacity::Vertices)?;
+        self.vertex_indices
+            .insert(key, index)
+            .map_err(|_| Capacity::Vertices)?;
+
+        debug!("Added vertex {}", index);
+        Ok(index)
+    }
 
-            debug!(
-                "Added point_index {} {} {}",
-                points.last().unwrap().x,
-                points.last().unwrap().y,
-                points.last().unwrap().z
-            )
-        } else if token == Some("vt") {
-            context.clear();
-            context
-                .write_str("tex coords")
-                .expect("model parse context should be less than 16 chars");
-
-            defmt::expect!(
-                tex_coords.push(Vec2 {
-                    x: parse_float(parts.next(), &context),
-                    y: parse_float(parts.next(), &context),
-                }),
-                "loaded model should not contain more than 4096 tex coords"
-            );
+    fn triangle(&mut self, verts: [u16; 3]) -> Result<(), Capacity> {
+        let face = Face {
+            verts,
+            material: self.current_material,
+        };
+        self.model.faces.push(face).map_err(|_| Capacity::Faces)?;
 
-            debug!(
-                "Added tex coord {} {}",
-                tex_coords.last().unwrap().x,
-                tex_coords.last().unwrap().y
-            )
-        } else if token == Some("vn") {
-            context.clear();
-            context
-                .write_str("vertex normal")
-                .expect("model parse context should be less than 16 chars");
-
-            defmt::expect!(
-                normals.push(Vec4 {
-                    x: parse_float(parts.next(), &context),
-                    y: parse_float(parts.next(), &context),
-                    z: parse_float(parts.next(), &context),
-                    w: 0.0
-                }),
-                "loaded model should not contain more than 4096 normals"
-            );
+        debug!("Added face {}", verts);
+        Ok(())
+    }
 
-            debug!(
-                "Added vertex normal {} {} {}",
-                normals.last().unwrap().x,
-                normals.last().unwrap().y,
-                normals.last().unwrap().z
-            )
-        } else if token == Some("f") {
-            context.clear();
-            context
-                .write_str("face")
-                .expect("model parse context should be less than 16 chars");
-
-            let mut face: Face = Face::new();
-
-            for i in 0..3 {
-                let mut vertex_components = parts.next().unwrap().split("/");
-
-                let point_index: usize = parse_size(vertex_components.next(), &context);
-                let tex_coord_index: usize = parse_size(vertex_components.next(), &context);
-                let normal_index: usize = parse_size(vertex_components.next(), &context);
-
-                let vertex_in_array = {
-                    get_vertex(
-                        point_index,
-                        tex_coord_index,
-                        normal_index,
-                        &points,
-                        &tex_coords,
-                        &normals,
-                        &vertices,
-                    )
-                };
-
-                face.verts[i] = match vertex_in_array {
-                    Some(i) => i,
-                    None => {
-                        debug!(
-                            "Adding vertex with indices {:?} {:?} {:?}",
-                            point_index, tex_coord_index, normal_index
-                        );
-                        defmt::expect!(
-                            vertices.push(Vertex {
-                                pos: points[point_index - 1].clone(),
-                                tex_coords: tex_coords[tex_coord_index - 1].clone(),
-                                normal: normals[normal_index - 1].clone(),
-                            }),
-                            "model should not contain more than 4096 unique vertices"
-                        );
-                        vertices.len() - 1
+    fn directive(&mut self, directive: &str, tokens: &mut Tokens) -> Result<(), ObjError> {
+        let materials = &mut self.model.materials;
+        match directive {
+            "mtllib" => {
+                while let Some(library) = tokens.next() {
+                    let Some(mtl_file) = (self.load_mtl)(library) else {
+                        debug!("Skipping material library {}", library);
+                        continue;
+                    };
+                    let library = from_mtl(mtl_file)?;
+                    if materials.len() + library.len() > MAX_MATERIALS {
+                        return Err(tokens.error_at(
+                            directive,
+                            ObjErrorKind::CapacityExceeded {
+                                capacity: Capacity::Materials,
+                                required: materials.len() + library.len(),
+                            },
+                        ));
                     }
-                };
+                    materials.extend(library);
+                }
+            }
+            "usemtl" => {
+                let name = tokens.rest();
+                self.current_material = materials
+                    .iter()
+                    .position(|m| m.name == name)
+                    .map(|i| i as u8);
+                if self.current_material.is_none() {
+                    debug!("Unknown material {}", name);
+                }
+            }
+            // Lines, points and free-form geometry can't be rendered
+            "l" | "p" | "curv" | "curv2" | "surf" | "cstype" => {
+                if self.options.strict {
+                    return Err(tokens.error_at(directive, ObjErrorKind::UnsupportedDirective));
+                }
+                warn!(
+                    "Skipping unsupported {} on line {}",
+                    directive,
+                    tokens.line()
+                );
             }
+            // Objects (o), groups (g), smoothing groups (s)
+            // and anything unknown are skipped
+            _ => {}
+        }
+        Ok(())
+    }
+}
 
-            faces
-                .push(face)
-                .expect("model should not contain more than 8192 unique faces");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::CUBE_OBJ;
+
+    #[test]
+    fn capacity_errors_report_a_capacity_that_loads_the_file() {
+        // The cube's 14 texture coordinates overflow first, but its 24
+        // vertices also need room, so the error asks for enough of both
+        let Err(error) = from_obj::<8, 12>(CUBE_OBJ) else {
+            panic!("cube loaded into 8 vertices");
+        };
+        let ObjErrorKind::CapacityExceeded { capacity, required } = error.kind else {
+            panic!("unexpected error {:?}", error);
+        };
+        assert_eq!(capacity, Capacity::TexCoords);
+        assert_eq!(required, required_capacity(CUBE_OBJ, Capacity::Vertices));
+
+        let model = from_obj::<36, 12>(CUBE_OBJ).unwrap();
+        assert!(model.verts.len() <= required);
+    }
 
-            debug!("Added face {}", faces.last().unwrap().verts)
+    /// Loads `obj` and returns the line, column and kind of its error.
+    fn error(obj: &str) -> (usize, usize, ObjErrorKind) {
+        match from_obj::<8, 8>(obj) {
+            Ok(_) => panic!("{:?} loaded", obj),
+            Err(e) => (e.line, e.column, e.kind),
         }
     }
 
-    Model {
-        verts: vertices,
-        faces: faces,
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        use ObjErrorKind::*;
+        assert_eq!(error("v 0 0 0\nv 1 x 1"), (2, 5, BadNumber));
+        assert_eq!(error("# points\n  v 1 2"), (2, 8, MissingValue));
+        // The texture coordinate index, not the whole corner
+        assert_eq!(error("v 0 0 0\nf 1 1/1 1"), (2, 7, IndexOutOfRange));
+        assert_eq!(error("v 0 0 0\nf 1 1 /1"), (2, 7, MissingIndex));
+        // Just past the last token, ignoring the comment
+        assert_eq!(error("v 0 0 0\nf 1 1 # 1"), (2, 6, UnsupportedFace));
     }
-}
 
-/// Safely reads a float from a string slice option.
-fn parse_float(word: Option<&'_ str>, context: &heapless::String<16>) -> f32 {
-    let mut error1 = heapless::String::<32>::new();
-    write!(error1, "{} value not found", context).unwrap();
-    let mut error2 = heapless::String::<41>::new();
-    write!(error2, "value in {} should be float", context).unwrap();
-
-    word.expect(error1.as_str())
-        .parse::<f32>()
-        .expect(error2.as_str())
-}
+    #[test]
+    fn material_errors_use_their_own_lines() {
+        let obj = "mtllib a.mtl\nv 0 0 0";
+        let mtl = "newmtl red\nKd 1 0 z";
+        let Err(e) = from_obj_with_mtl::<8, 8>(obj, |_| Some(mtl)) else {
+            panic!("bad material loaded");
+        };
+        assert_eq!((e.line, e.column, e.kind), (2, 8, ObjErrorKind::BadNumber));
+    }
 
-/// Safely reads an int (usize) from a string slice option.
-fn parse_size(word: Option<&'_ str>, context: &heapless::String<16>) -> usize {
-    let mut error1 = heapless::String::<32>::new();
-    write!(error1, "{} value not found", context).unwrap();
-    let mut error2 = heapless::String::<41>::new();
-    write!(error2, "value in {} should be usize", context).unwrap();
+    #[test]
+    fn lines_points_and_free_form_geometry_are_skipped() {
+        let obj = "v 0 0 0\nv 1 0 0\nv 0 1 0\nl 1 2\np 3\ncstype bspline\ncurv 0 1 1 2\nf 1 2 3";
+        let model = from_obj::<8, 8>(obj).unwrap();
+        assert_eq!(model.faces.len(), 1);
+
+        let strict = ObjOptions { strict: true };
+        let Err(e) = from_obj_with_options::<8, 8>(obj, |_| None, strict) else {
+            panic!("strict load skipped a line");
+        };
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (4, 1, ObjErrorKind::UnsupportedDirective)
+        );
+    }
 
-    word.expect(error1.as_str())
-        .parse::<usize>()
-        .expect(error2.as_str())
-}
+    /// A unit square in the z = 0 plane, with one texture
+    /// coordinate and normal per corner.
+    const SQUARE: &str = "
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+vn 0 0 1
+";
+
+    #[test]
+    fn every_face_form_loads() {
+        for face in [
+            "f 1 2 3",
+            "f 1/1 2/2 3/3",
+            "f 1//1 2//1 3//1",
+            "f 1/1/1 2/2/1 3/3/1",
+        ] {
+            let obj = [SQUARE, face].concat();
+            let model = from_obj::<8, 8>(&obj).unwrap();
+            assert_eq!(model.faces.len(), 1, "{}", face);
+            let v = model.verts[2];
+            assert_eq!((v.pos.x, v.pos.y), (1.0, 1.0), "{}", face);
+            assert_eq!(v.normal.z, 1.0, "{}", face);
+            let expected_u = if face.contains("/3") { 1.0 } else { 0.0 };
+            assert_eq!(v.tex_coords.x, expected_u, "{}", face);
+        }
+    }
 
-/// Gets the index of a vertex with the specified data.
-/// Passed indices should start at 1, as in the OBJ face
-/// data.
-fn get_vertex(
-    point_index: usize,
-    tex_coord_index: usize,
-    normal_index: usize,
-    points: &Vec<Vec4<f32>, 256>,
-    tex_coords: &Vec<Vec2<f32>, 256>,
-    normals: &Vec<Vec4<f32>, 256>,
-    vertices: &Vec<Vertex, 256>,
-) -> Option<usize> {
-    let given_point = points[point_index - 1];
-    let given_tex_coord = tex_coords[tex_coord_index - 1];
-    let given_normal = normals[normal_index - 1];
-
-    let mut matched_index = None;
-
-    for (i, v) in vertices.iter().enumerate() {
-        if v.pos == given_point && v.tex_coords == given_tex_coord && v.normal == given_normal {
-            if matched_index.is_some() {
-                debug!("about to panic");
-                defmt::panic!(
-                    "more than one matching point for {:?} {:?} {:?}",
-                    given_point,
-                    given_tex_coord,
-                    given_normal
-                );
-            }
-            matched_index = Some(i);
+    #[test]
+    fn negative_indices_count_back_from_the_latest_element() {
+        let absolute = from_obj::<8, 8>(&[SQUARE, "f 2/2/1 3/3/1 4/4/1"].concat()).unwrap();
+        let relative =
+            from_obj::<8, 8>(&[SQUARE, "f -3/-3/-1 -2/-2/-1 -1/-1/-1"].concat()).unwrap();
+        for (a, r) in absolute.verts.iter().zip(&relative.verts) {
+            assert_eq!((a.pos.x, a.pos.y), (r.pos.x, r.pos.y));
+            assert_eq!(
+                (a.tex_coords.x, a.tex_coords.y),
+                (r.tex_coords.x, r.tex_coords.y)
+            );
         }
+        assert_eq!(
+            error(&[SQUARE, "f -5 1 2"].concat()).2,
+            ObjErrorKind::IndexOutOfRange
+        );
     }
 
-    matched_index
+    #[test]
+    fn polygons_are_split_into_a_fan_sharing_vertices() {
+        let model = from_obj::<8, 8>(&[SQUARE, "f 1/1/1 2/2/1 3/3/1 4/4/1"].concat()).unwrap();
+        assert_eq!(model.verts.len(), 4);
+        let faces: [[u16; 3]; 2] = [model.faces[0].verts, model.faces[1].verts];
+        assert_eq!(faces, [[0, 1, 2], [0, 2, 3]]);
+    }
+
+    #[test]
+    fn corners_without_normals_take_their_polygons() {
+        // Counter-clockwise from the front, so facing +z
+        let model = from_obj::<8, 8>(&[SQUARE, "f 1 2 3 4\nf 1 4 3"].concat()).unwrap();
+        assert_eq!(model.verts[0].normal.z, 1.0);
+        // The second polygon faces the other way, so can't share them
+        assert_eq!(model.verts.len(), 7);
+        assert_eq!(model.verts[6].normal.z, -1.0);
+    }
+
+    #[test]
+    fn identical_corners_share_a_vertex_across_faces() {
+        // Two triangles sharing the diagonal from 1 to 3
+        let shared = "f 1/1/1 2/2/1 3/3/1\nf 1/1/1 3/3/1 4/4/1";
+        let model = from_obj::<8, 8>(&[SQUARE, "vn 0 0 -1\n", shared].concat()).unwrap();
+        assert_eq!(model.verts.len(), 4);
+        let faces: [[u16; 3]; 2] = [model.faces[0].verts, model.faces[1].verts];
+        assert_eq!(faces, [[0, 1, 2], [0, 2, 3]]);
+
+        // The same points with another texture coordinate or normal
+        for split in [
+            "f 1/1/1 2/2/1 3/3/1\nf 1/4/1 3/3/1 4/4/1",
+            "f 1/1/1 2/2/1 3/3/1\nf 1/1/2 3/3/1 4/4/1",
+        ] {
+            let model = from_obj::<8, 8>(&[SQUARE, "vn 0 0 -1\n", split].concat()).unwrap();
+            assert_eq!(model.verts.len(), 5, "{}", split);
+            let faces: [[u16; 3]; 2] = [model.faces[0].verts, model.faces[1].verts];
+            assert_eq!(faces, [[0, 1, 2], [3, 2, 4]], "{}", split);
+        }
+    }
 }
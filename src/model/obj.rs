@@ -0,0 +1,369 @@
+//! The OBJ reader shared by the model loader and the build
+//! script, which includes this file with `#[path]`. It only
+//! uses `core`, so it builds for the device and the host alike.
+
+use core::str::{FromStr, SplitWhitespace};
+
+/// The most vertices a polygon in an OBJ file can have.
+pub const MAX_FACE_VERTS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// An error from parsing an OBJ or MTL file,
+/// located by its 1-based line and column.
+pub struct ObjError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ObjErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjErrorKind {
+    /// A value could not be read as a number.
+    BadNumber,
+    /// A directive ended before all of its values were given.
+    MissingValue,
+    /// A face vertex is missing its position index.
+    MissingIndex,
+    /// A face refers to an element that hasn't been declared.
+    IndexOutOfRange,
+    /// A face has fewer than 3 vertices.
+    UnsupportedFace,
+    /// A known directive that can't be rendered, such as lines
+    /// or free-form surfaces. Only reported when loading strictly.
+    UnsupportedDirective,
+    /// A material or file name is longer than `MATERIAL_NAME_LEN`.
+    NameTooLong,
+    /// The file holds more of something than fits in memory.
+    /// `required` is the capacity needed to load it. Points,
+    /// texture coordinates, normals and vertices share one
+    /// capacity in `Model`, so for those it's the largest of
+    /// the four, counting vertices with `required_capacity`.
+    CapacityExceeded { capacity: Capacity, required: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The buffer that ran out of space.
+pub enum Capacity {
+    Points,
+    TexCoords,
+    Normals,
+    Vertices,
+    /// Vertices in a single polygon.
+    FaceVertices,
+    Faces,
+    Materials,
+}
+
+/// Splits a line of an OBJ or MTL file into whitespace-separated
+/// tokens, dropping any comment, and builds errors pointing at them.
+pub(crate) struct Tokens<'a> {
+    line: &'a str,
+    number: usize,
+    inner: SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    /// `number` is the 0-based index of the line in its file.
+    pub fn new(number: usize, line: &'a str) -> Tokens<'a> {
+        let line = match line.find('#') {
+            Some(i) => &line[..i],
+            None => line,
+        };
+        Tokens {
+            line,
+            number: number + 1,
+            inner: line.split_whitespace(),
+        }
+    }
+
+    pub fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+
+    /// Returns the rest of the line, with surrounding whitespace removed.
+    pub fn rest(&mut self) -> &'a str {
+        match self.inner.next() {
+            Some(first) => {
+                let start = self.column(first) - 1;
+                self.inner = "".split_whitespace();
+                self.line[start..].trim()
+            }
+            None => "",
+        }
+    }
+
+    /// Returns the 1-based line number.
+    pub fn line(&self) -> usize {
+        self.number
+    }
+
+    /// Returns the 1-based column of a token taken from this line.
+    pub fn column(&self, token: &str) -> usize {
+        token.as_ptr() as usize - self.line.as_ptr() as usize + 1
+    }
+
+    /// Creates an error pointing at `token`.
+    pub fn error_at(&self, token: &str, kind: ObjErrorKind) -> ObjError {
+        ObjError {
+            line: self.number,
+            column: self.column(token),
+            kind,
+        }
+    }
+
+    /// Creates an error pointing just past the end of the line.
+    pub fn error_at_end(&self, kind: ObjErrorKind) -> ObjError {
+        ObjError {
+            line: self.number,
+            column: self.line.trim_end().len() + 1,
+            kind,
+        }
+    }
+
+    /// Reads the next token as a number.
+    pub fn parse<T: FromStr>(&mut self) -> Result<T, ObjError> {
+        let token = self
+            .next()
+            .ok_or_else(|| self.error_at_end(ObjErrorKind::MissingValue))?;
+        self.parse_token(token)
+    }
+
+    /// Reads a token from this line as a number.
+    pub fn parse_token<T: FromStr>(&self, token: &str) -> Result<T, ObjError> {
+        token
+            .parse::<T>()
+            .map_err(|_| self.error_at(token, ObjErrorKind::BadNumber))
+    }
+
+    /// Reads the next token as a number, or returns
+    /// `default` if the line has ended.
+    pub fn parse_or<T: FromStr>(&mut self, default: T) -> Result<T, ObjError> {
+        match self.next() {
+            Some(token) => self.parse_token(token),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Identifies a unique vertex by the elements it was built from.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct VertexKey {
+    pub point: usize,
+    pub tex_coord: Option<usize>,
+    pub normal: NormalKey,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum NormalKey {
+    /// A normal declared in the file.
+    Given(usize),
+    /// The generated normal of the polygon on this line.
+    Polygon(usize),
+}
+
+/// Stores the elements of an OBJ file as `read_obj` finds them.
+/// Storage is up to the implementor; running out is reported
+/// by returning the buffer that is full.
+pub(crate) trait ObjSink {
+    fn point(&mut self, point: [f32; 3]) -> Result<(), Capacity>;
+
+    fn tex_coord(&mut self, tex_coord: [f32; 2]) -> Result<(), Capacity>;
+
+    fn normal(&mut self, normal: [f32; 3]) -> Result<(), Capacity>;
+
+    /// Returns a point stored earlier, by 0-based index.
+    fn point_at(&self, index: usize) -> [f32; 3];
+
+    /// Returns the index of the vertex made from `key`, adding it if
+    /// it's new. Vertices without a given normal take `polygon_normal`,
+    /// which is not normalised.
+    fn vertex(&mut self, key: VertexKey, polygon_normal: [f32; 3]) -> Result<u16, Capacity>;
+
+    fn triangle(&mut self, verts: [u16; 3]) -> Result<(), Capacity>;
+
+    /// Handles any other directive, with `tokens` positioned just
+    /// after it. Does nothing by default.
+    fn directive(&mut self, _directive: &str, _tokens: &mut Tokens) -> Result<(), ObjError> {
+        Ok(())
+    }
+}
+
+/// Reads an OBJ file into `sink`, resolving face corners and
+/// splitting polygons into triangles.
+pub(crate) fn read_obj(obj_file: &str, sink: &mut impl ObjSink) -> Result<(), ObjError> {
+    // Points, texture coordinates and normals read so far
+    let mut counts = [0; 3];
+
+    for (number, line) in obj_file.lines().enumerate() {
+        let mut tokens = Tokens::new(number, line);
+        let Some(token) = tokens.next() else {
+            continue;
+        };
+        let capacity_error = |tokens: &Tokens, at: &str, capacity| {
+            let required = match capacity {
+                Capacity::Points | Capacity::TexCoords | Capacity::Normals | Capacity::Vertices => {
+                    [
+                        Capacity::Points,
+                        Capacity::TexCoords,
+                        Capacity::Normals,
+                        Capacity::Vertices,
+                    ]
+                    .map(|c| required_capacity(obj_file, c))
+                    .into_iter()
+                    .max()
+                    .unwrap_or(0)
+                }
+                _ => required_capacity(obj_file, capacity),
+            };
+            tokens.error_at(at, ObjErrorKind::CapacityExceeded { capacity, required })
+        };
+
+        match token {
+            "v" => {
+                let point = [tokens.parse()?, tokens.parse()?, tokens.parse()?];
+                sink.point(point)
+                    .map_err(|c| capacity_error(&tokens, token, c))?;
+                counts[0] += 1;
+            }
+            "vt" => {
+                let tex_coord = [tokens.parse()?, tokens.parse_or(0.0)?];
+                sink.tex_coord(tex_coord)
+                    .map_err(|c| capacity_error(&tokens, token, c))?;
+                counts[1] += 1;
+            }
+            "vn" => {
+                let normal = [tokens.parse()?, tokens.parse()?, tokens.parse()?];
+                sink.normal(normal)
+                    .map_err(|c| capacity_error(&tokens, token, c))?;
+                counts[2] += 1;
+            }
+            "f" => {
+                // Resolve every corner before triangulating
+                let mut corners = [VertexKey {
+                    point: 0,
+                    tex_coord: None,
+                    normal: NormalKey::Polygon(number),
+                }; MAX_FACE_VERTS];
+                let mut len = 0;
+                while let Some(vertex) = tokens.next() {
+                    if len == MAX_FACE_VERTS {
+                        return Err(capacity_error(&tokens, token, Capacity::FaceVertices));
+                    }
+                    corners[len] = parse_corner(&tokens, vertex, number, counts)?;
+                    len += 1;
+                }
+                if len < 3 {
+                    return Err(tokens.error_at_end(ObjErrorKind::UnsupportedFace));
+                }
+                let corners = &corners[..len];
+
+                // Corners without a normal share the polygon's normal,
+                // so are only shared within the polygon
+                let polygon_normal = newell_normal(corners.iter().map(|c| sink.point_at(c.point)));
+
+                // Polygons are assumed convex and split into a fan
+                for i in 1..len - 1 {
+                    let mut verts = [0; 3];
+                    for (vert, key) in
+                        verts
+                            .iter_mut()
+                            .zip([corners[0], corners[i], corners[i + 1]])
+                    {
+                        *vert = sink
+                            .vertex(key, polygon_normal)
+                            .map_err(|c| capacity_error(&tokens, token, c))?;
+                    }
+                    sink.triangle(verts)
+                        .map_err(|c| capacity_error(&tokens, token, c))?;
+                }
+            }
+            _ => sink.directive(token, &mut tokens)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Counts how much of a buffer is needed to load an OBJ file.
+/// Vertices are counted once per polygon corner. That is an
+/// upper bound, not the exact count, as loading merges corners
+/// with the same indices. Materials aren't counted.
+pub fn required_capacity(obj_file: &str, capacity: Capacity) -> usize {
+    let mut required = 0;
+    for line in obj_file.lines() {
+        let mut tokens = Tokens::new(0, line);
+        let token = tokens.next();
+        let mut corners = || {
+            let mut n: usize = 0;
+            while tokens.next().is_some() {
+                n += 1;
+            }
+            n
+        };
+        required = match (capacity, token) {
+            (Capacity::Points, Some("v"))
+            | (Capacity::TexCoords, Some("vt"))
+            | (Capacity::Normals, Some("vn")) => required + 1,
+            (Capacity::Vertices, Some("f")) => required + corners(),
+            (Capacity::Faces, Some("f")) => required + corners().saturating_sub(2),
+            (Capacity::FaceVertices, Some("f")) => usize::max(required, corners()),
+            _ => required,
+        };
+    }
+    required
+}
+
+/// Reads a face corner in any of the forms `v`, `v/vt`,
+/// `v//vn` or `v/vt/vn`. Indices start at 1, or count back
+/// from the latest element when negative. `counts` holds the
+/// number of points, texture coordinates and normals read.
+fn parse_corner(
+    tokens: &Tokens,
+    vertex: &str,
+    number: usize,
+    counts: [usize; 3],
+) -> Result<VertexKey, ObjError> {
+    let mut components = vertex.split('/');
+
+    let mut index = |len: usize| -> Result<Option<usize>, ObjError> {
+        let Some(component) = components.next().filter(|c| !c.is_empty()) else {
+            return Ok(None);
+        };
+        let index: isize = tokens.parse_token(component)?;
+        let resolved = if index > 0 {
+            index as usize - 1
+        } else {
+            len.wrapping_sub(index.unsigned_abs())
+        };
+        if index == 0 || resolved >= len {
+            return Err(tokens.error_at(component, ObjErrorKind::IndexOutOfRange));
+        }
+        Ok(Some(resolved))
+    };
+
+    let point =
+        index(counts[0])?.ok_or_else(|| tokens.error_at(vertex, ObjErrorKind::MissingIndex))?;
+    let tex_coord = index(counts[1])?;
+    let normal = index(counts[2])?.map_or(NormalKey::Polygon(number), NormalKey::Given);
+
+    Ok(VertexKey {
+        point,
+        tex_coord,
+        normal,
+    })
+}
+
+/// Sums the normal of a polygon with Newell's method, which
+/// tolerates slightly non-planar polygons. Counter-clockwise
+/// polygons face towards the viewer. The result is scaled by
+/// twice the polygon's area.
+fn newell_normal(points: impl Iterator<Item = [f32; 3]> + Clone) -> [f32; 3] {
+    let mut normal = [0.0; 3];
+    let next = points.clone().cycle().skip(1);
+    for (a, b) in points.zip(next) {
+        normal[0] += (a[1] - b[1]) * (a[2] + b[2]);
+        normal[1] += (a[2] - b[2]) * (a[0] + b[0]);
+        normal[2] += (a[0] - b[0]) * (a[1] + b[1]);
+    }
+    normal
+}
@@ -0,0 +1,46 @@
+use defmt::{write, Format, Formatter};
+
+pub(crate) use crate::model::obj::Tokens;
+pub use crate::model::obj::{Capacity, ObjError, ObjErrorKind};
+
+// The error types live in the OBJ reader shared with the build
+// script, which can't depend on defmt, so they are formatted here.
+
+impl Format for ObjError {
+    fn format(&self, f: Formatter) {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl Format for ObjErrorKind {
+    fn format(&self, f: Formatter) {
+        match self {
+            ObjErrorKind::BadNumber => write!(f, "BadNumber"),
+            ObjErrorKind::MissingValue => write!(f, "MissingValue"),
+            ObjErrorKind::MissingIndex => write!(f, "MissingIndex"),
+            ObjErrorKind::IndexOutOfRange => write!(f, "IndexOutOfRange"),
+            ObjErrorKind::UnsupportedFace => write!(f, "UnsupportedFace"),
+            ObjErrorKind::UnsupportedDirective => write!(f, "UnsupportedDirective"),
+            ObjErrorKind::NameTooLong => write!(f, "NameTooLong"),
+            ObjErrorKind::CapacityExceeded { capacity, required } => write!(
+                f,
+                "CapacityExceeded {{ capacity: {}, required: {} }}",
+                capacity, required
+            ),
+        }
+    }
+}
+
+impl Format for Capacity {
+    fn format(&self, f: Formatter) {
+        match self {
+            Capacity::Points => write!(f, "Points"),
+            Capacity::TexCoords => write!(f, "TexCoords"),
+            Capacity::Normals => write!(f, "Normals"),
+            Capacity::Vertices => write!(f, "Vertices"),
+            Capacity::FaceVertices => write!(f, "FaceVertices"),
+            Capacity::Faces => write!(f, "Faces"),
+            Capacity::Materials => write!(f, "Materials"),
+        }
+    }
+}
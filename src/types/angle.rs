@@ -1,5 +1,8 @@
 use core::{f32::consts::PI, fmt::Debug};
+use defmt::Format;
 
+// Generated to more places than an f32 holds
+#[allow(clippy::excessive_precision)]
 const SINES: [f32; 1571] = [
     0.0,
     0.0009999998333333417,
@@ -1574,7 +1577,7 @@ const SINES: [f32; 1571] = [
     0.9999996829318346,
 ];
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Format)]
 /// Represents an angle.
 pub struct Angle {
     pub rad: f32,
@@ -1589,7 +1592,7 @@ impl Debug for Angle {
 impl Angle {
     /// Creates an Angle from radians
     pub fn from_rad(rad: f32) -> Angle {
-        Angle { rad: rad }
+        Angle { rad }
     }
 
     /// Creates an Angle from degrees
@@ -1613,68 +1616,34 @@ impl Angle {
 /// Returns the sine of a given angle in radians.
 /// The LUT used has a resolution to the thousands
 /// place, then lerps between values.
-pub fn sin(mut rad: f32) -> f32 {
+pub fn sin(rad: f32) -> f32 {
     let negative = rad < 0.0;
-    if negative {
-        rad *= -1.0;
-    }
-    rad = rad % (PI * 2.0); // restrict to range of sin
-
-    let sine: f32;
-    let n1: f32;
-    let n2: f32;
+    let rad = rad.abs() % (PI * 2.0); // restrict to range of sin
 
-    let index = ((rad % (PI / 2.0)) * 1000.0) as usize;
-    let inv_index = 1570 - index;
-
-    if rad == 0.0 {
-        return 0.0;
-    } else if rad < PI / 2.0 {
-        n1 = SINES[index];
-        if index == 1571 {
-            n2 = 1.0;
-        } else {
-            n2 = SINES[index + 1];
-        }
-    } else if rad == PI / 2.0 {
-        return 1.0;
-    } else if rad < PI {
-        n2 = SINES[inv_index];
-        if index == 1571 {
-            n1 = 0.0;
-        } else {
-            n1 = SINES[inv_index - 1];
-        }
-    } else if rad == PI {
-        return 0.0;
-    } else if rad < PI * 3.0 / 2.0 {
-        n1 = SINES[index] * -1.0;
-        if index == 1571 {
-            n2 = -1.0;
-        } else {
-            n2 = SINES[index + 1] * -1.0;
-        }
-    } else if rad == PI * 3.0 / 2.0 {
-        return -1.0;
+    // Fold into the first quadrant, which the LUT covers
+    let (rad, negative) = if rad < PI {
+        (rad, negative)
     } else {
-        n2 = SINES[inv_index] * -1.0;
-        if index == 1571 {
-            n1 = 0.0;
-        } else {
-            n1 = SINES[inv_index - 1] * -1.0;
-        }
-    }
+        (rad - PI, !negative)
+    };
+    let rad = if rad > PI / 2.0 { PI - rad } else { rad };
 
-    sine = n1 + (n2 - n1) * (rad - ((rad as i32) / 1000) as f32);
+    let pos = rad * 1000.0;
+    let index = pos as usize;
+    // The LUT stops just short of pi/2
+    let n1 = SINES.get(index).copied().unwrap_or(1.0);
+    let n2 = SINES.get(index + 1).copied().unwrap_or(1.0);
+
+    let sine = n1 + (n2 - n1) * (pos - index as f32);
 
     if negative {
-        sine * -1.0
+        -sine
     } else {
         sine
     }
 }
 
-/// Returns the sine of a given angle in radians.
+/// Returns the cosine of a given angle in radians.
 /// Uses angle::sin and rotates it by pi/2.
 pub fn cos(rad: f32) -> f32 {
     sin(rad + (PI / 2.0))
@@ -1683,3 +1652,54 @@ pub fn cos(rad: f32) -> f32 {
 pub fn tan(rad: f32) -> f32 {
     sin(rad) / cos(rad)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4, FRAC_PI_6};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 2e-6,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn matches_known_values_in_every_quadrant() {
+        for turn in [-2.0, 0.0, 1.0] {
+            let offset = turn * 2.0 * PI;
+            assert_close(sin(offset + FRAC_PI_6), 0.5);
+            assert_close(sin(offset + PI - FRAC_PI_6), 0.5);
+            assert_close(sin(offset + PI + FRAC_PI_6), -0.5);
+            assert_close(sin(offset - FRAC_PI_6), -0.5);
+            assert_close(sin(offset + FRAC_PI_4), FRAC_1_SQRT_2);
+            assert_close(sin(offset + FRAC_PI_2), 1.0);
+            assert_close(cos(offset + PI), -1.0);
+        }
+    }
+
+    #[test]
+    fn is_smooth_across_quadrants() {
+        // A spinning model jumped where the old lookup
+        // indexed the second and fourth quadrants wrongly
+        let step = 0.001;
+        let mut previous = sin(-7.0);
+        let mut rad = -7.0 + step;
+        while rad < 7.0 {
+            let sine = sin(rad);
+            assert!(
+                (sine - previous).abs() <= step * 1.01,
+                "jump at {}: {} to {}",
+                rad,
+                previous,
+                sine
+            );
+            assert_close(sine * sine + cos(rad) * cos(rad), 1.0);
+            previous = sine;
+            rad += step;
+        }
+    }
+}
@@ -186,70 +186,9 @@ impl Mul<f32> for Mat4<f32> {
 
 impl MulAssign<Mat4<f32>> for Mat4<f32> {
     fn mul_assign(&mut self, rhs: Mat4<f32>) {
-        self.v_00 = self.v_00 * rhs.v_00
-            + self.v_01 * rhs.v_10
-            + self.v_02 * rhs.v_20
-            + self.v_03 * rhs.v_30;
-        self.v_01 = self.v_00 * rhs.v_01
-            + self.v_01 * rhs.v_11
-            + self.v_02 * rhs.v_21
-            + self.v_03 * rhs.v_31;
-        self.v_02 = self.v_00 * rhs.v_02
-            + self.v_01 * rhs.v_12
-            + self.v_02 * rhs.v_22
-            + self.v_03 * rhs.v_32;
-        self.v_03 = self.v_00 * rhs.v_03
-            + self.v_01 * rhs.v_13
-            + self.v_02 * rhs.v_23
-            + self.v_03 * rhs.v_33;
-        self.v_10 = self.v_10 * rhs.v_00
-            + self.v_11 * rhs.v_10
-            + self.v_12 * rhs.v_20
-            + self.v_13 * rhs.v_30;
-        self.v_11 = self.v_10 * rhs.v_01
-            + self.v_11 * rhs.v_11
-            + self.v_12 * rhs.v_21
-            + self.v_13 * rhs.v_31;
-        self.v_12 = self.v_10 * rhs.v_02
-            + self.v_11 * rhs.v_12
-            + self.v_12 * rhs.v_22
-            + self.v_13 * rhs.v_32;
-        self.v_13 = self.v_10 * rhs.v_03
-            + self.v_11 * rhs.v_13
-            + self.v_12 * rhs.v_23
-            + self.v_13 * rhs.v_33;
-        self.v_20 = self.v_20 * rhs.v_00
-            + self.v_21 * rhs.v_10
-            + self.v_22 * rhs.v_20
-            + self.v_23 * rhs.v_30;
-        self.v_21 = self.v_20 * rhs.v_01
-            + self.v_21 * rhs.v_11
-            + self.v_22 * rhs.v_21
-            + self.v_23 * rhs.v_31;
-        self.v_22 = self.v_20 * rhs.v_02
-            + self.v_21 * rhs.v_12
-            + self.v_22 * rhs.v_22
-            + self.v_23 * rhs.v_32;
-        self.v_23 = self.v_20 * rhs.v_03
-            + self.v_21 * rhs.v_13
-            + self.v_22 * rhs.v_23
-            + self.v_23 * rhs.v_33;
-        self.v_30 = self.v_30 * rhs.v_00
-            + self.v_31 * rhs.v_10
-            + self.v_32 * rhs.v_20
-            + self.v_33 * rhs.v_30;
-        self.v_31 = self.v_30 * rhs.v_01
-            + self.v_31 * rhs.v_11
-            + self.v_32 * rhs.v_21
-            + self.v_33 * rhs.v_31;
-        self.v_32 = self.v_30 * rhs.v_02
-            + self.v_31 * rhs.v_12
-            + self.v_32 * rhs.v_22
-            + self.v_33 * rhs.v_32;
-        self.v_33 = self.v_30 * rhs.v_03
-            + self.v_31 * rhs.v_13
-            + self.v_32 * rhs.v_23
-            + self.v_33 * rhs.v_33;
+        // Every element depends on the original row, so
+        // they can't be overwritten one at a time
+        *self = *self * rhs;
     }
 }
 
@@ -328,17 +267,17 @@ impl Mat4<f32> {
     /// Creates a translation matrix.
     pub fn translate(vec: Vec3<f32>) -> Mat4<f32> {
         Mat4::<f32> {
-            v_00: 0.0,
+            v_00: 1.0,
             v_01: 0.0,
             v_02: 0.0,
             v_03: vec.x,
             v_10: 0.0,
-            v_11: 0.0,
+            v_11: 1.0,
             v_12: 0.0,
             v_13: vec.y,
             v_20: 0.0,
             v_21: 0.0,
-            v_22: 0.0,
+            v_22: 1.0,
             v_23: vec.z,
             v_30: 0.0,
             v_31: 0.0,
@@ -354,15 +293,15 @@ impl Mat4<f32> {
             v_00: 1.0 - 2.0 * (q.j * q.j + q.k * q.k),
             v_01: 2.0 * (q.i * q.j - q.k * q.a),
             v_02: 2.0 * (q.i * q.k + q.j * q.a),
-            v_03: 1.0,
+            v_03: 0.0,
             v_10: 2.0 * (q.i * q.j + q.k * q.a),
             v_11: 1.0 - 2.0 * (q.i * q.i + q.k * q.k),
             v_12: 2.0 * (q.j * q.k - q.i * q.a),
-            v_13: 1.0,
+            v_13: 0.0,
             v_20: 2.0 * (q.i * q.k - q.j * q.a),
             v_21: 2.0 * (q.j * q.k + q.i * q.a),
-            v_22: 1.0 - 2.0 * (q.i * q.i * q.j * q.j),
-            v_23: 1.0,
+            v_22: 1.0 - 2.0 * (q.i * q.i + q.j * q.j),
+            v_23: 0.0,
             v_30: 0.0,
             v_31: 0.0,
             v_32: 0.0,
@@ -372,6 +311,116 @@ impl Mat4<f32> {
 
     /// Creates a uniform scaling matrix.
     pub fn scale(scl: f32) -> Mat4<f32> {
-        Self::idt() * scl
+        let mut mat = Self::idt() * scl;
+        mat.v_33 = 1.0;
+        mat
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{angle::Angle, vector::VEC3_Z};
+
+    /// Asserts that `actual` matches `expected`, given row by row.
+    fn assert_mat(actual: Mat4<f32>, expected: [[f32; 4]; 4]) {
+        let m = actual;
+        let rows = [
+            [m.v_00, m.v_01, m.v_02, m.v_03],
+            [m.v_10, m.v_11, m.v_12, m.v_13],
+            [m.v_20, m.v_21, m.v_22, m.v_23],
+            [m.v_30, m.v_31, m.v_32, m.v_33],
+        ];
+        for (row, expected_row) in rows.iter().zip(expected) {
+            for (value, expected) in row.iter().zip(expected_row) {
+                assert!(
+                    (value - expected).abs() < 1e-5,
+                    "{:?} != {:?}",
+                    rows,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn translate_puts_the_offset_in_the_last_column() {
+        let t = Mat4::translate(Vec3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        });
+        assert_mat(
+            t,
+            [
+                [1.0, 0.0, 0.0, 1.0],
+                [0.0, 1.0, 0.0, 2.0],
+                [0.0, 0.0, 1.0, 3.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn rotate_turns_counter_clockwise_about_the_axis() {
+        // A quarter turn about z takes x to y and y to -x
+        let r = Mat4::rotate(Quaternion::of(VEC3_Z, Angle::from_deg(90.0)));
+        assert_mat(
+            r,
+            [
+                [0.0, -1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn scale_leaves_w_alone() {
+        assert_mat(
+            Mat4::scale(2.0),
+            [
+                [2.0, 0.0, 0.0, 0.0],
+                [0.0, 2.0, 0.0, 0.0],
+                [0.0, 0.0, 2.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn mul_assign_applies_the_right_hand_side_first() {
+        let offset = Vec3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+
+        // Scaled, then moved: the offset isn't scaled
+        let mut m = Mat4::translate(offset);
+        m *= Mat4::scale(2.0);
+        assert_mat(
+            m,
+            [
+                [2.0, 0.0, 0.0, 1.0],
+                [0.0, 2.0, 0.0, 2.0],
+                [0.0, 0.0, 2.0, 3.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        );
+
+        // Moved, then scaled: the offset is
+        let mut m = Mat4::scale(2.0);
+        m *= Mat4::translate(offset);
+        assert_mat(
+            m,
+            [
+                [2.0, 0.0, 0.0, 2.0],
+                [0.0, 2.0, 0.0, 4.0],
+                [0.0, 0.0, 2.0, 6.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        );
     }
 }
@@ -1,3 +1,4 @@
+use core::ops::Mul;
 use defmt::Format;
 
 use crate::{
@@ -22,7 +23,19 @@ pub struct Quaternion {
 }
 
 impl Quaternion {
-    pub fn of(axis: Vec3<f32>, angle: Angle) -> Quaternion {
+    /// The identity rotation.
+    pub fn idt() -> Quaternion {
+        Quaternion {
+            a: 1.0,
+            i: 0.0,
+            j: 0.0,
+            k: 0.0,
+        }
+    }
+
+    /// Creates a rotation of `angle` about `axis`, which needn't be normalised.
+    pub fn of(mut axis: Vec3<f32>, angle: Angle) -> Quaternion {
+        axis.nor();
         let h_sin_a = sin(angle.rad / 2.0);
         Quaternion {
             i: h_sin_a * axis.x,
@@ -43,6 +56,53 @@ impl Quaternion {
     }
 
     pub fn len(&self) -> f32 {
-        sqrt((self.i * self.i + self.j * self.j + self.k * self.k + self.a * self.a) as f32)
+        sqrt(self.i * self.i + self.j * self.j + self.k * self.k + self.a * self.a)
+    }
+}
+
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    /// Combines two rotations, applying `rhs` first.
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion {
+            a: self.a * rhs.a - self.i * rhs.i - self.j * rhs.j - self.k * rhs.k,
+            i: self.a * rhs.i + self.i * rhs.a + self.j * rhs.k - self.k * rhs.j,
+            j: self.a * rhs.j - self.i * rhs.k + self.j * rhs.a + self.k * rhs.i,
+            k: self.a * rhs.k + self.i * rhs.j - self.j * rhs.i + self.k * rhs.a,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::vector::{VEC3_X, VEC3_Z};
+
+    fn parts(q: Quaternion) -> [f32; 4] {
+        [q.a, q.i, q.j, q.k]
+    }
+
+    fn assert_parts(actual: Quaternion, expected: [f32; 4]) {
+        for (value, expected_value) in parts(actual).iter().zip(expected) {
+            assert!(
+                (value - expected_value).abs() < 1e-5,
+                "{:?} != {:?}",
+                parts(actual),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn mul_matches_the_hamilton_product() {
+        let x = Quaternion::of(VEC3_X, Angle::from_deg(90.0));
+        let z = Quaternion::of(VEC3_Z, Angle::from_deg(90.0));
+
+        // A third of a turn about (1, 1, 1)
+        assert_parts(z * x, [0.5, 0.5, 0.5, 0.5]);
+        // The other order turns about (1, -1, 1)
+        assert_parts(x * z, [0.5, 0.5, -0.5, 0.5]);
+        assert_parts(Quaternion::idt() * x, parts(x));
     }
 }
@@ -113,7 +113,7 @@ impl Vec2<f32> {
 
     /// Calculates the length of this vector.
     pub fn len(&self) -> f32 {
-        sqrt((self.x * self.x + self.y * self.y) as f32)
+        sqrt(self.x * self.x + self.y * self.y)
     }
 
     /// Normalises this vector, and returns it for chaining.
@@ -245,7 +245,7 @@ impl Vec3<f32> {
     pub fn cross(v1: Vec3<f32>, v2: Vec3<f32>) -> Vec3<f32> {
         Vec3::<f32> {
             x: v1.y * v2.z - v1.z * v2.y,
-            y: v1.x * v2.z - v1.z * v2.x,
+            y: v1.z * v2.x - v1.x * v2.z,
             z: v1.x * v2.y - v1.y * v2.x,
         }
     }
@@ -269,7 +269,7 @@ impl Vec3<f32> {
         Vec3::<f32> {
             x: vec2.x,
             y: vec2.y,
-            z: z,
+            z,
         }
     }
 
@@ -283,7 +283,7 @@ impl Vec3<f32> {
 
     /// Calculates the length of this vector.
     pub fn len(&self) -> f32 {
-        sqrt((self.x * self.x + self.y * self.y + self.z * self.z) as f32)
+        sqrt(self.x * self.x + self.y * self.y + self.z * self.z)
     }
 
     /// Normalises this vector, and returns it for chaining.
@@ -373,7 +373,7 @@ impl Vec4<f32> {
     pub fn cross(v1: Vec3<f32>, v2: Vec3<f32>) -> Vec3<f32> {
         Vec3::<f32> {
             x: v1.y * v2.z - v1.z * v2.y,
-            y: v1.x * v2.z - v1.z * v2.x,
+            y: v1.z * v2.x - v1.x * v2.z,
             z: v1.x * v2.y - v1.y * v2.x,
         }
     }
@@ -384,7 +384,7 @@ impl Vec4<f32> {
             x: vec3.x,
             y: vec3.y,
             z: vec3.z,
-            w: w,
+            w,
         }
     }
 
@@ -411,7 +411,7 @@ impl Vec4<f32> {
 
     /// Calculates the length of this vector.
     pub fn len(&self) -> f32 {
-        sqrt((self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w) as f32)
+        sqrt(self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w)
     }
 
     /// Normalises this vector, and returns it for chaining.